@@ -0,0 +1,104 @@
+use crate::scores::now_secs;
+use crate::session::{load_progress, save_progress, StateReader, StateWriter};
+
+const ACHIEVEMENTS_SAVE: &str = "achievements";
+
+/// A milestone a game can award. The accelerator track marks the ideas
+/// the Booster is built on; each badge carries a short lesson on the
+/// physics behind it, shown when it unlocks.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Badge {
+    TuneMeasured,
+    ChromCorrected,
+    BumpClosed,
+    TransitionTwice,
+}
+
+impl Badge {
+    /// In save-file order; new badges go on the end.
+    pub const ALL: [Badge; 4] = [Badge::TuneMeasured, Badge::ChromCorrected, Badge::BumpClosed, Badge::TransitionTwice];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Badge::TuneMeasured => "Measured a tune",
+            Badge::ChromCorrected => "Corrected chromaticity to ±1",
+            Badge::BumpClosed => "Closed a 4-bump",
+            Badge::TransitionTwice => "Survived transition twice in a row",
+        }
+    }
+
+    /// How to earn it, shown while it's locked.
+    pub fn hint(self) -> &'static str {
+        match self {
+            Badge::TuneMeasured => "Booster: open the console with : and run `measure tune`.",
+            Badge::ChromCorrected => "Booster: on the ramp, trim the sextupoles until both chromaticities are within 1 of the -7 target.",
+            Badge::BumpClosed => "Booster: press B twice for a 4-bump, raise it with W/S or E/Q, and keep the beam going round.",
+            Badge::TransitionTwice => "Booster: cross transition cleanly on two cycles running.",
+        }
+    }
+
+    /// The concept behind the badge.
+    pub fn lesson(self) -> &'static str {
+        match self {
+            Badge::TuneMeasured => "The tune is how many times a particle swings about the design orbit in one turn. \
+                On a whole or half number the same field error kicks it at the same phase every turn until it's lost, \
+                so the Booster runs at fractional tunes near 6.7 and 6.8.",
+            Badge::ChromCorrected => "Chromaticity is how far the tune shifts with a particle's momentum. \
+                Quadrupoles focus the faster particles too weakly, so sextupoles, placed where the orbit spreads out by momentum, \
+                add focusing that grows with the offset. Held near its target, the bunch's head and tail can't drive each other unstable.",
+            Badge::BumpClosed => "A bump moves the orbit in one place and nowhere else. \
+                Four correctors kicking +1 −1 −1 +1 push the beam out, hold it there and bring it back, \
+                and because the kicks cancel the orbit outside them is untouched. It's how operators steer round a tight aperture.",
+            Badge::TransitionTwice => "Below transition energy the faster particles go round sooner; above it their longer path makes them later. \
+                At γt the two effects cancel: the bunch stops rotating in phase space, is at its shortest and most fragile, \
+                and the RF phase has to jump. Twice running means it wasn't luck.",
+        }
+    }
+}
+
+/// Badges earned so far, kept with the other progress files.
+pub struct Achievements {
+    /// Unix seconds each badge unlocked, indexed like `Badge::ALL`; 0 if locked
+    unlocked: [u64; Badge::ALL.len()],
+}
+
+impl Achievements {
+    pub fn load() -> Self {
+        let mut unlocked = [0; Badge::ALL.len()];
+        if let Some(data) = load_progress(ACHIEVEMENTS_SAVE) {
+            let mut r = StateReader::new(&data);
+            let count = r.u32().unwrap_or(0) as usize;
+            for slot in unlocked.iter_mut().take(count) {
+                *slot = r.u64().unwrap_or(0);
+            }
+        }
+        Achievements { unlocked }
+    }
+
+    fn save(&self) {
+        let mut w = StateWriter::new();
+        w.u32(self.unlocked.len() as u32);
+        for &at in &self.unlocked {
+            w.u64(at);
+        }
+        save_progress(ACHIEVEMENTS_SAVE, &w.finish());
+    }
+
+    /// Record `badge`; true the first time, when it's worth announcing.
+    pub fn unlock(&mut self, badge: Badge) -> bool {
+        let slot = &mut self.unlocked[badge as usize];
+        if *slot != 0 { return false; }
+        *slot = now_secs().max(1);
+        self.save();
+        true
+    }
+
+    /// When `badge` was earned, if it has been.
+    pub fn unlocked_at(&self, badge: Badge) -> Option<u64> {
+        Some(self.unlocked[badge as usize]).filter(|&at| at != 0)
+    }
+
+    pub fn count(&self) -> usize {
+        self.unlocked.iter().filter(|&&at| at != 0).count()
+    }
+}
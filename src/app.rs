@@ -1,17 +1,79 @@
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::fs;
+use std::time::{Duration, Instant};
 
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::{Position, Rect};
+
+use crate::achievements::{Achievements, Badge};
+use crate::audio;
+use crate::config::{Config, ConfigWatcher, GAME_KEYS};
+use crate::control::{ControlServer, Json, Request, RpcError};
 use crate::games::asteroids::Asteroids;
 use crate::games::beam::BeamGame;
+use crate::games::beam_dump::BeamDumpGame;
 use crate::games::booster::BoosterGame;
 use crate::games::breakout::Breakout;
 use crate::games::dino_run::DinoRun;
 use crate::games::frogger::Frogger;
 use crate::games::jezzball::JezzBall;
 use crate::games::space_invaders::SpaceInvaders;
-use crate::games::Game;
-use crate::scores::HighScores;
+use crate::games::scope::ScopeGame;
+use crate::games::router::RouterGame;
+use crate::games::racer::RacerGame;
+use crate::games::delve::DelveGame;
+use crate::games::columns::ColumnsGame;
+use crate::games::whack::WhackGame;
+use crate::games::life::LifeGame;
+use crate::games::pong::PongGame;
+use crate::games::minesweeper::MinesweeperGame;
+use crate::games::{Game, GameInfo, GAME_COUNT};
+use crate::keymap::{KeyBind, Keymap, Layout};
+use crate::kiosk::Kiosk;
+use crate::leaderboard::Leaderboard;
+use crate::metrics::Metrics;
+use crate::plugins::PluginHost;
+use crate::profile::Profile;
+use crate::rng::GameRng;
+use crate::scores::{game_of, table_of, HighScores, HistoryEntry, BEAM_TA};
+use crate::scripting::Scripts;
+use crate::session::{clear_all_progress, Session, Snapshot};
+use crate::stats::{PlayStats, SessionSummary};
+use crate::ui::badges::{BadgeBrowser, BadgeCard};
+use crate::ui::compat;
+use crate::ui::fx;
+use crate::ui::game_area;
+use crate::ui::home::TILE_COLUMNS;
+use crate::ui::hud::Toast;
+use crate::ui::keybindings::KeyBindings;
+use crate::ui::maintenance::{MaintenanceAction, MaintenanceMenu, ResetTarget};
+use crate::ui::onboarding::{Onboarding, OnboardingAction, Tour};
+use crate::ui::score_browser::ScoreBrowser;
+use crate::ui::settings::{self, DisplaySettings, Setting, SettingsAction, SettingsTab, SettingsTabAction};
+use crate::ui::theme::Theme;
 
 const MAX_NAME_LEN: usize = 9;
+/// Fixed simulation step; game physics are tuned for ~60 updates per second.
+const SIM_STEP: Duration = Duration::from_millis(16);
+/// Most simulated time one tick catches up on. A slow terminal's frames
+/// fit well inside it, so its games keep real time; a longer stall (a
+/// suspend, a hung terminal) is dropped rather than fast-forwarded.
+const MAX_BACKLOG: Duration = Duration::from_millis(250);
+/// How often the drawn frame rate shown on the Settings tab is measured.
+const RATE_WINDOW: Duration = Duration::from_secs(1);
+/// Autosave the running game every ~10 s of simulation.
+const AUTOSAVE_STEPS: u32 = 600;
+/// Speed handicaps S cycles through on the Home tab, in percent.
+const SPEED_STEPS: [u32; 6] = [100, 125, 150, 200, 50, 75];
+/// Volumes V cycles the selected game through on the Home tab, in percent.
+const VOLUME_STEPS: [u32; 5] = [100, 75, 50, 25, 0];
+/// Frame intervals the Settings tab steps through, slowest first, in ms.
+const FRAME_STEPS: [u64; 4] = [33, 16, 8, 4];
+/// How far one press moves the master volume on the Settings tab, in percent.
+const MASTER_VOLUME_STEP: isize = 10;
+/// Most simulation steps one `step` call on the control socket may run
+const MAX_RPC_STEPS: u32 = 100_000;
+/// Practice mode caps the speed handicap here, in percent.
+const PRACTICE_SPEED: u32 = 75;
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum Tab {
@@ -24,11 +86,25 @@ pub enum Tab {
     Asteroids,
     Booster,
     Beam,
+    BeamDump,
+    Scope,
+    Router,
+    Racer,
+    Delve,
+    Columns,
+    Whack,
+    Life,
+    Pong,
+    Minesweeper,
+    /// Games from shared libraries; only shown when some are installed
+    Plugins,
+    /// Arcade-wide settings, written back to config.toml
+    Settings,
 }
 
 impl Tab {
     pub fn all() -> &'static [Tab] {
-        &[Tab::Home, Tab::Frogger, Tab::Breakout, Tab::DinoRun, Tab::SpaceInvaders, Tab::JezzBall, Tab::Asteroids, Tab::Booster, Tab::Beam]
+        &[Tab::Home, Tab::Frogger, Tab::Breakout, Tab::DinoRun, Tab::SpaceInvaders, Tab::JezzBall, Tab::Asteroids, Tab::Booster, Tab::Beam, Tab::BeamDump, Tab::Scope, Tab::Router, Tab::Racer, Tab::Delve, Tab::Columns, Tab::Whack, Tab::Life, Tab::Pong, Tab::Minesweeper, Tab::Plugins, Tab::Settings]
     }
 
     pub fn title(&self) -> &str {
@@ -42,6 +118,18 @@ impl Tab {
             Tab::Asteroids => " Asteroids ",
             Tab::Booster => " Booster ",
             Tab::Beam => " Beam ",
+            Tab::BeamDump => " Beam Dump ",
+            Tab::Scope => " Scope ",
+            Tab::Router => " Router ",
+            Tab::Racer => " Racer ",
+            Tab::Delve => " Delve ",
+            Tab::Columns => " Columns ",
+            Tab::Whack => " Whack ",
+            Tab::Life => " Life ",
+            Tab::Pong => " Pong ",
+            Tab::Minesweeper => " Minesweeper ",
+            Tab::Plugins => " Plugins ",
+            Tab::Settings => " Settings ",
         }
     }
 
@@ -56,15 +144,41 @@ impl Tab {
             Tab::Asteroids => 6,
             Tab::Booster => 7,
             Tab::Beam => 8,
+            Tab::BeamDump => 9,
+            Tab::Scope => 10,
+            Tab::Router => 11,
+            Tab::Racer => 12,
+            Tab::Delve => 13,
+            Tab::Columns => 14,
+            Tab::Whack => 15,
+            Tab::Life => 16,
+            Tab::Pong => 17,
+            Tab::Minesweeper => 18,
+            Tab::Plugins => 19,
+            Tab::Settings => 20,
+        }
+    }
+
+    /// Home tile index of a built-in game's tab; None for Home, Plugins
+    /// and Settings.
+    pub fn game_index(&self) -> Option<usize> {
+        match self {
+            Tab::Home | Tab::Plugins | Tab::Settings => None,
+            tab => Some(tab.index() - 1),
         }
     }
 
+    pub fn from_index(idx: usize) -> Option<Tab> {
+        Tab::all().get(idx).copied()
+    }
 }
 
 pub struct App {
     pub should_quit: bool,
     pub current_tab: Tab,
-    pub selected_game: usize, // 0-7 for home screen game selection
+    pub selected_game: usize, // Home tile index of the selected game
+    /// Home tiles as last drawn, and the game each launches, for clicks
+    pub home_tiles: Vec<(Rect, usize)>,
     pub frogger: Frogger,
     pub breakout: Breakout,
     pub dino_run: DinoRun,
@@ -73,8 +187,46 @@ pub struct App {
     pub asteroids: Asteroids,
     pub booster: BoosterGame,
     pub beam: BeamGame,
+    pub beam_dump: BeamDumpGame,
+    pub scope: ScopeGame,
+    pub router: RouterGame,
+    pub racer: RacerGame,
+    pub delve: DelveGame,
+    pub columns: ColumnsGame,
+    pub whack: WhackGame,
+    pub life: LifeGame,
+    pub pong: PongGame,
+    pub minesweeper: MinesweeperGame,
+    pub plugins: PluginHost,
     pub high_scores: HighScores,
+    pub play_stats: PlayStats,
     pub show_high_scores: bool,
+    pub score_browser: ScoreBrowser,
+    pub show_maintenance: bool,
+    pub maintenance: MaintenanceMenu,
+    pub show_keybindings: bool,
+    pub key_bindings: KeyBindings,
+    pub achievements: Achievements,
+    pub show_badges: bool,
+    pub badge_browser: BadgeBrowser,
+    pub show_settings: bool,
+    pub display_settings: DisplaySettings,
+    pub settings_tab: SettingsTab,
+    /// Lesson for the badge just earned
+    pub badge_card: Option<BadgeCard>,
+    /// Badges earned while another's card was up, shown next
+    badge_queue: Vec<Badge>,
+    pub keymap: Keymap,
+    pub profile: Profile,
+    /// Theme everything is drawn in: the profile's, or one being tried
+    pub theme: Theme,
+    /// First-run setup, while it's up
+    pub onboarding: Option<Onboarding>,
+    /// The tour offered at the end of setup, while it runs
+    pub tour: Option<Tour>,
+    pub leaderboard: Leaderboard,
+    /// `/` on Home: tiles not matching the query are dimmed
+    pub home_search: Option<String>,
     pub show_help: bool,
     pub help_scroll: u16,
     // Name entry state
@@ -82,41 +234,762 @@ pub struct App {
     pub name_buffer: String,
     pub name_game_idx: usize,
     pub name_score: u32,
+    /// Table detail stat for the pending score (e.g. Invaders waves)
+    pub name_detail: u32,
+    /// Speed handicap the pending score was played at
+    pub name_speed: u32,
+    /// Modifier flags the pending score was played with
+    pub name_flags: u32,
+    /// Extra stats for the pending score (e.g. Dino Run obstacles)
+    pub name_extra: Vec<u32>,
+    /// Per-game speed handicap in percent, indexed like the Home tiles
+    pub game_speed: [u32; GAME_COUNT],
+    /// Slowest speed each game has run at since it last started; this is
+    /// what its score gets flagged with
+    speed_used: [u32; GAME_COUNT],
+    /// Per-game sound volume in percent, indexed like the Home tiles
+    pub game_volume: [u32; GAME_COUNT],
+    /// U on the Home tab: every cue silenced
+    pub muted: bool,
+    pub config: Config,
+    /// Reloads `config.toml` and the key bindings when they're edited
+    config_watcher: Option<ConfigWatcher>,
+    /// config.toml as the Settings tab last wrote it, so the watcher
+    /// doesn't reload our own save
+    config_written: Option<String>,
+    /// Short notice in the corner, e.g. that the config was reloaded
+    pub toast: Option<Toast>,
+    // Simulation clock (decoupled from render rate)
+    last_tick: Instant,
+    sim_accumulator: Duration,
+    pub render_alpha: f32,
+    /// Frames drawn per second over the last `RATE_WINDOW` a game ran;
+    /// 0 until one has
+    pub drawn_fps: f32,
+    /// Start of the current `RATE_WINDOW` and the frame count then
+    rate_sample: (Instant, u64),
+    // Session autosave / crash recovery
+    pub recovered_session: Option<Session>,
+    autosave_steps: u32,
+    /// Game frozen at the last clean exit, offered as Continue on Home
+    pub snapshot: Option<Snapshot>,
+    /// The game most recently run, frozen on exit from Home too
+    last_played: Option<usize>,
+    /// Per-game bot scripts (only with the `scripting` feature)
+    pub scripts: Scripts,
+    /// JSON-RPC socket, when `[control] socket` is set
+    control: Option<ControlServer>,
+    pub metrics: Metrics,
+    /// Games a script or the control socket has played since they last
+    /// started; their scores don't go on the tables
+    automated: [bool; GAME_COUNT],
+    /// Per-game practice mode (P on the Home tab): slower, with aids, and
+    /// never scored
+    pub practice: [bool; GAME_COUNT],
+    /// Games played in practice at any point since they last started
+    practiced: [bool; GAME_COUNT],
+    /// F3: collision outlines in every game, practice or not
+    pub show_hitboxes: bool,
+    /// F4: a game kept running in a corner viewport while another is played
+    pub pinned: Option<usize>,
+    /// `--kiosk`: no way out to the shell, idle visits end in the demo
+    pub kiosk: Option<Kiosk>,
 }
 
 impl App {
     pub fn new() -> Self {
+        let config = Config::load();
+        let mut high_scores = HighScores::load();
+        high_scores.set_seasons(config.seasons.clone());
+        let score_browser = ScoreBrowser::new(&high_scores);
+        fx::set_flash_limit(config.max_flash_hz);
+        fx::set_alert_color(config.alert_color);
+        let profile = Profile::load();
+        let first_run = profile.is_none();
+        let mut profile = profile.unwrap_or_default();
+        // A theme set in config.toml wins over the one picked on screen
+        if let Some(theme) = config.theme {
+            profile.theme = theme;
+        }
+        compat::set_profile(profile.render_profile(&config));
+        audio::set_enabled(&config.sound_cues);
+        audio::set_muted(config.sound_muted);
+        audio::set_master_volume(config.sound_volume);
+        let mut frogger = Frogger::new();
+        frogger.set_skin(config.frogger_skin, config.seasonal_skins);
+        let mut asteroids = Asteroids::new();
+        asteroids.set_reduced_motion(config.reduced_motion);
+        asteroids.set_extra_life(config.asteroids_extra_life);
+        let mut breakout = Breakout::new();
+        breakout.set_guide(config.ball_guide);
+        let mut space_invaders = SpaceInvaders::new();
+        space_invaders.set_reduced_motion(config.reduced_motion);
+        space_invaders.set_best(high_scores.top_scores(3)[0].score);
+        let (control, control_error) = match config.control_socket.clone().map(ControlServer::start) {
+            Some(Ok(server)) => (Some(server), None),
+            Some(Err(error)) => (None, Some(error)),
+            None => (None, None),
+        };
+        let metrics = Metrics::new();
+        let metrics_error = config.metrics_listen.as_deref().and_then(|addr| metrics.serve(addr).err());
+        let mut keymap = Keymap::load();
+        keymap.set_config_binds(&config.key_binds);
+        let mut beam = BeamGame::new();
+        beam.set_default_difficulty(config.beam_hard);
+        let watched = Config::config_path().into_iter().chain([keymap.path().to_path_buf()]).collect();
         Self {
             should_quit: false,
             current_tab: Tab::Home,
             selected_game: 0,
-            frogger: Frogger::new(),
-            breakout: Breakout::new(),
+            home_tiles: Vec::new(),
+            frogger,
+            breakout,
             dino_run: DinoRun::new(),
-            space_invaders: SpaceInvaders::new(),
+            space_invaders,
             jezzball: JezzBall::new(),
-            asteroids: Asteroids::new(),
+            asteroids,
             booster: BoosterGame::new(),
-            beam: BeamGame::new(),
-            high_scores: HighScores::load(),
+            beam,
+            beam_dump: BeamDumpGame::new(),
+            scope: ScopeGame::new(),
+            router: RouterGame::new(),
+            racer: RacerGame::new(),
+            delve: DelveGame::new(),
+            columns: ColumnsGame::new(),
+            whack: WhackGame::new(),
+            life: LifeGame::new(),
+            pong: PongGame::new(),
+            minesweeper: MinesweeperGame::new(),
+            plugins: PluginHost::load(),
+            high_scores,
+            play_stats: PlayStats::load(),
             show_high_scores: false,
+            score_browser,
+            show_maintenance: false,
+            maintenance: MaintenanceMenu::new(),
+            show_keybindings: false,
+            key_bindings: KeyBindings::new(),
+            achievements: Achievements::load(),
+            show_badges: false,
+            badge_browser: BadgeBrowser::new(),
+            show_settings: false,
+            display_settings: DisplaySettings::new(&profile),
+            settings_tab: SettingsTab::default(),
+            badge_card: None,
+            badge_queue: Vec::new(),
+            keymap,
+            theme: profile.theme,
+            profile,
+            onboarding: first_run.then(Onboarding::new),
+            tour: None,
+            home_search: None,
             show_help: false,
             help_scroll: 0,
             entering_name: false,
             name_buffer: String::new(),
             name_game_idx: 0,
             name_score: 0,
+            name_detail: 0,
+            name_speed: 100,
+            name_flags: 0,
+            name_extra: Vec::new(),
+            game_speed: config.game_speed,
+            speed_used: config.game_speed,
+            game_volume: config.game_volume,
+            muted: config.sound_muted,
+            leaderboard: Leaderboard::new(&config),
+            config,
+            config_watcher: ConfigWatcher::start(watched),
+            config_written: None,
+            toast: control_error
+                .map(|error| format!("Control socket: {}", error))
+                .or(metrics_error.map(|error| format!("Metrics: {}", error)))
+                .map(Toast::error),
+            last_tick: Instant::now(),
+            sim_accumulator: Duration::ZERO,
+            render_alpha: 0.0,
+            drawn_fps: 0.0,
+            rate_sample: (Instant::now(), 0),
+            recovered_session: Session::load(),
+            autosave_steps: 0,
+            snapshot: Snapshot::load().filter(|s| s.game < GAME_COUNT),
+            last_played: None,
+            scripts: Scripts::load(),
+            control,
+            metrics,
+            automated: [false; GAME_COUNT],
+            practice: [false; GAME_COUNT],
+            practiced: [false; GAME_COUNT],
+            show_hitboxes: false,
+            pinned: None,
+            kiosk: None,
         }
     }
 
     pub fn on_tick(&mut self) {
-        // Don't update games while entering a name
-        if self.entering_name {
+        let now = Instant::now();
+        let elapsed = now - self.last_tick;
+        // The speed handicap scales how much simulated time passes
+        self.sim_accumulator += elapsed * self.active_speed() / 100;
+        self.last_tick = now;
+        let (since, frames) = self.rate_sample;
+        if now - since >= RATE_WINDOW {
+            let drawn = self.metrics.frames();
+            // Menus poll slowly on purpose; only a running game's rate counts
+            if !self.is_idle() {
+                self.drawn_fps = (drawn - frames) as f32 / (now - since).as_secs_f32();
+            }
+            self.rate_sample = (now, drawn);
+        }
+
+        self.leaderboard.poll();
+        self.metrics.set_leaderboard_pending(self.leaderboard.pending());
+        self.serve_control();
+        self.watch_config();
+        if let Some(kiosk) = self.kiosk.as_mut().filter(|k| k.timed_out()) {
+            kiosk.end_visit("idle");
+            self.start_attract();
+        }
+        if self.toast.as_ref().is_some_and(Toast::expired) {
+            self.toast = None;
+        }
+        if self.badge_card.as_ref().is_some_and(BadgeCard::expired) {
+            self.badge_card = None;
+        }
+        if self.badge_card.is_none() && !self.badge_queue.is_empty() {
+            self.badge_card = Some(BadgeCard::new(self.badge_queue.remove(0)));
+        }
+
+        if self.tour.as_mut().is_some_and(|tour| !tour.tick()) {
+            self.end_tour();
+        } else if let Some(tour) = &self.tour {
+            (self.current_tab, self.show_help) = tour.view();
+        }
+
+        // Don't update games while entering a name, deciding on recovery
+        // or being shown round
+        if self.entering_name || self.recovered_session.is_some() || self.onboarding.is_some() || self.tour.is_some() {
+            self.sim_accumulator = Duration::ZERO;
+            self.render_alpha = 0.0;
             return;
         }
 
+        // Run as many fixed steps as wall-clock time allows, however many
+        // frames the terminal manages to draw in between
+        if self.sim_accumulator > MAX_BACKLOG {
+            self.sim_accumulator = Duration::ZERO;
+        }
+        let mut steps = 0;
+        while self.sim_accumulator >= SIM_STEP {
+            self.sim_accumulator -= SIM_STEP;
+            self.update_active_game();
+            steps += 1;
+        }
+        self.render_alpha = self.sim_accumulator.as_secs_f32() / SIM_STEP.as_secs_f32();
+
+        if steps > 0 {
+            if let Some(g) = self.current_tab.game_index() {
+                if self.active_game().is_some_and(|game| !game.is_game_over() && !game.is_paused()) {
+                    self.play_stats.played(g, elapsed);
+                }
+            }
+
+            // Check for high scores when games end
+            self.check_submit_scores();
+            self.collect_badges();
+
+            self.autosave_steps += steps;
+            if self.autosave_steps >= AUTOSAVE_STEPS {
+                self.autosave_steps = 0;
+                self.autosave();
+            }
+        }
+    }
+
+    /// Keep the badges games earned this tick. Scripted and demo play
+    /// don't count.
+    fn collect_badges(&mut self) {
+        for g in 0..GAME_COUNT {
+            let badges = self.game_mut(g).take_badges();
+            if self.automated[g] || self.game(g).is_demo() { continue; }
+            for badge in badges {
+                if self.achievements.unlock(badge) {
+                    self.badge_queue.push(badge);
+                }
+            }
+        }
+    }
+
+    /// Write the active game and any pending score to the session file.
+    fn autosave(&self) {
+        if self.recovered_session.is_some() { return; }
+        let pending_score = if self.entering_name {
+            Some((self.name_game_idx, self.name_score, self.name_buffer.clone()))
+        } else {
+            None
+        };
+        let Some(game) = self.active_game() else {
+            // On Home: keep whatever the last game saved
+            if let Some(pending) = pending_score {
+                Session {
+                    tab_index: 0,
+                    pending_score: Some(pending),
+                    pending_detail: self.name_detail,
+                    pending_speed: self.name_speed,
+                    pending_flags: self.name_flags,
+                    pending_extra: self.name_extra.clone(),
+                    game_state: Vec::new(),
+                }
+                .save();
+            }
+            return;
+        };
+        let game_state = game.serialize_state();
+        if game_state.is_none() && pending_score.is_none() {
+            // Game over (or unsupported): nothing worth recovering
+            Session::clear();
+            return;
+        }
+        Session {
+            tab_index: self.current_tab.index(),
+            pending_score,
+            pending_detail: self.name_detail,
+            pending_speed: self.name_speed,
+            pending_flags: self.name_flags,
+            pending_extra: self.name_extra.clone(),
+            game_state: game_state.unwrap_or_default(),
+        }
+        .save();
+    }
+
+    fn recover_session(&mut self) {
+        let Some(session) = self.recovered_session.take() else { return };
+        if let Some(tab) = Tab::from_index(session.tab_index) {
+            self.current_tab = tab;
+        }
+        if !session.game_state.is_empty() {
+            if let Some(game) = self.active_game_mut() {
+                game.restore_state(&session.game_state);
+            }
+        }
+        if let Some((idx, score, name)) = session.pending_score {
+            self.entering_name = true;
+            self.name_game_idx = idx;
+            self.name_score = score;
+            self.name_detail = session.pending_detail;
+            self.name_speed = session.pending_speed;
+            self.name_flags = session.pending_flags;
+            self.name_extra = session.pending_extra;
+            self.name_buffer = name;
+            self.high_scores.mark_submitted(idx);
+        }
+    }
+
+    /// Clean shutdown: the autosave is only for recovering from crashes.
+    /// Returns the session summary to show, if it's wanted.
+    pub fn on_exit(&mut self) -> Option<SessionSummary> {
+        if let Some(kiosk) = &mut self.kiosk {
+            kiosk.end_visit("exit");
+        }
+        if self.tour.is_some() {
+            self.end_tour();
+        }
+        let summary = self.play_stats.end_session();
+        if self.recovered_session.is_none() {
+            Session::clear();
+        }
+        self.freeze_snapshot();
+        // Kiosk visits have their own log; the next visitor needn't see this one
+        summary.filter(|_| self.config.session_summary && self.kiosk.is_none())
+    }
+
+    /// Save the game on screen (or the last one played) for Continue at
+    /// the next launch. A finished game leaves any older snapshot alone.
+    fn freeze_snapshot(&mut self) {
+        if self.kiosk.is_some() { return; }
+        let Some(g) = self.current_tab.game_index().or(self.last_played) else { return };
+        if self.game(g).is_demo() { return; }
+        if let Some(game_state) = self.game(g).serialize_state() {
+            Snapshot { game: g, game_state }.save();
+        }
+    }
+
+    /// Resume the snapshot from the last exit (Home, C).
+    fn continue_snapshot(&mut self) {
+        let Some(snapshot) = self.snapshot.take() else { return };
+        Snapshot::clear();
+        let g = snapshot.game;
+        self.selected_game = g;
+        self.current_tab = Tab::from_index(g + 1).unwrap_or(Tab::Home);
+        if !self.game_mut(g).restore_state(&snapshot.game_state) {
+            self.toast = Some(Toast::error(format!("Couldn't restore the saved {} game", self.game(g).info().name)));
+        }
+    }
+
+    /// Keep what the first-run setup chose, and start the tour if asked.
+    fn finish_onboarding(&mut self, tour: bool) {
+        let Some(onboarding) = self.onboarding.take() else { return };
+        if onboarding.layout != self.keymap.layout {
+            self.keymap.set_layout(onboarding.layout);
+        }
+        if onboarding.left_handed != self.keymap.left_handed {
+            self.keymap.toggle_left_handed();
+        }
+        self.profile = onboarding.profile();
+        self.set_theme(self.profile.theme);
+        compat::set_profile(self.profile.render_profile(&self.config));
+        if tour {
+            let tour = Tour::new();
+            (self.current_tab, self.show_help) = tour.view();
+            self.tour = Some(tour);
+        }
+    }
+
+    /// Close the display settings, saving their picks to the profile or
+    /// putting the profile's back on screen.
+    fn close_settings(&mut self, keep: bool) {
+        self.show_settings = false;
+        if keep {
+            self.profile.charset = self.display_settings.charset;
+            self.set_theme(self.display_settings.theme);
+        }
+        self.theme = self.profile.theme;
+        compat::set_profile(self.profile.render_profile(&self.config));
+    }
+
+    /// Show `theme` and keep it in the profile, and in config.toml too
+    /// when the file names one, so neither overrides the other later.
+    fn set_theme(&mut self, theme: Theme) {
+        self.profile.theme = theme;
+        self.profile.save();
+        self.theme = theme;
+        if self.config.theme.is_some_and(|t| t != theme) {
+            self.config.theme = Some(theme);
+            self.write_config("display.theme", &format!("\"{}\"", theme.key()));
+        }
+    }
+
+    /// Write one setting to config.toml, saying on the Settings tab
+    /// where it went.
+    fn write_config(&mut self, key: &str, value: &str) {
+        self.settings_tab.message = Some(match Config::write_value(key, value) {
+            Ok(text) => {
+                self.config_written = Some(text);
+                "Saved to config.toml".to_string()
+            }
+            Err(e) => format!("Changed for now; couldn't save config.toml: {}", e),
+        });
+    }
+
+    fn handle_settings_key(&mut self, key: KeyEvent) {
+        match self.settings_tab.handle_key(key) {
+            SettingsTabAction::None => {}
+            SettingsTabAction::Change(setting, delta) => self.change_setting(setting, delta),
+            SettingsTabAction::ResetScores => {
+                self.show_maintenance = true;
+                self.maintenance = MaintenanceMenu::ticked(&[ResetTarget::Scores]);
+            }
+        }
+    }
+
+    /// Step a Settings tab row and apply it straight away.
+    fn change_setting(&mut self, setting: Setting, delta: isize) {
+        let on_off = |on: bool| if on { "true" } else { "false" };
+        match setting {
+            Setting::TickRate => {
+                let i = FRAME_STEPS.iter().position(|&ms| ms <= self.config.frame_ms).unwrap_or(FRAME_STEPS.len() - 1);
+                let i = (i as isize + delta).clamp(0, FRAME_STEPS.len() as isize - 1) as usize;
+                self.config.frame_ms = FRAME_STEPS[i];
+                self.config.idle_poll_ms = self.config.idle_poll_ms.max(self.config.frame_ms);
+                self.write_config("performance.frame_ms", &self.config.frame_ms.to_string());
+            }
+            Setting::Theme => {
+                let theme = settings::step(&Theme::ALL, self.profile.theme, delta);
+                self.config.theme = Some(theme);
+                self.set_theme(theme);
+                self.write_config("display.theme", &format!("\"{}\"", theme.key()));
+            }
+            Setting::Difficulty => {
+                self.config.beam_hard = !self.config.beam_hard;
+                self.beam.set_default_difficulty(self.config.beam_hard);
+                self.write_config("difficulty.beam", if self.config.beam_hard { "\"hard\"" } else { "\"easy\"" });
+            }
+            Setting::Volume => {
+                let volume = (self.config.sound_volume as isize + delta * MASTER_VOLUME_STEP).clamp(0, 100);
+                self.config.sound_volume = volume as u32;
+                audio::set_master_volume(self.config.sound_volume);
+                self.write_config("sound.volume", &self.config.sound_volume.to_string());
+            }
+            Setting::Mute => {
+                self.muted = !self.muted;
+                self.config.sound_muted = self.muted;
+                audio::set_muted(self.muted);
+                self.write_config("sound.mute", on_off(self.muted));
+            }
+            Setting::KeyLayout => {
+                self.keymap.set_layout(settings::step(&Layout::ALL, self.keymap.layout, delta));
+                self.settings_tab.message = Some("Saved to the key bindings file".to_string());
+            }
+            Setting::LeftHanded => {
+                self.keymap.toggle_left_handed();
+                self.settings_tab.message = Some("Saved to the key bindings file".to_string());
+            }
+            Setting::Simulation | Setting::ResetScores => {}
+        }
+    }
+
+    /// Each Settings tab row's value as shown, in `Setting::ALL` order.
+    pub fn setting_values(&self) -> Vec<String> {
+        let on_off = |on: bool| if on { "On" } else { "Off" }.to_string();
+        Setting::ALL
+            .iter()
+            .map(|setting| match setting {
+                Setting::TickRate => format!("{} ms · {} Hz", self.config.frame_ms, 1000 / self.config.frame_ms),
+                Setting::Simulation => match 1.0 / SIM_STEP.as_secs_f32() {
+                    hz if self.drawn_fps > 0.0 => format!("{:.1} Hz · {:.0} fps", hz, self.drawn_fps),
+                    hz => format!("{:.1} Hz", hz),
+                },
+                Setting::Theme => self.profile.theme.name().to_string(),
+                Setting::Difficulty => if self.config.beam_hard { "Hard" } else { "Easy" }.to_string(),
+                Setting::Volume => format!("{}%", self.config.sound_volume),
+                Setting::Mute => if self.muted { "Muted" } else { "On" }.to_string(),
+                Setting::KeyLayout => self.keymap.layout.name().to_string(),
+                Setting::LeftHanded => on_off(self.keymap.left_handed),
+                Setting::ResetScores => "Enter ↵".to_string(),
+            })
+            .collect()
+    }
+
+    fn end_tour(&mut self) {
+        self.tour = None;
+        self.show_help = false;
+        self.current_tab = Tab::Home;
+    }
+
+    /// Lock the arcade down for a shared terminal and start the demo.
+    pub fn enable_kiosk(&mut self) {
+        self.kiosk = Some(Kiosk::new(&self.config));
+        // Visitors get the arcade as the owner set it up
+        self.onboarding = None;
+        self.snapshot = None;
+        self.start_attract();
+    }
+
+    /// Start every game over on its own stream drawn from `seed`, so the
+    /// same seed plays out the same layouts, spawns and rolls again.
+    pub fn set_seed(&mut self, seed: u64) {
+        for g in 0..GAME_COUNT {
+            self.game_mut(g).reseed(GameRng::for_game(seed, g));
+        }
+    }
+
+    /// Clear away the last visitor's game and settings, then let the
+    /// Breakout AI play until someone presses a key.
+    fn start_attract(&mut self) {
+        if self.entering_name {
+            // Keep the score they walked away from, under "???" if unnamed
+            self.handle_name_input(KeyEvent::from(KeyCode::Enter));
+        }
+        if self.recovered_session.take().is_some() {
+            Session::clear();
+        }
+        self.show_help = false;
+        self.show_high_scores = false;
+        self.show_maintenance = false;
+        self.show_keybindings = false;
+        self.show_badges = false;
+        if self.show_settings {
+            self.close_settings(false);
+        }
+        self.home_search = None;
+        if let Some(game) = self.active_game_mut() {
+            game.reset();
+        }
+        self.pinned = None;
+        self.selected_game = 0;
+        self.game_speed = self.config.game_speed;
+        for g in 0..GAME_COUNT {
+            if self.practice[g] {
+                self.practice[g] = false;
+                self.game_mut(g).set_practice(false);
+            }
+        }
+        self.show_hitboxes = false;
+        self.sync_hitboxes();
+        self.current_tab = Tab::Breakout;
+        self.breakout.start_demo();
+    }
+
+    /// The game shown on the current tab, if any.
+    pub fn active_game(&self) -> Option<&dyn Game> {
+        match self.current_tab {
+            Tab::Home | Tab::Settings => None,
+            Tab::Frogger => Some(&self.frogger),
+            Tab::Breakout => Some(&self.breakout),
+            Tab::DinoRun => Some(&self.dino_run),
+            Tab::SpaceInvaders => Some(&self.space_invaders),
+            Tab::JezzBall => Some(&self.jezzball),
+            Tab::Asteroids => Some(&self.asteroids),
+            Tab::Booster => Some(&self.booster),
+            Tab::Beam => Some(&self.beam),
+            Tab::BeamDump => Some(&self.beam_dump),
+            Tab::Scope => Some(&self.scope),
+            Tab::Router => Some(&self.router),
+            Tab::Racer => Some(&self.racer),
+            Tab::Delve => Some(&self.delve),
+            Tab::Columns => Some(&self.columns),
+            Tab::Whack => Some(&self.whack),
+            Tab::Life => Some(&self.life),
+            Tab::Pong => Some(&self.pong),
+            Tab::Minesweeper => Some(&self.minesweeper),
+            Tab::Plugins => Some(&self.plugins),
+        }
+    }
+
+    pub fn active_game_mut(&mut self) -> Option<&mut dyn Game> {
+        match self.current_tab {
+            Tab::Home | Tab::Settings => None,
+            Tab::Frogger => Some(&mut self.frogger),
+            Tab::Breakout => Some(&mut self.breakout),
+            Tab::DinoRun => Some(&mut self.dino_run),
+            Tab::SpaceInvaders => Some(&mut self.space_invaders),
+            Tab::JezzBall => Some(&mut self.jezzball),
+            Tab::Asteroids => Some(&mut self.asteroids),
+            Tab::Booster => Some(&mut self.booster),
+            Tab::Beam => Some(&mut self.beam),
+            Tab::BeamDump => Some(&mut self.beam_dump),
+            Tab::Scope => Some(&mut self.scope),
+            Tab::Router => Some(&mut self.router),
+            Tab::Racer => Some(&mut self.racer),
+            Tab::Delve => Some(&mut self.delve),
+            Tab::Columns => Some(&mut self.columns),
+            Tab::Whack => Some(&mut self.whack),
+            Tab::Life => Some(&mut self.life),
+            Tab::Pong => Some(&mut self.pong),
+            Tab::Minesweeper => Some(&mut self.minesweeper),
+            Tab::Plugins => Some(&mut self.plugins),
+        }
+    }
+
+    /// Every game's self-description, in Home tile order.
+    pub fn game_infos(&self) -> [&'static GameInfo; GAME_COUNT] {
+        std::array::from_fn(|g| self.game(g).info())
+    }
+
+    /// A game by its Home tile index.
+    fn game(&self, g: usize) -> &dyn Game {
+        match g {
+            0 => &self.frogger,
+            1 => &self.breakout,
+            2 => &self.dino_run,
+            3 => &self.space_invaders,
+            4 => &self.jezzball,
+            5 => &self.asteroids,
+            6 => &self.booster,
+            7 => &self.beam,
+            8 => &self.beam_dump,
+            9 => &self.scope,
+            10 => &self.router,
+            11 => &self.racer,
+            12 => &self.delve,
+            13 => &self.columns,
+            14 => &self.whack,
+            15 => &self.life,
+            16 => &self.pong,
+            _ => &self.minesweeper,
+        }
+    }
+
+    fn game_mut(&mut self, g: usize) -> &mut dyn Game {
+        match g {
+            0 => &mut self.frogger,
+            1 => &mut self.breakout,
+            2 => &mut self.dino_run,
+            3 => &mut self.space_invaders,
+            4 => &mut self.jezzball,
+            5 => &mut self.asteroids,
+            6 => &mut self.booster,
+            7 => &mut self.beam,
+            8 => &mut self.beam_dump,
+            9 => &mut self.scope,
+            10 => &mut self.router,
+            11 => &mut self.racer,
+            12 => &mut self.delve,
+            13 => &mut self.columns,
+            14 => &mut self.whack,
+            15 => &mut self.life,
+            16 => &mut self.pong,
+            _ => &mut self.minesweeper,
+        }
+    }
+
+    /// Outline collisions wherever F3 or practice mode asks for them.
+    fn sync_hitboxes(&mut self) {
+        for g in 0..GAME_COUNT {
+            let on = self.show_hitboxes || self.practice[g];
+            self.game_mut(g).set_hitboxes(on);
+        }
+    }
+
+    /// True when nothing on screen animates, so the event loop can poll slowly.
+    pub fn is_idle(&self) -> bool {
+        if self.show_help || self.entering_name || self.recovered_session.is_some() || self.onboarding.is_some() || self.tour.is_some() {
+            return true;
+        }
+        let pinned_running = self.pinned_behind().is_some_and(|g| !self.game(g).is_paused());
+        match self.active_game() {
+            None => !pinned_running,
+            Some(game) => game.is_paused() && !pinned_running,
+        }
+    }
+
+    /// The pinned game, unless it's the one on screen anyway.
+    pub fn pinned_behind(&self) -> Option<usize> {
+        self.pinned.filter(|&p| self.current_tab.game_index() != Some(p))
+    }
+
+    pub fn pinned_game_mut(&mut self) -> Option<&mut dyn Game> {
+        let g = self.pinned_behind()?;
+        Some(self.game_mut(g))
+    }
+
+    /// F4: pin the game on screen, or unpin; on Home it just unpins.
+    fn toggle_pin(&mut self) {
+        self.pinned = match self.current_tab.game_index() {
+            Some(g) if self.pinned != Some(g) => Some(g),
+            _ => None,
+        };
+    }
+
+    /// F5: bring the pinned game full-screen and pin the one that was.
+    fn swap_pinned(&mut self) {
+        let Some(p) = self.pinned_behind() else { return };
+        self.pinned = self.current_tab.game_index();
+        self.current_tab = Tab::from_index(p + 1).unwrap_or(Tab::Home);
+    }
+
+    /// Speed a game runs at, in percent: its handicap, slowed for practice.
+    fn speed_of(&self, g: usize) -> u32 {
+        if self.practice[g] { self.game_speed[g].min(PRACTICE_SPEED) } else { self.game_speed[g] }
+    }
+
+    /// Speed handicap of the game on screen, in percent.
+    fn active_speed(&self) -> u32 {
+        self.current_tab.game_index().map_or(100, |g| self.speed_of(g))
+    }
+
+    fn update_active_game(&mut self) {
+        if let Some(g) = self.current_tab.game_index() {
+            self.last_played = Some(g);
+            if self.active_game().is_some_and(|game| !game.is_game_over()) {
+                self.speed_used[g] = self.speed_used[g].min(self.speed_of(g));
+                self.practiced[g] |= self.practice[g];
+            }
+            self.run_script(g);
+            audio::set_volume(self.game_volume[g]);
+        }
         match self.current_tab {
-            Tab::Home => {}
+            Tab::Home | Tab::Settings => {}
             Tab::Frogger => self.frogger.update(),
             Tab::Breakout => self.breakout.update(),
             Tab::DinoRun => self.dino_run.update(),
@@ -125,31 +998,106 @@ impl App {
             Tab::Asteroids => self.asteroids.update(),
             Tab::Booster => self.booster.update(),
             Tab::Beam => self.beam.update(),
+            Tab::BeamDump => self.beam_dump.update(),
+            Tab::Scope => self.scope.update(),
+            Tab::Router => self.router.update(),
+            Tab::Racer => self.racer.update(),
+            Tab::Delve => self.delve.update(),
+            Tab::Columns => self.columns.update(),
+            Tab::Whack => self.whack.update(),
+            Tab::Life => self.life.update(),
+            Tab::Pong => self.pong.update(),
+            Tab::Minesweeper => self.minesweeper.update(),
+            Tab::Plugins => self.plugins.update(),
+        }
+        // The pinned game keeps time with the one in front
+        if let Some(p) = self.pinned_behind() {
+            if !self.game(p).is_game_over() {
+                self.speed_used[p] = self.speed_used[p].min(self.active_speed());
+                self.practiced[p] |= self.practice[p];
+            }
+            audio::set_volume(self.game_volume[p]);
+            self.game_mut(p).update();
+            if let Some(g) = self.current_tab.game_index() {
+                audio::set_volume(self.game_volume[g]);
+            }
+        }
+    }
+
+    /// Let the game's script look at this step and press its keys.
+    fn run_script(&mut self, g: usize) {
+        let Some(game) = self.active_game() else { return };
+        let (score, over, paused, state) = (game.get_score(), game.is_game_over(), game.is_paused(), game.script_state());
+        let keys = self.scripts.tick(g, score, over, paused, state);
+        if !over && !keys.is_empty() {
+            self.automated[g] = true;
+        }
+        if let Some(game) = self.active_game_mut() {
+            for key in keys {
+                game.handle_input(key);
+            }
         }
-        // Check for high scores when games end
-        self.check_submit_scores();
     }
 
     fn check_submit_scores(&mut self) {
-        let games: [(usize, bool, u32); 8] = [
-            (0, self.frogger.is_game_over(), self.frogger.get_score()),
-            (1, self.breakout.is_game_over(), self.breakout.get_score()),
-            (2, self.dino_run.is_game_over(), self.dino_run.get_score()),
-            (3, self.space_invaders.is_game_over(), self.space_invaders.get_score()),
-            (4, self.jezzball.is_game_over(), self.jezzball.get_score()),
-            (5, self.asteroids.is_game_over(), self.asteroids.get_score()),
-            (6, self.booster.is_game_over(), self.booster.get_score()),
-            (7, self.beam.is_game_over(), self.beam.get_score()),
+        // (table, game over, score, detail stat, modifier flags)
+        let games: [(usize, bool, u32, u32, u32); 19] = [
+            (0, self.frogger.is_game_over(), self.frogger.get_score(), 0, 0),
+            (1, self.breakout.is_game_over(), self.breakout.get_score(), 0, 0),
+            (2, self.dino_run.is_game_over(), self.dino_run.get_score(), self.dino_run.distance(), self.dino_run.modifier_flags()),
+            (3, self.space_invaders.is_game_over(), self.space_invaders.get_score(), self.space_invaders.waves_cleared(), self.space_invaders.modifier_flags()),
+            (4, self.jezzball.is_game_over(), self.jezzball.get_score(), self.jezzball.levels_cleared(), 0),
+            (5, self.asteroids.is_game_over(), self.asteroids.get_score(), 0, 0),
+            (6, self.booster.is_game_over(), self.booster.get_score(), 0, 0),
+            (7, self.beam.is_game_over(), self.beam.get_score(), 0, 0),
+            (BEAM_TA, self.beam.time_attack_done(), self.beam.time_attack_score(), 0, 0),
+            (table_of(8), self.beam_dump.is_game_over(), self.beam_dump.get_score(), self.beam_dump.targets_cleared(), 0),
+            (table_of(9), self.scope.is_game_over(), self.scope.get_score(), self.scope.accuracy(), 0),
+            (table_of(10), self.router.is_game_over(), self.router.get_score(), self.router.packets_delivered(), 0),
+            (table_of(11), self.racer.is_game_over(), self.racer.get_score(), self.racer.checkpoints(), 0),
+            (table_of(12), self.delve.is_game_over(), self.delve.get_score(), self.delve.floor_reached(), self.delve.modifier_flags()),
+            (table_of(13), self.columns.is_game_over(), self.columns.get_score(), self.columns.gems_cleared(), 0),
+            (table_of(14), self.whack.is_game_over(), self.whack.get_score(), self.whack.median_reaction(), 0),
+            (table_of(15), self.life.is_game_over(), self.life.get_score(), self.life.challenges_cleared(), 0),
+            (table_of(16), self.pong.is_game_over(), self.pong.get_score(), self.pong.points_conceded(), self.pong.modifier_flags()),
+            (table_of(17), self.minesweeper.is_game_over(), self.minesweeper.get_score(), self.minesweeper.seconds(), self.minesweeper.modifier_flags()),
         ];
-        for (idx, game_over, score) in games {
+        for (idx, game_over, score, detail, flags) in games {
+            self.metrics.game_over(idx, game_over);
+            // Beam time attack shares Beam's speed setting
+            let game = game_of(idx);
             if game_over && score > 0 && !self.high_scores.was_submitted(idx) {
-                if self.high_scores.qualifies(idx, score) {
+                self.high_scores.roll_season();
+                // Bot, demo and practice runs don't go on the tables
+                let unscored = self.automated[game] || self.practiced[game] || self.game(game).is_demo();
+                let name = if idx == BEAM_TA { "Beam Time Attack" } else { self.game(game).info().name };
+                let demo = self.game(game).is_demo();
+                if let Some(kiosk) = self.kiosk.as_mut().filter(|_| !demo) {
+                    kiosk.game_over(name, score);
+                }
+                if !unscored {
+                    self.play_stats.finished(idx, score);
+                    let times = self.game(game).reaction_times().to_vec();
+                    self.play_stats.reacted(game, &times);
+                }
+                if self.high_scores.qualifies(idx, score) && !unscored {
+                    self.play_stats.table_entry();
                     // Prompt for name entry
                     self.entering_name = true;
-                    self.name_buffer.clear();
+                    // Kiosk visitors type their own
+                    self.name_buffer = if self.kiosk.is_none() { self.profile.name.clone() } else { String::new() };
                     self.name_game_idx = idx;
                     self.name_score = score;
+                    self.name_detail = detail;
+                    self.name_speed = self.speed_used[game];
+                    self.name_flags = flags;
+                    self.name_extra = match idx {
+                        2 => self.dino_run.extra_stats(),
+                        5 => self.asteroids.extra_stats(),
+                        _ => Vec::new(),
+                    };
                     self.high_scores.mark_submitted(idx);
+                    self.autosave();
                     return; // Only one at a time
                 } else {
                     // Score doesn't qualify, just mark as submitted
@@ -158,17 +1106,128 @@ impl App {
             }
             if !game_over && self.high_scores.was_submitted(idx) {
                 self.high_scores.clear_submitted(idx);
+                // A new game has started: flag it from its own speed on
+                self.speed_used[game] = self.speed_of(game);
+                self.automated[game] = false;
+                self.practiced[game] = self.practice[game];
             }
         }
     }
 
     pub fn on_key(&mut self, key: KeyEvent) {
+        let was_idle = self.is_idle();
+        self.handle_key(key);
+        if was_idle && !self.is_idle() {
+            // Resuming from a slow poll: don't simulate the time spent idle
+            self.last_tick = Instant::now();
+            self.sim_accumulator = Duration::ZERO;
+        }
+    }
+
+    /// Mouse input goes straight to the active game; overlays ignore it.
+    pub fn on_mouse(&mut self, mouse: MouseEvent) {
+        if self.show_help || self.entering_name || self.show_high_scores || self.show_maintenance
+            || self.show_keybindings || self.show_badges || self.show_settings || self.recovered_session.is_some()
+            || self.onboarding.is_some() || self.tour.is_some() {
+            return;
+        }
+        if self.current_tab == Tab::Home {
+            self.home_mouse(mouse);
+        } else if let Some(game) = self.active_game_mut() {
+            game.handle_mouse(mouse);
+        }
+    }
+
+    /// On Home the pointer picks a tile, a click launches it and the wheel
+    /// steps a row at a time.
+    fn home_mouse(&mut self, mouse: MouseEvent) {
+        let at = Position::new(mouse.column, mouse.row);
+        let tile = self.home_tiles.iter().find(|(area, _)| area.contains(at)).map(|&(_, g)| g);
+        match mouse.kind {
+            MouseEventKind::Moved => {
+                if let Some(g) = tile { self.selected_game = g; }
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                let Some(g) = tile else { return };
+                self.home_search = None;
+                self.selected_game = g;
+                self.current_tab = Tab::from_index(g + 1).unwrap_or(Tab::Home);
+            }
+            MouseEventKind::ScrollDown if self.selected_game + TILE_COLUMNS < GAME_COUNT => {
+                self.selected_game += TILE_COLUMNS;
+            }
+            MouseEventKind::ScrollUp if self.selected_game >= TILE_COLUMNS => {
+                self.selected_game -= TILE_COLUMNS;
+            }
+            _ => {}
+        }
+    }
+
+    /// Every game hears about a resize, not just the one on screen, so a
+    /// game switched to later is already the right size.
+    pub fn on_resize(&mut self, width: u16, height: u16) {
+        let area = game_area(width, height);
+        for g in 0..GAME_COUNT {
+            self.game_mut(g).on_resize(area);
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) {
+        if let Some(kiosk) = &mut self.kiosk {
+            kiosk.input();
+            // Raw mode already keeps these from signalling; don't act on them either
+            let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+            if ctrl && matches!(key.code, KeyCode::Char('c') | KeyCode::Char('z')) {
+                return;
+            }
+            // The key that wakes the demo just brings up Home
+            if matches!(self.current_tab, Tab::Breakout) && self.breakout.is_demo() {
+                self.breakout.reset();
+                self.current_tab = Tab::Home;
+                return;
+            }
+        }
+
         // Ctrl+C always quits
         if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
             self.should_quit = true;
             return;
         }
 
+        // Recovery prompt from a crashed session
+        if self.recovered_session.is_some() {
+            match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => self.recover_session(),
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                    self.recovered_session = None;
+                    Session::clear();
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        if let Some(onboarding) = &mut self.onboarding {
+            match onboarding.handle_key(key) {
+                OnboardingAction::None => {}
+                OnboardingAction::Preview => {
+                    self.theme = onboarding.theme;
+                    compat::set_profile(onboarding.charset.unwrap_or(self.config.render_profile));
+                }
+                OnboardingAction::Done { tour } => self.finish_onboarding(tour),
+            }
+            return;
+        }
+
+        if let Some(tour) = &mut self.tour {
+            if !tour.handle_key(key) {
+                self.end_tour();
+            } else if let Some(tour) = &self.tour {
+                (self.current_tab, self.show_help) = tour.view();
+            }
+            return;
+        }
+
         // If entering a name, intercept all input
         if self.entering_name {
             self.handle_name_input(key);
@@ -193,6 +1252,71 @@ impl App {
             return;
         }
 
+        // Score browser takes all input while open
+        if self.show_high_scores && matches!(self.current_tab, Tab::Home) {
+            // Kiosk visitors can't delete other people's scores
+            let delete = matches!(key.code, KeyCode::Char('x') | KeyCode::Char('X') | KeyCode::Delete);
+            if delete && self.kiosk.is_some() {
+                return;
+            }
+            if !self.score_browser.handle_key(key, &mut self.high_scores) {
+                self.show_high_scores = false;
+            }
+            return;
+        }
+
+        // Maintenance screen likewise
+        if self.show_maintenance && matches!(self.current_tab, Tab::Home | Tab::Settings) {
+            match self.maintenance.handle_key(key) {
+                MaintenanceAction::None => {}
+                MaintenanceAction::Close => self.show_maintenance = false,
+                MaintenanceAction::Apply(targets) => self.apply_resets(&targets),
+            }
+            return;
+        }
+
+        // Key binding editor captures every key, modifiers included
+        if self.show_keybindings && matches!(self.current_tab, Tab::Home) {
+            if !self.key_bindings.handle_key(key, &mut self.keymap) {
+                self.show_keybindings = false;
+            }
+            return;
+        }
+
+        if self.show_badges && matches!(self.current_tab, Tab::Home) {
+            if !self.badge_browser.handle_key(key) {
+                self.show_badges = false;
+            }
+            return;
+        }
+
+        if self.show_settings && matches!(self.current_tab, Tab::Home) {
+            match self.display_settings.handle_key(key) {
+                SettingsAction::None => {}
+                SettingsAction::Preview => {
+                    self.theme = self.display_settings.theme;
+                    compat::set_profile(self.display_settings.charset.unwrap_or(self.config.render_profile));
+                }
+                SettingsAction::Keep => self.close_settings(true),
+                SettingsAction::Cancel => self.close_settings(false),
+            }
+            return;
+        }
+
+        // A game's own text prompt gets the raw keys
+        if let Some(game) = self.active_game_mut() {
+            if game.wants_text_input() {
+                game.handle_input(key);
+                return;
+            }
+        }
+
+        // Home search takes the typing until Enter or Esc
+        if self.home_search.is_some() && matches!(self.current_tab, Tab::Home) {
+            self.handle_home_search(key);
+            return;
+        }
+
         if key.code == KeyCode::Char('?') {
             self.show_help = true;
             self.help_scroll = 0;
@@ -201,11 +1325,11 @@ impl App {
 
         // Global keys
         match key.code {
-            KeyCode::Char('q') | KeyCode::Char('Q') => {
-                if matches!(self.current_tab, Tab::Home) {
+            KeyCode::Char('q') | KeyCode::Char('Q') if matches!(self.current_tab, Tab::Home) => {
+                if self.kiosk.is_none() {
                     self.should_quit = true;
-                    return;
                 }
+                return;
             }
             KeyCode::Tab => {
                 if key.modifiers.contains(KeyModifiers::SHIFT) {
@@ -219,6 +1343,19 @@ impl App {
                 self.prev_tab();
                 return;
             }
+            KeyCode::F(3) => {
+                self.show_hitboxes = !self.show_hitboxes;
+                self.sync_hitboxes();
+                return;
+            }
+            KeyCode::F(4) => {
+                self.toggle_pin();
+                return;
+            }
+            KeyCode::F(5) => {
+                self.swap_pinned();
+                return;
+            }
             KeyCode::Esc => {
                 if !matches!(self.current_tab, Tab::Home) {
                     self.current_tab = Tab::Home;
@@ -231,65 +1368,110 @@ impl App {
         // Home screen shortcuts and navigation
         if matches!(self.current_tab, Tab::Home) && key.modifiers.is_empty() {
             match key.code {
-                KeyCode::Char('1') => { self.current_tab = Tab::Frogger; return; }
-                KeyCode::Char('2') => { self.current_tab = Tab::Breakout; return; }
-                KeyCode::Char('3') => { self.current_tab = Tab::DinoRun; return; }
-                KeyCode::Char('4') => { self.current_tab = Tab::SpaceInvaders; return; }
-                KeyCode::Char('5') => { self.current_tab = Tab::JezzBall; return; }
-                KeyCode::Char('6') => { self.current_tab = Tab::Asteroids; return; }
+                KeyCode::Char(c @ '1'..='9') => {
+                    self.current_tab = Tab::from_index(c as usize - '0' as usize).unwrap_or(Tab::Home);
+                    return;
+                }
                 KeyCode::Char('h') | KeyCode::Char('H') => {
-                    self.show_high_scores = !self.show_high_scores;
+                    self.show_high_scores = true;
+                    self.score_browser = ScoreBrowser::new(&self.high_scores);
+                    return;
+                }
+                // Resets and key rebinding write files; not for kiosk visitors
+                KeyCode::Char('m') | KeyCode::Char('M') if self.kiosk.is_none() => {
+                    self.show_maintenance = true;
+                    self.maintenance = MaintenanceMenu::new();
+                    return;
+                }
+                KeyCode::Char('k') | KeyCode::Char('K') if self.kiosk.is_none() => {
+                    self.show_keybindings = true;
+                    self.key_bindings = KeyBindings::new();
+                    return;
+                }
+                KeyCode::Char('t') | KeyCode::Char('T') if self.kiosk.is_none() => {
+                    self.show_settings = true;
+                    self.display_settings = DisplaySettings::new(&self.profile);
+                    return;
+                }
+                KeyCode::Char('a') | KeyCode::Char('A') => {
+                    self.show_badges = true;
+                    self.badge_browser = BadgeBrowser::new();
                     return;
                 }
-                KeyCode::Char('7') => { self.current_tab = Tab::Booster; return; }
-                KeyCode::Char('8') => { self.current_tab = Tab::Beam; return; }
-                // Arrow key navigation for game tile selection (2 rows: 4 + 4)
+                KeyCode::Char('s') | KeyCode::Char('S') => {
+                    let g = self.selected_game;
+                    let i = SPEED_STEPS.iter().position(|&s| s == self.game_speed[g]).map_or(0, |i| i + 1);
+                    self.game_speed[g] = SPEED_STEPS[i % SPEED_STEPS.len()];
+                    return;
+                }
+                KeyCode::Char('v') | KeyCode::Char('V') => {
+                    let g = self.selected_game;
+                    let i = VOLUME_STEPS.iter().position(|&v| v == self.game_volume[g]).map_or(0, |i| i + 1);
+                    self.game_volume[g] = VOLUME_STEPS[i % VOLUME_STEPS.len()];
+                    return;
+                }
+                KeyCode::Char('u') | KeyCode::Char('U') => {
+                    self.muted = !self.muted;
+                    audio::set_muted(self.muted);
+                    return;
+                }
+                KeyCode::Char('p') | KeyCode::Char('P') => {
+                    let g = self.selected_game;
+                    self.practice[g] = !self.practice[g];
+                    let on = self.practice[g];
+                    self.game_mut(g).set_practice(on);
+                    self.sync_hitboxes();
+                    return;
+                }
+                KeyCode::Char('/') => {
+                    self.home_search = Some(String::new());
+                    return;
+                }
+                KeyCode::Char('c') | KeyCode::Char('C') if self.snapshot.is_some() => {
+                    self.continue_snapshot();
+                    return;
+                }
+                // Arrow key navigation over the rows of game tiles
                 KeyCode::Right => {
-                    self.selected_game = (self.selected_game + 1) % 8;
+                    self.selected_game = (self.selected_game + 1) % GAME_COUNT;
                     return;
                 }
                 KeyCode::Left => {
-                    self.selected_game = (self.selected_game + 7) % 8;
+                    self.selected_game = (self.selected_game + GAME_COUNT - 1) % GAME_COUNT;
                     return;
                 }
                 KeyCode::Down => {
-                    if self.selected_game < 4 {
-                        self.selected_game = self.selected_game + 4;
-                    } else {
-                        self.selected_game -= 4;
-                    }
+                    let below = self.selected_game + TILE_COLUMNS;
+                    self.selected_game = if below < GAME_COUNT { below } else { self.selected_game % TILE_COLUMNS };
                     return;
                 }
                 KeyCode::Up => {
-                    if self.selected_game >= 4 {
-                        self.selected_game -= 4;
-                    } else {
-                        self.selected_game = self.selected_game + 4;
-                    }
+                    self.selected_game = match self.selected_game.checked_sub(TILE_COLUMNS) {
+                        Some(above) => above,
+                        // Wrap to the lowest tile in this column
+                        None => self.selected_game + (GAME_COUNT - 1 - self.selected_game) / TILE_COLUMNS * TILE_COLUMNS,
+                    };
                     return;
                 }
                 // Enter launches the selected game
                 KeyCode::Enter => {
-                    self.current_tab = match self.selected_game {
-                        0 => Tab::Frogger,
-                        1 => Tab::Breakout,
-                        2 => Tab::DinoRun,
-                        3 => Tab::SpaceInvaders,
-                        4 => Tab::JezzBall,
-                        5 => Tab::Asteroids,
-                        6 => Tab::Booster,
-                        7 => Tab::Beam,
-                        _ => Tab::Home,
-                    };
+                    self.current_tab = Tab::from_index(self.selected_game + 1).unwrap_or(Tab::Home);
                     return;
                 }
                 _ => {}
             }
         }
 
-        // Forward to active game
+        // The Settings tab reads its keys as typed, not through the bindings
+        if matches!(self.current_tab, Tab::Settings) {
+            self.handle_settings_key(key);
+            return;
+        }
+
+        // Forward to active game, through the player's key bindings
+        let key = self.keymap.translate(self.current_tab, key);
         match self.current_tab {
-            Tab::Home => {}
+            Tab::Home | Tab::Settings => {}
             Tab::Frogger => self.frogger.handle_input(key),
             Tab::Breakout => self.breakout.handle_input(key),
             Tab::DinoRun => self.dino_run.handle_input(key),
@@ -298,6 +1480,251 @@ impl App {
             Tab::Asteroids => self.asteroids.handle_input(key),
             Tab::Booster => self.booster.handle_input(key),
             Tab::Beam => self.beam.handle_input(key),
+            Tab::BeamDump => self.beam_dump.handle_input(key),
+            Tab::Scope => self.scope.handle_input(key),
+            Tab::Router => self.router.handle_input(key),
+            Tab::Racer => self.racer.handle_input(key),
+            Tab::Delve => self.delve.handle_input(key),
+            Tab::Columns => self.columns.handle_input(key),
+            Tab::Whack => self.whack.handle_input(key),
+            Tab::Life => self.life.handle_input(key),
+            Tab::Pong => self.pong.handle_input(key),
+            Tab::Minesweeper => self.minesweeper.handle_input(key),
+            Tab::Plugins => self.plugins.handle_input(key),
+        }
+    }
+
+    /// Answer whatever arrived on the control socket.
+    fn serve_control(&mut self) {
+        let Some(control) = &self.control else { return };
+        for request in control.poll() {
+            let result = self.handle_rpc(&request);
+            request.respond(result);
+        }
+    }
+
+    fn handle_rpc(&mut self, request: &Request) -> Result<Json, RpcError> {
+        let params = &request.params;
+        let game = self.current_tab.game_index();
+        match request.method.as_str() {
+            "status" => {
+                let tab = game.map_or("home", |g| GAME_KEYS[g]);
+                let mut status = vec![("tab", Json::Str(tab.to_string()))];
+                if let (Some(g), Some(active)) = (game, self.active_game()) {
+                    status.extend([
+                        ("score", Json::Num(active.get_score() as f64)),
+                        ("game_over", Json::Bool(active.is_game_over())),
+                        ("paused", Json::Bool(active.is_paused())),
+                        ("speed", Json::Num(self.speed_of(g) as f64 / 100.0)),
+                        ("practice", Json::Bool(self.practice[g])),
+                    ]);
+                }
+                if let Some(p) = self.pinned {
+                    status.push(("pinned", Json::Str(GAME_KEYS[p].to_string())));
+                }
+                Ok(Json::object(status))
+            }
+            "state" => {
+                let active = self.active_game().ok_or_else(|| RpcError::params("no game on screen"))?;
+                Ok(Json::object(active.script_state().into_iter().map(|(k, v)| (k, Json::Num(v)))))
+            }
+            "switch" => {
+                let name = params.get("tab").and_then(Json::as_str).ok_or_else(|| RpcError::params("expected {\"tab\": name}"))?;
+                let index = match name {
+                    "home" => 0,
+                    "settings" => Tab::Settings.index(),
+                    _ => GAME_KEYS.iter().position(|&k| k == name).map(|g| g + 1).ok_or_else(|| RpcError::params(format!("unknown tab '{}'", name)))?,
+                };
+                self.current_tab = Tab::from_index(index).unwrap_or(Tab::Home);
+                Ok(Json::Null)
+            }
+            "press" => {
+                let keys = match (params.get("key"), params.get("keys")) {
+                    (Some(Json::Str(k)), _) => vec![k.clone()],
+                    (_, Some(Json::Array(ks))) => ks.iter().filter_map(|k| k.as_str().map(String::from)).collect(),
+                    _ => return Err(RpcError::params("expected {\"key\": name} or {\"keys\": [...]}")),
+                };
+                let binds = keys
+                    .iter()
+                    .map(|k| KeyBind::parse(k).ok_or_else(|| RpcError::params(format!("unknown key '{}'", k))))
+                    .collect::<Result<Vec<_>, _>>()?;
+                self.mark_automated();
+                for bind in binds {
+                    self.handle_key(KeyEvent::new(bind.code, bind.modifiers));
+                }
+                Ok(Json::Null)
+            }
+            "command" => {
+                let line = params.get("line").and_then(Json::as_str).ok_or_else(|| RpcError::params("expected {\"line\": text}"))?;
+                if !matches!(self.current_tab, Tab::Booster) {
+                    return Err(RpcError::params("commands need the Booster on screen"));
+                }
+                self.mark_automated();
+                let output = self.booster.execute(line);
+                Ok(Json::Array(output.into_iter().map(Json::Str).collect()))
+            }
+            "step" => {
+                let steps = params.get("steps").and_then(Json::as_f64).unwrap_or(1.0);
+                if !(1.0..=MAX_RPC_STEPS as f64).contains(&steps) {
+                    return Err(RpcError::params(format!("steps must be 1-{}", MAX_RPC_STEPS)));
+                }
+                if game.is_none() {
+                    return Err(RpcError::params("no game on screen"));
+                }
+                self.mark_automated();
+                for _ in 0..steps as u32 {
+                    self.update_active_game();
+                }
+                self.check_submit_scores();
+                Ok(Json::Null)
+            }
+            "reset" => {
+                let active = self.active_game_mut().ok_or_else(|| RpcError::params("no game on screen"))?;
+                active.reset();
+                Ok(Json::Null)
+            }
+            _ => Err(RpcError::new(RpcError::METHOD_NOT_FOUND, format!("unknown method '{}'", request.method))),
+        }
+    }
+
+    fn mark_automated(&mut self) {
+        if let Some(g) = self.current_tab.game_index() {
+            if self.active_game().is_some_and(|game| !game.is_game_over()) {
+                self.automated[g] = true;
+            }
+        }
+    }
+
+    /// Wipe what the maintenance screen asked for and bring the running
+    /// games back in line with it.
+    fn apply_resets(&mut self, targets: &[ResetTarget]) {
+        for target in targets {
+            match target {
+                ResetTarget::Scores => {
+                    self.high_scores.reset();
+                    self.space_invaders.set_best(0);
+                }
+                ResetTarget::Progress => {
+                    clear_all_progress();
+                    self.snapshot = None;
+                    self.breakout.reload_campaign();
+                    self.booster.reload_cycles();
+                    self.jezzball.reload_stats();
+                    self.delve.reload_stats();
+                    self.frogger.reload_splits();
+                    self.play_stats = PlayStats::load();
+                    self.achievements = Achievements::load();
+                }
+                ResetTarget::Leaderboard => self.leaderboard.clear_queue(),
+                ResetTarget::Config => {
+                    self.config = Config::reset();
+                    self.apply_settings();
+                    self.game_speed = self.config.game_speed;
+                    self.game_volume = self.config.game_volume;
+                    self.muted = self.config.sound_muted;
+                    audio::set_muted(self.muted);
+                    self.leaderboard = Leaderboard::new(&self.config);
+                }
+            }
+        }
+    }
+
+    /// Push the settings that take effect immediately out to the games
+    /// and effects. The control socket and metrics listener only change
+    /// on the next launch.
+    fn apply_settings(&mut self) {
+        self.high_scores.set_seasons(self.config.seasons.clone());
+        self.asteroids.set_reduced_motion(self.config.reduced_motion);
+        self.asteroids.set_extra_life(self.config.asteroids_extra_life);
+        self.breakout.set_guide(self.config.ball_guide);
+        self.space_invaders.set_reduced_motion(self.config.reduced_motion);
+        self.frogger.set_skin(self.config.frogger_skin, self.config.seasonal_skins);
+        fx::set_flash_limit(self.config.max_flash_hz);
+        fx::set_alert_color(self.config.alert_color);
+        compat::set_profile(self.profile.render_profile(&self.config));
+        audio::set_enabled(&self.config.sound_cues);
+        audio::set_master_volume(self.config.sound_volume);
+        self.keymap.set_config_binds(&self.config.key_binds);
+    }
+
+    /// Apply edits to `config.toml` or the key bindings file. A config
+    /// that doesn't parse is reported and the running settings are kept.
+    fn watch_config(&mut self) {
+        let Some(watcher) = self.config_watcher.as_mut() else { return };
+        for path in watcher.changed() {
+            if path == self.keymap.path() {
+                if self.keymap.reload() {
+                    self.toast = Some(Toast::info("Key bindings reloaded"));
+                }
+                continue;
+            }
+            if self.config_written.is_some() && fs::read_to_string(&path).ok() == self.config_written {
+                continue;
+            }
+            match Config::reload() {
+                Ok(config) => {
+                    // Only what the file changed, so S and V on Home aren't undone
+                    for g in 0..self.game_speed.len() {
+                        if config.game_speed[g] != self.config.game_speed[g] {
+                            self.game_speed[g] = config.game_speed[g];
+                        }
+                        if config.game_volume[g] != self.config.game_volume[g] {
+                            self.game_volume[g] = config.game_volume[g];
+                        }
+                    }
+                    if config.sound_muted != self.config.sound_muted {
+                        self.muted = config.sound_muted;
+                        audio::set_muted(self.muted);
+                    }
+                    if config.beam_hard != self.config.beam_hard {
+                        self.beam.set_default_difficulty(config.beam_hard);
+                    }
+                    if let Some(theme) = config.theme.filter(|_| config.theme != self.config.theme) {
+                        self.config.theme = Some(theme);
+                        self.set_theme(theme);
+                    }
+                    let leaderboard_changed = config.leaderboard_url != self.config.leaderboard_url
+                        || config.leaderboard_secret != self.config.leaderboard_secret;
+                    self.config = config;
+                    self.apply_settings();
+                    if leaderboard_changed {
+                        self.leaderboard = Leaderboard::new(&self.config);
+                    }
+                    self.toast = Some(Toast::info("config.toml reloaded"));
+                }
+                Err(error) => self.toast = Some(Toast::error(format!("config.toml: {}", error))),
+            }
+        }
+    }
+
+    /// Keys while searching Home: typing narrows the tiles, arrows step
+    /// through the matches and Enter plays the selected one.
+    fn handle_home_search(&mut self, key: KeyEvent) {
+        let games = self.game_infos();
+        let Some(query) = self.home_search.as_mut() else { return };
+        let matching: Vec<usize> = (0..games.len()).filter(|&g| games[g].matches(query)).collect();
+        match key.code {
+            KeyCode::Esc => self.home_search = None,
+            KeyCode::Enter if matching.contains(&self.selected_game) => {
+                self.home_search = None;
+                self.current_tab = Tab::from_index(self.selected_game + 1).unwrap_or(Tab::Home);
+            }
+            // Pops a character; with none left it closes the search
+            KeyCode::Backspace if query.pop().is_none() => self.home_search = None,
+            KeyCode::Right | KeyCode::Down | KeyCode::Left | KeyCode::Up if !matching.is_empty() => {
+                let at = matching.iter().position(|&g| g == self.selected_game).unwrap_or(0);
+                let step = if matches!(key.code, KeyCode::Right | KeyCode::Down) { 1 } else { matching.len() - 1 };
+                self.selected_game = matching[(at + step) % matching.len()];
+            }
+            KeyCode::Char(c) if !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {
+                query.push(c);
+                if !games[self.selected_game].matches(query) {
+                    if let Some(first) = games.iter().position(|info| info.matches(query)) {
+                        self.selected_game = first;
+                    }
+                }
+            }
+            _ => {}
         }
     }
 
@@ -310,9 +1737,24 @@ impl App {
                 } else {
                     self.name_buffer.clone()
                 };
-                self.high_scores.submit(self.name_game_idx, &name, self.name_score);
+                self.high_scores.submit(HistoryEntry {
+                    game: self.name_game_idx,
+                    name: name.clone(),
+                    score: self.name_score,
+                    timestamp: 0,
+                    detail: self.name_detail,
+                    speed: self.name_speed,
+                    flags: self.name_flags,
+                    extra: std::mem::take(&mut self.name_extra),
+                });
+                self.leaderboard.submit(self.name_game_idx, &name, self.name_score, self.name_speed, self.name_flags);
+                self.metrics.score_submitted();
+                if self.name_game_idx == 3 {
+                    self.space_invaders.set_best(self.high_scores.top_scores(3)[0].score);
+                }
                 self.entering_name = false;
                 self.name_buffer.clear();
+                self.autosave();
             }
             KeyCode::Backspace => {
                 self.name_buffer.pop();
@@ -321,6 +1763,7 @@ impl App {
                 // Cancel — discard the score entirely
                 self.entering_name = false;
                 self.name_buffer.clear();
+                self.autosave();
             }
             KeyCode::Char(c) => {
                 // Only allow printable ASCII characters, up to MAX_NAME_LEN
@@ -332,15 +1775,29 @@ impl App {
         }
     }
 
+    /// Tabs on the bar: all of them, less Plugins when none are installed
+    /// and Settings for kiosk visitors.
+    pub fn tabs(&self) -> Vec<Tab> {
+        Tab::all()
+            .iter()
+            .copied()
+            .filter(|tab| match tab {
+                Tab::Plugins => !self.plugins.is_empty(),
+                Tab::Settings => self.kiosk.is_none(),
+                _ => true,
+            })
+            .collect()
+    }
+
     fn next_tab(&mut self) {
-        let tabs = Tab::all();
-        let idx = self.current_tab.index();
+        let tabs = self.tabs();
+        let idx = tabs.iter().position(|t| *t == self.current_tab).unwrap_or(0);
         self.current_tab = tabs[(idx + 1) % tabs.len()];
     }
 
     fn prev_tab(&mut self) {
-        let tabs = Tab::all();
-        let idx = self.current_tab.index();
+        let tabs = self.tabs();
+        let idx = tabs.iter().position(|t| *t == self.current_tab).unwrap_or(0);
         self.current_tab = tabs[(idx + tabs.len() - 1) % tabs.len()];
     }
 }
@@ -0,0 +1,341 @@
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Something a game wants heard (and felt, on a controller). Games call
+/// `emit` as it happens; the frontend plays whatever is due after each frame.
+#[derive(Clone, Copy, PartialEq)]
+pub enum AudioEvent {
+    // Booster operator alarms
+    /// A fresh beam is in the ring
+    Injection,
+    /// γ is closing on transition
+    TransitionWarning,
+    TransitionCrossed,
+    /// Losses passed the alarm level
+    LossAlarm,
+    Extraction,
+
+    // Dino Run
+    DinoJump,
+    /// The run ended on an obstacle
+    DinoCrash,
+
+    // Arcade games
+    /// The ball came off a paddle
+    Bounce,
+    /// Something was shot down, or the player was
+    Explosion,
+    /// A level was cleared
+    LevelUp,
+    /// An extra ship was earned
+    ExtraLife,
+}
+
+/// A controller shake: `strength` from 0 to 1, for `ms`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Rumble {
+    pub strength: f32,
+    pub ms: u64,
+}
+
+impl AudioEvent {
+    pub const ALL: [AudioEvent; 11] = [
+        AudioEvent::Injection,
+        AudioEvent::TransitionWarning,
+        AudioEvent::TransitionCrossed,
+        AudioEvent::LossAlarm,
+        AudioEvent::Extraction,
+        AudioEvent::DinoJump,
+        AudioEvent::DinoCrash,
+        AudioEvent::Bounce,
+        AudioEvent::Explosion,
+        AudioEvent::LevelUp,
+        AudioEvent::ExtraLife,
+    ];
+
+    /// Key under `[sound]` in config.toml.
+    pub fn key(self) -> &'static str {
+        match self {
+            AudioEvent::Injection => "booster_injection",
+            AudioEvent::TransitionWarning => "booster_transition_warning",
+            AudioEvent::TransitionCrossed => "booster_transition",
+            AudioEvent::LossAlarm => "booster_losses",
+            AudioEvent::Extraction => "booster_extraction",
+            AudioEvent::DinoJump => "dino_jump",
+            AudioEvent::DinoCrash => "dino_crash",
+            AudioEvent::Bounce => "bounce",
+            AudioEvent::Explosion => "explosion",
+            AudioEvent::LevelUp => "level_up",
+            AudioEvent::ExtraLife => "extra_life",
+        }
+    }
+
+    /// Whether the cue plays before the config says. A bell on every jump
+    /// wears thin, so that one is opt-in.
+    pub fn default_on(self) -> bool {
+        self != AudioEvent::DinoJump
+    }
+
+    /// Bell strikes, in ms from the event. A terminal bell has one pitch,
+    /// so the cues differ in count and rhythm; the busiest arcade sounds
+    /// only play through the synthesizer.
+    fn pattern(self) -> &'static [u64] {
+        match self {
+            AudioEvent::Injection => &[0],
+            AudioEvent::TransitionWarning => &[0, 600],
+            AudioEvent::TransitionCrossed => &[0, 150, 300],
+            AudioEvent::LossAlarm => &[0, 100, 200, 300, 400],
+            AudioEvent::Extraction => &[0, 300, 900],
+            AudioEvent::DinoJump => &[0],
+            AudioEvent::DinoCrash => &[0, 120],
+            AudioEvent::Bounce | AudioEvent::Explosion => &[],
+            AudioEvent::LevelUp => &[0, 150],
+            AudioEvent::ExtraLife => &[0, 100, 200, 450],
+        }
+    }
+
+    /// How a controller should shake for it, if at all.
+    fn rumble(self) -> Option<Rumble> {
+        match self {
+            AudioEvent::DinoJump => Some(Rumble { strength: 0.2, ms: 60 }),
+            AudioEvent::DinoCrash => Some(Rumble { strength: 1.0, ms: 400 }),
+            _ => None,
+        }
+    }
+}
+
+/// Bit per `AudioEvent::ALL` entry; all on until the config says otherwise.
+static ENABLED: AtomicU32 = AtomicU32::new(u32::MAX);
+/// Bell strikes waiting for their time
+static QUEUE: Mutex<Vec<Instant>> = Mutex::new(Vec::new());
+/// The strongest shake since the frontend last took one
+static RUMBLE: Mutex<Option<Rumble>> = Mutex::new(None);
+/// Loudness of the game being played, in percent
+static VOLUME: AtomicU32 = AtomicU32::new(100);
+/// Settings tab's master volume, scaling every game's, in percent
+static MASTER: AtomicU32 = AtomicU32::new(100);
+/// Home's mute: silences every cue at once
+static MUTED: AtomicBool = AtomicBool::new(false);
+
+/// Turn cues on or off, in `AudioEvent::ALL` order.
+pub fn set_enabled(enabled: &[bool; AudioEvent::ALL.len()]) {
+    let bits = enabled.iter().enumerate().fold(0, |bits, (i, &on)| if on { bits | 1 << i } else { bits });
+    ENABLED.store(bits, Ordering::Relaxed);
+}
+
+/// Volume for cues from here on, in percent; the app sets it to the
+/// volume of whichever game is about to run.
+pub fn set_volume(percent: u32) {
+    VOLUME.store(percent.min(100), Ordering::Relaxed);
+}
+
+pub fn set_master_volume(percent: u32) {
+    MASTER.store(percent.min(100), Ordering::Relaxed);
+}
+
+pub fn set_muted(muted: bool) {
+    MUTED.store(muted, Ordering::Relaxed);
+}
+
+pub fn emit(event: AudioEvent) {
+    // Muting a cue's bell doesn't stop the controller feeling it
+    if let (Some(rumble), Ok(mut pending)) = (event.rumble(), RUMBLE.lock()) {
+        if pending.is_none_or(|p| rumble.strength >= p.strength) {
+            *pending = Some(rumble);
+        }
+    }
+    if MUTED.load(Ordering::Relaxed) { return; }
+    let Some(i) = AudioEvent::ALL.iter().position(|&e| e == event) else { return };
+    if ENABLED.load(Ordering::Relaxed) & 1 << i == 0 { return; }
+    let volume = VOLUME.load(Ordering::Relaxed) * MASTER.load(Ordering::Relaxed) / 100;
+    if volume == 0 { return; }
+    // The bell is the fallback when there's no sound device to play on
+    if synth::play(event, volume as f32 / 100.0) { return; }
+    let now = Instant::now();
+    if let Ok(mut queue) = QUEUE.lock() {
+        queue.extend(event.pattern().iter().map(|&ms| now + Duration::from_millis(ms)));
+    }
+}
+
+/// Ring the terminal bell for every strike that's due. Several due at
+/// once (after a slow frame) ring once.
+pub fn play_due(out: &mut impl Write) {
+    let Ok(mut queue) = QUEUE.lock() else { return };
+    let now = Instant::now();
+    let before = queue.len();
+    queue.retain(|&at| at > now);
+    if queue.len() < before {
+        let _ = out.write_all(b"\x07");
+        let _ = out.flush();
+    }
+}
+
+/// The shake waiting for a controller, if any; taking it clears it.
+pub fn take_rumble() -> Option<Rumble> {
+    RUMBLE.lock().ok().and_then(|mut pending| pending.take())
+}
+
+/// Whether strikes are still waiting, so the loop keeps polling quickly.
+pub fn pending() -> bool {
+    QUEUE.lock().is_ok_and(|queue| !queue.is_empty())
+}
+
+/// Sound effects through the system audio device, rendered on the fly.
+#[cfg(feature = "sound")]
+mod synth {
+    use std::f32::consts::TAU;
+    use std::sync::mpsc::{self, Sender};
+    use std::sync::OnceLock;
+
+    use super::AudioEvent;
+
+    const RATE: u32 = 44_100;
+
+    /// The audio thread's inbox; `None` once opening the device failed
+    static OUTPUT: OnceLock<Option<Sender<(AudioEvent, f32)>>> = OnceLock::new();
+
+    #[derive(Clone, Copy)]
+    enum Wave {
+        Sine,
+        Square,
+        Noise,
+    }
+
+    /// One note of a cue: a tone sliding from `from` to `to` Hz, starting
+    /// `at` seconds in and dying away over `secs`.
+    struct Voice {
+        wave: Wave,
+        from: f32,
+        to: f32,
+        at: f32,
+        secs: f32,
+        gain: f32,
+    }
+
+    const fn voice(wave: Wave, from: f32, to: f32, at: f32, secs: f32, gain: f32) -> Voice {
+        Voice { wave, from, to, at, secs, gain }
+    }
+
+    fn voices(event: AudioEvent) -> Vec<Voice> {
+        use Wave::*;
+        match event {
+            AudioEvent::Injection => vec![voice(Sine, 880.0, 880.0, 0.0, 0.25, 0.5)],
+            AudioEvent::TransitionWarning => vec![
+                voice(Square, 440.0, 440.0, 0.0, 0.3, 0.3),
+                voice(Square, 440.0, 440.0, 0.6, 0.3, 0.3),
+            ],
+            AudioEvent::TransitionCrossed => vec![
+                voice(Sine, 660.0, 990.0, 0.0, 0.12, 0.5),
+                voice(Sine, 660.0, 990.0, 0.15, 0.12, 0.5),
+                voice(Sine, 660.0, 990.0, 0.3, 0.12, 0.5),
+            ],
+            AudioEvent::LossAlarm => vec![
+                voice(Square, 1200.0, 1200.0, 0.0, 0.08, 0.3),
+                voice(Square, 900.0, 900.0, 0.1, 0.08, 0.3),
+                voice(Square, 1200.0, 1200.0, 0.2, 0.08, 0.3),
+                voice(Square, 900.0, 900.0, 0.3, 0.08, 0.3),
+                voice(Square, 1200.0, 1200.0, 0.4, 0.08, 0.3),
+            ],
+            AudioEvent::Extraction => vec![
+                voice(Sine, 523.0, 523.0, 0.0, 0.3, 0.5),
+                voice(Sine, 659.0, 659.0, 0.3, 0.3, 0.5),
+                voice(Sine, 784.0, 784.0, 0.9, 0.5, 0.5),
+            ],
+            AudioEvent::DinoJump => vec![voice(Square, 300.0, 600.0, 0.0, 0.08, 0.25)],
+            AudioEvent::DinoCrash => vec![
+                voice(Noise, 0.0, 0.0, 0.0, 0.3, 0.4),
+                voice(Square, 200.0, 80.0, 0.0, 0.35, 0.3),
+            ],
+            AudioEvent::Bounce => vec![voice(Square, 440.0, 440.0, 0.0, 0.04, 0.25)],
+            AudioEvent::Explosion => vec![
+                voice(Noise, 0.0, 0.0, 0.0, 0.5, 0.5),
+                voice(Sine, 90.0, 40.0, 0.0, 0.4, 0.5),
+            ],
+            AudioEvent::LevelUp => vec![
+                voice(Square, 523.0, 523.0, 0.0, 0.1, 0.25),
+                voice(Square, 659.0, 659.0, 0.08, 0.1, 0.25),
+                voice(Square, 784.0, 784.0, 0.16, 0.1, 0.25),
+                voice(Square, 1047.0, 1047.0, 0.24, 0.25, 0.25),
+            ],
+            AudioEvent::ExtraLife => vec![
+                voice(Square, 784.0, 784.0, 0.0, 0.09, 0.25),
+                voice(Square, 784.0, 784.0, 0.1, 0.09, 0.25),
+                voice(Square, 784.0, 784.0, 0.2, 0.09, 0.25),
+                voice(Square, 1047.0, 1047.0, 0.3, 0.45, 0.3),
+                voice(Sine, 523.0, 523.0, 0.3, 0.45, 0.3),
+            ],
+        }
+    }
+
+    /// Mono samples for a cue at full volume.
+    fn render(event: AudioEvent) -> Vec<f32> {
+        let rate = RATE as f32;
+        let voices = voices(event);
+        let len = voices.iter().map(|v| v.at + v.secs).fold(0.0, f32::max);
+        let mut out = vec![0.0; (len * rate) as usize + 1];
+        let mut noise = 0x2545_f491_u32;
+        for v in &voices {
+            let first = (v.at * rate) as usize;
+            let n = (v.secs * rate) as usize;
+            let end = (first + n).min(out.len());
+            let mut phase = 0.0_f32;
+            for (i, sample) in out[first..end].iter_mut().enumerate() {
+                let t = i as f32 / n as f32;
+                phase = (phase + (v.from + (v.to - v.from) * t) / rate).fract();
+                let x = match v.wave {
+                    Wave::Sine => (phase * TAU).sin(),
+                    Wave::Square => if phase < 0.5 { 0.6 } else { -0.6 },
+                    Wave::Noise => {
+                        noise ^= noise << 13;
+                        noise ^= noise >> 17;
+                        noise ^= noise << 5;
+                        noise as f32 / u32::MAX as f32 * 2.0 - 1.0
+                    }
+                };
+                // 5 ms attack so notes don't click, then an exponential tail
+                let attack = (i as f32 / (0.005 * rate)).min(1.0);
+                *sample += x * v.gain * attack * (-4.0 * t).exp();
+            }
+        }
+        out
+    }
+
+    /// Open the device on a thread of its own (the stream has to stay on
+    /// the thread that made it) and hand back its inbox.
+    fn start() -> Option<Sender<(AudioEvent, f32)>> {
+        let (tx, rx) = mpsc::channel::<(AudioEvent, f32)>();
+        let (ready_tx, ready_rx) = mpsc::channel();
+        std::thread::Builder::new()
+            .name("audio".into())
+            .spawn(move || {
+                let Ok((_stream, handle)) = rodio::OutputStream::try_default() else {
+                    let _ = ready_tx.send(false);
+                    return;
+                };
+                let _ = ready_tx.send(true);
+                for (event, volume) in rx {
+                    let samples: Vec<f32> = render(event).into_iter().map(|s| s * volume).collect();
+                    let _ = handle.play_raw(rodio::buffer::SamplesBuffer::new(1, RATE, samples));
+                }
+            })
+            .ok()?;
+        ready_rx.recv().unwrap_or(false).then_some(tx)
+    }
+
+    /// Play `event` at `volume` (0-1); false when there's no device.
+    pub fn play(event: AudioEvent, volume: f32) -> bool {
+        OUTPUT.get_or_init(start).as_ref().is_some_and(|tx| tx.send((event, volume)).is_ok())
+    }
+}
+
+#[cfg(not(feature = "sound"))]
+mod synth {
+    use super::AudioEvent;
+
+    /// Built without the `sound` feature: always the bell.
+    pub fn play(_event: AudioEvent, _volume: f32) -> bool {
+        false
+    }
+}
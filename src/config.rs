@@ -0,0 +1,475 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::audio::AudioEvent;
+use crate::games::asteroids::DEFAULT_EXTRA_LIFE;
+use crate::games::{frogger_skins, GAME_COUNT};
+use crate::keymap::{is_reserved, KeyBind, ACTIONS};
+use crate::seasons::SeasonPeriod;
+use crate::ui::compat::RenderProfile;
+use crate::ui::fx::{Rgb, DEFAULT_ALERT_COLOR, DEFAULT_MAX_FLASH_HZ};
+use crate::ui::theme::Theme;
+
+/// Poll interval while a game is animating (~120 FPS render).
+pub const DEFAULT_FRAME_MS: u64 = 8;
+/// Fastest and slowest `frame_ms` the config may ask for.
+pub const MIN_FRAME_MS: u64 = 4;
+pub const MAX_FRAME_MS: u64 = 50;
+/// Poll interval while nothing on screen is moving (menus, pause screens).
+pub const DEFAULT_IDLE_POLL_MS: u64 = 200;
+/// Quiet time after the last file event before a change is acted on, so
+/// an editor's truncate-then-write save is read once, complete.
+const WATCH_SETTLE: Duration = Duration::from_millis(150);
+/// Slowest and fastest per-game speed handicap, in percent.
+pub const MIN_GAME_SPEED: u32 = 50;
+pub const MAX_GAME_SPEED: u32 = 200;
+/// Seconds a kiosk visit may sit untouched before the attract demo returns.
+pub const DEFAULT_KIOSK_IDLE_SECS: u64 = 120;
+/// Per-game keys for `[handicap]` and script file names, in Home tile order
+pub const GAME_KEYS: [&str; GAME_COUNT] = [
+    "frogger", "breakout", "dino_run", "invaders", "jezzball", "asteroids", "booster", "beam", "beam_dump", "scope", "router", "racer", "delve", "columns", "whack", "life", "pong", "minesweeper",
+];
+
+/// User settings read from `config.toml`. Missing or malformed values
+/// fall back to the defaults, so a broken file never stops the arcade.
+#[derive(Clone)]
+pub struct Config {
+    /// Drop to `idle_poll_ms` when paused or on a menu screen
+    pub adaptive_polling: bool,
+    /// Render and input poll interval while a game is animating
+    pub frame_ms: u64,
+    pub idle_poll_ms: u64,
+    /// Online leaderboard endpoint (plain `http://`); None keeps scores local
+    pub leaderboard_url: Option<String>,
+    /// Shared key used to sign submitted scores
+    pub leaderboard_secret: String,
+    /// Leaderboard season length; old seasons are archived, not deleted
+    pub seasons: SeasonPeriod,
+    /// Accessibility: skip screen shake and similar camera motion
+    pub reduced_motion: bool,
+    /// Accessibility: Breakout shows where the ball is heading
+    pub ball_guide: bool,
+    /// Accessibility: most blinks or screen flashes per second (0 = none)
+    pub max_flash_hz: u32,
+    /// Colour of game-over banners and flashing alerts
+    pub alert_color: Rgb,
+    /// Glyph and colour fallbacks for the terminal; detected by default
+    pub render_profile: RenderProfile,
+    /// Colour theme; None keeps the one picked in first-run setup
+    pub theme: Option<Theme>,
+    /// Which sound cues play, in `AudioEvent::ALL` order
+    pub sound_cues: [bool; AudioEvent::ALL.len()],
+    /// Start with every cue silenced (U on the Home tab)
+    pub sound_muted: bool,
+    /// Master volume over every game's own, in percent
+    pub sound_volume: u32,
+    /// Per-game sound volume (Home tile order), in percent
+    pub game_volume: [u32; GAME_COUNT],
+    /// Asteroids: points between extra ships (0 = none)
+    pub asteroids_extra_life: u32,
+    /// Starting speed handicap per game (Home tile order), in percent
+    pub game_speed: [u32; GAME_COUNT],
+    /// Unix socket for the JSON-RPC control API; None keeps it off
+    pub control_socket: Option<PathBuf>,
+    /// `host:port` to serve Prometheus metrics on; None keeps it off
+    pub metrics_listen: Option<String>,
+    /// `--kiosk` only: idle seconds before the attract demo takes over
+    pub kiosk_idle_secs: u64,
+    /// `--kiosk` only: session log; None puts it next to the binary
+    pub kiosk_log: Option<PathBuf>,
+    /// Print time played and scores to the terminal on quit
+    pub session_summary: bool,
+    /// Frogger's skin, as an index into `frogger_skins::SKINS`
+    pub frogger_skin: usize,
+    /// Let a seasonal Frogger skin stand in around its dates
+    pub seasonal_skins: bool,
+    /// `[keys.<game>]` bindings by action id; ones made with K on Home win
+    pub key_binds: Vec<(&'static str, KeyBind)>,
+    /// Beam starts a run on Hard rather than Easy
+    pub beam_hard: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            adaptive_polling: true,
+            frame_ms: DEFAULT_FRAME_MS,
+            idle_poll_ms: DEFAULT_IDLE_POLL_MS,
+            leaderboard_url: None,
+            leaderboard_secret: String::new(),
+            seasons: SeasonPeriod::None,
+            reduced_motion: false,
+            ball_guide: false,
+            max_flash_hz: DEFAULT_MAX_FLASH_HZ,
+            alert_color: DEFAULT_ALERT_COLOR,
+            render_profile: RenderProfile::detect(),
+            theme: None,
+            sound_cues: AudioEvent::ALL.map(AudioEvent::default_on),
+            sound_muted: false,
+            sound_volume: 100,
+            game_volume: [100; GAME_COUNT],
+            game_speed: [100; GAME_COUNT],
+            control_socket: None,
+            metrics_listen: None,
+            kiosk_idle_secs: DEFAULT_KIOSK_IDLE_SECS,
+            kiosk_log: None,
+            session_summary: true,
+            frogger_skin: 0,
+            seasonal_skins: true,
+            asteroids_extra_life: DEFAULT_EXTRA_LIFE,
+            key_binds: Vec::new(),
+            beam_hard: false,
+        }
+    }
+}
+
+impl Config {
+    pub fn load() -> Self {
+        let Some(text) = Self::config_path().and_then(|path| fs::read_to_string(path).ok()) else {
+            return Config::default();
+        };
+        Self::parse(&text, &mut Vec::new())
+    }
+
+    /// Re-read the file after it changed on disk. Unlike `load`, a broken
+    /// line or value is an error, so the caller can keep what it has.
+    pub fn reload() -> Result<Self, String> {
+        let Some(path) = Self::config_path() else { return Ok(Config::default()) };
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(e) => return Err(e.to_string()),
+        };
+        let mut errors = Vec::new();
+        let cfg = Self::parse(&text, &mut errors);
+        match errors.into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(cfg),
+        }
+    }
+
+    /// Settings from the file's text; anything unreadable is noted in
+    /// `errors` and left at its default.
+    fn parse(text: &str, errors: &mut Vec<String>) -> Self {
+        let mut cfg = Config::default();
+        let values = parse_toml_subset(text, errors);
+        let int = |v: &str| v.parse::<u64>().ok();
+
+        if let Some(v) = typed(&values, "performance.adaptive_polling", parse_bool, errors) {
+            cfg.adaptive_polling = v;
+        }
+        if let Some(v) = typed(&values, "performance.frame_ms", int, errors) {
+            cfg.frame_ms = v.clamp(MIN_FRAME_MS, MAX_FRAME_MS);
+        }
+        if let Some(v) = typed(&values, "performance.idle_poll_ms", int, errors) {
+            cfg.idle_poll_ms = v.clamp(cfg.frame_ms, 1000);
+        }
+        if let Some(v) = values.get("leaderboard.url").filter(|v| !v.is_empty()) {
+            cfg.leaderboard_url = Some(v.clone());
+        }
+        if let Some(v) = values.get("leaderboard.secret") {
+            cfg.leaderboard_secret = v.clone();
+        }
+        let period = values.get("seasons.period").map(String::as_str).unwrap_or("none");
+        let starts = values.get("seasons.starts").map(String::as_str);
+        match SeasonPeriod::parse(period, starts) {
+            Some(v) => cfg.seasons = v,
+            None => errors.push(format!("seasons: bad period \"{}\"", period)),
+        }
+        if let Some(v) = typed(&values, "accessibility.reduced_motion", parse_bool, errors) {
+            cfg.reduced_motion = v;
+        }
+        if let Some(v) = typed(&values, "accessibility.ball_guide", parse_bool, errors) {
+            cfg.ball_guide = v;
+        }
+        if let Some(v) = typed(&values, "accessibility.max_flash_hz", int, errors) {
+            cfg.max_flash_hz = v.min(DEFAULT_MAX_FLASH_HZ as u64) as u32;
+        }
+        if let Some(v) = typed(&values, "accessibility.alert_color", parse_rgb, errors) {
+            cfg.alert_color = v;
+        }
+        if let Some(v) = typed(&values, "display.profile", RenderProfile::parse, errors) {
+            cfg.render_profile = v;
+        }
+        if let Some(v) = typed(&values, "display.theme", Theme::parse, errors) {
+            cfg.theme = Some(v);
+        }
+        for (i, event) in AudioEvent::ALL.iter().enumerate() {
+            if let Some(v) = typed(&values, &format!("sound.{}", event.key()), parse_bool, errors) {
+                cfg.sound_cues[i] = v;
+            }
+        }
+        if let Some(v) = typed(&values, "sound.mute", parse_bool, errors) {
+            cfg.sound_muted = v;
+        }
+        if let Some(v) = typed(&values, "sound.volume", int, errors) {
+            cfg.sound_volume = v.min(100) as u32;
+        }
+        if let Some(v) = values.get("control.socket").filter(|v| !v.is_empty()) {
+            cfg.control_socket = Some(PathBuf::from(v));
+        }
+        if let Some(v) = values.get("metrics.listen").filter(|v| !v.is_empty()) {
+            cfg.metrics_listen = Some(v.clone());
+        }
+        if let Some(v) = typed(&values, "kiosk.idle_timeout", int, errors) {
+            cfg.kiosk_idle_secs = v.max(10);
+        }
+        if let Some(v) = values.get("kiosk.log").filter(|v| !v.is_empty()) {
+            cfg.kiosk_log = Some(PathBuf::from(v));
+        }
+        if let Some(v) = typed(&values, "stats.session_summary", parse_bool, errors) {
+            cfg.session_summary = v;
+        }
+        if let Some(v) = typed(&values, "frogger.skin", frogger_skins::find, errors) {
+            cfg.frogger_skin = v;
+        }
+        if let Some(v) = typed(&values, "frogger.seasonal", parse_bool, errors) {
+            cfg.seasonal_skins = v;
+        }
+        if let Some(v) = typed(&values, "asteroids.extra_life", int, errors) {
+            cfg.asteroids_extra_life = v.min(u32::MAX as u64) as u32;
+        }
+        for action in ACTIONS {
+            let key = format!("keys.{}", action.id);
+            match typed(&values, &key, KeyBind::parse, errors) {
+                Some(bind) if is_reserved(bind) => errors.push(format!("{}: {} is kept by the arcade", key, bind.label())),
+                Some(bind) => cfg.key_binds.push((action.id, bind)),
+                None => {}
+            }
+        }
+        if let Some(key) = values.keys().filter(|k| k.starts_with("keys.")).find(|k| !ACTIONS.iter().any(|a| k[5..] == *a.id)) {
+            errors.push(format!("{}: no such action", key));
+        }
+        let difficulty = |v: &str| match v {
+            "easy" => Some(false),
+            "hard" => Some(true),
+            _ => None,
+        };
+        if let Some(v) = typed(&values, "difficulty.beam", difficulty, errors) {
+            cfg.beam_hard = v;
+        }
+        for (i, key) in GAME_KEYS.iter().enumerate() {
+            if let Some(v) = typed(&values, &format!("handicap.{}", key), |v| v.parse::<f32>().ok(), errors) {
+                cfg.game_speed[i] = ((v * 100.0).round() as u32).clamp(MIN_GAME_SPEED, MAX_GAME_SPEED);
+            }
+            if let Some(v) = typed(&values, &format!("volume.{}", key), int, errors) {
+                cfg.game_volume[i] = v.min(100) as u32;
+            }
+        }
+        cfg
+    }
+
+    /// Set `key` (`section.key`) in the config file to `value`, written as
+    /// TOML, keeping the rest of the file and its comments as they were.
+    /// Returns the new text.
+    pub fn write_value(key: &str, value: &str) -> std::io::Result<String> {
+        let path = Self::config_path().ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "no config folder"))?;
+        // Only a missing file starts empty; one that can't be read would
+        // otherwise be overwritten with just this key
+        let old = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+        let text = set_value(&old, key, value);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, &text)?;
+        Ok(text)
+    }
+
+    /// Delete the config file and return the defaults.
+    pub fn reset() -> Self {
+        if let Some(path) = Self::config_path() {
+            let _ = fs::remove_file(path);
+        }
+        Config::default()
+    }
+
+    /// `$XDG_CONFIG_HOME/rust-cade/config.toml`, falling back to `~/.config`.
+    pub fn config_path() -> Option<PathBuf> {
+        if let Ok(dir) = std::env::var("XDG_CONFIG_HOME") {
+            if !dir.is_empty() {
+                return Some(PathBuf::from(dir).join("rust-cade").join("config.toml"));
+            }
+        }
+        if let Ok(dir) = std::env::var("APPDATA") {
+            return Some(PathBuf::from(dir).join("rust-cade").join("config.toml"));
+        }
+        std::env::var("HOME")
+            .ok()
+            .map(|home| PathBuf::from(home).join(".config").join("rust-cade").join("config.toml"))
+    }
+
+    /// Poll interval to use while idle; equals the frame rate when disabled.
+    pub fn idle_poll_ms(&self) -> u64 {
+        if self.adaptive_polling { self.idle_poll_ms } else { self.frame_ms }
+    }
+}
+
+/// Watches the settings files so edits apply without a restart. The
+/// folders are watched rather than the files, since most editors save by
+/// writing a new file and renaming it over the old one.
+pub struct ConfigWatcher {
+    _watcher: RecommendedWatcher,
+    rx: mpsc::Receiver<notify::Result<notify::Event>>,
+    files: Vec<PathBuf>,
+    /// Files touched since the last settled change
+    pending: Vec<PathBuf>,
+    last_event: Instant,
+}
+
+impl ConfigWatcher {
+    /// None if the platform can't watch files or none of their folders exist.
+    pub fn start(files: Vec<PathBuf>) -> Option<Self> {
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(tx).ok()?;
+        let mut watching = false;
+        let mut dirs: Vec<&Path> = files.iter().filter_map(|f| f.parent()).collect();
+        dirs.dedup();
+        for dir in dirs {
+            watching |= watcher.watch(dir, RecursiveMode::NonRecursive).is_ok();
+        }
+        if !watching { return None; }
+        Some(ConfigWatcher { _watcher: watcher, rx, files, pending: Vec::new(), last_event: Instant::now() })
+    }
+
+    /// Watched files that changed, once they've been left alone for a moment.
+    pub fn changed(&mut self) -> Vec<PathBuf> {
+        for event in self.rx.try_iter().flatten() {
+            if event.kind.is_access() { continue; }
+            for path in event.paths {
+                if !self.files.contains(&path) { continue; }
+                self.last_event = Instant::now();
+                if !self.pending.contains(&path) {
+                    self.pending.push(path);
+                }
+            }
+        }
+        if self.pending.is_empty() || self.last_event.elapsed() < WATCH_SETTLE {
+            return Vec::new();
+        }
+        std::mem::take(&mut self.pending)
+    }
+}
+
+/// Parse the small TOML subset we need: `[section]` headers, `key = value`
+/// pairs, `#` comments, and quoted strings. Keys come back as `section.key`;
+/// lines that are none of these are noted in `errors` and skipped.
+fn parse_toml_subset(text: &str, errors: &mut Vec<String>) -> HashMap<String, String> {
+    let mut values = HashMap::new();
+    let mut section = String::new();
+    for (n, raw) in text.lines().enumerate() {
+        let line = strip_comment(raw).trim();
+        if line.is_empty() { continue; }
+        if line.starts_with('[') && line.ends_with(']') {
+            section = line[1..line.len() - 1].trim().to_string();
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            errors.push(format!("line {}: expected key = value", n + 1));
+            continue;
+        };
+        let key = key.trim().trim_matches('"');
+        let value = value.trim();
+        let value = if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+            &value[1..value.len() - 1]
+        } else {
+            value
+        };
+        let full_key = if section.is_empty() { key.to_string() } else { format!("{}.{}", section, key) };
+        values.insert(full_key, value.to_string());
+    }
+    values
+}
+
+/// `text` with `key` set to `value`: its line replaced (keeping any
+/// comment after it), else a line added at the end of its section, else
+/// the section added at the end of the file.
+fn set_value(text: &str, key: &str, value: &str) -> String {
+    let (section, name) = key.rsplit_once('.').unwrap_or(("", key));
+    let mut lines: Vec<String> = text.lines().map(str::to_string).collect();
+    let mut current = String::new();
+    // Where a new line for the key would go, once its section is seen
+    let mut insert_at = section.is_empty().then_some(0);
+    for (i, raw) in lines.iter().enumerate() {
+        let line = strip_comment(raw).trim();
+        if line.starts_with('[') && line.ends_with(']') {
+            current = line[1..line.len() - 1].trim().to_string();
+            if current == section {
+                insert_at = Some(i + 1);
+            }
+            continue;
+        }
+        if current != section || line.is_empty() { continue; }
+        insert_at = Some(i + 1);
+        if line.split_once('=').is_some_and(|(k, _)| k.trim().trim_matches('"') == name) {
+            let indent = &raw[..raw.len() - raw.trim_start().len()];
+            let comment = raw[strip_comment(raw).len()..].trim();
+            let mut replaced = format!("{}{} = {}", indent, name, value);
+            if !comment.is_empty() {
+                replaced = format!("{} {}", replaced, comment);
+            }
+            lines[i] = replaced;
+            return lines.join("\n") + "\n";
+        }
+    }
+    let new_line = format!("{} = {}", name, value);
+    match insert_at {
+        Some(i) => lines.insert(i, new_line),
+        None => {
+            if lines.last().is_some_and(|l| !l.trim().is_empty()) {
+                lines.push(String::new());
+            }
+            lines.push(format!("[{}]", section));
+            lines.push(new_line);
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+/// Drop a trailing `# comment`, ignoring `#` inside quoted strings.
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// `key`'s value if it's set and `parse` accepts it; a value it rejects
+/// is noted in `errors`.
+fn typed<T>(values: &HashMap<String, String>, key: &str, parse: impl Fn(&str) -> Option<T>, errors: &mut Vec<String>) -> Option<T> {
+    let v = values.get(key)?;
+    let parsed = parse(v);
+    if parsed.is_none() {
+        errors.push(format!("{}: bad value \"{}\"", key, v));
+    }
+    parsed
+}
+
+fn parse_bool(v: &str) -> Option<bool> {
+    match v {
+        "true" => Some(true),
+        "false" => Some(false),
+        _ => None,
+    }
+}
+
+/// `#rrggbb` colour.
+fn parse_rgb(v: &str) -> Option<Rgb> {
+    let hex = v.strip_prefix('#')?;
+    if hex.len() != 6 { return None; }
+    let n = u32::from_str_radix(hex, 16).ok()?;
+    Some(((n >> 16) as u8, (n >> 8) as u8, n as u8))
+}
@@ -0,0 +1,349 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::sync::mpsc;
+
+/// A call waiting for the main thread, and where its response line goes.
+pub struct Request {
+    pub id: Json,
+    pub method: String,
+    pub params: Json,
+    reply: mpsc::Sender<String>,
+}
+
+impl Request {
+    pub fn respond(self, result: Result<Json, RpcError>) {
+        let mut body = BTreeMap::new();
+        body.insert("jsonrpc".to_string(), Json::Str("2.0".to_string()));
+        body.insert("id".to_string(), self.id);
+        match result {
+            Ok(value) => {
+                body.insert("result".to_string(), value);
+            }
+            Err(e) => {
+                body.insert("error".to_string(), e.to_json());
+            }
+        }
+        let _ = self.reply.send(Json::Object(body).to_string());
+    }
+}
+
+pub struct RpcError {
+    code: i32,
+    message: String,
+}
+
+impl RpcError {
+    pub const PARSE: i32 = -32700;
+    pub const INVALID_REQUEST: i32 = -32600;
+    pub const METHOD_NOT_FOUND: i32 = -32601;
+    pub const INVALID_PARAMS: i32 = -32602;
+
+    pub fn new(code: i32, message: impl Into<String>) -> Self {
+        RpcError { code, message: message.into() }
+    }
+
+    pub fn params(message: impl Into<String>) -> Self {
+        RpcError::new(Self::INVALID_PARAMS, message)
+    }
+
+    fn to_json(&self) -> Json {
+        let mut error = BTreeMap::new();
+        error.insert("code".to_string(), Json::Num(self.code as f64));
+        error.insert("message".to_string(), Json::Str(self.message.clone()));
+        Json::Object(error)
+    }
+}
+
+/// Opt-in JSON-RPC 2.0 endpoint on a Unix socket, one request per line.
+/// Connections are served on their own threads; the calls themselves are
+/// handed to the main thread through `poll`, so they see the same game
+/// state as the screen.
+pub struct ControlServer {
+    rx: mpsc::Receiver<Request>,
+    path: PathBuf,
+}
+
+impl ControlServer {
+    /// Listen on `path`; Err says why not, for the player to see.
+    #[cfg(unix)]
+    pub fn start(path: PathBuf) -> Result<Self, String> {
+        use std::io::{BufRead, BufReader, Write};
+        use std::os::unix::fs::FileTypeExt;
+        use std::io::ErrorKind;
+        use std::os::unix::net::{UnixListener, UnixStream};
+        use std::thread;
+
+        // A socket file left behind by a crash would block the bind, but one
+        // another arcade is still listening on is its, and anything else at
+        // the path is the player's; both stay put
+        match std::fs::symlink_metadata(&path) {
+            Ok(meta) if meta.file_type().is_socket() => match UnixStream::connect(&path) {
+                Ok(_) => return Err(format!("{} is already in use", path.display())),
+                Err(e) if e.kind() == ErrorKind::ConnectionRefused => {
+                    let _ = std::fs::remove_file(&path);
+                }
+                Err(e) => return Err(format!("{}: {}", path.display(), e)),
+            },
+            Ok(_) => return Err(format!("{} exists and isn't a socket", path.display())),
+            Err(_) => {}
+        }
+        let listener = UnixListener::bind(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let (tx, rx) = mpsc::channel::<Request>();
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else { continue };
+                let tx = tx.clone();
+                thread::spawn(move || {
+                    let Ok(mut writer) = stream.try_clone() else { return };
+                    for line in BufReader::new(stream).lines() {
+                        let Ok(line) = line else { return };
+                        if line.trim().is_empty() { continue; }
+                        let (reply, response) = mpsc::channel();
+                        match parse_request(&line) {
+                            Ok((id, method, params)) => {
+                                if tx.send(Request { id, method, params, reply }).is_err() {
+                                    return;
+                                }
+                            }
+                            Err(e) => Request { id: Json::Null, method: String::new(), params: Json::Null, reply }.respond(Err(e)),
+                        }
+                        let Ok(text) = response.recv() else { return };
+                        if writeln!(writer, "{}", text).is_err() {
+                            return;
+                        }
+                    }
+                });
+            }
+        });
+
+        Ok(ControlServer { rx, path })
+    }
+
+    /// Unix sockets only.
+    #[cfg(not(unix))]
+    pub fn start(_path: PathBuf) -> Result<Self, String> {
+        Err("needs Unix sockets".to_string())
+    }
+
+    /// Calls that arrived since the last poll.
+    pub fn poll(&self) -> Vec<Request> {
+        self.rx.try_iter().collect()
+    }
+}
+
+impl Drop for ControlServer {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+fn parse_request(line: &str) -> Result<(Json, String, Json), RpcError> {
+    let json = Json::parse(line).ok_or_else(|| RpcError::new(RpcError::PARSE, "parse error"))?;
+    let id = json.get("id").cloned().unwrap_or(Json::Null);
+    let method = json
+        .get("method")
+        .and_then(Json::as_str)
+        .ok_or_else(|| RpcError::new(RpcError::INVALID_REQUEST, "missing method"))?
+        .to_string();
+    let params = json.get("params").cloned().unwrap_or(Json::Null);
+    Ok((id, method, params))
+}
+
+// ── JSON ─────────────────────────────────────────────────────────────────────
+
+/// Just enough JSON for the control protocol.
+#[derive(Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Num(f64),
+    Str(String),
+    Array(Vec<Json>),
+    Object(BTreeMap<String, Json>),
+}
+
+impl Json {
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(map) => map.get(key),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Json::Num(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub fn object<'a>(entries: impl IntoIterator<Item = (&'a str, Json)>) -> Json {
+        Json::Object(entries.into_iter().map(|(k, v)| (k.to_string(), v)).collect())
+    }
+
+    pub fn parse(text: &str) -> Option<Json> {
+        let mut p = Parser { text, bytes: text.as_bytes(), pos: 0 };
+        let value = p.value(0)?;
+        p.skip_ws();
+        (p.pos == p.bytes.len()).then_some(value)
+    }
+}
+
+impl std::fmt::Display for Json {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Json::Null => f.write_str("null"),
+            Json::Bool(b) => write!(f, "{}", b),
+            Json::Num(n) if n.is_finite() => write!(f, "{}", n),
+            Json::Num(_) => f.write_str("null"),
+            Json::Str(s) => {
+                let mut out = String::with_capacity(s.len() + 2);
+                out.push('"');
+                for c in s.chars() {
+                    match c {
+                        '"' => out.push_str("\\\""),
+                        '\\' => out.push_str("\\\\"),
+                        '\n' => out.push_str("\\n"),
+                        '\r' => out.push_str("\\r"),
+                        '\t' => out.push_str("\\t"),
+                        c if (c as u32) < 0x20 => { let _ = write!(out, "\\u{:04x}", c as u32); }
+                        c => out.push(c),
+                    }
+                }
+                out.push('"');
+                f.write_str(&out)
+            }
+            Json::Array(items) => {
+                f.write_str("[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 { f.write_str(",")?; }
+                    write!(f, "{}", item)?;
+                }
+                f.write_str("]")
+            }
+            Json::Object(map) => {
+                f.write_str("{")?;
+                for (i, (key, value)) in map.iter().enumerate() {
+                    if i > 0 { f.write_str(",")?; }
+                    write!(f, "{}:{}", Json::Str(key.clone()), value)?;
+                }
+                f.write_str("}")
+            }
+        }
+    }
+}
+
+/// Nesting of arrays and objects past which a request is refused, well short
+/// of what would overflow the connection thread's stack.
+const MAX_DEPTH: usize = 64;
+
+struct Parser<'a> {
+    text: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn skip_ws(&mut self) {
+        while self.bytes.get(self.pos).is_some_and(|b| b.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn eat(&mut self, literal: &str) -> bool {
+        if self.bytes[self.pos..].starts_with(literal.as_bytes()) {
+            self.pos += literal.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn value(&mut self, depth: usize) -> Option<Json> {
+        if depth > MAX_DEPTH { return None; }
+        self.skip_ws();
+        match *self.bytes.get(self.pos)? {
+            b'n' => self.eat("null").then_some(Json::Null),
+            b't' => self.eat("true").then_some(Json::Bool(true)),
+            b'f' => self.eat("false").then_some(Json::Bool(false)),
+            b'"' => self.string().map(Json::Str),
+            b'[' => {
+                self.pos += 1;
+                let mut items = Vec::new();
+                self.skip_ws();
+                if self.eat("]") { return Some(Json::Array(items)); }
+                loop {
+                    items.push(self.value(depth + 1)?);
+                    self.skip_ws();
+                    if self.eat("]") { return Some(Json::Array(items)); }
+                    if !self.eat(",") { return None; }
+                }
+            }
+            b'{' => {
+                self.pos += 1;
+                let mut map = BTreeMap::new();
+                self.skip_ws();
+                if self.eat("}") { return Some(Json::Object(map)); }
+                loop {
+                    self.skip_ws();
+                    let key = self.string()?;
+                    self.skip_ws();
+                    if !self.eat(":") { return None; }
+                    map.insert(key, self.value(depth + 1)?);
+                    self.skip_ws();
+                    if self.eat("}") { return Some(Json::Object(map)); }
+                    if !self.eat(",") { return None; }
+                }
+            }
+            _ => {
+                let start = self.pos;
+                while self.bytes.get(self.pos).is_some_and(|b| matches!(b, b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')) {
+                    self.pos += 1;
+                }
+                std::str::from_utf8(&self.bytes[start..self.pos]).ok()?.parse().ok().map(Json::Num)
+            }
+        }
+    }
+
+    fn string(&mut self) -> Option<String> {
+        if !self.eat("\"") { return None; }
+        let mut out = String::new();
+        loop {
+            let c = self.text.get(self.pos..)?.chars().next()?;
+            self.pos += c.len_utf8();
+            match c {
+                '"' => return Some(out),
+                '\\' => {
+                    let e = *self.bytes.get(self.pos)?;
+                    self.pos += 1;
+                    out.push(match e {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'u' => {
+                            let hex = std::str::from_utf8(self.bytes.get(self.pos..self.pos + 4)?).ok()?;
+                            self.pos += 4;
+                            char::from_u32(u32::from_str_radix(hex, 16).ok()?).unwrap_or('\u{fffd}')
+                        }
+                        _ => return None,
+                    });
+                }
+                c => out.push(c),
+            }
+        }
+    }
+}
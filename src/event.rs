@@ -1,44 +1,125 @@
 use std::io;
-use std::sync::mpsc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{mpsc, Arc};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-use crossterm::event::{self, KeyEvent, KeyEventKind};
+use crossterm::event::{self, KeyEvent, KeyEventKind, MouseEvent, MouseEventKind};
+use crossterm::terminal;
+
+use crate::ui::compat::{self, KEY_DEDUPE};
 
 pub enum Event {
-    Key(KeyEvent),
+    /// Stamped when the input thread read it, for latency measurement
+    Key(KeyEvent, Instant),
+    Mouse(MouseEvent),
+    /// New terminal size in columns and rows; also sent once at startup
+    Resize(u16, u16),
     Tick,
 }
 
 pub struct EventHandler {
     rx: mpsc::Receiver<Event>,
+    idle: Arc<AtomicBool>,
+    /// Poll intervals in ms, while active and while idle
+    rates: Arc<[AtomicU64; 2]>,
+    /// Read past while skipping motion, returned next
+    held: Option<Event>,
 }
 
 impl EventHandler {
-    pub fn new(tick_rate_ms: u64) -> Self {
+    /// Ticks every `tick_rate_ms`, or every `idle_rate_ms` while marked idle.
+    /// Input always wakes the poll immediately, so idling never adds latency.
+    pub fn new(tick_rate_ms: u64, idle_rate_ms: u64) -> Self {
         let (tx, rx) = mpsc::channel();
-        let tick_rate = Duration::from_millis(tick_rate_ms);
+        let idle = Arc::new(AtomicBool::new(false));
+        // Set before the thread starts, which would spin on a zero interval
+        let rates = Arc::new([AtomicU64::new(tick_rate_ms), AtomicU64::new(idle_rate_ms.max(tick_rate_ms))]);
+        let thread_idle = Arc::clone(&idle);
+        let thread_rates = Arc::clone(&rates);
 
+        let mut last_key: Option<(KeyEvent, Instant)> = None;
+        if let Ok((width, height)) = terminal::size() {
+            let _ = tx.send(Event::Resize(width, height));
+        }
+        let mut last_tick = Instant::now();
         thread::spawn(move || loop {
-            if event::poll(tick_rate).unwrap_or(false) {
-                if let Ok(crossterm::event::Event::Key(key)) = event::read() {
-                    if key.kind == KeyEventKind::Press {
-                        if tx.send(Event::Key(key)).is_err() {
+            let rate = thread_rates[thread_idle.load(Ordering::Relaxed) as usize].load(Ordering::Relaxed);
+            // Wait out the rest of the interval, so a stream of input can't hold the tick off
+            let wait = Duration::from_millis(rate).saturating_sub(last_tick.elapsed());
+            if !wait.is_zero() && event::poll(wait).unwrap_or(false) {
+                match event::read() {
+                    Ok(crossterm::event::Event::Key(key)) if key.kind == KeyEventKind::Press => {
+                        let now = Instant::now();
+                        // ConPTY can report one press twice, back to back
+                        let doubled = last_key.is_some_and(|(k, at)| k == key && now - at < KEY_DEDUPE);
+                        last_key = Some((key, now));
+                        if doubled && compat::profile().dedupe_keys() { continue; }
+                        if tx.send(Event::Key(key, now)).is_err() {
+                            return;
+                        }
+                    }
+                    // Clicks, motion and the wheel; `next` skips stale motion
+                    Ok(crossterm::event::Event::Mouse(mouse)) => {
+                        let wanted = !matches!(
+                            mouse.kind,
+                            MouseEventKind::Up(_) | MouseEventKind::ScrollLeft | MouseEventKind::ScrollRight
+                        );
+                        if wanted && tx.send(Event::Mouse(mouse)).is_err() {
                             return;
                         }
                     }
+                    Ok(crossterm::event::Event::Resize(width, height)) => {
+                        if tx.send(Event::Resize(width, height)).is_err() {
+                            return;
+                        }
+                    }
+                    _ => {}
+                }
+            } else {
+                last_tick = Instant::now();
+                if tx.send(Event::Tick).is_err() {
+                    return;
                 }
-            } else if tx.send(Event::Tick).is_err() {
-                return;
             }
         });
 
-        Self { rx }
+        Self { rx, idle, rates, held: None }
     }
 
-    pub fn next(&self) -> io::Result<Event> {
-        self.rx
-            .recv()
-            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))
+    /// Change the poll intervals, e.g. after the config was reloaded.
+    pub fn set_rates(&self, tick_rate_ms: u64, idle_rate_ms: u64) {
+        self.rates[0].store(tick_rate_ms, Ordering::Relaxed);
+        self.rates[1].store(idle_rate_ms.max(tick_rate_ms), Ordering::Relaxed);
     }
+
+    /// Switch to the slow poll rate when nothing on screen is animating.
+    pub fn set_idle(&self, idle: bool) {
+        self.idle.store(idle, Ordering::Relaxed);
+    }
+
+    /// The next event. A pointer crossing the screen reports every cell
+    /// it passes, faster than frames draw, so queued motion is skipped to
+    /// the latest.
+    pub fn next(&mut self) -> io::Result<Event> {
+        let mut event = match self.held.take() {
+            Some(event) => event,
+            None => self.rx.recv().map_err(|e| io::Error::new(io::ErrorKind::Other, e))?,
+        };
+        while matches!(&event, Event::Mouse(m) if is_motion(m)) {
+            match self.rx.try_recv() {
+                Ok(Event::Mouse(m)) if is_motion(&m) => event = Event::Mouse(m),
+                Ok(other) => {
+                    self.held = Some(other);
+                    break;
+                }
+                Err(_) => break,
+            }
+        }
+        Ok(event)
+    }
+}
+
+fn is_motion(mouse: &MouseEvent) -> bool {
+    matches!(mouse.kind, MouseEventKind::Moved | MouseEventKind::Drag(_))
 }
@@ -0,0 +1,108 @@
+use std::io::{self, Stdout};
+use std::time::Instant;
+
+use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::backend::CrosstermBackend;
+use ratatui::Terminal;
+
+use crate::app::App;
+use crate::audio;
+use crate::config;
+use crate::event::{Event, EventHandler};
+use crate::ui;
+
+/// Where frames are drawn and input comes from. The main loop only talks
+/// to a `Frontend`; the terminal is the only one so far, and `Event` still
+/// carries crossterm's key and mouse types, so a host without crossterm
+/// (a browser terminal) can't implement it yet.
+pub trait Frontend {
+    fn draw(&mut self, app: &mut App) -> io::Result<()>;
+    /// Block until the next key, mouse event, resize or tick.
+    fn next_event(&mut self) -> io::Result<Event>;
+    /// Poll intervals in ms while animating and while idle, and which applies now.
+    fn set_pacing(&mut self, frame_ms: u64, idle_ms: u64, idle: bool);
+    /// Shake the controller, on hosts that have one.
+    fn rumble(&mut self, _rumble: audio::Rumble) {}
+}
+
+/// The local terminal through crossterm: raw mode on the alternate screen,
+/// with input read on its own thread. Dropping it restores the terminal.
+pub struct TerminalFrontend {
+    terminal: Terminal<CrosstermBackend<Stdout>>,
+    events: EventHandler,
+}
+
+impl TerminalFrontend {
+    pub fn start() -> io::Result<Self> {
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+        let mut terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+        terminal.clear()?;
+        let events = EventHandler::new(config::DEFAULT_FRAME_MS, config::DEFAULT_IDLE_POLL_MS);
+        Ok(TerminalFrontend { terminal, events })
+    }
+}
+
+impl Frontend for TerminalFrontend {
+    fn draw(&mut self, app: &mut App) -> io::Result<()> {
+        let theme = app.theme;
+        self.terminal.draw(|frame| ui::render(frame, app, &theme))?;
+        audio::play_due(self.terminal.backend_mut());
+        Ok(())
+    }
+
+    fn next_event(&mut self) -> io::Result<Event> {
+        self.events.next()
+    }
+
+    fn set_pacing(&mut self, frame_ms: u64, idle_ms: u64, idle: bool) {
+        self.events.set_rates(frame_ms, idle_ms);
+        self.events.set_idle(idle);
+    }
+}
+
+impl Drop for TerminalFrontend {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+        let _ = execute!(self.terminal.backend_mut(), LeaveAlternateScreen, DisableMouseCapture);
+        let _ = self.terminal.show_cursor();
+    }
+}
+
+/// Draw, wait for input or a tick, handle it; until the app quits.
+pub fn run(frontend: &mut impl Frontend, app: &mut App) -> io::Result<()> {
+    loop {
+        // Cheap enough to do every loop; picks up a reloaded config. Queued
+        // bell strikes need the fast poll to keep their rhythm
+        let idle = app.is_idle() && !audio::pending();
+        frontend.set_pacing(app.config.frame_ms, app.config.idle_poll_ms(), idle);
+        frontend.draw(app)?;
+        if let Some(rumble) = audio::take_rumble() {
+            frontend.rumble(rumble);
+        }
+        app.metrics.frame_rendered();
+
+        match frontend.next_event()? {
+            Event::Tick => {
+                let started = Instant::now();
+                app.on_tick();
+                app.metrics.tick_took(started.elapsed());
+            }
+            Event::Key(key, received) => {
+                app.on_key(key);
+                app.metrics.key_handled(received);
+            }
+            Event::Mouse(mouse) => app.on_mouse(mouse),
+            Event::Resize(width, height) => app.on_resize(width, height),
+        }
+
+        if app.should_quit {
+            return Ok(());
+        }
+    }
+}
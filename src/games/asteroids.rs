@@ -1,10 +1,17 @@
 use crossterm::event::{KeyCode, KeyEvent};
+use rand::Rng;
 use ratatui::prelude::*;
 use ratatui::widgets::*;
 
-use std::collections::HashMap;
-
-use crate::games::Game;
+use crate::audio::{self, AudioEvent};
+use crate::games::{Game, GameInfo};
+use crate::rng::GameRng;
+use crate::session::{StateReader, StateWriter};
+use crate::ui::braille::BrailleCanvas;
+use crate::ui::fx::{self, Emitter, Particles, ScreenFlash};
+use crate::ui::hud::{Countdown, Toast};
+use crate::ui::starfield::Starfield;
+use crate::ui::theme::{Ink, Role, Theme};
 
 const MAX_BULLETS: usize = 8;
 const BULLET_LIFETIME: u64 = 40;
@@ -13,7 +20,29 @@ const FRICTION: f32 = 0.99;
 const ROTATION_SPEED: f32 = 0.12;
 const BULLET_SPEED: f32 = 1.2;
 const SHIP_INVULN_TICKS: u64 = 60;
+/// The ship collides as a circle this size
+const SHIP_RADIUS: f32 = 1.2;
 const FIRE_COOLDOWN: u64 = 5;
+/// Points between extra ships unless the config says otherwise
+pub const DEFAULT_EXTRA_LIFE: u32 = 10_000;
+/// Ships in reserve past this aren't awarded
+const MAX_LIVES: u32 = 9;
+const SHAKE_DEATH_TICKS: u32 = 14;
+const SHAKE_LARGE_ROCK_TICKS: u32 = 4;
+/// Momentum a bullet hands to the rock it hits
+const BULLET_IMPULSE: f32 = 2.0;
+/// Fastest a rock may drift or spin, in cells and radians per tick
+const MAX_ROCK_SPEED: f32 = 0.9;
+const MAX_SPIN: f32 = 0.2;
+const SHIP_DEBRIS: Emitter = Emitter::burst(24, 0.45, 40);
+/// Distant stars creeping left, as if the whole field were adrift
+const STARS: Starfield = Starfield::new(8.0, (-0.02, 0.0));
+const SHIP_DEBRIS_PALETTE: &[Ink] = &[
+    Ink(Color::Rgb(80, 255, 140), Role::Green),
+    Ink(Color::Rgb(100, 230, 255), Role::Cyan),
+    Ink(Color::Rgb(255, 130, 30), Role::Orange),
+    Ink(Color::White, Role::Bright),
+];
 
 #[derive(Clone, Copy, PartialEq)]
 enum AsteroidSize {
@@ -31,6 +60,11 @@ impl AsteroidSize {
         }
     }
 
+    /// Rocks are uniform discs, so mass goes with the radius squared.
+    fn mass(&self) -> f32 {
+        self.radius() * self.radius()
+    }
+
     fn num_verts(&self) -> usize {
         match self {
             AsteroidSize::Large => 11,
@@ -55,23 +89,23 @@ impl AsteroidSize {
         }
     }
 
-    fn color(&self, seed: u8) -> Color {
+    fn color(&self, seed: u8) -> Ink {
         let v = seed % 3;
         match self {
             AsteroidSize::Large => match v {
-                0 => Color::Rgb(170, 150, 120),
-                1 => Color::Rgb(150, 140, 110),
-                _ => Color::Rgb(160, 145, 115),
+                0 => Ink(Color::Rgb(170, 150, 120), Role::Orange),
+                1 => Ink(Color::Rgb(150, 140, 110), Role::Dim),
+                _ => Ink(Color::Rgb(160, 145, 115), Role::Dim),
             },
             AsteroidSize::Medium => match v {
-                0 => Color::Rgb(190, 170, 140),
-                1 => Color::Rgb(180, 165, 135),
-                _ => Color::Rgb(185, 168, 138),
+                0 => Ink(Color::Rgb(190, 170, 140), Role::Orange),
+                1 => Ink(Color::Rgb(180, 165, 135), Role::Dim),
+                _ => Ink(Color::Rgb(185, 168, 138), Role::Dim),
             },
             AsteroidSize::Small => match v {
-                0 => Color::Rgb(210, 190, 160),
-                1 => Color::Rgb(200, 185, 155),
-                _ => Color::Rgb(205, 188, 158),
+                0 => Ink(Color::Rgb(210, 190, 160), Role::Orange),
+                1 => Ink(Color::Rgb(200, 185, 155), Role::Text),
+                _ => Ink(Color::Rgb(205, 188, 158), Role::Text),
             },
         }
     }
@@ -81,16 +115,40 @@ impl AsteroidSize {
 struct Asteroid {
     x: f32,
     y: f32,
+    prev_x: f32,
+    prev_y: f32,
     vx: f32,
     vy: f32,
+    /// Rotation, radians, and its rate per tick
+    angle: f32,
+    prev_angle: f32,
+    spin: f32,
     size: AsteroidSize,
     shape_seed: u8,
 }
 
+/// A bullet striking a rock.
+struct RockHit {
+    rock: Asteroid,
+    /// Bullet velocity
+    shot: (f32, f32),
+    /// Where it struck, relative to the rock's centre
+    offset: (f32, f32),
+}
+
+/// Outline seed for the `n`th rock of a level, so each level's rocks
+/// have the same shapes every game; fragments hash on from their parent.
+fn shape_seed(base: u32, n: u32) -> u8 {
+    let h = (base.wrapping_mul(2_654_435_761) ^ n.wrapping_mul(40_503)).wrapping_mul(2_246_822_519);
+    (h >> 24) as u8
+}
+
 #[derive(Clone)]
 struct Bullet {
     x: f32,
     y: f32,
+    prev_x: f32,
+    prev_y: f32,
     vx: f32,
     vy: f32,
     life: u64,
@@ -116,14 +174,41 @@ pub struct Asteroids {
     level: u32,
     game_over: bool,
     paused: bool,
+    countdown: Countdown,
     tick: u64,
     field_width: f32,
     field_height: f32,
-    rng_state: u32,
+    rng: GameRng,
+    // Interpolation: positions at the previous update, blended in render
+    prev_ship_x: f32,
+    prev_ship_y: f32,
+    prev_ship_angle: f32,
+    render_alpha: f32,
+    // Effects
+    particles: Particles,
+    flash: ScreenFlash,
+    shake_ticks: u32,
+    /// Accessibility: no screen shake
+    reduced_motion: bool,
+    show_hitboxes: bool,
+    /// Points between extra ships; 0 awards none
+    extra_life_every: u32,
+    /// Score that earns the next one
+    next_extra_life: u32,
+    /// Fanfare for an extra ship, over the field
+    toast: Option<Toast>,
+    // Run stats for the game-over line and the score tables
+    lives_lost: u32,
+    shots_fired: u32,
+    shots_hit: u32,
 }
 
 impl Asteroids {
     pub fn new() -> Self {
+        Self::with_rng(GameRng::from_entropy())
+    }
+
+    fn with_rng(rng: GameRng) -> Self {
         let fw = 80.0;
         let fh = 30.0;
         let mut a = Self {
@@ -146,46 +231,95 @@ impl Asteroids {
             level: 1,
             game_over: false,
             paused: false,
+            countdown: Countdown::default(),
             tick: 0,
             field_width: fw,
             field_height: fh,
-            rng_state: 42,
+            rng,
+            prev_ship_x: fw / 2.0,
+            prev_ship_y: fh / 2.0,
+            prev_ship_angle: -std::f32::consts::FRAC_PI_2,
+            render_alpha: 1.0,
+            particles: Particles::new(7),
+            flash: ScreenFlash::new(),
+            shake_ticks: 0,
+            reduced_motion: false,
+            show_hitboxes: false,
+            extra_life_every: DEFAULT_EXTRA_LIFE,
+            next_extra_life: DEFAULT_EXTRA_LIFE,
+            toast: None,
+            lives_lost: 0,
+            shots_fired: 0,
+            shots_hit: 0,
         };
         a.spawn_asteroids(2);
         a
     }
 
-    fn cheap_rand(&mut self) -> u32 {
-        self.rng_state ^= self.rng_state << 13;
-        self.rng_state ^= self.rng_state >> 17;
-        self.rng_state ^= self.rng_state << 5;
-        self.rng_state
+    pub fn set_reduced_motion(&mut self, on: bool) {
+        self.reduced_motion = on;
+        if on { self.shake_ticks = 0; }
+    }
+
+    /// Award a ship every `points` (0 for never), from the next multiple
+    /// above the current score.
+    pub fn set_extra_life(&mut self, points: u32) {
+        self.extra_life_every = points;
+        self.next_extra_life = match self.score.checked_div(points) {
+            Some(earned) => (earned + 1).saturating_mul(points),
+            None => u32::MAX,
+        };
     }
 
-    fn rand_f32(&mut self) -> f32 {
-        (self.cheap_rand() % 10000) as f32 / 10000.0
+    /// Ships lost, then accuracy in percent of shots fired, for the
+    /// score tables.
+    pub fn extra_stats(&self) -> Vec<u32> {
+        vec![self.lives_lost, self.accuracy()]
+    }
+
+    /// Share of shots that hit a rock, in percent.
+    fn accuracy(&self) -> u32 {
+        (self.shots_hit * 100).checked_div(self.shots_fired).unwrap_or(0)
+    }
+
+    /// Hand out any ships the score has passed the mark for.
+    fn award_extra_lives(&mut self) {
+        if self.extra_life_every == 0 { return; }
+        while self.score >= self.next_extra_life {
+            let mark = self.next_extra_life;
+            self.next_extra_life = mark.saturating_add(self.extra_life_every);
+            if self.lives >= MAX_LIVES { continue; }
+            self.lives += 1;
+            self.toast = Some(Toast::info(format!("Extra ship at {}!", mark)));
+            audio::emit(AudioEvent::ExtraLife);
+        }
     }
 
     fn spawn_asteroids(&mut self, count: usize) {
-        for _ in 0..count {
-            let edge = self.cheap_rand() % 4;
+        for n in 0..count {
+            let edge = self.rng.gen_range(0..4);
             let (x, y) = match edge {
-                0 => (self.rand_f32() * self.field_width, 0.0),
-                1 => (self.rand_f32() * self.field_width, self.field_height),
-                2 => (0.0, self.rand_f32() * self.field_height),
-                _ => (self.field_width, self.rand_f32() * self.field_height),
+                0 => (self.rng.gen::<f32>() * self.field_width, 0.0),
+                1 => (self.rng.gen::<f32>() * self.field_width, self.field_height),
+                2 => (0.0, self.rng.gen::<f32>() * self.field_height),
+                _ => (self.field_width, self.rng.gen::<f32>() * self.field_height),
             };
-            let angle = self.rand_f32() * std::f32::consts::TAU;
+            let angle = self.rng.gen::<f32>() * std::f32::consts::TAU;
             let base_speed = 0.1 + self.level as f32 * 0.02;
-            let speed = base_speed + self.rand_f32() * 0.15;
-            let seed = (self.cheap_rand() % 256) as u8;
+            let speed = base_speed + self.rng.gen::<f32>() * 0.15;
+            let spin = (self.rng.gen::<f32>() - 0.5) * 0.06;
             self.asteroids.push(Asteroid {
                 x,
                 y,
+                prev_x: x,
+                prev_y: y,
                 vx: angle.cos() * speed,
                 vy: angle.sin() * speed,
+                angle: 0.0,
+                prev_angle: 0.0,
+                spin,
                 size: AsteroidSize::Large,
-                shape_seed: seed,
+                shape_seed: shape_seed(self.level, n as u32),
             });
         }
     }
@@ -237,13 +371,18 @@ impl Asteroids {
             self.fire_cooldown -= 1;
         }
         if self.shooting && self.fire_cooldown == 0 && self.bullets.len() < MAX_BULLETS {
+            let bx = self.ship_x + self.ship_angle.cos() * 1.5;
+            let by = self.ship_y + self.ship_angle.sin() * 1.5;
             self.bullets.push(Bullet {
-                x: self.ship_x + self.ship_angle.cos() * 1.5,
-                y: self.ship_y + self.ship_angle.sin() * 1.5,
+                x: bx,
+                y: by,
+                prev_x: bx,
+                prev_y: by,
                 vx: self.ship_angle.cos() * BULLET_SPEED + self.ship_vx * 0.3,
                 vy: self.ship_angle.sin() * BULLET_SPEED + self.ship_vy * 0.3,
                 life: BULLET_LIFETIME,
             });
+            self.shots_fired += 1;
             self.fire_cooldown = FIRE_COOLDOWN;
         }
     }
@@ -266,6 +405,7 @@ impl Asteroids {
         for asteroid in &mut self.asteroids {
             asteroid.x += asteroid.vx;
             asteroid.y += asteroid.vy;
+            asteroid.angle += asteroid.spin;
             if asteroid.x < -margin { asteroid.x += self.field_width + margin * 2.0; }
             if asteroid.x >= self.field_width + margin { asteroid.x -= self.field_width + margin * 2.0; }
             if asteroid.y < -margin { asteroid.y += self.field_height + margin * 2.0; }
@@ -274,7 +414,7 @@ impl Asteroids {
     }
 
     fn check_collisions(&mut self) {
-        let mut hits: Vec<(usize, usize, f32, f32, AsteroidSize)> = Vec::new();
+        let mut hits: Vec<RockHit> = Vec::new();
         let mut bullets_to_remove: Vec<usize> = Vec::new();
         let mut asteroids_to_remove: Vec<usize> = Vec::new();
 
@@ -286,36 +426,23 @@ impl Asteroids {
                 let dist = (dx * dx + dy * dy).sqrt();
                 if dist < asteroid.size.radius() {
                     self.score += asteroid.size.points();
+                    self.shots_hit += 1;
                     bullets_to_remove.push(bi);
                     asteroids_to_remove.push(ai);
-                    hits.push((bi, ai, asteroid.x, asteroid.y, asteroid.size));
+                    hits.push(RockHit { rock: asteroid.clone(), shot: (bullet.vx, bullet.vy), offset: (dx, dy) });
                     break;
                 }
             }
         }
 
+        for hit in &hits {
+            self.rock_sparks(hit.rock.x, hit.rock.y, hit.rock.size);
+        }
+        self.award_extra_lives();
+
         let mut new_asteroids: Vec<Asteroid> = Vec::new();
-        for &(_, _, ax, ay, size) in &hits {
-            if let Some(new_size) = size.split() {
-                let spread_angle = self.rand_f32() * std::f32::consts::TAU;
-                let speed = 0.2 + self.rand_f32() * 0.3 + self.level as f32 * 0.02;
-                let seed1 = (self.cheap_rand() % 256) as u8;
-                let seed2 = (self.cheap_rand() % 256) as u8;
-                new_asteroids.push(Asteroid {
-                    x: ax, y: ay,
-                    vx: spread_angle.cos() * speed,
-                    vy: spread_angle.sin() * speed,
-                    size: new_size,
-                    shape_seed: seed1,
-                });
-                new_asteroids.push(Asteroid {
-                    x: ax, y: ay,
-                    vx: -spread_angle.cos() * speed,
-                    vy: -spread_angle.sin() * speed,
-                    size: new_size,
-                    shape_seed: seed2,
-                });
-            }
+        for hit in &hits {
+            new_asteroids.extend(self.fragments(hit));
         }
 
         bullets_to_remove.sort_unstable();
@@ -331,83 +458,162 @@ impl Asteroids {
         self.asteroids.extend(new_asteroids);
 
         // Ship-asteroid collisions
-        if self.invuln_timer == 0 {
-            for asteroid in &self.asteroids {
-                let dx = self.ship_x - asteroid.x;
-                let dy = self.ship_y - asteroid.y;
-                let dist = (dx * dx + dy * dy).sqrt();
-                if dist < asteroid.size.radius() + 1.2 {
-                    self.lives = self.lives.saturating_sub(1);
-                    if self.lives == 0 {
-                        self.game_over = true;
-                        if self.score > self.high_score {
-                            self.high_score = self.score;
-                        }
-                    } else {
-                        self.ship_x = self.field_width / 2.0;
-                        self.ship_y = self.field_height / 2.0;
-                        self.ship_vx = 0.0;
-                        self.ship_vy = 0.0;
-                        self.invuln_timer = SHIP_INVULN_TICKS;
-                    }
-                    break;
+        let ship_hit = self.invuln_timer == 0 && self.asteroids.iter().any(|asteroid| {
+            let dx = self.ship_x - asteroid.x;
+            let dy = self.ship_y - asteroid.y;
+            (dx * dx + dy * dy).sqrt() < asteroid.size.radius() + SHIP_RADIUS
+        });
+        if ship_hit {
+            self.ship_debris();
+            self.lives = self.lives.saturating_sub(1);
+            self.lives_lost += 1;
+            if self.lives == 0 {
+                self.game_over = true;
+                self.flash.trigger(fx::alert_color());
+                if self.score > self.high_score {
+                    self.high_score = self.score;
                 }
+            } else {
+                self.ship_x = self.field_width / 2.0;
+                self.ship_y = self.field_height / 2.0;
+                self.ship_vx = 0.0;
+                self.ship_vy = 0.0;
+                self.prev_ship_x = self.ship_x;
+                self.prev_ship_y = self.ship_y;
+                self.invuln_timer = SHIP_INVULN_TICKS;
+                self.countdown.start();
             }
         }
 
         // Level complete
         if self.asteroids.is_empty() && !self.game_over {
             self.level += 1;
+            audio::emit(AudioEvent::LevelUp);
             let count = (1 + self.level).min(8) as usize;
             self.spawn_asteroids(count);
             self.invuln_timer = SHIP_INVULN_TICKS;
         }
     }
 
-    // ── Braille rendering helpers ──────────────────────────────────────
+    /// The two pieces a shot rock breaks into. The bullet's impulse pushes
+    /// the rock along and, hitting off-centre, sets it spinning; the
+    /// pieces keep that motion, fly apart across the line of fire and pick
+    /// up the spin's edge speed at their offset from the centre.
+    fn fragments(&mut self, hit: &RockHit) -> Vec<Asteroid> {
+        let (rock, (bvx, bvy), (dx, dy)) = (&hit.rock, hit.shot, hit.offset);
+        let Some(size) = rock.size.split() else { return Vec::new() };
+        let (m, r) = (rock.size.mass(), rock.size.radius());
+        let shot_speed = (bvx * bvx + bvy * bvy).sqrt().max(1e-3);
+        let (jx, jy) = (bvx / shot_speed * BULLET_IMPULSE, bvy / shot_speed * BULLET_IMPULSE);
+        let vx = rock.vx + jx / m;
+        let vy = rock.vy + jy / m;
+        // Torque r × J over a disc's moment of inertia, ½·m·r²
+        let spin = rock.spin + (dx * jy - dy * jx) / (0.5 * m * r * r);
+
+        let across = bvy.atan2(bvx) + std::f32::consts::FRAC_PI_2 + (self.rng.gen::<f32>() - 0.5);
+        let sep = 0.15 + self.rng.gen::<f32>() * 0.2 + self.level as f32 * 0.02;
+        let mut pieces = Vec::with_capacity(2);
+        for (k, side) in [1.0f32, -1.0].into_iter().enumerate() {
+            let (ox, oy) = (across.cos() * side * size.radius(), across.sin() * side * size.radius());
+            let mut pvx = vx + across.cos() * side * sep - spin * oy;
+            let mut pvy = vy + across.sin() * side * sep + spin * ox;
+            let speed = (pvx * pvx + pvy * pvy).sqrt();
+            if speed > MAX_ROCK_SPEED {
+                pvx *= MAX_ROCK_SPEED / speed;
+                pvy *= MAX_ROCK_SPEED / speed;
+            }
+            let (x, y) = (rock.x + ox, rock.y + oy);
+            let jitter = (self.rng.gen::<f32>() - 0.5) * 0.04;
+            pieces.push(Asteroid {
+                x, y,
+                prev_x: x, prev_y: y,
+                vx: pvx,
+                vy: pvy,
+                angle: rock.angle,
+                prev_angle: rock.angle,
+                spin: (spin + jitter).clamp(-MAX_SPIN, MAX_SPIN),
+                size,
+                shape_seed: shape_seed(rock.shape_seed as u32, k as u32 + 1),
+            });
+        }
+        pieces
+    }
+
+    // ── Effects ────────────────────────────────────────────────────────
 
-    fn braille_bit(sub_x: usize, sub_y: usize) -> u8 {
-        match (sub_x, sub_y) {
-            (0, 0) => 0x01,
-            (0, 1) => 0x02,
-            (0, 2) => 0x04,
-            (0, 3) => 0x40,
-            (1, 0) => 0x08,
-            (1, 1) => 0x10,
-            (1, 2) => 0x20,
-            (1, 3) => 0x80,
-            _ => 0,
+    fn rock_sparks(&mut self, x: f32, y: f32, size: AsteroidSize) {
+        let count = match size {
+            AsteroidSize::Large => 14,
+            AsteroidSize::Medium => 10,
+            AsteroidSize::Small => 6,
+        };
+        self.particles.emit(&Emitter::burst(count, 0.6, 18), x, y, fx::SPARKS);
+        audio::emit(AudioEvent::Explosion);
+        if size == AsteroidSize::Large {
+            self.shake(SHAKE_LARGE_ROCK_TICKS);
         }
     }
 
-    fn line_cells(x0: i32, y0: i32, x1: i32, y1: i32) -> Vec<(i32, i32)> {
-        let mut cells = Vec::new();
-        let dx = (x1 - x0).abs();
-        let dy = -(y1 - y0).abs();
-        let sx = if x0 < x1 { 1 } else { -1 };
-        let sy = if y0 < y1 { 1 } else { -1 };
-        let mut err = dx + dy;
-        let mut cx = x0;
-        let mut cy = y0;
-        loop {
-            cells.push((cx, cy));
-            if cx == x1 && cy == y1 { break; }
-            let e2 = 2 * err;
-            if e2 >= dy { err += dy; cx += sx; }
-            if e2 <= dx { err += dx; cy += sy; }
+    fn ship_debris(&mut self) {
+        self.particles.emit(&SHIP_DEBRIS, self.ship_x, self.ship_y, SHIP_DEBRIS_PALETTE);
+        audio::emit(AudioEvent::Explosion);
+        self.shake(SHAKE_DEATH_TICKS);
+    }
+
+    fn shake(&mut self, ticks: u32) {
+        if !self.reduced_motion {
+            self.shake_ticks = self.shake_ticks.max(ticks);
         }
-        cells
     }
 
-    /// Generate irregular polygon vertices for an asteroid using its seed.
-    fn asteroid_verts(cx: f32, cy: f32, size: AsteroidSize, seed: u8) -> Vec<(f32, f32)> {
+    fn update_effects(&mut self) {
+        self.particles.update();
+        self.flash.update();
+        self.shake_ticks = self.shake_ticks.saturating_sub(1);
+    }
+
+    /// Whole-cell offset for the current shake frame, shrinking as it fades.
+    fn shake_offset(&self) -> (i32, i32) {
+        if self.shake_ticks == 0 || self.reduced_motion { return (0, 0); }
+        let amp: i32 = if self.shake_ticks > SHAKE_DEATH_TICKS / 2 { 2 } else { 1 };
+        let h = (self.shake_ticks as u64).wrapping_mul(2_654_435_761) ^ self.tick;
+        let dx = (h % (2 * amp + 1) as u64) as i32 - amp;
+        let dy = ((h >> 7) % 3) as i32 - 1;
+        (dx, dy)
+    }
+
+    /// The grid moved by whole cells, exposing blank cells at the edges.
+    fn shift_grid(grid: &[Vec<(char, Style)>], dx: i32, dy: i32, blank: Style) -> Vec<Vec<(char, Style)>> {
+        let h = grid.len() as i32;
+        (0..h)
+            .map(|y| {
+                let w = grid[y as usize].len() as i32;
+                (0..w)
+                    .map(|x| {
+                        let (sx, sy) = (x - dx, y - dy);
+                        if sx >= 0 && sy >= 0 && sx < w && sy < h {
+                            grid[sy as usize][sx as usize]
+                        } else {
+                            (' ', blank)
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    // ── Shape helpers ──────────────────────────────────────────────────
+
+    /// Generate irregular polygon vertices for an asteroid using its seed,
+    /// turned by `rotation` radians.
+    fn asteroid_verts(cx: f32, cy: f32, size: AsteroidSize, seed: u8, rotation: f32) -> Vec<(f32, f32)> {
         let n = size.num_verts();
         let r = size.radius();
         let mut verts = Vec::with_capacity(n);
         // Use seed to create per-vertex radius variation
         let mut s = seed as u32;
         for i in 0..n {
-            let angle = (i as f32 / n as f32) * std::f32::consts::TAU;
+            let angle = rotation + (i as f32 / n as f32) * std::f32::consts::TAU;
             // Simple hash for variation per vertex
             s = s.wrapping_mul(1103515245).wrapping_add(12345);
             let variation = 0.7 + ((s >> 16) % 300) as f32 / 1000.0; // 0.7 - 1.0
@@ -417,65 +623,72 @@ impl Asteroids {
         verts
     }
 
-    /// Set a braille dot in the map, with bounds checking.
-    fn set_braille_dot(map: &mut HashMap<(usize, usize), u8>, bx: i32, by: i32, bw: i32, bh: i32) {
-        if bx < 0 || by < 0 || bx >= bw || by >= bh { return; }
-        let cx = bx as usize / 2;
-        let cy = by as usize / 4;
-        let sx = bx as usize % 2;
-        let sy = by as usize % 4;
-        *map.entry((cx, cy)).or_insert(0) |= Self::braille_bit(sx, sy);
-    }
-
-    /// Write a braille map layer onto the grid with a given color.
-    fn write_braille_layer(
-        grid: &mut [Vec<(char, Style)>],
-        map: &HashMap<(usize, usize), u8>,
-        w: usize, h: usize,
-        color: Color, bg: Color, bold: bool,
-    ) {
-        for (&(cx, cy), &bits) in map {
-            if cx < w && cy < h && bits != 0 {
-                let ch = char::from_u32(0x2800 + bits as u32).unwrap_or(' ');
-                let mut style = Style::default().fg(color).bg(bg);
-                if bold { style = style.add_modifier(Modifier::BOLD); }
-                // Merge with existing braille in this cell
-                let existing = &grid[cy][cx];
-                if existing.0 as u32 >= 0x2800 && (existing.0 as u32) < 0x2900 {
-                    // Same-color merge: combine bits
-                    let old_bits = existing.0 as u32 - 0x2800;
-                    let merged = old_bits as u8 | bits;
-                    let merged_ch = char::from_u32(0x2800 + merged as u32).unwrap_or(ch);
-                    grid[cy][cx] = (merged_ch, style);
-                } else {
-                    grid[cy][cx] = (ch, style);
-                }
-            }
-        }
-    }
-
     /// Ship triangle: nose, left wing, notch, right wing.
-    fn ship_points(&self) -> [(f32, f32); 4] {
-        let a = self.ship_angle;
+    fn ship_points(x: f32, y: f32, a: f32) -> [(f32, f32); 4] {
         let nose_len = 3.0;
         let wing_len = 2.2;
         let notch_len = 1.2;
         let wing_angle = 2.5;
         [
-            (self.ship_x + a.cos() * nose_len,
-             self.ship_y + a.sin() * nose_len),
-            (self.ship_x + (a + std::f32::consts::PI - wing_angle / 2.0).cos() * wing_len,
-             self.ship_y + (a + std::f32::consts::PI - wing_angle / 2.0).sin() * wing_len),
-            (self.ship_x + (a + std::f32::consts::PI).cos() * notch_len,
-             self.ship_y + (a + std::f32::consts::PI).sin() * notch_len),
-            (self.ship_x + (a + std::f32::consts::PI + wing_angle / 2.0).cos() * wing_len,
-             self.ship_y + (a + std::f32::consts::PI + wing_angle / 2.0).sin() * wing_len),
+            (x + a.cos() * nose_len,
+             y + a.sin() * nose_len),
+            (x + (a + std::f32::consts::PI - wing_angle / 2.0).cos() * wing_len,
+             y + (a + std::f32::consts::PI - wing_angle / 2.0).sin() * wing_len),
+            (x + (a + std::f32::consts::PI).cos() * notch_len,
+             y + (a + std::f32::consts::PI).sin() * notch_len),
+            (x + (a + std::f32::consts::PI + wing_angle / 2.0).cos() * wing_len,
+             y + (a + std::f32::consts::PI + wing_angle / 2.0).sin() * wing_len),
         ]
     }
 
+    /// Blend from the previous update's position toward the current one.
+    /// Skips blending across a screen wrap so objects don't streak sideways.
+    fn lerp_pos(&self, prev_x: f32, prev_y: f32, x: f32, y: f32) -> (f32, f32) {
+        let t = self.render_alpha;
+        if (x - prev_x).abs() > self.field_width / 2.0 || (y - prev_y).abs() > self.field_height / 2.0 {
+            return (x, y);
+        }
+        (prev_x + (x - prev_x) * t, prev_y + (y - prev_y) * t)
+    }
+
+    fn snapshot_positions(&mut self) {
+        self.prev_ship_x = self.ship_x;
+        self.prev_ship_y = self.ship_y;
+        self.prev_ship_angle = self.ship_angle;
+        for a in &mut self.asteroids {
+            a.prev_x = a.x;
+            a.prev_y = a.y;
+            a.prev_angle = a.angle;
+        }
+        for b in &mut self.bullets {
+            b.prev_x = b.x;
+            b.prev_y = b.y;
+        }
+    }
+
+    /// Stretch the field to `fw` × `fh`, moving everything on it in proportion.
+    fn rescale(&mut self, fw: f32, fh: f32) {
+        if (fw - self.field_width).abs() <= 1.0 && (fh - self.field_height).abs() <= 1.0 { return; }
+        let ratio_x = fw / self.field_width;
+        let ratio_y = fh / self.field_height;
+        self.ship_x *= ratio_x;
+        self.ship_y *= ratio_y;
+        for a in &mut self.asteroids {
+            a.x *= ratio_x;
+            a.y *= ratio_y;
+        }
+        for b in &mut self.bullets {
+            b.x *= ratio_x;
+            b.y *= ratio_y;
+        }
+        self.snapshot_positions();
+        self.field_width = fw;
+        self.field_height = fh;
+    }
+
     // ── Main render ────────────────────────────────────────────────────
 
-    fn render_field(&self, width: usize, height: usize) -> Vec<Line<'static>> {
+    fn render_field(&self, width: usize, height: usize, theme: &Theme) -> Vec<Line<'static>> {
         let w = width;
         let h = height;
         let bw = (w * 2) as i32;
@@ -483,26 +696,20 @@ impl Asteroids {
         let bsx = bw as f32 / self.field_width;
         let bsy = bh as f32 / self.field_height;
 
-        let bg = Color::Rgb(5, 5, 15);
+        let bg = theme.paint(Color::Rgb(5, 5, 15), Role::Background);
         let mut grid: Vec<Vec<(char, Style)>> =
             vec![vec![(' ', Style::default().bg(bg)); w]; h];
 
         // Sparse background stars (regular chars, not braille)
-        for yi in 0..h {
-            for xi in 0..w {
-                let hash = ((xi * 7 + yi * 13 + 37) * 31) % 250;
-                if hash < 2 {
-                    let b = 35 + (hash as u8) * 15;
-                    grid[yi][xi] = ('.', Style::default().fg(Color::Rgb(b, b, b + 8)).bg(bg));
-                }
-            }
-        }
+        STARS.draw(&mut grid, bg, if self.reduced_motion { 0 } else { self.tick }, theme);
 
         // ── Asteroids (braille polygons) ───────────────────────────────
         for asteroid in &self.asteroids {
-            let verts = Self::asteroid_verts(asteroid.x, asteroid.y, asteroid.size, asteroid.shape_seed);
+            let (ax, ay) = self.lerp_pos(asteroid.prev_x, asteroid.prev_y, asteroid.x, asteroid.y);
+            let rotation = asteroid.prev_angle + (asteroid.angle - asteroid.prev_angle) * self.render_alpha;
+            let verts = Self::asteroid_verts(ax, ay, asteroid.size, asteroid.shape_seed, rotation);
             let color = asteroid.size.color(asteroid.shape_seed);
-            let mut amap: HashMap<(usize, usize), u8> = HashMap::new();
+            let mut outline = BrailleCanvas::new(w, h);
 
             // Draw polygon outline
             let n = verts.len();
@@ -513,47 +720,51 @@ impl Asteroids {
                 let by0 = (y0 * bsy) as i32;
                 let bx1 = (x1 * bsx) as i32;
                 let by1 = (y1 * bsy) as i32;
-                for (px, py) in Self::line_cells(bx0, by0, bx1, by1) {
-                    Self::set_braille_dot(&mut amap, px, py, bw, bh);
-                }
+                outline.line(bx0, by0, bx1, by1);
             }
 
-            Self::write_braille_layer(&mut grid, &amap, w, h, color, bg, false);
+            outline.draw(&mut grid, theme.ink(color), None, false);
         }
 
         // ── Bullets (braille dots with short trail) ────────────────────
         for bullet in &self.bullets {
-            let mut bmap: HashMap<(usize, usize), u8> = HashMap::new();
+            let mut dots = BrailleCanvas::new(w, h);
             let brightness = if bullet.life > BULLET_LIFETIME / 2 { 255 } else { 180 };
-            let color = Color::Rgb(brightness, brightness, 80);
+            let color = theme.shade(Color::Rgb(brightness, brightness, 80), Role::Yellow, brightness as f32 / 255.0);
 
             // Head dot (2x2 braille pixels for visibility)
-            let bx = (bullet.x * bsx) as i32;
-            let by = (bullet.y * bsy) as i32;
+            let (hx, hy) = self.lerp_pos(bullet.prev_x, bullet.prev_y, bullet.x, bullet.y);
+            let bx = (hx * bsx) as i32;
+            let by = (hy * bsy) as i32;
             for &(dx, dy) in &[(0, 0), (1, 0), (0, 1), (1, 1)] {
-                Self::set_braille_dot(&mut bmap, bx + dx, by + dy, bw, bh);
+                dots.set(bx + dx, by + dy);
             }
 
             // Trail dot
-            let tx = ((bullet.x - bullet.vx * 1.5) * bsx) as i32;
-            let ty = ((bullet.y - bullet.vy * 1.5) * bsy) as i32;
-            Self::set_braille_dot(&mut bmap, tx, ty, bw, bh);
+            let tx = ((hx - bullet.vx * 1.5) * bsx) as i32;
+            let ty = ((hy - bullet.vy * 1.5) * bsy) as i32;
+            dots.set(tx, ty);
 
-            Self::write_braille_layer(&mut grid, &bmap, w, h, color, bg, true);
+            dots.draw(&mut grid, color, None, true);
         }
 
+        // ── Particles (sparks and debris, fading out) ─────────────────
+        self.particles.draw(&mut grid, (bsx, bsy), self.render_alpha, theme);
+
         // ── Ship (braille triangle) ────────────────────────────────────
         if !self.game_over {
-            let visible = self.invuln_timer == 0 || (self.tick % 4) < 2;
+            let visible = self.invuln_timer == 0 || fx::blink(self.tick, 4);
             if visible {
-                let pts = self.ship_points();
+                let (sx, sy) = self.lerp_pos(self.prev_ship_x, self.prev_ship_y, self.ship_x, self.ship_y);
+                let sa = self.prev_ship_angle + (self.ship_angle - self.prev_ship_angle) * self.render_alpha;
+                let pts = Self::ship_points(sx, sy, sa);
                 let ship_color = if self.thrusting {
-                    Color::Rgb(100, 230, 255)
+                    theme.paint(Color::Rgb(100, 230, 255), Role::Cyan)
                 } else {
-                    Color::Rgb(80, 255, 140)
+                    theme.paint(Color::Rgb(80, 255, 140), Role::Green)
                 };
 
-                let mut smap: HashMap<(usize, usize), u8> = HashMap::new();
+                let mut hull = BrailleCanvas::new(w, h);
                 let to_bp = |fx: f32, fy: f32| -> (i32, i32) {
                     ((fx * bsx) as i32, (fy * bsy) as i32)
                 };
@@ -562,45 +773,50 @@ impl Asteroids {
                 // Outline: nose->left->notch->right->nose
                 let edges = [(0,1), (1,2), (2,3), (3,0)];
                 for &(a, b) in &edges {
-                    for (px, py) in Self::line_cells(bp[a].0, bp[a].1, bp[b].0, bp[b].1) {
-                        Self::set_braille_dot(&mut smap, px, py, bw, bh);
-                    }
+                    hull.line(bp[a].0, bp[a].1, bp[b].0, bp[b].1);
                 }
 
-                Self::write_braille_layer(&mut grid, &smap, w, h, ship_color, bg, true);
+                hull.draw(&mut grid, ship_color, None, true);
 
                 // Thrust flame
                 if self.thrusting {
-                    let mut fmap: HashMap<(usize, usize), u8> = HashMap::new();
-                    let fa = self.ship_angle + std::f32::consts::PI;
+                    let mut flame = BrailleCanvas::new(w, h);
+                    let fa = sa + std::f32::consts::PI;
                     for i in 0..10 {
                         let dist = 2.0 + i as f32 * 0.5;
                         let spread = (i as f32 * 0.18) * if i % 2 == 0 { 1.0 } else { -1.0 };
-                        let fx = self.ship_x + (fa + spread).cos() * dist;
-                        let fy = self.ship_y + (fa + spread).sin() * dist;
+                        let fx = sx + (fa + spread).cos() * dist;
+                        let fy = sy + (fa + spread).sin() * dist;
                         let fbx = (fx * bsx) as i32;
                         let fby = (fy * bsy) as i32;
-                        Self::set_braille_dot(&mut fmap, fbx, fby, bw, bh);
+                        flame.set(fbx, fby);
                         // Extra width dot
                         let perp = fa + std::f32::consts::FRAC_PI_2;
                         let px2 = fx + perp.cos() * 0.3;
                         let py2 = fy + perp.sin() * 0.3;
-                        Self::set_braille_dot(&mut fmap, (px2 * bsx) as i32, (py2 * bsy) as i32, bw, bh);
+                        flame.set((px2 * bsx) as i32, (py2 * bsy) as i32);
                     }
                     let flicker = if self.tick % 3 == 0 {
-                        Color::Rgb(255, 200, 60)
+                        theme.paint(Color::Rgb(255, 200, 60), Role::Yellow)
                     } else {
-                        Color::Rgb(255, 130, 30)
+                        theme.paint(Color::Rgb(255, 130, 30), Role::Orange)
                     };
                     // Don't overwrite ship cells
-                    for key in smap.keys() {
-                        fmap.remove(key);
-                    }
-                    Self::write_braille_layer(&mut grid, &fmap, w, h, flicker, bg, false);
+                    flame.mask(&hull);
+                    flame.draw(&mut grid, flicker, None, false);
                 }
             }
         }
 
+        if self.show_hitboxes {
+            self.draw_hitboxes(&mut grid, bsx, bsy, theme);
+        }
+
+        let (ox, oy) = self.shake_offset();
+        if (ox, oy) != (0, 0) {
+            grid = Self::shift_grid(&grid, ox, oy, Style::default().bg(bg));
+        }
+
         grid.into_iter()
             .map(|row| {
                 let spans: Vec<Span<'static>> = row
@@ -613,9 +829,55 @@ impl Asteroids {
     }
 }
 
+impl Asteroids {
+    /// Collision circles from `check_collisions`: bullets are points inside
+    /// a rock's radius, the ship a circle of `SHIP_RADIUS`.
+    fn draw_hitboxes(&self, grid: &mut [Vec<(char, Style)>], bsx: f32, bsy: f32, theme: &Theme) {
+        let cols = grid.first().map_or(0, |row| row.len());
+        let mut circles = BrailleCanvas::new(cols, grid.len());
+        for asteroid in &self.asteroids {
+            let (x, y) = self.lerp_pos(asteroid.prev_x, asteroid.prev_y, asteroid.x, asteroid.y);
+            let r = asteroid.size.radius();
+            circles.ellipse(x * bsx, y * bsy, r * bsx, r * bsy);
+        }
+        if !self.game_over {
+            let (x, y) = self.lerp_pos(self.prev_ship_x, self.prev_ship_y, self.ship_x, self.ship_y);
+            circles.ellipse(x * bsx, y * bsy, SHIP_RADIUS * bsx, SHIP_RADIUS * bsy);
+        }
+        circles.draw(grid, theme.ink(fx::HITBOX), None, false);
+    }
+}
+
+pub const INFO: GameInfo = GameInfo {
+    name: "Asteroids",
+    icon: "☄",
+    author: "RustCade",
+    blurb: "Shoot rocks\nin deep space!",
+    description: "Blast asteroids in deep space!",
+    controls: &[
+        ("← / →", "Rotate ship"),
+        ("↑", "Thrust"),
+        ("Space", "Shoot"),
+        ("R", "Restart"),
+        ("P", "Pause"),
+    ],
+    tags: &["arcade", "shooter", "physics", "retro"],
+    color: Ink(Color::Rgb(100, 200, 255), Role::Blue),
+    border_color: Ink(Color::Rgb(50, 100, 140), Role::Blue),
+};
+
 impl Game for Asteroids {
+    fn info(&self) -> &'static GameInfo {
+        &INFO
+    }
+
     fn update(&mut self) {
-        if self.game_over || self.paused {
+        self.snapshot_positions();
+        if !self.paused {
+            // Debris keeps flying after the last life is lost
+            self.update_effects();
+        }
+        if self.game_over || self.paused || self.countdown.tick() {
             self.thrusting = false;
             self.rotating_left = false;
             self.rotating_right = false;
@@ -638,7 +900,7 @@ impl Game for Asteroids {
             KeyCode::Char('r') | KeyCode::Char('R') => self.reset(),
             KeyCode::Char('p') | KeyCode::Char('P') => {
                 if !self.game_over {
-                    self.paused = !self.paused;
+                    self.countdown.toggle_pause(&mut self.paused);
                 }
             }
             _ => {
@@ -648,7 +910,7 @@ impl Game for Asteroids {
                     }
                     return;
                 }
-                if self.paused { return; }
+                if self.paused || self.countdown.is_running() { return; }
                 match key.code {
                     KeyCode::Left => self.rotating_left = true,
                     KeyCode::Right => self.rotating_right = true,
@@ -660,36 +922,26 @@ impl Game for Asteroids {
         }
     }
 
-    fn render(&mut self, frame: &mut Frame, area: Rect) {
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Color::Rgb(100, 200, 255)))
-            .title(" Asteroids ")
-            .title_style(Style::default().fg(Color::Rgb(130, 220, 255)).add_modifier(Modifier::BOLD));
+    fn on_resize(&mut self, area: Rect) {
+        // Inside the title bar's border
+        let inner = Block::bordered().inner(area);
+        // Below the status and help lines; too small to play in, keep the old field
+        let (fw, fh) = (inner.width, inner.height.saturating_sub(2));
+        if fw < 10 || fh < 4 { return; }
+        self.rescale(fw as f32, fh as f32);
+    }
+
+    fn reseed(&mut self, rng: GameRng) {
+        self.rng = rng;
+        self.reset();
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = self.title_bar(theme);
 
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
-        let new_fw = inner.width as f32;
-        let new_fh = (inner.height.saturating_sub(2)) as f32;
-        if (new_fw - self.field_width).abs() > 1.0 || (new_fh - self.field_height).abs() > 1.0 {
-            let ratio_x = new_fw / self.field_width;
-            let ratio_y = new_fh / self.field_height;
-            self.ship_x *= ratio_x;
-            self.ship_y *= ratio_y;
-            for a in &mut self.asteroids {
-                a.x *= ratio_x;
-                a.y *= ratio_y;
-            }
-            for b in &mut self.bullets {
-                b.x *= ratio_x;
-                b.y *= ratio_y;
-            }
-            self.field_width = new_fw;
-            self.field_height = new_fh;
-        }
-
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
@@ -705,27 +957,27 @@ impl Game for Asteroids {
             Span::styled(" \u{2604} ", Style::default()),
             Span::styled(
                 format!("Score: {} ", self.score),
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" | ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" | ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             Span::styled(
                 format!("Lives: {}", lives_str),
-                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme.paint(Color::Red, Role::Red)).add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" | ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" | ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             Span::styled(
                 format!("High: {} ", self.high_score),
-                Style::default().fg(Color::Cyan),
+                Style::default().fg(theme.paint(Color::Cyan, Role::Cyan)),
             ),
-            Span::styled(" | ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" | ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             Span::styled(
                 format!("Level: {} ", self.level),
-                Style::default().fg(Color::Green),
+                Style::default().fg(theme.paint(Color::Green, Role::Green)),
             ),
-            Span::styled(" | ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" | ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             Span::styled(
                 format!("Rocks: {} ", self.asteroids.len()),
-                Style::default().fg(Color::Rgb(160, 140, 120)),
+                Style::default().fg(theme.paint(Color::Rgb(160, 140, 120), Role::Dim)),
             ),
         ]);
         frame.render_widget(Paragraph::new(status), chunks[0]);
@@ -734,54 +986,174 @@ impl Game for Asteroids {
         let fw = chunks[1].width as usize;
         let fh = chunks[1].height as usize;
         if fw > 0 && fh > 0 {
-            let lines = self.render_field(fw, fh);
+            let lines = self.render_field(fw, fh, theme);
             frame.render_widget(Paragraph::new(lines), chunks[1]);
         }
+        self.countdown.render(frame, chunks[1], theme);
+        if let Some(toast) = self.toast.as_ref().filter(|t| !t.expired()) {
+            toast.render(frame, chunks[1], theme);
+        }
 
         // Help bar
         if self.game_over {
             let msg = Paragraph::new(Line::from(vec![
-                Span::styled(" GAME OVER! ", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
-                Span::styled("Press ENTER to restart, Esc for menu", Style::default().fg(Color::Gray)),
+                Span::styled(" GAME OVER! ", Style::default().fg(theme.ink(fx::alert_color())).add_modifier(Modifier::BOLD)),
+                Span::styled(
+                    format!("Ships lost: {} | Accuracy: {}% | Press ENTER to restart, Esc for menu", self.lives_lost, self.accuracy()),
+                    Style::default().fg(theme.paint(Color::Gray, Role::Dim)),
+                ),
             ]));
             frame.render_widget(msg, chunks[2]);
         } else if self.paused {
             let msg = Paragraph::new(Line::from(vec![
-                Span::styled(" PAUSED - Press P to resume ", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                Span::styled(" PAUSED - Press P to resume ", Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD)),
             ]));
             frame.render_widget(msg, chunks[2]);
         } else {
             let help = Paragraph::new(Line::from(vec![
-                Span::styled(" \u{2190}\u{2192} Rotate ", Style::default().fg(Color::DarkGray)),
-                Span::styled("| ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("\u{2191} Thrust ", Style::default().fg(Color::DarkGray)),
-                Span::styled("| ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("Space Shoot ", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
-                Span::styled("| ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("P Pause ", Style::default().fg(Color::DarkGray)),
-                Span::styled("| ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("R Restart ", Style::default().fg(Color::DarkGray)),
-                Span::styled("| ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("Esc Menu ", Style::default().fg(Color::DarkGray)),
-                Span::styled("| ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("? Help", Style::default().fg(Color::DarkGray)),
+                Span::styled(" \u{2190}\u{2192} Rotate ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                Span::styled("| ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint))),
+                Span::styled("\u{2191} Thrust ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                Span::styled("| ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint))),
+                Span::styled("Space Shoot ", Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD)),
+                Span::styled("| ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint))),
+                Span::styled("P Pause ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                Span::styled("| ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint))),
+                Span::styled("R Restart ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                Span::styled("| ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint))),
+                Span::styled("Esc Menu ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                Span::styled("| ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint))),
+                Span::styled("? Help", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             ]));
             frame.render_widget(help, chunks[2]);
         }
     }
 
+    fn render_interpolated(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, alpha: f32) {
+        self.render_alpha = alpha.clamp(0.0, 1.0);
+        self.render(frame, area, theme);
+        self.flash.draw(frame.buffer_mut(), area, theme);
+    }
+
     fn get_score(&self) -> u32 { self.score }
     fn is_game_over(&self) -> bool { self.game_over }
+    fn is_paused(&self) -> bool { self.paused }
+    fn set_hitboxes(&mut self, on: bool) { self.show_hitboxes = on; }
+
+    fn serialize_state(&self) -> Option<Vec<u8>> {
+        if self.game_over { return None; }
+        let mut w = StateWriter::new();
+        for v in [self.field_width, self.field_height, self.ship_x, self.ship_y, self.ship_vx, self.ship_vy, self.ship_angle] {
+            w.f32(v);
+        }
+        w.u64(self.invuln_timer);
+        w.u32(self.score);
+        w.u32(self.high_score);
+        w.u32(self.lives);
+        w.u32(self.level);
+        w.u64(self.tick);
+        w.u64(self.rng.state());
+        w.u32(self.asteroids.len() as u32);
+        for a in &self.asteroids {
+            for v in [a.x, a.y, a.vx, a.vy, a.angle, a.spin] {
+                w.f32(v);
+            }
+            w.u8(a.size as u8);
+            w.u8(a.shape_seed);
+        }
+        w.u32(self.bullets.len() as u32);
+        for b in &self.bullets {
+            for v in [b.x, b.y, b.vx, b.vy] {
+                w.f32(v);
+            }
+            w.u64(b.life);
+        }
+        w.u32(self.lives_lost);
+        w.u32(self.shots_fired);
+        w.u32(self.shots_hit);
+        Some(w.finish())
+    }
+
+    fn restore_state(&mut self, data: &[u8]) -> bool {
+        let mut r = StateReader::new(data);
+        let mut f = [0.0f32; 7];
+        for v in &mut f {
+            let Some(x) = r.f32() else { return false };
+            *v = x;
+        }
+        let [fw, fh, ship_x, ship_y, ship_vx, ship_vy, ship_angle] = f;
+        let (Some(invuln), Some(score), Some(high_score), Some(lives), Some(level), Some(tick), Some(rng_state), Some(count)) =
+            (r.u64(), r.u32(), r.u32(), r.u32(), r.u32(), r.u64(), r.u64(), r.u32())
+        else { return false };
+        if !(fw >= 1.0 && fh >= 1.0) || lives == 0 || count > 512 { return false; }
+        let mut asteroids = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let (Some(x), Some(y), Some(vx), Some(vy), Some(angle), Some(spin), Some(size), Some(shape_seed)) =
+                (r.f32(), r.f32(), r.f32(), r.f32(), r.f32(), r.f32(), r.u8(), r.u8())
+            else { return false };
+            let size = match size {
+                0 => AsteroidSize::Large,
+                1 => AsteroidSize::Medium,
+                _ => AsteroidSize::Small,
+            };
+            asteroids.push(Asteroid { x, y, prev_x: x, prev_y: y, vx, vy, angle, prev_angle: angle, spin, size, shape_seed });
+        }
+        let Some(count) = r.u32() else { return false };
+        if count as usize > MAX_BULLETS { return false; }
+        let mut bullets = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let (Some(x), Some(y), Some(vx), Some(vy), Some(life)) = (r.f32(), r.f32(), r.f32(), r.f32(), r.u64()) else { return false };
+            bullets.push(Bullet { x, y, prev_x: x, prev_y: y, vx, vy, life });
+        }
+        // Snapshots from before run stats start them from here
+        let (lives_lost, shots_fired, shots_hit) = (r.u32().unwrap_or(0), r.u32().unwrap_or(0), r.u32().unwrap_or(0));
+
+        // Saved at the terminal size of the time; scaled to this one below
+        let size = (self.field_width, self.field_height);
+        self.reset();
+        self.field_width = fw;
+        self.field_height = fh;
+        self.ship_x = ship_x;
+        self.ship_y = ship_y;
+        self.ship_vx = ship_vx;
+        self.ship_vy = ship_vy;
+        self.ship_angle = ship_angle;
+        self.invuln_timer = invuln;
+        self.score = score;
+        self.high_score = self.high_score.max(high_score);
+        self.lives = lives;
+        self.level = level;
+        self.tick = tick;
+        self.rng = GameRng::new(rng_state);
+        self.asteroids = asteroids;
+        self.bullets = bullets;
+        self.lives_lost = lives_lost;
+        self.shots_fired = shots_fired;
+        self.shots_hit = shots_hit.min(shots_fired);
+        self.set_extra_life(self.extra_life_every);
+        self.snapshot_positions();
+        self.rescale(size.0, size.1);
+        // Resume paused, with a countdown before the rocks move again
+        self.paused = true;
+        true
+    }
 
     fn reset(&mut self) {
         let hs = self.high_score;
         let fw = self.field_width;
         let fh = self.field_height;
-        *self = Asteroids::new();
+        let reduced_motion = self.reduced_motion;
+        let show_hitboxes = self.show_hitboxes;
+        let extra_life = self.extra_life_every;
+        *self = Asteroids::with_rng(self.rng.clone());
+        self.set_extra_life(extra_life);
         self.high_score = hs;
+        self.reduced_motion = reduced_motion;
+        self.show_hitboxes = show_hitboxes;
         self.field_width = fw;
         self.field_height = fh;
         self.ship_x = fw / 2.0;
         self.ship_y = fh / 2.0;
+        self.snapshot_positions();
     }
 }
@@ -1,9 +1,17 @@
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use rand::Rng;
+use std::time::{Duration, Instant};
 use ratatui::prelude::*;
 use ratatui::widgets::*;
 
-use crate::games::Game;
+use crate::games::beam_challenge::{Challenge, Restriction};
+use crate::games::beam_compare::{Comparison, MagnetDiff, PlaneMap, Prediction, SavedConfig};
+use crate::games::beam_hint::Hint;
+use crate::games::{Game, GameInfo};
+use crate::rng::GameRng;
+use crate::scores::format_score;
+use crate::ui::fx;
+use crate::ui::theme::{Ink, Role, Theme};
 
 #[derive(Clone, Copy, PartialEq)]
 #[allow(dead_code)]
@@ -21,10 +29,10 @@ impl BumpAxis {
         }
     }
 
-    fn color(&self) -> Color {
+    fn color(&self) -> Ink {
         match self {
-            BumpAxis::X => Color::Rgb(255, 180, 120),
-            BumpAxis::Y => Color::Rgb(200, 120, 255),
+            BumpAxis::X => Ink(Color::Rgb(255, 180, 120), Role::Orange),
+            BumpAxis::Y => Ink(Color::Rgb(200, 120, 255), Role::Violet),
         }
     }
 
@@ -103,23 +111,12 @@ impl BumpConfig {
     }
 }
 
-#[derive(Clone)]
-struct Restriction {
-    section: usize,       // which section (0-based)
-    axis: char,           // 'x' or 'y'
-    positive_blocked: bool, // true = blocks positive side (val > 0), false = blocks negative (val < 0)
-}
-
-impl Restriction {
-    fn label(&self) -> String {
-        let sign = if self.positive_blocked { "≤0" } else { "≥0" };
-        format!("{}{}",self.axis, sign)
-    }
-
-    fn check(&self, x: f32, y: f32) -> bool {
-        let val = if self.axis == 'x' { x } else { y };
-        if self.positive_blocked { val > 0.0 } else { val < 0.0 }
-    }
+/// Text being typed into the help bar
+#[derive(Clone, Copy, PartialEq)]
+enum TextInput {
+    ChallengeName,
+    ShareCode,
+    ConfigName,
 }
 
 #[derive(Clone, Copy, PartialEq)]
@@ -136,10 +133,10 @@ impl Difficulty {
         }
     }
 
-    fn color(&self) -> Color {
+    fn color(&self) -> Ink {
         match self {
-            Difficulty::Easy => Color::Rgb(80, 255, 80),
-            Difficulty::Hard => Color::Rgb(255, 60, 60),
+            Difficulty::Easy => Ink(Color::Rgb(80, 255, 80), Role::Green),
+            Difficulty::Hard => Ink(Color::Rgb(255, 60, 60), Role::Red),
         }
     }
 
@@ -171,6 +168,56 @@ const GOAL_TURNS: u32 = 5;
 const MAX_HISTORY: usize = 60;
 const NUM_RAMPS: usize = 10;
 const MAX_RAMP_DELTA: f32 = 0.5;
+// Time attack: GOAL_TURNS consecutive turns ending within this distance of the target
+const TA_TOLERANCE: f32 = 2.0;
+const TIME_ATTACK_TABLE: usize = 8; // "Beam TA" in the score tables
+// Longest gap counted between two updates (e.g. after switching tabs away)
+const TA_MAX_FRAME: Duration = Duration::from_millis(100);
+// Fault mode: bonus for finding the fault, less for each wrong flag
+const DIAGNOSIS_BONUS: u32 = 250;
+const WRONG_FLAG_PENALTY: u32 = 75;
+const MAX_WRONG_FLAGS: usize = 3;
+// Economy: the score for a ramp that spends no more power than the bend needs
+const ECONOMY_POINTS: f32 = 1000.0;
+
+/// A hidden wiring fault for the player to find from the orbit (fault mode).
+#[derive(Clone, Copy, PartialEq)]
+enum Fault {
+    /// This magnet's supply is connected with the polarity reversed
+    Reversed(usize),
+    /// The section's VT and HT cables are swapped at the supplies
+    SwappedTrims(usize),
+}
+
+impl Fault {
+    fn random(rng: &mut impl Rng) -> Self {
+        let section = rng.gen_range(0..NUM_SECTIONS);
+        // A reversed dipole would lose the beam at once; quads and trims are the puzzle
+        match rng.gen_range(0..5) {
+            0 => Fault::SwappedTrims(section),
+            n => Fault::Reversed(section * MAGNETS_PER_SECTION + [0, 2, 4, 5][n - 1]),
+        }
+    }
+
+    /// Whether flagging this magnet finds the fault.
+    fn implicates(&self, magnet: usize) -> bool {
+        match *self {
+            Fault::Reversed(m) => m == magnet,
+            Fault::SwappedTrims(sec) => magnet / MAGNETS_PER_SECTION == sec && magnet % MAGNETS_PER_SECTION >= 4,
+        }
+    }
+
+    fn describe(&self, magnets: &[Magnet]) -> String {
+        match *self {
+            Fault::Reversed(m) => format!(
+                "{} in sec {} had reversed polarity",
+                magnets[m].mag_type.label(),
+                m / MAGNETS_PER_SECTION + 1,
+            ),
+            Fault::SwappedTrims(sec) => format!("VT/HT cables swapped in sec {}", sec + 1),
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq)]
 enum MagnetType {
@@ -194,16 +241,40 @@ impl MagnetType {
         }
     }
 
-    fn color(&self) -> Color {
+    fn color(&self) -> Ink {
         match self {
-            MagnetType::FocusQuad => Color::Rgb(80, 180, 255),
-            MagnetType::Dipole1 => Color::Rgb(255, 120, 80),
-            MagnetType::DefocusQuad => Color::Rgb(80, 255, 140),
-            MagnetType::Dipole2 => Color::Rgb(255, 200, 80),
-            MagnetType::VTrim => Color::Rgb(200, 120, 255),
-            MagnetType::HTrim => Color::Rgb(255, 180, 120),
+            MagnetType::FocusQuad => Ink(Color::Rgb(80, 180, 255), Role::Blue),
+            MagnetType::Dipole1 => Ink(Color::Rgb(255, 120, 80), Role::Red),
+            MagnetType::DefocusQuad => Ink(Color::Rgb(80, 255, 140), Role::Green),
+            MagnetType::Dipole2 => Ink(Color::Rgb(255, 200, 80), Role::Orange),
+            MagnetType::VTrim => Ink(Color::Rgb(200, 120, 255), Role::Violet),
+            MagnetType::HTrim => Ink(Color::Rgb(255, 180, 120), Role::Orange),
         }
     }
+
+    /// What this magnet at `power` does to (position, angle) in x and y,
+    /// including the drift to the next element.
+    fn maps(&self, power: f32) -> [PlaneMap; 2] {
+        let [x, y] = match self {
+            // Thin lens focusing in X, defocusing in Y
+            MagnetType::FocusQuad => [PlaneMap::lens(-power), PlaneMap::lens(power)],
+            // Dipole must supply the design bend to keep beam on circular orbit.
+            // The beam "wants" to go straight; only the dipole bending force curves it.
+            // Error from design = how much the orbit deviates from the ideal circle.
+            // Y gets a small drift from its own angle
+            MagnetType::Dipole1 | MagnetType::Dipole2 => [
+                PlaneMap::kick(power - DESIGN_DIPOLE_POWER).then(&PlaneMap::drift(2.0)),
+                PlaneMap::drift(0.5),
+            ],
+            // Thin lens defocusing in X, focusing in Y
+            MagnetType::DefocusQuad => [PlaneMap::lens(power), PlaneMap::lens(-power)],
+            // Trim dipoles kick the angle, then drift from the kick
+            MagnetType::VTrim => [PlaneMap::IDENTITY, PlaneMap::kick(power).then(&PlaneMap::drift(1.0))],
+            MagnetType::HTrim => [PlaneMap::kick(power).then(&PlaneMap::drift(1.0)), PlaneMap::IDENTITY],
+        };
+        // Small drift between elements
+        [x.then(&PlaneMap::drift(0.5)), y.then(&PlaneMap::drift(0.3))]
+    }
 }
 
 #[derive(Clone)]
@@ -213,6 +284,15 @@ struct Magnet {
     _section: usize,  // which section (0-23)
 }
 
+/// Where the first turn would go at ramp point 0, traced
+/// before injecting.
+struct Preview {
+    /// Beam (x, y) after every element, up to where it's lost
+    path: Vec<(f32, f32)>,
+    /// Section it's lost in and what stops it
+    lost: Option<(usize, String)>,
+}
+
 pub struct BeamGame {
     magnets: Vec<Magnet>,
     selected: usize,           // currently selected magnet index
@@ -246,7 +326,7 @@ pub struct BeamGame {
     // Difficulty
     difficulty: Difficulty,
     // Message flash
-    message: Option<(String, u32, Color)>, // (text, ticks_remaining, color)
+    message: Option<(String, u32, Ink)>, // (text, ticks_remaining, color)
     // Bump mode: closed orbit bump using N consecutive trim magnets
     bump: Option<BumpConfig>,
     // Power supply ramp: 10 settings per magnet, one per turn (keys 0-9)
@@ -256,10 +336,43 @@ pub struct BeamGame {
     target_x: f32,
     target_y: f32,
     turn_positions: Vec<(f32, f32)>, // beam (x, y) at end of each turn
+    // Economy: |power| summed over every magnet the beam passes, and the
+    // part of it the design bend can't do without
+    power_used: f32,
+    power_needed: f32,
+    // Challenges: restriction editor, loaded challenge name, pool cursor
+    editing: bool,
+    challenge_name: Option<String>,
+    pool_index: usize,
+    text_input: Option<(TextInput, String)>,
+    // Saved magnet configurations side by side (V)
+    compare: Option<Comparison>,
+    // Time attack: the clock runs from arming until the orbit is established
+    time_attack: bool,
+    ta_elapsed: Duration,
+    ta_last: Option<Instant>,
+    ta_streak: u32,            // consecutive turns within TA_TOLERANCE of the target
+    // Fault mode: one hidden wiring fault per run until it's flagged
+    faults: bool,
+    fault: Option<Fault>,
+    diagnosed: bool,
+    wrong_flags: Vec<usize>,   // magnets flagged that weren't at fault
+    // Hint mode (H): a closed orbit that clears the restrictions, ghosted in
+    show_hint: bool,
+    hint: Option<Hint>,
+    // Last rendered panel areas, for mouse hit-testing
+    ring_area: Rect,
+    magnet_area: Rect,
+    // Restriction layouts, targets and faults
+    rng: GameRng,
 }
 
 impl BeamGame {
     pub fn new() -> Self {
+        Self::with_rng(GameRng::from_entropy())
+    }
+
+    fn with_rng(mut rng: GameRng) -> Self {
         let mut magnets = Vec::new();
         for sec in 0..NUM_SECTIONS {
             // All magnets start at 0 - player must:
@@ -276,7 +389,6 @@ impl BeamGame {
         }
 
         // Generate 4 random restrictions: 2 horizontal, 2 vertical on distinct sections
-        let mut rng = rand::thread_rng();
         let mut restriction_sections: Vec<usize> = Vec::new();
         while restriction_sections.len() < 4 {
             let s = rng.gen_range(0..NUM_SECTIONS);
@@ -331,62 +443,55 @@ impl BeamGame {
             target_x: rng.gen_range(-5.0..5.0),
             target_y: rng.gen_range(-5.0..5.0),
             turn_positions: Vec::new(),
+            power_used: 0.0,
+            power_needed: 0.0,
+            editing: false,
+            challenge_name: None,
+            pool_index: 0,
+            text_input: None,
+            compare: None,
+            time_attack: false,
+            ta_elapsed: Duration::ZERO,
+            ta_last: None,
+            ta_streak: 0,
+            faults: false,
+            fault: None,
+            diagnosed: false,
+            wrong_flags: Vec::new(),
+            show_hint: false,
+            hint: None,
+            ring_area: Rect::default(),
+            magnet_area: Rect::default(),
+            rng,
         }
     }
 
     fn apply_element(&mut self) {
         let mag_idx = self.beam_section * MAGNETS_PER_SECTION + self.beam_element;
         if mag_idx >= self.magnets.len() { return; }
-        let magnet = &self.magnets[mag_idx];
+        let (mag_type, power) = self.wired(mag_idx);
+        self.power_used += power.abs();
+        if matches!(mag_type, MagnetType::Dipole1 | MagnetType::Dipole2) {
+            self.power_needed += DESIGN_DIPOLE_POWER;
+        }
+
+        let [x_map, y_map] = mag_type.maps(power);
+        (self.beam_position, self.beam_angle) = x_map.apply((self.beam_position, self.beam_angle));
+        (self.beam_y_position, self.beam_y_angle) = y_map.apply((self.beam_y_position, self.beam_y_angle));
 
-        match magnet.mag_type {
+        // Quads also squeeze the envelope in the plane they focus
+        match mag_type {
             MagnetType::FocusQuad => {
-                // Thin lens focusing in X: x' -= k*x, size decreases
-                let k = magnet.power;
-                self.beam_angle -= k * self.beam_position;
-                self.beam_size = (self.beam_size * (1.0 - k.abs() * 0.5)).max(1.0);
-                // Opposite in Y: defocusing
-                self.beam_y_angle += k * self.beam_y_position;
-                self.beam_y_size = (self.beam_y_size * (1.0 + k.abs() * 0.3)).min(APERTURE * 2.0);
-            }
-            MagnetType::Dipole1 | MagnetType::Dipole2 => {
-                // Dipole must supply the design bend to keep beam on circular orbit.
-                // The beam "wants" to go straight; only the dipole bending force curves it.
-                // Error from design = how much the orbit deviates from the ideal circle.
-                let bend_error = magnet.power - DESIGN_DIPOLE_POWER;
-                self.beam_angle += bend_error;
-                // Drift effect: position changes with angle
-                self.beam_position += self.beam_angle * 2.0;
-                // Y gets a small drift from its own angle
-                self.beam_y_position += self.beam_y_angle * 0.5;
+                self.beam_size = (self.beam_size * (1.0 - power.abs() * 0.5)).max(1.0);
+                self.beam_y_size = (self.beam_y_size * (1.0 + power.abs() * 0.3)).min(APERTURE * 2.0);
             }
             MagnetType::DefocusQuad => {
-                // Thin lens defocusing in X: x' += k*x, size increases
-                let k = magnet.power;
-                self.beam_angle += k * self.beam_position;
-                self.beam_size = (self.beam_size * (1.0 + k.abs() * 0.3)).min(APERTURE * 2.0);
-                // Opposite in Y: focusing
-                self.beam_y_angle -= k * self.beam_y_position;
-                self.beam_y_size = (self.beam_y_size * (1.0 - k.abs() * 0.5)).max(1.0);
-            }
-            MagnetType::VTrim => {
-                // Vertical trim dipole: kicks the vertical angle
-                self.beam_y_angle += magnet.power;
-                // Drift from the vertical kick
-                self.beam_y_position += self.beam_y_angle * 1.0;
-            }
-            MagnetType::HTrim => {
-                // Horizontal trim dipole: kicks the horizontal angle
-                self.beam_angle += magnet.power;
-                // Drift from the horizontal kick
-                self.beam_position += self.beam_angle * 1.0;
+                self.beam_size = (self.beam_size * (1.0 + power.abs() * 0.3)).min(APERTURE * 2.0);
+                self.beam_y_size = (self.beam_y_size * (1.0 - power.abs() * 0.5)).max(1.0);
             }
+            _ => {}
         }
 
-        // Small drift between elements
-        self.beam_position += self.beam_angle * 0.5;
-        self.beam_y_position += self.beam_y_angle * 0.3;
-
         // Phase instability: constant beam size growth in Hard mode
         let growth = self.difficulty.size_growth_rate();
         if growth > 0.0 {
@@ -395,6 +500,163 @@ impl BeamGame {
         }
     }
 
+    /// What the beam sees at a magnet: its type and power after any
+    /// undiagnosed wiring fault. A swapped trim acts in the other plane.
+    fn wired(&self, mag_idx: usize) -> (MagnetType, f32) {
+        let magnet = &self.magnets[mag_idx];
+        match self.fault.filter(|_| !self.diagnosed) {
+            Some(Fault::Reversed(m)) if m == mag_idx => (magnet.mag_type, -magnet.power),
+            Some(Fault::SwappedTrims(sec)) if mag_idx / MAGNETS_PER_SECTION == sec => match magnet.mag_type {
+                MagnetType::VTrim => (MagnetType::HTrim, magnet.power),
+                MagnetType::HTrim => (MagnetType::VTrim, magnet.power),
+                t => (t, magnet.power),
+            },
+            _ => (magnet.mag_type, magnet.power),
+        }
+    }
+
+    fn toggle_faults(&mut self) {
+        self.faults = !self.faults;
+        if self.faults {
+            self.arm_fault();
+            self.message = Some(("Fault mode: one magnet is miswired".to_string(), 60, Ink(Color::Rgb(255, 120, 80), Role::Red)));
+        } else {
+            self.fault = None;
+            self.message = Some(("Fault mode off".to_string(), 30, Ink(Color::Rgb(140, 140, 160), Role::Dim)));
+        }
+    }
+
+    fn arm_fault(&mut self) {
+        self.fault = Some(Fault::random(&mut self.rng));
+        self.diagnosed = false;
+        self.wrong_flags.clear();
+    }
+
+    /// Flag the selected magnet as the faulty one.
+    fn flag_selected(&mut self) {
+        let Some(fault) = self.fault else { return };
+        if self.diagnosed || self.wrong_flags.len() >= MAX_WRONG_FLAGS { return; }
+        if fault.implicates(self.selected) {
+            self.diagnosed = true;
+            self.message = Some((
+                format!("Diagnosed! {} (+{})", fault.describe(&self.magnets), self.diagnosis_bonus()),
+                90, Ink(Color::Rgb(80, 255, 80), Role::Green),
+            ));
+        } else if !self.wrong_flags.contains(&self.selected) {
+            self.wrong_flags.push(self.selected);
+            self.message = Some(if self.wrong_flags.len() >= MAX_WRONG_FLAGS {
+                (format!("Out of flags: {}", fault.describe(&self.magnets)), 90, Ink(Color::Rgb(255, 60, 60), Role::Red))
+            } else {
+                (
+                    format!("Not that one ({} flags left)", MAX_WRONG_FLAGS - self.wrong_flags.len()),
+                    45, Ink(Color::Rgb(255, 160, 50), Role::Orange),
+                )
+            });
+        }
+    }
+
+    /// Score for finding the fault, once it's been flagged.
+    fn diagnosis_bonus(&self) -> u32 {
+        if !self.diagnosed { return 0; }
+        DIAGNOSIS_BONUS.saturating_sub(WRONG_FLAG_PENALTY * self.wrong_flags.len() as u32)
+    }
+
+    fn toggle_hint(&mut self) {
+        self.show_hint = !self.show_hint;
+        self.refresh_hint();
+        self.message = Some(match &self.hint {
+            _ if !self.show_hint => ("Hint off".to_string(), 30, Ink(Color::Rgb(140, 140, 160), Role::Dim)),
+            Some(hint) if hint.feasible && hint.new_optics => {
+                ("Hint: an orbit exists, with new quad/dipole settings".to_string(), 90, Ink(Color::Rgb(120, 220, 160), Role::Green))
+            }
+            Some(hint) if hint.feasible => ("Hint: an orbit exists, trims shown".to_string(), 90, Ink(Color::Rgb(120, 220, 160), Role::Green)),
+            _ => ("Hint: no orbit clears every restriction".to_string(), 90, Ink(Color::Rgb(255, 120, 80), Role::Red)),
+        });
+    }
+
+    /// Work the hint out again if the magnets, restrictions or target moved.
+    fn refresh_hint(&mut self) {
+        if !self.show_hint { return; }
+        let powers: Vec<f32> = self.magnets.iter().map(|m| m.power).collect();
+        let target = (self.target_x, self.target_y);
+        if self.hint.as_ref().is_some_and(|h| h.is_for(&powers, &self.restrictions, target)) { return; }
+        let trims = |t: MagnetType| -> Vec<usize> {
+            (0..TOTAL_MAGNETS).filter(|&i| self.magnets[i].mag_type == t).collect()
+        };
+        // Hints go by the magnets as labelled; a hidden fault stays hidden
+        self.hint = Hint::solve(
+            &powers,
+            &self.fallback_optics(),
+            [&trims(MagnetType::HTrim), &trims(MagnetType::VTrim)],
+            |i, p| self.magnets[i].mag_type.maps(p),
+            &self.restrictions,
+            MAGNETS_PER_SECTION,
+            target,
+        );
+    }
+
+    /// Design dipoles and the quad pair that's most comfortably stable in
+    /// both planes, keeping the current trims: where a hint starts when the
+    /// player's optics don't hold an orbit.
+    fn fallback_optics(&self) -> Vec<f32> {
+        let optics = |qf: f32, qd: f32| -> Vec<f32> {
+            self.magnets.iter().map(|m| match m.mag_type {
+                MagnetType::FocusQuad => qf,
+                MagnetType::DefocusQuad => qd,
+                MagnetType::Dipole1 | MagnetType::Dipole2 => DESIGN_DIPOLE_POWER,
+                MagnetType::VTrim | MagnetType::HTrim => m.power,
+            }).collect()
+        };
+        let worst_trace = |powers: &[f32]| -> f32 {
+            let turn = self.magnets.iter().zip(powers)
+                .fold([PlaneMap::IDENTITY; 2], |[x, y], (m, &p)| {
+                    let [ex, ey] = m.mag_type.maps(p);
+                    [x.then(&ex), y.then(&ey)]
+                });
+            turn[0].trace().abs().max(turn[1].trace().abs())
+        };
+        let steps = (1..=10).map(|i| i as f32 * 0.05);
+        let (qf, qd) = steps.clone()
+            .flat_map(|qf| steps.clone().map(move |qd| (qf, qd)))
+            .min_by(|a, b| worst_trace(&optics(a.0, a.1)).total_cmp(&worst_trace(&optics(b.0, b.1))))
+            .unwrap_or((0.2, 0.25));
+        optics(qf, qd)
+    }
+
+    /// The hint orbit (x, y) where the beam is, or at the end of the
+    /// selected section before a run, with the colour to ghost it in.
+    fn hint_point(&self) -> Option<((f32, f32), Ink)> {
+        let hint = self.hint.as_ref().filter(|_| self.show_hint)?;
+        let index = if self.beam_running {
+            (self.beam_section * MAGNETS_PER_SECTION + self.beam_element + TOTAL_MAGNETS - 1) % TOTAL_MAGNETS
+        } else {
+            self.selected_section() * MAGNETS_PER_SECTION + MAGNETS_PER_SECTION - 1
+        };
+        Some((hint.orbit[index], hint_color(hint)))
+    }
+
+    /// Trace the first turn from the injection point with ramp point 0, by
+    /// the magnets as labelled; `None` once the beam is in or the run is over.
+    fn preview(&self) -> Option<Preview> {
+        if self.beam_running || self.beam_lost || self.beam_completed { return None; }
+        let (mut x, mut y) = ((self.target_x, 0.0), (self.target_y, 0.0));
+        let mut path = Vec::with_capacity(TOTAL_MAGNETS);
+        for i in 0..TOTAL_MAGNETS {
+            let [x_map, y_map] = self.magnets[i].mag_type.maps(self.ramp_power_for_turn(i, 0));
+            x = x_map.apply(x);
+            y = y_map.apply(y);
+            path.push((x.0, y.0));
+            let section = i / MAGNETS_PER_SECTION;
+            if x.0.abs() > APERTURE || y.0.abs() > APERTURE {
+                return Some(Preview { path, lost: Some((section, "wall".to_string())) });
+            }
+            if let Some(r) = self.restrictions.iter().find(|r| r.section == section && r.check(x.0, y.0)) {
+                return Some(Preview { path, lost: Some((section, r.label())) });
+            }
+        }
+        Some(Preview { path, lost: None })
+    }
+
     fn advance_beam(&mut self) {
         self.beam_progress += 0.40;
 
@@ -405,7 +667,7 @@ impl BeamGame {
             // Hard wall: instant loss if position exceeds aperture
             if self.beam_position.abs() > APERTURE || self.beam_y_position.abs() > APERTURE {
                 self.beam_lost = true;
-                self.message = Some(("Hit aperture wall!".to_string(), 60, Color::Rgb(255, 60, 60)));
+                self.message = Some(("Hit aperture wall!".to_string(), 60, Ink(Color::Rgb(255, 60, 60), Role::Red)));
                 return;
             }
 
@@ -430,7 +692,7 @@ impl BeamGame {
                 self.message = Some((
                     format!("Beam losses exceeded {:.0}!", MAX_LOSSES),
                     60,
-                    Color::Rgb(255, 100, 100),
+                    Ink(Color::Rgb(255, 100, 100), Role::Red),
                 ));
                 return;
             }
@@ -442,7 +704,7 @@ impl BeamGame {
                     self.message = Some((
                         format!("Hit section {} restriction! ({})", r.section + 1, r.label()),
                         60,
-                        Color::Rgb(255, 100, 100),
+                        Ink(Color::Rgb(255, 100, 100), Role::Red),
                     ));
                     return;
                 }
@@ -469,8 +731,23 @@ impl BeamGame {
                     if self.turns_completed > self.best_turns {
                         self.best_turns = self.turns_completed;
                     }
-                    if self.turns_completed >= GOAL_TURNS {
+                    if self.time_attack {
+                        let off = ((self.beam_position - self.target_x).powi(2)
+                            + (self.beam_y_position - self.target_y).powi(2)).sqrt();
+                        self.ta_streak = if off <= TA_TOLERANCE { self.ta_streak + 1 } else { 0 };
+                        if self.ta_streak >= GOAL_TURNS {
+                            self.beam_completed = true;
+                            self.message = Some((
+                                format!("Orbit established in {}!", format_score(TIME_ATTACK_TABLE, self.time_attack_score())),
+                                240, Ink(Color::Rgb(80, 255, 80), Role::Green),
+                            ));
+                        }
+                    } else if self.turns_completed >= GOAL_TURNS {
                         self.beam_completed = true;
+                        // The supplies play out the rest of the ramp regardless
+                        let (used, needed) = self.ramp_program_power(self.turns_completed as usize);
+                        self.power_used += used;
+                        self.power_needed += needed;
                     }
                 }
             }
@@ -506,7 +783,7 @@ impl BeamGame {
         self.message = Some((
             format!("Copied section {} to all (all ramps)!", src_sec + 1),
             45,
-            Color::Rgb(80, 255, 180),
+            Ink(Color::Rgb(80, 255, 180), Role::Cyan),
         ));
     }
 
@@ -524,6 +801,37 @@ impl BeamGame {
         self.selected = sec * MAGNETS_PER_SECTION + elem;
     }
 
+    /// Ring section whose marker (or label) is nearest the clicked cell
+    fn ring_section_at(&self, col: u16, row: u16) -> Option<usize> {
+        let area = self.ring_area;
+        if !area.contains(Position::new(col, row)) { return None; }
+        let cx = area.width as f32 / 2.0;
+        let cy = area.height as f32 / 2.0;
+        let rx = (area.width as f32 * 0.35).min(cx - 4.0);
+        let ry = (area.height as f32 * 0.38).min(cy - 2.0);
+        let px = (col - area.x) as f32;
+        let py = (row - area.y) as f32;
+        (0..NUM_SECTIONS)
+            .map(|sec| {
+                let angle = (sec as f32 / NUM_SECTIONS as f32) * std::f32::consts::PI * 2.0 - std::f32::consts::FRAC_PI_2;
+                // Terminal cells are about twice as tall as wide
+                let d = (px - (cx + rx * angle.cos())).powi(2) + ((py - (cy + ry * angle.sin())) * 2.0).powi(2);
+                (sec, d)
+            })
+            .filter(|(_, d)| *d <= 9.0)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(sec, _)| sec)
+    }
+
+    /// Magnet row (element index) under the cursor in the magnet panel
+    fn magnet_row_at(&self, col: u16, row: u16) -> Option<usize> {
+        let area = self.magnet_area;
+        if self.bump.is_some() || !area.contains(Position::new(col, row)) { return None; }
+        // Border, section header and a blank line sit above the magnet rows
+        let elem = row.checked_sub(area.y + 3)? as usize;
+        (elem < MAGNETS_PER_SECTION).then_some(elem)
+    }
+
     /// Get a stability indicator (how centered and small the beam is in both planes)
     fn stability_score(&self) -> f32 {
         if self.pos_history.is_empty() { return 0.0; }
@@ -561,6 +869,32 @@ impl BeamGame {
         v
     }
 
+    /// |power| the ramp program spends from ramp point `from` to the end,
+    /// one turn per point, and the design bend's share of it.
+    fn ramp_program_power(&self, from: usize) -> (f32, f32) {
+        let mut used = 0.0;
+        let mut needed = 0.0;
+        for (m, ramps) in self.magnets.iter().zip(&self.ramp_powers) {
+            used += ramps[from.min(NUM_RAMPS)..].iter().map(|p| p.abs()).sum::<f32>();
+            if matches!(m.mag_type, MagnetType::Dipole1 | MagnetType::Dipole2) {
+                needed += DESIGN_DIPOLE_POWER * NUM_RAMPS.saturating_sub(from) as f32;
+            }
+        }
+        (used, needed)
+    }
+
+    /// How little power the orbit takes, 0..1: the design bend over all
+    /// the |power| spent. Integrated over the run once the beam is going,
+    /// projected over all ten ramp points before. None with every supply off.
+    fn economy(&self) -> Option<f32> {
+        let (used, needed) = if self.power_used > 0.0 {
+            (self.power_used, self.power_needed)
+        } else {
+            self.ramp_program_power(0)
+        };
+        (used > 0.0).then(|| (needed / used).min(1.0))
+    }
+
     /// Sync all magnets' display power from ramp_powers at the selected ramp point.
     fn sync_display_from_ramp(&mut self) {
         let ramp_idx = self.selected_ramp;
@@ -587,7 +921,246 @@ impl BeamGame {
     }
 }
 
+// ── Challenges ───────────────────────────────────────────────────────────────
+impl BeamGame {
+    /// Time attack result in centiseconds once the orbit is established, else 0.
+    /// Start on Hard (config.toml's `[difficulty]`); a run under way keeps
+    /// the difficulty it began with.
+    pub fn set_default_difficulty(&mut self, hard: bool) {
+        if self.beam_running { return; }
+        self.difficulty = if hard { Difficulty::Hard } else { Difficulty::Easy };
+    }
+
+    pub fn time_attack_score(&self) -> u32 {
+        if !self.time_attack { return 0; }
+        (self.ta_elapsed.as_millis() / 10).clamp(1, u32::MAX as u128) as u32
+    }
+
+    /// Start a fresh attempt: every run tunes a cold lattice from scratch.
+    fn arm_time_attack(&mut self) {
+        self.time_attack = true;
+        self.ta_elapsed = Duration::ZERO;
+        self.ta_streak = 0;
+        for ramps in self.ramp_powers.iter_mut() {
+            *ramps = [0.0; NUM_RAMPS];
+        }
+        self.sync_display_from_ramp();
+        self.message = Some(("Time attack: clock running!".to_string(), 60, Ink(Color::Rgb(255, 200, 80), Role::Orange)));
+    }
+
+    pub fn time_attack_done(&self) -> bool {
+        self.time_attack && self.beam_completed
+    }
+
+    fn current_challenge(&self) -> Challenge {
+        Challenge {
+            name: self.challenge_name.clone().unwrap_or_else(|| "Custom".to_string()),
+            restrictions: self.restrictions.clone(),
+            target_x: self.target_x,
+            target_y: self.target_y,
+        }
+    }
+
+    fn load_challenge(&mut self, challenge: Challenge) {
+        self.restrictions = challenge.restrictions;
+        self.target_x = challenge.target_x;
+        self.target_y = challenge.target_y;
+        self.message = Some((format!("Challenge: {}", challenge.name), 60, Ink(Color::Rgb(255, 200, 80), Role::Orange)));
+        self.challenge_name = Some(challenge.name);
+    }
+
+    /// Cycle one axis' restriction in the selected section: none → ≤0 → ≥0 → none.
+    fn cycle_restriction(&mut self, axis: char) {
+        let section = self.selected_section();
+        let existing = self.restrictions.iter().position(|r| r.section == section && r.axis == axis);
+        match existing {
+            None => self.restrictions.push(Restriction { section, axis, positive_blocked: true }),
+            Some(i) if self.restrictions[i].positive_blocked => self.restrictions[i].positive_blocked = false,
+            Some(i) => { self.restrictions.remove(i); }
+        }
+        self.restrictions.sort_by_key(|r| (r.section, r.axis));
+        // An edited layout is no longer the challenge it was loaded from
+        self.challenge_name = None;
+    }
+
+    fn handle_editor_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Left | KeyCode::Char('[') => self.prev_section(),
+            KeyCode::Right | KeyCode::Char(']') => self.next_section(),
+            KeyCode::Char('x') | KeyCode::Char('X') => self.cycle_restriction('x'),
+            KeyCode::Char('y') | KeyCode::Char('Y') => self.cycle_restriction('y'),
+            KeyCode::Up | KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('l')
+            | KeyCode::Char('J') | KeyCode::Char('L') => {
+                match key.code {
+                    KeyCode::Up => self.target_y += 0.5,
+                    KeyCode::Down => self.target_y -= 0.5,
+                    KeyCode::Char('j') | KeyCode::Char('J') => self.target_x -= 0.5,
+                    _ => self.target_x += 0.5,
+                }
+                self.target_x = self.target_x.clamp(-20.0, 20.0);
+                self.target_y = self.target_y.clamp(-20.0, 20.0);
+                self.challenge_name = None;
+            }
+            KeyCode::Char('c') | KeyCode::Char('C') => {
+                self.restrictions.clear();
+                self.challenge_name = None;
+            }
+            KeyCode::Char('s') | KeyCode::Char('S') => {
+                self.text_input = Some((TextInput::ChallengeName, String::new()));
+            }
+            KeyCode::Enter | KeyCode::Char('a') | KeyCode::Char('A') => {
+                self.editing = false;
+                self.message = Some(("Editor closed".to_string(), 30, Ink(Color::Rgb(140, 140, 160), Role::Dim)));
+            }
+            _ => {}
+        }
+    }
+
+    /// Open the comparison with the live ring against the newest save.
+    fn open_compare(&mut self) {
+        let live = SavedConfig { name: "Live ring".to_string(), powers: self.magnets.iter().map(|m| m.power).collect() };
+        let mut configs = vec![live];
+        configs.extend(SavedConfig::load_all(TOTAL_MAGNETS));
+        if configs.len() < 2 {
+            self.message = Some(("No saved configs yet │ O to save one".to_string(), 60, Ink(Color::Rgb(255, 200, 80), Role::Orange)));
+        }
+        let picks = [0, configs.len() - 1];
+        self.compare = Some(self.compare_configs(configs, picks, 1));
+    }
+
+    fn handle_compare_key(&mut self, key: KeyEvent) {
+        let Some(compare) = self.compare.take() else { return };
+        let Comparison { configs, mut picks, mut side, .. } = compare;
+        let count = configs.len();
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('v') | KeyCode::Char('V') => return,
+            KeyCode::Left | KeyCode::Right | KeyCode::Tab => side = 1 - side,
+            KeyCode::Up => picks[side] = (picks[side] + count - 1) % count,
+            KeyCode::Down => picks[side] = (picks[side] + 1) % count,
+            _ => {}
+        }
+        self.compare = Some(self.compare_configs(configs, picks, side));
+    }
+
+    /// Predict both picks and rank the magnets they disagree on by how far
+    /// changing that one alone moves the closed orbit.
+    fn compare_configs(&self, configs: Vec<SavedConfig>, picks: [usize; 2], side: usize) -> Comparison {
+        let ring_maps = |powers: &[f32]| -> Vec<[PlaneMap; 2]> {
+            self.magnets.iter().zip(powers).map(|(m, &p)| m.mag_type.maps(p)).collect()
+        };
+        let (a, b) = (&configs[picks[0]].powers, &configs[picks[1]].powers);
+        let mut a_maps = ring_maps(a);
+        let predictions = [Prediction::new(&a_maps, MAGNETS_PER_SECTION), Prediction::new(&ring_maps(b), MAGNETS_PER_SECTION)];
+        let mut diffs = Vec::new();
+        for (i, magnet) in self.magnets.iter().enumerate() {
+            if (a[i] - b[i]).abs() < 1e-6 { continue; }
+            let original = a_maps[i];
+            a_maps[i] = magnet.mag_type.maps(b[i]);
+            let impact = Prediction::new(&a_maps, MAGNETS_PER_SECTION).orbit_shift(&predictions[0]);
+            a_maps[i] = original;
+            diffs.push(MagnetDiff {
+                label: format!("{} s{}", magnet.mag_type.label(), i / MAGNETS_PER_SECTION + 1),
+                powers: (a[i], b[i]),
+                impact,
+            });
+        }
+        diffs.sort_by(|p, q| q.impact.unwrap_or(-1.0).total_cmp(&p.impact.unwrap_or(-1.0)));
+        Comparison { configs, picks, side, predictions, diffs }
+    }
+
+    fn handle_text_input(&mut self, key: KeyEvent) {
+        let Some((mode, ref mut buffer)) = self.text_input else { return };
+        match key.code {
+            // Separators would break the share code
+            KeyCode::Char(c) if mode != TextInput::ShareCode && (c == ';' || c == ',') => {}
+            KeyCode::Char(c) if buffer.len() < 120 => buffer.push(c),
+            KeyCode::Backspace => { buffer.pop(); }
+            KeyCode::Esc => self.text_input = None,
+            KeyCode::Enter => {
+                let text = buffer.trim().to_string();
+                self.text_input = None;
+                if text.is_empty() { return; }
+                match mode {
+                    TextInput::ChallengeName => {
+                        let mut challenge = self.current_challenge();
+                        challenge.name = text;
+                        let saved = challenge.save();
+                        self.message = Some(match saved {
+                            Some(file) => (format!("Saved {} │ {}", file, challenge.share_code()), 240, Ink(Color::Rgb(80, 255, 200), Role::Cyan)),
+                            None => ("Could not save challenge".to_string(), 60, Ink(Color::Rgb(255, 60, 60), Role::Red)),
+                        });
+                        self.challenge_name = Some(challenge.name);
+                    }
+                    TextInput::ConfigName => {
+                        let config = SavedConfig { name: text, powers: self.magnets.iter().map(|m| m.power).collect() };
+                        self.message = Some(match config.save() {
+                            Some(file) => (format!("Saved magnets/{} │ V to compare", file), 120, Ink(Color::Rgb(80, 255, 200), Role::Cyan)),
+                            None => ("Could not save configuration".to_string(), 60, Ink(Color::Rgb(255, 60, 60), Role::Red)),
+                        });
+                    }
+                    TextInput::ShareCode => match Challenge::from_code(&text) {
+                        Some(challenge) => {
+                            challenge.save();
+                            self.load_challenge(challenge);
+                        }
+                        None => {
+                            self.message = Some(("Invalid challenge code".to_string(), 60, Ink(Color::Rgb(255, 60, 60), Role::Red)));
+                        }
+                    },
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Blue for a first turn that makes it round, orange if it strays into
+/// the loss zone, red if it's lost.
+fn preview_color(preview: &Preview) -> Ink {
+    if preview.lost.is_some() {
+        Ink(Color::Rgb(255, 90, 90), Role::Red)
+    } else if preview.path.iter().any(|(x, y)| x.abs() > LOSS_ZONE || y.abs() > LOSS_ZONE) {
+        Ink(Color::Rgb(255, 170, 60), Role::Orange)
+    } else {
+        Ink(Color::Rgb(140, 180, 255), Role::Blue)
+    }
+}
+
+/// Ghost colour for a hint: green when the orbit clears everything.
+fn hint_color(hint: &Hint) -> Ink {
+    if hint.feasible { Ink(Color::Rgb(90, 170, 120), Role::Green) } else { Ink(Color::Rgb(170, 100, 70), Role::Orange) }
+}
+
+pub const INFO: GameInfo = GameInfo {
+    name: "Beam",
+    icon: "💫",
+    author: "RustCade",
+    blurb: "Tune the ring\nfor 5 orbits!",
+    description: "Tune magnets, steer a beam — 5 turns, lowest score wins!",
+    controls: &[
+        ("↑ / ↓", "Select magnet"),
+        ("← / →", "Adjust power"),
+        ("[ / ]", "Prev/next section"),
+        ("+ / -", "Step size"),
+        ("0-9", "Ramp point"),
+        ("B", "Bump mode (3/4/5/off)"),
+        ("C", "Copy section to all"),
+        ("Z", "Zero magnet"),
+        ("D", "Difficulty toggle"),
+        ("W/S  E/Q", "Bump X / Y only"),
+        ("O / V", "Save / compare configs"),
+        ("H", "Hint: ghost a working orbit"),
+    ],
+    tags: &["simulation", "physics", "puzzle"],
+    color: Ink(Color::Rgb(255, 160, 60), Role::Orange),
+    border_color: Ink(Color::Rgb(140, 80, 30), Role::Orange),
+};
+
 impl Game for BeamGame {
+    fn info(&self) -> &'static GameInfo {
+        &INFO
+    }
+
     fn update(&mut self) {
         // Always tick message timer
         if let Some((_, ref mut ticks, _)) = self.message {
@@ -597,6 +1170,16 @@ impl Game for BeamGame {
                 self.message = None;
             }
         }
+        // Time attack clock: wall time while unpaused and not yet established
+        if self.time_attack && !self.paused && !self.beam_completed {
+            let now = Instant::now();
+            if let Some(last) = self.ta_last {
+                self.ta_elapsed += (now - last).min(TA_MAX_FRAME);
+            }
+            self.ta_last = Some(now);
+        } else {
+            self.ta_last = None;
+        }
         if self.paused || self.beam_lost || self.beam_completed { return; }
         self.tick += 1;
         if self.beam_running {
@@ -630,14 +1213,44 @@ impl Game for BeamGame {
     }
 
     fn handle_input(&mut self, key: KeyEvent) {
+        if self.compare.is_some() {
+            self.handle_compare_key(key);
+            return;
+        }
+        if self.text_input.is_some() {
+            self.handle_text_input(key);
+            return;
+        }
+        if self.editing {
+            self.handle_editor_key(key);
+            return;
+        }
         match key.code {
-            KeyCode::Char('r') | KeyCode::Char('R') => self.reset(),
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.reset();
+                // A manual restart also restarts the time attack attempt
+                self.ta_elapsed = Duration::ZERO;
+            }
             KeyCode::Char('p') | KeyCode::Char('P') => {
                 if !self.beam_lost && !self.beam_completed {
                     self.paused = !self.paused;
                 }
             }
             _ => {
+                if self.time_attack_done() {
+                    match key.code {
+                        KeyCode::Enter | KeyCode::Char(' ') => {
+                            self.reset();
+                            self.arm_time_attack();
+                        }
+                        KeyCode::Char('t') | KeyCode::Char('T') => {
+                            self.reset();
+                            self.time_attack = false;
+                        }
+                        _ => {}
+                    }
+                    return;
+                }
                 if self.beam_lost || self.beam_completed {
                     if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
                         self.reset();
@@ -720,7 +1333,7 @@ impl Game for BeamGame {
                                         bump.size, bump.axis.label(),
                                         bump.start_section + 1),
                                     30,
-                                    Color::Rgb(120, 220, 255),
+                                    Ink(Color::Rgb(120, 220, 255), Role::Cyan),
                                 ));
                             }
                         } else {
@@ -740,7 +1353,7 @@ impl Game for BeamGame {
                                         bump.size, bump.axis.label(),
                                         bump.start_section + 1),
                                     30,
-                                    Color::Rgb(120, 220, 255),
+                                    Ink(Color::Rgb(120, 220, 255), Role::Cyan),
                                 ));
                             }
                         } else {
@@ -830,7 +1443,7 @@ impl Game for BeamGame {
                             }
                             self.message = Some((
                                 format!("Zeroed bump trims (Ramp{})", self.selected_ramp),
-                                30, Color::Rgb(255, 200, 80),
+                                30, Ink(Color::Rgb(255, 200, 80), Role::Orange),
                             ));
                         } else {
                             let sel = self.selected;
@@ -847,7 +1460,7 @@ impl Game for BeamGame {
                         self.sync_display_from_ramp();
                         self.message = Some((
                             format!("Ramp{}", ramp_idx),
-                            30, Color::Rgb(120, 200, 255),
+                            30, Ink(Color::Rgb(120, 200, 255), Role::Blue),
                         ));
                     }
                     // Cycle bump modes: B cycles off -> 3 -> 4 -> 5 -> off
@@ -859,21 +1472,21 @@ impl Game for BeamGame {
                                     self.bump = Some(BumpConfig::new(4, start));
                                     self.message = Some((
                                         format!("4-Bump mode (sec {}-{})", start + 1, (start + 3) % NUM_SECTIONS + 1),
-                                        45, Color::Rgb(80, 255, 200),
+                                        45, Ink(Color::Rgb(80, 255, 200), Role::Cyan),
                                     ));
                                 }
                                 4 => {
                                     self.bump = Some(BumpConfig::new(5, start));
                                     self.message = Some((
                                         format!("5-Bump mode (sec {}-{})", start + 1, (start + 4) % NUM_SECTIONS + 1),
-                                        45, Color::Rgb(80, 255, 200),
+                                        45, Ink(Color::Rgb(80, 255, 200), Role::Cyan),
                                     ));
                                 }
                                 _ => {
                                     self.bump = None;
                                     self.message = Some((
                                         "Bump mode OFF".to_string(), 30,
-                                        Color::Rgb(140, 140, 160),
+                                        Ink(Color::Rgb(140, 140, 160), Role::Dim),
                                     ));
                                 }
                             }
@@ -882,10 +1495,45 @@ impl Game for BeamGame {
                             self.bump = Some(BumpConfig::new(3, start));
                             self.message = Some((
                                 format!("3-Bump mode (sec {}-{})", start + 1, (start + 2) % NUM_SECTIONS + 1),
-                                45, Color::Rgb(80, 255, 200),
+                                45, Ink(Color::Rgb(80, 255, 200), Role::Cyan),
                             ));
                         }
                     }
+                    // Challenges (only before beam starts)
+                    KeyCode::Char('a') | KeyCode::Char('A') if !self.beam_running => {
+                        self.editing = true;
+                        self.bump = None;
+                        self.message = Some(("Restriction editor".to_string(), 45, Ink(Color::Rgb(255, 200, 80), Role::Orange)));
+                    }
+                    KeyCode::Char('g') | KeyCode::Char('G') if !self.beam_running => {
+                        let pool = Challenge::pool();
+                        self.pool_index %= pool.len();
+                        self.load_challenge(pool[self.pool_index].clone());
+                        self.pool_index += 1;
+                    }
+                    KeyCode::Char('n') | KeyCode::Char('N') if !self.beam_running => {
+                        let mut daily = Challenge::daily();
+                        daily.name = format!("Daily: {}", daily.name);
+                        self.load_challenge(daily);
+                    }
+                    KeyCode::Char('t') | KeyCode::Char('T') if !self.beam_running => {
+                        if self.time_attack {
+                            self.time_attack = false;
+                            self.message = Some(("Time attack off".to_string(), 30, Ink(Color::Rgb(140, 140, 160), Role::Dim)));
+                        } else {
+                            self.arm_time_attack();
+                        }
+                    }
+                    KeyCode::Char('u') | KeyCode::Char('U') if !self.beam_running => {
+                        self.text_input = Some((TextInput::ShareCode, String::new()));
+                    }
+                    KeyCode::Char('m') | KeyCode::Char('M') if !self.beam_running => self.toggle_faults(),
+                    KeyCode::Char('o') | KeyCode::Char('O') if !self.beam_running => {
+                        self.text_input = Some((TextInput::ConfigName, String::new()));
+                    }
+                    KeyCode::Char('v') | KeyCode::Char('V') if !self.beam_running => self.open_compare(),
+                    KeyCode::Char('f') | KeyCode::Char('F') => self.flag_selected(),
+                    KeyCode::Char('h') | KeyCode::Char('H') => self.toggle_hint(),
                     // Toggle difficulty (only before beam starts)
                     KeyCode::Char('d') | KeyCode::Char('D') => {
                         if !self.beam_running {
@@ -903,13 +1551,10 @@ impl Game for BeamGame {
         }
     }
 
-    fn render(&mut self, frame: &mut Frame, area: Rect) {
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Color::Rgb(100, 180, 255)))
-            .title(" ⚛ Beam ")
-            .title_style(Style::default().fg(Color::Rgb(120, 200, 255)).add_modifier(Modifier::BOLD));
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        self.refresh_hint();
+        let preview = self.preview();
+        let block = self.title_bar(theme);
 
         let inner = block.inner(area);
         frame.render_widget(block, area);
@@ -936,58 +1581,108 @@ impl Game for BeamGame {
 
         // Status bar
         let stability = self.stability_score();
-        let stab_color = if stability > 80.0 { Color::Rgb(80, 255, 80) }
-            else if stability > 50.0 { Color::Yellow }
-            else if stability > 20.0 { Color::Rgb(255, 160, 50) }
-            else { Color::Rgb(255, 60, 60) };
+        let stab_color = if stability > 80.0 { theme.paint(Color::Rgb(80, 255, 80), Role::Green) }
+            else if stability > 50.0 { theme.paint(Color::Yellow, Role::Yellow) }
+            else if stability > 20.0 { theme.paint(Color::Rgb(255, 160, 50), Role::Orange) }
+            else { theme.paint(Color::Rgb(255, 60, 60), Role::Red) };
         let mut status_spans = vec![
             Span::styled(
                 format!("[{}] ", self.difficulty.label()),
-                Style::default().fg(self.difficulty.color()).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme.ink(self.difficulty.color())).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                self.challenge_name.as_ref().map(|n| format!("[{}] ", n)).unwrap_or_default(),
+                Style::default().fg(theme.paint(Color::Rgb(255, 200, 80), Role::Orange)),
+            ),
+            Span::styled(
+                match self.fault {
+                    Some(_) if self.diagnosed => format!("[Fault found +{}] ", self.diagnosis_bonus()),
+                    Some(_) if self.wrong_flags.len() >= MAX_WRONG_FLAGS => "[Fault missed] ".to_string(),
+                    Some(_) => format!("[Fault? {} flags] ", MAX_WRONG_FLAGS - self.wrong_flags.len()),
+                    None => String::new(),
+                },
+                Style::default().fg(if self.diagnosed { theme.paint(Color::Rgb(80, 255, 80), Role::Green) } else { theme.paint(Color::Rgb(255, 120, 80), Role::Red) }),
+            ),
+            Span::styled(
+                match self.hint.as_ref().filter(|_| self.show_hint) {
+                    Some(hint) if hint.feasible => "[Hint] ",
+                    Some(_) => "[Hint: no orbit] ",
+                    None => "",
+                },
+                Style::default().fg(theme.ink(self.hint.as_ref().map_or(Ink(Color::DarkGray, Role::Faint), hint_color))),
+            ),
+            Span::styled(
+                match &preview {
+                    Some(Preview { lost: Some((sec, what)), .. }) => format!("[Turn 1: lost in {} at {}] ", sec + 1, what),
+                    Some(Preview { path, lost: None }) => path.last()
+                        .map(|(x, y)| format!("[Turn 1 back at {:+.1},{:+.1}] ", x, y))
+                        .unwrap_or_default(),
+                    None => String::new(),
+                },
+                Style::default().fg(theme.ink(preview.as_ref().map_or(Ink(Color::DarkGray, Role::Faint), preview_color))),
+            ),
+            Span::styled(
+                if self.time_attack {
+                    format!("⏱ {} ({}/{} on target) ", format_score(TIME_ATTACK_TABLE, self.time_attack_score()), self.ta_streak, GOAL_TURNS)
+                } else {
+                    String::new()
+                },
+                Style::default().fg(theme.paint(Color::Rgb(255, 200, 80), Role::Orange)).add_modifier(Modifier::BOLD),
             ),
             Span::styled(
                 format!("Turns: {}/{} ", self.turns_completed, GOAL_TURNS),
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             Span::styled(
                 format!("Best: {} ", self.best_turns),
-                Style::default().fg(Color::Cyan),
+                Style::default().fg(theme.paint(Color::Cyan, Role::Cyan)),
             ),
-            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             Span::styled(
                 format!("Size: {:.1}/{:.1} ", self.beam_size, self.beam_y_size),
-                Style::default().fg(if self.beam_size > 30.0 || self.beam_y_size > 30.0 { Color::Red } else { Color::Green }),
+                Style::default().fg(if self.beam_size > 30.0 || self.beam_y_size > 30.0 { theme.paint(Color::Red, Role::Red) } else { theme.paint(Color::Green, Role::Green) }),
             ),
-            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             Span::styled(
                 format!("Loss: {:.0}/{:.0} ", self.beam_losses, MAX_LOSSES),
                 Style::default().fg(
-                    if self.beam_losses > 75.0 { Color::Rgb(255, 60, 60) }
-                    else if self.beam_losses > 40.0 { Color::Rgb(255, 200, 50) }
-                    else { Color::Rgb(100, 100, 140) }
+                    if self.beam_losses > 75.0 { theme.paint(Color::Rgb(255, 60, 60), Role::Red) }
+                    else if self.beam_losses > 40.0 { theme.paint(Color::Rgb(255, 200, 50), Role::Yellow) }
+                    else { theme.paint(Color::Rgb(100, 100, 140), Role::Faint) }
                 ).add_modifier(if self.beam_losses > 40.0 { Modifier::BOLD } else { Modifier::empty() }),
             ),
         ];
-        status_spans.push(Span::styled(" │ ", Style::default().fg(Color::DarkGray)));
+        status_spans.push(Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))));
         status_spans.push(Span::styled(
             format!("Stability: {:.0}% ", stability),
             Style::default().fg(stab_color).add_modifier(Modifier::BOLD),
         ));
-        // Score: sum of absolute power across all magnets
-        let score: f32 = self.magnets.iter().map(|m| m.power.abs()).sum();
-        status_spans.push(Span::styled(" │ ", Style::default().fg(Color::DarkGray)));
-        status_spans.push(Span::styled(
-            format!("Score: {:.3} ", score),
-            Style::default().fg(Color::Rgb(255, 200, 80)).add_modifier(Modifier::BOLD),
-        ));
+        // Economy meter: the design bend over the power spent on the ramp
+        status_spans.push(Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))));
+        match self.economy() {
+            Some(economy) => {
+                let filled = (economy * 10.0).round() as usize;
+                let color = if economy >= 0.7 { theme.paint(Color::Rgb(80, 220, 120), Role::Green) }
+                    else if economy >= 0.4 { theme.paint(Color::Rgb(255, 200, 80), Role::Orange) }
+                    else { theme.paint(Color::Rgb(255, 100, 80), Role::Red) };
+                status_spans.push(Span::styled("Economy: ", Style::default().fg(theme.paint(Color::Rgb(255, 200, 80), Role::Orange))));
+                status_spans.push(Span::styled("█".repeat(filled), Style::default().fg(color)));
+                status_spans.push(Span::styled("·".repeat(10 - filled), Style::default().fg(theme.paint(Color::Rgb(60, 60, 80), Role::Faint))));
+                status_spans.push(Span::styled(
+                    format!(" {:.0}% ", economy * 100.0),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ));
+            }
+            None => status_spans.push(Span::styled("Economy: -- ", Style::default().fg(theme.paint(Color::Rgb(100, 100, 140), Role::Faint)))),
+        }
         // Show flash message if active
         if let Some((ref msg, ticks, color)) = self.message {
             if ticks > 0 {
-                status_spans.push(Span::styled(" │ ", Style::default().fg(Color::DarkGray)));
+                status_spans.push(Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))));
                 status_spans.push(Span::styled(
                     format!(" {} ", msg),
-                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                    Style::default().fg(theme.ink(color)).add_modifier(Modifier::BOLD),
                 ));
             }
         }
@@ -1001,22 +1696,31 @@ impl Game for BeamGame {
         // Aperture bar
         let center = bar_w / 2;
         let scale = center as f32 / APERTURE;
-        let mut bar_chars: Vec<(char, Style)> = vec![(' ', Style::default().bg(Color::Rgb(15, 15, 25))); bar_w];
+        let mut bar_chars: Vec<(char, Style)> = vec![(' ', Style::default().bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background))); bar_w];
 
         // Draw aperture limits (hard wall)
         let left_ap = center.saturating_sub((APERTURE * scale) as usize);
         let right_ap = (center + (APERTURE * scale) as usize).min(bar_w - 1);
-        if left_ap < bar_w { bar_chars[left_ap] = ('│', Style::default().fg(Color::Red).bg(Color::Rgb(15, 15, 25))); }
-        if right_ap < bar_w { bar_chars[right_ap] = ('│', Style::default().fg(Color::Red).bg(Color::Rgb(15, 15, 25))); }
+        if left_ap < bar_w { bar_chars[left_ap] = ('│', Style::default().fg(theme.paint(Color::Red, Role::Red)).bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background))); }
+        if right_ap < bar_w { bar_chars[right_ap] = ('│', Style::default().fg(theme.paint(Color::Red, Role::Red)).bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background))); }
 
         // Draw loss zone markers at ±25
         let left_lz = center.saturating_sub((LOSS_ZONE * scale) as usize);
         let right_lz = (center + (LOSS_ZONE * scale) as usize).min(bar_w - 1);
         if left_lz < bar_w && bar_chars[left_lz].0 == ' ' {
-            bar_chars[left_lz] = ('┆', Style::default().fg(Color::Rgb(255, 200, 50)).bg(Color::Rgb(15, 15, 25)));
+            bar_chars[left_lz] = ('┆', Style::default().fg(theme.paint(Color::Rgb(255, 200, 50), Role::Yellow)).bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
         }
         if right_lz < bar_w && bar_chars[right_lz].0 == ' ' {
-            bar_chars[right_lz] = ('┆', Style::default().fg(Color::Rgb(255, 200, 50)).bg(Color::Rgb(15, 15, 25)));
+            bar_chars[right_lz] = ('┆', Style::default().fg(theme.paint(Color::Rgb(255, 200, 50), Role::Yellow)).bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
+        }
+
+        // Hint orbit ghost, under the beam
+        let hint_point = self.hint_point();
+        if let Some(((hx, _), color)) = hint_point {
+            let gx = (center as f32 + hx * scale) as usize;
+            if gx < bar_w && bar_chars[gx].0 == ' ' {
+                bar_chars[gx] = ('╎', Style::default().fg(theme.ink(color)).bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
+            }
         }
 
         // Draw beam with smooth gradient using density characters
@@ -1037,25 +1741,25 @@ impl Game for BeamGame {
                     let g = (80.0 + intensity * 175.0) as u8;
                     let b = (120.0 + intensity * 135.0) as u8;
                     let r = (10.0 + intensity * 60.0) as u8;
-                    bar_chars[x] = (ch, Style::default().fg(Color::Rgb(r, g, b)).bg(Color::Rgb(15, 15, 25)));
+                    bar_chars[x] = (ch, Style::default().fg(theme.shade(Color::Rgb(r, g, b), Role::Cyan, intensity)).bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
                 }
             }
             if beam_center < bar_w {
-                bar_chars[beam_center] = ('█', Style::default().fg(Color::Rgb(200, 255, 255)).bg(Color::Rgb(15, 15, 25)));
+                bar_chars[beam_center] = ('█', Style::default().fg(theme.paint(Color::Rgb(200, 255, 255), Role::Cyan)).bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
             }
         }
 
         // Center mark
         bar_chars[center] = if bar_chars[center].0 == ' ' {
-            ('┊', Style::default().fg(Color::Rgb(60, 60, 80)).bg(Color::Rgb(15, 15, 25)))
+            ('┊', Style::default().fg(theme.paint(Color::Rgb(60, 60, 80), Role::Faint)).bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)))
         } else {
             bar_chars[center]
         };
 
         let spans: Vec<Span> = bar_chars.iter().map(|(ch, s)| Span::styled(String::from(*ch), *s)).collect();
-        let x_color = if self.beam_position.abs() > 30.0 { Color::Red } else { Color::Green };
+        let x_color = if self.beam_position.abs() > 30.0 { theme.paint(Color::Red, Role::Red) } else { theme.paint(Color::Green, Role::Green) };
         bar_lines.push(Line::from(vec![
-            Span::styled(" Beam X: ", Style::default().fg(Color::Rgb(100, 100, 140))),
+            Span::styled(" Beam X: ", Style::default().fg(theme.paint(Color::Rgb(100, 100, 140), Role::Faint))),
             Span::styled(
                 format!("{:+.1}", self.beam_position),
                 Style::default().fg(x_color).add_modifier(Modifier::BOLD),
@@ -1069,22 +1773,29 @@ impl Game for BeamGame {
         let mut y_bar_lines: Vec<Line> = Vec::new();
         let y_center = y_bar_w / 2;
         let y_scale = y_center as f32 / APERTURE;
-        let mut y_bar_chars: Vec<(char, Style)> = vec![(' ', Style::default().bg(Color::Rgb(15, 15, 25))); y_bar_w];
+        let mut y_bar_chars: Vec<(char, Style)> = vec![(' ', Style::default().bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background))); y_bar_w];
 
         // Draw aperture limits (hard wall)
         let y_left_ap = y_center.saturating_sub((APERTURE * y_scale) as usize);
         let y_right_ap = (y_center + (APERTURE * y_scale) as usize).min(y_bar_w - 1);
-        if y_left_ap < y_bar_w { y_bar_chars[y_left_ap] = ('│', Style::default().fg(Color::Red).bg(Color::Rgb(15, 15, 25))); }
-        if y_right_ap < y_bar_w { y_bar_chars[y_right_ap] = ('│', Style::default().fg(Color::Red).bg(Color::Rgb(15, 15, 25))); }
+        if y_left_ap < y_bar_w { y_bar_chars[y_left_ap] = ('│', Style::default().fg(theme.paint(Color::Red, Role::Red)).bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background))); }
+        if y_right_ap < y_bar_w { y_bar_chars[y_right_ap] = ('│', Style::default().fg(theme.paint(Color::Red, Role::Red)).bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background))); }
 
         // Draw loss zone markers at ±25
         let y_left_lz = y_center.saturating_sub((LOSS_ZONE * y_scale) as usize);
         let y_right_lz = (y_center + (LOSS_ZONE * y_scale) as usize).min(y_bar_w - 1);
         if y_left_lz < y_bar_w && y_bar_chars[y_left_lz].0 == ' ' {
-            y_bar_chars[y_left_lz] = ('┆', Style::default().fg(Color::Rgb(255, 200, 50)).bg(Color::Rgb(15, 15, 25)));
+            y_bar_chars[y_left_lz] = ('┆', Style::default().fg(theme.paint(Color::Rgb(255, 200, 50), Role::Yellow)).bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
         }
         if y_right_lz < y_bar_w && y_bar_chars[y_right_lz].0 == ' ' {
-            y_bar_chars[y_right_lz] = ('┆', Style::default().fg(Color::Rgb(255, 200, 50)).bg(Color::Rgb(15, 15, 25)));
+            y_bar_chars[y_right_lz] = ('┆', Style::default().fg(theme.paint(Color::Rgb(255, 200, 50), Role::Yellow)).bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
+        }
+
+        if let Some(((_, hy), color)) = hint_point {
+            let gy = (y_center as f32 + hy * y_scale) as usize;
+            if gy < y_bar_w && y_bar_chars[gy].0 == ' ' {
+                y_bar_chars[gy] = ('╎', Style::default().fg(theme.ink(color)).bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
+            }
         }
 
         // Draw beam Y with smooth gradient using density characters
@@ -1104,25 +1815,25 @@ impl Game for BeamGame {
                     let r = (60.0 + intensity * 140.0) as u8;
                     let g = (20.0 + intensity * 40.0) as u8;
                     let b = (100.0 + intensity * 155.0) as u8;
-                    y_bar_chars[x] = (ch, Style::default().fg(Color::Rgb(r, g, b)).bg(Color::Rgb(15, 15, 25)));
+                    y_bar_chars[x] = (ch, Style::default().fg(theme.shade(Color::Rgb(r, g, b), Role::Violet, intensity)).bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
                 }
             }
             if beam_y_center < y_bar_w {
-                y_bar_chars[beam_y_center] = ('█', Style::default().fg(Color::Rgb(255, 200, 255)).bg(Color::Rgb(15, 15, 25)));
+                y_bar_chars[beam_y_center] = ('█', Style::default().fg(theme.paint(Color::Rgb(255, 200, 255), Role::Magenta)).bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
             }
         }
 
         // Center mark
         y_bar_chars[y_center] = if y_bar_chars[y_center].0 == ' ' {
-            ('┊', Style::default().fg(Color::Rgb(60, 60, 80)).bg(Color::Rgb(15, 15, 25)))
+            ('┊', Style::default().fg(theme.paint(Color::Rgb(60, 60, 80), Role::Faint)).bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)))
         } else {
             y_bar_chars[y_center]
         };
 
         let y_spans: Vec<Span> = y_bar_chars.iter().map(|(ch, s)| Span::styled(String::from(*ch), *s)).collect();
-        let y_color = if self.beam_y_position.abs() > 30.0 { Color::Red } else { Color::Rgb(120, 200, 255) };
+        let y_color = if self.beam_y_position.abs() > 30.0 { theme.paint(Color::Red, Role::Red) } else { theme.paint(Color::Rgb(120, 200, 255), Role::Blue) };
         y_bar_lines.push(Line::from(vec![
-            Span::styled(" Beam Y: ", Style::default().fg(Color::Rgb(140, 100, 160))),
+            Span::styled(" Beam Y: ", Style::default().fg(theme.paint(Color::Rgb(140, 100, 160), Role::Violet))),
             Span::styled(
                 format!("{:+.1}", self.beam_y_position),
                 Style::default().fg(y_color).add_modifier(Modifier::BOLD),
@@ -1156,7 +1867,7 @@ impl Game for BeamGame {
                     // Use different connector chars based on angle for a nicer arc
                     let tangent = a.cos().abs();
                     let ch = if tangent > 0.7 { '─' } else if tangent < 0.3 { '│' } else { '·' };
-                    grid[dy][dx] = (ch, Style::default().fg(Color::Rgb(30, 40, 55)));
+                    grid[dy][dx] = (ch, Style::default().fg(theme.paint(Color::Rgb(30, 40, 55), Role::Panel)));
                 }
             }
         }
@@ -1180,27 +1891,27 @@ impl Game for BeamGame {
             let trail_entry = self.trail.iter().rev().find(|(s, _, _)| *s == sec);
 
             let (ch, style) = if is_beam_here {
-                ('◉', Style::default().fg(Color::Rgb(100, 255, 255)).add_modifier(Modifier::BOLD))
+                ('◉', Style::default().fg(theme.paint(Color::Rgb(100, 255, 255), Role::Cyan)).add_modifier(Modifier::BOLD))
             } else if let Some((_, pos, _size)) = trail_entry {
                 let intensity = if pos.abs() < 10.0 { 200 } else if pos.abs() < 30.0 { 140 } else { 80 };
-                ('●', Style::default().fg(Color::Rgb(30, intensity as u8, (intensity + 30).min(255) as u8)))
+                ('●', Style::default().fg(theme.shade(Color::Rgb(30, intensity as u8, (intensity + 30).min(255) as u8), Role::Cyan, intensity as f32 / 200.0)))
             } else if is_bump_section {
                 // Highlight bump sections with coefficient indicator
                 let coeff = self.bump.as_ref().and_then(|b| b.coeff_for_section(sec)).unwrap_or(0.0);
                 let ch = if coeff > 0.0 { '⊕' } else { '⊖' };
                 let color = if coeff > 0.0 {
-                    Color::Rgb(80, 255, 180) // green for positive
+                    theme.paint(Color::Rgb(80, 255, 180), Role::Cyan) // green for positive
                 } else {
-                    Color::Rgb(255, 140, 80) // orange for negative
+                    theme.paint(Color::Rgb(255, 140, 80), Role::Orange) // orange for negative
                 };
                 (ch, Style::default().fg(color).add_modifier(Modifier::BOLD))
             } else if is_selected {
-                ('◈', Style::default().fg(Color::Rgb(255, 220, 80)).add_modifier(Modifier::BOLD))
+                ('◈', Style::default().fg(theme.paint(Color::Rgb(255, 220, 80), Role::Yellow)).add_modifier(Modifier::BOLD))
             } else if self.restrictions.iter().any(|r| r.section == sec) {
                 // Section has aperture restriction - show warning marker
-                ('◆', Style::default().fg(Color::Rgb(255, 80, 80)))
+                ('◆', Style::default().fg(theme.paint(Color::Rgb(255, 80, 80), Role::Red)))
             } else {
-                ('○', Style::default().fg(Color::Rgb(60, 80, 100)))
+                ('○', Style::default().fg(theme.paint(Color::Rgb(60, 80, 100), Role::Faint)))
             };
 
             grid[iy][ix] = (ch, style);
@@ -1225,11 +1936,11 @@ impl Game for BeamGame {
                     let nx = lx + i;
                     if nx < ring_w {
                         let col = if has_restriction {
-                            Color::Rgb(255, 100, 100)
+                            theme.paint(Color::Rgb(255, 100, 100), Role::Red)
                         } else if is_selected {
-                            Color::Rgb(255, 220, 80)
+                            theme.paint(Color::Rgb(255, 220, 80), Role::Yellow)
                         } else {
-                            Color::Rgb(60, 60, 80)
+                            theme.paint(Color::Rgb(60, 60, 80), Role::Faint)
                         };
                         grid[ly][nx] = (c, Style::default().fg(col));
                     }
@@ -1237,6 +1948,54 @@ impl Game for BeamGame {
             }
         }
 
+        // Hint orbit ghost: x as a nudge out from (or in toward) the ring
+        if let Some(hint) = self.hint.as_ref().filter(|_| self.show_hint) {
+            let style = Style::default().fg(theme.ink(hint_color(hint)));
+            for (i, &(x, _)) in hint.orbit.iter().enumerate() {
+                let a = ((i + 1) as f32 / TOTAL_MAGNETS as f32) * std::f32::consts::PI * 2.0 - std::f32::consts::FRAC_PI_2;
+                let stretch = 1.0 + (x / LOSS_ZONE).clamp(-1.0, 1.0) * 0.3;
+                let gx = (cx + rx * stretch * a.cos()) as usize;
+                let gy = (cy + ry * stretch * a.sin()) as usize;
+                if gx < ring_w && gy < ring_h && matches!(grid[gy][gx].0, ' ' | '─' | '│' | '·') {
+                    grid[gy][gx] = ('∙', style);
+                }
+            }
+        }
+
+        // First-turn preview: a dashed line, x as the same nudge as the hint
+        if let Some(preview) = &preview {
+            let point = |i: f32, x: f32| -> (f32, f32) {
+                let a = (i / TOTAL_MAGNETS as f32) * std::f32::consts::PI * 2.0 - std::f32::consts::FRAC_PI_2;
+                let stretch = 1.0 + (x / LOSS_ZONE).clamp(-1.0, 1.0) * 0.3;
+                (cx + rx * stretch * a.cos(), cy + ry * stretch * a.sin())
+            };
+            let style = Style::default().fg(theme.ink(preview_color(preview)));
+            let mut from = (0.0, self.target_x);
+            for (i, &(x, _)) in preview.path.iter().enumerate() {
+                let to = ((i + 1) as f32, x);
+                let (x0, y0) = point(from.0, from.1);
+                let (x1, y1) = point(to.0, to.1);
+                let (dx, dy) = (x1 - x0, (y1 - y0) * 2.0);
+                let ch = if dx.abs() > dy.abs() * 2.0 { '╌' }
+                    else if dy.abs() > dx.abs() * 2.0 { '╎' }
+                    else if dx * dy > 0.0 { '╲' } else { '╱' };
+                // Every third element left blank to break the line into dashes
+                if i % 3 != 2 {
+                    let (gx, gy) = (x1 as usize, y1 as usize);
+                    if gx < ring_w && gy < ring_h && matches!(grid[gy][gx].0, ' ' | '─' | '│' | '·') {
+                        grid[gy][gx] = (ch, style);
+                    }
+                }
+                from = to;
+            }
+            if let (Some(_), Some(&(x, _))) = (&preview.lost, preview.path.last()) {
+                let (lx, ly) = point(preview.path.len() as f32, x);
+                if (lx as usize) < ring_w && (ly as usize) < ring_h {
+                    grid[ly as usize][lx as usize] = ('✗', style.add_modifier(Modifier::BOLD));
+                }
+            }
+        }
+
         // Center text
         let center_text = if self.beam_completed {
             "✓ STABLE!"
@@ -1251,11 +2010,11 @@ impl Game for BeamGame {
         };
         let ctx = (cx as usize).saturating_sub(center_text.len() / 2);
         let cty = cy as usize;
-        let ct_color = if self.beam_completed { Color::Rgb(80, 255, 80) }
-            else if self.beam_lost { Color::Rgb(255, 80, 80) }
-            else if self.paused { Color::Rgb(255, 200, 50) }
-            else if self.beam_running { Color::Rgb(80, 200, 255) }
-            else { Color::Rgb(140, 140, 160) };
+        let ct_color = if self.beam_completed { theme.paint(Color::Rgb(80, 255, 80), Role::Green) }
+            else if self.beam_lost { theme.paint(Color::Rgb(255, 80, 80), Role::Red) }
+            else if self.paused { theme.paint(Color::Rgb(255, 200, 50), Role::Yellow) }
+            else if self.beam_running { theme.paint(Color::Rgb(80, 200, 255), Role::Blue) }
+            else { theme.paint(Color::Rgb(140, 140, 160), Role::Dim) };
         for (i, c) in center_text.chars().enumerate() {
             let x = ctx + i;
             if x < ring_w && cty < ring_h {
@@ -1274,9 +2033,9 @@ impl Game for BeamGame {
                 if x < ring_w && cty + 1 < ring_h {
                     let norm = (val.abs() / APERTURE).min(1.0);
                     let idx = (norm * 7.0) as usize;
-                    let color = if norm < 0.2 { Color::Rgb(50, 200, 100) }
-                        else if norm < 0.5 { Color::Rgb(200, 200, 50) }
-                        else { Color::Rgb(200, 60, 60) };
+                    let color = if norm < 0.2 { theme.paint(Color::Rgb(50, 200, 100), Role::Green) }
+                        else if norm < 0.5 { theme.paint(Color::Rgb(200, 200, 50), Role::Yellow) }
+                        else { theme.paint(Color::Rgb(200, 60, 60), Role::Red) };
                     grid[cty + 1][x] = (sparkline_chars[idx], Style::default().fg(color));
                 }
             }
@@ -1314,7 +2073,7 @@ impl Game for BeamGame {
             panel_lines.push(Line::from(vec![
                 Span::styled(
                     format!(" ⊕⊖ {}-BUMP", bump.size),
-                    Style::default().fg(Color::Rgb(80, 255, 200)).add_modifier(Modifier::BOLD),
+                    Style::default().fg(theme.paint(Color::Rgb(80, 255, 200), Role::Cyan)).add_modifier(Modifier::BOLD),
                 ),
             ]));
 
@@ -1322,9 +2081,9 @@ impl Game for BeamGame {
             for (s, c) in &sec_coeffs {
                 let sign_str = if *c > 0.0 { "+" } else { "−" };
                 let color = if *c > 0.0 {
-                    Color::Rgb(80, 255, 180)
+                    theme.paint(Color::Rgb(80, 255, 180), Role::Cyan)
                 } else {
-                    Color::Rgb(255, 140, 80)
+                    theme.paint(Color::Rgb(255, 140, 80), Role::Orange)
                 };
                 panel_lines.push(Line::from(vec![
                     Span::styled(
@@ -1342,14 +2101,14 @@ impl Game for BeamGame {
                 let ht_idx = s * MAGNETS_PER_SECTION + 5;
                 let vt_idx = s * MAGNETS_PER_SECTION + 4;
                 panel_lines.push(Line::from(vec![
-                    Span::styled(format!("  S{} ", s + 1), Style::default().fg(Color::Rgb(160, 160, 180))),
-                    Span::styled("HT", Style::default().fg(Color::Rgb(255, 180, 120))),
-                    Span::styled(format!("{:+.3}", self.magnets[ht_idx].power), Style::default().fg(Color::Rgb(255, 200, 140))),
+                    Span::styled(format!("  S{} ", s + 1), Style::default().fg(theme.paint(Color::Rgb(160, 160, 180), Role::Dim))),
+                    Span::styled("HT", Style::default().fg(theme.paint(Color::Rgb(255, 180, 120), Role::Orange))),
+                    Span::styled(format!("{:+.3}", self.magnets[ht_idx].power), Style::default().fg(theme.paint(Color::Rgb(255, 200, 140), Role::Orange))),
                 ]));
                 panel_lines.push(Line::from(vec![
                     Span::styled("     ", Style::default()),
-                    Span::styled("VT", Style::default().fg(Color::Rgb(200, 120, 255))),
-                    Span::styled(format!("{:+.3}", self.magnets[vt_idx].power), Style::default().fg(Color::Rgb(220, 160, 255))),
+                    Span::styled("VT", Style::default().fg(theme.paint(Color::Rgb(200, 120, 255), Role::Violet))),
+                    Span::styled(format!("{:+.3}", self.magnets[vt_idx].power), Style::default().fg(theme.paint(Color::Rgb(220, 160, 255), Role::Violet))),
                 ]));
             }
 
@@ -1358,31 +2117,31 @@ impl Game for BeamGame {
 
             // Controls summary (stacked for narrow panel)
             panel_lines.push(Line::from(vec![
-                Span::styled(" ↑↓", Style::default().fg(Color::Rgb(255, 255, 100)).add_modifier(Modifier::BOLD)),
-                Span::styled(" X+Y ", Style::default().fg(Color::Rgb(140, 140, 160))),
-                Span::styled("W/S", Style::default().fg(Color::Rgb(255, 180, 120)).add_modifier(Modifier::BOLD)),
-                Span::styled(" X", Style::default().fg(Color::Rgb(140, 140, 160))),
+                Span::styled(" ↑↓", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow)).add_modifier(Modifier::BOLD)),
+                Span::styled(" X+Y ", Style::default().fg(theme.paint(Color::Rgb(140, 140, 160), Role::Dim))),
+                Span::styled("W/S", Style::default().fg(theme.paint(Color::Rgb(255, 180, 120), Role::Orange)).add_modifier(Modifier::BOLD)),
+                Span::styled(" X", Style::default().fg(theme.paint(Color::Rgb(140, 140, 160), Role::Dim))),
             ]));
             panel_lines.push(Line::from(vec![
-                Span::styled(" E/Q", Style::default().fg(Color::Rgb(200, 120, 255)).add_modifier(Modifier::BOLD)),
-                Span::styled(" Y ", Style::default().fg(Color::Rgb(140, 140, 160))),
-                Span::styled("←→", Style::default().fg(Color::Rgb(120, 220, 255)).add_modifier(Modifier::BOLD)),
-                Span::styled(" shift", Style::default().fg(Color::Rgb(140, 140, 160))),
+                Span::styled(" E/Q", Style::default().fg(theme.paint(Color::Rgb(200, 120, 255), Role::Violet)).add_modifier(Modifier::BOLD)),
+                Span::styled(" Y ", Style::default().fg(theme.paint(Color::Rgb(140, 140, 160), Role::Dim))),
+                Span::styled("←→", Style::default().fg(theme.paint(Color::Rgb(120, 220, 255), Role::Cyan)).add_modifier(Modifier::BOLD)),
+                Span::styled(" shift", Style::default().fg(theme.paint(Color::Rgb(140, 140, 160), Role::Dim))),
             ]));
             panel_lines.push(Line::from(vec![
-                Span::styled(" Z", Style::default().fg(Color::Rgb(255, 200, 80)).add_modifier(Modifier::BOLD)),
-                Span::styled(" zero ", Style::default().fg(Color::Rgb(140, 140, 160))),
-                Span::styled("B", Style::default().fg(Color::Rgb(140, 140, 160)).add_modifier(Modifier::BOLD)),
-                Span::styled(" exit", Style::default().fg(Color::Rgb(140, 140, 160))),
+                Span::styled(" Z", Style::default().fg(theme.paint(Color::Rgb(255, 200, 80), Role::Orange)).add_modifier(Modifier::BOLD)),
+                Span::styled(" zero ", Style::default().fg(theme.paint(Color::Rgb(140, 140, 160), Role::Dim))),
+                Span::styled("B", Style::default().fg(theme.paint(Color::Rgb(140, 140, 160), Role::Dim)).add_modifier(Modifier::BOLD)),
+                Span::styled(" exit", Style::default().fg(theme.paint(Color::Rgb(140, 140, 160), Role::Dim))),
             ]));
 
             let detail = Paragraph::new(panel_lines)
                 .block(Block::default()
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
-                    .border_style(Style::default().fg(Color::Rgb(60, 180, 140)))
+                    .border_style(Style::default().fg(theme.paint(Color::Rgb(60, 180, 140), Role::Cyan)))
                     .title(format!(" {}-Bump ", bump.size))
-                    .title_style(Style::default().fg(Color::Rgb(80, 255, 200)).add_modifier(Modifier::BOLD)));
+                    .title_style(Style::default().fg(theme.paint(Color::Rgb(80, 255, 200), Role::Cyan)).add_modifier(Modifier::BOLD)));
             frame.render_widget(detail, left_chunks[1]);
         } else {
             // Normal magnet detail panel - vertical layout with magnets stacked
@@ -1392,15 +2151,15 @@ impl Game for BeamGame {
             panel_lines.push(Line::from(vec![
                 Span::styled(
                     format!(" Sec {}/{}", sec + 1, NUM_SECTIONS),
-                    Style::default().fg(Color::Rgb(200, 200, 220)).add_modifier(Modifier::BOLD),
+                    Style::default().fg(theme.paint(Color::Rgb(200, 200, 220), Role::Text)).add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
                     format!(" R{}", self.selected_ramp),
-                    Style::default().fg(Color::Rgb(180, 140, 255)).add_modifier(Modifier::BOLD),
+                    Style::default().fg(theme.paint(Color::Rgb(180, 140, 255), Role::Violet)).add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
                     format!(" ⚡{:.3}", self.adjust_speed),
-                    Style::default().fg(Color::Rgb(140, 140, 160)),
+                    Style::default().fg(theme.paint(Color::Rgb(140, 140, 160), Role::Dim)),
                 ),
             ]));
 
@@ -1418,7 +2177,7 @@ impl Game for BeamGame {
                 let mut spans: Vec<Span> = Vec::new();
                 // Selection indicator
                 if is_sel {
-                    spans.push(Span::styled(" ▸", Style::default().fg(Color::Rgb(255, 255, 100))));
+                    spans.push(Span::styled(" ▸", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))));
                 } else {
                     spans.push(Span::styled("  ", Style::default()));
                 }
@@ -1426,25 +2185,40 @@ impl Game for BeamGame {
                 spans.push(Span::styled(
                     format!("{}", mag.mag_type.label()),
                     Style::default()
-                        .fg(if is_sel { Color::Rgb(255, 255, 255) } else { mag.mag_type.color() })
+                        .fg(if is_sel { theme.paint(Color::Rgb(255, 255, 255), Role::Bright) } else { theme.ink(mag.mag_type.color()) })
                         .add_modifier(if is_sel { Modifier::BOLD } else { Modifier::empty() }),
                 ));
                 // Power value
                 spans.push(Span::styled(
                     format!(" {:+.4} ", mag.power),
                     Style::default()
-                        .fg(if is_sel { Color::Rgb(255, 220, 80) } else { Color::Rgb(120, 120, 150) })
+                        .fg(if is_sel { theme.paint(Color::Rgb(255, 220, 80), Role::Yellow) } else { theme.paint(Color::Rgb(120, 120, 150), Role::Dim) })
                         .add_modifier(if is_sel { Modifier::BOLD } else { Modifier::empty() }),
                 ));
-                // Mini power bar
+                // Mini power bar, or the hint's power where it differs
                 let bar_color = mag.mag_type.color();
-                for i in 0..bar_width {
-                    if i < filled {
-                        spans.push(Span::styled("█", Style::default().fg(bar_color)));
-                    } else {
-                        spans.push(Span::styled("░", Style::default().fg(Color::Rgb(35, 35, 50))));
+                let hinted = self.hint.as_ref()
+                    .filter(|_| self.show_hint)
+                    .map(|h| (h.powers[sec_base + e], hint_color(h)))
+                    .filter(|(p, _)| (p - mag.power).abs() >= 5e-5);
+                if let Some((power, color)) = hinted {
+                    spans.push(Span::styled(format!("→{:+.4}", power), Style::default().fg(theme.ink(color))));
+                } else {
+                    for i in 0..bar_width {
+                        if i < filled {
+                            spans.push(Span::styled("█", Style::default().fg(theme.ink(bar_color))));
+                        } else {
+                            spans.push(Span::styled("░", Style::default().fg(theme.paint(Color::Rgb(35, 35, 50), Role::Panel))));
+                        }
                     }
                 }
+                // Fault flags: found, or ruled out by a wrong guess
+                let idx = sec_base + e;
+                if self.diagnosed && self.fault.is_some_and(|f| f.implicates(idx)) {
+                    spans.push(Span::styled(" ⚑", Style::default().fg(theme.paint(Color::Rgb(80, 255, 80), Role::Green))));
+                } else if self.wrong_flags.contains(&idx) {
+                    spans.push(Span::styled(" ✗", Style::default().fg(theme.paint(Color::Rgb(255, 120, 80), Role::Red))));
+                }
 
                 panel_lines.push(Line::from(spans));
             }
@@ -1454,25 +2228,25 @@ impl Game for BeamGame {
 
             // Controls hint
             panel_lines.push(Line::from(vec![
-                Span::styled(" ↑↓", Style::default().fg(Color::Rgb(255, 255, 100))),
-                Span::styled(" mag ", Style::default().fg(Color::Rgb(100, 100, 130))),
-                Span::styled("←→", Style::default().fg(Color::Rgb(255, 255, 100))),
-                Span::styled(" pow", Style::default().fg(Color::Rgb(100, 100, 130))),
+                Span::styled(" ↑↓", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                Span::styled(" mag ", Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint))),
+                Span::styled("←→", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                Span::styled(" pow", Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint))),
             ]));
             panel_lines.push(Line::from(vec![
-                Span::styled(" []", Style::default().fg(Color::Rgb(255, 255, 100))),
-                Span::styled(" sec ", Style::default().fg(Color::Rgb(100, 100, 130))),
-                Span::styled("+/-", Style::default().fg(Color::Rgb(255, 255, 100))),
-                Span::styled(" step", Style::default().fg(Color::Rgb(100, 100, 130))),
+                Span::styled(" []", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                Span::styled(" sec ", Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint))),
+                Span::styled("+/-", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                Span::styled(" step", Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint))),
             ]));
 
             let detail = Paragraph::new(panel_lines)
                 .block(Block::default()
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
-                    .border_style(Style::default().fg(Color::Rgb(60, 100, 140)))
+                    .border_style(Style::default().fg(theme.paint(Color::Rgb(60, 100, 140), Role::Blue)))
                     .title(" Magnet Control ")
-                    .title_style(Style::default().fg(Color::Rgb(120, 200, 255))));
+                    .title_style(Style::default().fg(theme.paint(Color::Rgb(120, 200, 255), Role::Blue))));
             frame.render_widget(detail, left_chunks[1]);
         }
 
@@ -1481,9 +2255,9 @@ impl Game for BeamGame {
             let bull_block = Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(Color::Rgb(80, 80, 120)))
+                .border_style(Style::default().fg(theme.paint(Color::Rgb(80, 80, 120), Role::Faint)))
                 .title(format!(" Orbit ({:+.1},{:+.1}) ", self.target_x, self.target_y))
-                .title_style(Style::default().fg(Color::Rgb(255, 200, 80)));
+                .title_style(Style::default().fg(theme.paint(Color::Rgb(255, 200, 80), Role::Orange)));
             let bull_inner = bull_block.inner(left_chunks[0]);
             frame.render_widget(bull_block, left_chunks[0]);
 
@@ -1497,23 +2271,23 @@ impl Game for BeamGame {
                 let sy = bcy / plot_range;
 
                 let mut bgrid: Vec<Vec<(char, Style)>> =
-                    vec![vec![(' ', Style::default().bg(Color::Rgb(10, 10, 18))); bw]; bh];
+                    vec![vec![(' ', Style::default().bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background))); bw]; bh];
 
                 // Draw faint crosshair axes through center
                 let cx_i = bcx as usize;
                 let cy_i = bcy as usize;
                 for x in 0..bw {
                     if cy_i < bh {
-                        bgrid[cy_i][x] = ('─', Style::default().fg(Color::Rgb(25, 25, 40)).bg(Color::Rgb(10, 10, 18)));
+                        bgrid[cy_i][x] = ('─', Style::default().fg(theme.paint(Color::Rgb(25, 25, 40), Role::Panel)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
                     }
                 }
                 for y in 0..bh {
                     if cx_i < bw {
-                        bgrid[y][cx_i] = ('│', Style::default().fg(Color::Rgb(25, 25, 40)).bg(Color::Rgb(10, 10, 18)));
+                        bgrid[y][cx_i] = ('│', Style::default().fg(theme.paint(Color::Rgb(25, 25, 40), Role::Panel)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
                     }
                 }
                 if cx_i < bw && cy_i < bh {
-                    bgrid[cy_i][cx_i] = ('┼', Style::default().fg(Color::Rgb(30, 30, 50)).bg(Color::Rgb(10, 10, 18)));
+                    bgrid[cy_i][cx_i] = ('┼', Style::default().fg(theme.paint(Color::Rgb(30, 30, 50), Role::Panel)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
                 }
 
                 // Draw bullseye rings around target
@@ -1527,13 +2301,13 @@ impl Game for BeamGame {
                     let px = (tx as f32 + rx_r * a.cos()) as usize;
                     let py = (ty as f32 + ry_r * a.sin()) as usize;
                     if px < bw && py < bh && bgrid[py][px].0 == ' ' || (px < bw && py < bh && bgrid[py][px].0 == '─') || (px < bw && py < bh && bgrid[py][px].0 == '│') {
-                        bgrid[py][px] = ('·', Style::default().fg(Color::Rgb(60, 50, 30)).bg(Color::Rgb(10, 10, 18)));
+                        bgrid[py][px] = ('·', Style::default().fg(theme.paint(Color::Rgb(60, 50, 30), Role::Panel)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
                     }
                 }
 
                 // Draw target marker
                 if tx < bw && ty < bh {
-                    bgrid[ty][tx] = ('◎', Style::default().fg(Color::Rgb(255, 200, 80)).bg(Color::Rgb(10, 10, 18)).add_modifier(Modifier::BOLD));
+                    bgrid[ty][tx] = ('◎', Style::default().fg(theme.paint(Color::Rgb(255, 200, 80), Role::Orange)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)).add_modifier(Modifier::BOLD));
                 }
 
                 // Draw turn positions (older = dimmer)
@@ -1546,13 +2320,13 @@ impl Game for BeamGame {
                         let brightness = (1.0 - age / 12.0).max(0.3);
                         let dist = ((px - self.target_x).powi(2) + (py - self.target_y).powi(2)).sqrt();
                         let (ch, color) = if dist < 2.0 {
-                            ('●', Color::Rgb((80.0 * brightness) as u8, (255.0 * brightness) as u8, (80.0 * brightness) as u8))
+                            ('●', theme.shade(Color::Rgb((80.0 * brightness) as u8, (255.0 * brightness) as u8, (80.0 * brightness) as u8), Role::Green, brightness))
                         } else if dist < 8.0 {
-                            ('●', Color::Rgb((255.0 * brightness) as u8, (255.0 * brightness) as u8, (50.0 * brightness) as u8))
+                            ('●', theme.shade(Color::Rgb((255.0 * brightness) as u8, (255.0 * brightness) as u8, (50.0 * brightness) as u8), Role::Yellow, brightness))
                         } else {
-                            ('●', Color::Rgb((255.0 * brightness) as u8, (60.0 * brightness) as u8, (60.0 * brightness) as u8))
+                            ('●', theme.shade(Color::Rgb((255.0 * brightness) as u8, (60.0 * brightness) as u8, (60.0 * brightness) as u8), Role::Red, brightness))
                         };
-                        bgrid[dot_y][dot_x] = (ch, Style::default().fg(color).bg(Color::Rgb(10, 10, 18)));
+                        bgrid[dot_y][dot_x] = (ch, Style::default().fg(color).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
                     }
                 }
 
@@ -1561,7 +2335,7 @@ impl Game for BeamGame {
                     let cur_x = (bcx + self.beam_position * sx) as usize;
                     let cur_y = (bcy - self.beam_y_position * sy) as usize;
                     if cur_x < bw && cur_y < bh {
-                        bgrid[cur_y][cur_x] = ('◆', Style::default().fg(Color::Rgb(100, 255, 255)).bg(Color::Rgb(10, 10, 18)).add_modifier(Modifier::BOLD));
+                        bgrid[cur_y][cur_x] = ('◆', Style::default().fg(theme.paint(Color::Rgb(100, 255, 255), Role::Cyan)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)).add_modifier(Modifier::BOLD));
                     }
                 }
 
@@ -1577,45 +2351,140 @@ impl Game for BeamGame {
         }
 
         // Help bar
-        if self.beam_lost {
+        if let Some((mode, ref buffer)) = self.text_input {
+            let label = match mode {
+                TextInput::ChallengeName => " Challenge name: ",
+                TextInput::ShareCode => " Paste code: ",
+                TextInput::ConfigName => " Config name: ",
+            };
+            let prompt = Paragraph::new(Line::from(vec![
+                Span::styled(label, Style::default().fg(theme.paint(Color::Rgb(255, 200, 80), Role::Orange)).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{}_", buffer), Style::default().fg(theme.paint(Color::White, Role::Bright)).add_modifier(Modifier::BOLD)),
+                Span::styled("  Enter OK │ Esc Cancel │ Bksp Delete", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+            ]));
+            frame.render_widget(prompt, chunks[4]);
+        } else if self.editing {
+            let help = Paragraph::new(Line::from(vec![
+                Span::styled(" EDITOR ", Style::default().fg(theme.paint(Color::Rgb(255, 200, 80), Role::Orange)).add_modifier(Modifier::BOLD)),
+                Span::styled("│ ←→ Sec │ X/Y Restrict │ ↑↓ J/L Target │ C Clear │ S Save+Share │ A/Enter Done │ ? Help",
+                    Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+            ]));
+            frame.render_widget(help, chunks[4]);
+        } else if self.beam_lost {
             let msg = Paragraph::new(Line::from(vec![
-                Span::styled(" ✗ BEAM LOST! ", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
-                Span::styled("Adjust magnets and press ENTER to retry, Esc for menu", Style::default().fg(Color::Gray)),
+                Span::styled(" ✗ BEAM LOST! ", Style::default().fg(theme.ink(fx::alert_color())).add_modifier(Modifier::BOLD)),
+                Span::styled("Adjust magnets and press ENTER to retry, Esc for menu", Style::default().fg(theme.paint(Color::Gray, Role::Dim))),
+            ]));
+            frame.render_widget(msg, chunks[4]);
+        } else if self.time_attack_done() {
+            let msg = Paragraph::new(Line::from(vec![
+                Span::styled(
+                    format!(" ⏱ ORBIT ESTABLISHED in {}! ", format_score(TIME_ATTACK_TABLE, self.time_attack_score())),
+                    Style::default().fg(theme.paint(Color::Green, Role::Green)).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled("Press ENTER to try again, T to leave time attack", Style::default().fg(theme.paint(Color::Gray, Role::Dim))),
             ]));
             frame.render_widget(msg, chunks[4]);
         } else if self.beam_completed {
             let msg = Paragraph::new(Line::from(vec![
                 Span::styled(
-                format!(" ✓ BEAM STABLE! {} turns! Score: {:.4} ", GOAL_TURNS, self.magnets.iter().map(|m| m.power.abs()).sum::<f32>()),
-                Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+                format!(" ✓ BEAM STABLE! {} turns! Economy {:.0}%, score: {} ", GOAL_TURNS,
+                    self.economy().unwrap_or(0.0) * 100.0, self.get_score()),
+                Style::default().fg(theme.paint(Color::Green, Role::Green)).add_modifier(Modifier::BOLD),
             ),
-                Span::styled("Press ENTER to play again", Style::default().fg(Color::Gray)),
+                Span::styled("Press ENTER to play again", Style::default().fg(theme.paint(Color::Gray, Role::Dim))),
             ]));
             frame.render_widget(msg, chunks[4]);
         } else if self.bump.is_some() {
             // Bump mode help bar
             let help = Paragraph::new(Line::from(vec![
-                Span::styled(" BUMP ", Style::default().fg(Color::Rgb(80, 255, 200)).add_modifier(Modifier::BOLD)),
+                Span::styled(" BUMP ", Style::default().fg(theme.paint(Color::Rgb(80, 255, 200), Role::Cyan)).add_modifier(Modifier::BOLD)),
                 Span::styled("│ ↑↓ X+Y │ W/S X │ E/Q Y │ ←→ Shift │ 0-9 Ramp │ Z Zero │ B Cycle/Exit │ +/- Step │ P │ Esc │ ? Help",
-                    Style::default().fg(Color::DarkGray)),
+                    Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             ]));
             frame.render_widget(help, chunks[4]);
         } else {
             let help = Paragraph::new(Line::from(vec![
                 Span::styled(if self.beam_running { " SPACE: running " } else { " SPACE: start " },
-                    Style::default().fg(if self.beam_running { Color::Green } else { Color::Yellow })),
-                Span::styled("│ ↑↓ Mag │ ←→ Pow │ [] Sec │ 0-9 Ramp │ B Bump │ C Copy │ +/- Step │ Z Zero │ D Diff │ P │ Esc │ ? Help",
-                    Style::default().fg(Color::DarkGray)),
+                    Style::default().fg(if self.beam_running { theme.paint(Color::Green, Role::Green) } else { theme.paint(Color::Yellow, Role::Yellow) })),
+                Span::styled(
+                    format!(
+                        "│ ↑↓ Mag │ ←→ Pow │ [] Sec │ 0-9 Ramp │ B Bump │ C Copy │ +/- Step │ Z Zero │ D Diff │ T Time │ M Fault{} │ A Edit │ G/N/U Challenge │ O/V Save/Compare │ H Hint │ P │ Esc │ ? Help",
+                        if self.fault.is_some() { " │ F Flag" } else { "" },
+                    ),
+                    Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             ]));
             frame.render_widget(help, chunks[4]);
         }
+
+        self.ring_area = middle[1];
+        self.magnet_area = left_chunks[1];
+
+        if let Some(compare) = &self.compare {
+            compare.render(frame, inner, theme);
+        }
+    }
+
+    fn wants_text_input(&self) -> bool {
+        self.text_input.is_some() || self.compare.is_some()
+    }
+
+    /// A fresh layout from the new stream; only the difficulty carries over.
+    fn reseed(&mut self, rng: GameRng) {
+        let difficulty = self.difficulty;
+        *self = BeamGame::with_rng(rng);
+        self.difficulty = difficulty;
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent) {
+        if self.paused || self.beam_lost || self.beam_completed { return; }
+        let (col, row) = (event.column, event.row);
+        let step = match event.kind {
+            MouseEventKind::ScrollUp => 1.0,
+            MouseEventKind::ScrollDown => -1.0,
+            MouseEventKind::Down(MouseButton::Left) => {
+                // Click a ring section to edit it (or move the bump there)
+                if let Some(sec) = self.ring_section_at(col, row) {
+                    match self.bump {
+                        Some(ref mut bump) => bump.start_section = sec,
+                        None => self.selected = sec * MAGNETS_PER_SECTION + self.selected_element(),
+                    }
+                } else if let Some(elem) = self.magnet_row_at(col, row) {
+                    self.selected = self.selected_section() * MAGNETS_PER_SECTION + elem;
+                }
+                return;
+            }
+            _ => return,
+        };
+
+        // Wheel over the ring steps through sections
+        if self.bump.is_none() && self.ring_area.contains(Position::new(col, row)) {
+            if step > 0.0 { self.next_section(); } else { self.prev_section(); }
+            return;
+        }
+
+        // Wheel over the magnet panel adjusts the power under the cursor
+        if !self.magnet_area.contains(Position::new(col, row)) { return; }
+        let delta = step * self.adjust_speed;
+        if let Some(ref bump) = self.bump {
+            for (sec, coeff) in bump.section_coefficients() {
+                self.adjust_ramp_power(sec * MAGNETS_PER_SECTION + 5, delta * coeff);
+                self.adjust_ramp_power(sec * MAGNETS_PER_SECTION + 4, delta * coeff);
+            }
+        } else if let Some(elem) = self.magnet_row_at(col, row) {
+            self.selected = self.selected_section() * MAGNETS_PER_SECTION + elem;
+            self.adjust_ramp_power(self.selected, delta);
+        }
     }
 
     fn get_score(&self) -> u32 {
-        let score: f32 = self.magnets.iter().map(|m| m.power.abs()).sum();
-        (score * 100.0) as u32
+        // Time attack runs go to their own table (see time_attack_score)
+        if self.time_attack { return 0; }
+        let economy = self.economy().unwrap_or(0.0);
+        (economy * ECONOMY_POINTS) as u32 + self.diagnosis_bonus()
     }
     fn is_game_over(&self) -> bool { self.beam_completed }
+    fn is_paused(&self) -> bool { self.paused }
 
     fn reset(&mut self) {
         let best = self.best_turns;
@@ -1629,7 +2498,29 @@ impl Game for BeamGame {
         let selected_ramp = self.selected_ramp;
         let target_x = self.target_x;
         let target_y = self.target_y;
-        *self = BeamGame::new();
+        let challenge_name = self.challenge_name.take();
+        let pool_index = self.pool_index;
+        let (time_attack, ta_elapsed) = (self.time_attack, self.ta_elapsed);
+        // The fault stays in place for retries; a finished run gets a new one
+        let completed = self.beam_completed;
+        let (faults, fault, diagnosed) = (self.faults, self.fault, self.diagnosed);
+        let wrong_flags = std::mem::take(&mut self.wrong_flags);
+        let show_hint = self.show_hint;
+        *self = BeamGame::with_rng(self.rng.clone());
+        self.show_hint = show_hint;
+        self.faults = faults;
+        if faults && completed {
+            self.arm_fault();
+        } else {
+            self.fault = fault;
+            self.diagnosed = diagnosed;
+            self.wrong_flags = wrong_flags;
+        }
+        // A retry keeps the time attack clock running
+        self.time_attack = time_attack;
+        self.ta_elapsed = ta_elapsed;
+        self.challenge_name = challenge_name;
+        self.pool_index = pool_index;
         self.best_turns = best;
         self.target_x = target_x;
         self.target_y = target_y;
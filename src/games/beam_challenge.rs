@@ -0,0 +1,137 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::scores::{data_path, now_secs};
+
+const CODE_PREFIX: &str = "BEAM1";
+const FILE_EXT: &str = "beam";
+
+/// One-sided aperture restriction in a ring section.
+#[derive(Clone, PartialEq)]
+pub struct Restriction {
+    pub section: usize,          // which section (0-based)
+    pub axis: char,              // 'x' or 'y'
+    pub positive_blocked: bool,  // true = blocks positive side (val > 0), false = blocks negative (val < 0)
+}
+
+impl Restriction {
+    pub fn label(&self) -> String {
+        let sign = if self.positive_blocked { "≤0" } else { "≥0" };
+        format!("{}{}", self.axis, sign)
+    }
+
+    pub fn check(&self, x: f32, y: f32) -> bool {
+        let val = if self.axis == 'x' { x } else { y };
+        if self.positive_blocked { val > 0.0 } else { val < 0.0 }
+    }
+}
+
+/// A restriction set plus target orbit that can be saved, shared as a
+/// one-line code, and replayed by anyone.
+///
+/// Share code: `BEAM1;<name>;<restrictions>;<target x>,<target y>` where each
+/// restriction is `<axis><section 1-24><+|->`, `+` blocking the positive side,
+/// e.g. `BEAM1;Dogleg;x3+,x15-,y8+;2.0,-1.5`.
+#[derive(Clone)]
+pub struct Challenge {
+    pub name: String,
+    pub restrictions: Vec<Restriction>,
+    pub target_x: f32,
+    pub target_y: f32,
+}
+
+/// Hand-picked challenges the daily rotation draws from.
+const CURATED: [&str; 8] = [
+    "BEAM1;Warm-up;x6+,y18-;0.0,0.0",
+    "BEAM1;Slalom;x3+,x9-,x15+,x21-;0.0,0.0",
+    "BEAM1;Corkscrew;x2+,y8+,x14-,y20-;1.5,1.5",
+    "BEAM1;Keyhole;x12+,x12-,y12+;0.0,-2.0",
+    "BEAM1;Low Road;y4+,y10+,y16+,y22+;2.0,-3.0",
+    "BEAM1;Crossfire;x5+,y5-,x17-,y17+;-2.5,2.5",
+    "BEAM1;Dogleg;x3+,x4+,x15-,x16-,y9+;3.0,0.0",
+    "BEAM1;Gauntlet;x1-,y4+,x7+,y10-,x13-,y16+,x19+,y22-;-1.0,1.0",
+];
+
+impl Challenge {
+    pub fn share_code(&self) -> String {
+        let restrictions: Vec<String> = self.restrictions.iter()
+            .map(|r| format!("{}{}{}", r.axis, r.section + 1, if r.positive_blocked { '+' } else { '-' }))
+            .collect();
+        format!(
+            "{};{};{};{:.1},{:.1}",
+            CODE_PREFIX,
+            self.name,
+            restrictions.join(","),
+            self.target_x,
+            self.target_y,
+        )
+    }
+
+    pub fn from_code(code: &str) -> Option<Challenge> {
+        let mut parts = code.trim().split(';');
+        if parts.next()? != CODE_PREFIX { return None; }
+        let name = parts.next()?.trim().to_string();
+        let restriction_list = parts.next()?;
+        let (tx, ty) = parts.next()?.split_once(',')?;
+        if parts.next().is_some() { return None; }
+
+        let mut restrictions = Vec::new();
+        for item in restriction_list.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            let axis = item.chars().next()?;
+            let positive_blocked = match item.chars().last()? {
+                '+' => true,
+                '-' => false,
+                _ => return None,
+            };
+            let section: usize = item.get(1..item.len() - 1)?.parse().ok()?;
+            if !matches!(axis, 'x' | 'y') || !(1..=24).contains(&section) { return None; }
+            restrictions.push(Restriction { section: section - 1, axis, positive_blocked });
+        }
+
+        let target_x: f32 = tx.trim().parse().ok()?;
+        let target_y: f32 = ty.trim().parse().ok()?;
+        if !target_x.is_finite() || !target_y.is_finite() { return None; }
+        Some(Challenge {
+            name: if name.is_empty() { "Untitled".to_string() } else { name },
+            restrictions,
+            target_x: target_x.clamp(-20.0, 20.0),
+            target_y: target_y.clamp(-20.0, 20.0),
+        })
+    }
+
+    /// Today's challenge; the same for every player on a given UTC day.
+    pub fn daily() -> Challenge {
+        let day = (now_secs() / 86_400) as usize;
+        Challenge::from_code(CURATED[day % CURATED.len()]).expect("curated challenge codes are valid")
+    }
+
+    /// Curated challenges followed by the player's saved and imported ones.
+    pub fn pool() -> Vec<Challenge> {
+        let mut pool: Vec<Challenge> = CURATED.iter().filter_map(|c| Challenge::from_code(c)).collect();
+        if let Ok(entries) = fs::read_dir(challenge_dir()) {
+            let mut files: Vec<PathBuf> = entries.flatten()
+                .map(|e| e.path())
+                .filter(|p| p.extension().is_some_and(|e| e == FILE_EXT))
+                .collect();
+            files.sort();
+            pool.extend(files.iter().filter_map(|p| Challenge::from_code(&fs::read_to_string(p).ok()?)));
+        }
+        pool
+    }
+
+    /// Write to the challenges folder; returns the file name on success.
+    pub fn save(&self) -> Option<String> {
+        let dir = challenge_dir();
+        fs::create_dir_all(&dir).ok()?;
+        let stem: String = self.name.chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+            .collect();
+        let file_name = format!("{}.{}", stem, FILE_EXT);
+        fs::write(dir.join(&file_name), self.share_code()).ok()?;
+        Some(file_name)
+    }
+}
+
+fn challenge_dir() -> PathBuf {
+    data_path("challenges")
+}
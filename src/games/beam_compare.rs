@@ -0,0 +1,323 @@
+use std::fs;
+use std::path::PathBuf;
+
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::scores::data_path;
+use crate::ui::hud;
+use crate::ui::theme::{Role, Theme};
+
+const CONFIG_PREFIX: &str = "BEAMCFG1";
+const FILE_EXT: &str = "beamcfg";
+
+/// Closed orbit differences above this (mm) are highlighted.
+const ORBIT_TOLERANCE: f32 = 0.5;
+
+/// One plane's effect on (position, angle): `z → m·z + c`. Every Beam
+/// element is affine, so a whole ring folds into one of these per plane.
+#[derive(Clone, Copy)]
+pub struct PlaneMap {
+    pub m: [[f32; 2]; 2],
+    pub c: [f32; 2],
+}
+
+impl PlaneMap {
+    pub const IDENTITY: PlaneMap = PlaneMap { m: [[1.0, 0.0], [0.0, 1.0]], c: [0.0, 0.0] };
+
+    /// Thin lens: the angle changes by `k` times the position.
+    pub fn lens(k: f32) -> Self {
+        PlaneMap { m: [[1.0, 0.0], [k, 1.0]], c: [0.0, 0.0] }
+    }
+
+    /// Fixed change of angle, as from a dipole or trim.
+    pub fn kick(angle: f32) -> Self {
+        PlaneMap { c: [0.0, angle], ..Self::IDENTITY }
+    }
+
+    /// Straight path: the position moves by `length` times the angle.
+    pub fn drift(length: f32) -> Self {
+        PlaneMap { m: [[1.0, length], [0.0, 1.0]], c: [0.0, 0.0] }
+    }
+
+    pub fn apply(&self, (pos, angle): (f32, f32)) -> (f32, f32) {
+        (
+            self.m[0][0] * pos + self.m[0][1] * angle + self.c[0],
+            self.m[1][0] * pos + self.m[1][1] * angle + self.c[1],
+        )
+    }
+
+    /// This map followed by `next`.
+    pub fn then(&self, next: &PlaneMap) -> PlaneMap {
+        let n = &next.m;
+        let mut m = [[0.0; 2]; 2];
+        for (row, out) in m.iter_mut().enumerate() {
+            for (col, value) in out.iter_mut().enumerate() {
+                *value = n[row][0] * self.m[0][col] + n[row][1] * self.m[1][col];
+            }
+        }
+        let (c0, c1) = next.apply((self.c[0], self.c[1]));
+        PlaneMap { m, c: [c0, c1] }
+    }
+
+    pub fn trace(&self) -> f32 {
+        self.m[0][0] + self.m[1][1]
+    }
+
+    /// Oscillations about the closed orbit stay bounded.
+    pub fn stable(&self) -> bool {
+        self.trace().abs() < 2.0
+    }
+
+    /// The (position, angle) that comes back to itself, if there is one.
+    pub fn fixed_point(&self) -> Option<(f32, f32)> {
+        let (a, b) = (1.0 - self.m[0][0], -self.m[0][1]);
+        let (c, d) = (-self.m[1][0], 1.0 - self.m[1][1]);
+        let det = a * d - b * c;
+        if det.abs() < 1e-6 { return None; }
+        let pos = (d * self.c[0] - b * self.c[1]) / det;
+        let angle = (a * self.c[1] - c * self.c[0]) / det;
+        (pos.is_finite() && angle.is_finite()).then_some((pos, angle))
+    }
+}
+
+/// What a magnet configuration does to the beam, worked out from the
+/// element maps without running it.
+pub struct Prediction {
+    /// One-turn maps from the injection point, x then y
+    pub turn: [PlaneMap; 2],
+    /// Closed orbit (x, y) at the end of each section; `None` if either
+    /// plane has no closed orbit
+    pub orbit: Option<Vec<(f32, f32)>>,
+}
+
+impl Prediction {
+    /// `elements` holds the (x, y) maps of every magnet in ring order.
+    pub fn new(elements: &[[PlaneMap; 2]], per_section: usize) -> Self {
+        let turn = elements.iter().fold([PlaneMap::IDENTITY; 2], |[x, y], [ex, ey]| [x.then(ex), y.then(ey)]);
+        let orbit = turn[0].fixed_point().zip(turn[1].fixed_point()).map(|(mut x, mut y)| {
+            elements.chunks(per_section)
+                .map(|section| {
+                    for [ex, ey] in section {
+                        x = ex.apply(x);
+                        y = ey.apply(y);
+                    }
+                    (x.0, y.0)
+                })
+                .collect()
+        });
+        Prediction { turn, orbit }
+    }
+
+    /// Largest closed orbit difference from `other`, in either plane;
+    /// infinite when only one of them closes, `None` when neither does.
+    pub fn orbit_shift(&self, other: &Prediction) -> Option<f32> {
+        match (&self.orbit, &other.orbit) {
+            (Some(a), Some(b)) => Some(a.iter().zip(b)
+                .map(|(p, q)| (p.0 - q.0).abs().max((p.1 - q.1).abs()))
+                .fold(0.0, f32::max)),
+            (None, None) => None,
+            _ => Some(f32::INFINITY),
+        }
+    }
+}
+
+/// A set of magnet powers saved under a name for later comparison.
+///
+/// File: `BEAMCFG1;<name>;<power>,<power>,...` with one power per magnet
+/// in ring order.
+#[derive(Clone)]
+pub struct SavedConfig {
+    pub name: String,
+    pub powers: Vec<f32>,
+}
+
+impl SavedConfig {
+    pub fn to_line(&self) -> String {
+        let powers: Vec<String> = self.powers.iter().map(|p| format!("{:.4}", p)).collect();
+        format!("{};{};{}", CONFIG_PREFIX, self.name, powers.join(","))
+    }
+
+    pub fn from_line(line: &str, magnets: usize) -> Option<SavedConfig> {
+        let mut parts = line.trim().split(';');
+        if parts.next()? != CONFIG_PREFIX { return None; }
+        let name = parts.next()?.trim().to_string();
+        let powers: Vec<f32> = parts.next()?.split(',').map(|p| p.trim().parse().ok()).collect::<Option<_>>()?;
+        if parts.next().is_some() || powers.len() != magnets || powers.iter().any(|p| !p.is_finite()) {
+            return None;
+        }
+        Some(SavedConfig { name: if name.is_empty() { "Untitled".to_string() } else { name }, powers })
+    }
+
+    /// Saved configurations, sorted by file name.
+    pub fn load_all(magnets: usize) -> Vec<SavedConfig> {
+        let Ok(entries) = fs::read_dir(config_dir()) else { return Vec::new() };
+        let mut files: Vec<PathBuf> = entries.flatten()
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|e| e == FILE_EXT))
+            .collect();
+        files.sort();
+        files.iter().filter_map(|p| SavedConfig::from_line(&fs::read_to_string(p).ok()?, magnets)).collect()
+    }
+
+    /// Write to the magnet config folder; returns the file name on success.
+    pub fn save(&self) -> Option<String> {
+        let dir = config_dir();
+        fs::create_dir_all(&dir).ok()?;
+        let stem: String = self.name.chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '-' })
+            .collect();
+        let stem = stem.trim_matches('-');
+        let file_name = format!("{}.{}", if stem.is_empty() { "config" } else { stem }, FILE_EXT);
+        fs::write(dir.join(&file_name), self.to_line() + "\n").ok()?;
+        Some(file_name)
+    }
+}
+
+fn config_dir() -> PathBuf {
+    data_path("magnets")
+}
+
+/// A magnet set differently in the two configurations.
+pub struct MagnetDiff {
+    pub label: String,
+    pub powers: (f32, f32),
+    /// Closed orbit shift from changing only this magnet, A to B
+    pub impact: Option<f32>,
+}
+
+/// Two configurations side by side, A on the left and B on the right.
+pub struct Comparison {
+    /// The live ring first, then the saved configurations
+    pub configs: Vec<SavedConfig>,
+    pub picks: [usize; 2],
+    /// Which side ↑↓ changes
+    pub side: usize,
+    pub predictions: [Prediction; 2],
+    /// Most influential first
+    pub diffs: Vec<MagnetDiff>,
+}
+
+impl Comparison {
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        frame.render_widget(Clear, area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.paint(Color::Rgb(100, 180, 255), Role::Blue)))
+            .title(" Compare Configurations ")
+            .title_style(Style::default().fg(theme.paint(Color::Rgb(120, 200, 255), Role::Blue)).add_modifier(Modifier::BOLD))
+            .title_bottom(hud::overlay_legend(&[("←→", "Side"), ("↑↓", "Config"), ("Esc", "Close")], theme))
+            .style(Style::default().bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Min(4)])
+            .split(inner);
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(56), Constraint::Min(30)])
+            .split(rows[1]);
+
+        let heading = Style::default().fg(theme.paint(Color::Rgb(255, 220, 120), Role::Yellow)).add_modifier(Modifier::BOLD);
+        let dim = Style::default().fg(theme.paint(Color::DarkGray, Role::Faint));
+        let text = Style::default().fg(theme.paint(Color::Rgb(200, 200, 210), Role::Text));
+        let colors = [theme.paint(Color::Rgb(120, 200, 255), Role::Blue), theme.paint(Color::Rgb(255, 180, 120), Role::Orange)];
+
+        // Which configuration is on each side
+        let mut picker = Vec::new();
+        for (side, (&pick, color)) in self.picks.iter().zip(colors).enumerate() {
+            let name = &self.configs[pick].name;
+            let label = if side == self.side { format!("◀ {} ▶", name) } else { format!("  {}  ", name) };
+            let mut style = Style::default().fg(color).add_modifier(Modifier::BOLD);
+            if side == self.side { style = style.add_modifier(Modifier::REVERSED); }
+            picker.push(Span::styled(format!(" {}: ", ['A', 'B'][side]), dim));
+            picker.push(Span::styled(label, style));
+            picker.push(Span::raw("   "));
+        }
+        frame.render_widget(Paragraph::new(Line::from(picker)), rows[0]);
+
+        // Closed orbit per section, with differences over tolerance lit up
+        let mut orbit_lines = vec![
+            Line::styled("CLOSED ORBIT (mm at section end)", heading),
+            Line::styled(format!("{:<5}{:>8}{:>8}{:>9}{:>8}{:>7}{:>7}", "sec", "A x", "A y", "B x", "B y", "Δx", "Δy"), dim),
+        ];
+        match (&self.predictions[0].orbit, &self.predictions[1].orbit) {
+            (Some(a), Some(b)) => {
+                for (sec, (p, q)) in a.iter().zip(b).enumerate() {
+                    let (dx, dy) = (q.0 - p.0, q.1 - p.1);
+                    let flag = |d: f32| if d.abs() > ORBIT_TOLERANCE {
+                        Style::default().fg(theme.paint(Color::Rgb(255, 120, 80), Role::Red)).add_modifier(Modifier::BOLD)
+                    } else {
+                        dim
+                    };
+                    orbit_lines.push(Line::from(vec![
+                        Span::styled(format!("{:<5}", sec + 1), dim),
+                        Span::styled(format!("{:>8.2}{:>8.2}", p.0, p.1), Style::default().fg(colors[0])),
+                        Span::styled(format!("{:>9.2}{:>8.2}", q.0, q.1), Style::default().fg(colors[1])),
+                        Span::styled(format!("{:>7.2}", dx), flag(dx)),
+                        Span::styled(format!("{:>7.2}", dy), flag(dy)),
+                    ]));
+                }
+            }
+            (a, b) => {
+                for (side, orbit) in [a, b].into_iter().enumerate() {
+                    if orbit.is_none() {
+                        orbit_lines.push(Line::styled(
+                            format!("{} has no closed orbit", ['A', 'B'][side]),
+                            Style::default().fg(theme.paint(Color::Rgb(255, 60, 60), Role::Red)),
+                        ));
+                    }
+                }
+            }
+        }
+        frame.render_widget(Paragraph::new(orbit_lines), columns[0]);
+
+        // One-turn matrices, then the settings that made the difference
+        let mut right = vec![Line::styled("ONE-TURN MAPS", heading)];
+        for (plane, name) in ["x", "y"].iter().enumerate() {
+            for (side, prediction) in self.predictions.iter().enumerate() {
+                let map = &prediction.turn[plane];
+                let (verdict, color) = if map.stable() {
+                    ("stable", theme.paint(Color::Rgb(80, 255, 80), Role::Green))
+                } else {
+                    ("unstable", theme.paint(Color::Rgb(255, 60, 60), Role::Red))
+                };
+                right.push(Line::from(vec![
+                    Span::styled(format!("{} {} ", ['A', 'B'][side], name), Style::default().fg(colors[side])),
+                    Span::styled(
+                        format!("[{:>6.2} {:>6.2} │ {:>6.2} {:>6.2}] tr {:>6.2} ", map.m[0][0], map.m[0][1], map.m[1][0], map.m[1][1], map.trace()),
+                        text,
+                    ),
+                    Span::styled(verdict, Style::default().fg(color)),
+                ]));
+            }
+        }
+        right.push(Line::raw(""));
+        right.push(Line::styled("SETTINGS THAT MATTERED", heading));
+        if self.diffs.is_empty() {
+            right.push(Line::styled("The two configurations are identical", dim));
+        } else {
+            right.push(Line::styled(format!("{:<10}{:>9}{:>9}{:>12}", "magnet", "A", "B", "orbit shift"), dim));
+            for diff in &self.diffs {
+                let (impact, style) = match diff.impact {
+                    None => ("-".to_string(), dim),
+                    Some(shift) if shift.is_infinite() => {
+                        ("∞".to_string(), Style::default().fg(theme.paint(Color::Rgb(255, 60, 60), Role::Red)).add_modifier(Modifier::BOLD))
+                    }
+                    Some(shift) if shift > ORBIT_TOLERANCE => {
+                        (format!("{:.2}", shift), Style::default().fg(theme.paint(Color::Rgb(255, 120, 80), Role::Red)).add_modifier(Modifier::BOLD))
+                    }
+                    Some(shift) => (format!("{:.2}", shift), dim),
+                };
+                right.push(Line::from(vec![
+                    Span::styled(format!("{:<10}{:>9.4}{:>9.4}", diff.label, diff.powers.0, diff.powers.1), text),
+                    Span::styled(format!("{:>12}", impact), style),
+                ]));
+            }
+        }
+        frame.render_widget(Paragraph::new(right), columns[1]);
+    }
+}
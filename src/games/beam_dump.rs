@@ -0,0 +1,586 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::games::{Game, GameInfo};
+use crate::session::{StateReader, StateWriter};
+use crate::ui::braille::BrailleCanvas;
+use crate::ui::fx::{self, Emitter, Particles};
+use crate::ui::hud::{self, Countdown};
+use crate::ui::theme::{Ink, Role, Theme};
+
+const BRICK_ROWS: usize = 5;
+const BRICKS_PER_ROW: usize = 10;
+const PULSES: u32 = 3;
+const BUNCH_SPEED: f32 = 0.4;
+const MAX_SPEED: f32 = 0.6;
+/// Furthest one corrector kick turns the bunch, in radians
+const KICK: f32 = 0.3;
+/// Ticks before the next kick; correctors take time to ramp
+const KICK_COOLDOWN: u32 = 3;
+/// Intensity, in percent, each kick and each graze of the pipe costs
+const KICK_COST: f32 = 0.5;
+const SCRAPE_COST: f32 = 6.0;
+/// Below this the pulse is aborted into the dump
+const MIN_INTENSITY: f32 = 15.0;
+/// Steepest and shallowest injection angles either side of straight up
+const AIM_MIN: f32 = 0.2;
+const AIM_MAX: f32 = 1.2;
+const AIM_STEP: f32 = 0.1;
+/// Ticks the pipe glows after a graze
+const SCRAPE_GLOW: u32 = 8;
+const ABLATION: Emitter = Emitter::burst(8, 0.3, 20).with_gravity(0.015);
+const SCRAPE_SPARKS: Emitter = Emitter::burst(5, 0.25, 12);
+const FIELD_BG: Ink = Ink(Color::Rgb(8, 8, 16), Role::Background);
+
+/// Target material: hits to ablate at full intensity, points per hit.
+/// Graphite, copper and tungsten, from the front of the target to the back.
+struct Material {
+    hits: f32,
+    points: u32,
+    color: Ink,
+}
+
+const MATERIALS: [Material; 3] = [
+    Material { hits: 1.0, points: 10, color: Ink(Color::Rgb(140, 140, 150), Role::Dim) },
+    Material { hits: 2.0, points: 15, color: Ink(Color::Rgb(220, 130, 60), Role::Orange) },
+    Material { hits: 3.0, points: 25, color: Ink(Color::Rgb(150, 180, 230), Role::Blue) },
+];
+
+#[derive(Clone)]
+struct Brick {
+    x: f32,
+    y: f32,
+    width: f32,
+    material: usize,
+    /// Hits left at full intensity; ablated at zero
+    hp: f32,
+}
+
+impl Brick {
+    fn alive(&self) -> bool {
+        self.hp > 0.01
+    }
+}
+
+/// Breakout without a paddle: the proton bunch is steered with corrector
+/// kicks into a target it ablates, and every graze of the pipe walls
+/// scrapes away intensity.
+pub struct BeamDumpGame {
+    x: f32,
+    y: f32,
+    /// Direction of travel; 0 is right, -π/2 straight up
+    angle: f32,
+    /// Percent of the injected charge still in the bunch
+    intensity: f32,
+    bricks: Vec<Brick>,
+    score: u32,
+    high_score: u32,
+    pulses: u32,
+    level: u32,
+    targets_cleared: u32,
+    kicks: u32,
+    kick_cooldown: u32,
+    injected: bool,
+    cleared: bool,
+    game_over: bool,
+    paused: bool,
+    countdown: Countdown,
+    scrape_glow: u32,
+    tick: u64,
+    field_width: f32,
+    field_height: f32,
+    particles: Particles,
+    show_hitboxes: bool,
+}
+
+impl BeamDumpGame {
+    pub fn new() -> Self {
+        let fw = 70.0;
+        let fh = 28.0;
+        let mut game = Self {
+            x: fw / 2.0,
+            y: fh - 2.0,
+            angle: -FRAC_PI_2 + AIM_MIN * 2.0,
+            intensity: 100.0,
+            bricks: Vec::new(),
+            score: 0,
+            high_score: 0,
+            pulses: PULSES,
+            level: 1,
+            targets_cleared: 0,
+            kicks: 0,
+            kick_cooldown: 0,
+            injected: false,
+            cleared: false,
+            game_over: false,
+            paused: false,
+            countdown: Countdown::default(),
+            scrape_glow: 0,
+            tick: 0,
+            field_width: fw,
+            field_height: fh,
+            particles: Particles::new(53),
+            show_hitboxes: false,
+        };
+        game.init_bricks();
+        game
+    }
+
+    pub fn targets_cleared(&self) -> u32 {
+        self.targets_cleared
+    }
+
+    /// Denser material moves down the target as the levels go on.
+    fn init_bricks(&mut self) {
+        // Inside the pipe walls
+        let brick_width = (self.field_width - 2.0) / BRICKS_PER_ROW as f32;
+        self.bricks.clear();
+        for row in 0..BRICK_ROWS {
+            let material = ((BRICK_ROWS - 1 - row) + self.level as usize - 1) / 2;
+            let material = material.min(MATERIALS.len() - 1);
+            for col in 0..BRICKS_PER_ROW {
+                self.bricks.push(Brick {
+                    x: 1.0 + col as f32 * brick_width,
+                    y: 2.0 + row as f32 * 1.5,
+                    width: brick_width,
+                    material,
+                    hp: MATERIALS[material].hits,
+                });
+            }
+        }
+    }
+
+    fn untouched(&self) -> bool {
+        self.bricks.iter().all(|b| b.hp == MATERIALS[b.material].hits)
+    }
+
+    fn speed(&self) -> f32 {
+        (BUNCH_SPEED + 0.02 * (self.level - 1) as f32).min(MAX_SPEED)
+    }
+
+    fn velocity(&self) -> (f32, f32) {
+        let speed = self.speed();
+        (self.angle.cos() * speed, self.angle.sin() * speed)
+    }
+
+    /// A fresh pulse waiting at the injection point, aimed up and away
+    /// from where the last one went.
+    fn next_pulse(&mut self) {
+        self.x = self.field_width / 2.0;
+        self.y = self.field_height - 2.0;
+        let side = if self.angle.cos() < 0.0 { 1.0 } else { -1.0 };
+        self.angle = -FRAC_PI_2 + side * AIM_MIN * 2.0;
+        self.intensity = 100.0;
+        self.injected = false;
+        self.kick_cooldown = 0;
+    }
+
+    /// Before injection, ←/→ tilt the injection angle.
+    fn aim(&mut self, step: f32) {
+        let tilt = (self.angle + FRAC_PI_2 + step).clamp(-AIM_MAX, AIM_MAX);
+        // Never straight up, or the bunch would bounce in one column forever
+        let tilt = if tilt.abs() < AIM_MIN { AIM_MIN.copysign(step) } else { tilt };
+        self.angle = tilt - FRAC_PI_2;
+    }
+
+    /// Turn the bunch toward `heading` by at most one kick.
+    fn kick(&mut self, heading: f32) {
+        if self.kick_cooldown > 0 { return; }
+        let diff = (heading - self.angle + PI).rem_euclid(2.0 * PI) - PI;
+        self.angle += diff.clamp(-KICK, KICK);
+        self.intensity -= KICK_COST;
+        self.kicks += 1;
+        self.kick_cooldown = KICK_COOLDOWN;
+    }
+
+    /// The bunch grazed the pipe at (x, y).
+    fn scrape(&mut self, x: f32, y: f32) {
+        self.intensity -= SCRAPE_COST;
+        self.scrape_glow = SCRAPE_GLOW;
+        self.particles.emit(&SCRAPE_SPARKS, x, y, fx::SPARKS);
+    }
+
+    fn lose_pulse(&mut self) {
+        self.pulses = self.pulses.saturating_sub(1);
+        if self.pulses == 0 {
+            self.game_over = true;
+            self.high_score = self.high_score.max(self.score);
+        } else {
+            self.next_pulse();
+        }
+    }
+
+    fn move_bunch(&mut self) {
+        let (dx, dy) = self.velocity();
+        self.x += dx;
+        self.y += dy;
+
+        // Pipe walls reflect the bunch, at a cost
+        if self.x <= 0.5 || self.x >= self.field_width - 1.5 {
+            self.x = self.x.clamp(0.5, self.field_width - 1.5);
+            self.angle = PI - self.angle;
+            self.scrape(self.x, self.y);
+        }
+        if self.y <= 0.5 {
+            self.y = 0.5;
+            self.angle = -self.angle;
+            self.scrape(self.x, self.y);
+        }
+        // Out of the bottom, or too weak to be worth keeping: into the dump
+        if self.y >= self.field_height || self.intensity < MIN_INTENSITY {
+            self.lose_pulse();
+            return;
+        }
+
+        let hit = self.bricks.iter().position(|b| {
+            b.alive()
+                && self.x >= b.x - 0.5
+                && self.x < b.x + b.width + 0.5
+                && self.y >= b.y - 0.5
+                && self.y < b.y + 1.5
+        });
+        let Some(idx) = hit else { return };
+        let brick = &mut self.bricks[idx];
+        let (cx, cy) = (brick.x + brick.width / 2.0, brick.y + 0.75);
+        if (self.x - cx).abs() / brick.width > (self.y - cy).abs() / 1.5 {
+            self.angle = PI - self.angle;
+        } else {
+            self.angle = -self.angle;
+        }
+        // A dimmer bunch deposits less energy
+        let deposit = (self.intensity / 100.0).min(brick.hp);
+        brick.hp -= deposit;
+        let material = &MATERIALS[brick.material];
+        self.score += (deposit * (material.points * self.level) as f32).round() as u32;
+        let ablated = !brick.alive();
+        if ablated {
+            self.particles.emit(&ABLATION, cx, cy, &[material.color, Ink(Color::Rgb(255, 200, 120), Role::Yellow)]);
+        }
+        if ablated && self.bricks.iter().all(|b| !b.alive()) {
+            self.cleared = true;
+            self.targets_cleared += 1;
+            self.high_score = self.high_score.max(self.score);
+        }
+    }
+
+    fn next_target(&mut self) {
+        self.level += 1;
+        self.cleared = false;
+        self.init_bricks();
+        self.next_pulse();
+    }
+
+    fn render_field(&self, width: usize, height: usize, theme: &Theme) -> Vec<Line<'static>> {
+        let (w, h) = (width, height);
+        let sx = w as f32 / self.field_width;
+        let sy = h as f32 / self.field_height;
+        let mut grid: Vec<Vec<(char, Style)>> = vec![vec![(' ', Style::default().bg(theme.ink(FIELD_BG))); w]; h];
+        if w < 2 || h < 2 { return Vec::new(); }
+
+        // The beam pipe, glowing where it was just grazed
+        let pipe = if self.scrape_glow > 0 { theme.paint(Color::Rgb(255, 150, 60), Role::Orange) } else { theme.paint(Color::Rgb(70, 80, 110), Role::Faint) };
+        let pipe = Style::default().fg(pipe).bg(theme.ink(FIELD_BG));
+        for row in grid.iter_mut() {
+            row[0] = ('║', pipe);
+            row[w - 1] = ('║', pipe);
+        }
+        for cell in grid[0].iter_mut() {
+            *cell = ('═', pipe);
+        }
+        grid[0][0] = ('╔', pipe);
+        grid[0][w - 1] = ('╗', pipe);
+
+        // Target bricks, thinning as they're ablated
+        for brick in self.bricks.iter().filter(|b| b.alive()) {
+            let by = (brick.y * sy) as usize;
+            if by >= h { continue; }
+            let color = theme.ink(MATERIALS[brick.material].color);
+            let ch = match brick.hp.ceil() as u32 {
+                3.. => '▓',
+                2 => '▒',
+                _ => '░',
+            };
+            let bx_start = (brick.x * sx) as usize;
+            let bx_end = (((brick.x + brick.width) * sx) as usize).min(w);
+            let cells = grid[by].get_mut(bx_start..bx_end).unwrap_or_default();
+            let last = cells.len().saturating_sub(1);
+            for (i, cell) in cells.iter_mut().enumerate() {
+                *cell = (if i == last { '▏' } else { ch }, Style::default().fg(color).bg(theme.ink(FIELD_BG)));
+            }
+        }
+
+        self.particles.draw(&mut grid, (sx * 2.0, sy * 4.0), 1.0, theme);
+
+        // The bunch dims with its intensity
+        let glow = (80.0 + self.intensity * 1.75) as u8;
+        let (dx, dy) = self.velocity();
+        if !self.injected {
+            // Injection point and the aim it will leave on
+            let dot = Style::default().fg(theme.paint(Color::Rgb(90, 110, 150), Role::Blue)).bg(theme.ink(FIELD_BG));
+            for step in 2..8 {
+                let (px, py) = (((self.x + dx * step as f32 * 2.5) * sx) as usize, ((self.y + dy * step as f32 * 2.5) * sy) as usize);
+                if px < w && py < h && grid[py][px].0 == ' ' {
+                    grid[py][px] = ('·', dot);
+                }
+            }
+        } else {
+            for back in [4.0, 2.0] {
+                let (tx, ty) = (((self.x - dx * back) * sx) as usize, ((self.y - dy * back) * sy) as usize);
+                if tx < w && ty < h && grid[ty][tx].0 == ' ' {
+                    grid[ty][tx] = ('·', Style::default().fg(theme.shade(Color::Rgb(glow / 2, glow / 3, glow / 2), Role::Violet, glow as f32 / 510.0)).bg(theme.ink(FIELD_BG)));
+                }
+            }
+        }
+        let (bx, by) = ((self.x * sx) as usize, (self.y * sy) as usize);
+        if bx < w && by < h {
+            grid[by][bx] = ('●', Style::default().fg(theme.shade(Color::Rgb(glow, glow, 255), Role::Bright, glow as f32 / 255.0)).bg(theme.ink(FIELD_BG)).add_modifier(Modifier::BOLD));
+        }
+
+        if self.show_hitboxes {
+            self.draw_hitboxes(&mut grid, sx * 2.0, sy * 4.0, theme);
+        }
+
+        grid.into_iter()
+            .map(|row| Line::from(row.into_iter().map(|(ch, style)| Span::styled(String::from(ch), style)).collect::<Vec<_>>()))
+            .collect()
+    }
+
+    /// The zones `move_bunch` tests the bunch's centre against, in braille
+    /// dots (`dx`, `dy` per field unit).
+    fn draw_hitboxes(&self, grid: &mut [Vec<(char, Style)>], dx: f32, dy: f32, theme: &Theme) {
+        let cols = grid.first().map_or(0, |row| row.len());
+        let mut boxes = BrailleCanvas::new(cols, grid.len());
+        for brick in self.bricks.iter().filter(|b| b.alive()) {
+            boxes.rect(
+                ((brick.x - 0.5) * dx) as i32,
+                ((brick.y - 0.5) * dy) as i32,
+                ((brick.x + brick.width + 0.5) * dx) as i32 + 1,
+                ((brick.y + 1.5) * dy) as i32 + 1,
+            );
+        }
+        let (x, y) = ((self.x * dx) as i32, (self.y * dy) as i32);
+        boxes.line(x - 1, y, x + 1, y);
+        boxes.line(x, y - 1, x, y + 1);
+        boxes.draw(grid, theme.ink(fx::HITBOX), None, false);
+    }
+}
+
+pub const INFO: GameInfo = GameInfo {
+    name: "Beam Dump",
+    icon: "🎯",
+    author: "RustCade",
+    blurb: "Kick the proton\nbunch into the target",
+    description: "Steer protons with corrector kicks to ablate the target",
+    controls: &[
+        ("← / →", "Horizontal kick / aim"),
+        ("↑ / ↓", "Vertical kick"),
+        ("Space", "Inject pulse"),
+        ("R", "Restart"),
+        ("P", "Pause"),
+    ],
+    tags: &["physics", "arcade", "simulation"],
+    color: Ink(Color::Rgb(255, 150, 60), Role::Orange),
+    border_color: Ink(Color::Rgb(130, 70, 30), Role::Orange),
+};
+
+impl Game for BeamDumpGame {
+    fn info(&self) -> &'static GameInfo {
+        &INFO
+    }
+
+    fn update(&mut self) {
+        if self.paused || self.countdown.tick() { return; }
+        self.particles.update();
+        self.scrape_glow = self.scrape_glow.saturating_sub(1);
+        if self.game_over || self.cleared || !self.injected { return; }
+        self.tick += 1;
+        self.kick_cooldown = self.kick_cooldown.saturating_sub(1);
+        self.move_bunch();
+    }
+
+    fn handle_input(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('r') | KeyCode::Char('R') => self.reset(),
+            KeyCode::Char('p') | KeyCode::Char('P') if !self.game_over && !self.cleared => {
+                self.countdown.toggle_pause(&mut self.paused);
+            }
+            _ if self.game_over => {
+                if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+                    self.reset();
+                }
+            }
+            _ if self.cleared => {
+                if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+                    self.next_target();
+                }
+            }
+            _ if self.paused || self.countdown.is_running() => {}
+            _ if !self.injected => match key.code {
+                KeyCode::Left => self.aim(-AIM_STEP),
+                KeyCode::Right => self.aim(AIM_STEP),
+                KeyCode::Char(' ') | KeyCode::Up => self.injected = true,
+                _ => {}
+            },
+            KeyCode::Left => self.kick(PI),
+            KeyCode::Right => self.kick(0.0),
+            KeyCode::Up => self.kick(-FRAC_PI_2),
+            KeyCode::Down => self.kick(FRAC_PI_2),
+            _ => {}
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = self.title_bar(theme);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        // Refit the pipe to the window while the target is untouched
+        let (new_fw, new_fh) = (inner.width as f32, inner.height.saturating_sub(2) as f32);
+        let resized = (new_fw - self.field_width).abs() > 1.0 || (new_fh - self.field_height).abs() > 1.0;
+        if resized && !self.injected && !self.game_over && self.untouched() {
+            self.field_width = new_fw;
+            self.field_height = new_fh;
+            self.init_bricks();
+            self.x = new_fw / 2.0;
+            self.y = new_fh - 2.0;
+        }
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(8), Constraint::Length(1)])
+            .split(inner);
+
+        let sep = || Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint)));
+        let intensity_color = if self.intensity < 40.0 { theme.ink(fx::alert_color()) } else { theme.paint(Color::Rgb(120, 200, 255), Role::Blue) };
+        let filled = (self.intensity / 10.0).round().clamp(0.0, 10.0) as usize;
+        let status = Line::from(vec![
+            Span::styled(" 🎯 ", Style::default()),
+            Span::styled(format!("Target {} ", self.level), Style::default().fg(theme.paint(Color::Rgb(255, 150, 60), Role::Orange)).add_modifier(Modifier::BOLD)),
+            sep(),
+            Span::styled(format!("Score: {} ", self.score), Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD)),
+            sep(),
+            Span::styled(format!("Pulses: {} ", "● ".repeat(self.pulses as usize)), Style::default().fg(theme.paint(Color::Rgb(170, 170, 255), Role::Blue))),
+            sep(),
+            Span::styled(
+                format!("Intensity {}{} {:>3.0}% ", "█".repeat(filled), "░".repeat(10 - filled), self.intensity.max(0.0)),
+                Style::default().fg(intensity_color),
+            ),
+            sep(),
+            Span::styled(
+                format!("Bricks: {}/{} ", self.bricks.iter().filter(|b| b.alive()).count(), self.bricks.len()),
+                Style::default().fg(theme.paint(Color::Green, Role::Green)),
+            ),
+            sep(),
+            Span::styled(format!("Kicks: {} ", self.kicks), Style::default().fg(theme.paint(Color::Rgb(140, 140, 160), Role::Dim))),
+        ]);
+        frame.render_widget(Paragraph::new(status), chunks[0]);
+
+        let lines = self.render_field(chunks[1].width as usize, chunks[1].height as usize, theme);
+        frame.render_widget(Paragraph::new(lines), chunks[1]);
+        self.countdown.render(frame, chunks[1], theme);
+
+        let footer = if self.game_over {
+            hud::game_over("Press ENTER to restart, Esc for menu", theme)
+        } else if self.cleared {
+            hud::outcome(
+                &format!("🎉 TARGET {} ABLATED!", self.level),
+                theme.paint(Color::Green, Role::Green),
+                &format!("Score: {} │ Press ENTER for target {}", self.score, self.level + 1),
+                theme,
+            )
+        } else if self.paused {
+            hud::pause_overlay(frame, chunks[1], theme);
+            hud::paused(theme)
+        } else if !self.injected {
+            hud::legend(Some("SPACE Inject"), &[("←→", "Aim")], theme)
+        } else {
+            hud::legend(None, &[("←→", "H kick"), ("↑↓", "V kick")], theme)
+        };
+        frame.render_widget(Paragraph::new(footer), chunks[2]);
+    }
+
+    fn get_score(&self) -> u32 { self.score }
+    fn is_game_over(&self) -> bool { self.game_over }
+    fn is_paused(&self) -> bool { self.paused }
+    fn set_hitboxes(&mut self, on: bool) { self.show_hitboxes = on; }
+
+    fn script_state(&self) -> Vec<(&'static str, f64)> {
+        let (dx, dy) = self.velocity();
+        vec![
+            ("x", self.x as f64),
+            ("y", self.y as f64),
+            ("dx", dx as f64),
+            ("dy", dy as f64),
+            ("intensity", self.intensity as f64),
+            ("injected", self.injected as u8 as f64),
+            ("pulses", self.pulses as f64),
+            ("level", self.level as f64),
+            ("bricks", self.bricks.iter().filter(|b| b.alive()).count() as f64),
+        ]
+    }
+
+    fn serialize_state(&self) -> Option<Vec<u8>> {
+        if self.game_over || self.cleared { return None; }
+        let mut w = StateWriter::new();
+        w.u32(self.score);
+        w.u32(self.high_score);
+        w.u32(self.pulses);
+        w.u32(self.level);
+        w.u32(self.targets_cleared);
+        w.u32(self.kicks);
+        w.f32(self.field_width);
+        w.f32(self.field_height);
+        w.u32(self.bricks.len() as u32);
+        for brick in &self.bricks {
+            w.f32(brick.hp);
+        }
+        Some(w.finish())
+    }
+
+    fn restore_state(&mut self, data: &[u8]) -> bool {
+        let mut r = StateReader::new(data);
+        let (Some(score), Some(high_score), Some(pulses), Some(level), Some(targets), Some(kicks), Some(fw), Some(fh), Some(count)) =
+            (r.u32(), r.u32(), r.u32(), r.u32(), r.u32(), r.u32(), r.f32(), r.f32(), r.u32())
+        else { return false };
+        if fw < 10.0 || fh < 8.0 || count as usize != BRICK_ROWS * BRICKS_PER_ROW { return false; }
+        let mut hp = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let Some(h) = r.f32() else { return false };
+            hp.push(h);
+        }
+
+        self.reset();
+        self.field_width = fw;
+        self.field_height = fh;
+        self.level = level.max(1);
+        self.init_bricks();
+        for (brick, h) in self.bricks.iter_mut().zip(hp) {
+            brick.hp = h.min(MATERIALS[brick.material].hits);
+        }
+        self.score = score;
+        self.high_score = self.high_score.max(high_score);
+        self.pulses = pulses.clamp(1, PULSES);
+        self.targets_cleared = targets;
+        self.kicks = kicks;
+        // Resume with a fresh pulse at the injection point, paused
+        self.next_pulse();
+        self.paused = true;
+        true
+    }
+
+    fn reset(&mut self) {
+        let high_score = self.high_score;
+        let (fw, fh) = (self.field_width, self.field_height);
+        let show_hitboxes = self.show_hitboxes;
+        *self = BeamDumpGame::new();
+        self.high_score = high_score;
+        self.show_hitboxes = show_hitboxes;
+        self.field_width = fw;
+        self.field_height = fh;
+        self.init_bricks();
+        self.next_pulse();
+    }
+}
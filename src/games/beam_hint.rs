@@ -0,0 +1,172 @@
+use crate::games::beam_challenge::Restriction;
+use crate::games::beam_compare::PlaneMap;
+
+/// How far inside the allowed side of a restriction the hint keeps the orbit (mm).
+const MARGIN: f32 = 1.0;
+/// Closed orbit limit everywhere else, well inside the loss zone (mm).
+const ORBIT_LIMIT: f32 = 15.0;
+/// How close the orbit must come back to the target position (mm) and to
+/// the flat angle the beam is injected with.
+const TARGET_BAND: f32 = 0.05;
+const ANGLE_BAND: f32 = 0.005;
+/// Largest trim power the hint will suggest.
+const TRIM_LIMIT: f32 = 2.0;
+const MAX_SWEEPS: usize = 3000;
+/// Give up once a hundred sweeps have cut the worst shortfall by less than 1%.
+const PLATEAU: f32 = 0.99;
+
+/// A magnet configuration whose closed orbit clears the restrictions and
+/// comes back to the target, found by adjusting only the trims.
+pub struct Hint {
+    /// Suggested power for every magnet in ring order
+    pub powers: Vec<f32>,
+    /// Closed orbit (x, y) after every element
+    pub orbit: Vec<(f32, f32)>,
+    /// The orbit clears every restriction and meets the target
+    pub feasible: bool,
+    /// The current quads and dipoles had no stable orbit, so the hint
+    /// starts from the fallback optics instead
+    pub new_optics: bool,
+    /// Worked out for what it was asked about, to tell when it's stale
+    inputs: (Vec<f32>, Vec<Restriction>, f32, f32),
+}
+
+/// One inequality on a plane: `sign · state[index] ≥ bound`.
+struct Bound {
+    index: usize,
+    sign: f32,
+    bound: f32,
+}
+
+impl Hint {
+    /// Steer the closed orbit of `powers` with the trims listed per plane
+    /// (x then y), or of `fallback` if `powers` has no stable orbit. `maps`
+    /// gives the (x, y) maps of element `i` at a power.
+    pub fn solve(
+        powers: &[f32],
+        fallback: &[f32],
+        trims: [&[usize]; 2],
+        maps: impl Fn(usize, f32) -> [PlaneMap; 2],
+        restrictions: &[Restriction],
+        per_section: usize,
+        target: (f32, f32),
+    ) -> Option<Hint> {
+        let steer = |start: &[f32]| steer(start, trims, &maps, restrictions, per_section, target);
+        let (new_optics, (suggested, planes)) = match steer(powers) {
+            Some(found) => (false, found),
+            None => (true, steer(fallback)?),
+        };
+        let orbit: Vec<(f32, f32)> = planes[0][2..].iter().copied().zip(planes[1][2..].iter().copied()).collect();
+        let clears = restrictions.iter().all(|r| {
+            orbit[r.section * per_section..][..per_section].iter().all(|&(x, y)| !r.check(x, y))
+        });
+        let on_target = (planes[0][0] - target.0).abs() <= TARGET_BAND * 2.0
+            && (planes[1][0] - target.1).abs() <= TARGET_BAND * 2.0;
+        Some(Hint {
+            powers: suggested,
+            orbit,
+            feasible: clears && on_target,
+            new_optics,
+            inputs: (powers.to_vec(), restrictions.to_vec(), target.0, target.1),
+        })
+    }
+
+    /// Whether this hint was worked out for exactly these inputs.
+    pub fn is_for(&self, powers: &[f32], restrictions: &[Restriction], target: (f32, f32)) -> bool {
+        self.inputs.0 == powers && self.inputs.1 == restrictions && (self.inputs.2, self.inputs.3) == target
+    }
+}
+
+/// Adjust the trims of `powers` plane by plane; the new powers and each
+/// plane's closed orbit states, or `None` if the optics are unstable.
+fn steer(
+    powers: &[f32],
+    trims: [&[usize]; 2],
+    maps: &impl Fn(usize, f32) -> [PlaneMap; 2],
+    restrictions: &[Restriction],
+    per_section: usize,
+    target: (f32, f32),
+) -> Option<(Vec<f32>, Vec<Vec<f32>>)> {
+    let mut suggested = powers.to_vec();
+    let mut planes = Vec::new();
+    for (plane, (axis, goal)) in [('x', target.0), ('y', target.1)].into_iter().enumerate() {
+        let plane_maps = |powers: &[f32]| -> Vec<PlaneMap> {
+            powers.iter().enumerate().map(|(i, &p)| maps(i, p)[plane]).collect()
+        };
+        let base = plane_states(&plane_maps(&suggested))?;
+        // Every state is affine in the trims, so one unit nudge per trim gives
+        // its column; kept as rows, one per state
+        let mut rows = vec![vec![0.0; trims[plane].len()]; base.len()];
+        for (j, &t) in trims[plane].iter().enumerate() {
+            let mut nudged = suggested.clone();
+            nudged[t] += 1.0;
+            let moved = plane_states(&plane_maps(&nudged)).unwrap_or_else(|| base.clone());
+            for (row, (m, b)) in rows.iter_mut().zip(moved.iter().zip(&base)) {
+                row[j] = m - b;
+            }
+        }
+        let norms: Vec<f32> = rows.iter().map(|row| row.iter().map(|r| r * r).sum()).collect();
+
+        // State 0 and 1 are the position and angle at injection, then the
+        // position after each element
+        let mut bounds = vec![
+            Bound { index: 0, sign: 1.0, bound: goal - TARGET_BAND },
+            Bound { index: 0, sign: -1.0, bound: -goal - TARGET_BAND },
+            Bound { index: 1, sign: 1.0, bound: -ANGLE_BAND },
+            Bound { index: 1, sign: -1.0, bound: -ANGLE_BAND },
+        ];
+        for r in restrictions.iter().filter(|r| r.axis == axis) {
+            let sign = if r.positive_blocked { -1.0 } else { 1.0 };
+            for e in 0..per_section {
+                bounds.push(Bound { index: 2 + r.section * per_section + e, sign, bound: MARGIN });
+            }
+        }
+        for index in 2..base.len() {
+            bounds.push(Bound { index, sign: 1.0, bound: -ORBIT_LIMIT });
+            bounds.push(Bound { index, sign: -1.0, bound: -ORBIT_LIMIT });
+        }
+
+        // Project onto each violated bound in turn until none is left
+        let start: Vec<f32> = trims[plane].iter().map(|&t| suggested[t]).collect();
+        let mut settings = start.clone();
+        let mut checkpoint = f32::INFINITY;
+        for sweep in 0..MAX_SWEEPS {
+            let mut worst = 0.0_f32;
+            for b in &bounds {
+                let row = &rows[b.index];
+                let moved: f32 = row.iter().zip(settings.iter().zip(&start)).map(|(r, (s, s0))| r * (s - s0)).sum();
+                let short = b.bound - b.sign * (base[b.index] + moved);
+                if short <= 0.0 || norms[b.index] < 1e-9 { continue; }
+                worst = worst.max(short);
+                let step = b.sign * short / norms[b.index];
+                for (s, r) in settings.iter_mut().zip(row) {
+                    *s = (*s + step * r).clamp(-TRIM_LIMIT, TRIM_LIMIT);
+                }
+            }
+            if worst < 1e-3 { break; }
+            if sweep % 100 == 0 {
+                if worst > checkpoint * PLATEAU { break; }
+                checkpoint = worst;
+            }
+        }
+        for (&t, &s) in trims[plane].iter().zip(&settings) {
+            suggested[t] = s;
+        }
+        planes.push(plane_states(&plane_maps(&suggested))?);
+    }
+    Some((suggested, planes))
+}
+
+/// Closed orbit position and angle at injection followed by the position
+/// after every element; `None` if the turn map is unstable.
+fn plane_states(elements: &[PlaneMap]) -> Option<Vec<f32>> {
+    let turn = elements.iter().fold(PlaneMap::IDENTITY, |turn, e| turn.then(e));
+    if !turn.stable() { return None; }
+    let mut z = turn.fixed_point()?;
+    let mut states = vec![z.0, z.1];
+    for e in elements {
+        z = e.apply(z);
+        states.push(z.0);
+    }
+    Some(states)
+}
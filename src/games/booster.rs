@@ -1,10 +1,23 @@
 #![allow(dead_code)]
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use rand::Rng;
 use ratatui::prelude::*;
 use ratatui::widgets::*;
 
-use crate::games::Game;
+use crate::achievements::Badge;
+use crate::audio::{self, AudioEvent};
+use crate::games::booster_console::{self, Cells, Command, Console, Measurement, Param, CORRECTOR_FIELDS};
+use crate::games::booster_match::{self, ScanFit, ScanPoint, Twiss};
+use crate::games::booster_palette::{Palette, PaletteOutcome};
+use crate::games::booster_report::CycleReport;
+use crate::games::{Game, GameInfo};
+use crate::rng::GameRng;
+use crate::scores::now_secs;
+use crate::session::{load_progress, save_progress, StateReader, StateWriter};
+use crate::ui::fx::{self, ScreenFlash};
+use crate::ui::hud;
+use crate::ui::theme::{Ink, Role, Theme};
 
 // ── Fermilab Booster Physical Constants ──────────────────────────────────────
 const NUM_SECTIONS: usize = 24;       // 24 identical periods (superperiods)
@@ -29,12 +42,30 @@ const E_INJECTION_GEV: f64 = 0.4;     // kinetic energy at injection (GeV)
 const E_EXTRACTION_GEV: f64 = 8.0;    // kinetic energy at extraction (GeV)
 const PROTON_MASS_GEV: f64 = 0.93827; // proton rest mass (GeV/c²)
 const GAMMA_TRANSITION: f64 = 5.446;  // transition gamma
+const TRANSITION_WARNING: f64 = 0.95; // γ/γt at which the approach alarm sounds
 
 // RF parameters
 const HARMONIC_NUMBER: u32 = 84;
 const NUM_RF_CAVITIES: u32 = 22;
 const MAX_RF_VOLTAGE_MV: f64 = 1.16;  // MV total ring voltage
 const CYCLE_FREQ_HZ: f64 = 15.0;      // cycling rate (Hz)
+const MAX_RF2_RATIO: f64 = 0.6;       // harmonic cavity voltage, as a fraction of the fundamental
+const SC_LOSS_THRESHOLD: f64 = 0.05;  // space-charge tune shift above which halo is lost
+const SC_REF_BUNCHING: f64 = 0.8;     // bunching factor of the 0.5 MV injection bucket
+const EDDY_CHROM: f64 = 16.0;         // chromaticity per unit of Ḃ/B, ~5 at its peak early in the ramp
+const CHROM_TOLERANCE: f64 = 4.0;     // chromaticity error the ramp rides out without head-tail growth
+const CLEAN_TRANSITION: f64 = 3.0;    // chromaticity error that crosses transition cleanly
+
+// Badges
+const BADGE_CHROM: f64 = 1.0;         // per-plane chromaticity error for the correction badge
+const BADGE_EDDY: f64 = 1.0;          // eddy chromaticity that has to be there to correct
+const BADGE_BUMP_KICK: f64 = 0.005;   // 4-bump height that counts as steering the beam
+
+// Notching and cogging
+const NOTCH_TURN: u32 = 300;          // notcher fires early, while losses are cheap
+const NOTCH_WIDTH: usize = 3;         // buckets cleared for the extraction kicker rise
+const COG_GAIN: f64 = 0.07;           // notch slip (buckets/turn) per unit MDAT trim
+const COG_DRIFT_MAX: f64 = 3.0;       // worst uncorrected notch drift over a cycle (buckets)
 
 // Tunes (bare lattice at injection)
 const TUNE_X_BARE: f64 = 6.7;
@@ -49,27 +80,59 @@ const D_APERTURE_V_MM: f64 = 28.6;    // 2.25" / 2 → mm
 // Beam parameters
 const EMITTANCE_NORM_95: f64 = 12.0;  // π mm·mrad (normalized, 95%)
 const LONG_EMITTANCE_EVS: f64 = 0.10; // eV·s (95%) at injection
+const BETA_REF_X: f64 = 33.7;         // m, β_x,max where σ_x is quoted
+const BETA_REF_Y: f64 = 20.4;         // m, β_y,max where σ_y is quoted
 
 // Game display scaling
 const APERTURE_DISPLAY: f32 = 50.0;   // display units for full aperture
 const LOSS_ZONE: f32 = 25.0;          // beam edges past this accumulate losses
 const MAX_LOSSES: f32 = 100.0;        // game over threshold
+const LOSS_ALARM: f32 = MAX_LOSSES * 0.5; // audible alarm, once per cycle
 
 // Simulation
 const ELEMENTS_PER_CELL: usize = 6;   // F, short_drift, F, D, long_drift, D
 const TOTAL_ELEMENTS: usize = NUM_SECTIONS * ELEMENTS_PER_CELL;
 const MAX_HISTORY: usize = 60;
+const EMITTANCE_SAMPLE_TURNS: u32 = 100; // turns between emittance samples
 
 // Ramp timing: total ramp is ~33ms (half-period of 15Hz sinusoid)
 // We discretize into turns around the ring
 // Revolution period = C / (β·c) ≈ 2.2 μs at injection → ~15,000 turns in a cycle
 const TURNS_IN_CYCLE: u32 = 15000;
 const TURNS_TO_TRANSITION: u32 = 7100; // approximate turn at γ = γ_t
+/// Practice-mode hints for the settings the physics wants
+const IDEAL_COLOR: Ink = Ink(Color::Rgb(120, 230, 160), Role::Green);
+
+// Injection matching
+const SCAN_STEPS: usize = 9;
+const SCAN_RANGE: f64 = 0.2;          // MQAT swept over ±this (its full range)
+const SCAN_NOISE: f64 = 0.02;         // profile monitor resolution, relative
+const MATCH_BONUS: f64 = 300.0;       // setup points for a matched injection, gone by mismatch 2.0
 
 // Corrector magnets per cell: located in long straight section
 const CORRECTORS_PER_CELL: usize = 4; // H-trim, V-trim, trim-quad, skew-quad
 const SEXTUPOLES_PER_CELL: usize = 2; // 2 families for chromaticity
 
+// Corrector supplies and magnet heating
+const TRIM_RATED: f64 = 0.008;        // trim dipole current the coils carry continuously (rad)
+const QUAD_RATED: f64 = 0.25;         // trim and skew quad rating (m^-2)
+const SEXT_RATED: f64 = 0.2;          // sextupole rating (m^-3)
+const SUPPLY_LIMIT: f64 = 2.0;        // supplies top out at this multiple of the rating
+const AMBIENT_C: f64 = 30.0;          // water-cooled coils with no current (°C)
+const HEAT_RISE_C: f64 = 40.0;        // steady rise per unit of rated I² power (°C)
+const TRIP_C: f64 = 90.0;             // interlock trips the cell's supplies
+const RESET_C: f64 = 50.0;            // interlock clears once cooled to here
+const THERMAL_TICKS: f64 = 600.0;     // coil time constant (~10 s)
+
+// Ground motion and supply drift, and the slow orbit feedback against it
+const DRIFT_WANDER: f64 = 2e-11;      // per-turn random change in each cell's drift rate (mrad/turn)
+const BPM_TURNS: u32 = 100;           // BPM averaging when the feedback is off
+const BPM_NOISE: f64 = 0.0005;         // one BPM reading's electronics noise (mm)
+const ORBIT_TOLERANCE: f64 = 0.005;    // rms closed orbit (mm) that counts as held
+const FEEDBACK_POINTS: f64 = 500.0;   // orbit held all cycle with a gain of 1
+const MAX_FEEDBACK_GAIN: f64 = 2.5;   // past 2 the loop rings up; players may find out
+const MAX_FEEDBACK_TURNS: u32 = 2000;
+
 // ── Element Types ────────────────────────────────────────────────────────────
 #[derive(Clone, Copy, PartialEq)]
 enum ElementType {
@@ -89,12 +152,12 @@ impl ElementType {
         }
     }
 
-    fn color(&self) -> Color {
+    fn color(&self) -> Ink {
         match self {
-            ElementType::FMagnet => Color::Rgb(80, 180, 255),
-            ElementType::DMagnet => Color::Rgb(80, 255, 140),
-            ElementType::ShortDrift => Color::Rgb(80, 80, 100),
-            ElementType::LongDrift => Color::Rgb(120, 100, 80),
+            ElementType::FMagnet => Ink(Color::Rgb(80, 180, 255), Role::Blue),
+            ElementType::DMagnet => Ink(Color::Rgb(80, 255, 140), Role::Green),
+            ElementType::ShortDrift => Ink(Color::Rgb(80, 80, 100), Role::Faint),
+            ElementType::LongDrift => Ink(Color::Rgb(120, 100, 80), Role::Faint),
         }
     }
 
@@ -127,6 +190,27 @@ struct CorrectorPackage {
 }
 
 impl CorrectorPackage {
+    /// Settings in panel order, as CorrectorSelect::ALL.
+    fn values(&self) -> [f64; 6] {
+        [self.h_trim, self.v_trim, self.trim_quad, self.skew_quad, self.sext_a, self.sext_b]
+    }
+
+    /// I² power relative to rated, summed over the package's coils.
+    fn power(&self) -> f64 {
+        CorrectorSelect::ALL.iter().zip(self.values()).map(|(ct, v)| (v / ct.rated()).powi(2)).sum()
+    }
+
+    /// Hold every supply within what it can deliver.
+    fn limit(&mut self) {
+        for (ct, v) in CorrectorSelect::ALL.iter().zip([
+            &mut self.h_trim, &mut self.v_trim, &mut self.trim_quad,
+            &mut self.skew_quad, &mut self.sext_a, &mut self.sext_b,
+        ]) {
+            let max = ct.rated() * SUPPLY_LIMIT;
+            *v = v.clamp(-max, max);
+        }
+    }
+
     fn new() -> Self {
         Self {
             h_trim: 0.0,
@@ -154,10 +238,10 @@ impl BumpAxis {
         }
     }
 
-    fn color(&self) -> Color {
+    fn color(&self) -> Ink {
         match self {
-            BumpAxis::X => Color::Rgb(255, 180, 120),
-            BumpAxis::Y => Color::Rgb(200, 120, 255),
+            BumpAxis::X => Ink(Color::Rgb(255, 180, 120), Role::Orange),
+            BumpAxis::Y => Ink(Color::Rgb(200, 120, 255), Role::Violet),
         }
     }
 
@@ -174,11 +258,19 @@ struct BumpConfig {
     size: usize,
     start_section: usize,
     axis: BumpAxis,
+    /// Height dialled in since the bump was set up or last moved
+    kick: f64,
 }
 
 impl BumpConfig {
     fn new(size: usize, start_section: usize) -> Self {
-        Self { size, start_section, axis: BumpAxis::X }
+        Self { size, start_section, axis: BumpAxis::X, kick: 0.0 }
+    }
+
+    /// Slide the bump to start at `sec`; the kicks stay where they were.
+    fn move_to(&mut self, sec: usize) {
+        self.start_section = sec;
+        self.kick = 0.0;
     }
 
     fn coefficients(&self) -> Vec<f64> {
@@ -245,16 +337,29 @@ impl GamePhase {
         }
     }
 
-    fn color(&self) -> Color {
+    fn color(&self) -> Ink {
+        match self {
+            GamePhase::Setup => Ink(Color::Rgb(140, 140, 160), Role::Dim),
+            GamePhase::Injection => Ink(Color::Rgb(80, 200, 255), Role::Blue),
+            GamePhase::EarlyRamp => Ink(Color::Rgb(80, 255, 140), Role::Green),
+            GamePhase::PreTransition => Ink(Color::Rgb(255, 200, 50), Role::Yellow),
+            GamePhase::Transition => Ink(Color::Rgb(255, 60, 60), Role::Red),
+            GamePhase::PostTransition => Ink(Color::Rgb(255, 140, 80), Role::Orange),
+            GamePhase::Extraction => Ink(Color::Rgb(80, 255, 80), Role::Green),
+            GamePhase::Lost => Ink(Color::Rgb(255, 60, 60), Role::Red),
+        }
+    }
+
+    /// Slot in the report's `LOSS_PHASES` that losses in this phase go to.
+    fn loss_slot(&self) -> Option<usize> {
         match self {
-            GamePhase::Setup => Color::Rgb(140, 140, 160),
-            GamePhase::Injection => Color::Rgb(80, 200, 255),
-            GamePhase::EarlyRamp => Color::Rgb(80, 255, 140),
-            GamePhase::PreTransition => Color::Rgb(255, 200, 50),
-            GamePhase::Transition => Color::Rgb(255, 60, 60),
-            GamePhase::PostTransition => Color::Rgb(255, 140, 80),
-            GamePhase::Extraction => Color::Rgb(80, 255, 80),
-            GamePhase::Lost => Color::Rgb(255, 60, 60),
+            GamePhase::Injection => Some(0),
+            GamePhase::EarlyRamp => Some(1),
+            GamePhase::PreTransition => Some(2),
+            GamePhase::Transition => Some(3),
+            GamePhase::PostTransition => Some(4),
+            GamePhase::Extraction => Some(5),
+            GamePhase::Setup | GamePhase::Lost => None,
         }
     }
 }
@@ -279,6 +384,16 @@ enum CorrectorSelect {
 }
 
 impl CorrectorSelect {
+    /// Panel order, top to bottom
+    const ALL: [CorrectorSelect; 6] = [
+        CorrectorSelect::HTrim,
+        CorrectorSelect::VTrim,
+        CorrectorSelect::TrimQuad,
+        CorrectorSelect::SkewQuad,
+        CorrectorSelect::SextA,
+        CorrectorSelect::SextB,
+    ];
+
     fn label(&self) -> &str {
         match self {
             CorrectorSelect::HTrim => "H-Trim",
@@ -290,14 +405,23 @@ impl CorrectorSelect {
         }
     }
 
-    fn color(&self) -> Color {
+    fn color(&self) -> Ink {
         match self {
-            CorrectorSelect::HTrim => Color::Rgb(255, 180, 120),
-            CorrectorSelect::VTrim => Color::Rgb(200, 120, 255),
-            CorrectorSelect::TrimQuad => Color::Rgb(120, 200, 255),
-            CorrectorSelect::SkewQuad => Color::Rgb(255, 255, 120),
-            CorrectorSelect::SextA => Color::Rgb(255, 120, 180),
-            CorrectorSelect::SextB => Color::Rgb(180, 120, 255),
+            CorrectorSelect::HTrim => Ink(Color::Rgb(255, 180, 120), Role::Orange),
+            CorrectorSelect::VTrim => Ink(Color::Rgb(200, 120, 255), Role::Violet),
+            CorrectorSelect::TrimQuad => Ink(Color::Rgb(120, 200, 255), Role::Blue),
+            CorrectorSelect::SkewQuad => Ink(Color::Rgb(255, 255, 120), Role::Yellow),
+            CorrectorSelect::SextA => Ink(Color::Rgb(255, 120, 180), Role::Magenta),
+            CorrectorSelect::SextB => Ink(Color::Rgb(180, 120, 255), Role::Violet),
+        }
+    }
+
+    /// Setting the coil carries continuously; heat goes as its square.
+    fn rated(&self) -> f64 {
+        match self {
+            CorrectorSelect::HTrim | CorrectorSelect::VTrim => TRIM_RATED,
+            CorrectorSelect::TrimQuad | CorrectorSelect::SkewQuad => QUAD_RATED,
+            CorrectorSelect::SextA | CorrectorSelect::SextB => SEXT_RATED,
         }
     }
 
@@ -324,6 +448,86 @@ impl CorrectorSelect {
     }
 }
 
+// ── Console ramps ────────────────────────────────────────────────────────────
+/// Linear ramp of a ring parameter set up from the console.
+#[derive(Clone)]
+struct ParamRamp {
+    param: Param,
+    from: f64,
+    to: f64,
+    start_turn: u32,
+    end_turn: u32,
+}
+
+/// Slow orbit feedback: every `interval` turns it takes the BPM averages
+/// since the last update and trims the dipole correctors by `gain` times
+/// the kicks the model response says would cancel them.
+#[derive(Clone, Copy)]
+struct OrbitFeedback {
+    gain: f64,
+    interval: u32,
+}
+
+impl OrbitFeedback {
+    /// How far the loop sits from both sluggish (gain 0) and ringing
+    /// (gain 2): 1 at a gain of 1, where one update cancels the orbit.
+    fn margin(&self) -> f64 {
+        (1.0 - (1.0 - self.gain).abs()).max(0.0)
+    }
+}
+
+// ── Cycle history ────────────────────────────────────────────────────────────
+/// Summary of one finished cycle for the ghost traces: (turn, intensity,
+/// losses) every EMITTANCE_SAMPLE_TURNS.
+#[derive(Clone)]
+struct CycleTrace {
+    score: u32,
+    samples: Vec<(u32, f32, f32)>,
+}
+
+impl CycleTrace {
+    /// The sample at or just before `turn`.
+    fn at(&self, turn: u32) -> Option<(u32, f32, f32)> {
+        self.samples.iter().rev().find(|s| s.0 <= turn).copied()
+    }
+}
+
+/// Finished cycles kept, oldest dropped first.
+const CYCLE_ARCHIVE: usize = 10;
+const CYCLE_SAVE: &str = "booster_cycles";
+
+fn load_cycles() -> Vec<CycleTrace> {
+    let Some(data) = load_progress(CYCLE_SAVE) else { return Vec::new() };
+    let mut r = StateReader::new(&data);
+    let mut cycles = Vec::new();
+    let count = r.u32().unwrap_or(0) as usize;
+    for _ in 0..count.min(CYCLE_ARCHIVE) {
+        let Some(score) = r.u32() else { break };
+        let Some(len) = r.u32() else { break };
+        let samples: Option<Vec<_>> = (0..len.min(TURNS_IN_CYCLE / EMITTANCE_SAMPLE_TURNS + 1))
+            .map(|_| Some((r.u32()?, r.f32()?, r.f32()?)))
+            .collect();
+        let Some(samples) = samples else { break };
+        cycles.push(CycleTrace { score, samples });
+    }
+    cycles
+}
+
+fn save_cycles(cycles: &[CycleTrace]) {
+    let mut w = StateWriter::new();
+    w.u32(cycles.len() as u32);
+    for cycle in cycles {
+        w.u32(cycle.score);
+        w.u32(cycle.samples.len() as u32);
+        for &(turn, intensity, losses) in &cycle.samples {
+            w.u32(turn);
+            w.f32(intensity);
+            w.f32(losses);
+        }
+    }
+    save_progress(CYCLE_SAVE, &w.finish());
+}
+
 // ── Display Mode ─────────────────────────────────────────────────────────────
 #[derive(Clone, Copy, PartialEq)]
 enum DisplayMode {
@@ -332,6 +536,12 @@ enum DisplayMode {
     PhaseSpaceY,
     Longitudinal,
     TuneDiagram,
+    ResponseX,
+    ResponseY,
+    Emittance,
+    BucketFill,
+    Cycle,
+    Match,
 }
 
 impl DisplayMode {
@@ -342,6 +552,12 @@ impl DisplayMode {
             DisplayMode::PhaseSpaceY => "Y-Y'",
             DisplayMode::Longitudinal => "Longit.",
             DisplayMode::TuneDiagram => "Tune",
+            DisplayMode::ResponseX => "ORM-H",
+            DisplayMode::ResponseY => "ORM-V",
+            DisplayMode::Emittance => "Emit",
+            DisplayMode::BucketFill => "Fill",
+            DisplayMode::Cycle => "Cycle",
+            DisplayMode::Match => "Match",
         }
     }
 
@@ -351,17 +567,29 @@ impl DisplayMode {
             DisplayMode::PhaseSpaceX => DisplayMode::PhaseSpaceY,
             DisplayMode::PhaseSpaceY => DisplayMode::Longitudinal,
             DisplayMode::Longitudinal => DisplayMode::TuneDiagram,
-            DisplayMode::TuneDiagram => DisplayMode::Orbit,
+            DisplayMode::TuneDiagram => DisplayMode::ResponseX,
+            DisplayMode::ResponseX => DisplayMode::ResponseY,
+            DisplayMode::ResponseY => DisplayMode::Emittance,
+            DisplayMode::Emittance => DisplayMode::BucketFill,
+            DisplayMode::BucketFill => DisplayMode::Cycle,
+            DisplayMode::Cycle => DisplayMode::Match,
+            DisplayMode::Match => DisplayMode::Orbit,
         }
     }
 
     fn prev(&self) -> DisplayMode {
         match self {
-            DisplayMode::Orbit => DisplayMode::TuneDiagram,
+            DisplayMode::Orbit => DisplayMode::Match,
+            DisplayMode::Match => DisplayMode::Cycle,
+            DisplayMode::Cycle => DisplayMode::BucketFill,
             DisplayMode::PhaseSpaceX => DisplayMode::Orbit,
             DisplayMode::PhaseSpaceY => DisplayMode::PhaseSpaceX,
             DisplayMode::Longitudinal => DisplayMode::PhaseSpaceY,
             DisplayMode::TuneDiagram => DisplayMode::Longitudinal,
+            DisplayMode::ResponseX => DisplayMode::TuneDiagram,
+            DisplayMode::ResponseY => DisplayMode::ResponseX,
+            DisplayMode::Emittance => DisplayMode::ResponseY,
+            DisplayMode::BucketFill => DisplayMode::Emittance,
         }
     }
 }
@@ -457,6 +685,69 @@ impl Matrix2 {
     }
 }
 
+/// Solve `a·x = b` by Gaussian elimination with partial pivoting; `None`
+/// when `a` is singular.
+fn solve_linear(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col].abs() < 1e-12 { return None; }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        let (done, rest) = a.split_at_mut(col + 1);
+        let pivot_row = &done[col];
+        let pivot_b = b[col];
+        for (row, rhs) in rest.iter_mut().zip(&mut b[col + 1..]) {
+            let f = row[col] / pivot_row[col];
+            for (v, p) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *v -= f * p;
+            }
+            *rhs -= f * pivot_b;
+        }
+    }
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let sum: f64 = (row + 1..n).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+    Some(x)
+}
+
+/// Injection-energy F and D magnets with the quad bus at `mqat`, for x then y.
+fn injection_magnets(mqat: f64) -> [(Matrix2, Matrix2); 2] {
+    let k_f = K1_F_INJECTION * (1.0 + mqat);
+    let k_d = K1_D_INJECTION * (1.0 + mqat);
+    [
+        (Matrix2::focusing(k_f, MAGNET_LENGTH), Matrix2::focusing(-k_d, MAGNET_LENGTH)),
+        (Matrix2::focusing(-k_f, MAGNET_LENGTH), Matrix2::focusing(k_d, MAGNET_LENGTH)),
+    ]
+}
+
+/// Injection point (a cell's first F magnet) to the profile monitor at the
+/// end of its long straight, for x then y.
+fn monitor_transfer(mqat: f64) -> (Matrix2, Matrix2) {
+    let [x, y] = injection_magnets(mqat).map(|(f, d)| {
+        // Beam order F, Os, F, D, OL: the last element multiplies from the left
+        Matrix2::drift(LONG_DRIFT).multiply(&d).multiply(&f).multiply(&Matrix2::drift(SHORT_DRIFT)).multiply(&f)
+    });
+    (x, y)
+}
+
+/// The ring's periodic Twiss at the injection point, for x then y.
+fn matched_twiss(mqat: f64) -> (Twiss, Twiss) {
+    let [x, y] = injection_magnets(mqat).map(|(f, d)| {
+        let cell = d.multiply(&Matrix2::drift(LONG_DRIFT))
+            .multiply(&d)
+            .multiply(&f)
+            .multiply(&Matrix2::drift(SHORT_DRIFT))
+            .multiply(&f);
+        let cos_mu = ((cell.m11 + cell.m22) / 2.0).clamp(-0.999, 0.999);
+        let sin_mu = (1.0 - cos_mu * cos_mu).sqrt().copysign(cell.m12);
+        Twiss { beta: cell.m12 / sin_mu, alpha: (cell.m11 - cell.m22) / (2.0 * sin_mu) }
+    });
+    (x, y)
+}
+
 // ── Relativistic helpers ─────────────────────────────────────────────────────
 fn kinetic_to_gamma(ke_gev: f64) -> f64 {
     (ke_gev + PROTON_MASS_GEV) / PROTON_MASS_GEV
@@ -511,9 +802,10 @@ fn draw_plot_ticks(
     bcx: f32, bcy: f32,
     sx: f32, sy: f32,
     x_range: f32, y_range: f32,
+    theme: &Theme,
 ) {
-    let tick_style = Style::default().fg(Color::Rgb(70, 70, 100)).bg(Color::Rgb(10, 10, 18));
-    let label_style = Style::default().fg(Color::Rgb(55, 65, 90)).bg(Color::Rgb(10, 10, 18));
+    let tick_style = Style::default().fg(theme.paint(Color::Rgb(70, 70, 100), Role::Faint)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background));
+    let label_style = Style::default().fg(theme.paint(Color::Rgb(55, 65, 90), Role::Faint)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background));
     let cx_i = bcx as usize;
     let cy_i = bcy as usize;
 
@@ -571,6 +863,20 @@ pub struct BoosterGame {
     // Lattice
     lattice: Vec<LatticeElement>,
     correctors: Vec<CorrectorPackage>,  // one per cell (24 total)
+    magnet_temp: [f64; NUM_SECTIONS],   // corrector coil temperature per cell (°C)
+    tripped: [bool; NUM_SECTIONS],      // overtemperature interlock: supplies off until cool
+    orbit_drift: [(f64, f64); NUM_SECTIONS], // stray kicks from ground motion and supply drift (mrad)
+    drift_rate: [(f64, f64); NUM_SECTIONS],  // how fast each is moving (mrad/turn)
+    feedback: Option<OrbitFeedback>,
+    /// BPM sums at each cell's correctors since the last feedback update
+    bpm_sum: [(f64, f64); NUM_SECTIONS],
+    bpm_turns: u32,
+    /// Trim change per turn while the feedback slews in its last correction
+    feedback_slew: [(f64, f64); NUM_SECTIONS],
+    /// Rms closed orbit (mm) at the last BPM average
+    orbit_rms: f64,
+    /// Turns the feedback held the orbit, weighted by its margin
+    feedback_credit: f64,
 
     // Beam transverse state (x, x', y, y' in mm and mrad)
     beam_x: f64,
@@ -599,6 +905,9 @@ pub struct BoosterGame {
     // RF state
     rf_voltage_mv: f64,        // total RF voltage (MV) — player adjustable
     rf_phase_deg: f64,         // synchronous phase (degrees) — player adjustable
+    rf2_multiple: u32,         // harmonic cavity runs at rf2_multiple × h
+    rf2_ratio: f64,            // harmonic cavity voltage / fundamental voltage
+    rf2_phase_deg: f64,        // harmonic cavity phase relative to the fundamental
 
     // Computed optics (updated each turn based on energy)
     tune_x: f64,
@@ -620,6 +929,9 @@ pub struct BoosterGame {
     beam_running: bool,
     beam_lost: bool,
     beam_losses: f32,          // accumulated fractional losses
+    /// Losses booked per ramp phase, and how much of `beam_losses` that covers
+    loss_by_phase: [f32; 6],
+    losses_booked: f32,
 
     // Game state
     phase: GamePhase,
@@ -628,6 +940,15 @@ pub struct BoosterGame {
     turns_completed: u32,
     best_turns: u32,
     transition_crossed: bool,
+    /// This cycle's transition and loss alarms have sounded
+    transition_warned: bool,
+    /// Cycles in a row that crossed transition cleanly
+    transition_streak: u32,
+    /// Earned since the arcade last asked
+    badges: Vec<Badge>,
+    loss_alarmed: bool,
+    /// Screen tint when transition is crossed
+    flash: ScreenFlash,
 
     // Player controls
     selected_cell: usize,       // which cell's correctors we're editing
@@ -653,6 +974,19 @@ pub struct BoosterGame {
     x_xp_history: Vec<(f32, f32)>,
     y_yp_history: Vec<(f32, f32)>,
 
+    // RMS emittance growth (ε/ε₀) sampled every EMITTANCE_SAMPLE_TURNS: (turn, x, y)
+    emittance_history: Vec<(u32, f32, f32)>,
+
+    // This cycle's intensity and losses, and the finished cycles before it
+    cycle_trace: Vec<(u32, f32, f32)>,
+    past_cycles: Vec<CycleTrace>,
+
+    // Bunch train: occupancy of each of the 84 RF buckets, notch at buckets 0..NOTCH_WIDTH
+    bucket_fill: Vec<f32>,
+    notched: bool,
+    cog_offset: f64,           // buckets the train has slipped past the extraction kicker
+    cog_drift: f64,            // uncorrected slip per turn (revolution frequency error)
+
     // Display mode
     display_mode: DisplayMode,
 
@@ -664,7 +998,15 @@ pub struct BoosterGame {
     quad_bus_trim: f64,
 
     // Message flash
-    message: Option<(String, u32, Color)>,
+    message: Option<(String, u32, Ink)>,
+    /// Write-up of the cycle that just ended; O shows it
+    report: Option<CycleReport>,
+    report_open: bool,
+    report_scroll: u16,
+    /// Ctrl+P command list, while open
+    palette: Option<Palette>,
+    /// Help bar shows every key instead of the basics (A)
+    advanced_keys: bool,
 
     // Injection coordinate input
     input_mode: InputMode,
@@ -672,13 +1014,38 @@ pub struct BoosterGame {
     inject_x: f64,
     inject_y: f64,
 
+    // `:` command console and the ramps it has scheduled
+    console: Console,
+    ramps: Vec<ParamRamp>,
+    /// Practice mode: show the settings the physics is asking for
+    show_ideal: bool,
+
+    // Injection matching: the transfer line's Twiss, its correction from
+    // `match`, the last quad scan and the mismatch at the last injection
+    line_twiss: (Twiss, Twiss),
+    line_correction: Option<[[[f64; 2]; 2]; 2]>,
+    quad_scan: Vec<ScanPoint>,
+    scan_fit: Option<ScanFit>,
+    inject_mismatch: (f64, f64),
+
     // Scoring
     initial_emittance_x: f64,
     initial_emittance_y: f64,
+
+    // Last rendered panel areas, for mouse hit-testing
+    ring_area: Rect,
+    corrector_area: Rect,
+
+    // Transfer line errors, linac ripple and monitor noise
+    rng: GameRng,
 }
 
 impl BoosterGame {
     pub fn new() -> Self {
+        Self::with_rng(GameRng::from_entropy())
+    }
+
+    fn with_rng(mut rng: GameRng) -> Self {
         // Build lattice: per cell is F, Os, F, D, OL, D
         let mut lattice = Vec::new();
         for cell in 0..NUM_SECTIONS {
@@ -704,12 +1071,27 @@ impl BoosterGame {
         let geom_emit = EMITTANCE_NORM_95 / bg; // mm·mrad (geometric, 95%)
         // RMS = 95% / 6 for Gaussian
         let geom_emit_rms = geom_emit / 6.0;
-        let sigma_x = (geom_emit_rms * 33.7_f64).sqrt(); // β_x,max ~ 33.7 m → convert to mm
-        let sigma_y = (geom_emit_rms * 20.4_f64).sqrt(); // β_y,max ~ 20.4 m
+        let sigma_x = (geom_emit_rms * BETA_REF_X).sqrt(); // β_x,max ~ 33.7 m → convert to mm
+        let sigma_y = (geom_emit_rms * BETA_REF_Y).sqrt(); // β_y,max ~ 20.4 m
+
+        // The transfer line delivers the beam off the ring's matched Twiss
+        let (mx, my) = matched_twiss(0.0);
+        let mut mismatched = |m: Twiss| Twiss { beta: m.beta * rng.gen_range(0.55..1.8), alpha: m.alpha + rng.gen_range(-1.0..1.0) };
+        let line_twiss = (mismatched(mx), mismatched(my));
 
         Self {
             lattice,
             correctors,
+            magnet_temp: [AMBIENT_C; NUM_SECTIONS],
+            tripped: [false; NUM_SECTIONS],
+            orbit_drift: [(0.0, 0.0); NUM_SECTIONS],
+            drift_rate: [(0.0, 0.0); NUM_SECTIONS],
+            feedback: None,
+            bpm_sum: [(0.0, 0.0); NUM_SECTIONS],
+            bpm_turns: 0,
+            feedback_slew: [(0.0, 0.0); NUM_SECTIONS],
+            orbit_rms: 0.0,
+            feedback_credit: 0.0,
 
             beam_x: 0.0,
             beam_xp: 0.0,
@@ -730,6 +1112,9 @@ impl BoosterGame {
 
             rf_voltage_mv: 0.5,    // start at moderate voltage
             rf_phase_deg: 0.0,     // synchronous phase
+            rf2_multiple: 2,
+            rf2_ratio: 0.0,        // harmonic cavity off
+            rf2_phase_deg: 180.0,  // bunch-lengthening mode
 
             tune_x: TUNE_X_BARE,
             tune_y: TUNE_Y_BARE,
@@ -747,6 +1132,8 @@ impl BoosterGame {
             beam_running: false,
             beam_lost: false,
             beam_losses: 0.0,
+            loss_by_phase: [0.0; 6],
+            losses_booked: 0.0,
 
             phase: GamePhase::Setup,
             tick: 0,
@@ -754,6 +1141,11 @@ impl BoosterGame {
             turns_completed: 0,
             best_turns: 0,
             transition_crossed: false,
+            transition_warned: false,
+            transition_streak: 0,
+            badges: Vec::new(),
+            loss_alarmed: false,
+            flash: ScreenFlash::new(),
 
             selected_cell: 0,
             selected_corrector: CorrectorSelect::HTrim,
@@ -772,20 +1164,50 @@ impl BoosterGame {
             x_xp_history: Vec::new(),
             y_yp_history: Vec::new(),
 
+            emittance_history: vec![(0, 1.0, 1.0)],
+
+            cycle_trace: vec![(0, 1.0, 0.0)],
+            past_cycles: load_cycles(),
+
+            bucket_fill: vec![1.0; HARMONIC_NUMBER as usize],
+            notched: false,
+            cog_offset: 0.0,
+            cog_drift: 0.0,
+
             display_mode: DisplayMode::Orbit,
             sim_speed: SimSpeed::Slow,
             bend_bus_trim: 0.0,
             quad_bus_trim: 0.0,
 
             message: None,
+            report: None,
+            report_open: false,
+            report_scroll: 0,
+            palette: None,
+            advanced_keys: false,
 
             input_mode: InputMode::None,
             input_buffer: String::new(),
             inject_x: 0.0,
             inject_y: 0.0,
 
+            console: Console::default(),
+            ramps: Vec::new(),
+            show_ideal: false,
+
+            line_twiss,
+            line_correction: None,
+            quad_scan: Vec::new(),
+            scan_fit: None,
+            inject_mismatch: (1.0, 1.0),
+
             initial_emittance_x: geom_emit_rms,
             initial_emittance_y: geom_emit_rms,
+
+            ring_area: Rect::default(),
+            corrector_area: Rect::default(),
+
+            rng,
         }
     }
 
@@ -857,7 +1279,7 @@ impl BoosterGame {
         }
 
         // Apply trim quad corrections to tune
-        let trim_quad_sum: f64 = self.correctors.iter().map(|c| c.trim_quad).sum();
+        let trim_quad_sum: f64 = self.live_correctors().map(|c| c.trim_quad).sum();
         self.tune_x += trim_quad_sum * 0.05; // approximate sensitivity
         self.tune_y -= trim_quad_sum * 0.05;
 
@@ -886,16 +1308,130 @@ impl BoosterGame {
         };
 
         // Chromaticity: natural + sextupole correction
-        let sext_a_sum: f64 = self.correctors.iter().map(|c| c.sext_a).sum();
-        let sext_b_sum: f64 = self.correctors.iter().map(|c| c.sext_b).sum();
-        // Natural chromaticity is ~ -1 per unit of tune
-        self.chromaticity_x = -self.tune_x + sext_a_sum * 2.0 + sext_b_sum * 1.0;
-        self.chromaticity_y = -self.tune_y - sext_a_sum * 1.0 + sext_b_sum * 2.0;
-
-        // Space charge tune shift: ΔQ ∝ N / (ε_n · β · γ²)
+        let sext_a_sum: f64 = self.live_correctors().map(|c| c.sext_a).sum();
+        let sext_b_sum: f64 = self.live_correctors().map(|c| c.sext_b).sum();
+        // Natural chromaticity is ~ -1 per unit of tune; the eddy-current
+        // sextupole in the dipoles pushes x up and y down
+        let eddy = self.eddy_chromaticity();
+        self.chromaticity_x = -self.tune_x + eddy + sext_a_sum * 2.0 + sext_b_sum * 1.0;
+        self.chromaticity_y = -self.tune_y - eddy - sext_a_sum * 1.0 + sext_b_sum * 2.0;
+
+        // Laslett space charge tune shift: ΔQ ∝ N / (ε_n · β · γ² · B_f)
         let bg2 = self.current_beta * self.current_gamma * self.current_gamma;
         let emit_factor = if self.initial_emittance_x > 0.0 { self.initial_emittance_x } else { 1.0 };
-        self.sc_tune_shift = -0.3 * self.beam_intensity / (emit_factor * bg2);
+        // A shorter bunch means a higher peak line density for the same charge
+        let peak_density = SC_REF_BUNCHING / self.bunching_factor();
+        self.sc_tune_shift = -0.3 * self.beam_intensity * peak_density / (emit_factor * bg2);
+    }
+
+    /// Correctors whose supplies are on; a tripped cell delivers nothing.
+    fn live_correctors(&self) -> impl Iterator<Item = &CorrectorPackage> {
+        self.correctors.iter().zip(self.tripped).filter(|(_, t)| !t).map(|(c, _)| c)
+    }
+
+    /// Chromaticity from the sextupole component eddy currents induce in
+    /// the dipole vacuum chambers. It follows the ramp rate over the field,
+    /// Ḃ/B: nothing at injection or extraction where the sine ramp is flat,
+    /// strongest early on while the field is still low.
+    fn eddy_chromaticity(&self) -> f64 {
+        let t_frac = self.ramp_turn as f64 / TURNS_IN_CYCLE as f64;
+        let brho_ratio = gamma_to_brho(kinetic_to_gamma(E_INJECTION_GEV)) / self.current_brho;
+        EDDY_CHROM * (std::f64::consts::PI * t_frac).sin() * brho_ratio
+    }
+
+    /// How far the chromaticities are from the -7 the ramp wants.
+    fn chrom_error(&self) -> f64 {
+        (self.chromaticity_x.abs() - 7.0).abs() + (self.chromaticity_y.abs() - 7.0).abs()
+    }
+
+    /// Per-cell sextupole settings that put both chromaticities at the -7
+    /// `handle_transition` grades against, for the current tunes and ramp rate.
+    fn ideal_sextupoles(&self) -> (f64, f64) {
+        // Cx = -Qx + E + 2A + B, Cy = -Qy - E - A + 2B, solved for the family sums
+        let eddy = self.eddy_chromaticity();
+        let rx = self.tune_x - 7.0 - eddy;
+        let ry = self.tune_y - 7.0 + eddy;
+        let a = (2.0 * rx - ry) / 5.0;
+        let b = (rx + 2.0 * ry) / 5.0;
+        (a / NUM_SECTIONS as f64, b / NUM_SECTIONS as f64)
+    }
+
+    /// MDAT trim that cancels the notch's drift against the kicker.
+    fn ideal_mdat(&self) -> f64 {
+        self.cog_drift / COG_GAIN
+    }
+
+    fn rf2_label(&self) -> String {
+        format!(
+            "RF h={}: {:.0}% / {:.0} deg (flat {:.0}%)",
+            self.rf2_multiple * HARMONIC_NUMBER,
+            self.rf2_ratio * 100.0,
+            self.rf2_phase_deg,
+            self.bucket_flatness() * 100.0,
+        )
+    }
+
+    /// RF restoring force relative to the fundamental alone, per unit phase:
+    ///   V(φ) ∝ sin(φs + φ) + r·sin(n(φs + φ) + θ)
+    /// 1.0 with the harmonic cavity off, 0.0 for a flat-bottomed bucket, negative
+    /// when over-driven (the bucket splits in two).
+    fn rf_focusing(&self) -> f64 {
+        let phi_s = self.rf_phase_deg.to_radians();
+        let n = self.rf2_multiple as f64;
+        let theta = self.rf2_phase_deg.to_radians();
+        let cos_s = phi_s.cos();
+        if cos_s.abs() < 1e-6 { return 1.0; }
+        (cos_s + n * self.rf2_ratio * (n * phi_s + theta).cos()) / cos_s
+    }
+
+    /// How flat the bucket bottom is, 0..1.
+    fn bucket_flatness(&self) -> f64 {
+        (1.0 - self.rf_focusing().abs()).clamp(0.0, 1.0)
+    }
+
+    /// Bunching factor B_f: mean over peak line density, roughly the bunch
+    /// length as a fraction of the RF period. The bunch fills the bucket
+    /// with its longitudinal emittance, so more RF voltage squeezes it
+    /// shorter; a flat-bottomed bucket lets it spread back out.
+    fn bunching_factor(&self) -> f64 {
+        let eta = slip_factor(self.current_gamma).abs();
+        let v_gv = self.rf_voltage_mv * 1e-3;
+        if eta < 1e-6 || v_gv <= 0.0 { return 1.0; }
+        let total_e_gev = self.current_ke_gev + PROTON_MASS_GEV;
+        // Stationary bucket area = 8·ΔE_half / ω_rf (eV·s), shrunk by the
+        // moving-bucket factor once the synchronous phase is off zero
+        let de_half_ev = (2.0 * v_gv * self.current_beta.powi(2) * total_e_gev
+            / (std::f64::consts::PI * HARMONIC_NUMBER as f64 * eta)).sqrt() * 1e9;
+        let omega_rf = std::f64::consts::TAU * HARMONIC_NUMBER as f64
+            * self.current_beta * 299_792_458.0 / CIRCUMFERENCE;
+        let sin_s = self.rf_phase_deg.to_radians().sin().abs();
+        let area = 8.0 * de_half_ev / omega_rf * (1.0 - sin_s) / (1.0 + sin_s);
+        if area <= 0.0 { return 1.0; }
+        // Matched bunch half-length φ̂ = 4·√(ε / (π·A)) for a small fill
+        let half_length = 4.0 * (LONG_EMITTANCE_EVS / (std::f64::consts::PI * area)).sqrt();
+        let fraction = half_length / std::f64::consts::PI * (1.0 + 0.5 * self.bucket_flatness());
+        fraction.clamp(0.2, 1.0)
+    }
+
+    /// Normalized RF waveform seen by a particle at phase offset φ:
+    /// fundamental plus harmonic cavity, with the synchronous values removed.
+    fn rf_waveform(&self, phi: f64) -> f64 {
+        let phi_s = self.rf_phase_deg.to_radians();
+        let n = self.rf2_multiple as f64;
+        let theta = self.rf2_phase_deg.to_radians();
+        (phi_s + phi).sin() - phi_s.sin()
+            + self.rf2_ratio * ((n * (phi_s + phi) + theta).sin() - (n * phi_s + theta).sin())
+    }
+
+    /// Integral of rf_waveform from 0 to φ (the bucket potential, up to sign).
+    fn rf_potential(&self, phi: f64) -> f64 {
+        let phi_s = self.rf_phase_deg.to_radians();
+        let n = self.rf2_multiple as f64;
+        let theta = self.rf2_phase_deg.to_radians();
+        let fund = phi_s.cos() - (phi_s + phi).cos() - phi * phi_s.sin();
+        let harm = ((n * phi_s + theta).cos() - (n * (phi_s + phi) + theta).cos()) / n
+            - phi * (n * phi_s + theta).sin();
+        fund + self.rf2_ratio * harm
     }
 
     // ── Transfer Matrix for one element at current energy ────────────────
@@ -955,8 +1491,19 @@ impl BoosterGame {
             self.beam_xp += self.bend_bus_trim * DIPOLE_ANGLE * brho_scale;
         }
 
-        // Apply correctors at long drift (element index 4 in cell)
+        // The cell's BPM sits with its correctors, where the ground has
+        // moved the magnets by however much it has drifted this cycle
         if elem.index == 4 {
+            let bpm = &mut self.bpm_sum[self.beam_cell];
+            bpm.0 += self.beam_x + self.rng.gen_range(-BPM_NOISE..BPM_NOISE);
+            bpm.1 += self.beam_y + self.rng.gen_range(-BPM_NOISE..BPM_NOISE);
+            let (dx, dy) = self.orbit_drift[self.beam_cell];
+            self.beam_xp += dx;
+            self.beam_yp += dy;
+        }
+
+        // Apply correctors at long drift (element index 4 in cell)
+        if elem.index == 4 && !self.tripped[self.beam_cell] {
             let corr = &self.correctors[self.beam_cell];
             // Trim dipoles: angular kicks
             self.beam_xp += corr.h_trim;
@@ -989,18 +1536,15 @@ impl BoosterGame {
 
         // Synchrotron equation of motion:
         // Δφ = 2π·h·η·δ   (phase slip per turn)
-        // Δδ = eV/(2π·β²·E) · (sin(φ_s + Δφ) - sin(φ_s))
-        let phi_s = self.rf_phase_deg.to_radians();
+        // Δδ = eV/(2π·β²·E) · (sin(φ_s + Δφ) - sin(φ_s)), plus the harmonic cavity
         let v_per_turn = self.rf_voltage_mv * 1e-3; // convert MV to GV
 
         // Phase update
         self.beam_phi += std::f64::consts::TAU * HARMONIC_NUMBER as f64 * eta * self.beam_dp;
 
         // Energy kick from RF
-        let sin_phi = (phi_s + self.beam_phi).sin();
-        let sin_phi_s = phi_s.sin();
         let de_kick = v_per_turn / (std::f64::consts::TAU * self.current_beta * self.current_beta * total_e_gev)
-            * (sin_phi - sin_phi_s);
+            * self.rf_waveform(self.beam_phi);
         self.beam_de += de_kick;
 
         // Update momentum offset from energy deviation
@@ -1026,6 +1570,22 @@ impl BoosterGame {
         if self.beam_de.abs() > bucket_area * 3.0 || self.beam_phi.abs() > std::f64::consts::PI {
             self.beam_losses += 2.0; // longitudinal loss
         }
+
+        // Space-charge halo scraping at low energy; flattening the bucket helps
+        let sc_excess = self.sc_tune_shift.abs() - SC_LOSS_THRESHOLD;
+        if sc_excess > 0.0 {
+            self.beam_intensity *= 1.0 - sc_excess * 2e-4;
+        }
+        // Chromaticity left far off while the eddy currents move it drives
+        // head-tail growth, so the sextupoles have to follow the ramp
+        let chrom_excess = self.chrom_error() - CHROM_TOLERANCE;
+        if chrom_excess > 0.0 {
+            self.beam_losses += (chrom_excess * 0.001) as f32;
+        }
+        // An over-driven harmonic cavity splits the bucket and the bunch tears apart
+        if self.rf_focusing() < -0.2 {
+            self.beam_losses += 0.05;
+        }
     }
 
     // ── Transition crossing special handling ─────────────────────────────
@@ -1033,11 +1593,14 @@ impl BoosterGame {
         if self.transition_crossed { return; }
 
         let gamma_ratio = self.current_gamma / GAMMA_TRANSITION;
+        if gamma_ratio > TRANSITION_WARNING && !self.transition_warned {
+            self.transition_warned = true;
+            audio::emit(AudioEvent::TransitionWarning);
+        }
         if gamma_ratio > 0.99 && gamma_ratio < 1.01 {
             // At transition: RF phase must flip for stability
             // If player hasn't set chromaticity correctly, large losses occur
-            let chrom_quality = (self.chromaticity_x.abs() - 7.0).abs()
-                + (self.chromaticity_y.abs() - 7.0).abs();
+            let chrom_quality = self.chrom_error();
 
             // Bunch length oscillation excitation
             let oscillation_amp = 0.5 + chrom_quality * 0.3;
@@ -1055,11 +1618,19 @@ impl BoosterGame {
 
             if gamma_ratio > 1.005 {
                 self.transition_crossed = true;
+                let clean = chrom_quality < CLEAN_TRANSITION;
+                self.transition_streak = if clean { self.transition_streak + 1 } else { 0 };
+                if self.transition_streak >= 2 {
+                    self.earn(Badge::TransitionTwice);
+                }
+                let color = if clean { Ink(Color::Rgb(80, 255, 80), Role::Green) } else { fx::alert_color() };
                 self.message = Some((
                     format!("Transition crossed! Chrom quality: {:.1}", chrom_quality),
                     90,
-                    if chrom_quality < 3.0 { Color::Rgb(80, 255, 80) } else { Color::Rgb(255, 80, 80) },
+                    color,
                 ));
+                self.flash.trigger(color);
+                audio::emit(AudioEvent::TransitionCrossed);
             }
         }
     }
@@ -1080,9 +1651,12 @@ impl BoosterGame {
 
             // Hard wall check
             if display_x.abs() > APERTURE_DISPLAY || display_y.abs() > APERTURE_DISPLAY {
+                self.book_losses();
                 self.beam_lost = true;
                 self.phase = GamePhase::Lost;
-                self.message = Some(("Hit aperture wall!".to_string(), 60, Color::Rgb(255, 60, 60)));
+                self.message = Some(("Hit aperture wall!".to_string(), 60, Ink(Color::Rgb(255, 60, 60), Role::Red)));
+                self.archive_cycle();
+                self.write_report("hit the aperture wall");
                 return;
             }
 
@@ -1100,14 +1674,21 @@ impl BoosterGame {
                 self.beam_losses += loss_this_step;
                 self.beam_intensity *= (1.0 - loss_this_step as f64 * 0.001).max(0.0);
             }
+            if self.beam_losses >= LOSS_ALARM && !self.loss_alarmed {
+                self.loss_alarmed = true;
+                audio::emit(AudioEvent::LossAlarm);
+            }
 
             if self.beam_losses >= MAX_LOSSES {
+                self.book_losses();
                 self.beam_lost = true;
                 self.phase = GamePhase::Lost;
                 self.message = Some((
                     format!("Beam losses exceeded {:.0}!", MAX_LOSSES),
-                    60, Color::Rgb(255, 100, 100),
+                    60, Ink(Color::Rgb(255, 100, 100), Role::Red),
                 ));
+                self.archive_cycle();
+                self.write_report("losses over the limit");
                 return;
             }
 
@@ -1148,16 +1729,36 @@ impl BoosterGame {
                         self.best_turns = self.turns_completed;
                     }
 
+                    self.apply_ramps();
+                    self.book_losses();
+                    self.drift_orbit();
+                    self.run_feedback();
+
                     // Update energy each turn
                     self.update_energy_for_turn();
                     self.update_optics();
                     self.advance_longitudinal();
                     self.handle_transition();
+                    self.check_badges();
+
+                    self.advance_notch();
+
+                    if self.ramp_turn.is_multiple_of(EMITTANCE_SAMPLE_TURNS) {
+                        let (ex, ey) = self.emittance_growth();
+                        self.emittance_history.push((self.ramp_turn, ex as f32, ey as f32));
+                        self.cycle_trace.push((self.ramp_turn, self.beam_intensity as f32, self.beam_losses));
+                    }
 
                     // Check extraction
                     if self.ramp_turn >= TURNS_IN_CYCLE {
+                        self.book_losses();
                         self.phase = GamePhase::Extraction;
+                        self.extract_with_kicker();
+                        self.book_losses();
+                        audio::emit(AudioEvent::Extraction);
                         self.beam_running = false;
+                        self.archive_cycle();
+                        self.write_report("at 8 GeV");
                     }
                 }
             }
@@ -1166,6 +1767,301 @@ impl BoosterGame {
 
     // ── Helper methods ───────────────────────────────────────────────────
 
+    fn earn(&mut self, badge: Badge) {
+        if !self.badges.contains(&badge) {
+            self.badges.push(badge);
+        }
+    }
+
+    /// Badges for how the machine is set up as the beam finishes a turn.
+    fn check_badges(&mut self) {
+        // Only counts while the eddy currents are pulling it away
+        let near = |c: f64| (c.abs() - 7.0).abs() <= BADGE_CHROM;
+        if self.eddy_chromaticity().abs() >= BADGE_EDDY && near(self.chromaticity_x) && near(self.chromaticity_y) {
+            self.earn(Badge::ChromCorrected);
+        }
+        if self.bump.as_ref().is_some_and(|b| b.size == 4 && b.kick.abs() >= BADGE_BUMP_KICK) {
+            self.earn(Badge::BumpClosed);
+        }
+    }
+
+    /// One turn of ground motion and supply drift. It's the drift rates
+    /// that wander, so the stray kicks move smoothly rather than jumping,
+    /// which would set the beam oscillating.
+    fn drift_orbit(&mut self) {
+        for (drift, rate) in self.orbit_drift.iter_mut().zip(self.drift_rate.iter_mut()) {
+            rate.0 += self.rng.gen_range(-DRIFT_WANDER..DRIFT_WANDER);
+            rate.1 += self.rng.gen_range(-DRIFT_WANDER..DRIFT_WANDER);
+            drift.0 += rate.0;
+            drift.1 += rate.1;
+        }
+    }
+
+    /// Average the BPMs once enough turns are in, and if the feedback is on,
+    /// correct the closed orbit they show. Corrections are slewed in over
+    /// the next interval: a sudden step would set the beam oscillating.
+    fn run_feedback(&mut self) {
+        if self.feedback.is_some() {
+            for cell in 0..NUM_SECTIONS {
+                // A tripped cell's supplies are off; the others do what they can
+                if self.tripped[cell] { continue; }
+                let (dx, dy) = self.feedback_slew[cell];
+                let corr = &mut self.correctors[cell];
+                corr.h_trim += dx;
+                corr.v_trim += dy;
+                corr.limit();
+            }
+        }
+        self.bpm_turns += 1;
+        if self.bpm_turns < self.feedback.map_or(BPM_TURNS, |f| f.interval) { return; }
+        let turns = self.bpm_turns as f64;
+        let orbit_x: Vec<f64> = self.bpm_sum.iter().map(|b| b.0 / turns).collect();
+        let orbit_y: Vec<f64> = self.bpm_sum.iter().map(|b| b.1 / turns).collect();
+        let sum_sq: f64 = orbit_x.iter().chain(&orbit_y).map(|v| v * v).sum();
+        self.orbit_rms = (sum_sq / NUM_SECTIONS as f64).sqrt();
+        self.bpm_sum = [(0.0, 0.0); NUM_SECTIONS];
+        self.bpm_turns = 0;
+
+        let Some(fb) = self.feedback else { return };
+        if self.orbit_rms <= ORBIT_TOLERANCE {
+            self.feedback_credit += fb.margin() * turns;
+        }
+        let per_turn = -fb.gain / fb.interval as f64;
+        let kicks_x = solve_linear(self.response_matrix(false), orbit_x).unwrap_or_else(|| vec![0.0; NUM_SECTIONS]);
+        let kicks_y = solve_linear(self.response_matrix(true), orbit_y).unwrap_or_else(|| vec![0.0; NUM_SECTIONS]);
+        for (slew, (kx, ky)) in self.feedback_slew.iter_mut().zip(kicks_x.into_iter().zip(kicks_y)) {
+            *slew = (kx * per_turn, ky * per_turn);
+        }
+    }
+
+    fn feedback_label(&self) -> String {
+        match self.feedback {
+            Some(fb) => format!("feedback gain {:.2} every {} turns, margin {:.0}%", fb.gain, fb.interval, fb.margin() * 100.0),
+            None => "feedback off".to_string(),
+        }
+    }
+
+    /// Closed orbit and the feedback, for the foot of the orbit plot.
+    fn feedback_status(&self, theme: &Theme) -> Line<'static> {
+        let held = self.orbit_rms <= ORBIT_TOLERANCE;
+        let color = if !held { theme.paint(Color::Rgb(255, 140, 60), Role::Orange) } else if self.feedback.is_some() { theme.paint(Color::Rgb(80, 255, 180), Role::Cyan) } else { theme.paint(Color::Rgb(120, 120, 140), Role::Dim) };
+        let fb = match self.feedback {
+            Some(fb) => format!("FB g{:.2}/{}t", fb.gain, fb.interval),
+            None => "FB off".to_string(),
+        };
+        Line::styled(format!(" CO {:.1}µm · {} ", self.orbit_rms * 1000.0, fb), Style::default().fg(color))
+    }
+
+    /// Points for the feedback holding the orbit, scaled by its margin.
+    fn feedback_points(&self) -> u32 {
+        (FEEDBACK_POINTS * self.feedback_credit / TURNS_IN_CYCLE as f64) as u32
+    }
+
+    /// Kick the bump's correctors by `amount` times their coefficients, in
+    /// the planes asked for.
+    fn kick_bump(&mut self, amount: f64, horizontal: bool, vertical: bool) {
+        let Some(bump) = self.bump.as_mut() else { return };
+        bump.kick += amount;
+        for (sec, coeff) in bump.section_coefficients() {
+            let corr = &mut self.correctors[sec];
+            if horizontal { corr.h_trim += amount * coeff; }
+            if vertical { corr.v_trim += amount * coeff; }
+            corr.limit();
+        }
+    }
+
+    /// RMS emittance (geometric, mm·mrad) from the beam envelope: ε = σ²/β.
+    fn emittance(&self) -> (f64, f64) {
+        (self.beam_sigma_x.powi(2) / BETA_REF_X, self.beam_sigma_y.powi(2) / BETA_REF_Y)
+    }
+
+    /// Emittance growth relative to injection, ε/ε₀ per plane.
+    fn emittance_growth(&self) -> (f64, f64) {
+        let (ex, ey) = self.emittance();
+        (ex / self.initial_emittance_x, ey / self.initial_emittance_y)
+    }
+
+    /// Score weight in 0..1: 1.0 when both planes kept their injected emittance.
+    fn emittance_preservation(&self) -> f64 {
+        let (gx, gy) = self.emittance_growth();
+        (1.0 / (gx * gy).sqrt()).clamp(0.0, 1.0)
+    }
+
+    // ── Notching & cogging ──────────────────────────────────────────────
+    /// Fill every bucket from the linac (with a little batch-to-batch ripple)
+    /// and pick this cycle's revolution frequency error.
+    fn fill_buckets(&mut self) {
+        self.bucket_fill = (0..HARMONIC_NUMBER).map(|_| self.rng.gen_range(0.9..=1.0)).collect();
+        self.notched = false;
+        self.cog_offset = 0.0;
+        self.cog_drift = self.rng.gen_range(-COG_DRIFT_MAX..=COG_DRIFT_MAX) / TURNS_IN_CYCLE as f64;
+    }
+
+    /// Once per turn: fire the notcher on schedule, then slip the train against
+    /// the extraction kicker. MDAT moves the radial position, which cogs the notch.
+    fn advance_notch(&mut self) {
+        if !self.notched && self.ramp_turn >= NOTCH_TURN {
+            let total: f32 = self.bucket_fill.iter().sum();
+            let removed: f32 = self.bucket_fill[..NOTCH_WIDTH].iter().sum();
+            self.bucket_fill[..NOTCH_WIDTH].iter_mut().for_each(|b| *b = 0.0);
+            self.beam_intensity *= 1.0 - (removed / total.max(1e-6)) as f64;
+            self.notched = true;
+            self.message = Some((
+                format!("Notch cut: {} buckets cleared", NOTCH_WIDTH),
+                60, Ink(Color::Rgb(200, 160, 255), Role::Violet),
+            ));
+        }
+        let slip = self.cog_drift - self.bend_bus_trim * COG_GAIN;
+        self.cog_offset = (self.cog_offset + slip).rem_euclid(HARMONIC_NUMBER as f64);
+    }
+
+    /// Notch misalignment in buckets, signed (-42..42).
+    fn notch_error(&self) -> f64 {
+        let h = HARMONIC_NUMBER as f64;
+        (self.cog_offset + h / 2.0).rem_euclid(h) - h / 2.0
+    }
+
+    /// Fraction of a bucket (by train index) inside the kicker rise window.
+    fn kicker_overlap(&self, bucket: usize) -> f64 {
+        let h = HARMONIC_NUMBER as f64;
+        let start = (bucket as f64 + self.cog_offset).rem_euclid(h);
+        // Window is [0, NOTCH_WIDTH); check the bucket and its wrapped image
+        [start, start - h].iter()
+            .map(|&a| ((a + 1.0).min(NOTCH_WIDTH as f64) - a.max(0.0)).max(0.0))
+            .sum()
+    }
+
+    /// Beam sitting in the kicker rise window is sprayed on the septum at extraction.
+    fn extract_with_kicker(&mut self) {
+        let total: f64 = self.bucket_fill.iter().map(|&f| f as f64).sum();
+        if total <= 0.0 { return; }
+        let lost: f64 = self.bucket_fill.iter().enumerate()
+            .map(|(i, &f)| f as f64 * self.kicker_overlap(i))
+            .sum::<f64>() / total;
+        if lost > 0.001 {
+            self.beam_intensity *= 1.0 - lost;
+            self.beam_losses += (lost * 100.0) as f32;
+            self.message = Some((
+                format!("Notch off by {:+.1} bkts: {:.1}% lost at extraction", self.notch_error(), lost * 100.0),
+                120, Ink(Color::Rgb(255, 120, 80), Role::Red),
+            ));
+        }
+    }
+
+    /// File the finished cycle's trace, closing it at the turn it ended.
+    fn archive_cycle(&mut self) {
+        if !self.transition_crossed {
+            self.transition_streak = 0;
+        }
+        let mut samples = std::mem::take(&mut self.cycle_trace);
+        if samples.last().is_some_and(|s| s.0 < self.ramp_turn) {
+            samples.push((self.ramp_turn, self.beam_intensity as f32, self.beam_losses));
+        }
+        if samples.len() < 2 { return; }
+        self.past_cycles.push(CycleTrace { score: self.get_score(), samples });
+        if self.past_cycles.len() > CYCLE_ARCHIVE {
+            self.past_cycles.remove(0);
+        }
+        save_cycles(&self.past_cycles);
+    }
+
+    /// Charge losses since the last call to the phase the beam is in.
+    fn book_losses(&mut self) {
+        let new = self.beam_losses - self.losses_booked;
+        self.losses_booked = self.beam_losses;
+        if let Some(slot) = self.phase.loss_slot() {
+            self.loss_by_phase[slot] += new;
+        }
+    }
+
+    /// Take down the end-of-cycle report; `outcome` says how it ended.
+    fn write_report(&mut self, outcome: &str) {
+        self.report = Some(CycleReport {
+            finished: now_secs(),
+            extracted: self.phase == GamePhase::Extraction,
+            outcome: outcome.to_string(),
+            turn: self.ramp_turn,
+            turns_in_cycle: TURNS_IN_CYCLE,
+            energy_gev: self.current_ke_gev,
+            intensity: self.beam_intensity,
+            losses: self.beam_losses,
+            max_losses: MAX_LOSSES,
+            loss_by_phase: self.loss_by_phase,
+            tunes: (self.tune_x, self.tune_y),
+            chromaticity: (self.chromaticity_x, self.chromaticity_y),
+            emittance_growth: self.emittance_growth(),
+            mismatch: self.inject_mismatch,
+            transition_crossed: self.transition_crossed,
+            notch_error: self.notch_error(),
+            params: Param::ALL.iter().map(|&p| (p, self.param(p))).collect(),
+            correctors: self.correctors.iter().map(CorrectorPackage::values).collect(),
+            score_parts: self.score_parts().to_vec(),
+        });
+        self.report_scroll = 0;
+    }
+
+    /// The score's terms: surviving intensity, turns and bonuses.
+    fn score_parts(&self) -> [(&'static str, u32); 6] {
+        [
+            // Intensity survival weighted by emittance preservation
+            ("Intensity x emittance", (self.beam_intensity * self.emittance_preservation() * 1000.0) as u32),
+            ("Turns", self.ramp_turn),
+            ("Transition bonus", if self.transition_crossed { 500 } else { 0 }),
+            ("Extraction bonus", if self.phase == GamePhase::Extraction { 2000 } else { 0 }),
+            ("Match bonus", self.match_bonus()),
+            ("Orbit feedback", self.feedback_points()),
+        ]
+    }
+
+    fn render_report(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let Some(report) = &self.report else { return };
+        let lines = report.lines();
+        let width = (lines.iter().map(|l| l.chars().count()).max().unwrap_or(0) as u16 + 4).min(area.width);
+        let height = area.height.saturating_sub(2).min(lines.len() as u16 + 3);
+        let area = Rect::new(area.x + (area.width - width) / 2, area.y + (area.height - height) / 2, width, height);
+        frame.render_widget(Clear, area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.paint(Color::Rgb(100, 180, 255), Role::Blue)))
+            .title(" Cycle Report ")
+            .title_style(Style::default().fg(theme.paint(Color::Rgb(120, 200, 255), Role::Blue)).add_modifier(Modifier::BOLD))
+            .title_bottom(hud::overlay_legend(&[("↑↓", "Scroll"), ("S", "Save"), ("Esc", "Close")], theme))
+            .style(Style::default().bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+        let max_scroll = (lines.len() as u16).saturating_sub(inner.height);
+        let text: Vec<Line> = lines.into_iter()
+            .map(|l| {
+                let heading = !l.starts_with(' ') && l.chars().all(|c| !c.is_lowercase()) && !l.is_empty();
+                let style = if heading {
+                    Style::default().fg(theme.paint(Color::Rgb(255, 220, 120), Role::Yellow)).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme.paint(Color::Rgb(200, 200, 210), Role::Text))
+                };
+                Line::styled(l, style)
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(text).scroll((self.report_scroll.min(max_scroll), 0)), inner);
+    }
+
+    /// Highest-scoring earlier cycle, drawn as the ghost.
+    fn best_cycle(&self) -> Option<&CycleTrace> {
+        self.past_cycles.iter().max_by_key(|c| c.score)
+    }
+
+    /// Re-read the cycle archive after it was deleted from outside the game.
+    pub fn reload_cycles(&mut self) {
+        self.past_cycles = load_cycles();
+    }
+
+    /// Fresh beam envelope and emittance record for a new injection.
+    fn reset_emittance(&mut self) {
+        self.beam_sigma_x = (self.initial_emittance_x * BETA_REF_X).sqrt();
+        self.beam_sigma_y = (self.initial_emittance_y * BETA_REF_Y).sqrt();
+        self.emittance_history = vec![(0, 1.0, 1.0)];
+    }
+
     fn stability_score(&self) -> f32 {
         if self.pos_history.is_empty() { return 0.0; }
         let avg_pos: f32 = self.pos_history.iter().map(|p| p.abs()).sum::<f32>() / self.pos_history.len() as f32;
@@ -1181,81 +2077,603 @@ impl BoosterGame {
         ((x_score + y_score) * 0.5) * 100.0
     }
 
-    fn adjust_corrector(&mut self, cell: usize, corr_type: CorrectorSelect, delta: f64) {
-        let corr = &mut self.correctors[cell];
-        match corr_type {
-            CorrectorSelect::HTrim => corr.h_trim += delta,
-            CorrectorSelect::VTrim => corr.v_trim += delta,
-            CorrectorSelect::TrimQuad => corr.trim_quad += delta,
-            CorrectorSelect::SkewQuad => corr.skew_quad += delta,
-            CorrectorSelect::SextA => corr.sext_a += delta,
-            CorrectorSelect::SextB => corr.sext_b += delta,
-        }
+    /// Start a fresh cycle with the beam injected at (x, y) mm.
+    fn inject(&mut self, x: f64, y: f64) {
+        self.beam_running = true;
+        self.phase = GamePhase::Injection;
+        self.beam_x = x;
+        self.beam_xp = 0.0;
+        self.beam_y = y;
+        self.beam_yp = 0.0;
+        self.beam_dp = 0.0;
+        self.beam_phi = 0.0;
+        self.beam_de = 0.0;
+        self.beam_cell = 0;
+        self.beam_element = 0;
+        self.beam_progress = 0.0;
+        self.beam_losses = 0.0;
+        self.ramp_turn = 0;
+        self.transition_crossed = false;
+        self.transition_warned = false;
+        self.loss_alarmed = false;
+        self.current_ke_gev = E_INJECTION_GEV;
+        self.current_gamma = kinetic_to_gamma(E_INJECTION_GEV);
+        self.current_beta = gamma_to_beta(self.current_gamma);
+        self.current_brho = gamma_to_brho(self.current_gamma);
+        self.beam_intensity = 1.0;
+        // The ground starts settled each cycle; the drift is the cycle's own
+        self.orbit_drift = [(0.0, 0.0); NUM_SECTIONS];
+        self.drift_rate = [(0.0, 0.0); NUM_SECTIONS];
+        self.bpm_sum = [(0.0, 0.0); NUM_SECTIONS];
+        self.feedback_slew = [(0.0, 0.0); NUM_SECTIONS];
+        (self.bpm_turns, self.orbit_rms, self.feedback_credit) = (0, 0.0, 0.0);
+        self.update_optics();
+        self.trail.clear();
+        self.pos_history.clear();
+        self.size_history.clear();
+        self.y_pos_history.clear();
+        self.y_size_history.clear();
+        self.phi_history.clear();
+        self.de_history.clear();
+        self.x_xp_history.clear();
+        self.y_yp_history.clear();
+        self.reset_emittance();
+        // A mismatched beam filaments up to the matched ellipse that encloses it
+        self.inject_mismatch = self.mismatch();
+        self.beam_sigma_x *= self.inject_mismatch.0.sqrt();
+        self.beam_sigma_y *= self.inject_mismatch.1.sqrt();
+        self.cycle_trace = vec![(0, 1.0, 0.0)];
+        self.fill_buckets();
+        audio::emit(AudioEvent::Injection);
     }
 
-    fn copy_correctors_to_all(&mut self) {
-        let src = self.correctors[self.selected_cell].clone();
-        for i in 0..NUM_SECTIONS {
-            if i != self.selected_cell {
-                self.correctors[i] = src.clone();
-            }
+    // ── Injection matching ───────────────────────────────────────────────
+
+    /// The beam's Twiss at the injection point, after any line correction.
+    fn delivered_twiss(&self) -> (Twiss, Twiss) {
+        let (x, y) = self.line_twiss;
+        match self.line_correction {
+            Some([cx, cy]) => (x.transport(cx), y.transport(cy)),
+            None => (x, y),
         }
-        self.message = Some((
-            format!("Copied cell {} correctors to all!", self.selected_cell + 1),
-            45, Color::Rgb(80, 255, 180),
-        ));
     }
 
-    fn energy_fraction(&self) -> f64 {
-        (self.current_ke_gev - E_INJECTION_GEV) / (E_EXTRACTION_GEV - E_INJECTION_GEV)
+    /// Mismatch factor of each plane against the ring at the current MQAT.
+    fn mismatch(&self) -> (f64, f64) {
+        let (mx, my) = matched_twiss(self.quad_bus_trim);
+        let (dx, dy) = self.delivered_twiss();
+        (dx.mismatch(&mx), dy.mismatch(&my))
     }
 
-    fn transition_fraction(&self) -> f64 {
-        let gamma_inj = kinetic_to_gamma(E_INJECTION_GEV);
-        (self.current_gamma - gamma_inj) / (GAMMA_TRANSITION - gamma_inj)
+    /// Sweep MQAT across its range, recording the first-turn beam size at
+    /// the profile monitor in cell 1's long straight, then fit the
+    /// injected Twiss. The bus is left where it was.
+    fn run_quad_scan(&mut self) {
+        let (dx, dy) = self.delivered_twiss();
+        self.quad_scan = (0..SCAN_STEPS)
+            .map(|i| {
+                let mqat = -SCAN_RANGE + 2.0 * SCAN_RANGE * i as f64 / (SCAN_STEPS - 1) as f64;
+                let (mx, my) = monitor_transfer(mqat);
+                let mut size = |t: Twiss, m: Matrix2, emit: f64| {
+                    let beta = t.transport([[m.m11, m.m12], [m.m21, m.m22]]).beta;
+                    (emit * beta).sqrt() * (1.0 + self.rng.gen_range(-SCAN_NOISE..SCAN_NOISE))
+                };
+                ScanPoint {
+                    mqat,
+                    sigma_x: size(dx, mx, self.initial_emittance_x),
+                    sigma_y: size(dy, my, self.initial_emittance_y),
+                    mx: (mx.m11, mx.m12),
+                    my: (my.m11, my.m12),
+                }
+            })
+            .collect();
+        self.scan_fit = booster_match::fit(&self.quad_scan);
     }
-}
 
-// ── Game Trait Implementation ────────────────────────────────────────────────
-impl Game for BoosterGame {
-    fn update(&mut self) {
-        // Tick message timer
-        if let Some((_, ref mut ticks, _)) = self.message {
-            if *ticks > 0 { *ticks -= 1; } else { self.message = None; }
+    /// Setup points for how well the injection was matched.
+    fn match_bonus(&self) -> u32 {
+        if self.phase == GamePhase::Setup { return 0; }
+        let worst = self.inject_mismatch.0.max(self.inject_mismatch.1);
+        (MATCH_BONUS * (2.0 - worst).clamp(0.0, 1.0)) as u32
+    }
+
+    // ── Console ──────────────────────────────────────────────────────────
+
+    fn corrector_field(&mut self, cell: usize, corr_type: CorrectorSelect) -> &mut f64 {
+        let corr = &mut self.correctors[cell];
+        match corr_type {
+            CorrectorSelect::HTrim => &mut corr.h_trim,
+            CorrectorSelect::VTrim => &mut corr.v_trim,
+            CorrectorSelect::TrimQuad => &mut corr.trim_quad,
+            CorrectorSelect::SkewQuad => &mut corr.skew_quad,
+            CorrectorSelect::SextA => &mut corr.sext_a,
+            CorrectorSelect::SextB => &mut corr.sext_b,
         }
-        if self.paused || self.beam_lost || self.phase == GamePhase::Extraction { return; }
-        self.tick += 1;
-        if self.beam_running {
-            for _ in 0..self.sim_speed.steps_per_tick() {
-                if self.beam_lost || self.phase == GamePhase::Extraction { break; }
-                self.advance_beam();
-            }
-            // Record display history
-            if self.tick % 3 == 0 {
-                let dx = (self.beam_x * 0.5) as f32;
-                let dy = (self.beam_y * 0.5) as f32;
-                let sx = (self.beam_sigma_x * 0.5) as f32;
-                let sy = (self.beam_sigma_y * 0.5) as f32;
-                self.pos_history.push(dx);
-                self.size_history.push(sx);
-                self.y_pos_history.push(dy);
-                self.y_size_history.push(sy);
-                if self.pos_history.len() > MAX_HISTORY { self.pos_history.remove(0); }
-                if self.size_history.len() > MAX_HISTORY { self.size_history.remove(0); }
-                if self.y_pos_history.len() > MAX_HISTORY { self.y_pos_history.remove(0); }
-                if self.y_size_history.len() > MAX_HISTORY { self.y_size_history.remove(0); }
-            }
+    }
+
+    fn param(&self, param: Param) -> f64 {
+        match param {
+            Param::Rf => self.rf_voltage_mv,
+            Param::RfPhase => self.rf_phase_deg,
+            Param::Rf2 => self.rf2_ratio,
+            Param::Rf2Phase => self.rf2_phase_deg,
+            Param::Mqat => self.quad_bus_trim,
+            Param::Mdat => self.bend_bus_trim,
+            Param::SextA => self.correctors.iter().map(|c| c.sext_a).sum::<f64>() / NUM_SECTIONS as f64,
+            Param::SextB => self.correctors.iter().map(|c| c.sext_b).sum::<f64>() / NUM_SECTIONS as f64,
         }
     }
 
-    fn handle_input(&mut self, key: KeyEvent) {
+    /// Set a ring parameter within the same limits as the keys; returns
+    /// the value actually applied.
+    fn set_param(&mut self, param: Param, value: f64) -> f64 {
+        let slot = match param {
+            Param::Rf => { self.rf_voltage_mv = value.clamp(0.0, MAX_RF_VOLTAGE_MV); &self.rf_voltage_mv }
+            Param::RfPhase => { self.rf_phase_deg = value.rem_euclid(360.0); &self.rf_phase_deg }
+            Param::Rf2 => { self.rf2_ratio = value.clamp(0.0, MAX_RF2_RATIO); &self.rf2_ratio }
+            Param::Rf2Phase => { self.rf2_phase_deg = value.rem_euclid(360.0); &self.rf2_phase_deg }
+            Param::Mqat => { self.quad_bus_trim = value.clamp(-0.2, 0.2); &self.quad_bus_trim }
+            Param::Mdat => { self.bend_bus_trim = value.clamp(-0.1, 0.1); &self.bend_bus_trim }
+            // A sextupole family is one bus: every cell takes the same setting
+            Param::SextA => {
+                self.correctors.iter_mut().for_each(|c| { c.sext_a = value; c.limit() });
+                &self.correctors[0].sext_a
+            }
+            Param::SextB => {
+                self.correctors.iter_mut().for_each(|c| { c.sext_b = value; c.limit() });
+                &self.correctors[0].sext_b
+            }
+        };
+        *slot
+    }
+
+    /// Step console ramps to the current turn; finished ramps are dropped.
+    fn apply_ramps(&mut self) {
+        let turn = self.ramp_turn;
+        let mut ramps = std::mem::take(&mut self.ramps);
+        for r in &ramps {
+            if turn < r.start_turn { continue; }
+            let span = (r.end_turn - r.start_turn).max(1) as f64;
+            let t = ((turn - r.start_turn) as f64 / span).min(1.0);
+            self.set_param(r.param, r.from + (r.to - r.from) * t);
+        }
+        ramps.retain(|r| turn < r.end_turn);
+        self.ramps = ramps;
+    }
+
+    fn measurement(&self, m: Measurement) -> String {
+        match m {
+            Measurement::Tune => format!(
+                "tune   Qx={:.4} Qy={:.4} ΔQsc={:.4} Bf={:.2}",
+                self.tune_x, self.tune_y, self.sc_tune_shift, self.bunching_factor(),
+            ),
+            Measurement::Orbit => format!(
+                "orbit  x={:+.2} mm y={:+.2} mm σx={:.2} σy={:.2} closed orbit {:.1} µm rms",
+                self.beam_x, self.beam_y, self.beam_sigma_x, self.beam_sigma_y, self.orbit_rms * 1000.0,
+            ),
+            Measurement::Chrom => format!(
+                "chrom  ξx={:+.2} ξy={:+.2} eddy={:+.2}",
+                self.chromaticity_x, self.chromaticity_y, self.eddy_chromaticity(),
+            ),
+            Measurement::Emittance => {
+                let (gx, gy) = self.emittance_growth();
+                format!("emit   εx/ε0={:.3} εy/ε0={:.3}", gx, gy)
+            }
+            Measurement::Intensity => format!(
+                "beam   I={:.1}% losses={:.1}/{:.0}",
+                self.beam_intensity * 100.0, self.beam_losses, MAX_LOSSES,
+            ),
+            Measurement::Energy => format!(
+                "energy KE={:.3} GeV γ={:.3} turn {}/{}",
+                self.current_ke_gev, self.current_gamma, self.ramp_turn, TURNS_IN_CYCLE,
+            ),
+            Measurement::Mismatch if self.phase == GamePhase::Setup => match self.scan_fit {
+                Some(fit) => {
+                    let (mx, my) = matched_twiss(self.quad_bus_trim);
+                    format!("match  Bx={:.3} By={:.3} from the last scan", fit.x.mismatch(&mx), fit.y.mismatch(&my))
+                }
+                None => "match  no scan yet (scan before injecting)".to_string(),
+            },
+            Measurement::Mismatch => format!(
+                "match  Bx={:.3} By={:.3} at injection, +{} setup",
+                self.inject_mismatch.0, self.inject_mismatch.1, self.match_bonus(),
+            ),
+        }
+    }
+
+    fn run_command(&mut self, line: &str) {
+        let command = match booster_console::parse(line, NUM_SECTIONS) {
+            Ok(c) => c,
+            Err(e) if e.is_empty() => return,
+            Err(e) => { self.console.print(format!("error: {}", e)); return; }
+        };
+        match command {
+            Command::SetCorrector { cells, field, value } => {
+                let ct = CorrectorSelect::ALL[field];
+                let targets: Vec<usize> = match cells {
+                    Cells::One(c) => vec![c],
+                    Cells::All => (0..NUM_SECTIONS).collect(),
+                };
+                for &cell in &targets {
+                    *self.corrector_field(cell, ct) = value;
+                    self.correctors[cell].limit();
+                }
+                let where_ = match cells { Cells::One(c) => format!("c{}", c + 1), Cells::All => "all cells".to_string() };
+                self.console.print(format!("{} {} = {:+.5}", where_, CORRECTOR_FIELDS[field], value));
+            }
+            Command::SetParam { param, value } => {
+                let applied = self.set_param(param, value);
+                self.console.print(format!("{} = {:.4}", param.name(), applied));
+            }
+            Command::GetCell(cell) => {
+                let values: Vec<String> = CorrectorSelect::ALL
+                    .iter()
+                    .zip(CORRECTOR_FIELDS)
+                    .map(|(&ct, name)| format!("{}={:+.4}", name, *self.corrector_field(cell, ct)))
+                    .collect();
+                self.console.print(format!("c{} {}", cell + 1, values.join(" ")));
+            }
+            Command::GetParam(param) => {
+                self.console.print(format!("{} = {:.4}", param.name(), self.param(param)));
+            }
+            Command::Ramp { param, from, to, turn } => {
+                if turn <= self.ramp_turn || turn > TURNS_IN_CYCLE {
+                    self.console.print(format!("error: @turn must be after turn {} and at most {}", self.ramp_turn, TURNS_IN_CYCLE));
+                    return;
+                }
+                self.ramps.retain(|r| r.param != param);
+                self.ramps.push(ParamRamp { param, from, to, start_turn: self.ramp_turn, end_turn: turn });
+                self.set_param(param, from);
+                self.console.print(format!("ramp {} {:.4} → {:.4} over turns {}..{}", param.name(), from, to, self.ramp_turn, turn));
+            }
+            Command::ListRamps => {
+                if self.ramps.is_empty() {
+                    self.console.print("no ramps");
+                }
+                let lines: Vec<String> = self.ramps.iter()
+                    .map(|r| format!("{} {:.4} → {:.4} turns {}..{}", r.param.name(), r.from, r.to, r.start_turn, r.end_turn))
+                    .collect();
+                for l in lines {
+                    self.console.print(l);
+                }
+            }
+            Command::ClearRamps => {
+                self.ramps.clear();
+                self.console.print("ramps cleared");
+            }
+            Command::Measure(list) => {
+                if list.contains(&Measurement::Tune) {
+                    self.earn(Badge::TuneMeasured);
+                }
+                for m in list {
+                    let text = self.measurement(m);
+                    self.console.print(text);
+                }
+            }
+            Command::QuadScan => {
+                if self.beam_running {
+                    self.console.print("error: scan before injecting (R resets)");
+                    return;
+                }
+                self.run_quad_scan();
+                self.console.print("scan   MQAT     σx mm   σy mm");
+                let rows: Vec<String> = self.quad_scan.iter()
+                    .map(|p| format!("       {:+.3}  {:6.2}  {:6.2}", p.mqat, p.sigma_x, p.sigma_y))
+                    .collect();
+                rows.into_iter().for_each(|r| self.console.print(r));
+                match self.scan_fit {
+                    Some(fit) => {
+                        let (mx, my) = matched_twiss(self.quad_bus_trim);
+                        self.console.print(format!(
+                            "fit    βx={:.1} αx={:+.2} εx={:.3} | βy={:.1} αy={:+.2} εy={:.3}",
+                            fit.x.beta, fit.x.alpha, fit.emit_x, fit.y.beta, fit.y.alpha, fit.emit_y,
+                        ));
+                        self.console.print(format!(
+                            "       mismatch Bx={:.3} By={:.3} (match corrects the line)",
+                            fit.x.mismatch(&mx), fit.y.mismatch(&my),
+                        ));
+                    }
+                    None => self.console.print("fit    failed; scan again"),
+                }
+            }
+            Command::Match => {
+                let Some(fit) = self.scan_fit.filter(|_| !self.beam_running) else {
+                    self.console.print("error: needs a fresh scan before injecting");
+                    return;
+                };
+                let (mx, my) = matched_twiss(self.quad_bus_trim);
+                let fix = [fit.x.correction_to(&mx), fit.y.correction_to(&my)];
+                self.line_correction = Some(match self.line_correction {
+                    Some([cx, cy]) => [booster_match::chain(cx, fix[0]), booster_match::chain(cy, fix[1])],
+                    None => fix,
+                });
+                self.scan_fit = None;
+                self.quad_scan.clear();
+                self.console.print("line rematched to the fitted Twiss; scan again to check");
+            }
+            Command::Zero(cells) => {
+                match cells {
+                    Cells::One(c) => self.correctors[c] = CorrectorPackage::new(),
+                    Cells::All => self.correctors.iter_mut().for_each(|c| *c = CorrectorPackage::new()),
+                }
+                self.console.print("correctors zeroed");
+            }
+            Command::Inject(x, y) => {
+                if self.beam_running {
+                    self.console.print("error: beam already circulating (R resets)");
+                } else {
+                    self.inject(x, y);
+                    self.console.print(format!("injected at x={:.1} y={:.1} mm", x, y));
+                }
+            }
+            Command::Feedback(None) => {
+                self.feedback = None;
+                self.feedback_slew = [(0.0, 0.0); NUM_SECTIONS];
+                self.console.print("feedback off; the trims stay where it left them");
+            }
+            Command::Feedback(Some((gain, turns))) => {
+                let fb = OrbitFeedback { gain: gain.clamp(0.0, MAX_FEEDBACK_GAIN), interval: turns.clamp(1, MAX_FEEDBACK_TURNS) };
+                self.feedback = Some(fb);
+                let text = self.feedback_label();
+                self.console.print(text);
+            }
+            Command::ShowFeedback => {
+                let text = self.feedback_label();
+                self.console.print(text);
+            }
+            Command::Help => booster_console::HELP.iter().for_each(|l| self.console.print(*l)),
+            Command::Clear => self.console.log.clear(),
+        }
+    }
+
+    /// Run a console command and return what it printed.
+    pub fn execute(&mut self, line: &str) -> Vec<String> {
+        let log = std::mem::take(&mut self.console.log);
+        self.run_command(line);
+        let output = std::mem::replace(&mut self.console.log, log);
+        for l in &output {
+            self.console.print(l.clone());
+        }
+        output
+    }
+
+    fn render_console(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let height = area.height.min(12);
+        let area = Rect::new(area.x, area.bottom() - height, area.width, height);
+        frame.render_widget(Clear, area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.paint(Color::Rgb(255, 200, 80), Role::Orange)))
+            .title(" Console ")
+            .title_style(Style::default().fg(theme.paint(Color::Rgb(255, 220, 120), Role::Yellow)).add_modifier(Modifier::BOLD))
+            .style(Style::default().bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let rows = inner.height.saturating_sub(1) as usize;
+        let mut lines: Vec<Line> = self.console.log[self.console.log.len().saturating_sub(rows)..]
+            .iter()
+            .map(|l| {
+                let color = if l.starts_with("error") {
+                    theme.ink(fx::alert_color())
+                } else if l.starts_with(':') {
+                    theme.paint(Color::Rgb(140, 140, 160), Role::Dim)
+                } else {
+                    theme.paint(Color::Rgb(200, 220, 200), Role::Text)
+                };
+                Line::from(Span::styled(l.clone(), Style::default().fg(color)))
+            })
+            .collect();
+        while lines.len() < rows {
+            lines.insert(0, Line::from(""));
+        }
+        lines.push(Line::from(vec![
+            Span::styled(":", Style::default().fg(theme.paint(Color::Rgb(255, 220, 80), Role::Yellow)).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{}_", self.console.input), Style::default().fg(theme.paint(Color::White, Role::Bright)).add_modifier(Modifier::BOLD)),
+        ]));
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+
+    fn adjust_corrector(&mut self, cell: usize, corr_type: CorrectorSelect, delta: f64) {
+        let corr = &mut self.correctors[cell];
+        match corr_type {
+            CorrectorSelect::HTrim => corr.h_trim += delta,
+            CorrectorSelect::VTrim => corr.v_trim += delta,
+            CorrectorSelect::TrimQuad => corr.trim_quad += delta,
+            CorrectorSelect::SkewQuad => corr.skew_quad += delta,
+            CorrectorSelect::SextA => corr.sext_a += delta,
+            CorrectorSelect::SextB => corr.sext_b += delta,
+        }
+        corr.limit();
+    }
+
+    /// Warm each cell's corrector coils toward the temperature their current
+    /// holds them at. Past TRIP_C the interlock drops the cell's supplies
+    /// until it has cooled to RESET_C; the settings stay dialled in.
+    fn heat_magnets(&mut self) {
+        for cell in 0..NUM_SECTIONS {
+            self.correctors[cell].limit();
+            let power = if self.tripped[cell] { 0.0 } else { self.correctors[cell].power() };
+            let temp = &mut self.magnet_temp[cell];
+            *temp += (AMBIENT_C + HEAT_RISE_C * power - *temp) / THERMAL_TICKS;
+            if !self.tripped[cell] && *temp >= TRIP_C {
+                self.tripped[cell] = true;
+                self.message = Some((
+                    format!("Cell {} correctors tripped on overtemperature!", cell + 1),
+                    90, Ink(Color::Rgb(255, 100, 60), Role::Red),
+                ));
+            } else if self.tripped[cell] && *temp <= RESET_C {
+                self.tripped[cell] = false;
+                self.message = Some((
+                    format!("Cell {} correctors cooled and back on", cell + 1),
+                    60, Ink(Color::Rgb(80, 255, 180), Role::Cyan),
+                ));
+            }
+        }
+    }
+
+    fn copy_correctors_to_all(&mut self) {
+        let src = self.correctors[self.selected_cell].clone();
+        for i in 0..NUM_SECTIONS {
+            if i != self.selected_cell {
+                self.correctors[i] = src.clone();
+            }
+        }
+        self.message = Some((
+            format!("Copied cell {} correctors to all!", self.selected_cell + 1),
+            45, Ink(Color::Rgb(80, 255, 180), Role::Cyan),
+        ));
+    }
+
+    fn energy_fraction(&self) -> f64 {
+        (self.current_ke_gev - E_INJECTION_GEV) / (E_EXTRACTION_GEV - E_INJECTION_GEV)
+    }
+
+    fn transition_fraction(&self) -> f64 {
+        let gamma_inj = kinetic_to_gamma(E_INJECTION_GEV);
+        (self.current_gamma - gamma_inj) / (GAMMA_TRANSITION - gamma_inj)
+    }
+
+    // ── Mouse Hit-Testing ────────────────────────────────────────────────
+    /// Ring section whose marker (or label) is nearest the clicked cell.
+    fn ring_section_at(&self, col: u16, row: u16) -> Option<usize> {
+        let area = self.ring_area;
+        if !area.contains(Position::new(col, row)) { return None; }
+        let (cx, cy, rx, ry) = ring_geometry(area.width as usize, area.height as usize)?;
+        let px = (col - area.x) as f32;
+        let py = (row - area.y) as f32;
+        (0..NUM_SECTIONS)
+            .map(|sec| {
+                let (x, y) = ring_marker(sec, cx, cy, rx, ry);
+                // Terminal cells are about twice as tall as wide
+                let d = (px - x).powi(2) + ((py - y) * 2.0).powi(2);
+                (sec, d)
+            })
+            .filter(|(_, d)| *d <= 9.0)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(sec, _)| sec)
+    }
+
+    /// Corrector row under the cursor in the (non-bump) corrector panel.
+    fn corrector_row_at(&self, col: u16, row: u16) -> Option<CorrectorSelect> {
+        let area = self.corrector_area;
+        if self.bump.is_some() || !area.contains(Position::new(col, row)) { return None; }
+        // Border, cell header and the temperature line sit above the corrector rows
+        let idx = row.checked_sub(area.y + 3)? as usize;
+        CorrectorSelect::ALL.get(idx).copied()
+    }
+}
+
+/// Ring center and radii for a `w`×`h` drawing area (None if too small).
+fn ring_geometry(w: usize, h: usize) -> Option<(f32, f32, f32, f32)> {
+    if w < 10 || h < 6 { return None; }
+    let cx = w as f32 / 2.0;
+    let cy = h as f32 / 2.0;
+    let rx = (w as f32 * 0.35).min(cx - 4.0);
+    let ry = (h as f32 * 0.38).min(cy - 2.0);
+    Some((cx, cy, rx, ry))
+}
+
+/// Position of a section marker on the ring (section 0 at the top).
+fn ring_marker(sec: usize, cx: f32, cy: f32, rx: f32, ry: f32) -> (f32, f32) {
+    let angle = (sec as f32 / NUM_SECTIONS as f32) * std::f32::consts::PI * 2.0 - std::f32::consts::FRAC_PI_2;
+    (cx + rx * angle.cos(), cy + ry * angle.sin())
+}
+
+// ── Game Trait Implementation ────────────────────────────────────────────────
+pub const INFO: GameInfo = GameInfo {
+    name: "Booster",
+    icon: "⚛",
+    author: "RustCade",
+    blurb: "Steer particles\naround the ring!",
+    description: "Tune magnets, steer a beam — 5 turns, lowest score wins!",
+    controls: &[
+        ("↑ / ↓", "Select magnet"),
+        ("← / →", "Adjust power"),
+        ("[ / ]", "Prev/next section"),
+        ("+ / -", "Step size"),
+        ("0-9", "Ramp point"),
+        ("B", "Bump mode (3/4/5/off)"),
+        ("C", "Copy section to all"),
+        ("Z", "Zero magnet"),
+        ("D", "Difficulty toggle"),
+        ("W/S  E/Q", "Bump X / Y only"),
+        ("Ctrl+P", "Command palette"),
+        ("A", "Basic / all keys in help bar"),
+    ],
+    tags: &["simulation", "physics", "puzzle"],
+    color: Ink(Color::Rgb(120, 200, 255), Role::Blue),
+    border_color: Ink(Color::Rgb(50, 100, 140), Role::Blue),
+};
+
+impl Game for BoosterGame {
+    fn info(&self) -> &'static GameInfo {
+        &INFO
+    }
+
+    fn update(&mut self) {
+        self.flash.update();
+        // Tick message timer
+        if let Some((_, ref mut ticks, _)) = self.message {
+            if *ticks > 0 { *ticks -= 1; } else { self.message = None; }
+        }
+        if self.paused { return; }
+        self.heat_magnets();
+        if self.beam_lost || self.phase == GamePhase::Extraction { return; }
+        self.tick += 1;
+        if self.beam_running {
+            for _ in 0..self.sim_speed.steps_per_tick() {
+                if self.beam_lost || self.phase == GamePhase::Extraction { break; }
+                self.advance_beam();
+            }
+            // Record display history
+            if self.tick % 3 == 0 {
+                let dx = (self.beam_x * 0.5) as f32;
+                let dy = (self.beam_y * 0.5) as f32;
+                let sx = (self.beam_sigma_x * 0.5) as f32;
+                let sy = (self.beam_sigma_y * 0.5) as f32;
+                self.pos_history.push(dx);
+                self.size_history.push(sx);
+                self.y_pos_history.push(dy);
+                self.y_size_history.push(sy);
+                if self.pos_history.len() > MAX_HISTORY { self.pos_history.remove(0); }
+                if self.size_history.len() > MAX_HISTORY { self.size_history.remove(0); }
+                if self.y_pos_history.len() > MAX_HISTORY { self.y_pos_history.remove(0); }
+                if self.y_size_history.len() > MAX_HISTORY { self.y_size_history.remove(0); }
+            }
+        }
+    }
+
+    fn handle_input(&mut self, key: KeyEvent) {
+        // ── Command palette: filter, then run the pick as its key ──
+        if let Some(palette) = &mut self.palette {
+            match palette.handle_key(key) {
+                PaletteOutcome::Open => {}
+                PaletteOutcome::Close => self.palette = None,
+                PaletteOutcome::Run(c) => {
+                    self.palette = None;
+                    self.handle_input(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+                }
+            }
+            return;
+        }
+
+        // ── Console: intercept all keys for the command line ──
+        if self.console.open {
+            if let Some(line) = self.console.handle_key(key) {
+                self.run_command(&line);
+            }
+            return;
+        }
+
         // ── Input mode: intercept all keys for coordinate entry ──
         if self.input_mode != InputMode::None {
             match key.code {
                 KeyCode::Esc => {
                     self.input_mode = InputMode::None;
                     self.input_buffer.clear();
-                    self.message = Some(("Inject cancelled".to_string(), 30, Color::Rgb(140, 140, 160)));
+                    self.message = Some(("Inject cancelled".to_string(), 30, Ink(Color::Rgb(140, 140, 160), Role::Dim)));
                 }
                 KeyCode::Backspace => {
                     self.input_buffer.pop();
@@ -1272,40 +2690,11 @@ impl Game for BoosterGame {
                                 self.inject_y = val;
                                 self.input_mode = InputMode::None;
                                 self.input_buffer.clear();
-                                // Inject beam at (inject_x, inject_y)
-                                self.beam_running = true;
-                                self.phase = GamePhase::Injection;
-                                self.beam_x = self.inject_x;
-                                self.beam_xp = 0.0;
-                                self.beam_y = self.inject_y;
-                                self.beam_yp = 0.0;
-                                self.beam_dp = 0.0;
-                                self.beam_phi = 0.0;
-                                self.beam_de = 0.0;
-                                self.beam_cell = 0;
-                                self.beam_element = 0;
-                                self.beam_progress = 0.0;
-                                self.beam_losses = 0.0;
-                                self.ramp_turn = 0;
-                                self.transition_crossed = false;
-                                self.current_ke_gev = E_INJECTION_GEV;
-                                self.current_gamma = kinetic_to_gamma(E_INJECTION_GEV);
-                                self.current_beta = gamma_to_beta(self.current_gamma);
-                                self.current_brho = gamma_to_brho(self.current_gamma);
-                                self.beam_intensity = 1.0;
-                                self.update_optics();
-                                self.trail.clear();
-                                self.pos_history.clear();
-                                self.size_history.clear();
-                                self.y_pos_history.clear();
-                                self.y_size_history.clear();
-                                self.phi_history.clear();
-                                self.de_history.clear();
-                                self.x_xp_history.clear();
-                                self.y_yp_history.clear();
+                                let (x, y) = (self.inject_x, self.inject_y);
+                                self.inject(x, y);
                                 self.message = Some((
                                     format!("Injected at x={:.1} y={:.1} mm", self.inject_x, self.inject_y),
-                                    60, Color::Rgb(80, 200, 255),
+                                    60, Ink(Color::Rgb(80, 200, 255), Role::Blue),
                                 ));
                             }
                         }
@@ -1313,7 +2702,7 @@ impl Game for BoosterGame {
                             let label = if self.input_mode == InputMode::InjectX { "X" } else { "Y" };
                             self.message = Some((
                                 format!("Invalid {}: '{}'", label, self.input_buffer),
-                                45, Color::Rgb(255, 80, 80),
+                                45, Ink(Color::Rgb(255, 80, 80), Role::Red),
                             ));
                             self.input_buffer.clear();
                         }
@@ -1327,7 +2716,35 @@ impl Game for BoosterGame {
             return;
         }
 
+        // ── Report overlay: scroll, save or close ──
+        if self.report_open {
+            match key.code {
+                KeyCode::Up | KeyCode::Char('k') => self.report_scroll = self.report_scroll.saturating_sub(1),
+                KeyCode::Down | KeyCode::Char('j') => self.report_scroll = self.report_scroll.saturating_add(1),
+                KeyCode::PageUp => self.report_scroll = self.report_scroll.saturating_sub(10),
+                KeyCode::PageDown => self.report_scroll = self.report_scroll.saturating_add(10),
+                KeyCode::Char('s') | KeyCode::Char('S') => {
+                    let saved = self.report.as_ref().and_then(CycleReport::save);
+                    self.message = Some(match saved {
+                        Some(name) => (format!("Report saved to reports/{}", name), 120, Ink(Color::Rgb(80, 255, 140), Role::Green)),
+                        None => ("Couldn't write the report".to_string(), 120, fx::alert_color()),
+                    });
+                }
+                KeyCode::Esc | KeyCode::Enter | KeyCode::Char('o') | KeyCode::Char('O') => self.report_open = false,
+                _ => {}
+            }
+            return;
+        }
+
         match key.code {
+            KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL) => self.palette = Some(Palette::default()),
+            KeyCode::Char('a') | KeyCode::Char('A') => self.advanced_keys = !self.advanced_keys,
+            KeyCode::Char(':') => {
+                self.console.open = true;
+                if self.console.log.is_empty() {
+                    self.console.print("Booster console — type help for commands, Esc to close");
+                }
+            }
             KeyCode::Char('r') | KeyCode::Char('R') => self.reset(),
             KeyCode::Char('p') | KeyCode::Char('P') => {
                 if !self.beam_lost && self.phase != GamePhase::Extraction {
@@ -1336,8 +2753,13 @@ impl Game for BoosterGame {
             }
             _ => {
                 if self.beam_lost || self.phase == GamePhase::Extraction {
-                    if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
-                        self.reset();
+                    match key.code {
+                        KeyCode::Enter | KeyCode::Char(' ') => self.reset(),
+                        KeyCode::Char('o') | KeyCode::Char('O') if self.report.is_some() => {
+                            self.report_open = true;
+                            self.report_scroll = 0;
+                        }
+                        _ => {}
                     }
                     return;
                 }
@@ -1345,37 +2767,8 @@ impl Game for BoosterGame {
                 match key.code {
                     KeyCode::Char(' ') => {
                         if !self.beam_running {
-                            self.beam_running = true;
-                            self.phase = GamePhase::Injection;
-                            self.beam_x = 0.0;
-                            self.beam_xp = 0.0;
-                            self.beam_y = 0.0;
-                            self.beam_yp = 0.0;
-                            self.beam_dp = 0.0;
-                            self.beam_phi = 0.0;
-                            self.beam_de = 0.0;
-                            self.beam_cell = 0;
-                            self.beam_element = 0;
-                            self.beam_progress = 0.0;
-                            self.beam_losses = 0.0;
-                            self.ramp_turn = 0;
-                            self.transition_crossed = false;
-                            self.current_ke_gev = E_INJECTION_GEV;
-                            self.current_gamma = kinetic_to_gamma(E_INJECTION_GEV);
-                            self.current_beta = gamma_to_beta(self.current_gamma);
-                            self.current_brho = gamma_to_brho(self.current_gamma);
-                            self.beam_intensity = 1.0;
-                            self.update_optics();
-                            self.trail.clear();
-                            self.pos_history.clear();
-                            self.size_history.clear();
-                            self.y_pos_history.clear();
-                            self.y_size_history.clear();
-                            self.phi_history.clear();
-                            self.de_history.clear();
-                            self.x_xp_history.clear();
-                            self.y_yp_history.clear();
-                            self.message = Some(("Beam injected at 400 MeV!".to_string(), 60, Color::Rgb(80, 200, 255)));
+                            self.inject(0.0, 0.0);
+                            self.message = Some(("Beam injected at 400 MeV!".to_string(), 60, Ink(Color::Rgb(80, 200, 255), Role::Blue)));
                         }
                     }
                     // Injection with coordinate input
@@ -1400,30 +2793,14 @@ impl Game for BoosterGame {
                     KeyCode::Up => {
                         if self.bump.is_some() {
                             // Bump mode: adjust trim correctors up
-                            if let Some(ref bump) = self.bump {
-                                let sec_coeffs = bump.section_coefficients();
-                                let speed = self.adjust_speed;
-                                for (sec, coeff) in &sec_coeffs {
-                                    let corr = &mut self.correctors[*sec];
-                                    corr.h_trim += speed * coeff;
-                                    corr.v_trim += speed * coeff;
-                                }
-                            }
+                            self.kick_bump(self.adjust_speed, true, true);
                         } else {
                             self.selected_corrector = self.selected_corrector.prev();
                         }
                     }
                     KeyCode::Down => {
                         if self.bump.is_some() {
-                            if let Some(ref bump) = self.bump {
-                                let sec_coeffs = bump.section_coefficients();
-                                let speed = self.adjust_speed;
-                                for (sec, coeff) in &sec_coeffs {
-                                    let corr = &mut self.correctors[*sec];
-                                    corr.h_trim -= speed * coeff;
-                                    corr.v_trim -= speed * coeff;
-                                }
-                            }
+                            self.kick_bump(-self.adjust_speed, true, true);
                         } else {
                             self.selected_corrector = self.selected_corrector.next();
                         }
@@ -1432,11 +2809,7 @@ impl Game for BoosterGame {
                     KeyCode::Left => {
                         if self.bump.is_some() {
                             if let Some(ref mut bump) = self.bump {
-                                bump.start_section = if bump.start_section == 0 {
-                                    NUM_SECTIONS - 1
-                                } else {
-                                    bump.start_section - 1
-                                };
+                                bump.move_to((bump.start_section + NUM_SECTIONS - 1) % NUM_SECTIONS);
                             }
                         } else {
                             let cell = self.selected_cell;
@@ -1448,7 +2821,7 @@ impl Game for BoosterGame {
                     KeyCode::Right => {
                         if self.bump.is_some() {
                             if let Some(ref mut bump) = self.bump {
-                                bump.start_section = (bump.start_section + 1) % NUM_SECTIONS;
+                                bump.move_to((bump.start_section + 1) % NUM_SECTIONS);
                             }
                         } else {
                             let cell = self.selected_cell;
@@ -1459,55 +2832,52 @@ impl Game for BoosterGame {
                     }
                     // Bump mode W/S: H-trim only
                     KeyCode::Char('w') | KeyCode::Char('W') => {
-                        if let Some(ref bump) = self.bump {
-                            let sec_coeffs = bump.section_coefficients();
-                            let speed = self.adjust_speed;
-                            for (sec, coeff) in &sec_coeffs {
-                                self.correctors[*sec].h_trim += speed * coeff;
-                            }
-                        }
+                        self.kick_bump(self.adjust_speed, true, false);
                     }
                     KeyCode::Char('s') | KeyCode::Char('S') => {
-                        if let Some(ref bump) = self.bump {
-                            let sec_coeffs = bump.section_coefficients();
-                            let speed = self.adjust_speed;
-                            for (sec, coeff) in &sec_coeffs {
-                                self.correctors[*sec].h_trim -= speed * coeff;
-                            }
-                        }
+                        self.kick_bump(-self.adjust_speed, true, false);
                     }
                     // Bump mode E/Q: V-trim only
                     KeyCode::Char('e') | KeyCode::Char('E') => {
-                        if let Some(ref bump) = self.bump {
-                            let sec_coeffs = bump.section_coefficients();
-                            let speed = self.adjust_speed;
-                            for (sec, coeff) in &sec_coeffs {
-                                self.correctors[*sec].v_trim += speed * coeff;
-                            }
-                        }
+                        self.kick_bump(self.adjust_speed, false, true);
                     }
                     KeyCode::Char('q') | KeyCode::Char('Q') => {
-                        if let Some(ref bump) = self.bump {
-                            let sec_coeffs = bump.section_coefficients();
-                            let speed = self.adjust_speed;
-                            for (sec, coeff) in &sec_coeffs {
-                                self.correctors[*sec].v_trim -= speed * coeff;
-                            }
-                        }
+                        self.kick_bump(-self.adjust_speed, false, true);
                     }
                     // RF controls: F/G for voltage, V for phase
                     KeyCode::Char('f') | KeyCode::Char('F') => {
                         self.rf_voltage_mv = (self.rf_voltage_mv + 0.02).min(MAX_RF_VOLTAGE_MV);
                         self.message = Some((
-                            format!("RF V: {:.2} MV", self.rf_voltage_mv), 30, Color::Rgb(255, 200, 80),
+                            format!("RF V: {:.2} MV", self.rf_voltage_mv), 30, Ink(Color::Rgb(255, 200, 80), Role::Orange),
                         ));
                     }
                     KeyCode::Char('g') | KeyCode::Char('G') => {
                         self.rf_voltage_mv = (self.rf_voltage_mv - 0.02).max(0.0);
                         self.message = Some((
-                            format!("RF V: {:.2} MV", self.rf_voltage_mv), 30, Color::Rgb(255, 200, 80),
+                            format!("RF V: {:.2} MV", self.rf_voltage_mv), 30, Ink(Color::Rgb(255, 200, 80), Role::Orange),
                         ));
                     }
+                    // Harmonic cavity: H/L amplitude, Y/U phase, O harmonic multiple
+                    KeyCode::Char('h') | KeyCode::Char('H') => {
+                        self.rf2_ratio = (self.rf2_ratio + 0.02).min(MAX_RF2_RATIO);
+                        self.message = Some((self.rf2_label(), 30, Ink(Color::Rgb(255, 160, 220), Role::Magenta)));
+                    }
+                    KeyCode::Char('l') | KeyCode::Char('L') => {
+                        self.rf2_ratio = (self.rf2_ratio - 0.02).max(0.0);
+                        self.message = Some((self.rf2_label(), 30, Ink(Color::Rgb(255, 160, 220), Role::Magenta)));
+                    }
+                    KeyCode::Char('y') | KeyCode::Char('Y') => {
+                        self.rf2_phase_deg = (self.rf2_phase_deg - 10.0).rem_euclid(360.0);
+                        self.message = Some((self.rf2_label(), 30, Ink(Color::Rgb(255, 160, 220), Role::Magenta)));
+                    }
+                    KeyCode::Char('u') | KeyCode::Char('U') => {
+                        self.rf2_phase_deg = (self.rf2_phase_deg + 10.0).rem_euclid(360.0);
+                        self.message = Some((self.rf2_label(), 30, Ink(Color::Rgb(255, 160, 220), Role::Magenta)));
+                    }
+                    KeyCode::Char('o') | KeyCode::Char('O') => {
+                        self.rf2_multiple = if self.rf2_multiple >= 4 { 2 } else { self.rf2_multiple + 1 };
+                        self.message = Some((self.rf2_label(), 30, Ink(Color::Rgb(255, 160, 220), Role::Magenta)));
+                    }
                     KeyCode::Char('v') | KeyCode::Char('V') => {
                         // Cycle display mode (View)
                         self.display_mode = self.display_mode.next();
@@ -1531,7 +2901,7 @@ impl Game for BoosterGame {
                                 self.correctors[*sec].h_trim = 0.0;
                                 self.correctors[*sec].v_trim = 0.0;
                             }
-                            self.message = Some(("Zeroed bump trims".to_string(), 30, Color::Rgb(255, 200, 80)));
+                            self.message = Some(("Zeroed bump trims".to_string(), 30, Ink(Color::Rgb(255, 200, 80), Role::Orange)));
                         } else {
                             let cell = self.selected_cell;
                             let ct = self.selected_corrector;
@@ -1554,33 +2924,33 @@ impl Game for BoosterGame {
                             self.rf_phase_deg = 180.0 - self.rf_phase_deg.max(90.0);
                         }
                         self.message = Some((
-                            format!("RF phase: {:.0} deg", self.rf_phase_deg), 30, Color::Rgb(200, 180, 255),
+                            format!("RF phase: {:.0} deg", self.rf_phase_deg), 30, Ink(Color::Rgb(200, 180, 255), Role::Violet),
                         ));
                     }
                     // Quad bus trim (MQAT)
                     KeyCode::Char('j') | KeyCode::Char('J') => {
                         self.quad_bus_trim = (self.quad_bus_trim + self.adjust_speed).min(0.2);
                         self.message = Some((
-                            format!("MQAT: {:+.4}", self.quad_bus_trim), 30, Color::Rgb(120, 200, 255),
+                            format!("MQAT: {:+.4}", self.quad_bus_trim), 30, Ink(Color::Rgb(120, 200, 255), Role::Blue),
                         ));
                     }
                     KeyCode::Char('k') | KeyCode::Char('K') => {
                         self.quad_bus_trim = (self.quad_bus_trim - self.adjust_speed).max(-0.2);
                         self.message = Some((
-                            format!("MQAT: {:+.4}", self.quad_bus_trim), 30, Color::Rgb(120, 200, 255),
+                            format!("MQAT: {:+.4}", self.quad_bus_trim), 30, Ink(Color::Rgb(120, 200, 255), Role::Blue),
                         ));
                     }
                     // Main bend bus trim (MDAT)
                     KeyCode::Char('m') | KeyCode::Char('M') => {
                         self.bend_bus_trim = (self.bend_bus_trim + self.adjust_speed).min(0.1);
                         self.message = Some((
-                            format!("MDAT: {:+.4}", self.bend_bus_trim), 30, Color::Rgb(255, 180, 120),
+                            format!("MDAT: {:+.4}", self.bend_bus_trim), 30, Ink(Color::Rgb(255, 180, 120), Role::Orange),
                         ));
                     }
                     KeyCode::Char('n') | KeyCode::Char('N') => {
                         self.bend_bus_trim = (self.bend_bus_trim - self.adjust_speed).max(-0.1);
                         self.message = Some((
-                            format!("MDAT: {:+.4}", self.bend_bus_trim), 30, Color::Rgb(255, 180, 120),
+                            format!("MDAT: {:+.4}", self.bend_bus_trim), 30, Ink(Color::Rgb(255, 180, 120), Role::Orange),
                         ));
                     }
                     // Bump mode toggle
@@ -1590,21 +2960,21 @@ impl Game for BoosterGame {
                             match bump.size {
                                 3 => {
                                     self.bump = Some(BumpConfig::new(4, start));
-                                    self.message = Some((format!("4-Bump mode"), 45, Color::Rgb(80, 255, 200)));
+                                    self.message = Some((format!("4-Bump mode"), 45, Ink(Color::Rgb(80, 255, 200), Role::Cyan)));
                                 }
                                 4 => {
                                     self.bump = Some(BumpConfig::new(5, start));
-                                    self.message = Some((format!("5-Bump mode"), 45, Color::Rgb(80, 255, 200)));
+                                    self.message = Some((format!("5-Bump mode"), 45, Ink(Color::Rgb(80, 255, 200), Role::Cyan)));
                                 }
                                 _ => {
                                     self.bump = None;
-                                    self.message = Some(("Bump mode OFF".to_string(), 30, Color::Rgb(140, 140, 160)));
+                                    self.message = Some(("Bump mode OFF".to_string(), 30, Ink(Color::Rgb(140, 140, 160), Role::Dim)));
                                 }
                             }
                         } else {
                             let start = self.selected_cell;
                             self.bump = Some(BumpConfig::new(3, start));
-                            self.message = Some((format!("3-Bump mode"), 45, Color::Rgb(80, 255, 200)));
+                            self.message = Some((format!("3-Bump mode"), 45, Ink(Color::Rgb(80, 255, 200), Role::Cyan)));
                         }
                     }
                     // Simulation speed
@@ -1617,7 +2987,7 @@ impl Game for BoosterGame {
                         };
                         self.message = Some((
                             format!("Speed: {} ({})", self.sim_speed.label(), desc),
-                            30, Color::Rgb(255, 255, 100),
+                            30, Ink(Color::Rgb(255, 255, 100), Role::Yellow),
                         ));
                     }
                     _ => {}
@@ -1626,13 +2996,50 @@ impl Game for BoosterGame {
         }
     }
 
-    fn render(&mut self, frame: &mut Frame, area: Rect) {
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Color::Rgb(100, 180, 255)))
-            .title(" Fermilab Booster ")
-            .title_style(Style::default().fg(Color::Rgb(120, 200, 255)).add_modifier(Modifier::BOLD));
+    fn handle_mouse(&mut self, event: MouseEvent) {
+        if self.paused || self.input_mode != InputMode::None || self.console.open { return; }
+        let (col, row) = (event.column, event.row);
+        let step = match event.kind {
+            MouseEventKind::ScrollUp => 1.0,
+            MouseEventKind::ScrollDown => -1.0,
+            MouseEventKind::Down(MouseButton::Left) => {
+                // Click a ring cell to edit it (or move the bump there)
+                if let Some(sec) = self.ring_section_at(col, row) {
+                    match self.bump {
+                        Some(ref mut bump) => bump.move_to(sec),
+                        None => self.selected_cell = sec,
+                    }
+                } else if let Some(ct) = self.corrector_row_at(col, row) {
+                    self.selected_corrector = ct;
+                }
+                return;
+            }
+            _ => return,
+        };
+
+        // Wheel over the ring steps through cells
+        if self.bump.is_none() && self.ring_area.contains(Position::new(col, row)) {
+            self.selected_cell = if step > 0.0 {
+                (self.selected_cell + 1) % NUM_SECTIONS
+            } else {
+                (self.selected_cell + NUM_SECTIONS - 1) % NUM_SECTIONS
+            };
+            return;
+        }
+
+        // Wheel over the corrector panel adjusts the value under the cursor
+        if !self.corrector_area.contains(Position::new(col, row)) { return; }
+        let delta = step * self.adjust_speed;
+        if self.bump.is_some() {
+            self.kick_bump(delta, true, true);
+        } else if let Some(ct) = self.corrector_row_at(col, row) {
+            self.selected_corrector = ct;
+            self.adjust_corrector(self.selected_cell, ct, delta);
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = self.title_bar(theme);
 
         let inner = block.inner(area);
         frame.render_widget(block, area);
@@ -1658,10 +3065,10 @@ impl Game for BoosterGame {
 
         // ── Status Bar (2 lines) ─────────────────────────────────────────
         let stability = self.stability_score();
-        let stab_color = if stability > 80.0 { Color::Rgb(80, 255, 80) }
-            else if stability > 50.0 { Color::Yellow }
-            else if stability > 20.0 { Color::Rgb(255, 160, 50) }
-            else { Color::Rgb(255, 60, 60) };
+        let stab_color = if stability > 80.0 { theme.paint(Color::Rgb(80, 255, 80), Role::Green) }
+            else if stability > 50.0 { theme.paint(Color::Yellow, Role::Yellow) }
+            else if stability > 20.0 { theme.paint(Color::Rgb(255, 160, 50), Role::Orange) }
+            else { theme.paint(Color::Rgb(255, 60, 60), Role::Red) };
 
         let energy_bar_w = 20;
         let energy_frac = self.energy_fraction();
@@ -1681,42 +3088,51 @@ impl Game for BoosterGame {
         let status_line1 = Line::from(vec![
             Span::styled(
                 format!("[{}] ", self.phase.label()),
-                Style::default().fg(self.phase.color()).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme.ink(self.phase.color())).add_modifier(Modifier::BOLD),
             ),
             Span::styled(
                 format!("E:{:.2}GeV ", self.current_ke_gev),
-                Style::default().fg(Color::Rgb(255, 200, 80)).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme.paint(Color::Rgb(255, 200, 80), Role::Orange)).add_modifier(Modifier::BOLD),
             ),
             Span::styled(
                 format!("[{}] ", energy_bar),
-                Style::default().fg(if self.phase == GamePhase::Transition { Color::Red } else { Color::Rgb(80, 180, 80) }),
+                Style::default().fg(if self.phase == GamePhase::Transition { theme.paint(Color::Red, Role::Red) } else { theme.paint(Color::Rgb(80, 180, 80), Role::Green) }),
             ),
             Span::styled(
                 format!("Turn:{}/{} ", self.ramp_turn, TURNS_IN_CYCLE),
-                Style::default().fg(Color::Cyan),
+                Style::default().fg(theme.paint(Color::Cyan, Role::Cyan)),
             ),
             Span::styled(
                 format!("I:{:.0}% ", self.beam_intensity * 100.0),
-                Style::default().fg(if self.beam_intensity > 0.8 { Color::Green } else { Color::Red }),
+                Style::default().fg(if self.beam_intensity > 0.8 { theme.paint(Color::Green, Role::Green) } else { theme.paint(Color::Red, Role::Red) }),
             ),
         ]);
+        let mut status_line1 = status_line1;
+        if self.show_ideal && !self.transition_crossed {
+            let hint = if self.phase == GamePhase::Transition && self.rf_phase_deg < 90.0 {
+                "T: flip RF phase now".to_string()
+            } else {
+                format!("flip RF phase (T) at turn ~{}", TURNS_TO_TRANSITION)
+            };
+            status_line1.push_span(Span::styled(hint, Style::default().fg(theme.ink(IDEAL_COLOR))));
+        }
 
         let status_line2_spans = vec![
             Span::styled(
                 format!("Qx:{:.3} Qy:{:.3} ", self.tune_x + self.sc_tune_shift, self.tune_y + self.sc_tune_shift),
-                Style::default().fg(Color::Rgb(120, 200, 255)),
+                Style::default().fg(theme.paint(Color::Rgb(120, 200, 255), Role::Blue)),
             ),
             Span::styled(
                 format!("eta:{:+.4} ", eta),
-                Style::default().fg(if eta.abs() < 0.01 { Color::Red } else { Color::Rgb(140, 140, 160) }),
+                Style::default().fg(if eta.abs() < 0.01 { theme.paint(Color::Red, Role::Red) } else { theme.paint(Color::Rgb(140, 140, 160), Role::Dim) }),
             ),
             Span::styled(
                 format!("Cx:{:.1} Cy:{:.1} ", self.chromaticity_x, self.chromaticity_y),
-                Style::default().fg(Color::Rgb(255, 120, 180)),
+                Style::default().fg(theme.paint(Color::Rgb(255, 120, 180), Role::Magenta)),
             ),
             Span::styled(
                 format!("RF:{:.2}MV/{:.0}deg ", self.rf_voltage_mv, self.rf_phase_deg),
-                Style::default().fg(Color::Rgb(255, 200, 80)),
+                Style::default().fg(theme.paint(Color::Rgb(255, 200, 80), Role::Orange)),
             ),
             Span::styled(
                 format!("Stab:{:.0}% ", stability),
@@ -1724,22 +3140,26 @@ impl Game for BoosterGame {
             ),
             Span::styled(
                 format!("MDAT:{:+.3} ", self.bend_bus_trim),
-                Style::default().fg(Color::Rgb(255, 180, 120)),
+                Style::default().fg(theme.paint(Color::Rgb(255, 180, 120), Role::Orange)),
+            ),
+            Span::styled(
+                if self.show_ideal { format!("(ideal {:+.3}) ", self.ideal_mdat()) } else { String::new() },
+                Style::default().fg(theme.ink(IDEAL_COLOR)),
             ),
             Span::styled(
                 format!("MQAT:{:+.3} ", self.quad_bus_trim),
-                Style::default().fg(Color::Rgb(120, 200, 255)),
+                Style::default().fg(theme.paint(Color::Rgb(120, 200, 255), Role::Blue)),
             ),
             Span::styled(
                 format!("[{}] ", self.display_mode.label()),
-                Style::default().fg(Color::Rgb(200, 200, 100)),
+                Style::default().fg(theme.paint(Color::Rgb(200, 200, 100), Role::Yellow)),
             ),
         ];
         // Append flash message if active
         let mut line2_spans = status_line2_spans;
         if let Some((ref msg, ticks, color)) = self.message {
             if ticks > 0 {
-                line2_spans.push(Span::styled(format!(" {} ", msg), Style::default().fg(color).add_modifier(Modifier::BOLD)));
+                line2_spans.push(Span::styled(format!(" {} ", msg), Style::default().fg(theme.ink(color)).add_modifier(Modifier::BOLD)));
             }
         }
         let status_line2 = Line::from(line2_spans);
@@ -1749,14 +3169,12 @@ impl Game for BoosterGame {
         // ── Beam X Position Bar ──────────────────────────────────────────
         let display_x = (self.beam_x * 0.5) as f32;
         let display_sx = (self.beam_sigma_x * 0.5) as f32;
-        self.render_beam_bar(frame, chunks[1], display_x, display_sx, "X",
-            Color::Rgb(80, 200, 255), Color::Rgb(10, 60, 80));
+        self.render_beam_bar(frame, chunks[1], display_x, display_sx, "X", theme.paint(Color::Rgb(80, 200, 255), Role::Blue), theme.paint(Color::Rgb(10, 60, 80), Role::Panel), theme);
 
         // ── Beam Y Position Bar ──────────────────────────────────────────
         let display_y = (self.beam_y * 0.5) as f32;
         let display_sy = (self.beam_sigma_y * 0.5) as f32;
-        self.render_beam_bar(frame, chunks[2], display_y, display_sy, "Y",
-            Color::Rgb(200, 120, 255), Color::Rgb(60, 10, 80));
+        self.render_beam_bar(frame, chunks[2], display_y, display_sy, "Y", theme.paint(Color::Rgb(200, 120, 255), Role::Violet), theme.paint(Color::Rgb(60, 10, 80), Role::Panel), theme);
 
         // ── Left Panel: orbit plot + corrector control ───────────────────
         let left_chunks = Layout::default()
@@ -1768,56 +3186,206 @@ impl Game for BoosterGame {
             .split(middle[0]);
 
         match self.display_mode {
-            DisplayMode::Orbit => self.render_orbit_plot(frame, left_chunks[0]),
-            DisplayMode::PhaseSpaceX => self.render_phase_space_x(frame, left_chunks[0]),
-            DisplayMode::PhaseSpaceY => self.render_phase_space_y(frame, left_chunks[0]),
-            DisplayMode::Longitudinal => self.render_longitudinal_plot(frame, left_chunks[0]),
-            DisplayMode::TuneDiagram => self.render_tune_diagram(frame, left_chunks[0]),
+            DisplayMode::Orbit => self.render_orbit_plot(frame, left_chunks[0], theme),
+            DisplayMode::PhaseSpaceX => self.render_phase_space_x(frame, left_chunks[0], theme),
+            DisplayMode::PhaseSpaceY => self.render_phase_space_y(frame, left_chunks[0], theme),
+            DisplayMode::Longitudinal => self.render_longitudinal_plot(frame, left_chunks[0], theme),
+            DisplayMode::TuneDiagram => self.render_tune_diagram(frame, left_chunks[0], theme),
+            DisplayMode::ResponseX => self.render_response_matrix(frame, left_chunks[0], false, theme),
+            DisplayMode::ResponseY => self.render_response_matrix(frame, left_chunks[0], true, theme),
+            DisplayMode::Emittance => self.render_emittance_plot(frame, left_chunks[0], theme),
+            DisplayMode::BucketFill => self.render_bucket_fill(frame, left_chunks[0], theme),
+            DisplayMode::Cycle => self.render_cycle_plot(frame, left_chunks[0], theme),
+            DisplayMode::Match => self.render_match_plot(frame, left_chunks[0], theme),
         }
-        self.render_corrector_panel(frame, left_chunks[1]);
+        self.render_corrector_panel(frame, left_chunks[1], theme);
+        self.corrector_area = left_chunks[1];
 
         // ── Ring Visualization ───────────────────────────────────────────
-        self.render_ring(frame, middle[1]);
+        self.render_ring(frame, middle[1], theme);
+        self.ring_area = middle[1];
 
         // ── Help Bar ─────────────────────────────────────────────────────
-        self.render_help_bar(frame, chunks[4]);
+        self.render_help_bar(frame, chunks[4], theme);
+
+        if self.console.open {
+            self.render_console(frame, chunks[3], theme);
+        }
+        if self.report_open {
+            self.render_report(frame, inner, theme);
+        }
+        if let Some(palette) = &self.palette {
+            palette.render(frame, inner, theme);
+        }
+
+        self.flash.draw(frame.buffer_mut(), area, theme);
     }
 
     fn get_score(&self) -> u32 {
-        // Score: intensity survival * turns * emittance preservation
-        let intensity_score = (self.beam_intensity * 1000.0) as u32;
-        let turn_score = self.ramp_turn;
-        let transition_bonus = if self.transition_crossed { 500 } else { 0 };
-        let extraction_bonus = if self.phase == GamePhase::Extraction { 2000 } else { 0 };
-        intensity_score + turn_score + transition_bonus + extraction_bonus
+        self.score_parts().iter().map(|(_, points)| points).sum()
+    }
+
+    fn set_practice(&mut self, on: bool) {
+        self.show_ideal = on;
+    }
+
+    /// A fresh machine from the new stream, transfer line errors included.
+    fn reseed(&mut self, rng: GameRng) {
+        *self = BoosterGame::with_rng(rng);
+    }
+
+    fn wants_text_input(&self) -> bool {
+        self.console.open || self.input_mode != InputMode::None || self.report_open || self.palette.is_some()
     }
 
     fn is_game_over(&self) -> bool {
         self.phase == GamePhase::Extraction
     }
 
+    fn take_badges(&mut self) -> Vec<Badge> {
+        std::mem::take(&mut self.badges)
+    }
+
+    fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    fn script_state(&self) -> Vec<(&'static str, f64)> {
+        vec![
+            ("running", self.beam_running as u8 as f64),
+            ("lost", self.beam_lost as u8 as f64),
+            ("phase", self.phase as u8 as f64),
+            ("turn", self.ramp_turn as f64),
+            ("energy_gev", self.current_ke_gev),
+            ("gamma", self.current_gamma),
+            ("intensity", self.beam_intensity),
+            ("losses", self.beam_losses as f64),
+            ("orbit_x", self.beam_x),
+            ("orbit_y", self.beam_y),
+            ("sigma_x", self.beam_sigma_x),
+            ("sigma_y", self.beam_sigma_y),
+            ("tune_x", self.tune_x),
+            ("tune_y", self.tune_y),
+            ("chrom_x", self.chromaticity_x),
+            ("chrom_y", self.chromaticity_y),
+            ("rf", self.rf_voltage_mv),
+            ("rf_phase", self.rf_phase_deg),
+            ("cell", self.selected_cell as f64 + 1.0),
+        ]
+    }
+
+    /// The machine settings; a cycle in flight comes back at setup.
+    fn serialize_state(&self) -> Option<Vec<u8>> {
+        let mut w = StateWriter::new();
+        w.u32(self.best_turns);
+        w.u32(self.selected_cell as u32);
+        w.u32(self.rf2_multiple);
+        for c in &self.correctors {
+            for v in c.values() {
+                w.u64(v.to_bits());
+            }
+        }
+        for v in [self.rf_voltage_mv, self.rf_phase_deg, self.rf2_ratio, self.rf2_phase_deg, self.bend_bus_trim, self.quad_bus_trim] {
+            w.u64(v.to_bits());
+        }
+        // Interval 0 is the feedback off
+        let (gain, interval) = self.feedback.map_or((0.0, 0), |fb| (fb.gain, fb.interval));
+        w.u64(gain.to_bits());
+        w.u32(interval);
+        Some(w.finish())
+    }
+
+    fn restore_state(&mut self, data: &[u8]) -> bool {
+        let mut r = StateReader::new(data);
+        let (Some(best), Some(cell), Some(rf2_multiple)) = (r.u32(), r.u32(), r.u32()) else { return false };
+        let mut correctors = self.correctors.clone();
+        if cell as usize >= correctors.len() { return false; }
+        let mut read = || r.u64().map(f64::from_bits).filter(|v| v.is_finite());
+        for c in correctors.iter_mut() {
+            for v in [&mut c.h_trim, &mut c.v_trim, &mut c.trim_quad, &mut c.skew_quad, &mut c.sext_a, &mut c.sext_b] {
+                let Some(x) = read() else { return false };
+                *v = x;
+            }
+        }
+        let mut rf = [0.0; 6];
+        for v in &mut rf {
+            let Some(x) = read() else { return false };
+            *v = x;
+        }
+        let [rf_voltage, rf_phase, rf2_ratio, rf2_phase, bend_bus_trim, quad_bus_trim] = rf;
+        // Saves from before the feedback end here
+        let feedback = match (r.u64().map(f64::from_bits), r.u32()) {
+            (Some(gain), Some(interval)) if (0.0..=MAX_FEEDBACK_GAIN).contains(&gain) && (1..=MAX_FEEDBACK_TURNS).contains(&interval) => {
+                Some(OrbitFeedback { gain, interval })
+            }
+            _ => None,
+        };
+
+        self.reset();
+        self.best_turns = self.best_turns.max(best);
+        self.selected_cell = cell as usize;
+        self.correctors = correctors;
+        self.rf_voltage_mv = rf_voltage;
+        self.rf_phase_deg = rf_phase;
+        self.rf2_multiple = rf2_multiple;
+        self.rf2_ratio = rf2_ratio;
+        self.rf2_phase_deg = rf2_phase;
+        self.bend_bus_trim = bend_bus_trim;
+        self.quad_bus_trim = quad_bus_trim;
+        self.feedback = feedback;
+        self.paused = true;
+        true
+    }
+
     fn reset(&mut self) {
         let best = self.best_turns;
+        // Restarting before transition gives up the streak
+        let streak = if self.beam_running && !self.transition_crossed { 0 } else { self.transition_streak };
+        let badges = std::mem::take(&mut self.badges);
         let correctors = self.correctors.clone();
+        // The coils don't cool just because the cycle restarted
+        let (magnet_temp, tripped) = (self.magnet_temp, self.tripped);
         let selected_cell = self.selected_cell;
         let selected_corrector = self.selected_corrector;
         let adjust_speed = self.adjust_speed;
         let bump = self.bump.clone();
         let rf_voltage = self.rf_voltage_mv;
         let rf_phase = self.rf_phase_deg;
+        let (rf2_multiple, rf2_ratio, rf2_phase) = (self.rf2_multiple, self.rf2_ratio, self.rf2_phase_deg);
         let display_mode = self.display_mode;
+        let advanced_keys = self.advanced_keys;
         let sim_speed = self.sim_speed;
         let bend_bus_trim = self.bend_bus_trim;
         let quad_bus_trim = self.quad_bus_trim;
-        *self = BoosterGame::new();
+        let console = std::mem::take(&mut self.console);
+        let show_ideal = self.show_ideal;
+        let feedback = self.feedback;
+        let (line_twiss, line_correction) = (self.line_twiss, self.line_correction);
+        let (quad_scan, scan_fit) = (std::mem::take(&mut self.quad_scan), self.scan_fit);
+        *self = BoosterGame::with_rng(self.rng.clone());
+        self.advanced_keys = advanced_keys;
+        self.console = console;
+        self.show_ideal = show_ideal;
+        self.feedback = feedback;
+        self.line_twiss = line_twiss;
+        self.line_correction = line_correction;
+        self.quad_scan = quad_scan;
+        self.scan_fit = scan_fit;
         self.best_turns = best;
+        self.transition_streak = streak;
+        self.badges = badges;
         self.correctors = correctors;
+        self.magnet_temp = magnet_temp;
+        self.tripped = tripped;
         self.selected_cell = selected_cell;
         self.selected_corrector = selected_corrector;
         self.adjust_speed = adjust_speed;
         self.bump = bump;
         self.rf_voltage_mv = rf_voltage;
         self.rf_phase_deg = rf_phase;
+        self.rf2_multiple = rf2_multiple;
+        self.rf2_ratio = rf2_ratio;
+        self.rf2_phase_deg = rf2_phase;
         self.display_mode = display_mode;
         self.sim_speed = sim_speed;
         self.bend_bus_trim = bend_bus_trim;
@@ -1828,26 +3396,26 @@ impl Game for BoosterGame {
 // ── Rendering Helpers ────────────────────────────────────────────────────────
 impl BoosterGame {
     fn render_beam_bar(&self, frame: &mut Frame, area: Rect, pos: f32, size: f32, label: &str,
-                       beam_color: Color, _bg_hint: Color) {
+                       beam_color: Color, _bg_hint: Color, theme: &Theme) {
         let bar_w = area.width as usize;
         let center = bar_w / 2;
         let scale = center as f32 / APERTURE_DISPLAY;
-        let mut bar_chars: Vec<(char, Style)> = vec![(' ', Style::default().bg(Color::Rgb(15, 15, 25))); bar_w];
+        let mut bar_chars: Vec<(char, Style)> = vec![(' ', Style::default().bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background))); bar_w];
 
         // Aperture limits
         let left_ap = center.saturating_sub((APERTURE_DISPLAY * scale) as usize);
         let right_ap = (center + (APERTURE_DISPLAY * scale) as usize).min(bar_w.saturating_sub(1));
-        if left_ap < bar_w { bar_chars[left_ap] = ('|', Style::default().fg(Color::Red).bg(Color::Rgb(15, 15, 25))); }
-        if right_ap < bar_w { bar_chars[right_ap] = ('|', Style::default().fg(Color::Red).bg(Color::Rgb(15, 15, 25))); }
+        if left_ap < bar_w { bar_chars[left_ap] = ('|', Style::default().fg(theme.paint(Color::Red, Role::Red)).bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background))); }
+        if right_ap < bar_w { bar_chars[right_ap] = ('|', Style::default().fg(theme.paint(Color::Red, Role::Red)).bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background))); }
 
         // Loss zone
         let left_lz = center.saturating_sub((LOSS_ZONE * scale) as usize);
         let right_lz = (center + (LOSS_ZONE * scale) as usize).min(bar_w.saturating_sub(1));
         if left_lz < bar_w && bar_chars[left_lz].0 == ' ' {
-            bar_chars[left_lz] = (':', Style::default().fg(Color::Rgb(255, 200, 50)).bg(Color::Rgb(15, 15, 25)));
+            bar_chars[left_lz] = (':', Style::default().fg(theme.paint(Color::Rgb(255, 200, 50), Role::Yellow)).bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
         }
         if right_lz < bar_w && bar_chars[right_lz].0 == ' ' {
-            bar_chars[right_lz] = (':', Style::default().fg(Color::Rgb(255, 200, 50)).bg(Color::Rgb(15, 15, 25)));
+            bar_chars[right_lz] = (':', Style::default().fg(theme.paint(Color::Rgb(255, 200, 50), Role::Yellow)).bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
         }
 
         // Draw beam
@@ -1867,37 +3435,38 @@ impl BoosterGame {
                         }
                         _ => (100, 200, 255),
                     };
-                    bar_chars[x] = (ch, Style::default().fg(Color::Rgb(r, g, b)).bg(Color::Rgb(15, 15, 25)));
+                    bar_chars[x] = (ch, Style::default().fg(theme.shade(Color::Rgb(r, g, b), Role::Cyan, intensity)).bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
                 }
             }
             if beam_center < bar_w {
-                bar_chars[beam_center] = ('#', Style::default().fg(Color::Rgb(255, 255, 255)).bg(Color::Rgb(15, 15, 25)));
+                bar_chars[beam_center] = ('#', Style::default().fg(theme.paint(Color::Rgb(255, 255, 255), Role::Bright)).bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
             }
         }
 
         // Center mark
         if bar_chars[center].0 == ' ' {
-            bar_chars[center] = ('.', Style::default().fg(Color::Rgb(60, 60, 80)).bg(Color::Rgb(15, 15, 25)));
+            bar_chars[center] = ('.', Style::default().fg(theme.paint(Color::Rgb(60, 60, 80), Role::Faint)).bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
         }
 
-        let pos_color = if pos.abs() > 30.0 { Color::Red } else { Color::Green };
+        let pos_color = if pos.abs() > 30.0 { theme.paint(Color::Red, Role::Red) } else { theme.paint(Color::Green, Role::Green) };
         let label_line = Line::from(vec![
-            Span::styled(format!(" Beam {}: ", label), Style::default().fg(Color::Rgb(100, 100, 140))),
+            Span::styled(format!(" Beam {}: ", label), Style::default().fg(theme.paint(Color::Rgb(100, 100, 140), Role::Faint))),
             Span::styled(format!("{:+.1}mm", pos), Style::default().fg(pos_color).add_modifier(Modifier::BOLD)),
-            Span::styled(format!(" sig:{:.1}", size), Style::default().fg(Color::Rgb(140, 140, 160))),
+            Span::styled(format!(" sig:{:.1}", size), Style::default().fg(theme.paint(Color::Rgb(140, 140, 160), Role::Dim))),
         ]);
         let bar_spans: Vec<Span> = bar_chars.iter().map(|(ch, s)| Span::styled(String::from(*ch), *s)).collect();
         let bar_line = Line::from(bar_spans);
         frame.render_widget(Paragraph::new(vec![label_line, bar_line]), area);
     }
 
-    fn render_orbit_plot(&self, frame: &mut Frame, area: Rect) {
+    fn render_orbit_plot(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let bull_block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Color::Rgb(80, 80, 120)))
+            .border_style(Style::default().fg(theme.paint(Color::Rgb(80, 80, 120), Role::Faint)))
             .title(format!(" Orbit ({:.1},{:.1}) ", self.beam_x, self.beam_y))
-            .title_style(Style::default().fg(Color::Rgb(255, 200, 80)));
+            .title_style(Style::default().fg(theme.paint(Color::Rgb(255, 200, 80), Role::Orange)))
+            .title_bottom(self.feedback_status(theme));
         let bull_inner = bull_block.inner(area);
         frame.render_widget(bull_block, area);
 
@@ -1912,21 +3481,21 @@ impl BoosterGame {
         let sy = bcy / plot_range;
 
         let mut bgrid: Vec<Vec<(char, Style)>> =
-            vec![vec![(' ', Style::default().bg(Color::Rgb(10, 10, 18))); bw]; bh];
+            vec![vec![(' ', Style::default().bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background))); bw]; bh];
 
         // Crosshair
         let cx_i = bcx as usize;
         let cy_i = bcy as usize;
         for x in 0..bw {
-            if cy_i < bh { bgrid[cy_i][x] = ('-', Style::default().fg(Color::Rgb(25, 25, 40)).bg(Color::Rgb(10, 10, 18))); }
+            if cy_i < bh { bgrid[cy_i][x] = ('-', Style::default().fg(theme.paint(Color::Rgb(25, 25, 40), Role::Panel)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background))); }
         }
         for y in 0..bh {
-            if cx_i < bw { bgrid[y][cx_i] = ('|', Style::default().fg(Color::Rgb(25, 25, 40)).bg(Color::Rgb(10, 10, 18))); }
+            if cx_i < bw { bgrid[y][cx_i] = ('|', Style::default().fg(theme.paint(Color::Rgb(25, 25, 40), Role::Panel)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background))); }
         }
         if cx_i < bw && cy_i < bh {
-            bgrid[cy_i][cx_i] = ('+', Style::default().fg(Color::Rgb(30, 30, 50)).bg(Color::Rgb(10, 10, 18)));
+            bgrid[cy_i][cx_i] = ('+', Style::default().fg(theme.paint(Color::Rgb(30, 30, 50), Role::Panel)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
         }
-        draw_plot_ticks(&mut bgrid, bw, bh, bcx, bcy, sx, sy, plot_range, plot_range);
+        draw_plot_ticks(&mut bgrid, bw, bh, bcx, bcy, sx, sy, plot_range, plot_range, theme);
 
         // Turn positions
         let n = self.turn_positions.len();
@@ -1937,13 +3506,13 @@ impl BoosterGame {
                 let brightness = (1.0 - (n - 1 - i) as f32 / 12.0).max(0.3);
                 let dist = (px * px + py * py).sqrt();
                 let color = if dist < 2.0 {
-                    Color::Rgb((80.0 * brightness) as u8, (255.0 * brightness) as u8, (80.0 * brightness) as u8)
+                    theme.shade(Color::Rgb((80.0 * brightness) as u8, (255.0 * brightness) as u8, (80.0 * brightness) as u8), Role::Green, brightness)
                 } else if dist < 8.0 {
-                    Color::Rgb((255.0 * brightness) as u8, (255.0 * brightness) as u8, (50.0 * brightness) as u8)
+                    theme.shade(Color::Rgb((255.0 * brightness) as u8, (255.0 * brightness) as u8, (50.0 * brightness) as u8), Role::Yellow, brightness)
                 } else {
-                    Color::Rgb((255.0 * brightness) as u8, (60.0 * brightness) as u8, (60.0 * brightness) as u8)
+                    theme.shade(Color::Rgb((255.0 * brightness) as u8, (60.0 * brightness) as u8, (60.0 * brightness) as u8), Role::Red, brightness)
                 };
-                bgrid[dot_y][dot_x] = ('o', Style::default().fg(color).bg(Color::Rgb(10, 10, 18)));
+                bgrid[dot_y][dot_x] = ('o', Style::default().fg(color).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
             }
         }
 
@@ -1952,7 +3521,7 @@ impl BoosterGame {
             let cur_x = (bcx + (self.beam_x * 0.5) as f32 * sx) as usize;
             let cur_y = (bcy - (self.beam_y * 0.5) as f32 * sy) as usize;
             if cur_x < bw && cur_y < bh {
-                bgrid[cur_y][cur_x] = ('*', Style::default().fg(Color::Rgb(100, 255, 255)).bg(Color::Rgb(10, 10, 18)).add_modifier(Modifier::BOLD));
+                bgrid[cur_y][cur_x] = ('*', Style::default().fg(theme.paint(Color::Rgb(100, 255, 255), Role::Cyan)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)).add_modifier(Modifier::BOLD));
             }
         }
 
@@ -1962,13 +3531,13 @@ impl BoosterGame {
         frame.render_widget(Paragraph::new(lines), bull_inner);
     }
 
-    fn render_phase_space_x(&self, frame: &mut Frame, area: Rect) {
+    fn render_phase_space_x(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Color::Rgb(120, 80, 40)))
+            .border_style(Style::default().fg(theme.paint(Color::Rgb(120, 80, 40), Role::Orange)))
             .title(format!(" X-X' ({:.1},{:.1}) ", self.beam_x, self.beam_xp))
-            .title_style(Style::default().fg(Color::Rgb(255, 180, 120)));
+            .title_style(Style::default().fg(theme.paint(Color::Rgb(255, 180, 120), Role::Orange)));
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
@@ -1984,21 +3553,21 @@ impl BoosterGame {
         let sy = bcy / plot_range_xp;
 
         let mut grid: Vec<Vec<(char, Style)>> =
-            vec![vec![(' ', Style::default().bg(Color::Rgb(10, 10, 18))); bw]; bh];
+            vec![vec![(' ', Style::default().bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background))); bw]; bh];
 
         // Crosshair axes
         let cx_i = bcx as usize;
         let cy_i = bcy as usize;
         for x in 0..bw {
-            if cy_i < bh { grid[cy_i][x] = ('-', Style::default().fg(Color::Rgb(25, 25, 40)).bg(Color::Rgb(10, 10, 18))); }
+            if cy_i < bh { grid[cy_i][x] = ('-', Style::default().fg(theme.paint(Color::Rgb(25, 25, 40), Role::Panel)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background))); }
         }
         for y in 0..bh {
-            if cx_i < bw { grid[y][cx_i] = ('|', Style::default().fg(Color::Rgb(25, 25, 40)).bg(Color::Rgb(10, 10, 18))); }
+            if cx_i < bw { grid[y][cx_i] = ('|', Style::default().fg(theme.paint(Color::Rgb(25, 25, 40), Role::Panel)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background))); }
         }
         if cx_i < bw && cy_i < bh {
-            grid[cy_i][cx_i] = ('+', Style::default().fg(Color::Rgb(30, 30, 50)).bg(Color::Rgb(10, 10, 18)));
+            grid[cy_i][cx_i] = ('+', Style::default().fg(theme.paint(Color::Rgb(30, 30, 50), Role::Panel)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
         }
-        draw_plot_ticks(&mut grid, bw, bh, bcx, bcy, sx, sy, plot_range_x, plot_range_xp);
+        draw_plot_ticks(&mut grid, bw, bh, bcx, bcy, sx, sy, plot_range_x, plot_range_xp, theme);
 
         // Draw approximate Courant-Snyder ellipse boundary
         let bg = self.current_beta * self.current_gamma;
@@ -2015,7 +3584,7 @@ impl BoosterGame {
                 let px = (bcx + ex * sx) as usize;
                 let py = (bcy - exp * sy) as usize;
                 if px < bw && py < bh && grid[py][px].0 == ' ' || (px < bw && py < bh && grid[py][px].0 == '-') || (px < bw && py < bh && grid[py][px].0 == '|') {
-                    grid[py][px] = ('.', Style::default().fg(Color::Rgb(80, 50, 20)).bg(Color::Rgb(10, 10, 18)));
+                    grid[py][px] = ('.', Style::default().fg(theme.paint(Color::Rgb(80, 50, 20), Role::Panel)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
                 }
             }
         }
@@ -2030,7 +3599,7 @@ impl BoosterGame {
                 let r = (255.0 * brightness) as u8;
                 let g = (140.0 * brightness) as u8;
                 let b = (50.0 * brightness) as u8;
-                grid[py][px] = ('o', Style::default().fg(Color::Rgb(r, g, b)).bg(Color::Rgb(10, 10, 18)));
+                grid[py][px] = ('o', Style::default().fg(theme.shade(Color::Rgb(r, g, b), Role::Orange, brightness)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
             }
         }
 
@@ -2039,7 +3608,7 @@ impl BoosterGame {
             let cur_px = (bcx + self.beam_x as f32 * sx) as usize;
             let cur_py = (bcy - self.beam_xp as f32 * sy) as usize;
             if cur_px < bw && cur_py < bh {
-                grid[cur_py][cur_px] = ('*', Style::default().fg(Color::Rgb(255, 220, 100)).bg(Color::Rgb(10, 10, 18)).add_modifier(Modifier::BOLD));
+                grid[cur_py][cur_px] = ('*', Style::default().fg(theme.paint(Color::Rgb(255, 220, 100), Role::Yellow)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)).add_modifier(Modifier::BOLD));
             }
         }
 
@@ -2049,13 +3618,13 @@ impl BoosterGame {
         frame.render_widget(Paragraph::new(lines), inner);
     }
 
-    fn render_phase_space_y(&self, frame: &mut Frame, area: Rect) {
+    fn render_phase_space_y(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Color::Rgb(80, 40, 120)))
+            .border_style(Style::default().fg(theme.paint(Color::Rgb(80, 40, 120), Role::Violet)))
             .title(format!(" Y-Y' ({:.1},{:.1}) ", self.beam_y, self.beam_yp))
-            .title_style(Style::default().fg(Color::Rgb(200, 120, 255)));
+            .title_style(Style::default().fg(theme.paint(Color::Rgb(200, 120, 255), Role::Violet)));
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
@@ -2071,21 +3640,21 @@ impl BoosterGame {
         let sy = bcy / plot_range_yp;
 
         let mut grid: Vec<Vec<(char, Style)>> =
-            vec![vec![(' ', Style::default().bg(Color::Rgb(10, 10, 18))); bw]; bh];
+            vec![vec![(' ', Style::default().bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background))); bw]; bh];
 
         // Crosshair axes
         let cx_i = bcx as usize;
         let cy_i = bcy as usize;
         for x in 0..bw {
-            if cy_i < bh { grid[cy_i][x] = ('-', Style::default().fg(Color::Rgb(25, 25, 40)).bg(Color::Rgb(10, 10, 18))); }
+            if cy_i < bh { grid[cy_i][x] = ('-', Style::default().fg(theme.paint(Color::Rgb(25, 25, 40), Role::Panel)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background))); }
         }
         for y in 0..bh {
-            if cx_i < bw { grid[y][cx_i] = ('|', Style::default().fg(Color::Rgb(25, 25, 40)).bg(Color::Rgb(10, 10, 18))); }
+            if cx_i < bw { grid[y][cx_i] = ('|', Style::default().fg(theme.paint(Color::Rgb(25, 25, 40), Role::Panel)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background))); }
         }
         if cx_i < bw && cy_i < bh {
-            grid[cy_i][cx_i] = ('+', Style::default().fg(Color::Rgb(30, 30, 50)).bg(Color::Rgb(10, 10, 18)));
+            grid[cy_i][cx_i] = ('+', Style::default().fg(theme.paint(Color::Rgb(30, 30, 50), Role::Panel)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
         }
-        draw_plot_ticks(&mut grid, bw, bh, bcx, bcy, sx, sy, plot_range_y, plot_range_yp);
+        draw_plot_ticks(&mut grid, bw, bh, bcx, bcy, sx, sy, plot_range_y, plot_range_yp, theme);
 
         // Draw approximate Courant-Snyder ellipse boundary
         let bg = self.current_beta * self.current_gamma;
@@ -2104,7 +3673,7 @@ impl BoosterGame {
                 if px < bw && py < bh {
                     let ch = grid[py][px].0;
                     if ch == ' ' || ch == '-' || ch == '|' {
-                        grid[py][px] = ('.', Style::default().fg(Color::Rgb(50, 20, 80)).bg(Color::Rgb(10, 10, 18)));
+                        grid[py][px] = ('.', Style::default().fg(theme.paint(Color::Rgb(50, 20, 80), Role::Panel)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
                     }
                 }
             }
@@ -2120,7 +3689,7 @@ impl BoosterGame {
                 let r = (180.0 * brightness) as u8;
                 let g = (80.0 * brightness) as u8;
                 let b = (255.0 * brightness) as u8;
-                grid[py][px] = ('o', Style::default().fg(Color::Rgb(r, g, b)).bg(Color::Rgb(10, 10, 18)));
+                grid[py][px] = ('o', Style::default().fg(theme.shade(Color::Rgb(r, g, b), Role::Violet, brightness)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
             }
         }
 
@@ -2129,7 +3698,7 @@ impl BoosterGame {
             let cur_px = (bcx + self.beam_y as f32 * sx) as usize;
             let cur_py = (bcy - self.beam_yp as f32 * sy) as usize;
             if cur_px < bw && cur_py < bh {
-                grid[cur_py][cur_px] = ('*', Style::default().fg(Color::Rgb(220, 160, 255)).bg(Color::Rgb(10, 10, 18)).add_modifier(Modifier::BOLD));
+                grid[cur_py][cur_px] = ('*', Style::default().fg(theme.paint(Color::Rgb(220, 160, 255), Role::Violet)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)).add_modifier(Modifier::BOLD));
             }
         }
 
@@ -2139,13 +3708,18 @@ impl BoosterGame {
         frame.render_widget(Paragraph::new(lines), inner);
     }
 
-    fn render_longitudinal_plot(&self, frame: &mut Frame, area: Rect) {
+    fn render_longitudinal_plot(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Color::Rgb(80, 120, 40)))
-            .title(format!(" Longit. phi:{:.2} dE:{:.4} ", self.beam_phi, self.beam_de))
-            .title_style(Style::default().fg(Color::Rgb(180, 255, 80)));
+            .border_style(Style::default().fg(theme.paint(Color::Rgb(80, 120, 40), Role::Green)))
+            .title(if self.rf2_ratio > 0.0 {
+                format!(" Longit. phi:{:.2} dE:{:.4} {}h:{:.0}%/{:.0}° ",
+                    self.beam_phi, self.beam_de, self.rf2_multiple, self.rf2_ratio * 100.0, self.rf2_phase_deg)
+            } else {
+                format!(" Longit. phi:{:.2} dE:{:.4} ", self.beam_phi, self.beam_de)
+            })
+            .title_style(Style::default().fg(theme.paint(Color::Rgb(180, 255, 80), Role::Green)));
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
@@ -2172,31 +3746,47 @@ impl BoosterGame {
         let sy = bcy / de_range;
 
         let mut grid: Vec<Vec<(char, Style)>> =
-            vec![vec![(' ', Style::default().bg(Color::Rgb(10, 10, 18))); bw]; bh];
+            vec![vec![(' ', Style::default().bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background))); bw]; bh];
 
         // Crosshair axes
         let cx_i = bcx as usize;
         let cy_i = bcy as usize;
         for x in 0..bw {
-            if cy_i < bh { grid[cy_i][x] = ('-', Style::default().fg(Color::Rgb(25, 25, 40)).bg(Color::Rgb(10, 10, 18))); }
+            if cy_i < bh { grid[cy_i][x] = ('-', Style::default().fg(theme.paint(Color::Rgb(25, 25, 40), Role::Panel)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background))); }
         }
         for y in 0..bh {
-            if cx_i < bw { grid[y][cx_i] = ('|', Style::default().fg(Color::Rgb(25, 25, 40)).bg(Color::Rgb(10, 10, 18))); }
+            if cx_i < bw { grid[y][cx_i] = ('|', Style::default().fg(theme.paint(Color::Rgb(25, 25, 40), Role::Panel)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background))); }
         }
         if cx_i < bw && cy_i < bh {
-            grid[cy_i][cx_i] = ('+', Style::default().fg(Color::Rgb(30, 30, 50)).bg(Color::Rgb(10, 10, 18)));
+            grid[cy_i][cx_i] = ('+', Style::default().fg(theme.paint(Color::Rgb(30, 30, 50), Role::Panel)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
+        }
+        draw_plot_ticks(&mut grid, bw, bh, bcx, bcy, sx, sy, phi_range, de_range, theme);
+
+        // Combined RF waveform (fundamental + harmonic cavity), drawn faintly
+        if self.rf2_ratio > 0.0 {
+            let wave_style = Style::default().fg(theme.paint(Color::Rgb(90, 50, 90), Role::Faint)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background));
+            let amp = bh as f64 / 4.0 / (1.0 + self.rf2_ratio);
+            let wave: Vec<usize> = (0..bw)
+                .map(|px| (bcy as f64 - self.rf_waveform(((px as f32 - bcx) / sx) as f64) * amp) as usize)
+                .collect();
+            for (px, &py) in wave.iter().enumerate() {
+                if py < bh && matches!(grid[py][px].0, ' ' | '-' | '|') {
+                    grid[py][px] = ('~', wave_style);
+                }
+            }
         }
-        draw_plot_ticks(&mut grid, bw, bh, bcx, bcy, sx, sy, phi_range, de_range);
 
         // Draw RF bucket separatrix
-        // dE = sqrt(eV*beta^2*E / (pi*h*|eta|) * (cos(phi) + 1))
+        // dE = sqrt(eV*beta^2*E / (pi*h*|eta|) * (U(pi) - U(phi))), U = RF potential
+        // (with the fundamental alone, U(pi) - U(phi) = cos(phi) + 1)
         if eta.abs() > 1e-6 {
             let coeff = v_per_turn * self.current_beta.powi(2) * total_e_gev
                 / (std::f64::consts::PI * HARMONIC_NUMBER as f64 * eta.abs());
+            let u_edge = self.rf_potential(std::f64::consts::PI).max(self.rf_potential(-std::f64::consts::PI));
             let steps = bw * 2;
             for i in 0..steps {
                 let phi = -std::f64::consts::PI + (i as f64 / steps as f64) * 2.0 * std::f64::consts::PI;
-                let val = coeff * (phi.cos() + 1.0);
+                let val = coeff * (u_edge - self.rf_potential(phi));
                 if val > 0.0 {
                     let de_sep = val.sqrt();
                     // Upper separatrix
@@ -2206,13 +3796,13 @@ impl BoosterGame {
                     if px < bw && py_up < bh {
                         let ch = grid[py_up][px].0;
                         if ch == ' ' || ch == '-' || ch == '|' {
-                            grid[py_up][px] = ('.', Style::default().fg(Color::Rgb(40, 80, 30)).bg(Color::Rgb(10, 10, 18)));
+                            grid[py_up][px] = ('.', Style::default().fg(theme.paint(Color::Rgb(40, 80, 30), Role::Faint)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
                         }
                     }
                     if px < bw && py_dn < bh {
                         let ch = grid[py_dn][px].0;
                         if ch == ' ' || ch == '-' || ch == '|' {
-                            grid[py_dn][px] = ('.', Style::default().fg(Color::Rgb(40, 80, 30)).bg(Color::Rgb(10, 10, 18)));
+                            grid[py_dn][px] = ('.', Style::default().fg(theme.paint(Color::Rgb(40, 80, 30), Role::Faint)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
                         }
                     }
                 }
@@ -2229,7 +3819,7 @@ impl BoosterGame {
                 let r = (120.0 * brightness) as u8;
                 let g = (255.0 * brightness) as u8;
                 let b = (50.0 * brightness) as u8;
-                grid[py][px] = ('o', Style::default().fg(Color::Rgb(r, g, b)).bg(Color::Rgb(10, 10, 18)));
+                grid[py][px] = ('o', Style::default().fg(theme.shade(Color::Rgb(r, g, b), Role::Green, brightness)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
             }
         }
 
@@ -2238,7 +3828,96 @@ impl BoosterGame {
             let cur_px = (bcx + self.beam_phi as f32 * sx) as usize;
             let cur_py = (bcy - self.beam_de as f32 * sy) as usize;
             if cur_px < bw && cur_py < bh {
-                grid[cur_py][cur_px] = ('*', Style::default().fg(Color::Rgb(180, 255, 80)).bg(Color::Rgb(10, 10, 18)).add_modifier(Modifier::BOLD));
+                grid[cur_py][cur_px] = ('*', Style::default().fg(theme.paint(Color::Rgb(180, 255, 80), Role::Green)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)).add_modifier(Modifier::BOLD));
+            }
+        }
+
+        let lines: Vec<Line> = grid.into_iter()
+            .map(|row| Line::from(row.into_iter().map(|(ch, s)| Span::styled(String::from(ch), s)).collect::<Vec<_>>()))
+            .collect();
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+
+    /// Modeled orbit response: closed-orbit shift at each cell's BPM per unit
+    /// kick from each cell's trim dipole, for the current tune.
+    ///   R_ij = β / (2 sin πQ) · cos(|φ_i − φ_j| − πQ)
+    /// BPMs and correctors share the same lattice location, so β is common.
+    fn response_matrix(&self, vertical: bool) -> Vec<Vec<f64>> {
+        let q = if vertical { self.tune_y } else { self.tune_x };
+        let beta = if vertical { self.beta_y_max } else { self.beta_x_max };
+        let mu_cell = std::f64::consts::TAU * q / NUM_SECTIONS as f64;
+        let sin_pq = (std::f64::consts::PI * q).sin();
+        // On an integer resonance the closed orbit is undefined
+        let denom = if sin_pq.abs() < 1e-3 { 1e-3_f64.copysign(sin_pq) } else { sin_pq };
+        (0..NUM_SECTIONS)
+            .map(|bpm| {
+                (0..NUM_SECTIONS)
+                    .map(|corr| {
+                        let dphi = ((bpm + NUM_SECTIONS - corr) % NUM_SECTIONS) as f64 * mu_cell;
+                        beta / (2.0 * denom) * (dphi - std::f64::consts::PI * q).cos()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn render_response_matrix(&self, frame: &mut Frame, area: Rect, vertical: bool, theme: &Theme) {
+        let bg = theme.paint(Color::Rgb(10, 10, 18), Role::Background);
+        let q = if vertical { self.tune_y } else { self.tune_x };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.paint(Color::Rgb(40, 80, 120), Role::Blue)))
+            .title(format!(" ORM {} Q:{:.2} ", if vertical { "V" } else { "H" }, q))
+            .title_style(Style::default().fg(theme.paint(Color::Rgb(100, 200, 255), Role::Blue)));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let bw = inner.width as usize;
+        let bh = inner.height as usize;
+        let label_w = 3;
+        if bw < label_w + NUM_SECTIONS || bh < 3 { return; }
+
+        let matrix = self.response_matrix(vertical);
+        let peak = matrix.iter().flatten().fold(0.0_f64, |m, v| m.max(v.abs())).max(1e-9);
+
+        let mut grid: Vec<Vec<(char, Style)>> = vec![vec![(' ', Style::default().bg(bg)); bw]; bh];
+
+        // Header: mark every 6th corrector column and the selected one
+        let axis_style = Style::default().fg(theme.paint(Color::Rgb(55, 65, 90), Role::Faint)).bg(bg);
+        for corr in 0..NUM_SECTIONS {
+            let x = label_w + corr;
+            grid[0][x] = if corr == self.selected_cell {
+                ('▼', Style::default().fg(theme.paint(Color::Rgb(255, 220, 80), Role::Yellow)).bg(bg).add_modifier(Modifier::BOLD))
+            } else if corr % 6 == 0 {
+                ('|', axis_style)
+            } else {
+                ('·', axis_style)
+            };
+        }
+
+        // Rows are BPM cells, binned when the panel is shorter than the ring
+        let rows = (bh - 1).min(NUM_SECTIONS);
+        let shades = [' ', '░', '▒', '▓', '█'];
+        for row in 0..rows {
+            let first = row * NUM_SECTIONS / rows;
+            let last = ((row + 1) * NUM_SECTIONS / rows).max(first + 1).min(NUM_SECTIONS);
+            let label = format!("{:>2}", first + 1);
+            for (i, c) in label.chars().enumerate() {
+                grid[row + 1][i] = (c, axis_style);
+            }
+            for corr in 0..NUM_SECTIONS {
+                let v = matrix[first..last].iter().map(|r| r[corr]).sum::<f64>() / (last - first) as f64;
+                let norm = (v.abs() / peak).min(1.0);
+                let shade = shades[((norm * 4.0).round() as usize).min(4)];
+                let level = (80.0 + norm * 175.0) as u8;
+                // Warm = orbit moves outward/up, cool = inward/down
+                let fg = if v >= 0.0 {
+                    theme.shade(Color::Rgb(level, level / 2, 40), Role::Orange, norm as f32)
+                } else {
+                    theme.shade(Color::Rgb(40, level / 2, level), Role::Blue, norm as f32)
+                };
+                grid[row + 1][label_w + corr] = (shade, Style::default().fg(fg).bg(bg));
             }
         }
 
@@ -2248,15 +3927,15 @@ impl BoosterGame {
         frame.render_widget(Paragraph::new(lines), inner);
     }
 
-    fn render_tune_diagram(&self, frame: &mut Frame, area: Rect) {
+    fn render_tune_diagram(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let qx = (self.tune_x + self.sc_tune_shift).fract() as f32;
         let qy = (self.tune_y + self.sc_tune_shift).fract() as f32;
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Color::Rgb(40, 80, 120)))
+            .border_style(Style::default().fg(theme.paint(Color::Rgb(40, 80, 120), Role::Blue)))
             .title(format!(" Tune Qx:{:.3} Qy:{:.3} ", qx, qy))
-            .title_style(Style::default().fg(Color::Rgb(100, 200, 255)));
+            .title_style(Style::default().fg(theme.paint(Color::Rgb(100, 200, 255), Role::Blue)));
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
@@ -2270,7 +3949,7 @@ impl BoosterGame {
         let q_range = q_max - q_min;
 
         let mut grid: Vec<Vec<(char, Style)>> =
-            vec![vec![(' ', Style::default().bg(Color::Rgb(10, 10, 18))); bw]; bh];
+            vec![vec![(' ', Style::default().bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background))); bw]; bh];
 
         // Map tune value to pixel coordinate
         let to_px = |q: f32| -> usize {
@@ -2285,12 +3964,12 @@ impl BoosterGame {
         let py_half = to_py(0.5);
         if px_half < bw {
             for y in 0..bh {
-                grid[y][px_half] = ('|', Style::default().fg(Color::Rgb(180, 120, 40)).bg(Color::Rgb(10, 10, 18)));
+                grid[y][px_half] = ('|', Style::default().fg(theme.paint(Color::Rgb(180, 120, 40), Role::Orange)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
             }
         }
         if py_half < bh {
             for x in 0..bw {
-                grid[py_half][x] = ('-', Style::default().fg(Color::Rgb(180, 120, 40)).bg(Color::Rgb(10, 10, 18)));
+                grid[py_half][x] = ('-', Style::default().fg(theme.paint(Color::Rgb(180, 120, 40), Role::Orange)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
             }
         }
 
@@ -2300,14 +3979,14 @@ impl BoosterGame {
         if px_third < bw {
             for y in 0..bh {
                 if grid[y][px_third].0 == ' ' {
-                    grid[y][px_third] = (':', Style::default().fg(Color::Rgb(180, 180, 40)).bg(Color::Rgb(10, 10, 18)));
+                    grid[y][px_third] = (':', Style::default().fg(theme.paint(Color::Rgb(180, 180, 40), Role::Yellow)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
                 }
             }
         }
         if py_third < bh {
             for x in 0..bw {
                 if grid[py_third][x].0 == ' ' {
-                    grid[py_third][x] = ('.', Style::default().fg(Color::Rgb(180, 180, 40)).bg(Color::Rgb(10, 10, 18)));
+                    grid[py_third][x] = ('.', Style::default().fg(theme.paint(Color::Rgb(180, 180, 40), Role::Yellow)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
                 }
             }
         }
@@ -2319,7 +3998,7 @@ impl BoosterGame {
             let py = to_py(q);
             if px < bw && py < bh {
                 if grid[py][px].0 == ' ' {
-                    grid[py][px] = ('/', Style::default().fg(Color::Rgb(40, 180, 180)).bg(Color::Rgb(10, 10, 18)));
+                    grid[py][px] = ('/', Style::default().fg(theme.paint(Color::Rgb(40, 180, 180), Role::Cyan)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
                 }
             }
         }
@@ -2333,7 +4012,7 @@ impl BoosterGame {
                 let py = to_py(qy_val);
                 if px < bw && py < bh {
                     if grid[py][px].0 == ' ' {
-                        grid[py][px] = ('\\', Style::default().fg(Color::Rgb(40, 180, 180)).bg(Color::Rgb(10, 10, 18)));
+                        grid[py][px] = ('\\', Style::default().fg(theme.paint(Color::Rgb(40, 180, 180), Role::Cyan)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
                     }
                 }
             }
@@ -2344,13 +4023,13 @@ impl BoosterGame {
             let wpx = to_px(qx);
             let wpy = to_py(qy);
             if wpx < bw && wpy < bh {
-                grid[wpy][wpx] = ('*', Style::default().fg(Color::Rgb(255, 255, 255)).bg(Color::Rgb(10, 10, 18)).add_modifier(Modifier::BOLD));
+                grid[wpy][wpx] = ('*', Style::default().fg(theme.paint(Color::Rgb(255, 255, 255), Role::Bright)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)).add_modifier(Modifier::BOLD));
             }
         }
 
         // Axis tick labels along bottom (Qx) and left edge (Qy)
-        let tick_label_style = Style::default().fg(Color::Rgb(55, 65, 90)).bg(Color::Rgb(10, 10, 18));
-        let tick_mark_style = Style::default().fg(Color::Rgb(70, 70, 100)).bg(Color::Rgb(10, 10, 18));
+        let tick_label_style = Style::default().fg(theme.paint(Color::Rgb(55, 65, 90), Role::Faint)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background));
+        let tick_mark_style = Style::default().fg(theme.paint(Color::Rgb(70, 70, 100), Role::Faint)).bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background));
         for &q in &[0.5, 0.6, 0.7, 0.8, 0.9, 1.0] {
             // Bottom edge: Qx labels
             let px = to_px(q);
@@ -2388,44 +4067,447 @@ impl BoosterGame {
         frame.render_widget(Paragraph::new(lines), inner);
     }
 
-    fn render_corrector_panel(&self, frame: &mut Frame, area: Rect) {
+    fn render_emittance_plot(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let (gx, gy) = self.emittance_growth();
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.paint(Color::Rgb(40, 80, 120), Role::Blue)))
+            .title(format!(" Emittance εx:{:.2} εy:{:.2} (×ε₀) ", gx, gy))
+            .title_style(Style::default().fg(theme.paint(Color::Rgb(100, 200, 255), Role::Blue)));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let bw = inner.width as usize;
+        let bh = inner.height as usize;
+        if bw < 8 || bh < 3 { return; }
+
+        let bg = theme.paint(Color::Rgb(10, 10, 18), Role::Background);
+        let mut grid: Vec<Vec<(char, Style)>> = vec![vec![(' ', Style::default().bg(bg)); bw]; bh];
+
+        // Y axis from 1.0 (no growth) up to the largest growth seen, at least 2×
+        let g_max = self.emittance_history.iter()
+            .fold(2.0_f32, |m, &(_, x, y)| m.max(x).max(y))
+            .min(20.0);
+        let label_w = 5;
+        let plot_w = bw - label_w;
+        let plot_h = bh - 1; // bottom row holds turn labels
+        let to_px = |turn: u32| -> usize {
+            label_w + ((turn as f32 / TURNS_IN_CYCLE as f32) * (plot_w - 1) as f32).round() as usize
+        };
+        let to_py = |g: f32| -> usize {
+            let frac = ((g - 1.0) / (g_max - 1.0)).clamp(0.0, 1.0);
+            ((1.0 - frac) * (plot_h - 1) as f32).round() as usize
+        };
+
+        // Growth gridlines with labels
+        let tick_label_style = Style::default().fg(theme.paint(Color::Rgb(55, 65, 90), Role::Faint)).bg(bg);
+        let gridline_style = Style::default().fg(theme.paint(Color::Rgb(30, 30, 45), Role::Panel)).bg(bg);
+        for step in 0..=4 {
+            let g = 1.0 + (g_max - 1.0) * step as f32 / 4.0;
+            let py = to_py(g);
+            for cell in grid[py].iter_mut().skip(label_w) {
+                cell.0 = '·';
+                cell.1 = gridline_style;
+            }
+            for (i, c) in format!("{:>4.1}", g).chars().enumerate().take(label_w - 1) {
+                grid[py][i] = (c, tick_label_style);
+            }
+        }
+
+        // Transition crossing marker
+        let px_t = to_px(TURNS_TO_TRANSITION);
+        if px_t < bw {
+            for row in grid.iter_mut().take(plot_h) {
+                row[px_t] = (':', Style::default().fg(theme.paint(Color::Rgb(180, 120, 40), Role::Orange)).bg(bg));
+            }
+        }
+
+        // Turn labels along the bottom
+        for &turn in &[0, 5000, 10000, 15000] {
+            let label = format!("{}k", turn / 1000);
+            let start = to_px(turn).saturating_sub(label.len() / 2).min(bw - label.len());
+            for (i, c) in label.chars().enumerate() {
+                grid[bh - 1][start + i] = (c, tick_label_style);
+            }
+        }
+
+        // Curves: horizontal over vertical where they overlap
+        let x_style = Style::default().fg(theme.paint(Color::Rgb(80, 200, 255), Role::Blue)).bg(bg).add_modifier(Modifier::BOLD);
+        let y_style = Style::default().fg(theme.paint(Color::Rgb(255, 140, 80), Role::Orange)).bg(bg).add_modifier(Modifier::BOLD);
+        for &(turn, _, gy) in &self.emittance_history {
+            let px = to_px(turn);
+            if px < bw { grid[to_py(gy)][px] = ('v', y_style); }
+        }
+        for &(turn, gx, _) in &self.emittance_history {
+            let px = to_px(turn);
+            if px < bw { grid[to_py(gx)][px] = ('h', x_style); }
+        }
+
+        let lines: Vec<Line> = grid.into_iter()
+            .map(|row| Line::from(row.into_iter().map(|(ch, s)| Span::styled(String::from(ch), s)).collect::<Vec<_>>()))
+            .collect();
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+
+    /// Intensity and losses against turn, over the best earlier cycle's ghost.
+    fn render_cycle_plot(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let best = self.best_cycle();
+        let title = match best.and_then(|b| b.at(self.ramp_turn)) {
+            Some((_, gi, gl)) => format!(
+                " Cycle I:{:.1}% ({:+.1} vs best) losses:{:.1} ({:+.1}) ",
+                self.beam_intensity * 100.0, (self.beam_intensity as f32 - gi) * 100.0,
+                self.beam_losses, self.beam_losses - gl,
+            ),
+            None => format!(" Cycle I:{:.1}% losses:{:.1} · no earlier cycle ", self.beam_intensity * 100.0, self.beam_losses),
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.paint(Color::Rgb(40, 80, 120), Role::Blue)))
+            .title(title)
+            .title_style(Style::default().fg(theme.paint(Color::Rgb(100, 200, 255), Role::Blue)));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let bw = inner.width as usize;
+        let bh = inner.height as usize;
+        if bw < 12 || bh < 7 { return; }
+
+        let bg = theme.paint(Color::Rgb(10, 10, 18), Role::Background);
+        let mut grid: Vec<Vec<(char, Style)>> = vec![vec![(' ', Style::default().bg(bg)); bw]; bh];
+        let label_w = 5;
+        let plot_w = bw - label_w;
+        let to_px = |turn: u32| -> usize {
+            label_w + ((turn as f32 / TURNS_IN_CYCLE as f32) * (plot_w - 1) as f32).round() as usize
+        };
+
+        // Ranges cover both traces: intensity down from 100%, losses up from 0
+        let ghost: &[(u32, f32, f32)] = best.map_or(&[], |b| &b.samples);
+        let all = || self.cycle_trace.iter().chain(ghost);
+        let i_min = all().fold(0.9_f32, |m, s| m.min(s.1)).max(0.0);
+        let i_min = (i_min * 10.0).floor() / 10.0;
+        let l_max = all().fold(10.0_f32, |m, s| m.max(s.2)).min(MAX_LOSSES);
+
+        // Top strip intensity, bottom strip losses, turn labels underneath
+        let strip_h = (bh - 2) / 2;
+        let strips = [
+            (0, i_min, 1.0, 1usize, theme.paint(Color::Rgb(80, 220, 120), Role::Green)),
+            (strip_h + 1, 0.0, l_max, 2usize, theme.paint(Color::Rgb(255, 120, 80), Role::Red)),
+        ];
+        let tick_label_style = Style::default().fg(theme.paint(Color::Rgb(55, 65, 90), Role::Faint)).bg(bg);
+        let gridline_style = Style::default().fg(theme.paint(Color::Rgb(30, 30, 45), Role::Panel)).bg(bg);
+        let ghost_style = Style::default().fg(theme.paint(Color::Rgb(90, 90, 130), Role::Faint)).bg(bg);
+        for (top, lo, hi, field, color) in strips {
+            let to_py = |v: f32| -> usize {
+                let frac = ((v - lo) / (hi - lo).max(1e-6)).clamp(0.0, 1.0);
+                top + ((1.0 - frac) * (strip_h - 1) as f32).round() as usize
+            };
+            for (v, py) in [(hi, top), (lo, top + strip_h - 1)] {
+                for cell in grid[py].iter_mut().skip(label_w) {
+                    *cell = ('·', gridline_style);
+                }
+                let label = if field == 1 { format!("{:>3.0}%", v * 100.0) } else { format!("{:>4.0}", v) };
+                for (i, c) in label.chars().enumerate().take(label_w - 1) {
+                    grid[py][i] = (c, tick_label_style);
+                }
+            }
+            let value = |s: &(u32, f32, f32)| if field == 1 { s.1 } else { s.2 };
+            for s in ghost {
+                let px = to_px(s.0);
+                if px < bw { grid[to_py(value(s))][px] = ('∙', ghost_style); }
+            }
+            let style = Style::default().fg(color).bg(bg).add_modifier(Modifier::BOLD);
+            for s in &self.cycle_trace {
+                let px = to_px(s.0);
+                if px < bw { grid[to_py(value(s))][px] = ('●', style); }
+            }
+        }
+
+        // Transition crossing marker
+        let px_t = to_px(TURNS_TO_TRANSITION);
+        if px_t < bw {
+            for row in grid.iter_mut().take(strip_h * 2 + 1) {
+                if row[px_t].0 == ' ' || row[px_t].0 == '·' {
+                    row[px_t] = (':', Style::default().fg(theme.paint(Color::Rgb(180, 120, 40), Role::Orange)).bg(bg));
+                }
+            }
+        }
+
+        let mut legend = vec![("intensity ●", theme.paint(Color::Rgb(80, 220, 120), Role::Green)), ("losses ●", theme.paint(Color::Rgb(255, 120, 80), Role::Red))];
+        if best.is_some() { legend.push(("best earlier cycle ∙", theme.paint(Color::Rgb(90, 90, 130), Role::Faint))); }
+        let mut col = label_w;
+        for (text, color) in legend {
+            for c in text.chars() {
+                if col < bw { grid[strip_h][col] = (c, Style::default().fg(color).bg(bg)); }
+                col += 1;
+            }
+            col += 3;
+        }
+
+        for &turn in &[0, 5000, 10000, 15000] {
+            let label = format!("{}k", turn / 1000);
+            let start = to_px(turn).saturating_sub(label.len() / 2).min(bw - label.len());
+            for (i, c) in label.chars().enumerate() {
+                grid[bh - 1][start + i] = (c, tick_label_style);
+            }
+        }
+
+        let lines: Vec<Line> = grid.into_iter()
+            .map(|row| Line::from(row.into_iter().map(|(ch, s)| Span::styled(String::from(ch), s)).collect::<Vec<_>>()))
+            .collect();
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+
+    /// Quad scan points and fit, and the fitted injected ellipses in the
+    /// ring's normalised phase space, where the matched beam is a circle.
+    fn render_match_plot(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let (mx, my) = matched_twiss(self.quad_bus_trim);
+        let title = match self.scan_fit {
+            Some(fit) => format!(" Match · scan Bx:{:.2} By:{:.2} ", fit.x.mismatch(&mx), fit.y.mismatch(&my)),
+            None if self.phase != GamePhase::Setup => format!(
+                " Match · injected Bx:{:.2} By:{:.2} ", self.inject_mismatch.0, self.inject_mismatch.1,
+            ),
+            None => " Match · no scan (console: scan) ".to_string(),
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.paint(Color::Rgb(40, 80, 120), Role::Blue)))
+            .title(title)
+            .title_style(Style::default().fg(theme.paint(Color::Rgb(100, 200, 255), Role::Blue)));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let bw = inner.width as usize;
+        let bh = inner.height as usize;
+        if bw < 20 || bh < 5 { return; }
+
+        let bg = theme.paint(Color::Rgb(10, 10, 18), Role::Background);
+        let mut grid: Vec<Vec<(char, Style)>> = vec![vec![(' ', Style::default().bg(bg)); bw]; bh];
+        let tick_label_style = Style::default().fg(theme.paint(Color::Rgb(55, 65, 90), Role::Faint)).bg(bg);
+        let x_color = theme.paint(Color::Rgb(80, 200, 255), Role::Blue);
+        let y_color = theme.paint(Color::Rgb(255, 140, 80), Role::Orange);
+        // Scan and ellipses side by side when there's room, else stacked:
+        // (left, top, width, height) of each
+        let (scan, phase) = if bw >= 50 {
+            ((0, 0, bw / 2, bh), (bw / 2, 0, bw - bw / 2, bh))
+        } else {
+            ((0, 0, bw, bh / 2), (0, bh / 2, bw, bh - bh / 2))
+        };
+
+        // σ at the monitor against MQAT, measured and fitted
+        let label_w = 5;
+        let plot_w = scan.2.saturating_sub(label_w + 1).max(2);
+        let plot_h = scan.3 - 1;
+        let fitted = |mqat: f64, vertical: bool| -> Option<f64> {
+            let fit = self.scan_fit?;
+            let (t, emit) = if vertical { (fit.y, fit.emit_y) } else { (fit.x, fit.emit_x) };
+            let (m_x, m_y) = monitor_transfer(mqat);
+            let m = if vertical { m_y } else { m_x };
+            Some((emit * t.transport([[m.m11, m.m12], [m.m21, m.m22]]).beta).sqrt())
+        };
+        let s_max = self.quad_scan.iter()
+            .fold(1.0_f64, |m, p| m.max(p.sigma_x).max(p.sigma_y)) * 1.15;
+        let to_px = |mqat: f64| label_w + (((mqat + SCAN_RANGE) / (2.0 * SCAN_RANGE)) * (plot_w - 1) as f64).round() as usize;
+        let to_py = |s: f64| scan.1 + ((1.0 - (s / s_max).clamp(0.0, 1.0)) * (plot_h - 1) as f64).round() as usize;
+        for step in 0..=2 {
+            let s = s_max * step as f64 / 2.0;
+            for (i, c) in format!("{:>4.1}", s).chars().enumerate().take(label_w - 1) {
+                grid[to_py(s)][i] = (c, tick_label_style);
+            }
+        }
+        for (mqat, text) in [(-SCAN_RANGE, "-0.2"), (0.0, "MQAT"), (SCAN_RANGE, "+0.2")] {
+            let start = to_px(mqat).saturating_sub(text.len() / 2).min(scan.2.saturating_sub(text.len()));
+            for (i, c) in text.chars().enumerate() {
+                grid[scan.1 + scan.3 - 1][start + i] = (c, tick_label_style);
+            }
+        }
+        for col in 0..plot_w {
+            let mqat = -SCAN_RANGE + 2.0 * SCAN_RANGE * col as f64 / (plot_w - 1) as f64;
+            for (vertical, color) in [(false, x_color), (true, y_color)] {
+                if let Some(s) = fitted(mqat, vertical) {
+                    grid[to_py(s)][label_w + col] = ('·', Style::default().fg(color).bg(bg));
+                }
+            }
+        }
+        for p in &self.quad_scan {
+            let px = to_px(p.mqat);
+            grid[to_py(p.sigma_y)][px] = ('y', Style::default().fg(y_color).bg(bg).add_modifier(Modifier::BOLD));
+            grid[to_py(p.sigma_x)][px] = ('x', Style::default().fg(x_color).bg(bg).add_modifier(Modifier::BOLD));
+        }
+
+        // Fitted ellipses (unit emittance) over the matched circle
+        let unit = Twiss { beta: 1.0, alpha: 0.0 };
+        let ellipses: Vec<(Twiss, Color)> = match self.scan_fit {
+            Some(fit) => vec![
+                (fit.x.transport(mx.correction_to(&unit)), x_color),
+                (fit.y.transport(my.correction_to(&unit)), y_color),
+            ],
+            None => Vec::new(),
+        };
+        let range = ellipses.iter().fold(1.0_f64, |r, (t, _)| r.max(t.beta.sqrt()).max(t.gamma().sqrt())) * 1.1;
+        let half_w = phase.2 as f64 / 2.0;
+        let half_h = phase.3 as f64 / 2.0;
+        // Cells are about twice as tall as wide
+        let scale = (half_w / range).min(2.0 * half_h / range);
+        let (ccx, ccy) = (phase.0 as f64 + half_w, phase.1 as f64 + half_h);
+        let mut plot = |t: Twiss, ch: char, style: Style| {
+            for i in 0..96 {
+                let theta = i as f64 / 96.0 * std::f64::consts::TAU;
+                let x = t.beta.sqrt() * theta.cos();
+                let p = -(t.alpha * theta.cos() + theta.sin()) / t.beta.sqrt();
+                let (px, py) = ((ccx + x * scale) as usize, (ccy - p * scale / 2.0) as usize);
+                if px >= phase.0 && px < bw && py >= phase.1 && py < bh {
+                    grid[py][px] = (ch, style);
+                }
+            }
+        };
+        plot(unit, '.', Style::default().fg(theme.paint(Color::Rgb(80, 80, 110), Role::Faint)).bg(bg));
+        for (t, color) in ellipses {
+            plot(t, '•', Style::default().fg(color).bg(bg));
+        }
+        for (i, c) in "matched ○".chars().enumerate() {
+            if phase.0 + 1 + i < bw { grid[phase.1][phase.0 + 1 + i] = (c, tick_label_style); }
+        }
+
+        let lines: Vec<Line> = grid.into_iter()
+            .map(|row| Line::from(row.into_iter().map(|(ch, s)| Span::styled(String::from(ch), s)).collect::<Vec<_>>()))
+            .collect();
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+
+    fn render_bucket_fill(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let err = self.notch_error();
+        let err_color = if !self.notched { theme.paint(Color::Rgb(100, 100, 130), Role::Faint) }
+            else if err.abs() < 0.5 { theme.paint(Color::Rgb(80, 255, 80), Role::Green) }
+            else if err.abs() < 1.5 { theme.paint(Color::Yellow, Role::Yellow) }
+            else { theme.paint(Color::Rgb(255, 60, 60), Role::Red) };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.paint(Color::Rgb(40, 80, 120), Role::Blue)))
+            .title(format!(" Fill {} bkts ", HARMONIC_NUMBER))
+            .title_style(Style::default().fg(theme.paint(Color::Rgb(100, 200, 255), Role::Blue)));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let bw = inner.width as usize;
+        let bh = inner.height as usize;
+        let Some((cx, cy, rx, ry)) = ring_geometry(bw, bh) else { return };
+
+        let bg = theme.paint(Color::Rgb(10, 10, 18), Role::Background);
+        let mut grid: Vec<Vec<(char, Style)>> = vec![vec![(' ', Style::default().bg(bg)); bw]; bh];
+
+        // Buckets sit at their azimuth relative to the kicker (top of the ring).
+        // Several buckets share a cell, so keep the emptiest to show the gap.
+        let h = HARMONIC_NUMBER as f64;
+        let mut cell_fill: Vec<Vec<Option<f32>>> = vec![vec![None; bw]; bh];
+        for (i, &fill) in self.bucket_fill.iter().enumerate() {
+            let azimuth = ((i as f64 + self.cog_offset + 0.5).rem_euclid(h) / h) as f32;
+            let angle = azimuth * std::f32::consts::TAU - std::f32::consts::FRAC_PI_2;
+            let x = (cx + rx * angle.cos()) as usize;
+            let y = (cy + ry * angle.sin()) as usize;
+            if x < bw && y < bh {
+                let cell = &mut cell_fill[y][x];
+                *cell = Some(cell.map_or(fill, |f| f.min(fill)));
+            }
+        }
+        for (y, row) in cell_fill.iter().enumerate() {
+            for (x, fill) in row.iter().enumerate() {
+                let Some(fill) = *fill else { continue };
+                grid[y][x] = if fill < 0.05 {
+                    ('·', Style::default().fg(theme.paint(Color::Rgb(200, 120, 255), Role::Violet)).bg(bg))
+                } else {
+                    let ch = if fill > 0.95 { '█' } else if fill > 0.85 { '▓' } else if fill > 0.5 { '▒' } else { '░' };
+                    (ch, Style::default().fg(theme.shade(Color::Rgb(60, (120.0 + fill * 120.0) as u8, 255), Role::Blue, 0.5 + fill * 0.5)).bg(bg))
+                };
+            }
+        }
+
+        // Extraction kicker just outside the ring at the top
+        let kx = cx as usize;
+        let ky = (cy - ry - 1.0).max(0.0) as usize;
+        if kx < bw && ky < bh {
+            grid[ky][kx] = ('▼', Style::default().fg(theme.paint(Color::Rgb(255, 200, 50), Role::Yellow)).bg(bg).add_modifier(Modifier::BOLD));
+        }
+
+        // Centre readout
+        let status = if self.notched { format!("notch {:+.1}", err) } else { "no notch".to_string() };
+        let sy = cy as usize;
+        let sx = (cx as usize).saturating_sub(status.len() / 2);
+        for (i, c) in status.chars().enumerate() {
+            if sy < bh && sx + i < bw {
+                grid[sy][sx + i] = (c, Style::default().fg(err_color).bg(bg).add_modifier(Modifier::BOLD));
+            }
+        }
+
+        let lines: Vec<Line> = grid.into_iter()
+            .map(|row| Line::from(row.into_iter().map(|(ch, s)| Span::styled(String::from(ch), s)).collect::<Vec<_>>()))
+            .collect();
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+
+    /// A cell's coil temperature against the trip point, or its trip.
+    fn temperature_line(&self, cell: usize, theme: &Theme) -> Line<'static> {
+        let temp = self.magnet_temp[cell];
+        if self.tripped[cell] {
+            return Line::from(vec![
+                Span::styled(" TRIPPED", Style::default().fg(theme.paint(Color::Rgb(255, 100, 60), Role::Red)).add_modifier(Modifier::BOLD)),
+                Span::styled(format!(" {:.0}°C, on at {:.0}", temp, RESET_C), Style::default().fg(theme.paint(Color::Rgb(140, 140, 160), Role::Dim))),
+            ]);
+        }
+        let frac = ((temp - AMBIENT_C) / (TRIP_C - AMBIENT_C)).clamp(0.0, 1.0);
+        let filled = (frac * 10.0).round() as usize;
+        let color = if frac < 0.5 { theme.paint(Color::Rgb(80, 200, 120), Role::Green) }
+            else if frac < 0.8 { theme.paint(Color::Rgb(230, 200, 60), Role::Yellow) }
+            else { theme.paint(Color::Rgb(255, 100, 60), Role::Red) };
+        Line::from(vec![
+            Span::styled(format!(" {:>3.0}°C ", temp), Style::default().fg(color)),
+            Span::styled("█".repeat(filled), Style::default().fg(color)),
+            Span::styled("·".repeat(10 - filled), Style::default().fg(theme.paint(Color::Rgb(50, 50, 70), Role::Panel))),
+            Span::styled(format!(" {:.0}", TRIP_C), Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint))),
+        ])
+    }
+
+    fn render_corrector_panel(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         if let Some(ref bump) = self.bump {
             // Bump mode panel
             let sec_coeffs = bump.section_coefficients();
             let mut lines: Vec<Line> = Vec::new();
             lines.push(Line::from(vec![
-                Span::styled(format!(" {}-BUMP", bump.size), Style::default().fg(Color::Rgb(80, 255, 200)).add_modifier(Modifier::BOLD)),
+                Span::styled(format!(" {}-BUMP", bump.size), Style::default().fg(theme.paint(Color::Rgb(80, 255, 200), Role::Cyan)).add_modifier(Modifier::BOLD)),
             ]));
             for (s, c) in &sec_coeffs {
                 let sign = if *c > 0.0 { "+" } else { "-" };
-                let color = if *c > 0.0 { Color::Rgb(80, 255, 180) } else { Color::Rgb(255, 140, 80) };
+                let color = if *c > 0.0 { theme.paint(Color::Rgb(80, 255, 180), Role::Cyan) } else { theme.paint(Color::Rgb(255, 140, 80), Role::Orange) };
                 lines.push(Line::from(vec![
                     Span::styled(format!("  {}C{}(x{:.0})", sign, s + 1, c.abs()), Style::default().fg(color)),
                 ]));
                 lines.push(Line::from(vec![
                     Span::styled(format!("    H:{:+.4} V:{:+.4}", self.correctors[*s].h_trim, self.correctors[*s].v_trim),
-                        Style::default().fg(Color::Rgb(160, 160, 180))),
+                        Style::default().fg(theme.paint(Color::Rgb(160, 160, 180), Role::Dim))),
                 ]));
             }
             lines.push(Line::from(Span::styled("", Style::default())));
             lines.push(Line::from(vec![
-                Span::styled(" U/D", Style::default().fg(Color::Rgb(255, 255, 100))),
-                Span::styled(" H+V ", Style::default().fg(Color::Rgb(140, 140, 160))),
-                Span::styled("W/S", Style::default().fg(Color::Rgb(255, 180, 120))),
-                Span::styled(" H", Style::default().fg(Color::Rgb(140, 140, 160))),
+                Span::styled(" U/D", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                Span::styled(" H+V ", Style::default().fg(theme.paint(Color::Rgb(140, 140, 160), Role::Dim))),
+                Span::styled("W/S", Style::default().fg(theme.paint(Color::Rgb(255, 180, 120), Role::Orange))),
+                Span::styled(" H", Style::default().fg(theme.paint(Color::Rgb(140, 140, 160), Role::Dim))),
             ]));
             lines.push(Line::from(vec![
-                Span::styled(" E/Q", Style::default().fg(Color::Rgb(200, 120, 255))),
-                Span::styled(" V ", Style::default().fg(Color::Rgb(140, 140, 160))),
-                Span::styled("L/R", Style::default().fg(Color::Rgb(120, 220, 255))),
-                Span::styled(" shift", Style::default().fg(Color::Rgb(140, 140, 160))),
+                Span::styled(" E/Q", Style::default().fg(theme.paint(Color::Rgb(200, 120, 255), Role::Violet))),
+                Span::styled(" V ", Style::default().fg(theme.paint(Color::Rgb(140, 140, 160), Role::Dim))),
+                Span::styled("L/R", Style::default().fg(theme.paint(Color::Rgb(120, 220, 255), Role::Cyan))),
+                Span::styled(" shift", Style::default().fg(theme.paint(Color::Rgb(140, 140, 160), Role::Dim))),
             ]));
 
             let panel = Paragraph::new(lines)
                 .block(Block::default().borders(Borders::ALL).border_type(BorderType::Rounded)
-                    .border_style(Style::default().fg(Color::Rgb(60, 180, 140)))
+                    .border_style(Style::default().fg(theme.paint(Color::Rgb(60, 180, 140), Role::Cyan)))
                     .title(format!(" {}-Bump ", bump.size))
-                    .title_style(Style::default().fg(Color::Rgb(80, 255, 200)).add_modifier(Modifier::BOLD)));
+                    .title_style(Style::default().fg(theme.paint(Color::Rgb(80, 255, 200), Role::Cyan)).add_modifier(Modifier::BOLD)));
             frame.render_widget(panel, area);
         } else {
             // Normal corrector panel
@@ -2435,11 +4517,11 @@ impl BoosterGame {
 
             lines.push(Line::from(vec![
                 Span::styled(format!(" Cell {}/{}", cell + 1, NUM_SECTIONS),
-                    Style::default().fg(Color::Rgb(200, 200, 220)).add_modifier(Modifier::BOLD)),
+                    Style::default().fg(theme.paint(Color::Rgb(200, 200, 220), Role::Text)).add_modifier(Modifier::BOLD)),
                 Span::styled(format!(" step:{:.4}", self.adjust_speed),
-                    Style::default().fg(Color::Rgb(140, 140, 160))),
+                    Style::default().fg(theme.paint(Color::Rgb(140, 140, 160), Role::Dim))),
             ]));
-            lines.push(Line::from(Span::styled("", Style::default())));
+            lines.push(self.temperature_line(cell, theme));
 
             let corrector_values: Vec<(CorrectorSelect, f64)> = vec![
                 (CorrectorSelect::HTrim, corr.h_trim),
@@ -2453,49 +4535,54 @@ impl BoosterGame {
             for (ct, val) in &corrector_values {
                 let is_sel = *ct == self.selected_corrector;
                 let indicator = if is_sel { " >" } else { "  " };
+                let value_color = if val.abs() > ct.rated() { theme.paint(Color::Rgb(255, 150, 60), Role::Orange) }
+                    else if is_sel { theme.paint(Color::Rgb(255, 220, 80), Role::Yellow) }
+                    else { theme.paint(Color::Rgb(120, 120, 150), Role::Dim) };
                 lines.push(Line::from(vec![
-                    Span::styled(indicator, Style::default().fg(Color::Rgb(255, 255, 100))),
+                    Span::styled(indicator, Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
                     Span::styled(format!("{}", ct.label()),
-                        Style::default().fg(if is_sel { Color::White } else { ct.color() })
+                        Style::default().fg(if is_sel { theme.paint(Color::White, Role::Bright) } else { theme.ink(ct.color()) })
                             .add_modifier(if is_sel { Modifier::BOLD } else { Modifier::empty() })),
                     Span::styled(format!(" {:+.5}", val),
-                        Style::default().fg(if is_sel { Color::Rgb(255, 220, 80) } else { Color::Rgb(120, 120, 150) })
+                        Style::default().fg(value_color)
                             .add_modifier(if is_sel { Modifier::BOLD } else { Modifier::empty() })),
                 ]));
             }
+            if self.show_ideal {
+                let (a, b) = self.ideal_sextupoles();
+                lines.push(Line::from(Span::styled(
+                    format!(" ideal A{:+.5} B{:+.5}", a, b),
+                    Style::default().fg(theme.ink(IDEAL_COLOR)),
+                )));
+            }
 
             lines.push(Line::from(Span::styled("", Style::default())));
             lines.push(Line::from(vec![
-                Span::styled(" U/D", Style::default().fg(Color::Rgb(255, 255, 100))),
-                Span::styled(" sel ", Style::default().fg(Color::Rgb(100, 100, 130))),
-                Span::styled("L/R", Style::default().fg(Color::Rgb(255, 255, 100))),
-                Span::styled(" adj", Style::default().fg(Color::Rgb(100, 100, 130))),
+                Span::styled(" U/D", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                Span::styled(" sel ", Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint))),
+                Span::styled("L/R", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                Span::styled(" adj", Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint))),
             ]));
             lines.push(Line::from(vec![
-                Span::styled(" []", Style::default().fg(Color::Rgb(255, 255, 100))),
-                Span::styled(" cell ", Style::default().fg(Color::Rgb(100, 100, 130))),
-                Span::styled("+/-", Style::default().fg(Color::Rgb(255, 255, 100))),
-                Span::styled(" step", Style::default().fg(Color::Rgb(100, 100, 130))),
+                Span::styled(" []", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                Span::styled(" cell ", Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint))),
+                Span::styled("+/-", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                Span::styled(" step", Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint))),
             ]));
 
             let panel = Paragraph::new(lines)
                 .block(Block::default().borders(Borders::ALL).border_type(BorderType::Rounded)
-                    .border_style(Style::default().fg(Color::Rgb(60, 100, 140)))
+                    .border_style(Style::default().fg(theme.paint(Color::Rgb(60, 100, 140), Role::Blue)))
                     .title(" Correctors ")
-                    .title_style(Style::default().fg(Color::Rgb(120, 200, 255))));
+                    .title_style(Style::default().fg(theme.paint(Color::Rgb(120, 200, 255), Role::Blue))));
             frame.render_widget(panel, area);
         }
     }
 
-    fn render_ring(&self, frame: &mut Frame, area: Rect) {
+    fn render_ring(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let ring_w = area.width as usize;
         let ring_h = area.height as usize;
-        if ring_w < 10 || ring_h < 6 { return; }
-
-        let cx = ring_w as f32 / 2.0;
-        let cy = ring_h as f32 / 2.0;
-        let rx = (ring_w as f32 * 0.35).min(cx - 4.0);
-        let ry = (ring_h as f32 * 0.38).min(cy - 2.0);
+        let Some((cx, cy, rx, ry)) = ring_geometry(ring_w, ring_h) else { return };
 
         let mut grid: Vec<Vec<(char, Style)>> = vec![vec![(' ', Style::default()); ring_w]; ring_h];
 
@@ -2512,7 +4599,7 @@ impl BoosterGame {
                 if dx < ring_w && dy < ring_h && grid[dy][dx].0 == ' ' {
                     let tangent = a.cos().abs();
                     let ch = if tangent > 0.7 { '-' } else if tangent < 0.3 { '|' } else { '.' };
-                    grid[dy][dx] = (ch, Style::default().fg(Color::Rgb(30, 40, 55)));
+                    grid[dy][dx] = (ch, Style::default().fg(theme.paint(Color::Rgb(30, 40, 55), Role::Panel)));
                 }
             }
         }
@@ -2520,8 +4607,8 @@ impl BoosterGame {
         // Section markers
         for sec in 0..NUM_SECTIONS {
             let angle = (sec as f32 / NUM_SECTIONS as f32) * std::f32::consts::PI * 2.0 - std::f32::consts::FRAC_PI_2;
-            let x = (cx + rx * angle.cos()) as usize;
-            let y = (cy + ry * angle.sin()) as usize;
+            let (mx, my) = ring_marker(sec, cx, cy, rx, ry);
+            let (x, y) = (mx as usize, my as usize);
             if x >= ring_w || y >= ring_h { continue; }
 
             let is_beam_here = self.beam_running && !self.beam_lost && self.beam_cell == sec;
@@ -2531,19 +4618,21 @@ impl BoosterGame {
             let trail_entry = self.trail.iter().rev().find(|(s, _, _)| *s == sec);
 
             let (ch, style) = if is_beam_here {
-                ('O', Style::default().fg(Color::Rgb(100, 255, 255)).add_modifier(Modifier::BOLD))
+                ('O', Style::default().fg(theme.paint(Color::Rgb(100, 255, 255), Role::Cyan)).add_modifier(Modifier::BOLD))
             } else if let Some((_, pos, _)) = trail_entry {
                 let intensity = if pos.abs() < 10.0 { 200 } else if pos.abs() < 30.0 { 140 } else { 80 };
-                ('o', Style::default().fg(Color::Rgb(30, intensity as u8, (intensity + 30).min(255) as u8)))
+                ('o', Style::default().fg(theme.shade(Color::Rgb(30, intensity as u8, (intensity + 30).min(255) as u8), Role::Cyan, intensity as f32 / 200.0)))
             } else if is_bump {
                 let coeff = self.bump.as_ref().and_then(|b| b.coeff_for_section(sec)).unwrap_or(0.0);
                 let ch = if coeff > 0.0 { '+' } else { '-' };
-                let color = if coeff > 0.0 { Color::Rgb(80, 255, 180) } else { Color::Rgb(255, 140, 80) };
+                let color = if coeff > 0.0 { theme.paint(Color::Rgb(80, 255, 180), Role::Cyan) } else { theme.paint(Color::Rgb(255, 140, 80), Role::Orange) };
                 (ch, Style::default().fg(color).add_modifier(Modifier::BOLD))
+            } else if self.tripped[sec] {
+                ('x', Style::default().fg(theme.paint(Color::Rgb(255, 100, 60), Role::Red)).add_modifier(Modifier::BOLD))
             } else if is_selected {
-                ('*', Style::default().fg(Color::Rgb(255, 220, 80)).add_modifier(Modifier::BOLD))
+                ('*', Style::default().fg(theme.paint(Color::Rgb(255, 220, 80), Role::Yellow)).add_modifier(Modifier::BOLD))
             } else {
-                ('o', Style::default().fg(Color::Rgb(60, 80, 100)))
+                ('o', Style::default().fg(theme.paint(Color::Rgb(60, 80, 100), Role::Faint)))
             };
 
             grid[y][x] = (ch, style);
@@ -2556,7 +4645,7 @@ impl BoosterGame {
                 for (i, c) in label.chars().enumerate() {
                     let nx = lx + i;
                     if nx < ring_w {
-                        let col = if is_selected { Color::Rgb(255, 220, 80) } else { Color::Rgb(60, 60, 80) };
+                        let col = if is_selected { theme.paint(Color::Rgb(255, 220, 80), Role::Yellow) } else { theme.paint(Color::Rgb(60, 60, 80), Role::Faint) };
                         grid[ly][nx] = (c, Style::default().fg(col));
                     }
                 }
@@ -2579,7 +4668,7 @@ impl BoosterGame {
         for (i, c) in center_text.chars().enumerate() {
             let x = ctx + i;
             if x < ring_w && cty < ring_h {
-                grid[cty][x] = (c, Style::default().fg(ct_color).add_modifier(Modifier::BOLD));
+                grid[cty][x] = (c, Style::default().fg(theme.ink(ct_color)).add_modifier(Modifier::BOLD));
             }
         }
 
@@ -2590,7 +4679,7 @@ impl BoosterGame {
             for (i, c) in energy_text.chars().enumerate() {
                 let x = ex + i;
                 if x < ring_w {
-                    grid[cty + 1][x] = (c, Style::default().fg(Color::Rgb(255, 200, 80)));
+                    grid[cty + 1][x] = (c, Style::default().fg(theme.paint(Color::Rgb(255, 200, 80), Role::Orange)));
                 }
             }
         }
@@ -2606,9 +4695,9 @@ impl BoosterGame {
                 if x < ring_w && cty + 2 < ring_h {
                     let norm = (val.abs() / APERTURE_DISPLAY).min(1.0);
                     let idx = (norm * 7.0) as usize;
-                    let color = if norm < 0.2 { Color::Rgb(50, 200, 100) }
-                        else if norm < 0.5 { Color::Rgb(200, 200, 50) }
-                        else { Color::Rgb(200, 60, 60) };
+                    let color = if norm < 0.2 { theme.paint(Color::Rgb(50, 200, 100), Role::Green) }
+                        else if norm < 0.5 { theme.paint(Color::Rgb(200, 200, 50), Role::Yellow) }
+                        else { theme.paint(Color::Rgb(200, 60, 60), Role::Red) };
                     grid[cty + 2][x] = (sparkline_chars[idx], Style::default().fg(color));
                 }
             }
@@ -2620,12 +4709,12 @@ impl BoosterGame {
         frame.render_widget(Paragraph::new(lines), area);
     }
 
-    fn render_help_bar(&self, frame: &mut Frame, area: Rect) {
+    fn render_help_bar(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         // Input mode: show coordinate prompt instead of normal help
         if self.input_mode != InputMode::None {
             let (label, prompt_color) = match self.input_mode {
-                InputMode::InjectX => ("Inject X (mm)", Color::Rgb(255, 180, 120)),
-                InputMode::InjectY => ("Inject Y (mm)", Color::Rgb(200, 120, 255)),
+                InputMode::InjectX => ("Inject X (mm)", theme.paint(Color::Rgb(255, 180, 120), Role::Orange)),
+                InputMode::InjectY => ("Inject Y (mm)", theme.paint(Color::Rgb(200, 120, 255), Role::Violet)),
                 InputMode::None => unreachable!(),
             };
             let lines = vec![
@@ -2636,16 +4725,16 @@ impl BoosterGame {
                     ),
                     Span::styled(
                         format!("{}_", self.input_buffer),
-                        Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+                        Style::default().fg(theme.paint(Color::White, Role::Bright)).add_modifier(Modifier::BOLD),
                     ),
                 ]),
                 Line::from(vec![
-                    Span::styled(" Enter", Style::default().fg(Color::Rgb(255, 255, 100))),
-                    Span::styled(" Confirm ", Style::default().fg(Color::DarkGray)),
-                    Span::styled("Esc", Style::default().fg(Color::Rgb(255, 255, 100))),
-                    Span::styled(" Cancel ", Style::default().fg(Color::DarkGray)),
-                    Span::styled("Bksp", Style::default().fg(Color::Rgb(255, 255, 100))),
-                    Span::styled(" Delete", Style::default().fg(Color::DarkGray)),
+                    Span::styled(" Enter", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                    Span::styled(" Confirm ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                    Span::styled("Esc", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                    Span::styled(" Cancel ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                    Span::styled("Bksp", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                    Span::styled(" Delete", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
                 ]),
             ];
             frame.render_widget(Paragraph::new(lines), area);
@@ -2656,14 +4745,16 @@ impl BoosterGame {
             GamePhase::Lost => {
                 vec![
                     Line::from(vec![
-                        Span::styled(" BEAM LOST! ", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
-                        Span::styled("Adjust correctors and press ENTER to retry, Esc for menu", Style::default().fg(Color::Gray)),
+                        Span::styled(" BEAM LOST! ", Style::default().fg(theme.paint(Color::Red, Role::Red)).add_modifier(Modifier::BOLD)),
+                        Span::styled("Adjust correctors and press ENTER to retry, Esc for menu", Style::default().fg(theme.paint(Color::Gray, Role::Dim))),
                     ]),
                     Line::from(vec![
-                        Span::styled(" V", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" View ", Style::default().fg(Color::DarkGray)),
-                        Span::styled("R", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" Reset", Style::default().fg(Color::DarkGray)),
+                        Span::styled(" V", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                        Span::styled(" View ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                        Span::styled("O", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                        Span::styled(" Report ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                        Span::styled("R", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                        Span::styled(" Reset", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
                     ]),
                 ]
             }
@@ -2672,52 +4763,54 @@ impl BoosterGame {
                     Line::from(vec![
                         Span::styled(
                             format!(" EXTRACTED! I:{:.0}% Score:{} ", self.beam_intensity * 100.0, self.get_score()),
-                            Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+                            Style::default().fg(theme.paint(Color::Green, Role::Green)).add_modifier(Modifier::BOLD),
                         ),
-                        Span::styled("Press ENTER to play again", Style::default().fg(Color::Gray)),
+                        Span::styled("Press ENTER to play again", Style::default().fg(theme.paint(Color::Gray, Role::Dim))),
                     ]),
                     Line::from(vec![
-                        Span::styled(" V", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" View ", Style::default().fg(Color::DarkGray)),
-                        Span::styled("R", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" Reset", Style::default().fg(Color::DarkGray)),
+                        Span::styled(" V", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                        Span::styled(" View ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                        Span::styled("O", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                        Span::styled(" Report ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                        Span::styled("R", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                        Span::styled(" Reset", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
                     ]),
                 ]
             }
             _ if self.bump.is_some() => {
                 vec![
                     Line::from(vec![
-                        Span::styled(" BUMP ", Style::default().fg(Color::Rgb(80, 255, 200)).add_modifier(Modifier::BOLD)),
-                        Span::styled("U/D", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" H+V ", Style::default().fg(Color::DarkGray)),
-                        Span::styled("W/S", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" H ", Style::default().fg(Color::DarkGray)),
-                        Span::styled("E/Q", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" V ", Style::default().fg(Color::DarkGray)),
-                        Span::styled("L/R", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" Shift ", Style::default().fg(Color::DarkGray)),
-                        Span::styled("Z", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" Zero ", Style::default().fg(Color::DarkGray)),
-                        Span::styled("B", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" Cycle", Style::default().fg(Color::DarkGray)),
+                        Span::styled(" BUMP ", Style::default().fg(theme.paint(Color::Rgb(80, 255, 200), Role::Cyan)).add_modifier(Modifier::BOLD)),
+                        Span::styled("U/D", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                        Span::styled(" H+V ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                        Span::styled("W/S", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                        Span::styled(" H ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                        Span::styled("E/Q", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                        Span::styled(" V ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                        Span::styled("L/R", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                        Span::styled(" Shift ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                        Span::styled("Z", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                        Span::styled(" Zero ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                        Span::styled("B", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                        Span::styled(" Cycle", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
                     ]),
                     Line::from(vec![
-                        Span::styled(" J/K", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" Quad ", Style::default().fg(Color::DarkGray)),
-                        Span::styled("M/N", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" Bend ", Style::default().fg(Color::DarkGray)),
-                        Span::styled("F/G", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" RF-V ", Style::default().fg(Color::DarkGray)),
-                        Span::styled("T", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" RF-ph ", Style::default().fg(Color::DarkGray)),
-                        Span::styled("V", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" View ", Style::default().fg(Color::DarkGray)),
-                        Span::styled("+/-", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" Step ", Style::default().fg(Color::DarkGray)),
-                        Span::styled("P", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" Pause ", Style::default().fg(Color::DarkGray)),
-                        Span::styled(".", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(format!(" {}", self.sim_speed.label()), Style::default().fg(Color::DarkGray)),
+                        Span::styled(" J/K", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                        Span::styled(" Quad ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                        Span::styled("M/N", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                        Span::styled(" Bend ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                        Span::styled("F/G", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                        Span::styled(" RF-V ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                        Span::styled("T", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                        Span::styled(" RF-ph ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                        Span::styled("V", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                        Span::styled(" View ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                        Span::styled("+/-", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                        Span::styled(" Step ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                        Span::styled("P", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                        Span::styled(" Pause ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                        Span::styled(".", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                        Span::styled(format!(" {}", self.sim_speed.label()), Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
                     ]),
                 ]
             }
@@ -2726,52 +4819,61 @@ impl BoosterGame {
                     Line::from({
                         let mut spans = vec![
                             Span::styled(if self.beam_running { " SPACE" } else { " SPACE" },
-                                Style::default().fg(Color::Rgb(255, 255, 100))),
+                                Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
                             Span::styled(if self.beam_running { " Run " } else { " Inject " },
-                                Style::default().fg(if self.beam_running { Color::Green } else { Color::Yellow })),
+                                Style::default().fg(if self.beam_running { theme.paint(Color::Green, Role::Green) } else { theme.paint(Color::Yellow, Role::Yellow) })),
                         ];
                         if !self.beam_running {
-                            spans.push(Span::styled("I", Style::default().fg(Color::Rgb(255, 255, 100))));
-                            spans.push(Span::styled(" Inject@XY ", Style::default().fg(Color::DarkGray)));
+                            spans.push(Span::styled("I", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))));
+                            spans.push(Span::styled(" Inject@XY ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))));
                         }
                         spans.extend_from_slice(&[
-                            Span::styled("U/D", Style::default().fg(Color::Rgb(255, 255, 100))),
-                            Span::styled(" Corr ", Style::default().fg(Color::DarkGray)),
-                            Span::styled("L/R", Style::default().fg(Color::Rgb(255, 255, 100))),
-                            Span::styled(" Adj ", Style::default().fg(Color::DarkGray)),
-                            Span::styled("[]", Style::default().fg(Color::Rgb(255, 255, 100))),
-                            Span::styled(" Cell ", Style::default().fg(Color::DarkGray)),
-                            Span::styled("B", Style::default().fg(Color::Rgb(255, 255, 100))),
-                            Span::styled(" Bump ", Style::default().fg(Color::DarkGray)),
-                            Span::styled("C", Style::default().fg(Color::Rgb(255, 255, 100))),
-                            Span::styled(" Copy ", Style::default().fg(Color::DarkGray)),
-                            Span::styled("Z", Style::default().fg(Color::Rgb(255, 255, 100))),
-                            Span::styled(" Zero", Style::default().fg(Color::DarkGray)),
+                            Span::styled("U/D", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                            Span::styled(" Corr ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                            Span::styled("L/R", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                            Span::styled(" Adj ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                            Span::styled("[]", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                            Span::styled(" Cell ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                            Span::styled("B", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                            Span::styled(" Bump ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                            Span::styled("C", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                            Span::styled(" Copy ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                            Span::styled("Z", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow))),
+                            Span::styled(" Zero", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                        ]);
+                        spans
+                    }),
+                    Line::from({
+                        let key = |k: &'static str| Span::styled(k, Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow)));
+                        let desc = |d: String| Span::styled(d, Style::default().fg(theme.paint(Color::DarkGray, Role::Faint)));
+                        // Basic keys only until A asks for the rest
+                        let mut spans = if self.advanced_keys {
+                            vec![
+                                key(" J/K"), desc(" Quad ".into()),
+                                key("M/N"), desc(" Bend ".into()),
+                                key("F/G"), desc(" RF-V ".into()),
+                                key("T"), desc(" RF-ph ".into()),
+                                key("V"), desc(" View ".into()),
+                            ]
+                        } else {
+                            vec![key(" V"), desc(" View ".into())]
+                        };
+                        spans.extend([
+                            key("+/-"), desc(" Step ".into()),
+                            key("P"), desc(" Pause ".into()),
+                            key("."), desc(format!(" {} ", self.sim_speed.label())),
+                            key("R"), desc(" Reset ".into()),
+                        ]);
+                        if self.advanced_keys {
+                            spans.extend([key(":"), desc(" Console ".into())]);
+                        }
+                        spans.extend([
+                            key("Ctrl+P"), desc(" Commands ".into()),
+                            key("A"), desc(if self.advanced_keys { " Fewer keys ".into() } else { " All keys ".into() }),
+                            key("?"), desc(" Help".into()),
                         ]);
                         spans
                     }),
-                    Line::from(vec![
-                        Span::styled(" J/K", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" Quad ", Style::default().fg(Color::DarkGray)),
-                        Span::styled("M/N", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" Bend ", Style::default().fg(Color::DarkGray)),
-                        Span::styled("F/G", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" RF-V ", Style::default().fg(Color::DarkGray)),
-                        Span::styled("T", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" RF-ph ", Style::default().fg(Color::DarkGray)),
-                        Span::styled("V", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" View ", Style::default().fg(Color::DarkGray)),
-                        Span::styled("+/-", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" Step ", Style::default().fg(Color::DarkGray)),
-                        Span::styled("P", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" Pause ", Style::default().fg(Color::DarkGray)),
-                        Span::styled(".", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(format!(" {} ", self.sim_speed.label()), Style::default().fg(Color::DarkGray)),
-                        Span::styled("R", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" Reset ", Style::default().fg(Color::DarkGray)),
-                        Span::styled("?", Style::default().fg(Color::Rgb(255, 255, 100))),
-                        Span::styled(" Help", Style::default().fg(Color::DarkGray)),
-                    ]),
                 ]
             }
         };
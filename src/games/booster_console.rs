@@ -0,0 +1,289 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+/// Corrector names as typed on the console, in the panel's top-to-bottom order.
+pub const CORRECTOR_FIELDS: [&str; 6] = ["htrim", "vtrim", "tquad", "squad", "sexta", "sextb"];
+const LOG_LINES: usize = 50;
+const HISTORY_LEN: usize = 50;
+
+/// Ring-wide settings the console can set and ramp.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Param {
+    Rf,
+    RfPhase,
+    Rf2,
+    Rf2Phase,
+    Mqat,
+    Mdat,
+    SextA,
+    SextB,
+}
+
+impl Param {
+    pub const ALL: [Param; 8] = [
+        Param::Rf, Param::RfPhase, Param::Rf2, Param::Rf2Phase, Param::Mqat, Param::Mdat, Param::SextA, Param::SextB,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Param::Rf => "rf",
+            Param::RfPhase => "rfphase",
+            Param::Rf2 => "rf2",
+            Param::Rf2Phase => "rf2phase",
+            Param::Mqat => "mqat",
+            Param::Mdat => "mdat",
+            Param::SextA => "sexta",
+            Param::SextB => "sextb",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Param> {
+        Self::ALL.into_iter().find(|p| p.name() == s)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Measurement {
+    Tune,
+    Orbit,
+    Chrom,
+    Emittance,
+    Intensity,
+    Energy,
+    Mismatch,
+}
+
+impl Measurement {
+    const NAMES: [(&'static str, Measurement); 7] = [
+        ("tune", Measurement::Tune),
+        ("orbit", Measurement::Orbit),
+        ("chrom", Measurement::Chrom),
+        ("emit", Measurement::Emittance),
+        ("intensity", Measurement::Intensity),
+        ("energy", Measurement::Energy),
+        ("match", Measurement::Mismatch),
+    ];
+}
+
+/// A cell on the console is `c1`..`c24`, or `all`.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Cells {
+    One(usize),
+    All,
+}
+
+pub enum Command {
+    /// `set c12 htrim -0.002`
+    SetCorrector { cells: Cells, field: usize, value: f64 },
+    /// `set rf 0.8`
+    SetParam { param: Param, value: f64 },
+    /// `get c12` / `get rf`
+    GetCell(usize),
+    GetParam(Param),
+    /// `ramp rf 0.5 0.9 @turn 3000`: linear from `from` now to `to` at `turn`
+    Ramp { param: Param, from: f64, to: f64, turn: u32 },
+    /// `ramps` lists the active ramps, `ramps clear` drops them
+    ListRamps,
+    ClearRamps,
+    /// `measure [tune|orbit|chrom|emit|intensity|energy|match]`; empty = all
+    Measure(Vec<Measurement>),
+    /// `scan`: quad scan of the injected beam at the profile monitor
+    QuadScan,
+    /// `match`: correct the transfer line to the last scan's fit
+    Match,
+    /// `zero c12` / `zero all`
+    Zero(Cells),
+    /// `inject [x y]`
+    Inject(f64, f64),
+    /// `feedback <gain> <turns>` turns the slow orbit feedback on, `feedback
+    /// off` turns it off, and `feedback` alone shows it
+    Feedback(Option<(f64, u32)>),
+    ShowFeedback,
+    Help,
+    Clear,
+}
+
+pub const HELP: &[&str] = &[
+    "set c<1-24>|all <htrim|vtrim|tquad|squad|sexta|sextb> <value>",
+    "set <rf|rfphase|rf2|rf2phase|mqat|mdat|sexta|sextb> <value>",
+    "get c<1-24> | get <param>",
+    "ramp <param> <from> <to> @turn <n>    ramps [clear]",
+    "measure [tune|orbit|chrom|emit|intensity|energy|match]",
+    "scan (quad scan before injection)    match (rematch line to fit)",
+    "zero c<1-24>|all    inject [x y]    clear",
+    "feedback [<gain> <turns>|off] (slow orbit feedback)",
+];
+
+fn number(s: Option<&str>, what: &str) -> Result<f64, String> {
+    let s = s.ok_or_else(|| format!("missing {}", what))?;
+    s.parse::<f64>().ok().filter(|v| v.is_finite()).ok_or_else(|| format!("bad {}: '{}'", what, s))
+}
+
+fn cells(s: &str, count: usize) -> Result<Cells, String> {
+    if s == "all" { return Ok(Cells::All); }
+    let n = s.strip_prefix('c').and_then(|n| n.parse::<usize>().ok());
+    match n {
+        Some(n) if (1..=count).contains(&n) => Ok(Cells::One(n - 1)),
+        _ => Err(format!("bad cell '{}' (c1-c{} or all)", s, count)),
+    }
+}
+
+/// Parse one console line. `cell_count` bounds the `c<n>` cell numbers.
+pub fn parse(line: &str, cell_count: usize) -> Result<Command, String> {
+    let lower = line.trim().to_ascii_lowercase();
+    let mut words = lower.split_whitespace();
+    let Some(verb) = words.next() else { return Err(String::new()) };
+    let args: Vec<&str> = words.collect();
+    let end = |n: usize| if args.len() > n { Err(format!("unexpected '{}'", args[n])) } else { Ok(()) };
+
+    let command = match verb {
+        "set" => {
+            let target = args.first().ok_or("set what?")?;
+            if let Some(param) = Param::parse(target) {
+                end(2)?;
+                Command::SetParam { param, value: number(args.get(1).copied(), "value")? }
+            } else {
+                let cells = cells(target, cell_count)?;
+                let name = args.get(1).ok_or("missing corrector")?;
+                let field = CORRECTOR_FIELDS
+                    .iter()
+                    .position(|f| f == name)
+                    .ok_or_else(|| format!("unknown corrector '{}'", name))?;
+                end(3)?;
+                Command::SetCorrector { cells, field, value: number(args.get(2).copied(), "value")? }
+            }
+        }
+        "get" => {
+            let target = args.first().ok_or("get what?")?;
+            end(1)?;
+            match Param::parse(target) {
+                Some(param) => Command::GetParam(param),
+                None => match cells(target, cell_count)? {
+                    Cells::One(cell) => Command::GetCell(cell),
+                    Cells::All => return Err("get one cell at a time".to_string()),
+                },
+            }
+        }
+        "ramp" => {
+            let name = args.first().ok_or("ramp what?")?;
+            let param = Param::parse(name).ok_or_else(|| format!("unknown parameter '{}'", name))?;
+            let from = number(args.get(1).copied(), "start value")?;
+            let to = number(args.get(2).copied(), "end value")?;
+            let turn = match (args.get(3).copied(), args.get(4).copied()) {
+                (Some("@turn"), Some(t)) => t.parse::<u32>().map_err(|_| format!("bad turn '{}'", t))?,
+                (Some(t), None) if t.starts_with('@') => {
+                    t[1..].parse::<u32>().map_err(|_| format!("bad turn '{}'", t))?
+                }
+                _ => return Err("expected @turn <n>".to_string()),
+            };
+            Command::Ramp { param, from, to, turn }
+        }
+        "ramps" => match args.as_slice() {
+            [] => Command::ListRamps,
+            ["clear"] => Command::ClearRamps,
+            _ => return Err("ramps [clear]".to_string()),
+        },
+        "measure" | "meas" => {
+            let mut list = Vec::new();
+            for a in &args {
+                let m = Measurement::NAMES
+                    .iter()
+                    .find(|(n, _)| n == a)
+                    .ok_or_else(|| format!("can't measure '{}'", a))?;
+                list.push(m.1);
+            }
+            if list.is_empty() {
+                list = Measurement::NAMES.iter().map(|(_, m)| *m).collect();
+            }
+            Command::Measure(list)
+        }
+        "scan" => {
+            end(0)?;
+            Command::QuadScan
+        }
+        "match" => {
+            end(0)?;
+            Command::Match
+        }
+        "zero" => {
+            let target = args.first().ok_or("zero what?")?;
+            end(1)?;
+            Command::Zero(cells(target, cell_count)?)
+        }
+        "inject" => match args.len() {
+            0 => Command::Inject(0.0, 0.0),
+            2 => Command::Inject(number(args.first().copied(), "x")?, number(args.get(1).copied(), "y")?),
+            _ => return Err("inject [x y]".to_string()),
+        },
+        "feedback" | "fb" => match args.as_slice() {
+            [] => Command::ShowFeedback,
+            ["off"] => Command::Feedback(None),
+            [gain, turns] => {
+                let gain = number(Some(gain), "gain")?;
+                let turns = turns.parse::<u32>().map_err(|_| format!("bad turns '{}'", turns))?;
+                Command::Feedback(Some((gain, turns)))
+            }
+            _ => return Err("feedback [<gain> <turns>|off]".to_string()),
+        },
+        "help" | "?" => Command::Help,
+        "clear" | "cls" => Command::Clear,
+        _ => return Err(format!("unknown command '{}' (try help)", verb)),
+    };
+    Ok(command)
+}
+
+/// `:` prompt with a scrollback log and command history.
+#[derive(Default)]
+pub struct Console {
+    pub open: bool,
+    pub input: String,
+    pub log: Vec<String>,
+    history: Vec<String>,
+    /// Position while stepping back through history with ↑
+    history_pos: Option<usize>,
+}
+
+impl Console {
+    pub fn print(&mut self, line: impl Into<String>) {
+        self.log.push(line.into());
+        if self.log.len() > LOG_LINES {
+            self.log.remove(0);
+        }
+    }
+
+    /// Edit the prompt; returns the line when Enter is pressed.
+    pub fn handle_key(&mut self, key: KeyEvent) -> Option<String> {
+        match key.code {
+            KeyCode::Esc => {
+                self.open = false;
+                self.input.clear();
+                self.history_pos = None;
+            }
+            KeyCode::Backspace => { self.input.pop(); }
+            KeyCode::Up if !self.history.is_empty() => {
+                let pos = self.history_pos.map_or(self.history.len() - 1, |p| p.saturating_sub(1));
+                self.history_pos = Some(pos);
+                self.input = self.history[pos].clone();
+            }
+            KeyCode::Down => {
+                self.history_pos = self.history_pos.map(|p| p + 1).filter(|&p| p < self.history.len());
+                self.input = self.history_pos.map_or(String::new(), |p| self.history[p].clone());
+            }
+            KeyCode::Enter => {
+                let line = std::mem::take(&mut self.input);
+                self.history_pos = None;
+                if line.trim().is_empty() { return None; }
+                if self.history.last() != Some(&line) {
+                    self.history.push(line.clone());
+                    if self.history.len() > HISTORY_LEN {
+                        self.history.remove(0);
+                    }
+                }
+                self.print(format!(": {}", line));
+                return Some(line);
+            }
+            KeyCode::Char(c) if !c.is_control() => self.input.push(c),
+            _ => {}
+        }
+        None
+    }
+}
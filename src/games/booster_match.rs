@@ -0,0 +1,118 @@
+/// Courant-Snyder parameters of one plane at a point in the lattice.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Twiss {
+    pub beta: f64,
+    pub alpha: f64,
+}
+
+impl Twiss {
+    pub fn gamma(&self) -> f64 {
+        (1.0 + self.alpha * self.alpha) / self.beta
+    }
+
+    /// Mismatch factor against the ring's matched Twiss: 1.0 when matched;
+    /// the emittance grows by this much once the beam filaments.
+    pub fn mismatch(&self, matched: &Twiss) -> f64 {
+        0.5 * (matched.beta * self.gamma() - 2.0 * matched.alpha * self.alpha + matched.gamma() * self.beta)
+    }
+
+    /// Twiss after a transfer matrix `[[m11, m12], [m21, m22]]`.
+    pub fn transport(&self, m: [[f64; 2]; 2]) -> Twiss {
+        let [[m11, m12], [m21, m22]] = m;
+        Twiss {
+            beta: m11 * m11 * self.beta - 2.0 * m11 * m12 * self.alpha + m12 * m12 * self.gamma(),
+            alpha: -m11 * m21 * self.beta + (m11 * m22 + m12 * m21) * self.alpha - m12 * m22 * self.gamma(),
+        }
+    }
+
+    /// Transfer-line correction that turns `self` into `target`: the
+    /// normalising map of `self` followed by the inverse one of `target`.
+    pub fn correction_to(&self, target: &Twiss) -> [[f64; 2]; 2] {
+        let (sb, tb) = (self.beta.sqrt(), target.beta.sqrt());
+        // A(t) · A(s)⁻¹ with A = [[√β, 0], [-α/√β, 1/√β]]
+        [
+            [tb / sb, 0.0],
+            [(self.alpha - target.alpha) / (sb * tb), sb / tb],
+        ]
+    }
+}
+
+/// `second` applied after `first`, as one transfer matrix.
+pub fn chain(first: [[f64; 2]; 2], second: [[f64; 2]; 2]) -> [[f64; 2]; 2] {
+    let [[a, b], [c, d]] = second;
+    let [[e, f], [g, h]] = first;
+    [[a * e + b * g, a * f + b * h], [c * e + d * g, c * f + d * h]]
+}
+
+/// Beam size at the profile monitor for one MQAT setting, with the
+/// monitor's transfer-matrix terms for each plane.
+#[derive(Clone, Copy)]
+pub struct ScanPoint {
+    pub mqat: f64,
+    pub sigma_x: f64,
+    pub sigma_y: f64,
+    /// (m11, m12) from the injection point to the monitor
+    pub mx: (f64, f64),
+    pub my: (f64, f64),
+}
+
+/// Injected beam as fitted from a quad scan.
+#[derive(Clone, Copy)]
+pub struct ScanFit {
+    pub x: Twiss,
+    pub y: Twiss,
+    /// RMS geometric emittance, mm·mrad
+    pub emit_x: f64,
+    pub emit_y: f64,
+}
+
+/// Least-squares fit of σ² = m11²·Σ11 + 2·m11·m12·Σ12 + m12²·Σ22 over the
+/// scan, for the injected beam matrix Σ of each plane.
+pub fn fit(points: &[ScanPoint]) -> Option<ScanFit> {
+    let (x, emit_x) = fit_plane(points.iter().map(|p| (p.mx, p.sigma_x)))?;
+    let (y, emit_y) = fit_plane(points.iter().map(|p| (p.my, p.sigma_y)))?;
+    Some(ScanFit { x, y, emit_x, emit_y })
+}
+
+fn fit_plane(points: impl Iterator<Item = ((f64, f64), f64)>) -> Option<(Twiss, f64)> {
+    // Normal equations AᵀA·s = Aᵀb for s = (Σ11, Σ12, Σ22)
+    let mut ata = [[0.0; 3]; 3];
+    let mut atb = [0.0; 3];
+    let mut n = 0;
+    for ((m11, m12), sigma) in points {
+        let row = [m11 * m11, 2.0 * m11 * m12, m12 * m12];
+        for (i, ata_row) in ata.iter_mut().enumerate() {
+            for (j, v) in ata_row.iter_mut().enumerate() {
+                *v += row[i] * row[j];
+            }
+            atb[i] += row[i] * sigma * sigma;
+        }
+        n += 1;
+    }
+    if n < 3 { return None; }
+    let [s11, s12, s22] = solve3(ata, atb)?;
+    let emit2 = s11 * s22 - s12 * s12;
+    if s11 <= 0.0 || emit2 <= 0.0 { return None; }
+    let emit = emit2.sqrt();
+    Some((Twiss { beta: s11 / emit, alpha: -s12 / emit }, emit))
+}
+
+/// Cramer's rule; None when the scan didn't constrain all three terms.
+fn solve3(a: [[f64; 3]; 3], b: [f64; 3]) -> Option<[f64; 3]> {
+    let det = |m: [[f64; 3]; 3]| {
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    };
+    let d = det(a);
+    if d.abs() < 1e-12 * a.iter().flatten().map(|v| v.abs()).fold(0.0, f64::max).powi(3) { return None; }
+    let mut out = [0.0; 3];
+    for (col, slot) in out.iter_mut().enumerate() {
+        let mut m = a;
+        for (row, &v) in m.iter_mut().zip(&b) {
+            row[col] = v;
+        }
+        *slot = det(m) / d;
+    }
+    Some(out)
+}
@@ -0,0 +1,177 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::ui::hud;
+use crate::ui::theme::{Role, Theme};
+
+/// One Booster key command as the palette lists it.
+pub struct PaletteCommand {
+    /// Key the Booster's own handler takes for it
+    pub key: char,
+    pub keys: &'static str,
+    pub name: &'static str,
+    /// Hidden from the basic help bar; still in the palette
+    pub advanced: bool,
+}
+
+const fn basic(key: char, keys: &'static str, name: &'static str) -> PaletteCommand {
+    PaletteCommand { key, keys, name, advanced: false }
+}
+
+const fn advanced(key: char, keys: &'static str, name: &'static str) -> PaletteCommand {
+    PaletteCommand { key, keys, name, advanced: true }
+}
+
+pub const COMMANDS: &[PaletteCommand] = &[
+    basic(' ', "Space", "Inject beam on axis"),
+    basic('i', "I", "Inject at X/Y offset"),
+    basic('[', "[", "Previous cell"),
+    basic(']', "]", "Next cell"),
+    basic('z', "Z", "Zero selected corrector"),
+    basic('c', "C", "Copy cell correctors to all cells"),
+    basic('b', "B", "Bump mode: 3, 4, 5 cells, off"),
+    basic('+', "+", "Bigger adjust step"),
+    basic('-', "-", "Smaller adjust step"),
+    basic('v', "V", "Cycle display view"),
+    basic('.', ".", "Simulation speed"),
+    basic('p', "P", "Pause / resume"),
+    basic('r', "R", "Reset cycle"),
+    basic(':', ":", "Open console"),
+    advanced('f', "F", "RF voltage up"),
+    advanced('g', "G", "RF voltage down"),
+    advanced('t', "T", "Flip RF phase for transition"),
+    advanced('h', "H", "Harmonic cavity amplitude up"),
+    advanced('l', "L", "Harmonic cavity amplitude down"),
+    advanced('y', "Y", "Harmonic cavity phase back"),
+    advanced('u', "U", "Harmonic cavity phase forward"),
+    advanced('o', "O", "Harmonic multiple (report when over)"),
+    advanced('j', "J", "Quad bus trim (MQAT) up"),
+    advanced('k', "K", "Quad bus trim (MQAT) down"),
+    advanced('m', "M", "Bend bus trim (MDAT) up"),
+    advanced('n', "N", "Bend bus trim (MDAT) down"),
+    advanced('w', "W", "Bump horizontal trim up"),
+    advanced('s', "S", "Bump horizontal trim down"),
+    advanced('e', "E", "Bump vertical trim up"),
+    advanced('q', "Q", "Bump vertical trim down"),
+];
+
+/// Fuzzy match: every query character in order, case-insensitive. Lower
+/// scores are better: matches that start early and stay together.
+fn fuzzy_score(query: &str, text: &str) -> Option<usize> {
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut at = 0;
+    let mut last: Option<usize> = None;
+    for q in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let found = at + text[at..].iter().position(|&c| c == q)?;
+        score += match last {
+            None => found,
+            Some(prev) => (found - prev - 1) * 2,
+        };
+        last = Some(found);
+        at = found + 1;
+    }
+    Some(score)
+}
+
+/// Ctrl+P command palette: type to filter, Enter runs the pick.
+#[derive(Default)]
+pub struct Palette {
+    pub query: String,
+    pub selected: usize,
+}
+
+pub enum PaletteOutcome {
+    Open,
+    Close,
+    Run(char),
+}
+
+impl Palette {
+    /// Commands matching the query, best first; all of them when it's empty.
+    pub fn matches(&self) -> Vec<&'static PaletteCommand> {
+        let mut found: Vec<(usize, usize, &PaletteCommand)> = COMMANDS
+            .iter()
+            .enumerate()
+            .filter_map(|(i, c)| {
+                let by_name = fuzzy_score(&self.query, c.name);
+                // A bare key name ("f", "mqat") should find its command too
+                let by_key = fuzzy_score(&self.query, c.keys).filter(|_| self.query.chars().count() <= c.keys.len());
+                let score = by_name.into_iter().chain(by_key).min()?;
+                Some((score, i, c))
+            })
+            .collect();
+        found.sort_by_key(|&(score, i, _)| (score, i));
+        found.into_iter().map(|(_, _, c)| c).collect()
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> PaletteOutcome {
+        let count = self.matches().len();
+        match key.code {
+            KeyCode::Esc => return PaletteOutcome::Close,
+            KeyCode::Enter => {
+                return match self.matches().get(self.selected) {
+                    Some(command) => PaletteOutcome::Run(command.key),
+                    None => PaletteOutcome::Close,
+                };
+            }
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => self.selected = (self.selected + 1).min(count.saturating_sub(1)),
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.selected = 0;
+            }
+            KeyCode::Char(c) if self.query.len() < 32 => {
+                self.query.push(c);
+                self.selected = 0;
+            }
+            _ => {}
+        }
+        PaletteOutcome::Open
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let matches = self.matches();
+        let width = 56u16.min(area.width);
+        let height = (matches.len() as u16 + 5).clamp(7, 20).min(area.height);
+        let area = Rect::new(area.x + (area.width - width) / 2, area.y + (area.height - height) / 2, width, height);
+        frame.render_widget(Clear, area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.paint(Color::Rgb(100, 180, 255), Role::Blue)))
+            .title(" Commands ")
+            .title_style(Style::default().fg(theme.paint(Color::Rgb(120, 200, 255), Role::Blue)).add_modifier(Modifier::BOLD))
+            .title_bottom(hud::overlay_legend(&[("↑↓", "Select"), ("Enter", "Run"), ("Esc", "Close")], theme))
+            .style(Style::default().bg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled(" > ", Style::default().fg(theme.paint(Color::Rgb(255, 255, 100), Role::Yellow)).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{}_", self.query), Style::default().fg(theme.paint(Color::White, Role::Bright)).add_modifier(Modifier::BOLD)),
+            ]),
+            Line::from(""),
+        ];
+        if matches.is_empty() {
+            lines.push(Line::from(Span::styled("   No matching command", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint)))));
+        }
+        // Keep the selection in view
+        let rows = inner.height.saturating_sub(2) as usize;
+        let first = self.selected.saturating_sub(rows.saturating_sub(1));
+        for (i, command) in matches.iter().enumerate().skip(first).take(rows) {
+            let style = if i == self.selected {
+                Style::default().fg(theme.paint(Color::Rgb(10, 10, 18), Role::Background)).bg(theme.paint(Color::Rgb(120, 200, 255), Role::Blue)).add_modifier(Modifier::BOLD)
+            } else if command.advanced {
+                Style::default().fg(theme.paint(Color::Rgb(150, 150, 170), Role::Dim))
+            } else {
+                Style::default().fg(theme.paint(Color::Rgb(210, 210, 230), Role::Text))
+            };
+            let name_w = (inner.width as usize).saturating_sub(10);
+            lines.push(Line::from(Span::styled(format!(" {:<name_w$} {:>6} ", command.name, command.keys), style)));
+        }
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+}
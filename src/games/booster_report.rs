@@ -0,0 +1,139 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::games::booster_console::{Param, CORRECTOR_FIELDS};
+use crate::scores::{data_path, format_datetime};
+
+/// Parts of the ramp losses are booked against, in ramp order.
+pub const LOSS_PHASES: [&str; 6] = ["Injection", "Early ramp", "Pre-transition", "Transition", "Post-transition", "Extraction"];
+
+/// Text width of the report, so the file reads the same as the overlay.
+const WIDTH: usize = 64;
+
+/// A finished cycle written up for the shift log: how the machine was set,
+/// how the beam fared and where it was lost. Taken at extraction or loss.
+pub struct CycleReport {
+    pub finished: u64,
+    pub extracted: bool,
+    /// Why the cycle ended
+    pub outcome: String,
+    pub turn: u32,
+    pub turns_in_cycle: u32,
+    pub energy_gev: f64,
+    pub intensity: f64,
+    pub losses: f32,
+    pub max_losses: f32,
+    /// Losses per `LOSS_PHASES` entry
+    pub loss_by_phase: [f32; 6],
+    pub tunes: (f64, f64),
+    pub chromaticity: (f64, f64),
+    pub emittance_growth: (f64, f64),
+    pub mismatch: (f64, f64),
+    pub transition_crossed: bool,
+    /// Notch position against the kicker, in buckets
+    pub notch_error: f64,
+    pub params: Vec<(Param, f64)>,
+    /// One row per cell, in `CORRECTOR_FIELDS` order
+    pub correctors: Vec<[f64; 6]>,
+    /// Score terms, summing to the total
+    pub score_parts: Vec<(&'static str, u32)>,
+}
+
+impl CycleReport {
+    pub fn score(&self) -> u32 {
+        self.score_parts.iter().map(|(_, points)| points).sum()
+    }
+
+    /// The report as plain text lines, for the overlay and the file alike.
+    pub fn lines(&self) -> Vec<String> {
+        let rule = "-".repeat(WIDTH);
+        let mut out = vec![
+            format!("{:<36}{:>28}", "FERMILAB BOOSTER - CYCLE REPORT", format!("{} UTC", format_datetime(self.finished))),
+            "=".repeat(WIDTH),
+        ];
+        let outcome = if self.extracted { "EXTRACTED" } else { "BEAM LOST" };
+        out.push(format!("{:<22}{}  {}", "Outcome", outcome, self.outcome));
+        out.push(format!("{:<22}{} / {}  ({:.2} GeV)", "Turn", self.turn, self.turns_in_cycle, self.energy_gev));
+        out.push(format!("{:<22}{:.1}%", "Intensity delivered", self.intensity * 100.0));
+        out.push(format!("{:<22}{}", "Score", self.score()));
+
+        out.push(String::new());
+        out.push("KEY METRICS".to_string());
+        out.push(rule.clone());
+        out.push(format!("{:<22}{:>10}{:>10}", "", "x", "y"));
+        out.push(format!("{:<22}{:>10.3}{:>10.3}", "Tune", self.tunes.0, self.tunes.1));
+        out.push(format!("{:<22}{:>10.2}{:>10.2}", "Chromaticity", self.chromaticity.0, self.chromaticity.1));
+        out.push(format!("{:<22}{:>10.3}{:>10.3}", "Emittance growth", self.emittance_growth.0, self.emittance_growth.1));
+        out.push(format!("{:<22}{:>10.3}{:>10.3}", "Injection mismatch", self.mismatch.0, self.mismatch.1));
+        let transition = if self.transition_crossed { "crossed" } else { "not crossed" };
+        out.push(format!("{:<22}{}", "Transition", transition));
+        out.push(format!("{:<22}{:+.1} buckets", "Notch vs kicker", self.notch_error));
+
+        out.push(String::new());
+        out.push("LOSSES BY PHASE".to_string());
+        out.push(rule.clone());
+        for (name, &loss) in LOSS_PHASES.iter().zip(&self.loss_by_phase) {
+            let bar = "#".repeat(((loss / self.max_losses) * 30.0).round().clamp(0.0, 30.0) as usize);
+            out.push(format!("{:<22}{:>8.1}  {}", name, loss, bar));
+        }
+        out.push(format!("{:<22}{:>8.1}  of {:.0} allowed", "Total", self.losses, self.max_losses));
+
+        out.push(String::new());
+        out.push("MACHINE SETTINGS".to_string());
+        out.push(rule.clone());
+        for &(param, value) in &self.params {
+            out.push(format!("{:<22}{:>10.4}", param.name(), value));
+        }
+        let mut header = format!("{:<8}", "cell");
+        for field in CORRECTOR_FIELDS {
+            header.push_str(&format!("{:>9}", field));
+        }
+        out.push(String::new());
+        out.push(header);
+        let mut any = false;
+        for (cell, fields) in self.correctors.iter().enumerate() {
+            if fields.iter().all(|&v| v == 0.0) { continue; }
+            any = true;
+            let mut row = format!("{:<8}", format!("c{:02}", cell + 1));
+            for v in fields {
+                row.push_str(&format!("{:>9.4}", v));
+            }
+            out.push(row);
+        }
+        if !any {
+            out.push("(all correctors at zero)".to_string());
+        }
+
+        out.push(String::new());
+        out.push("SCORE".to_string());
+        out.push(rule);
+        for (label, points) in &self.score_parts {
+            out.push(format!("{:<22}{:>10}", label, points));
+        }
+        out.push(format!("{:<22}{:>10}", "Total", self.score()));
+        out
+    }
+
+    /// Write to the reports folder; returns the file name on success.
+    pub fn save(&self) -> Option<String> {
+        let dir = report_dir();
+        fs::create_dir_all(&dir).ok()?;
+        let stamp: String = format_datetime(self.finished)
+            .chars()
+            .filter_map(|c| match c {
+                ' ' => Some('-'),
+                ':' => None,
+                c => Some(c),
+            })
+            .collect();
+        let file_name = format!("booster-{}.txt", stamp);
+        let mut text = self.lines().join("\n");
+        text.push('\n');
+        fs::write(dir.join(&file_name), text).ok()?;
+        Some(file_name)
+    }
+}
+
+fn report_dir() -> PathBuf {
+    data_path("reports")
+}
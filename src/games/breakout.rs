@@ -1,11 +1,42 @@
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::prelude::*;
 use ratatui::widgets::*;
 
-use crate::games::Game;
+use crate::audio::{self, AudioEvent};
+use crate::games::breakout_levels::{ArtLevel, ROW_BRICKS};
+use crate::games::{Game, GameInfo};
+use crate::session::{clear_progress, load_progress, save_progress, StateReader, StateWriter};
+use crate::ui::braille::BrailleCanvas;
+use crate::ui::fx::{self, Emitter, Particles};
+use crate::ui::hud::{self, Countdown};
+use crate::ui::theme::{Ink, Role, Theme};
 
 const BRICK_ROWS: usize = 6;
 const BRICKS_PER_ROW: usize = 12;
+const CAMPAIGN_SAVE: &str = "breakout";
+const BASE_SPEED: f32 = 0.35;
+const MAX_START_SPEED: f32 = 0.55;
+/// Chips knocked off a brick, falling back down the field
+const BRICK_CHIPS: Emitter = Emitter::burst(8, 0.35, 22).with_gravity(0.02);
+/// Furthest the assist moves the paddle per tick; well under a key press
+const ASSIST_NUDGE: f32 = 0.15;
+/// Score multiplier lost for each return the assist helped with
+const ASSIST_PENALTY: f32 = 0.05;
+const ASSIST_MIN_MULTIPLIER: f32 = 0.5;
+/// Demo paddle speed per tick
+const DEMO_SPEED: f32 = 1.0;
+/// Ticks the demo waits before launching or starting over
+const DEMO_PAUSE: u32 = 60;
+/// How far one key press moves a paddle
+const PADDLE_STEP: f32 = 2.0;
+/// Bounces the path guide looks ahead
+const GUIDE_BOUNCES: u32 = 2;
+/// Brick points are scaled by this while the guide shows outside practice
+const GUIDE_MULTIPLIER: f32 = 0.8;
+/// Ticks between the guide's dots
+const GUIDE_DOT_TICKS: usize = 3;
+/// Furthest the guide follows the ball, in ticks
+const GUIDE_MAX_TICKS: usize = 2000;
 
 #[derive(Clone)]
 struct Brick {
@@ -13,7 +44,7 @@ struct Brick {
     y: f32,
     width: f32,
     alive: bool,
-    color: Color,
+    color: Ink,
     points: u32,
 }
 
@@ -32,12 +63,46 @@ pub struct Breakout {
     game_over: bool,
     won: bool,
     paused: bool,
+    countdown: Countdown,
     launched: bool,
     tick: u64,
     // Dynamic dimensions
     field_width: f32,
     field_height: f32,
     paddle_y: f32,
+    // Campaign
+    level: u32,
+    /// Saved (level reached, campaign score), offered on the start screen
+    saved_campaign: Option<(u32, u32)>,
+    start_screen: bool,
+    particles: Particles,
+    show_hitboxes: bool,
+    /// Where the field was last drawn, for the pointer
+    field_area: Rect,
+    /// Attract-mode demo: the AI plays and nothing is saved
+    demo: bool,
+    demo_wait: u32,
+    /// Assist paddle: nudges toward where the ball will land
+    assist: bool,
+    /// Whether the assist has moved the paddle since the last return
+    assisted_ball: bool,
+    /// Returns the assist helped with this game, which cost multiplier
+    assists_used: u32,
+    /// Path guide: dots along the ball's way through its next bounces
+    guide: bool,
+    practice: bool,
+    /// Text art fields from the levels folder
+    art_levels: Vec<ArtLevel>,
+    /// 0 is the classic field, then one per `art_levels` entry
+    layout: usize,
+    /// Co-op: a second paddle on Z / X, each keeping to its half
+    coop: bool,
+    paddle2_x: f32,
+    /// Player whose paddle holds the ball before launch
+    server: usize,
+    /// Player who last returned the ball, credited with the bricks it breaks
+    last_touch: usize,
+    player_points: [u32; 2],
 }
 
 impl Breakout {
@@ -51,9 +116,9 @@ impl Breakout {
             paddle_width: pw,
             ball_x: fw / 2.0,
             ball_y: py - 1.0,
-            ball_dx: 0.35,
-            ball_dy: -0.35,
-            ball_speed: 0.35,
+            ball_dx: BASE_SPEED,
+            ball_dy: -BASE_SPEED,
+            ball_speed: BASE_SPEED,
             bricks: Vec::new(),
             score: 0,
             high_score: 0,
@@ -61,46 +126,355 @@ impl Breakout {
             game_over: false,
             won: false,
             paused: false,
+            countdown: Countdown::default(),
             launched: false,
             tick: 0,
             field_width: fw,
             field_height: fh,
             paddle_y: py,
+            level: 1,
+            saved_campaign: None,
+            start_screen: false,
+            particles: Particles::new(31),
+            show_hitboxes: false,
+            field_area: Rect::default(),
+            demo: false,
+            demo_wait: 0,
+            assist: false,
+            assisted_ball: false,
+            assists_used: 0,
+            guide: false,
+            practice: false,
+            art_levels: ArtLevel::load_all(),
+            layout: 0,
+            coop: false,
+            paddle2_x: fw / 2.0 - pw / 2.0,
+            server: 0,
+            last_touch: 0,
+            player_points: [0; 2],
         };
+        b.saved_campaign = Self::load_campaign();
+        b.start_screen = b.saved_campaign.is_some();
         b.init_bricks();
         b
     }
 
     fn init_bricks(&mut self) {
         self.bricks.clear();
-        let colors = [
-            Color::Rgb(220, 50, 50),   // Red
-            Color::Rgb(220, 130, 30),  // Orange
-            Color::Rgb(220, 200, 30),  // Yellow
-            Color::Rgb(50, 200, 50),   // Green
-            Color::Rgb(50, 130, 220),  // Blue
-            Color::Rgb(150, 50, 220),  // Purple
-        ];
-        let points = [60, 50, 40, 30, 20, 10];
+        if let Some(art) = self.art_level() {
+            let brick_width = self.field_width / art.columns() as f32;
+            self.bricks = art.cells.iter().enumerate()
+                .flat_map(|(row, cells)| cells.iter().enumerate().filter_map(move |(col, cell)| {
+                    let (color, points) = (*cell)?;
+                    Some(Brick {
+                        x: col as f32 * brick_width,
+                        y: 2.0 + row as f32 * 1.5,
+                        width: brick_width,
+                        alive: true,
+                        color,
+                        points,
+                    })
+                }))
+                .collect();
+            return;
+        }
         let brick_width = self.field_width / BRICKS_PER_ROW as f32;
         
         for row in 0..BRICK_ROWS {
             for col in 0..BRICKS_PER_ROW {
+                let (color, points) = ROW_BRICKS[row % ROW_BRICKS.len()];
                 self.bricks.push(Brick {
                     x: col as f32 * brick_width,
                     y: 2.0 + row as f32 * 1.5,
                     width: brick_width,
                     alive: true,
-                    color: colors[row % colors.len()],
-                    points: points[row % points.len()],
+                    color,
+                    points,
                 });
             }
         }
     }
 
-    fn reset_ball(&mut self) {
-        self.ball_x = self.paddle_x + self.paddle_width / 2.0;
+    /// The text art field in play, if not the classic one.
+    fn art_level(&self) -> Option<&ArtLevel> {
+        self.layout.checked_sub(1).and_then(|i| self.art_levels.get(i))
+    }
+
+    fn layout_name(&self) -> &str {
+        self.art_level().map_or("Classic", |art| &art.name)
+    }
+
+    /// Step to the next brick field; only before the first ball of a level.
+    fn cycle_layout(&mut self) {
+        self.art_levels = ArtLevel::load_all();
+        self.layout = (self.layout + 1) % (self.art_levels.len() + 1);
+        self.init_bricks();
+    }
+
+    /// Launch speed for a level; each level starts a little faster.
+    fn level_speed(level: u32) -> f32 {
+        (BASE_SPEED + 0.03 * level.saturating_sub(1) as f32).min(MAX_START_SPEED)
+    }
+
+    fn load_campaign() -> Option<(u32, u32)> {
+        let data = load_progress(CAMPAIGN_SAVE)?;
+        let mut r = StateReader::new(&data);
+        let (level, score) = (r.u32()?, r.u32()?);
+        (level > 1).then_some((level, score))
+    }
+
+    /// Record the level the player has reached and the score carried into it.
+    fn save_campaign(&mut self, level: u32) {
+        let mut w = StateWriter::new();
+        w.u32(level);
+        w.u32(self.score);
+        save_progress(CAMPAIGN_SAVE, &w.finish());
+        self.saved_campaign = Some((level, self.score));
+    }
+
+    /// Re-read the campaign save after it was deleted from outside the game.
+    pub fn reload_campaign(&mut self) {
+        self.saved_campaign = Self::load_campaign();
+        if self.start_screen && self.saved_campaign.is_none() {
+            self.start_campaign(false);
+        }
+    }
+
+    /// Leave the start screen, either resuming the saved campaign or starting over.
+    fn start_campaign(&mut self, resume: bool) {
+        match self.saved_campaign.filter(|_| resume) {
+            Some((level, score)) => {
+                self.level = level;
+                self.score = score;
+            }
+            None => {
+                clear_progress(CAMPAIGN_SAVE);
+                self.saved_campaign = None;
+                self.level = 1;
+                self.score = 0;
+            }
+        }
+        self.start_screen = false;
+        self.ball_speed = Self::level_speed(self.level);
+        self.init_bricks();
+        self.reset_ball();
+    }
+
+    fn next_level(&mut self) {
+        self.level += 1;
+        self.particles.clear();
+        self.won = false;
+        self.ball_speed = Self::level_speed(self.level);
+        self.init_bricks();
+        self.reset_ball();
+    }
+
+    fn launch(&mut self) {
+        self.launched = true;
+        self.last_touch = self.server;
+        (self.ball_dx, self.ball_dy) = self.launch_velocity();
+    }
+
+    fn launch_velocity(&self) -> (f32, f32) {
+        (self.ball_speed * 0.7, -self.ball_speed)
+    }
+
+    /// Brick points are scaled by this; each assisted return lowers it,
+    /// and so does the path guide while it shows outside practice.
+    fn score_multiplier(&self) -> f32 {
+        let multiplier = (1.0 - ASSIST_PENALTY * self.assists_used as f32).max(ASSIST_MIN_MULTIPLIER);
+        if self.guide && !self.practice { multiplier * GUIDE_MULTIPLIER } else { multiplier }
+    }
+
+    /// Path guide on, as the accessibility setting has it; G in the game
+    /// still toggles it.
+    pub fn set_guide(&mut self, on: bool) {
+        self.guide = on;
+    }
+
+    fn guide_shown(&self) -> bool {
+        (self.guide || self.practice) && !self.demo && !self.game_over && !self.won && !self.start_screen
+    }
+
+    /// The first live brick, other than those in `gone`, whose zone holds
+    /// the ball's centre at (`x`, `y`).
+    fn brick_at(&self, x: f32, y: f32, gone: &[usize]) -> Option<usize> {
+        self.bricks.iter().enumerate().position(|(i, brick)| {
+            brick.alive
+                && !gone.contains(&i)
+                && x >= brick.x - 0.5
+                && x < brick.x + brick.width + 0.5
+                && y >= brick.y - 0.5
+                && y < brick.y + 1.5
+        })
+    }
+
+    /// Whether the ball at (`x`, `y`) meets `brick` on an end rather than
+    /// its top or bottom, so it turns back sideways.
+    fn hits_brick_end(brick: &Brick, x: f32, y: f32) -> bool {
+        let (cx, cy) = (brick.x + brick.width / 2.0, brick.y + 0.75);
+        (x - cx).abs() / brick.width > (y - cy).abs() / 1.5
+    }
+
+    /// Where the ball goes through its next `GUIDE_BOUNCES` bounces off
+    /// walls and bricks, tick by tick as `move_ball` steps it, stopping
+    /// where it comes down to the paddle. Bricks it hits on the way count
+    /// as broken.
+    fn predict_path(&self) -> Vec<(f32, f32)> {
+        let (mut x, mut y) = (self.ball_x, self.ball_y);
+        let (mut dx, mut dy) = if self.launched { (self.ball_dx, self.ball_dy) } else { self.launch_velocity() };
+        let mut path = vec![(x, y)];
+        let mut broken = Vec::new();
+        let mut bounces = 0;
+        for _ in 0..GUIDE_MAX_TICKS {
+            x += dx;
+            y += dy;
+            if x <= 0.5 {
+                x = 0.5;
+                bounces += (dx < 0.0) as u32;
+                dx = dx.abs();
+            }
+            if x >= self.field_width - 1.5 {
+                x = self.field_width - 1.5;
+                bounces += (dx > 0.0) as u32;
+                dx = -dx.abs();
+            }
+            if y <= 0.5 {
+                y = 0.5;
+                bounces += (dy < 0.0) as u32;
+                dy = dy.abs();
+            }
+            path.push((x, y));
+            if dy > 0.0 && y >= self.paddle_y - 0.5 { break; }
+            if let Some(idx) = self.brick_at(x, y, &broken) {
+                if Self::hits_brick_end(&self.bricks[idx], x, y) { dx = -dx } else { dy = -dy }
+                broken.push(idx);
+                bounces += 1;
+            }
+            if bounces >= GUIDE_BOUNCES { break; }
+        }
+        path
+    }
+
+    /// Where the falling ball will reach the paddle, bouncing off the side
+    /// walls on the way. Bricks are ignored: below them there are none.
+    fn predict_landing(&self) -> Option<f32> {
+        if !self.launched || self.ball_dy <= 0.0 { return None; }
+        let ticks = (self.paddle_y - 0.5 - self.ball_y) / self.ball_dy;
+        if ticks < 0.0 { return None; }
+        let (lo, hi) = (0.5, self.field_width - 1.5);
+        let span = hi - lo;
+        // Unfold the reflections into a straight line, then fold it back
+        let x = (self.ball_x + self.ball_dx * ticks - lo).rem_euclid(2.0 * span);
+        Some(lo + if x > span { 2.0 * span - x } else { x })
+    }
+
+    /// Whether the second paddle is in play; the demo always plays alone.
+    fn coop_active(&self) -> bool {
+        self.coop && !self.demo
+    }
+
+    /// Left edge of each paddle in play, player 1 first.
+    fn paddles(&self) -> Vec<f32> {
+        if self.coop_active() { vec![self.paddle_x, self.paddle2_x] } else { vec![self.paddle_x] }
+    }
+
+    /// Where a player's paddle may go: the whole field alone, its own
+    /// half in co-op so the two never overlap.
+    fn paddle_range(&self, player: usize) -> (f32, f32) {
+        let (lo, hi) = (0.5, self.field_width - self.paddle_width - 0.5);
+        if !self.coop_active() { return (lo, hi); }
+        let mid = self.field_width / 2.0;
+        if player == 0 { (lo, (mid - self.paddle_width).max(lo)) } else { (mid.min(hi), hi) }
+    }
+
+    /// Move a player's paddle by `dx`, carrying the ball if it's theirs to serve.
+    fn move_paddle(&mut self, player: usize, dx: f32) {
+        let (lo, hi) = self.paddle_range(player);
+        let x = if player == 0 { &mut self.paddle_x } else { &mut self.paddle2_x };
+        *x = (*x + dx).clamp(lo, hi);
+        if !self.launched {
+            self.hold_ball();
+        }
+    }
+
+    /// Sit the ball on the serving paddle.
+    fn hold_ball(&mut self) {
+        let px = self.paddles().get(self.server).copied().unwrap_or(self.paddle_x);
+        self.ball_x = px + self.paddle_width / 2.0;
         self.ball_y = self.paddle_y - 1.0;
+    }
+
+    /// Both paddles centred in their halves, or the one paddle centred.
+    fn place_paddles(&mut self) {
+        let fw = self.field_width;
+        if self.coop {
+            self.paddle_x = fw / 4.0 - self.paddle_width / 2.0;
+            self.paddle2_x = fw * 0.75 - self.paddle_width / 2.0;
+        } else {
+            self.paddle_x = fw / 2.0 - self.paddle_width / 2.0;
+        }
+        self.server = 0;
+        self.hold_ball();
+    }
+
+    /// Switch co-op on or off; only before the first ball of a game.
+    fn toggle_coop(&mut self) {
+        self.coop = !self.coop;
+        self.player_points = [0; 2];
+        self.place_paddles();
+    }
+
+    /// Move player 1's paddle centre toward `target` by at most `max_step`;
+    /// returns whether it moved.
+    fn steer_paddle(&mut self, target: f32, max_step: f32) -> bool {
+        let step = (target - (self.paddle_x + self.paddle_width / 2.0)).clamp(-max_step, max_step);
+        let before = self.paddle_x;
+        self.move_paddle(0, step);
+        (self.paddle_x - before).abs() > 1e-3
+    }
+
+    /// Start a throwaway demo game from level 1.
+    pub fn start_demo(&mut self) {
+        self.reset();
+        self.start_screen = false;
+        self.demo = true;
+    }
+
+    /// One tick of the demo AI: launch after a pause, then meet the ball at
+    /// the point of the paddle that sends it at the nearest low brick.
+    fn demo_step(&mut self) {
+        if self.game_over || self.won || !self.launched {
+            self.demo_wait += 1;
+            if self.demo_wait < DEMO_PAUSE { return; }
+            self.demo_wait = 0;
+            if self.game_over {
+                self.start_demo();
+            } else if self.won {
+                self.next_level();
+            } else {
+                self.launch();
+            }
+            return;
+        }
+        let Some(landing) = self.predict_landing() else {
+            self.steer_paddle(self.ball_x, DEMO_SPEED);
+            return;
+        };
+        let target = self.bricks.iter().filter(|b| b.alive).min_by(|a, b| {
+            let cost = |brick: &Brick| (brick.x + brick.width / 2.0 - landing).abs() - brick.y * 4.0;
+            cost(a).total_cmp(&cost(b))
+        });
+        let Some(target) = target else { return };
+        // The return angle comes from where the ball meets the paddle
+        let rise = (self.paddle_y - target.y) / self.ball_dy.abs().max(0.15);
+        let dx = (target.x + target.width / 2.0 - landing) / rise;
+        let hit_pos = (0.5 + dx / (3.0 * self.ball_speed)).clamp(0.05, 0.95);
+        self.steer_paddle(landing - (hit_pos - 0.5) * self.paddle_width, DEMO_SPEED);
+    }
+
+    fn reset_ball(&mut self) {
+        self.hold_ball();
         self.ball_dx = self.ball_speed;
         self.ball_dy = -self.ball_speed;
         self.launched = false;
@@ -108,8 +482,7 @@ impl Breakout {
 
     fn move_ball(&mut self) {
         if !self.launched {
-            self.ball_x = self.paddle_x + self.paddle_width / 2.0;
-            self.ball_y = self.paddle_y - 1.0;
+            self.hold_ball();
             return;
         }
 
@@ -133,9 +506,12 @@ impl Breakout {
         // Ball falls below paddle
         if self.ball_y >= self.field_height {
             self.lives = self.lives.saturating_sub(1);
+            self.assisted_ball = false;
+            // In co-op whoever's half the ball fell in serves the next one
+            self.server = if self.coop_active() && self.ball_x >= self.field_width / 2.0 { 1 } else { 0 };
             if self.lives == 0 {
                 self.game_over = true;
-                if self.score > self.high_score {
+                if self.score > self.high_score && !self.demo {
                     self.high_score = self.score;
                 }
             } else {
@@ -145,14 +521,21 @@ impl Breakout {
         }
 
         // Paddle collision
-        if self.ball_dy > 0.0
-            && self.ball_y >= self.paddle_y - 0.5
-            && self.ball_y <= self.paddle_y + 1.0
-            && self.ball_x >= self.paddle_x - 0.5
-            && self.ball_x <= self.paddle_x + self.paddle_width + 0.5
-        {
+        let hit = self.paddles().into_iter().enumerate().find(|&(_, px)| {
+            self.ball_x >= px - 0.5 && self.ball_x <= px + self.paddle_width + 0.5
+        });
+        if let Some((player, px)) = hit.filter(|_| {
+            self.ball_dy > 0.0 && self.ball_y >= self.paddle_y - 0.5 && self.ball_y <= self.paddle_y + 1.0
+        }) {
             self.ball_dy = -self.ball_dy.abs();
-            let hit_pos = (self.ball_x - self.paddle_x) / self.paddle_width;
+            self.last_touch = player;
+            if !self.demo {
+                audio::emit(AudioEvent::Bounce);
+            }
+            if std::mem::take(&mut self.assisted_ball) {
+                self.assists_used += 1;
+            }
+            let hit_pos = (self.ball_x - px) / self.paddle_width;
             self.ball_dx = self.ball_speed * (hit_pos - 0.5) * 3.0;
             if self.ball_dy.abs() < 0.15 {
                 self.ball_dy = -0.15;
@@ -164,43 +547,67 @@ impl Breakout {
         }
 
         // Brick collisions
-        let mut hit_idx = None;
-        for (i, brick) in self.bricks.iter().enumerate() {
-            if !brick.alive { continue; }
-            if self.ball_x >= brick.x - 0.5
-                && self.ball_x < brick.x + brick.width + 0.5
-                && self.ball_y >= brick.y - 0.5
-                && self.ball_y < brick.y + 1.5
-            {
-                hit_idx = Some(i);
-                break;
-            }
-        }
-        if let Some(idx) = hit_idx {
+        if let Some(idx) = self.brick_at(self.ball_x, self.ball_y, &[]) {
             let brick = &self.bricks[idx];
             let cx = brick.x + brick.width / 2.0;
             let cy = brick.y + 0.75;
-            let dx = self.ball_x - cx;
-            let dy = self.ball_y - cy;
-            if dx.abs() / brick.width > dy.abs() / 1.5 {
+            if Self::hits_brick_end(brick, self.ball_x, self.ball_y) {
                 self.ball_dx = -self.ball_dx;
             } else {
                 self.ball_dy = -self.ball_dy;
             }
-            self.score += self.bricks[idx].points;
+            let points = (self.bricks[idx].points as f32 * self.score_multiplier()).round() as u32;
+            self.score += points;
+            if self.coop_active() {
+                self.player_points[self.last_touch] += points;
+            }
             self.bricks[idx].alive = false;
+            let Ink(color, role) = self.bricks[idx].color;
+            if let Color::Rgb(r, g, b) = color {
+                self.particles.emit(&BRICK_CHIPS, cx, cy, &[Ink(color, role), Ink(Color::Rgb(r / 2 + 100, g / 2 + 100, b / 2 + 100), role)]);
+            }
 
             if self.bricks.iter().all(|b| !b.alive) {
                 self.won = true;
-                if self.score > self.high_score {
-                    self.high_score = self.score;
+                if !self.demo {
+                    audio::emit(AudioEvent::LevelUp);
+                    self.high_score = self.high_score.max(self.score);
+                    self.save_campaign(self.level + 1);
                 }
             }
             self.ball_speed = (self.ball_speed + 0.003).min(0.7);
         }
     }
 
-    fn render_field(&self, width: usize, height: usize) -> Vec<Line<'static>> {
+    /// Continue / new campaign prompt drawn over the field.
+    fn render_start_screen(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let Some((level, score)) = self.saved_campaign else { return };
+        let w = 40.min(area.width);
+        let h = 8.min(area.height);
+        let rect = Rect::new(area.x + (area.width - w) / 2, area.y + (area.height - h) / 2, w, h);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.paint(Color::Rgb(220, 80, 80), Role::Red)))
+            .title(" Campaign ")
+            .title_style(Style::default().fg(theme.paint(Color::Rgb(255, 100, 100), Role::Red)).add_modifier(Modifier::BOLD))
+            .style(Style::default().bg(theme.paint(Color::Rgb(10, 10, 20), Role::Background)));
+        let key = Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD);
+        let text = Style::default().fg(theme.paint(Color::Gray, Role::Dim));
+        let lines = vec![
+            Line::from(Span::styled(
+                format!("Reached level {}  ·  {} pts", level, score),
+                Style::default().fg(theme.paint(Color::Rgb(180, 200, 255), Role::Blue)),
+            )),
+            Line::from(""),
+            Line::from(vec![Span::styled("ENTER / C ", key), Span::styled(format!("Continue at level {}", level), text)]),
+            Line::from(vec![Span::styled("N ", key), Span::styled("New campaign (clears progress)", text)]),
+        ];
+        frame.render_widget(Clear, rect);
+        frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center).block(block), rect);
+    }
+
+    fn render_field(&self, width: usize, height: usize, theme: &Theme) -> Vec<Line<'static>> {
         let w = width;
         let h = height;
 
@@ -209,21 +616,30 @@ impl Breakout {
         let sy = h as f32 / self.field_height;
 
         let mut grid: Vec<Vec<(char, Style)>> =
-            vec![vec![(' ', Style::default().bg(Color::Rgb(10, 10, 20))); w]; h];
+            vec![vec![(' ', Style::default().bg(theme.paint(Color::Rgb(10, 10, 20), Role::Background))); w]; h];
+
+        // Path guide first, so walls, bricks and paddles cover it
+        if self.guide_shown() {
+            let mut dots = BrailleCanvas::new(w, h);
+            for &(x, y) in self.predict_path().iter().step_by(GUIDE_DOT_TICKS).skip(1) {
+                dots.set((x * sx * 2.0) as i32, (y * sy * 4.0) as i32);
+            }
+            dots.draw(&mut grid, theme.paint(Color::Rgb(110, 170, 130), Role::Green), None, false);
+        }
 
         // Draw walls
         for y in 0..h {
-            grid[y][0] = ('│', Style::default().fg(Color::Rgb(60, 60, 80)).bg(Color::Rgb(10, 10, 20)));
+            grid[y][0] = ('│', Style::default().fg(theme.paint(Color::Rgb(60, 60, 80), Role::Faint)).bg(theme.paint(Color::Rgb(10, 10, 20), Role::Background)));
             if w > 1 {
-                grid[y][w - 1] = ('│', Style::default().fg(Color::Rgb(60, 60, 80)).bg(Color::Rgb(10, 10, 20)));
+                grid[y][w - 1] = ('│', Style::default().fg(theme.paint(Color::Rgb(60, 60, 80), Role::Faint)).bg(theme.paint(Color::Rgb(10, 10, 20), Role::Background)));
             }
         }
         for x in 0..w {
-            grid[0][x] = ('─', Style::default().fg(Color::Rgb(60, 60, 80)).bg(Color::Rgb(10, 10, 20)));
+            grid[0][x] = ('─', Style::default().fg(theme.paint(Color::Rgb(60, 60, 80), Role::Faint)).bg(theme.paint(Color::Rgb(10, 10, 20), Role::Background)));
         }
         if w > 0 && h > 0 {
-            grid[0][0] = ('╭', Style::default().fg(Color::Rgb(60, 60, 80)).bg(Color::Rgb(10, 10, 20)));
-            grid[0][w - 1] = ('╮', Style::default().fg(Color::Rgb(60, 60, 80)).bg(Color::Rgb(10, 10, 20)));
+            grid[0][0] = ('╭', Style::default().fg(theme.paint(Color::Rgb(60, 60, 80), Role::Faint)).bg(theme.paint(Color::Rgb(10, 10, 20), Role::Background)));
+            grid[0][w - 1] = ('╮', Style::default().fg(theme.paint(Color::Rgb(60, 60, 80), Role::Faint)).bg(theme.paint(Color::Rgb(10, 10, 20), Role::Background)));
         }
 
         // Draw bricks
@@ -243,7 +659,7 @@ impl Breakout {
                         } else {
                             '█'
                         };
-                        grid[by][bx] = (ch, Style::default().fg(brick.color).bg(Color::Rgb(10, 10, 20)));
+                        grid[by][bx] = (ch, Style::default().fg(theme.ink(brick.color)).bg(theme.paint(Color::Rgb(10, 10, 20), Role::Background)));
                     }
                 }
                 // Shadow row below
@@ -252,19 +668,24 @@ impl Breakout {
                     for bx in bx_start..bx_end.min(w) {
                         if bx < w && grid[shadow_y][bx].0 == ' ' {
                             grid[shadow_y][bx] = ('░', Style::default()
-                                .fg(Color::Rgb(30, 30, 40))
-                                .bg(Color::Rgb(10, 10, 20)));
+                                .fg(theme.paint(Color::Rgb(30, 30, 40), Role::Panel))
+                                .bg(theme.paint(Color::Rgb(10, 10, 20), Role::Background)));
                         }
                     }
                 }
             }
         }
 
-        // Draw paddle
-        let px_start = (self.paddle_x * sx) as usize;
-        let px_end = ((self.paddle_x + self.paddle_width) * sx) as usize;
+        // Brick chips
+        self.particles.draw(&mut grid, (sx * 2.0, sy * 4.0), 1.0, theme);
+
+        // Draw paddles: player 1 blue, player 2 orange
         let py = (self.paddle_y * sy) as usize;
-        if py < h {
+        let colors = [(theme.paint(Color::Rgb(180, 200, 255), Role::Blue), theme.paint(Color::Rgb(30, 50, 120), Role::Blue)), (theme.paint(Color::Rgb(255, 220, 170), Role::Orange), theme.paint(Color::Rgb(130, 60, 20), Role::Orange))];
+        for (paddle_x, (fg, bg)) in self.paddles().into_iter().zip(colors) {
+            if py >= h { break; }
+            let px_start = (paddle_x * sx) as usize;
+            let px_end = ((paddle_x + self.paddle_width) * sx) as usize;
             for px in px_start..px_end.min(w) {
                 if px < w {
                     let ch = if px == px_start {
@@ -277,8 +698,8 @@ impl Breakout {
                         '═'
                     };
                     grid[py][px] = (ch, Style::default()
-                        .fg(Color::Rgb(180, 200, 255))
-                        .bg(Color::Rgb(30, 50, 120))
+                        .fg(fg)
+                        .bg(bg)
                         .add_modifier(Modifier::BOLD));
                 }
             }
@@ -289,8 +710,8 @@ impl Breakout {
         let by = (self.ball_y * sy) as usize;
         if bx < w && by < h {
             grid[by][bx] = ('●', Style::default()
-                .fg(Color::Rgb(255, 255, 255))
-                .bg(Color::Rgb(10, 10, 20))
+                .fg(theme.paint(Color::Rgb(255, 255, 255), Role::Bright))
+                .bg(theme.paint(Color::Rgb(10, 10, 20), Role::Background))
                 .add_modifier(Modifier::BOLD));
             // Ball trail
             let trail_x = (self.ball_x - self.ball_dx * 2.0) * sx;
@@ -299,11 +720,15 @@ impl Breakout {
             let ty = trail_y as usize;
             if tx < w && ty < h && (tx != bx || ty != by) {
                 grid[ty][tx] = ('·', Style::default()
-                    .fg(Color::Rgb(100, 100, 120))
-                    .bg(Color::Rgb(10, 10, 20)));
+                    .fg(theme.paint(Color::Rgb(100, 100, 120), Role::Faint))
+                    .bg(theme.paint(Color::Rgb(10, 10, 20), Role::Background)));
             }
         }
 
+        if self.show_hitboxes {
+            self.draw_hitboxes(&mut grid, sx * 2.0, sy * 4.0, theme);
+        }
+
         grid.into_iter()
             .map(|row| {
                 let spans: Vec<Span<'static>> = row
@@ -316,48 +741,128 @@ impl Breakout {
     }
 }
 
+impl Breakout {
+    /// The zones `move_ball` tests the ball's centre against, in braille
+    /// dots (`dx`, `dy` per field unit).
+    fn draw_hitboxes(&self, grid: &mut [Vec<(char, Style)>], dx: f32, dy: f32, theme: &Theme) {
+        let cols = grid.first().map_or(0, |row| row.len());
+        let mut boxes = BrailleCanvas::new(cols, grid.len());
+        let mut zone = |x0: f32, y0: f32, x1: f32, y1: f32| {
+            boxes.rect((x0 * dx) as i32, (y0 * dy) as i32, (x1 * dx) as i32 + 1, (y1 * dy) as i32 + 1);
+        };
+        for brick in self.bricks.iter().filter(|b| b.alive) {
+            zone(brick.x - 0.5, brick.y - 0.5, brick.x + brick.width + 0.5, brick.y + 1.5);
+        }
+        for px in self.paddles() {
+            zone(px - 0.5, self.paddle_y - 0.5, px + self.paddle_width + 0.5, self.paddle_y + 1.0);
+        }
+        let (x, y) = ((self.ball_x * dx) as i32, (self.ball_y * dy) as i32);
+        boxes.line(x - 1, y, x + 1, y);
+        boxes.line(x, y - 1, x, y + 1);
+        boxes.draw(grid, theme.ink(fx::HITBOX), None, false);
+    }
+}
+
+pub const INFO: GameInfo = GameInfo {
+    name: "Breakout",
+    icon: "🧱",
+    author: "RustCade",
+    blurb: "Smash bricks\nwith the ball!",
+    description: "Smash all the bricks!",
+    controls: &[
+        ("← / →", "Move paddle"),
+        ("Mouse", "Paddle follows the pointer, click to launch"),
+        ("Space", "Launch ball"),
+        ("L", "Brick layout (before launch)"),
+        ("C", "Co-op: second paddle on Z / X"),
+        ("A / D", "Assist paddle / AI demo"),
+        ("G", "Path guide (bricks score less)"),
+        ("R", "Restart"),
+        ("P", "Pause"),
+    ],
+    tags: &["arcade", "physics", "retro"],
+    color: Ink(Color::Rgb(220, 80, 80), Role::Red),
+    border_color: Ink(Color::Rgb(120, 40, 40), Role::Red),
+};
+
 impl Game for Breakout {
+    fn info(&self) -> &'static GameInfo {
+        &INFO
+    }
+
     fn update(&mut self) {
-        if self.game_over || self.won || self.paused { return; }
+        if self.paused || self.countdown.tick() { return; }
+        self.particles.update();
+        if self.demo {
+            self.demo_step();
+        }
+        if self.game_over || self.won || self.start_screen { return; }
         self.tick += 1;
+        if self.assist && !self.demo {
+            if let Some(landing) = self.predict_landing() {
+                self.assisted_ball |= self.steer_paddle(landing, ASSIST_NUDGE);
+            }
+        }
         self.move_ball();
     }
 
     fn handle_input(&mut self, key: KeyEvent) {
+        // Any key ends the demo
+        if self.demo {
+            self.reset();
+            return;
+        }
         match key.code {
             KeyCode::Char('r') | KeyCode::Char('R') => self.reset(),
+            // Paused games only answer to P and R
+            KeyCode::Char('d') | KeyCode::Char('D') if !self.paused => self.start_demo(),
+            KeyCode::Char('a') | KeyCode::Char('A') if !self.paused => self.assist = !self.assist,
+            KeyCode::Char('g') | KeyCode::Char('G') if !self.paused => self.guide = !self.guide,
             KeyCode::Char('p') | KeyCode::Char('P') => {
-                if !self.game_over && !self.won {
-                    self.paused = !self.paused;
+                if !self.game_over && !self.won && !self.start_screen {
+                    self.countdown.toggle_pause(&mut self.paused);
                 }
             }
             _ => {
-                if self.game_over || self.won {
+                if self.start_screen {
+                    match key.code {
+                        KeyCode::Enter | KeyCode::Char(' ') | KeyCode::Char('c') | KeyCode::Char('C') => {
+                            self.start_campaign(true)
+                        }
+                        KeyCode::Char('n') | KeyCode::Char('N') => self.start_campaign(false),
+                        _ => {}
+                    }
+                    return;
+                }
+                if self.won {
+                    if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+                        self.next_level();
+                    }
+                    return;
+                }
+                if self.game_over {
                     if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
                         self.reset();
                     }
                     return;
                 }
-                if self.paused { return; }
+                if self.paused || self.countdown.is_running() { return; }
                 match key.code {
-                    KeyCode::Left => {
-                        self.paddle_x = (self.paddle_x - 2.0).max(0.5);
+                    KeyCode::Left => self.move_paddle(0, -PADDLE_STEP),
+                    KeyCode::Right => self.move_paddle(0, PADDLE_STEP),
+                    KeyCode::Char('z') | KeyCode::Char('Z') if self.coop => self.move_paddle(1, -PADDLE_STEP),
+                    KeyCode::Char('x') | KeyCode::Char('X') if self.coop => self.move_paddle(1, PADDLE_STEP),
+                    KeyCode::Char(' ') | KeyCode::Up => {
                         if !self.launched {
-                            self.ball_x = self.paddle_x + self.paddle_width / 2.0;
+                            self.launch();
                         }
                     }
-                    KeyCode::Right => {
-                        self.paddle_x = (self.paddle_x + 2.0).min(self.field_width - self.paddle_width - 0.5);
-                        if !self.launched {
-                            self.ball_x = self.paddle_x + self.paddle_width / 2.0;
-                        }
+                    // Only on an untouched field, so a cleared-out level can't be swapped
+                    KeyCode::Char('l') | KeyCode::Char('L') if !self.launched && self.bricks.iter().all(|b| b.alive) => {
+                        self.cycle_layout();
                     }
-                    KeyCode::Char(' ') | KeyCode::Up => {
-                        if !self.launched {
-                            self.launched = true;
-                            self.ball_dy = -self.ball_speed;
-                            self.ball_dx = self.ball_speed * 0.7;
-                        }
+                    KeyCode::Char('c') | KeyCode::Char('C') if !self.launched && self.bricks.iter().all(|b| b.alive) => {
+                        self.toggle_coop();
                     }
                     _ => {}
                 }
@@ -365,13 +870,24 @@ impl Game for Breakout {
         }
     }
 
-    fn render(&mut self, frame: &mut Frame, area: Rect) {
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Color::Rgb(220, 80, 80)))
-            .title(" 🧱 Breakout ")
-            .title_style(Style::default().fg(Color::Rgb(255, 100, 100)).add_modifier(Modifier::BOLD));
+    fn handle_mouse(&mut self, event: MouseEvent) {
+        if self.demo || self.paused || self.countdown.is_running() || self.game_over || self.won || self.start_screen { return; }
+        let area = self.field_area;
+        if area.width == 0 || !area.contains(Position::new(event.column, event.row)) { return; }
+        match event.kind {
+            // Player 1's paddle centres under the pointer
+            MouseEventKind::Moved | MouseEventKind::Drag(MouseButton::Left) => {
+                let x = ((event.column - area.x) as f32 + 0.5) * self.field_width / area.width as f32;
+                let dx = x - (self.paddle_x + self.paddle_width / 2.0);
+                self.move_paddle(0, dx);
+            }
+            MouseEventKind::Down(MouseButton::Left) if !self.launched => self.launch(),
+            _ => {}
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = self.title_bar(theme);
 
         let inner = block.inner(area);
         frame.render_widget(block, area);
@@ -384,6 +900,7 @@ impl Game for Breakout {
                 let ratio_x = new_fw / self.field_width;
                 let ratio_y = new_fh / self.field_height;
                 self.paddle_x *= ratio_x;
+                self.paddle2_x *= ratio_x;
                 self.ball_x *= ratio_x;
                 self.ball_y *= ratio_y;
                 self.paddle_y = new_fh - 3.0;
@@ -406,102 +923,236 @@ impl Game for Breakout {
 
         // Status bar
         let bricks_left = self.bricks.iter().filter(|b| b.alive).count();
-        let total_bricks = BRICK_ROWS * BRICKS_PER_ROW;
+        let total_bricks = self.bricks.len();
         let status = Line::from(vec![
             Span::styled(" 🧱 ", Style::default()),
+            Span::styled(
+                format!("Level {} ", self.level),
+                Style::default().fg(theme.paint(Color::Rgb(255, 100, 100), Role::Red)).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                if self.layout > 0 { format!("· {} ", self.layout_name()) } else { String::new() },
+                Style::default().fg(theme.paint(Color::Rgb(255, 180, 120), Role::Orange)),
+            ),
+            Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             Span::styled(
                 format!("Score: {} ", self.score),
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             Span::styled(
                 format!("Lives: {} ", "♥ ".repeat(self.lives as usize)),
-                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme.paint(Color::Red, Role::Red)).add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             Span::styled(
                 format!("🏆 High: {} ", self.high_score),
-                Style::default().fg(Color::Cyan),
+                Style::default().fg(theme.paint(Color::Cyan, Role::Cyan)),
             ),
-            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             Span::styled(
                 format!("Bricks: {}/{} ", bricks_left, total_bricks),
-                Style::default().fg(Color::Green),
+                Style::default().fg(theme.paint(Color::Green, Role::Green)),
             ),
         ]);
+        let mut status = status;
+        if self.coop_active() {
+            status.push_span(Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))));
+            status.push_span(Span::styled(
+                format!("Co-op P1 {} · P2 {} ", self.player_points[0], self.player_points[1]),
+                Style::default().fg(theme.paint(Color::Rgb(255, 220, 170), Role::Orange)),
+            ));
+        }
+        if self.demo {
+            status.push_span(Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))));
+            status.push_span(Span::styled("DEMO ", Style::default().fg(theme.paint(Color::Rgb(120, 200, 255), Role::Blue)).add_modifier(Modifier::BOLD)));
+        } else if self.assist || self.assists_used > 0 || self.guide {
+            let mut aids = Vec::new();
+            if self.assist || self.assists_used > 0 {
+                aids.push(if self.assist { "Assist" } else { "Assist off" });
+            }
+            if self.guide {
+                aids.push("Guide");
+            }
+            status.push_span(Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))));
+            status.push_span(Span::styled(
+                format!("{} ×{:.2} ", aids.join(" · "), self.score_multiplier()),
+                Style::default().fg(theme.paint(Color::Rgb(255, 180, 120), Role::Orange)),
+            ));
+        }
         frame.render_widget(Paragraph::new(status), chunks[0]);
 
         // Game field
         let fw = chunks[1].width as usize;
         let fh = chunks[1].height as usize;
-        let lines = self.render_field(fw, fh);
+        let lines = self.render_field(fw, fh, theme);
         frame.render_widget(Paragraph::new(lines), chunks[1]);
+        self.field_area = chunks[1];
+        self.countdown.render(frame, chunks[1], theme);
+        if self.start_screen {
+            self.render_start_screen(frame, chunks[1], theme);
+        }
 
         // Help bar
-        if self.game_over {
-            let msg = Paragraph::new(Line::from(vec![
-                Span::styled(" 💀 GAME OVER! ", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
-                Span::styled("Press ENTER to restart, Esc for menu", Style::default().fg(Color::Gray)),
-            ]));
-            frame.render_widget(msg, chunks[2]);
+        let footer = if self.demo {
+            hud::outcome("DEMO", theme.paint(Color::Rgb(120, 200, 255), Role::Blue), "Press any key to play", theme)
+        } else if self.start_screen {
+            hud::menu_legend(Some("ENTER/C Continue"), &[("N", "New campaign"), ("D", "Demo")], theme)
+        } else if self.game_over {
+            hud::game_over(&match self.saved_campaign {
+                Some((level, _)) => format!("Press ENTER to continue from level {}, Esc for menu", level),
+                None => "Press ENTER to restart, Esc for menu".to_string(),
+            }, theme)
         } else if self.won {
-            let msg = Paragraph::new(Line::from(vec![
-                Span::styled(" 🎉 YOU WIN! ", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
-                Span::styled(format!("Score: {} │ Press ENTER to play again", self.score), Style::default().fg(Color::Gray)),
-            ]));
-            frame.render_widget(msg, chunks[2]);
+            hud::outcome(
+                &format!("🎉 LEVEL {} CLEARED!", self.level),
+                theme.paint(Color::Green, Role::Green),
+                &format!("Score: {} │ Progress saved │ Press ENTER for level {}", self.score, self.level + 1),
+                theme,
+            )
         } else if self.paused {
-            let msg = Paragraph::new(Line::from(vec![
-                Span::styled(" ⏸ PAUSED - Press P to resume ", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
-            ]));
-            frame.render_widget(msg, chunks[2]);
+            hud::pause_overlay(frame, chunks[1], theme);
+            hud::paused(theme)
+        } else if self.coop_active() && !self.launched && self.bricks.iter().all(|b| b.alive) {
+            hud::legend(Some("SPACE Launch"), &[("←→", "P1"), ("Z X", "P2"), ("L", "Layout"), ("C", "Solo")], theme)
+        } else if self.coop_active() {
+            hud::legend(if self.launched { None } else { Some("SPACE Launch") }, &[("←→", "P1"), ("Z X", "P2")], theme)
+        } else if !self.launched && self.bricks.iter().all(|b| b.alive) {
+            hud::legend(Some("SPACE Launch"), &[("←→", "Move Paddle"), ("L", "Layout"), ("C", "Co-op"), ("A", "Assist"), ("D", "Demo")], theme)
         } else if !self.launched {
-            let help = Paragraph::new(Line::from(vec![
-                Span::styled(" ←→ Move Paddle ", Style::default().fg(Color::DarkGray)),
-                Span::styled("│ ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("SPACE Launch ", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
-                Span::styled("│ ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("P Pause ", Style::default().fg(Color::DarkGray)),
-                Span::styled("│ ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("R Restart ", Style::default().fg(Color::DarkGray)),
-                Span::styled("│ ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("Esc Menu ", Style::default().fg(Color::DarkGray)),
-                Span::styled("│ ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("? Help", Style::default().fg(Color::DarkGray)),
-            ]));
-            frame.render_widget(help, chunks[2]);
+            hud::legend(Some("SPACE Launch"), &[("←→", "Move Paddle"), ("A", "Assist"), ("D", "Demo")], theme)
         } else {
-            let help = Paragraph::new(Line::from(vec![
-                Span::styled(" ←→ Move Paddle ", Style::default().fg(Color::DarkGray)),
-                Span::styled("│ ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("P Pause ", Style::default().fg(Color::DarkGray)),
-                Span::styled("│ ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("R Restart ", Style::default().fg(Color::DarkGray)),
-                Span::styled("│ ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("Esc Menu ", Style::default().fg(Color::DarkGray)),
-                Span::styled("│ ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("? Help", Style::default().fg(Color::DarkGray)),
-            ]));
-            frame.render_widget(help, chunks[2]);
-        }
+            hud::legend(None, &[("←→", "Move Paddle"), ("A", "Assist"), ("G", "Guide")], theme)
+        };
+        frame.render_widget(Paragraph::new(footer), chunks[2]);
     }
 
     fn get_score(&self) -> u32 { self.score }
     fn is_game_over(&self) -> bool { self.game_over }
+    fn is_paused(&self) -> bool { self.paused }
+    fn is_demo(&self) -> bool { self.demo }
+    fn set_hitboxes(&mut self, on: bool) { self.show_hitboxes = on; }
+    fn set_practice(&mut self, on: bool) { self.practice = on; }
+
+    fn script_state(&self) -> Vec<(&'static str, f64)> {
+        vec![
+            ("ball_x", self.ball_x as f64),
+            ("ball_y", self.ball_y as f64),
+            ("ball_dx", self.ball_dx as f64),
+            ("ball_dy", self.ball_dy as f64),
+            ("paddle_x", (self.paddle_x + self.paddle_width / 2.0) as f64),
+            ("paddle_y", self.paddle_y as f64),
+            ("launched", self.launched as u8 as f64),
+            ("lives", self.lives as f64),
+            ("level", self.level as f64),
+            ("bricks", self.bricks.iter().filter(|b| b.alive).count() as f64),
+        ]
+    }
+
+    fn serialize_state(&self) -> Option<Vec<u8>> {
+        if self.game_over || self.won || self.start_screen || self.demo { return None; }
+        let mut w = StateWriter::new();
+        w.u32(self.score);
+        w.u32(self.high_score);
+        w.u32(self.lives);
+        w.f32(self.ball_speed);
+        w.f32(self.field_width);
+        w.f32(self.field_height);
+        w.f32(self.paddle_x);
+        w.u32(self.bricks.len() as u32);
+        for brick in &self.bricks {
+            w.bool(brick.alive);
+        }
+        w.u32(self.level);
+        w.u32(self.assists_used);
+        w.string(self.layout_name());
+        w.bool(self.coop);
+        w.f32(self.paddle2_x);
+        w.u32(self.player_points[0]);
+        w.u32(self.player_points[1]);
+        Some(w.finish())
+    }
+
+    fn restore_state(&mut self, data: &[u8]) -> bool {
+        let mut r = StateReader::new(data);
+        let (Some(score), Some(high_score), Some(lives), Some(ball_speed), Some(fw), Some(fh), Some(paddle_x), Some(count)) =
+            (r.u32(), r.u32(), r.u32(), r.f32(), r.f32(), r.f32(), r.f32(), r.u32())
+        else { return false };
+        if fw < 10.0 || fh < 8.0 { return false; }
+        let mut alive = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let Some(a) = r.bool() else { return false };
+            alive.push(a);
+        }
+        // Snapshots from before campaigns have no level
+        let level = r.u32().unwrap_or(1).max(1);
+        let assists_used = r.u32().unwrap_or(0);
+        // ...and before text art layouts are on the classic field
+        let layout = match r.string() {
+            Some(name) => match self.art_levels.iter().position(|art| art.name == name) {
+                Some(i) => i + 1,
+                None if name == "Classic" => 0,
+                None => return false,
+            },
+            None => 0,
+        };
+        let expected = layout.checked_sub(1).map_or(BRICK_ROWS * BRICKS_PER_ROW, |i| self.art_levels[i].brick_count());
+        if count as usize != expected { return false; }
+        // ...and before co-op are solo games
+        let coop = r.bool().unwrap_or(false);
+        let paddle2_x = r.f32().unwrap_or(paddle_x);
+        let player_points = [r.u32().unwrap_or(0), r.u32().unwrap_or(0)];
+
+        self.reset();
+        self.start_screen = false;
+        self.layout = layout;
+        self.level = level;
+        self.field_width = fw;
+        self.field_height = fh;
+        self.paddle_y = fh - 3.0;
+        self.paddle_width = (fw / 6.0).max(6.0);
+        self.coop = coop;
+        self.player_points = player_points;
+        self.paddle_x = paddle_x;
+        self.paddle2_x = paddle2_x;
+        self.move_paddle(0, 0.0);
+        self.move_paddle(1, 0.0);
+        self.init_bricks();
+        for (brick, a) in self.bricks.iter_mut().zip(alive) {
+            brick.alive = a;
+        }
+        self.score = score;
+        self.high_score = self.high_score.max(high_score);
+        self.lives = lives.max(1);
+        self.assists_used = assists_used;
+        self.ball_speed = ball_speed;
+        // Resume with the ball back on the paddle, paused
+        self.reset_ball();
+        self.paused = true;
+        true
+    }
 
     fn reset(&mut self) {
         let hs = self.high_score;
         let fw = self.field_width;
         let fh = self.field_height;
+        let show_hitboxes = self.show_hitboxes;
+        let assist = self.assist;
+        let (guide, practice) = (self.guide, self.practice);
+        let layout = self.layout;
+        let coop = self.coop;
         *self = Breakout::new();
+        self.layout = layout.min(self.art_levels.len());
         self.high_score = hs;
+        self.show_hitboxes = show_hitboxes;
+        self.assist = assist;
+        (self.guide, self.practice) = (guide, practice);
+        self.coop = coop;
         self.field_width = fw;
         self.field_height = fh;
         self.paddle_y = fh - 3.0;
         self.paddle_width = (fw / 6.0).max(6.0);
-        self.paddle_x = fw / 2.0 - self.paddle_width / 2.0;
-        self.ball_x = fw / 2.0;
-        self.ball_y = self.paddle_y - 1.0;
+        self.place_paddles();
         self.init_bricks();
     }
 }
@@ -0,0 +1,148 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ratatui::style::Color;
+
+use crate::scores::data_path;
+use crate::ui::theme::{Ink, Role};
+
+const FILE_EXT: &str = "txt";
+/// Largest brick field a text file can describe
+pub const MAX_ROWS: usize = 12;
+pub const MAX_COLS: usize = 60;
+
+/// Colours of the classic field, top row first, with their points.
+pub const ROW_BRICKS: [(Ink, u32); 6] = [
+    (Ink(Color::Rgb(220, 50, 50), Role::Red), 60),   // Red
+    (Ink(Color::Rgb(220, 130, 30), Role::Orange), 50),  // Orange
+    (Ink(Color::Rgb(220, 200, 30), Role::Yellow), 40),  // Yellow
+    (Ink(Color::Rgb(50, 200, 50), Role::Green), 30),   // Green
+    (Ink(Color::Rgb(50, 130, 220), Role::Blue), 20),  // Blue
+    (Ink(Color::Rgb(150, 50, 220), Role::Violet), 10),  // Purple
+];
+
+/// A brick field drawn as text art, one character per brick.
+///
+/// ```text
+/// ; comments start with a semicolon
+/// :* #ff8800 80      legend: character, colour, points
+/// R.R.RRR.R...R...RRR
+/// RRR.RR..R...R...R.R
+/// R.R.RRR.RRR.RRR.RRR
+/// ```
+///
+/// Space and `.` leave a gap. Letters R O Y G B P pick the classic colours,
+/// W C M add white, cyan and magenta, and any other character without a
+/// legend entry takes the classic colour of its row.
+#[derive(Clone)]
+pub struct ArtLevel {
+    pub name: String,
+    /// Row by row; `None` is a gap
+    pub cells: Vec<Vec<Option<(Ink, u32)>>>,
+}
+
+impl ArtLevel {
+    pub fn parse(name: &str, text: &str) -> Option<ArtLevel> {
+        let mut legend: Vec<(char, (Ink, u32))> = Vec::new();
+        let mut rows: Vec<&str> = Vec::new();
+        for line in text.lines() {
+            if line.starts_with(';') { continue; }
+            if let Some(entry) = line.strip_prefix(':') {
+                let mut chars = entry.chars();
+                let key = chars.next()?;
+                let mut fields = chars.as_str().split_whitespace();
+                let color = parse_color(fields.next()?)?;
+                let points = match fields.next() {
+                    Some(p) => p.parse().ok()?,
+                    None => 10,
+                };
+                legend.push((key, (color, points)));
+                continue;
+            }
+            rows.push(line.trim_end());
+        }
+        // Blank lines around the art don't count, blank lines inside do
+        while rows.last().is_some_and(|r| r.is_empty()) { rows.pop(); }
+        let first = rows.iter().position(|r| !r.is_empty())?;
+
+        let cells: Vec<Vec<Option<(Ink, u32)>>> = rows[first..].iter()
+            .take(MAX_ROWS)
+            .enumerate()
+            .map(|(row, line)| {
+                line.chars().take(MAX_COLS).map(|c| match c {
+                    ' ' | '.' => None,
+                    c => Some(legend.iter().find(|(k, _)| *k == c).map(|(_, brick)| *brick)
+                        .unwrap_or_else(|| letter_brick(c).unwrap_or(ROW_BRICKS[row % ROW_BRICKS.len()]))),
+                }).collect()
+            })
+            .collect();
+        if cells.iter().flatten().all(Option::is_none) { return None; }
+        Some(ArtLevel { name: name.to_string(), cells })
+    }
+
+    pub fn columns(&self) -> usize {
+        self.cells.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    pub fn brick_count(&self) -> usize {
+        self.cells.iter().flatten().filter(|c| c.is_some()).count()
+    }
+
+    /// Text levels from the `levels` folder, sorted by file name.
+    pub fn load_all() -> Vec<ArtLevel> {
+        let Ok(entries) = fs::read_dir(level_dir()) else { return Vec::new() };
+        let mut files: Vec<PathBuf> = entries.flatten()
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|e| e == FILE_EXT))
+            .collect();
+        files.sort();
+        files.iter().filter_map(|p| ArtLevel::parse(&file_stem(p), &fs::read_to_string(p).ok()?)).collect()
+    }
+}
+
+fn letter_brick(c: char) -> Option<(Ink, u32)> {
+    let classic = |i: usize| Some(ROW_BRICKS[i]);
+    match c.to_ascii_uppercase() {
+        'R' => classic(0),
+        'O' => classic(1),
+        'Y' => classic(2),
+        'G' => classic(3),
+        'B' => classic(4),
+        'P' => classic(5),
+        'W' => Some((Ink(Color::Rgb(230, 230, 240), Role::Bright), 70)),
+        'C' => Some((Ink(Color::Rgb(50, 210, 220), Role::Cyan), 30)),
+        'M' => Some((Ink(Color::Rgb(220, 60, 180), Role::Magenta), 40)),
+        _ => None,
+    }
+}
+
+/// `#rrggbb` or one of the letter colour names above, spelled out.
+fn parse_color(s: &str) -> Option<Ink> {
+    if let Some(hex) = s.strip_prefix('#') {
+        if hex.len() != 6 { return None; }
+        let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+        let rgb = (channel(0)?, channel(2)?, channel(4)?);
+        return Some(Ink(Color::Rgb(rgb.0, rgb.1, rgb.2), Role::nearest(rgb)));
+    }
+    let letter = match s.to_ascii_lowercase().as_str() {
+        "red" => 'R',
+        "orange" => 'O',
+        "yellow" => 'Y',
+        "green" => 'G',
+        "blue" => 'B',
+        "purple" => 'P',
+        "white" => 'W',
+        "cyan" => 'C',
+        "magenta" => 'M',
+        _ => return None,
+    };
+    letter_brick(letter).map(|(color, _)| color)
+}
+
+fn file_stem(path: &Path) -> String {
+    path.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default()
+}
+
+fn level_dir() -> PathBuf {
+    data_path("levels")
+}
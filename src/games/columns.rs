@@ -0,0 +1,556 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use rand::Rng;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::games::gravity::{self, Gravity, Step};
+use crate::games::tile_grid::{CellArt, TileGrid};
+use crate::games::{Game, GameInfo};
+use crate::rng::GameRng;
+use crate::session::{StateReader, StateWriter};
+use crate::ui::hud::{self, Countdown};
+use crate::ui::theme::{Ink, Role, Theme};
+
+const WELL_W: usize = 6;
+const WELL_H: usize = 13;
+/// Gems in a falling column
+const PIECE_LEN: usize = 3;
+/// Column a new piece comes in at
+const SPAWN_X: i32 = 2;
+/// Gems to clear for each level
+const GEMS_PER_LEVEL: u32 = 30;
+/// Ticks matched gems stay lit before they go
+const CLEAR_TICKS: u32 = 24;
+/// Ticks a chain or combo callout stays up
+const CALLOUT_TICKS: u32 = 90;
+/// Glyph and colour of each gem kind; the glyph tells them apart without colour
+const GEMS: [(char, Ink); 6] = [
+    ('◆', Ink(Color::Rgb(240, 80, 80), Role::Red)),
+    ('●', Ink(Color::Rgb(90, 210, 110), Role::Green)),
+    ('▲', Ink(Color::Rgb(90, 150, 255), Role::Blue)),
+    ('■', Ink(Color::Rgb(250, 210, 70), Role::Yellow)),
+    ('★', Ink(Color::Rgb(210, 110, 240), Role::Magenta)),
+    ('♥', Ink(Color::Rgb(80, 220, 220), Role::Cyan)),
+];
+/// Directions a line of gems can run: across, down and both diagonals
+const LINES: [(i32, i32); 4] = [(1, 0), (0, 1), (1, 1), (1, -1)];
+const ACCENT: Ink = Ink(Color::Rgb(230, 120, 200), Role::Magenta);
+const WELL_BG: Ink = Ink(Color::Rgb(18, 14, 28), Role::Background);
+
+/// The falling column, top gem first; `y` is the bottom gem's row and
+/// may be above the well while it comes in.
+#[derive(Clone, Copy)]
+struct Piece {
+    x: i32,
+    y: i32,
+    gems: [u8; PIECE_LEN],
+}
+
+impl Piece {
+    fn random(rng: &mut impl Rng) -> Self {
+        Piece { x: SPAWN_X, y: 0, gems: [0; PIECE_LEN].map(|_| rng.gen_range(0..GEMS.len() as u8)) }
+    }
+
+    /// (x, y, gem) of each gem, top first.
+    fn cells(&self) -> impl Iterator<Item = (i32, i32, u8)> + '_ {
+        self.gems.iter().enumerate().map(|(i, &g)| (self.x, self.y + i as i32 + 1 - PIECE_LEN as i32, g))
+    }
+}
+
+enum Phase {
+    Falling,
+    /// Matched gems are lit; they go when the ticks run out
+    Clearing(u32),
+}
+
+/// Columns: three-gem columns fall into the well, and lines of three or
+/// more alike across, down or diagonally vanish. Gems above drop into
+/// the gaps, and any new lines that makes clear as a chain.
+pub struct ColumnsGame {
+    well: TileGrid<Option<u8>>,
+    piece: Piece,
+    next: Piece,
+    gravity: Gravity,
+    phase: Phase,
+    /// Cells matched in the clear being shown
+    matched: Vec<(usize, usize)>,
+    /// Clears since the last piece locked
+    chain: u32,
+    best_chain: u32,
+    gems_cleared: u32,
+    score: u32,
+    high_score: u32,
+    game_over: bool,
+    paused: bool,
+    countdown: Countdown,
+    /// Chain or combo message and ticks left
+    callout: Option<(String, u32)>,
+    /// The gems each new column is made of
+    rng: GameRng,
+}
+
+impl ColumnsGame {
+    pub fn new() -> Self {
+        Self::with_rng(GameRng::from_entropy())
+    }
+
+    fn with_rng(mut rng: GameRng) -> Self {
+        Self {
+            well: TileGrid::new(WELL_W, WELL_H, None),
+            piece: Piece::random(&mut rng),
+            next: Piece::random(&mut rng),
+            gravity: Gravity::default(),
+            phase: Phase::Falling,
+            matched: Vec::new(),
+            chain: 0,
+            best_chain: 0,
+            gems_cleared: 0,
+            score: 0,
+            high_score: 0,
+            game_over: false,
+            paused: false,
+            countdown: Countdown::default(),
+            callout: None,
+            rng,
+        }
+    }
+
+    fn level(&self) -> u32 {
+        1 + self.gems_cleared / GEMS_PER_LEVEL
+    }
+
+    /// Gems cleared this game.
+    pub fn gems_cleared(&self) -> u32 {
+        self.gems_cleared
+    }
+
+    /// Whether (x, y) is off the sides or bottom or holds a gem; rows above
+    /// the well are open.
+    fn blocked(&self, x: i32, y: i32) -> bool {
+        if x < 0 || x >= WELL_W as i32 || y >= WELL_H as i32 { return true; }
+        y >= 0 && self.well.get(x as usize, y as usize).is_some_and(Option::is_some)
+    }
+
+    fn fits(&self, piece: &Piece) -> bool {
+        piece.cells().all(|(x, y, _)| !self.blocked(x, y))
+    }
+
+    fn grounded(&self) -> bool {
+        self.blocked(self.piece.x, self.piece.y + 1)
+    }
+
+    /// Row the piece's bottom gem would land on if dropped now.
+    fn landing_row(&self) -> i32 {
+        let mut y = self.piece.y;
+        while !self.blocked(self.piece.x, y + 1) { y += 1; }
+        y
+    }
+
+    fn shift(&mut self, dx: i32) {
+        let moved = Piece { x: self.piece.x + dx, ..self.piece };
+        if self.fits(&moved) {
+            self.piece = moved;
+            self.gravity.moved();
+        }
+    }
+
+    /// Cycle the gems in the column: down sends the bottom one to the top.
+    fn cycle(&mut self, down: bool) {
+        if down { self.piece.gems.rotate_right(1) } else { self.piece.gems.rotate_left(1) }
+        self.gravity.moved();
+    }
+
+    fn soft_drop(&mut self) {
+        if self.grounded() {
+            self.lock();
+        } else {
+            self.piece.y += 1;
+            self.score += 1;
+            self.gravity.dropped();
+        }
+    }
+
+    fn hard_drop(&mut self) {
+        let row = self.landing_row();
+        self.score += 2 * (row - self.piece.y) as u32;
+        self.piece.y = row;
+        self.lock();
+    }
+
+    /// Fix the piece in the well and look for lines; a column that locks
+    /// sticking out of the top ends the game.
+    fn lock(&mut self) {
+        let piece = self.piece;
+        for (x, y, gem) in piece.cells() {
+            if y < 0 {
+                self.finish();
+                return;
+            }
+            if let Some(cell) = self.well.get_mut(x as usize, y as usize) {
+                *cell = Some(gem);
+            }
+        }
+        self.chain = 0;
+        self.find_matches();
+    }
+
+    /// Mark every gem in a line of three or more; returns the lines found.
+    fn mark_matches(&mut self) -> u32 {
+        let mut hit = [false; WELL_W * WELL_H];
+        let mut lines = 0;
+        for (x, y, gem) in self.well.iter() {
+            let Some(gem) = *gem else { continue };
+            let (x, y) = (x as i32, y as i32);
+            let same = |x: i32, y: i32| x >= 0 && y >= 0 && self.well.get(x as usize, y as usize) == Some(&Some(gem));
+            for (dx, dy) in LINES {
+                // Count each line once, from its first gem
+                if same(x - dx, y - dy) { continue; }
+                let len = (0..).take_while(|&i| same(x + dx * i, y + dy * i)).count() as i32;
+                if len < 3 { continue; }
+                lines += 1;
+                for i in 0..len {
+                    hit[((y + dy * i) as usize) * WELL_W + (x + dx * i) as usize] = true;
+                }
+            }
+        }
+        self.matched = hit.iter().enumerate().filter(|(_, &h)| h).map(|(i, _)| (i % WELL_W, i / WELL_W)).collect();
+        lines
+    }
+
+    /// Score the lines the well has now, or bring in the next piece if
+    /// there are none.
+    fn find_matches(&mut self) {
+        let lines = self.mark_matches();
+        if self.matched.is_empty() {
+            self.spawn();
+            return;
+        }
+
+        self.chain += 1;
+        self.best_chain = self.best_chain.max(self.chain);
+        let level = self.level();
+        let gems = self.matched.len() as u32;
+        // Every gem counts for more the deeper the chain; extra lines in one
+        // clear are a combo bonus on top
+        self.score += gems * 10 * level * self.chain + (lines - 1) * 50 * level;
+        self.gems_cleared += gems;
+        self.callout = match (self.chain, lines) {
+            (1, 1) => None,
+            (1, n) => Some((format!("{} LINE COMBO!", n), CALLOUT_TICKS)),
+            (c, 1) => Some((format!("CHAIN ×{}!", c), CALLOUT_TICKS)),
+            (c, n) => Some((format!("CHAIN ×{} + {} LINE COMBO!", c, n), CALLOUT_TICKS)),
+        }
+        .or(self.callout.take());
+        self.phase = Phase::Clearing(CLEAR_TICKS);
+    }
+
+    /// Remove the matched gems and let the ones above fall into the gaps.
+    fn collapse(&mut self) {
+        for &(x, y) in &self.matched {
+            if let Some(cell) = self.well.get_mut(x, y) { *cell = None; }
+        }
+        self.matched.clear();
+        for x in 0..WELL_W {
+            let column: Vec<u8> = (0..WELL_H).rev().filter_map(|y| *self.well.get(x, y).unwrap_or(&None)).collect();
+            for y in 0..WELL_H {
+                if let Some(cell) = self.well.get_mut(x, WELL_H - 1 - y) { *cell = column.get(y).copied(); }
+            }
+        }
+    }
+
+    fn spawn(&mut self) {
+        self.phase = Phase::Falling;
+        self.piece = std::mem::replace(&mut self.next, Piece::random(&mut self.rng));
+        self.gravity.spawn();
+        if !self.fits(&self.piece) {
+            self.finish();
+        }
+    }
+
+    fn finish(&mut self) {
+        self.game_over = true;
+        self.high_score = self.high_score.max(self.score);
+    }
+
+    fn draw_gem(gem: u8, art: &mut CellArt, lit: bool, dim: bool, theme: &Theme) {
+        let (glyph, color) = GEMS[gem as usize % GEMS.len()];
+        if art.len() < 2 {
+            // Compact cells: just the glyph
+            let style = if lit { Style::default().fg(theme.paint(Color::Black, Role::Background)).bg(theme.paint(Color::White, Role::Bright)) } else { Style::default().fg(theme.ink(color)) };
+            art[0][0] = (glyph, style.add_modifier(Modifier::BOLD));
+            return;
+        }
+        let face = if lit { theme.paint(Color::White, Role::Bright) } else { theme.ink(color) };
+        let mut style = Style::default().fg(theme.paint(Color::Black, Role::Background)).bg(face);
+        if dim { style = style.add_modifier(Modifier::DIM); }
+        let w = art[0].len() - 1;
+        for (i, cell) in art[0].iter_mut().take(w).enumerate() {
+            *cell = (if i == w / 2 { glyph } else { ' ' }, style);
+        }
+        let shade = Style::default().fg(if lit { theme.paint(Color::Gray, Role::Dim) } else { theme.paint(Color::Rgb(60, 50, 70), Role::Panel) }).bg(theme.ink(WELL_BG));
+        for cell in art[1].iter_mut().take(w) {
+            *cell = ('▀', shade);
+        }
+    }
+
+    fn draw_cell(&self, x: usize, y: usize, gem: Option<u8>, landing: i32, art: &mut CellArt, theme: &Theme) {
+        let piece = matches!(self.phase, Phase::Falling) && !self.game_over;
+        let falling = self.piece.cells().find(|&(px, py, _)| piece && px == x as i32 && py == y as i32);
+        if let Some((_, _, g)) = falling {
+            // The piece fades as its lock delay runs out
+            Self::draw_gem(g, art, false, self.gravity.lock_progress() > 0.5, theme);
+        } else if let Some(g) = gem {
+            Self::draw_gem(g, art, self.matched.contains(&(x, y)), false, theme);
+        } else if piece && x as i32 == self.piece.x && y as i32 > self.piece.y && y as i32 <= landing {
+            // Where the column will land
+            let top = landing + 1 - PIECE_LEN as i32;
+            if y as i32 >= top {
+                let mid = (art[0].len() - 1) / 2;
+                art[0][mid] = ('·', Style::default().fg(theme.paint(Color::Rgb(90, 80, 110), Role::Faint)).bg(theme.ink(WELL_BG)));
+            }
+        }
+    }
+
+    fn draw_side(&self, frame: &mut Frame, area: Rect, cell: (usize, usize), theme: &Theme) {
+        let label = |s: &str| Span::styled(format!("{:<8}", s), Style::default().fg(theme.paint(Color::Gray, Role::Dim)));
+        let value = |s: String| Span::styled(s, Style::default().fg(theme.paint(Color::White, Role::Bright)).add_modifier(Modifier::BOLD));
+        let mut lines = vec![Line::from(Span::styled("NEXT", Style::default().fg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD)))];
+        let mut preview = TileGrid::new(1, PIECE_LEN, None);
+        for (i, &g) in self.next.gems.iter().enumerate() {
+            if let Some(c) = preview.get_mut(0, i) { *c = Some(g); }
+        }
+        lines.extend(preview.render(cell, Style::default().bg(theme.ink(WELL_BG)), |_, _, gem, art| {
+            if let Some(g) = *gem { Self::draw_gem(g, art, false, false, theme); }
+        }));
+        lines.push(Line::default());
+        lines.push(Line::from(vec![label("Level"), value(self.level().to_string())]));
+        lines.push(Line::from(vec![label("Gems"), value(self.gems_cleared.to_string())]));
+        lines.push(Line::from(vec![label("Chain"), value(format!("best ×{}", self.best_chain))]));
+        lines.push(Line::from(vec![label("Speed"), value(format!("{:.1} rows/s", 60.0 / gravity::level_ticks(self.level()) as f32))]));
+        lines.push(Line::from(vec![label("Best"), value(self.high_score.max(self.score).to_string())]));
+        if let Some((text, _)) = &self.callout {
+            lines.push(Line::default());
+            lines.push(Line::from(Span::styled(text.clone(), Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD))));
+        }
+        frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), area);
+    }
+}
+
+pub const INFO: GameInfo = GameInfo {
+    name: "Columns",
+    icon: "💎",
+    author: "RustCade",
+    blurb: "Line up the gems,\nchain the falls!",
+    description: "Drop columns of gems and line up three or more to clear them in chains",
+    controls: &[
+        ("← →", "Move column"),
+        ("↑ / X", "Cycle gems"),
+        ("↓", "Soft drop"),
+        ("Space", "Hard drop"),
+        ("R", "Restart"),
+        ("P", "Pause"),
+    ],
+    tags: &["puzzle", "arcade", "match-three"],
+    color: ACCENT,
+    border_color: Ink(Color::Rgb(110, 50, 100), Role::Magenta),
+};
+
+impl Game for ColumnsGame {
+    fn info(&self) -> &'static GameInfo {
+        &INFO
+    }
+
+    fn update(&mut self) {
+        if self.paused || self.countdown.tick() || self.game_over { return; }
+        if let Some((_, ticks)) = &mut self.callout {
+            *ticks = ticks.saturating_sub(1);
+            if *ticks == 0 { self.callout = None; }
+        }
+        match self.phase {
+            Phase::Clearing(0) => {
+                self.collapse();
+                self.find_matches();
+            }
+            Phase::Clearing(ref mut ticks) => *ticks -= 1,
+            Phase::Falling => match self.gravity.tick(gravity::level_ticks(self.level()), self.grounded()) {
+                Step::Fall => self.piece.y += 1,
+                Step::Lock => self.lock(),
+                Step::Hold => {}
+            },
+        }
+    }
+
+    fn handle_input(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('r') | KeyCode::Char('R') => self.reset(),
+            KeyCode::Char('p') | KeyCode::Char('P') if !self.game_over => {
+                self.countdown.toggle_pause(&mut self.paused);
+            }
+            _ if self.game_over => {
+                if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+                    self.reset();
+                }
+            }
+            _ if self.paused || self.countdown.is_running() || !matches!(self.phase, Phase::Falling) => {}
+            KeyCode::Left => self.shift(-1),
+            KeyCode::Right => self.shift(1),
+            KeyCode::Up | KeyCode::Char('x') | KeyCode::Char('X') => self.cycle(true),
+            KeyCode::Char('z') | KeyCode::Char('Z') => self.cycle(false),
+            KeyCode::Down => self.soft_drop(),
+            KeyCode::Char(' ') => self.hard_drop(),
+            _ => {}
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = self.title_bar(theme);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(6), Constraint::Length(1)])
+            .split(inner);
+
+        let sep = || Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint)));
+        let status = vec![
+            Span::styled(" 💎 ", Style::default()),
+            Span::styled(format!("Level {} ", self.level()), Style::default().fg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD)),
+            sep(),
+            Span::styled(format!("Score: {} ", self.score), Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD)),
+            sep(),
+            Span::styled(
+                format!("Next level: {} gems ", GEMS_PER_LEVEL - self.gems_cleared % GEMS_PER_LEVEL),
+                Style::default().fg(theme.paint(Color::Gray, Role::Dim)),
+            ),
+        ];
+        frame.render_widget(Paragraph::new(Line::from(status)), chunks[0]);
+
+        // Tall enough terminals get gems as blocks, the rest as glyphs
+        let field = chunks[1];
+        let cell = if field.height as usize > WELL_H * 2 { (4, 2) } else { (2, 1) };
+        let (ww, wh) = ((WELL_W * cell.0 + 2) as u16, (WELL_H * cell.1 + 1) as u16);
+        let side_w = 24u16;
+        let left = field.x + field.width.saturating_sub(ww + side_w + 2) / 2;
+        let well_area = Rect::new(left, field.y + field.height.saturating_sub(wh) / 2, ww.min(field.width), wh.min(field.height));
+        let well_block = Block::default()
+            .borders(Borders::LEFT | Borders::RIGHT | Borders::BOTTOM)
+            .border_type(BorderType::Thick)
+            .border_style(Style::default().fg(theme.ink(ACCENT)));
+        let well_inner = well_block.inner(well_area);
+        frame.render_widget(well_block, well_area);
+        let landing = self.landing_row();
+        let lines = self.well.render(cell, Style::default().bg(theme.ink(WELL_BG)), |x, y, gem, art| self.draw_cell(x, y, *gem, landing, art, theme));
+        frame.render_widget(Paragraph::new(lines), well_inner);
+
+        let side = Rect::new(well_area.right() + 2, well_area.y, side_w.min(field.right().saturating_sub(well_area.right() + 2)), well_area.height);
+        self.draw_side(frame, side, cell, theme);
+        self.countdown.render(frame, field, theme);
+
+        let footer = if self.game_over {
+            hud::game_over(&format!(
+                "The well is full! {} gems, best chain ×{} │ ENTER to restart, Esc for menu",
+                self.gems_cleared, self.best_chain
+            ), theme)
+        } else if self.paused {
+            hud::pause_overlay(frame, field, theme);
+            hud::paused(theme)
+        } else {
+            hud::legend(None, &[("←→", "Move"), ("↑", "Cycle"), ("↓", "Drop"), ("Space", "Hard drop")], theme)
+        };
+        frame.render_widget(Paragraph::new(footer), chunks[2]);
+    }
+
+    fn get_score(&self) -> u32 { self.score }
+    fn is_game_over(&self) -> bool { self.game_over }
+    fn is_paused(&self) -> bool { self.paused }
+
+    fn reseed(&mut self, rng: GameRng) {
+        self.rng = rng;
+        self.reset();
+    }
+
+    fn script_state(&self) -> Vec<(&'static str, f64)> {
+        let height = (0..WELL_W)
+            .map(|x| (0..WELL_H).find(|&y| self.well.get(x, y).is_some_and(Option::is_some)).map_or(0, |y| WELL_H - y))
+            .max()
+            .unwrap_or(0);
+        vec![
+            ("level", self.level() as f64),
+            ("piece_x", self.piece.x as f64),
+            ("piece_y", self.piece.y as f64),
+            ("gem_top", self.piece.gems[0] as f64),
+            ("gem_middle", self.piece.gems[1] as f64),
+            ("gem_bottom", self.piece.gems[2] as f64),
+            ("stack_height", height as f64),
+            ("gems_cleared", self.gems_cleared as f64),
+            ("chain", self.chain as f64),
+        ]
+    }
+
+    fn serialize_state(&self) -> Option<Vec<u8>> {
+        if self.game_over { return None; }
+        let mut w = StateWriter::new();
+        w.u32(self.score);
+        w.u32(self.high_score);
+        w.u32(self.gems_cleared);
+        w.u32(self.best_chain);
+        w.bool(matches!(self.phase, Phase::Clearing(_)));
+        w.u32(self.chain);
+        w.bytes(&self.well.iter().map(|(_, _, g)| g.map_or(0, |g| g + 1)).collect::<Vec<_>>());
+        for piece in [&self.piece, &self.next] {
+            w.u32(piece.x as u32);
+            w.u32(piece.y as u32);
+            w.bytes(&piece.gems);
+        }
+        self.gravity.write(&mut w);
+        Some(w.finish())
+    }
+
+    fn restore_state(&mut self, data: &[u8]) -> bool {
+        let mut r = StateReader::new(data);
+        let (Some(score), Some(high_score), Some(gems_cleared), Some(best_chain), Some(clearing), Some(chain), Some(cells)) =
+            (r.u32(), r.u32(), r.u32(), r.u32(), r.bool(), r.u32(), r.bytes())
+        else { return false };
+        if cells.len() != WELL_W * WELL_H || cells.iter().any(|&c| c as usize > GEMS.len()) { return false; }
+        let mut pieces = Vec::new();
+        for _ in 0..2 {
+            let (Some(x), Some(y), Some(gems)) = (r.u32(), r.u32(), r.bytes()) else { return false };
+            let Ok(gems) = <[u8; PIECE_LEN]>::try_from(gems) else { return false };
+            if gems.iter().any(|&g| g as usize >= GEMS.len()) { return false; }
+            pieces.push(Piece { x: x as i32, y: y as i32, gems });
+        }
+        let Some(gravity) = Gravity::read(&mut r) else { return false };
+
+        let mut restored = ColumnsGame::with_rng(self.rng.clone());
+        for (i, (_, _, cell)) in restored.well.iter_mut().enumerate() {
+            *cell = cells[i].checked_sub(1);
+        }
+        restored.piece = pieces[0];
+        restored.next = pieces[1];
+        if clearing {
+            // The clear shows again, already scored
+            restored.mark_matches();
+            if restored.matched.is_empty() { return false; }
+            restored.phase = Phase::Clearing(CLEAR_TICKS);
+            restored.chain = chain;
+        } else if !restored.fits(&restored.piece) {
+            return false;
+        }
+        restored.gravity = gravity;
+        restored.score = score;
+        restored.high_score = self.high_score.max(high_score);
+        restored.gems_cleared = gems_cleared;
+        restored.best_chain = best_chain;
+        // Resume paused, with a countdown before the column falls again
+        restored.paused = true;
+        *self = restored;
+        true
+    }
+
+    fn reset(&mut self) {
+        let high_score = self.high_score;
+        *self = ColumnsGame::with_rng(self.rng.clone());
+        self.high_score = high_score;
+    }
+}
@@ -0,0 +1,963 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use rand::Rng;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::games::tile_grid::{CellArt, Dir, TileGrid};
+use crate::games::{Game, GameInfo};
+use crate::rng::GameRng;
+use crate::scores::now_secs;
+use crate::session::{load_progress, save_progress, StateReader, StateWriter};
+use crate::ui::fx;
+use crate::ui::hud::{self, Countdown};
+use crate::ui::theme::{Ink, Role, Theme};
+
+const MAP_W: usize = 56;
+const MAP_H: usize = 22;
+/// Terminal (columns, rows) each map tile is drawn in
+const CELL: (usize, usize) = (2, 1);
+/// Floors to the bottom, where the dragon waits
+const FLOORS: u32 = 10;
+/// How far the torch reaches, in tiles
+const SIGHT: i32 = 7;
+/// Turns between each point of health coming back
+const REGEN_TURNS: u32 = 8;
+const START_HP: i32 = 20;
+/// Messages kept for the log under the map
+const LOG_LINES: usize = 3;
+/// Mixed into the day number for the daily dungeon, as Dino Run does
+const DAILY_SALT: u64 = 0xDE1_7E5E;
+const STATS_SAVE: &str = "delve_stats";
+const ACCENT: Ink = Ink(Color::Rgb(190, 140, 255), Role::Violet);
+const WALL_LIT: Ink = Ink(Color::Rgb(130, 110, 90), Role::Dim);
+const WALL_SEEN: Ink = Ink(Color::Rgb(55, 50, 60), Role::Panel);
+
+#[derive(Clone, Copy, PartialEq)]
+enum Tile {
+    Wall,
+    Floor,
+    Stairs,
+}
+
+#[derive(Clone, Copy)]
+struct Cell {
+    tile: Tile,
+    /// Mapped: seen by torchlight or a scroll, drawn dim once out of sight
+    seen: bool,
+}
+
+struct Kind {
+    glyph: char,
+    name: &'static str,
+    hp: i32,
+    attack: i32,
+    defense: i32,
+    xp: u32,
+    color: Ink,
+    /// Shallowest floor it turns up on
+    floor: u32,
+}
+
+/// Monsters, shallowest first; the last is the dragon on the bottom floor.
+const KINDS: [Kind; 8] = [
+    Kind { glyph: 'r', name: "rat", hp: 3, attack: 2, defense: 0, xp: 1, color: Ink(Color::Rgb(170, 140, 110), Role::Orange), floor: 1 },
+    Kind { glyph: 'b', name: "bat", hp: 4, attack: 2, defense: 0, xp: 2, color: Ink(Color::Rgb(150, 120, 180), Role::Violet), floor: 1 },
+    Kind { glyph: 'g', name: "goblin", hp: 7, attack: 3, defense: 1, xp: 4, color: Ink(Color::Rgb(110, 200, 90), Role::Green), floor: 2 },
+    Kind { glyph: 's', name: "skeleton", hp: 10, attack: 4, defense: 2, xp: 6, color: Ink(Color::Rgb(230, 230, 210), Role::Bright), floor: 4 },
+    Kind { glyph: 'o', name: "orc", hp: 14, attack: 5, defense: 2, xp: 9, color: Ink(Color::Rgb(90, 160, 70), Role::Green), floor: 5 },
+    Kind { glyph: 'W', name: "wraith", hp: 16, attack: 7, defense: 3, xp: 14, color: Ink(Color::Rgb(140, 170, 255), Role::Blue), floor: 7 },
+    Kind { glyph: 'T', name: "troll", hp: 26, attack: 8, defense: 4, xp: 20, color: Ink(Color::Rgb(60, 180, 140), Role::Cyan), floor: 8 },
+    Kind { glyph: 'D', name: "dragon", hp: 90, attack: 15, defense: 6, xp: 100, color: Ink(Color::Rgb(255, 80, 60), Role::Red), floor: FLOORS },
+];
+const DRAGON: u8 = KINDS.len() as u8 - 1;
+const BAT: u8 = 1;
+
+struct Monster {
+    kind: u8,
+    x: usize,
+    y: usize,
+    hp: i32,
+    /// Has seen the player and gives chase
+    awake: bool,
+}
+
+impl Monster {
+    fn kind(&self) -> &'static Kind {
+        &KINDS[self.kind as usize]
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Item {
+    Potion,
+    Gold(u32),
+    Weapon,
+    Armor,
+    /// Maps the whole floor
+    Scroll,
+}
+
+impl Item {
+    fn glyph(self) -> (char, Ink) {
+        match self {
+            Item::Potion => ('!', Ink(Color::Rgb(255, 90, 140), Role::Red)),
+            Item::Gold(_) => ('$', Ink(Color::Rgb(255, 215, 60), Role::Yellow)),
+            Item::Weapon => ('/', Ink(Color::Rgb(120, 220, 255), Role::Cyan)),
+            Item::Armor => ('[', Ink(Color::Rgb(170, 190, 210), Role::Text)),
+            Item::Scroll => ('?', Ink(Color::Rgb(240, 230, 170), Role::Yellow)),
+        }
+    }
+
+    fn code(self) -> (u8, u32) {
+        match self {
+            Item::Potion => (0, 0),
+            Item::Gold(n) => (1, n),
+            Item::Weapon => (2, 0),
+            Item::Armor => (3, 0),
+            Item::Scroll => (4, 0),
+        }
+    }
+
+    fn from_code(code: u8, n: u32) -> Option<Item> {
+        Some(match code {
+            0 => Item::Potion,
+            1 => Item::Gold(n),
+            2 => Item::Weapon,
+            3 => Item::Armor,
+            4 => Item::Scroll,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Room {
+    x: usize,
+    y: usize,
+    w: usize,
+    h: usize,
+}
+
+impl Room {
+    fn center(&self) -> (usize, usize) {
+        (self.x + self.w / 2, self.y + self.h / 2)
+    }
+
+    /// Overlaps `other` or comes within a tile of it.
+    fn crowds(&self, other: &Room) -> bool {
+        self.x <= other.x + other.w && other.x <= self.x + self.w && self.y <= other.y + other.h && other.y <= self.y + self.h
+    }
+}
+
+/// A floor as the seed lays it out.
+struct Level {
+    grid: TileGrid<Cell>,
+    start: (usize, usize),
+    monsters: Vec<Monster>,
+    items: Vec<(usize, usize, Item)>,
+}
+
+/// The same floor every time for a given seed, so a resumed or daily run
+/// only has to remember what's changed since.
+fn floor_rng(seed: u64, floor: u32) -> GameRng {
+    GameRng::new(seed ^ (floor as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+}
+
+fn generate(seed: u64, floor: u32) -> Level {
+    let mut rng = floor_rng(seed, floor);
+    let mut grid = TileGrid::new(MAP_W, MAP_H, Cell { tile: Tile::Wall, seen: false });
+    let carve = |grid: &mut TileGrid<Cell>, x: usize, y: usize, tile: Tile| {
+        if let Some(cell) = grid.get_mut(x, y) { cell.tile = tile; }
+    };
+
+    let mut rooms: Vec<Room> = Vec::new();
+    for _ in 0..200 {
+        if rooms.len() >= 9 { break; }
+        let (w, h) = (rng.gen_range(4..=11), rng.gen_range(3..=6));
+        let room = Room { x: rng.gen_range(1..MAP_W - w - 1), y: rng.gen_range(1..MAP_H - h - 1), w, h };
+        if rooms.iter().any(|r| r.crowds(&room)) { continue; }
+        for y in room.y..room.y + h {
+            for x in room.x..room.x + w {
+                carve(&mut grid, x, y, Tile::Floor);
+            }
+        }
+        // Corridor back to the room before, bending once
+        if let Some(prev) = rooms.last() {
+            let ((ax, ay), (bx, by)) = (prev.center(), room.center());
+            let bend = if rng.gen_bool(0.5) { (bx, ay) } else { (ax, by) };
+            for (from, to) in [((ax, ay), bend), (bend, (bx, by))] {
+                for x in from.0.min(to.0)..=from.0.max(to.0) {
+                    for y in from.1.min(to.1)..=from.1.max(to.1) {
+                        carve(&mut grid, x, y, Tile::Floor);
+                    }
+                }
+            }
+        }
+        rooms.push(room);
+    }
+
+    let start = rooms[0].center();
+    let far = *rooms.iter()
+        .max_by_key(|r| { let (x, y) = r.center(); x.abs_diff(start.0) + y.abs_diff(start.1) })
+        .unwrap_or(&rooms[0]);
+    let mut taken = vec![start, far.center()];
+    let mut monsters = Vec::new();
+    if floor < FLOORS {
+        carve(&mut grid, far.center().0, far.center().1, Tile::Stairs);
+    } else {
+        let (x, y) = far.center();
+        let kind = &KINDS[DRAGON as usize];
+        monsters.push(Monster { kind: DRAGON, x, y, hp: kind.hp, awake: false });
+    }
+
+    // A free floor tile somewhere in a room, away from the start if `away`
+    let spot = |rng: &mut GameRng, taken: &mut Vec<(usize, usize)>, away: bool| -> Option<(usize, usize)> {
+        for _ in 0..30 {
+            let room = rooms[rng.gen_range(if away && rooms.len() > 1 { 1 } else { 0 }..rooms.len())];
+            let pos = (rng.gen_range(room.x..room.x + room.w), rng.gen_range(room.y..room.y + room.h));
+            if !taken.contains(&pos) {
+                taken.push(pos);
+                return Some(pos);
+            }
+        }
+        None
+    };
+
+    let eligible: Vec<u8> = (0..DRAGON).filter(|&k| KINDS[k as usize].floor <= floor).collect();
+    for _ in 0..2 + floor {
+        let Some((x, y)) = spot(&mut rng, &mut taken, true) else { break };
+        // Mostly the deepest few kinds this floor allows
+        let kind = eligible[eligible.len() - 1 - rng.gen_range(0..eligible.len().min(3))];
+        monsters.push(Monster { kind, x, y, hp: KINDS[kind as usize].hp, awake: false });
+    }
+
+    let mut loot = vec![Item::Potion];
+    for _ in 0..rng.gen_range(2..=4) {
+        loot.push(Item::Gold(rng.gen_range(5..=15) * floor));
+    }
+    for (item, chance) in [(Item::Potion, 0.5), (Item::Weapon, 0.3), (Item::Armor, 0.3), (Item::Scroll, 0.25)] {
+        if rng.gen_bool(chance) { loot.push(item); }
+    }
+    let items = loot.into_iter()
+        .filter_map(|item| spot(&mut rng, &mut taken, false).map(|(x, y)| (x, y, item)))
+        .collect();
+
+    Level { grid, start, monsters, items }
+}
+
+/// Lifetime totals across every run, kept in a progress file.
+#[derive(Default)]
+struct DelveStats {
+    runs: u32,
+    wins: u32,
+    deepest: u32,
+    kills: u32,
+}
+
+impl DelveStats {
+    fn load() -> Self {
+        let Some(data) = load_progress(STATS_SAVE) else { return Self::default() };
+        let mut r = StateReader::new(&data);
+        (|| Some(DelveStats { runs: r.u32()?, wins: r.u32()?, deepest: r.u32()?, kills: r.u32()? }))().unwrap_or_default()
+    }
+
+    fn save(&self) {
+        let mut w = StateWriter::new();
+        w.u32(self.runs);
+        w.u32(self.wins);
+        w.u32(self.deepest);
+        w.u32(self.kills);
+        save_progress(STATS_SAVE, &w.finish());
+    }
+}
+
+/// Ten-floor roguelike: rooms and corridors laid out from the run's seed,
+/// monsters that wake when they see you, and one life to reach the dragon.
+pub struct DelveGame {
+    grid: TileGrid<Cell>,
+    /// Tiles in torchlight this turn
+    lit: Vec<bool>,
+    monsters: Vec<Monster>,
+    items: Vec<(usize, usize, Item)>,
+    seed: u64,
+    daily: bool,
+    /// Combat rolls; reseeded from the turn on restore
+    rng: GameRng,
+    floor: u32,
+    turn: u32,
+    x: usize,
+    y: usize,
+    hp: i32,
+    max_hp: i32,
+    attack: i32,
+    defense: i32,
+    level: u32,
+    /// Experience towards the next level
+    xp: u32,
+    potions: u32,
+    gold: u32,
+    kills: u32,
+    score: u32,
+    high_score: u32,
+    game_over: bool,
+    won: bool,
+    /// What ended the run, for the footer
+    fate: String,
+    paused: bool,
+    countdown: Countdown,
+    log: Vec<(String, Ink)>,
+    /// Frames the player stays flashed red after a hit
+    hurt: u32,
+    stats: DelveStats,
+}
+
+impl DelveGame {
+    pub fn new() -> Self {
+        Self::with_rng(GameRng::from_entropy())
+    }
+
+    fn with_rng(rng: GameRng) -> Self {
+        let mut game = Self {
+            grid: TileGrid::new(0, 0, Cell { tile: Tile::Wall, seen: false }),
+            lit: Vec::new(),
+            monsters: Vec::new(),
+            items: Vec::new(),
+            seed: 0,
+            daily: false,
+            rng,
+            floor: 1,
+            turn: 0,
+            x: 0,
+            y: 0,
+            hp: START_HP,
+            max_hp: START_HP,
+            attack: 3,
+            defense: 0,
+            level: 1,
+            xp: 0,
+            potions: 1,
+            gold: 0,
+            kills: 0,
+            score: 0,
+            high_score: 0,
+            game_over: false,
+            won: false,
+            fate: String::new(),
+            paused: false,
+            countdown: Countdown::default(),
+            log: Vec::new(),
+            hurt: 0,
+            stats: DelveStats::load(),
+        };
+        game.start_run(false);
+        game
+    }
+
+    /// Seed for the daily dungeon, the same for everyone on that UTC day.
+    fn daily_seed(day: u64) -> u64 {
+        (day ^ DAILY_SALT).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+    }
+
+    fn start_run(&mut self, daily: bool) {
+        self.daily = daily;
+        self.seed = if daily { Self::daily_seed(now_secs() / 86_400) } else { self.rng.gen() };
+        self.rng = GameRng::new(self.seed);
+        self.enter_floor(1);
+        self.log.clear();
+        let welcome = if daily { "Today's dungeon. Everyone gets the same ten floors." } else { "You light a torch and step into the dark." };
+        self.say(welcome, Ink(Color::Gray, Role::Dim));
+    }
+
+    fn enter_floor(&mut self, floor: u32) {
+        let level = generate(self.seed, floor);
+        self.floor = floor;
+        self.grid = level.grid;
+        (self.x, self.y) = level.start;
+        self.monsters = level.monsters;
+        self.items = level.items;
+        self.see();
+    }
+
+    /// Deepest floor reached this run, kept with the score.
+    pub fn floor_reached(&self) -> u32 {
+        self.floor
+    }
+
+    /// Score flags (see `scores::flag_labels`): bit 0 for the daily dungeon.
+    pub fn modifier_flags(&self) -> u32 {
+        self.daily as u32
+    }
+
+    /// Wipe the lifetime totals after the saved progress was cleared.
+    pub fn reload_stats(&mut self) {
+        self.stats = DelveStats::load();
+    }
+
+    fn say(&mut self, text: impl Into<String>, color: Ink) {
+        let text = text.into();
+        if self.log.last().is_some_and(|(last, _)| *last == text) { return; }
+        self.log.push((text, color));
+        if self.log.len() > LOG_LINES {
+            self.log.remove(0);
+        }
+    }
+
+    fn walkable(&self, x: usize, y: usize) -> bool {
+        self.grid.get(x, y).is_some_and(|c| c.tile != Tile::Wall)
+    }
+
+    fn monster_at(&self, x: usize, y: usize) -> Option<usize> {
+        self.monsters.iter().position(|m| m.x == x && m.y == y)
+    }
+
+    fn is_lit(&self, x: usize, y: usize) -> bool {
+        self.lit.get(y * MAP_W + x).copied().unwrap_or(false)
+    }
+
+    /// Nothing but floor on the straight line between two tiles.
+    fn clear_line(&self, from: (usize, usize), to: (usize, usize)) -> bool {
+        let (mut x, mut y) = (from.0 as i32, from.1 as i32);
+        let (tx, ty) = (to.0 as i32, to.1 as i32);
+        let (dx, dy) = ((tx - x).abs(), -(ty - y).abs());
+        let (sx, sy) = ((tx - x).signum(), (ty - y).signum());
+        let mut err = dx + dy;
+        loop {
+            if (x, y) == (tx, ty) { return true; }
+            if (x, y) != (from.0 as i32, from.1 as i32) && !self.walkable(x as usize, y as usize) { return false; }
+            let e2 = 2 * err;
+            if e2 >= dy { err += dy; x += sx; }
+            if e2 <= dx { err += dx; y += sy; }
+        }
+    }
+
+    /// Light the tiles the torch reaches and map them.
+    fn see(&mut self) {
+        self.lit = vec![false; MAP_W * MAP_H];
+        for dy in -SIGHT..=SIGHT {
+            for dx in -SIGHT..=SIGHT {
+                if dx * dx + dy * dy > SIGHT * SIGHT + SIGHT { continue; }
+                let (x, y) = (self.x as i32 + dx, self.y as i32 + dy);
+                if x < 0 || y < 0 || x >= MAP_W as i32 || y >= MAP_H as i32 { continue; }
+                let (x, y) = (x as usize, y as usize);
+                if self.clear_line((self.x, self.y), (x, y)) {
+                    self.lit[y * MAP_W + x] = true;
+                    if let Some(cell) = self.grid.get_mut(x, y) { cell.seen = true; }
+                }
+            }
+        }
+        for m in &mut self.monsters {
+            m.awake |= self.lit[m.y * MAP_W + m.x];
+        }
+    }
+
+    fn roll_damage(&mut self, attack: i32, defense: i32) -> i32 {
+        (attack + self.rng.gen_range(0..=2) - defense).max(1)
+    }
+
+    /// Take a turn; the monsters answer if it used one.
+    fn act(&mut self, dir: Option<Dir>) {
+        let took_turn = match dir {
+            None => true,
+            Some(dir) => self.step(dir),
+        };
+        if !took_turn { return; }
+        self.turn += 1;
+        if self.turn.is_multiple_of(REGEN_TURNS) {
+            self.hp = (self.hp + 1).min(self.max_hp);
+        }
+        self.monsters_turn();
+        self.see();
+    }
+
+    /// Move or attack; false when walking into a wall.
+    fn step(&mut self, dir: Dir) -> bool {
+        let Some((nx, ny)) = self.grid.neighbor(self.x, self.y, dir) else { return false };
+        if let Some(i) = self.monster_at(nx, ny) {
+            self.strike(i);
+            return true;
+        }
+        if !self.walkable(nx, ny) { return false; }
+        (self.x, self.y) = (nx, ny);
+        self.pick_up();
+        if self.grid.get(nx, ny).is_some_and(|c| c.tile == Tile::Stairs) {
+            self.say("Stairs lead down. > to descend.", ACCENT);
+        }
+        true
+    }
+
+    fn strike(&mut self, i: usize) {
+        let (id, kind) = (self.monsters[i].kind, self.monsters[i].kind());
+        let damage = self.roll_damage(self.attack, kind.defense);
+        self.monsters[i].hp -= damage;
+        self.monsters[i].awake = true;
+        if self.monsters[i].hp > 0 {
+            self.say(format!("You hit the {} for {}.", kind.name, damage), Ink(Color::Gray, Role::Dim));
+            return;
+        }
+        self.monsters.remove(i);
+        self.kills += 1;
+        self.score += kind.xp * 10;
+        self.say(format!("You slay the {}!", kind.name), Ink(Color::Rgb(255, 200, 80), Role::Orange));
+        if id == DRAGON {
+            self.score += 1000;
+            self.finish(true, "Slew the dragon".to_string());
+            return;
+        }
+        self.gain_xp(kind.xp);
+    }
+
+    fn gain_xp(&mut self, xp: u32) {
+        self.xp += xp;
+        while self.xp >= self.level * 10 {
+            self.xp -= self.level * 10;
+            self.level += 1;
+            self.max_hp += 5;
+            self.hp += 5;
+            self.attack += 1;
+            self.say(format!("Welcome to level {}: more health and a stronger arm.", self.level), Ink(Color::Rgb(120, 230, 120), Role::Green));
+        }
+    }
+
+    fn pick_up(&mut self) {
+        let Some(i) = self.items.iter().position(|&(x, y, _)| (x, y) == (self.x, self.y)) else { return };
+        let (_, _, item) = self.items.remove(i);
+        let (_, color) = item.glyph();
+        match item {
+            Item::Potion => {
+                self.potions += 1;
+                self.say("You pick up a healing potion.", color);
+            }
+            Item::Gold(n) => {
+                self.gold += n;
+                self.score += n;
+                self.say(format!("{} gold.", n), color);
+            }
+            Item::Weapon => {
+                self.attack += 2;
+                self.say("A keener blade: +2 attack.", color);
+            }
+            Item::Armor => {
+                self.defense += 1;
+                self.say("Sturdier armour: +1 defence.", color);
+            }
+            Item::Scroll => {
+                for (_, _, cell) in self.grid.iter_mut() {
+                    cell.seen = true;
+                }
+                self.say("The scroll maps the whole floor.", color);
+            }
+        }
+    }
+
+    fn drink(&mut self) {
+        if self.potions == 0 {
+            self.say("No potions left.", Ink(Color::DarkGray, Role::Faint));
+            return;
+        }
+        if self.hp >= self.max_hp {
+            self.say("You're already at full health.", Ink(Color::DarkGray, Role::Faint));
+            return;
+        }
+        self.potions -= 1;
+        let healed = (self.max_hp / 2).min(self.max_hp - self.hp);
+        self.hp += healed;
+        self.say(format!("The potion restores {} health.", healed), Item::Potion.glyph().1);
+        self.act(None);
+    }
+
+    fn descend(&mut self) {
+        if !self.grid.get(self.x, self.y).is_some_and(|c| c.tile == Tile::Stairs) {
+            self.say("There are no stairs here.", Ink(Color::DarkGray, Role::Faint));
+            return;
+        }
+        self.score += 50 * self.floor;
+        self.enter_floor(self.floor + 1);
+        let text = if self.floor == FLOORS {
+            "The bottom floor. Something huge is breathing in the dark.".to_string()
+        } else {
+            format!("You descend to floor {}.", self.floor)
+        };
+        self.say(text, ACCENT);
+    }
+
+    /// Awake monsters close in and attack; bats flap about at random.
+    fn monsters_turn(&mut self) {
+        for i in 0..self.monsters.len() {
+            if self.game_over { return; }
+            let m = &self.monsters[i];
+            if !m.awake { continue; }
+            let (mx, my, kind) = (m.x, m.y, m.kind);
+            let distance = |x: usize, y: usize| x.abs_diff(self.x) + y.abs_diff(self.y);
+            if distance(mx, my) == 1 {
+                let kind = &KINDS[kind as usize];
+                let damage = self.roll_damage(kind.attack, self.defense);
+                self.hp -= damage;
+                self.hurt = 12;
+                if self.hp <= 0 {
+                    self.finish(false, format!("Killed by a {} on floor {}", kind.name, self.floor));
+                } else {
+                    self.say(format!("The {} hits you for {}.", kind.name, damage), fx::alert_color());
+                }
+                continue;
+            }
+            let mut dirs = Dir::ALL;
+            let wander = kind == BAT && self.rng.gen_bool(0.5);
+            if wander {
+                dirs.rotate_left(self.rng.gen_range(0..4));
+            }
+            let current = distance(mx, my);
+            let next = dirs.into_iter()
+                .filter_map(|d| self.grid.neighbor(mx, my, d))
+                .filter(|&(x, y)| self.walkable(x, y) && self.monster_at(x, y).is_none() && (x, y) != (self.x, self.y))
+                .filter(|&(x, y)| wander || distance(x, y) < current)
+                .min_by_key(|&(x, y)| if wander { 0 } else { distance(x, y) });
+            if let Some((x, y)) = next {
+                (self.monsters[i].x, self.monsters[i].y) = (x, y);
+            }
+        }
+    }
+
+    fn finish(&mut self, won: bool, fate: String) {
+        self.game_over = true;
+        self.won = won;
+        self.fate = fate;
+        self.high_score = self.high_score.max(self.score);
+        let stats = &mut self.stats;
+        stats.runs += 1;
+        stats.wins += won as u32;
+        stats.deepest = stats.deepest.max(self.floor);
+        stats.kills += self.kills;
+        stats.save();
+    }
+
+    fn draw_cell(&self, x: usize, y: usize, cell: &Cell, art: &mut CellArt, theme: &Theme) {
+        let lit = self.is_lit(x, y);
+        if !cell.seen { return; }
+        let (glyph, color) = if (x, y) == (self.x, self.y) {
+            ('@', if self.hurt > 0 { theme.ink(fx::alert_color()) } else { theme.paint(Color::Rgb(255, 240, 120), Role::Yellow) })
+        } else if let Some(m) = self.monsters.iter().find(|m| lit && (m.x, m.y) == (x, y)) {
+            (m.kind().glyph, theme.ink(m.kind().color))
+        } else if let Some(&(_, _, item)) = self.items.iter().find(|&&(ix, iy, _)| (ix, iy) == (x, y)) {
+            let (glyph, color) = item.glyph();
+            (glyph, theme.ink(if lit { color } else { WALL_SEEN }))
+        } else {
+            match cell.tile {
+                Tile::Wall => {
+                    let style = Style::default().fg(if lit { theme.ink(WALL_LIT) } else { theme.ink(WALL_SEEN) });
+                    art[0] = vec![('█', style); CELL.0];
+                    return;
+                }
+                Tile::Floor => ('·', if lit { theme.paint(Color::Rgb(110, 100, 90), Role::Faint) } else { theme.paint(Color::Rgb(45, 45, 55), Role::Panel) }),
+                Tile::Stairs => ('>', if lit { theme.ink(ACCENT) } else { theme.paint(Color::Rgb(90, 70, 120), Role::Violet) }),
+            }
+        };
+        art[0][0] = (glyph, Style::default().fg(color).add_modifier(Modifier::BOLD));
+    }
+
+    fn sidebar(&self, theme: &Theme) -> Vec<Line<'static>> {
+        let label = Style::default().fg(theme.paint(Color::DarkGray, Role::Faint));
+        let value = Style::default().fg(theme.paint(Color::White, Role::Bright)).add_modifier(Modifier::BOLD);
+        let row = |name: &str, v: String| Line::from(vec![Span::styled(format!(" {:<9}", name), label), Span::styled(v, value)]);
+        let filled = (self.hp.max(0) * 12 / self.max_hp) as usize;
+        let hp_color = if self.hp * 4 <= self.max_hp { theme.ink(fx::alert_color()) } else { theme.paint(Color::Rgb(120, 230, 120), Role::Green) };
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled(" HP       ", label),
+                Span::styled("█".repeat(filled), Style::default().fg(hp_color)),
+                Span::styled("░".repeat(12 - filled), Style::default().fg(theme.paint(Color::Rgb(50, 50, 60), Role::Panel))),
+            ]),
+            row("", format!("{}/{}", self.hp.max(0), self.max_hp)),
+            row("Level", format!("{} ({}/{} xp)", self.level, self.xp, self.level * 10)),
+            row("Attack", self.attack.to_string()),
+            row("Defence", self.defense.to_string()),
+            row("Potions", self.potions.to_string()),
+            row("Gold", self.gold.to_string()),
+            row("Kills", self.kills.to_string()),
+            Line::default(),
+            Line::from(Span::styled(" In sight", Style::default().fg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD))),
+        ];
+        let seen: Vec<&Monster> = self.monsters.iter().filter(|m| self.is_lit(m.x, m.y)).collect();
+        if seen.is_empty() {
+            lines.push(Line::from(Span::styled(" nothing", label)));
+        }
+        for m in seen.into_iter().take(5) {
+            let kind = m.kind();
+            lines.push(Line::from(vec![
+                Span::styled(format!(" {} ", kind.glyph), Style::default().fg(theme.ink(kind.color)).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{:<9}", kind.name), Style::default().fg(theme.paint(Color::Gray, Role::Dim))),
+                Span::styled(format!("{}/{}", m.hp, kind.hp), label),
+            ]));
+        }
+        lines.push(Line::default());
+        lines.push(Line::from(Span::styled(" Every run", Style::default().fg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD))));
+        lines.push(row("Runs", self.stats.runs.to_string()));
+        lines.push(row("Escapes", self.stats.wins.to_string()));
+        lines.push(row("Deepest", format!("floor {}", self.stats.deepest)));
+        lines.push(row("Kills", self.stats.kills.to_string()));
+        lines
+    }
+}
+
+fn dir_key(code: KeyCode) -> Option<Dir> {
+    Dir::from_key(code).or(match code {
+        KeyCode::Char('k') => Some(Dir::Up),
+        KeyCode::Char('l') => Some(Dir::Right),
+        KeyCode::Char('j') => Some(Dir::Down),
+        KeyCode::Char('h') => Some(Dir::Left),
+        _ => None,
+    })
+}
+
+pub const INFO: GameInfo = GameInfo {
+    name: "Delve",
+    icon: "🐉",
+    author: "RustCade",
+    blurb: "Ten floors down,\none life to spend",
+    description: "Fight down ten seeded dungeon floors to the dragon; death is permanent",
+    controls: &[
+        ("←↑↓→ / hjkl", "Move, bump to attack"),
+        (". / Space", "Wait a turn"),
+        ("U", "Drink a potion"),
+        ("> / Enter", "Take the stairs"),
+        ("D", "Daily dungeon (first turn)"),
+        ("R", "New dungeon"),
+        ("P", "Pause"),
+    ],
+    tags: &["roguelike", "dungeon", "turn-based"],
+    color: ACCENT,
+    border_color: Ink(Color::Rgb(80, 55, 120), Role::Violet),
+};
+
+impl Game for DelveGame {
+    fn info(&self) -> &'static GameInfo {
+        &INFO
+    }
+
+    fn update(&mut self) {
+        if self.paused || self.countdown.tick() { return; }
+        self.hurt = self.hurt.saturating_sub(1);
+    }
+
+    fn handle_input(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('r') | KeyCode::Char('R') => self.reset(),
+            KeyCode::Char('p') | KeyCode::Char('P') if !self.game_over => {
+                self.countdown.toggle_pause(&mut self.paused);
+            }
+            _ if self.game_over => {
+                if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+                    self.reset();
+                }
+            }
+            _ if self.paused || self.countdown.is_running() => {}
+            KeyCode::Char('d') | KeyCode::Char('D') if self.turn == 0 && self.floor == 1 => {
+                self.start_run(!self.daily);
+            }
+            KeyCode::Char('.') | KeyCode::Char(' ') => self.act(None),
+            KeyCode::Char('u') | KeyCode::Char('U') => self.drink(),
+            KeyCode::Char('>') | KeyCode::Enter => self.descend(),
+            code => {
+                if let Some(dir) = dir_key(code) {
+                    self.act(Some(dir));
+                }
+            }
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = self.title_bar(theme);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(6), Constraint::Length(LOG_LINES as u16), Constraint::Length(1)])
+            .split(inner);
+
+        let sep = || Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint)));
+        let seed = if self.daily { "Daily".to_string() } else { format!("Seed {:06x}", self.seed & 0xFF_FFFF) };
+        let status = vec![
+            Span::styled(" 🐉 ", Style::default()),
+            Span::styled(format!("Floor {}/{} ", self.floor, FLOORS), Style::default().fg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD)),
+            sep(),
+            Span::styled(format!("Score: {} ", self.score), Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD)),
+            sep(),
+            Span::styled(format!("Turn {} ", self.turn), Style::default().fg(theme.paint(Color::Gray, Role::Dim))),
+            sep(),
+            Span::styled(seed, Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+        ];
+        frame.render_widget(Paragraph::new(Line::from(status)), chunks[0]);
+
+        let body = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(20), Constraint::Length(30)])
+            .split(chunks[1]);
+        let field = body[0];
+        let (gw, gh) = ((MAP_W * CELL.0) as u16, (MAP_H * CELL.1) as u16);
+        // Centre the map, or follow the player when it doesn't fit
+        let scroll = |size: u16, view: u16, at: usize| -> u16 {
+            if size <= view { 0 } else { (at as u16).saturating_sub(view / 2).min(size - view) }
+        };
+        let map_area = Rect::new(
+            field.x + field.width.saturating_sub(gw) / 2,
+            field.y + field.height.saturating_sub(gh) / 2,
+            gw.min(field.width),
+            gh.min(field.height),
+        );
+        let offset = (scroll(gh, field.height, self.y * CELL.1), scroll(gw, field.width, self.x * CELL.0));
+        let lines = self.grid.render(CELL, Style::default(), |x, y, cell, art| self.draw_cell(x, y, cell, art, theme));
+        frame.render_widget(Paragraph::new(lines).scroll(offset), map_area);
+        self.countdown.render(frame, field, theme);
+
+        let side = Block::default()
+            .borders(Borders::LEFT)
+            .border_style(Style::default().fg(theme.paint(Color::Rgb(60, 50, 80), Role::Panel)));
+        frame.render_widget(Paragraph::new(self.sidebar(theme)).block(side), body[1]);
+
+        let log: Vec<Line> = self.log.iter().enumerate().map(|(i, (text, color))| {
+            let style = if i + 1 == self.log.len() { Style::default().fg(theme.ink(*color)) } else { Style::default().fg(theme.paint(Color::Rgb(90, 90, 100), Role::Faint)) };
+            Line::from(Span::styled(format!(" {}", text), style))
+        }).collect();
+        frame.render_widget(Paragraph::new(log), chunks[2]);
+
+        let footer = if self.game_over && self.won {
+            hud::outcome("🏆 DRAGON SLAIN!", theme.paint(Color::Green, Role::Green), &format!("{} in {} turns │ ENTER for a new dungeon, Esc for menu", self.score, self.turn), theme)
+        } else if self.game_over {
+            hud::game_over(&format!("{} │ ENTER for a new dungeon, Esc for menu", self.fate), theme)
+        } else if self.paused {
+            hud::pause_overlay(frame, field, theme);
+            hud::paused(theme)
+        } else {
+            let keys = [("←↑↓→", "Move/attack"), (".", "Wait"), ("U", "Potion"), (">", "Descend")];
+            let prompt = match (self.turn == 0 && self.floor == 1, self.daily) {
+                (true, false) => Some("D for today's daily dungeon"),
+                (true, true) => Some("D for a random dungeon"),
+                _ => None,
+            };
+            hud::legend(prompt, &keys, theme)
+        };
+        frame.render_widget(Paragraph::new(footer), chunks[3]);
+    }
+
+    fn get_score(&self) -> u32 { self.score }
+    fn is_game_over(&self) -> bool { self.game_over }
+    fn is_paused(&self) -> bool { self.paused }
+
+    fn reseed(&mut self, rng: GameRng) {
+        self.rng = rng;
+        self.reset();
+    }
+
+    fn script_state(&self) -> Vec<(&'static str, f64)> {
+        let nearest = self.monsters.iter()
+            .filter(|m| self.is_lit(m.x, m.y))
+            .map(|m| m.x.abs_diff(self.x) + m.y.abs_diff(self.y))
+            .min();
+        vec![
+            ("floor", self.floor as f64),
+            ("x", self.x as f64),
+            ("y", self.y as f64),
+            ("hp", self.hp as f64),
+            ("max_hp", self.max_hp as f64),
+            ("potions", self.potions as f64),
+            ("nearest_monster", nearest.map_or(-1.0, |d| d as f64)),
+        ]
+    }
+
+    /// Runs are permadeath, so a finished one leaves nothing to resume.
+    fn serialize_state(&self) -> Option<Vec<u8>> {
+        if self.game_over { return None; }
+        let mut w = StateWriter::new();
+        w.u64(self.seed);
+        w.bool(self.daily);
+        for v in [self.floor, self.turn, self.x as u32, self.y as u32, self.level, self.xp, self.potions, self.gold, self.kills, self.score, self.high_score] {
+            w.u32(v);
+        }
+        for v in [self.hp, self.max_hp, self.attack, self.defense] {
+            w.u32(v as u32);
+        }
+        w.u32(self.monsters.len() as u32);
+        for m in &self.monsters {
+            w.u8(m.kind);
+            w.u32(m.x as u32);
+            w.u32(m.y as u32);
+            w.u32(m.hp as u32);
+            w.bool(m.awake);
+        }
+        w.u32(self.items.len() as u32);
+        for &(x, y, item) in &self.items {
+            let (code, n) = item.code();
+            w.u32(x as u32);
+            w.u32(y as u32);
+            w.u8(code);
+            w.u32(n);
+        }
+        w.bytes(&self.grid.iter().map(|(_, _, c)| c.seen as u8).collect::<Vec<_>>());
+        Some(w.finish())
+    }
+
+    fn restore_state(&mut self, data: &[u8]) -> bool {
+        let mut r = StateReader::new(data);
+        let (Some(seed), Some(daily)) = (r.u64(), r.bool()) else { return false };
+        let mut v = [0u32; 15];
+        for slot in &mut v {
+            let Some(x) = r.u32() else { return false };
+            *slot = x;
+        }
+        let [floor, turn, x, y, level, xp, potions, gold, kills, score, high_score, hp, max_hp, attack, defense] = v;
+        let (x, y) = (x as usize, y as usize);
+        if !(1..=FLOORS).contains(&floor) || (hp as i32) <= 0 { return false; }
+        let Some(count) = r.u32() else { return false };
+        let mut monsters = Vec::new();
+        for _ in 0..count.min(64) {
+            let (Some(kind), Some(mx), Some(my), Some(hp), Some(awake)) = (r.u8(), r.u32(), r.u32(), r.u32(), r.bool()) else { return false };
+            if kind as usize >= KINDS.len() { return false; }
+            monsters.push(Monster { kind, x: mx as usize, y: my as usize, hp: hp as i32, awake });
+        }
+        let Some(count) = r.u32() else { return false };
+        let mut items = Vec::new();
+        for _ in 0..count.min(64) {
+            let (Some(ix), Some(iy), Some(code), Some(n)) = (r.u32(), r.u32(), r.u8(), r.u32()) else { return false };
+            let Some(item) = Item::from_code(code, n) else { return false };
+            items.push((ix as usize, iy as usize, item));
+        }
+        let Some(seen) = r.bytes() else { return false };
+
+        // The layout comes back from the seed; only what's changed was saved
+        let level_map = generate(seed, floor);
+        if seen.len() != MAP_W * MAP_H || !level_map.grid.get(x, y).is_some_and(|c| c.tile != Tile::Wall) { return false; }
+        self.reset();
+        self.seed = seed;
+        self.daily = daily;
+        self.rng = GameRng::new(seed ^ turn as u64);
+        self.floor = floor;
+        self.grid = level_map.grid;
+        for ((_, _, cell), &s) in self.grid.iter_mut().zip(&seen) {
+            cell.seen = s != 0;
+        }
+        self.monsters = monsters;
+        self.items = items;
+        (self.turn, self.x, self.y) = (turn, x, y);
+        (self.hp, self.max_hp, self.attack, self.defense) = (hp as i32, max_hp as i32, attack as i32, defense as i32);
+        (self.level, self.xp, self.potions, self.gold, self.kills) = (level, xp, potions, gold, kills);
+        self.score = score;
+        self.high_score = self.high_score.max(high_score);
+        self.log.clear();
+        self.say(format!("Back on floor {}.", floor), Ink(Color::Gray, Role::Dim));
+        self.see();
+        self.paused = true;
+        true
+    }
+
+    /// A fresh dungeon, daily again if this one was.
+    fn reset(&mut self) {
+        let (high_score, daily) = (self.high_score, self.daily);
+        let stats = std::mem::take(&mut self.stats);
+        *self = DelveGame::with_rng(self.rng.clone());
+        self.high_score = high_score;
+        self.stats = stats;
+        if daily {
+            self.start_run(true);
+        }
+    }
+}
@@ -3,12 +3,26 @@ use rand::Rng;
 use ratatui::prelude::*;
 use ratatui::widgets::*;
 
-use crate::games::Game;
+use crate::audio::{self, AudioEvent};
+use crate::games::{Game, GameInfo};
+use crate::rng::GameRng;
+use crate::scores::{format_date, now_secs};
+use crate::session::{StateReader, StateWriter};
+use crate::ui::hud::{self, Countdown};
+use crate::ui::theme::{Ink, Role, Theme};
 
 const DINO_X: usize = 10;
 const GRAVITY: f32 = 0.065;
 const JUMP_VELOCITY: f32 = -1.05;
 const DUCK_DURATION: u32 = 8; // ticks ducking lasts per keypress
+// Two-lane mode: rows between the back and front lanes' ground lines
+const LANE_GAP: f32 = 5.0;
+// Chance of a second obstacle in the other lane, once past this score
+const PAIR_CHANCE: f64 = 0.35;
+const PAIR_SCORE: u32 = 100;
+const START_SPEED: f32 = 0.5;
+/// Mixed into the day number so the daily stream isn't just seed 20,000-odd
+const DAILY_SALT: u64 = 0xD1A0_5EED;
 
 #[derive(Clone)]
 struct Obstacle {
@@ -17,6 +31,8 @@ struct Obstacle {
     height: usize,
     is_bird: bool,
     bird_y_offset: f32, // offset from ground (negative = above ground)
+    lane: usize,        // 0 = back, 1 = front (always 1 in one-lane mode)
+    cleared: bool,      // already counted as passed
 }
 
 pub struct DinoRun {
@@ -24,6 +40,9 @@ pub struct DinoRun {
     dino_vy: f32,
     ducking: bool,
     duck_timer: u32,
+    // Two parallel ground lanes, switched with up/down (start screen L)
+    two_lane: bool,
+    lane: usize,
     obstacles: Vec<Obstacle>,
     score: u32,
     high_score: u32,
@@ -31,12 +50,22 @@ pub struct DinoRun {
     game_over: bool,
     started: bool,
     paused: bool,
+    countdown: Countdown,
     tick: u64,
     next_obstacle_tick: u64,
     ground_offset: usize,
     // Dynamic dimensions (updated each render)
     field_width: usize,
     ground_y: f32,
+    /// Obstacle stream; seeded from the day for daily runs
+    rng: GameRng,
+    /// Daily run: UTC day number the run's seed comes from (start screen D)
+    daily: bool,
+    seed_day: u64,
+    // Run stats kept with the score
+    distance: f32,
+    obstacles_cleared: u32,
+    top_speed: f32,
 }
 
 impl DinoRun {
@@ -46,23 +75,97 @@ impl DinoRun {
             dino_vy: 0.0,
             ducking: false,
             duck_timer: 0,
+            two_lane: false,
+            lane: 1,
             obstacles: Vec::new(),
             score: 0,
             high_score: 0,
-            speed: 0.5,
+            speed: START_SPEED,
             game_over: false,
             started: false,
             paused: false,
+            countdown: Countdown::default(),
             tick: 0,
             next_obstacle_tick: 60,
             ground_offset: 0,
             field_width: 70,
             ground_y: 15.0,
+            rng: GameRng::from_entropy(),
+            daily: false,
+            seed_day: 0,
+            distance: 0.0,
+            obstacles_cleared: 0,
+            top_speed: START_SPEED,
         }
     }
 
+    /// Seed for a daily run, the same for everyone on that UTC day. A run
+    /// resumed mid-way reseeds from where it was, so it stays shared too.
+    fn daily_rng(day: u64, tick: u64) -> GameRng {
+        GameRng::new((day ^ DAILY_SALT).wrapping_mul(0x9E37_79B9_7F4A_7C15) ^ tick)
+    }
+
+    fn start_run(&mut self) {
+        self.started = true;
+        self.dino_y = self.dino_ground();
+        if self.daily {
+            self.seed_day = now_secs() / 86_400;
+            self.rng = Self::daily_rng(self.seed_day, 0);
+        }
+    }
+
+    /// Distance run, in columns scrolled.
+    pub fn distance(&self) -> u32 {
+        self.distance as u32
+    }
+
+    /// Score flags (see `scores::flag_labels`): bit 0 for a daily run.
+    pub fn modifier_flags(&self) -> u32 {
+        self.daily as u32
+    }
+
+    /// Obstacles cleared, then top speed in percent of the start speed.
+    pub fn extra_stats(&self) -> Vec<u32> {
+        vec![self.obstacles_cleared, (self.top_speed / START_SPEED * 100.0).round() as u32]
+    }
+
+    /// Ground level of a lane; the back lane sits LANE_GAP rows up.
+    fn lane_ground(&self, lane: usize) -> f32 {
+        if lane == 0 { self.ground_y - LANE_GAP } else { self.ground_y }
+    }
+
+    fn dino_ground(&self) -> f32 {
+        self.lane_ground(self.lane)
+    }
+
+    fn toggle_two_lane(&mut self) {
+        self.two_lane = !self.two_lane;
+        self.lane = 1;
+        self.dino_y = self.ground_y;
+    }
+
     fn spawn_obstacle(&mut self) {
-        let mut rng = rand::thread_rng();
+        if !self.two_lane {
+            self.spawn_in_lane(1, 0.0);
+        } else {
+            // One lane, or now and then both with a stagger: change lanes
+            // into the gap, or jump whichever one you're in front of
+            let lane = self.rng.gen_range(0..2);
+            self.spawn_in_lane(lane, 0.0);
+            if self.score > PAIR_SCORE && self.rng.gen_bool(PAIR_CHANCE) {
+                let offset = self.rng.gen_range(6.0..18.0);
+                self.spawn_in_lane(1 - lane, offset);
+            }
+        }
+
+        // Schedule next obstacle
+        let min_gap = (40.0 / self.speed) as u64;
+        let max_gap = (80.0 / self.speed) as u64;
+        self.next_obstacle_tick = self.tick + self.rng.gen_range(min_gap.max(20)..=max_gap.max(30));
+    }
+
+    fn spawn_in_lane(&mut self, lane: usize, offset: f32) {
+        let rng = &mut self.rng;
         let is_bird = self.score > 200 && rng.gen_bool(0.3);
 
         let obs = if is_bird {
@@ -72,11 +175,13 @@ impl DinoRun {
                 -5.0 // high bird - can run under or jump over
             };
             Obstacle {
-                x: self.field_width as f32 + 5.0,
+                x: self.field_width as f32 + 5.0 + offset,
                 width: 4,
                 height: 1,
                 is_bird: true,
                 bird_y_offset,
+                lane,
+                cleared: false,
             }
         } else {
             let variants: Vec<(usize, usize)> = vec![
@@ -88,30 +193,27 @@ impl DinoRun {
             ];
             let (w, h) = variants[rng.gen_range(0..variants.len())];
             Obstacle {
-                x: self.field_width as f32 + 5.0,
+                x: self.field_width as f32 + 5.0 + offset,
                 width: w,
                 height: h,
                 is_bird: false,
                 bird_y_offset: 0.0,
+                lane,
+                cleared: false,
             }
         };
         self.obstacles.push(obs);
-
-        // Schedule next obstacle
-        let min_gap = (40.0 / self.speed) as u64;
-        let max_gap = (80.0 / self.speed) as u64;
-        self.next_obstacle_tick = self.tick + rng.gen_range(min_gap.max(20)..=max_gap.max(30));
     }
 
     fn check_collision(&self) -> bool {
-        let ground_y = self.ground_y;
         let dino_top = self.dino_y as i32;
         let dino_height: i32 = if self.ducking { 1 } else { 3 };
         let dino_bottom = dino_top + dino_height;
         let dino_left = DINO_X as i32;
         let dino_right = DINO_X as i32 + 3;
 
-        for obs in &self.obstacles {
+        for obs in self.obstacles.iter().filter(|o| o.lane == self.lane) {
+            let ground_y = self.lane_ground(obs.lane);
             let obs_left = obs.x as i32;
             let obs_right = obs_left + obs.width as i32;
             let (obs_top, obs_bottom) = if obs.is_bird {
@@ -134,164 +236,96 @@ impl DinoRun {
         false
     }
 
-    fn render_field(&self, width: usize, height: usize) -> Vec<Line<'static>> {
-        let w = width;
-        let h = height;
-        let ground_row = self.ground_y as usize + 1;
-
-        let mut grid: Vec<Vec<(char, Style)>> =
-            vec![vec![(' ', Style::default()); w]; h];
-
-        // Draw sky gradient (subtle)
-        for y in 0..h.min(ground_row) {
-            let brightness = 15 + (y * 5).min(40);
-            let sky_style = Style::default().fg(Color::Rgb(brightness as u8, brightness as u8, (brightness + 20).min(60) as u8));
-            for x in 0..w {
-                grid[y][x] = (' ', sky_style);
-            }
+    fn draw_obstacle(&self, grid: &mut [Vec<(char, Style)>], obs: &Obstacle, theme: &Theme) {
+        let (w, h) = (grid.first().map_or(0, |r| r.len()), grid.len());
+        let ground_y = self.lane_ground(obs.lane);
+        let ox = obs.x as i32;
+        if ox < -(obs.width as i32) || ox >= w as i32 + 5 {
+            return; // Off screen
         }
-
-        // Draw ground line
-        if ground_row < h {
-            for x in 0..w {
-                let ch = if (x + self.ground_offset) % 8 == 0 {
-                    '▪'
-                } else if (x + self.ground_offset) % 4 == 0 {
-                    '·'
+        if obs.is_bird {
+            let by = (ground_y + obs.bird_y_offset) as i32;
+            if by >= 0 {
+                let by = by as usize;
+                // Draw bird with animation
+                let wing_up = self.tick % 8 < 4;
+                let bird_chars = if wing_up {
+                    vec![' ', '/', '▬', '\\', ' ']
                 } else {
-                    '━'
+                    vec!['\\', '_', '▬', '_', '/']
                 };
-                grid[ground_row][x] = (
-                    ch,
-                    Style::default().fg(Color::Rgb(140, 120, 100)),
-                );
-            }
-        }
-
-        // Draw terrain details below ground
-        for dy in 1..3 {
-            let row = ground_row + dy;
-            if row < h {
-                for x in 0..w {
-                    let hash = (x.wrapping_mul(7) + self.ground_offset.wrapping_mul(3) + dy * 13) % 11;
-                    let (ch, col) = match hash {
-                        0 => ('.', Color::Rgb(80, 70, 55)),
-                        3 => ('·', Color::Rgb(60, 55, 45)),
-                        7 => (',', Color::Rgb(70, 60, 50)),
-                        _ => (' ', Color::Rgb(30, 25, 20)),
-                    };
-                    grid[row][x] = (ch, Style::default().fg(col).bg(Color::Rgb(30, 25, 20)));
-                }
-            }
-        }
-
-        // Draw clouds (decorative, scrolling)
-        let cloud_art = ["  .-~~~-.  ", " /       \\ ", "(  ~cloud~ )", " \\_______/ "];
-        let cloud_starts = [5usize, 30, 55, 80, 110];
-        for &cx_base in &cloud_starts {
-            let cx = ((cx_base + 500).wrapping_sub(self.ground_offset / 4)) % (w + 40);
-            let cy = (cx_base % 4) + 1;
-            for (row_i, row_str) in cloud_art.iter().enumerate() {
-                let y = cy + row_i;
-                if y < h && y < ground_row {
-                    for (col_i, ch) in row_str.chars().enumerate() {
-                        let x = cx.wrapping_add(col_i);
-                        if x < w && ch != ' ' {
-                            grid[y][x] = (ch, Style::default().fg(Color::Rgb(50, 50, 65)));
-                        }
+                for (dx, &ch) in bird_chars.iter().enumerate() {
+                    let x = ox + dx as i32;
+                    if x >= 0 && (x as usize) < w && by < h && ch != ' ' {
+                        grid[by][x as usize] = (
+                            ch,
+                            Style::default()
+                                .fg(theme.paint(Color::Rgb(220, 80, 80), Role::Red))
+                                .add_modifier(Modifier::BOLD),
+                        );
                     }
                 }
             }
-        }
-
-        // Draw obstacles
-        for obs in &self.obstacles {
-            let ox = obs.x as i32;
-            if ox < -(obs.width as i32) || ox >= w as i32 + 5 {
-                continue; // Off screen
-            }
-            if obs.is_bird {
-                let by = (self.ground_y + obs.bird_y_offset) as i32;
-                if by >= 0 {
-                    let by = by as usize;
-                    // Draw bird with animation
-                    let wing_up = self.tick % 8 < 4;
-                    let bird_chars = if wing_up {
-                        vec![' ', '/', '▬', '\\', ' ']
-                    } else {
-                        vec!['\\', '_', '▬', '_', '/']
-                    };
-                    for (dx, &ch) in bird_chars.iter().enumerate() {
+        } else {
+            // Draw cactus with improved graphics
+            let base_y = ground_y as i32 + 1;
+            for dy in 0..obs.height {
+                let y = base_y - 1 - dy as i32;
+                if y >= 0 && (y as usize) < h {
+                    for dx in 0..obs.width {
                         let x = ox + dx as i32;
-                        if x >= 0 && (x as usize) < w && by < h && ch != ' ' {
-                            grid[by][x as usize] = (
+                        if x >= 0 && (x as usize) < w {
+                            let (ch, color) = if dy == obs.height - 1 {
+                                // Top of cactus
+                                if obs.width == 1 {
+                                    ('▲', theme.paint(Color::Rgb(40, 160, 40), Role::Green))
+                                } else if dx == 0 {
+                                    ('╔', theme.paint(Color::Rgb(30, 140, 30), Role::Green))
+                                } else if dx == obs.width - 1 {
+                                    ('╗', theme.paint(Color::Rgb(30, 140, 30), Role::Green))
+                                } else {
+                                    ('▓', theme.paint(Color::Rgb(40, 160, 40), Role::Green))
+                                }
+                            } else if dy == 0 {
+                                // Base of cactus
+                                if dx == 0 {
+                                    ('╚', theme.paint(Color::Rgb(25, 120, 25), Role::Green))
+                                } else if dx == obs.width - 1 {
+                                    ('╝', theme.paint(Color::Rgb(25, 120, 25), Role::Green))
+                                } else {
+                                    ('█', theme.paint(Color::Rgb(30, 130, 30), Role::Green))
+                                }
+                            } else {
+                                // Middle
+                                if dx == 0 || dx == obs.width - 1 {
+                                    ('║', theme.paint(Color::Rgb(25, 120, 25), Role::Green))
+                                } else {
+                                    ('█', theme.paint(Color::Rgb(35, 150, 35), Role::Green))
+                                }
+                            };
+                            grid[y as usize][x as usize] = (
                                 ch,
-                                Style::default()
-                                    .fg(Color::Rgb(220, 80, 80))
-                                    .add_modifier(Modifier::BOLD),
+                                Style::default().fg(color),
                             );
                         }
                     }
                 }
-            } else {
-                // Draw cactus with improved graphics
-                let base_y = self.ground_y as i32 + 1;
-                for dy in 0..obs.height {
-                    let y = base_y - 1 - dy as i32;
-                    if y >= 0 && (y as usize) < h {
-                        for dx in 0..obs.width {
-                            let x = ox + dx as i32;
-                            if x >= 0 && (x as usize) < w {
-                                let (ch, color) = if dy == obs.height - 1 {
-                                    // Top of cactus
-                                    if obs.width == 1 {
-                                        ('▲', Color::Rgb(40, 160, 40))
-                                    } else if dx == 0 {
-                                        ('╔', Color::Rgb(30, 140, 30))
-                                    } else if dx == obs.width - 1 {
-                                        ('╗', Color::Rgb(30, 140, 30))
-                                    } else {
-                                        ('▓', Color::Rgb(40, 160, 40))
-                                    }
-                                } else if dy == 0 {
-                                    // Base of cactus
-                                    if dx == 0 {
-                                        ('╚', Color::Rgb(25, 120, 25))
-                                    } else if dx == obs.width - 1 {
-                                        ('╝', Color::Rgb(25, 120, 25))
-                                    } else {
-                                        ('█', Color::Rgb(30, 130, 30))
-                                    }
-                                } else {
-                                    // Middle
-                                    if dx == 0 || dx == obs.width - 1 {
-                                        ('║', Color::Rgb(25, 120, 25))
-                                    } else {
-                                        ('█', Color::Rgb(35, 150, 35))
-                                    }
-                                };
-                                grid[y as usize][x as usize] = (
-                                    ch,
-                                    Style::default().fg(color),
-                                );
-                            }
-                        }
-                    }
-                }
             }
         }
+    }
 
-        // Draw dino
+    fn draw_dino(&self, grid: &mut [Vec<(char, Style)>], theme: &Theme) {
+        let (w, h) = (grid.first().map_or(0, |r| r.len()), grid.len());
         let dy = self.dino_y as i32;
         if self.ducking {
             // Ducking dino (1 row, wider)
             if dy >= 0 && (dy as usize) < h {
                 let dino_chars = [
-                    ('▶', Color::Rgb(200, 200, 200)),
-                    ('▓', Color::Rgb(180, 180, 180)),
-                    ('▓', Color::Rgb(160, 160, 160)),
-                    ('▬', Color::Rgb(140, 140, 140)),
-                    ('─', Color::Rgb(120, 120, 120)),
+                    ('▶', theme.paint(Color::Rgb(200, 200, 200), Role::Text)),
+                    ('▓', theme.paint(Color::Rgb(180, 180, 180), Role::Text)),
+                    ('▓', theme.paint(Color::Rgb(160, 160, 160), Role::Dim)),
+                    ('▬', theme.paint(Color::Rgb(140, 140, 140), Role::Dim)),
+                    ('─', theme.paint(Color::Rgb(120, 120, 120), Role::Dim)),
                 ];
                 for (i, &(ch, color)) in dino_chars.iter().enumerate() {
                     let x = DINO_X + i;
@@ -310,9 +344,9 @@ impl DinoRun {
             if head_y >= 0 && (head_y as usize) < h {
                 let head = [
                     (' ', Color::Reset),
-                    ('▄', Color::Rgb(180, 180, 180)),
-                    ('█', Color::Rgb(200, 200, 200)),
-                    ('▀', Color::Rgb(200, 50, 50)), // eye
+                    ('▄', theme.paint(Color::Rgb(180, 180, 180), Role::Text)),
+                    ('█', theme.paint(Color::Rgb(200, 200, 200), Role::Text)),
+                    ('▀', theme.paint(Color::Rgb(200, 50, 50), Role::Red)), // eye
                 ];
                 for (i, &(ch, color)) in head.iter().enumerate() {
                     let x = DINO_X + i;
@@ -328,10 +362,10 @@ impl DinoRun {
             let body_y = dy + 1;
             if body_y >= 0 && (body_y as usize) < h {
                 let body = [
-                    ('▄', Color::Rgb(160, 160, 160)),
-                    ('█', Color::Rgb(180, 180, 180)),
-                    ('█', Color::Rgb(200, 200, 200)),
-                    ('▌', Color::Rgb(160, 160, 160)),
+                    ('▄', theme.paint(Color::Rgb(160, 160, 160), Role::Dim)),
+                    ('█', theme.paint(Color::Rgb(180, 180, 180), Role::Text)),
+                    ('█', theme.paint(Color::Rgb(200, 200, 200), Role::Text)),
+                    ('▌', theme.paint(Color::Rgb(160, 160, 160), Role::Dim)),
                 ];
                 for (i, &(ch, color)) in body.iter().enumerate() {
                     let x = DINO_X + i;
@@ -346,7 +380,7 @@ impl DinoRun {
             // Row 2: legs (animated)
             let leg_y = dy + 2;
             if leg_y >= 0 && (leg_y as usize) < h {
-                let legs = if self.dino_y as i32 + 2 >= self.ground_y as i32 {
+                let legs = if self.dino_y as i32 + 2 >= self.dino_ground() as i32 {
                     // On ground - animate running
                     if self.tick % 10 < 5 {
                         [('▘', true), (' ', false), ('▝', true), (' ', false)]
@@ -363,13 +397,103 @@ impl DinoRun {
                         grid[leg_y as usize][x] = (
                             ch,
                             Style::default()
-                                .fg(Color::Rgb(160, 160, 160))
+                                .fg(theme.paint(Color::Rgb(160, 160, 160), Role::Dim))
                                 .add_modifier(Modifier::BOLD),
                         );
                     }
                 }
             }
         }
+    }
+
+    fn render_field(&self, width: usize, height: usize, theme: &Theme) -> Vec<Line<'static>> {
+        let w = width;
+        let h = height;
+        let ground_row = self.ground_y as usize + 1;
+
+        let mut grid: Vec<Vec<(char, Style)>> =
+            vec![vec![(' ', Style::default()); w]; h];
+
+        // Draw sky gradient (subtle)
+        for y in 0..h.min(ground_row) {
+            let brightness = 15 + (y * 5).min(40);
+            let sky_style = Style::default().fg(theme.shade(Color::Rgb(brightness as u8, brightness as u8, (brightness + 20).min(60) as u8), Role::Faint, brightness as f32 / 60.0));
+            for x in 0..w {
+                grid[y][x] = (' ', sky_style);
+            }
+        }
+
+        // Draw ground line
+        if ground_row < h {
+            for x in 0..w {
+                let ch = if (x + self.ground_offset) % 8 == 0 {
+                    '▪'
+                } else if (x + self.ground_offset) % 4 == 0 {
+                    '·'
+                } else {
+                    '━'
+                };
+                grid[ground_row][x] = (
+                    ch,
+                    Style::default().fg(theme.paint(Color::Rgb(140, 120, 100), Role::Dim)),
+                );
+            }
+        }
+
+        // Draw terrain details below ground
+        for dy in 1..3 {
+            let row = ground_row + dy;
+            if row < h {
+                for x in 0..w {
+                    let hash = (x.wrapping_mul(7) + self.ground_offset.wrapping_mul(3) + dy * 13) % 11;
+                    let (ch, col) = match hash {
+                        0 => ('.', theme.paint(Color::Rgb(80, 70, 55), Role::Faint)),
+                        3 => ('·', theme.paint(Color::Rgb(60, 55, 45), Role::Panel)),
+                        7 => (',', theme.paint(Color::Rgb(70, 60, 50), Role::Faint)),
+                        _ => (' ', theme.paint(Color::Rgb(30, 25, 20), Role::Panel)),
+                    };
+                    grid[row][x] = (ch, Style::default().fg(col).bg(theme.paint(Color::Rgb(30, 25, 20), Role::Panel)));
+                }
+            }
+        }
+
+        // Draw clouds (decorative, scrolling)
+        let cloud_art = ["  .-~~~-.  ", " /       \\ ", "(  ~cloud~ )", " \\_______/ "];
+        let cloud_starts = [5usize, 30, 55, 80, 110];
+        for &cx_base in &cloud_starts {
+            let cx = ((cx_base + 500).wrapping_sub(self.ground_offset / 4)) % (w + 40);
+            let cy = (cx_base % 4) + 1;
+            for (row_i, row_str) in cloud_art.iter().enumerate() {
+                let y = cy + row_i;
+                if y < h && y < ground_row {
+                    for (col_i, ch) in row_str.chars().enumerate() {
+                        let x = cx.wrapping_add(col_i);
+                        if x < w && ch != ' ' {
+                            grid[y][x] = (ch, Style::default().fg(theme.paint(Color::Rgb(50, 50, 65), Role::Panel)));
+                        }
+                    }
+                }
+            }
+        }
+
+        // Back lane's ground, a dimmer dashed track above the front one
+        let back_row = self.lane_ground(0) as usize + 1;
+        if self.two_lane && back_row < h {
+            for (x, cell) in grid[back_row].iter_mut().enumerate() {
+                let ch = if (x + self.ground_offset) % 4 < 3 { '─' } else { ' ' };
+                *cell = (ch, Style::default().fg(theme.paint(Color::Rgb(100, 85, 70), Role::Faint)));
+            }
+        }
+
+        // Obstacles and the dino, back lane first so the front one overlaps it
+        for lane in 0..2 {
+            for obs in self.obstacles.iter().filter(|o| o.lane == lane) {
+                self.draw_obstacle(&mut grid, obs, theme);
+            }
+            if self.lane == lane {
+                self.draw_dino(&mut grid, theme);
+            }
+        }
 
         // Convert to lines
         grid.into_iter()
@@ -384,9 +508,31 @@ impl DinoRun {
     }
 }
 
+pub const INFO: GameInfo = GameInfo {
+    name: "Dino Run",
+    icon: "🦖",
+    author: "RustCade",
+    blurb: "Jump obstacles\nin endless run!",
+    description: "Endless runner — dodge everything!",
+    controls: &[
+        ("Space / ↑", "Jump"),
+        ("↓", "Duck"),
+        ("D", "Daily seeded run (start screen)"),
+        ("R", "Restart"),
+        ("P", "Pause"),
+    ],
+    tags: &["arcade", "endless", "reflex"],
+    color: Ink(Color::Rgb(200, 120, 255), Role::Violet),
+    border_color: Ink(Color::Rgb(100, 60, 140), Role::Violet),
+};
+
 impl Game for DinoRun {
+    fn info(&self) -> &'static GameInfo {
+        &INFO
+    }
+
     fn update(&mut self) {
-        if self.game_over || self.paused || !self.started {
+        if self.game_over || self.paused || !self.started || self.countdown.tick() {
             return;
         }
 
@@ -410,28 +556,35 @@ impl Game for DinoRun {
         if self.tick % 200 == 0 {
             self.speed = (self.speed + 0.05).min(1.5);
         }
+        self.distance += self.speed;
+        self.top_speed = self.top_speed.max(self.speed);
 
         // Apply gravity
-        if self.dino_y < self.ground_y {
+        let ground = self.dino_ground();
+        if self.dino_y < ground {
             self.dino_vy += GRAVITY;
         }
         self.dino_y += self.dino_vy;
 
         // Land on ground
-        if self.dino_y >= self.ground_y {
-            self.dino_y = self.ground_y;
+        if self.dino_y >= ground {
+            self.dino_y = ground;
             self.dino_vy = 0.0;
         }
 
         // Can't duck in air
-        if self.dino_y < self.ground_y {
+        if self.dino_y < ground {
             self.ducking = false;
             self.duck_timer = 0;
         }
 
-        // Move obstacles
+        // Move obstacles, counting the ones that make it past the dino
         for obs in &mut self.obstacles {
             obs.x -= self.speed;
+            if !obs.cleared && obs.x + (obs.width as f32) < DINO_X as f32 {
+                obs.cleared = true;
+                self.obstacles_cleared += 1;
+            }
         }
 
         // Remove off-screen obstacles
@@ -444,6 +597,7 @@ impl Game for DinoRun {
 
         // Check collision
         if self.check_collision() {
+            audio::emit(AudioEvent::DinoCrash);
             self.game_over = true;
             if self.score > self.high_score {
                 self.high_score = self.score;
@@ -458,7 +612,7 @@ impl Game for DinoRun {
             }
             KeyCode::Char('p') | KeyCode::Char('P') => {
                 if !self.game_over && self.started {
-                    self.paused = !self.paused;
+                    self.countdown.toggle_pause(&mut self.paused);
                 }
             }
             _ => {
@@ -471,28 +625,40 @@ impl Game for DinoRun {
                 }
                 if !self.started {
                     match key.code {
-                        KeyCode::Char(' ') | KeyCode::Up | KeyCode::Enter => {
-                            self.started = true;
-                            self.dino_y = self.ground_y;
-                        }
+                        KeyCode::Char(' ') | KeyCode::Up | KeyCode::Enter => self.start_run(),
+                        KeyCode::Char('l') | KeyCode::Char('L') => self.toggle_two_lane(),
+                        KeyCode::Char('d') | KeyCode::Char('D') => self.daily = !self.daily,
                         _ => {}
                     }
                     return;
                 }
-                if self.paused {
+                if self.paused || self.countdown.is_running() {
                     return;
                 }
+                let on_ground = self.dino_y >= self.dino_ground();
                 match key.code {
+                    // Two lanes: up/down change lane on the ground; up in the
+                    // back lane still jumps and down in the front one ducks
+                    KeyCode::Up if self.two_lane && on_ground && self.lane == 1 => {
+                        self.lane = 0;
+                        self.dino_y = self.dino_ground();
+                        self.ducking = false;
+                    }
+                    KeyCode::Down if self.two_lane && on_ground && self.lane == 0 => {
+                        self.lane = 1;
+                        self.dino_y = self.dino_ground();
+                    }
                     KeyCode::Char(' ') | KeyCode::Up => {
                         // Jump (only if on ground)
-                        if self.dino_y >= self.ground_y {
+                        if on_ground {
+                            audio::emit(AudioEvent::DinoJump);
                             self.dino_vy = JUMP_VELOCITY;
                             self.ducking = false;
                             self.duck_timer = 0;
                         }
                     }
                     KeyCode::Down => {
-                        if self.dino_y >= self.ground_y {
+                        if on_ground {
                             // Duck - set timer
                             self.ducking = true;
                             self.duck_timer = DUCK_DURATION;
@@ -507,17 +673,8 @@ impl Game for DinoRun {
         }
     }
 
-    fn render(&mut self, frame: &mut Frame, area: Rect) {
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Color::Rgb(180, 100, 220)))
-            .title(" 🦖 Dino Run ")
-            .title_style(
-                Style::default()
-                    .fg(Color::Rgb(200, 120, 255))
-                    .add_modifier(Modifier::BOLD),
-            );
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = self.title_bar(theme);
 
         let inner = block.inner(area);
         frame.render_widget(block, area);
@@ -525,12 +682,17 @@ impl Game for DinoRun {
         // Update dynamic dimensions based on available space
         let field_height = inner.height.saturating_sub(2) as usize;
         let new_field_width = inner.width as usize;
-        let new_ground_y = (field_height as f32 * 0.72).max(8.0);
+        // Two lanes sit lower to leave jumping room above the back one
+        let new_ground_y = if self.two_lane {
+            (field_height as f32 * 0.8).max(8.0 + LANE_GAP)
+        } else {
+            (field_height as f32 * 0.72).max(8.0)
+        };
 
         // Update dimensions (only if not mid-game or if starting)
         if !self.started || self.game_over {
             self.ground_y = new_ground_y;
-            self.dino_y = new_ground_y;
+            self.dino_y = self.dino_ground();
         }
         self.field_width = new_field_width;
 
@@ -549,18 +711,35 @@ impl Game for DinoRun {
             Span::styled(
                 format!("Score: {:05} ", self.score),
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(theme.paint(Color::Yellow, Role::Yellow))
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             Span::styled(
                 format!("🏆 High: {:05} ", self.high_score),
-                Style::default().fg(Color::Cyan),
+                Style::default().fg(theme.paint(Color::Cyan, Role::Cyan)),
+            ),
+            Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+            Span::styled(
+                format!("⚡ Speed: {:.1}x ", self.speed / START_SPEED),
+                Style::default().fg(theme.paint(Color::Green, Role::Green)),
+            ),
+            Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+            Span::styled(
+                format!("{} m · {} cleared ", self.distance(), self.obstacles_cleared),
+                Style::default().fg(theme.paint(Color::Rgb(180, 180, 200), Role::Text)),
+            ),
+            Span::styled(
+                if self.two_lane { format!(" │ Lanes: {} ", if self.lane == 0 { "▲ back" } else { "▼ front" }) } else { String::new() },
+                Style::default().fg(theme.paint(Color::Rgb(200, 120, 255), Role::Violet)),
             ),
-            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
             Span::styled(
-                format!("⚡ Speed: {:.1}x ", self.speed / 0.5),
-                Style::default().fg(Color::Green),
+                match (self.daily, self.started) {
+                    (false, _) => String::new(),
+                    (true, false) => " │ Daily run ".to_string(),
+                    (true, true) => format!(" │ Daily {} ", format_date(self.seed_day * 86_400)),
+                },
+                Style::default().fg(theme.paint(Color::Rgb(255, 200, 80), Role::Orange)).add_modifier(Modifier::BOLD),
             ),
         ]);
         frame.render_widget(Paragraph::new(status), chunks[0]);
@@ -568,76 +747,105 @@ impl Game for DinoRun {
         // Game field
         let fw = chunks[1].width as usize;
         let fh = chunks[1].height as usize;
-        let lines = self.render_field(fw, fh);
+        let lines = self.render_field(fw, fh, theme);
         frame.render_widget(Paragraph::new(lines), chunks[1]);
+        self.countdown.render(frame, chunks[1], theme);
 
         // Help / overlay
-        if self.game_over {
-            let msg = Paragraph::new(Line::from(vec![
-                Span::styled(
-                    " 💀 GAME OVER! ",
-                    Style::default()
-                        .fg(Color::Red)
-                        .add_modifier(Modifier::BOLD),
-                ),
-                Span::styled(
-                    format!("Score: {} │ ", self.score),
-                    Style::default().fg(Color::Yellow),
-                ),
-                Span::styled(
-                    "Press ENTER to restart, Esc for menu",
-                    Style::default().fg(Color::Gray),
-                ),
-            ]));
-            frame.render_widget(msg, chunks[2]);
+        let footer = if self.game_over {
+            hud::game_over(&format!(
+                "Score: {} │ {} m, {} obstacles, top {:.1}x │ Press ENTER to restart, Esc for menu",
+                self.score, self.distance(), self.obstacles_cleared, self.top_speed / START_SPEED,
+            ), theme)
         } else if !self.started {
-            let msg = Paragraph::new(Line::from(vec![
-                Span::styled(
-                    " ▶ Press SPACE to start! ",
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD),
-                ),
-                Span::styled(
-                    "SPACE/↑ Jump │ ↓ Duck │ P Pause │ R Restart │ Esc Menu",
-                    Style::default().fg(Color::DarkGray),
-                ),
-            ]));
-            frame.render_widget(msg, chunks[2]);
+            hud::menu_legend(
+                Some("▶ Press SPACE to start!"),
+                &[
+                    ("SPACE/↑", "Jump"),
+                    ("↓", "Duck"),
+                    ("L", if self.two_lane { "One lane" } else { "Two lanes" }),
+                    ("D", if self.daily { "Random run" } else { "Daily run" }),
+                ],
+                theme,
+            )
         } else if self.paused {
-            let msg = Paragraph::new(Line::from(vec![Span::styled(
-                " ⏸ PAUSED - Press P to resume ",
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD),
-            )]));
-            frame.render_widget(msg, chunks[2]);
+            hud::pause_overlay(frame, chunks[1], theme);
+            hud::paused(theme)
+        } else if self.two_lane {
+            hud::legend(None, &[("SPACE", "Jump"), ("↑↓", "Lane"), ("↓ front", "Duck")], theme)
         } else {
-            let help = Paragraph::new(Line::from(vec![
-                Span::styled(" SPACE/↑ Jump ", Style::default().fg(Color::DarkGray)),
-                Span::styled("│ ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("↓ Duck ", Style::default().fg(Color::DarkGray)),
-                Span::styled("│ ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("P Pause ", Style::default().fg(Color::DarkGray)),
-                Span::styled("│ ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("R Restart ", Style::default().fg(Color::DarkGray)),
-                Span::styled("│ ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("Esc Menu ", Style::default().fg(Color::DarkGray)),
-                Span::styled("│ ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("? Help", Style::default().fg(Color::DarkGray)),
-            ]));
-            frame.render_widget(help, chunks[2]);
-        }
+            hud::legend(None, &[("SPACE/↑", "Jump"), ("↓", "Duck")], theme)
+        };
+        frame.render_widget(Paragraph::new(footer), chunks[2]);
     }
 
     fn get_score(&self) -> u32 { self.score }
     fn is_game_over(&self) -> bool { self.game_over }
+    fn is_paused(&self) -> bool { self.paused }
+
+    fn reseed(&mut self, rng: GameRng) {
+        self.rng = rng;
+        self.reset();
+    }
+
+    fn serialize_state(&self) -> Option<Vec<u8>> {
+        if self.game_over || !self.started { return None; }
+        let mut w = StateWriter::new();
+        w.u32(self.score);
+        w.u32(self.high_score);
+        w.f32(self.speed);
+        w.u64(self.tick);
+        w.bool(self.two_lane);
+        w.u8(self.lane as u8);
+        w.bool(self.daily);
+        w.u64(self.seed_day);
+        w.f32(self.distance);
+        w.u32(self.obstacles_cleared);
+        Some(w.finish())
+    }
+
+    fn restore_state(&mut self, data: &[u8]) -> bool {
+        let mut r = StateReader::new(data);
+        let (Some(score), Some(high_score), Some(speed), Some(tick)) = (r.u32(), r.u32(), r.f32(), r.u64()) else { return false };
+        // Saves from before two-lane mode end here
+        let two_lane = r.bool().unwrap_or(false);
+        let lane = r.u8().map_or(1, |l| l.min(1) as usize);
+        // ...and before daily runs and run stats
+        let (daily, seed_day) = (r.bool().unwrap_or(false), r.u64().unwrap_or(0));
+        let (distance, obstacles_cleared) = (r.f32().unwrap_or(0.0), r.u32().unwrap_or(0));
+        // Clear track ahead, paused, at the saved distance and speed
+        self.reset();
+        self.two_lane = two_lane;
+        self.lane = if two_lane { lane } else { 1 };
+        self.dino_y = self.dino_ground();
+        self.score = score;
+        self.high_score = self.high_score.max(high_score);
+        self.speed = speed;
+        self.top_speed = speed;
+        self.tick = tick;
+        self.distance = distance;
+        self.obstacles_cleared = obstacles_cleared;
+        self.daily = daily;
+        self.seed_day = seed_day;
+        if daily {
+            self.rng = Self::daily_rng(seed_day, tick);
+        }
+        self.next_obstacle_tick = tick + 60;
+        self.started = true;
+        self.paused = true;
+        true
+    }
 
     fn reset(&mut self) {
         let hs = self.high_score;
         let fw = self.field_width;
         let gy = self.ground_y;
+        let (two_lane, daily) = (self.two_lane, self.daily);
+        let rng = self.rng.clone();
         *self = DinoRun::new();
+        self.rng = rng;
+        self.two_lane = two_lane;
+        self.daily = daily;
         self.high_score = hs;
         self.field_width = fw;
         self.ground_y = gy;
@@ -3,9 +3,19 @@ use rand::Rng;
 use ratatui::prelude::*;
 use ratatui::widgets::*;
 
-use crate::games::Game;
+use crate::games::frogger_skins::{self, Skin, SKINS};
+use crate::games::frogger_splits::{self, SplitBook, HOMES};
+use crate::games::{Game, GameInfo};
+use crate::rng::GameRng;
+use crate::session::{StateReader, StateWriter};
+use crate::ui::braille::BrailleCanvas;
+use crate::ui::fx;
+use crate::ui::hud::{self, Countdown};
+use crate::ui::theme::{Ink, Role, Theme};
 
 const NUM_LANES: usize = 13;
+/// Fraction of the remaining distance the drawn frog covers each tick
+const HOP_EASE: f32 = 0.5;
 
 #[derive(Clone)]
 struct Lane {
@@ -25,12 +35,21 @@ enum LaneType {
 #[derive(Clone)]
 struct Obj {
     x: f32,
+    /// Position before the last update, for render interpolation
+    prev_x: f32,
     width: i32,
 }
 
 pub struct Frogger {
-    frog_x: i32,
+    // Logical position: collisions use the lane index and this x
+    frog_x: f32,
     frog_y: usize,
+    // Drawn position (x in cells, y in lanes), easing toward the logical one
+    draw_x: f32,
+    draw_y: f32,
+    prev_draw_x: f32,
+    prev_draw_y: f32,
+    render_alpha: f32,
     lanes: Vec<Lane>,
     score: u32,
     high_score: u32,
@@ -38,17 +57,120 @@ pub struct Frogger {
     game_over: bool,
     won: bool,
     paused: bool,
+    countdown: Countdown,
     tick: u64,
     goals_reached: [bool; 5],
     field_width: i32,
+    show_hitboxes: bool,
+    // Skin from the config, whether seasonal skins may stand in for it,
+    // and the one picked with S this session
+    skin: usize,
+    seasonal: bool,
+    picked_skin: Option<usize>,
+    // Where each lane's logs and cars start
+    rng: GameRng,
+    // Speedrun timer (T): the seed this layout was drawn from, None once
+    // a resumed run has its lanes redrawn; the tick each pad was filled
+    // on, and which of those segments were golds
+    show_timer: bool,
+    run_seed: Option<u64>,
+    splits: Vec<u64>,
+    gold_splits: Vec<bool>,
+    /// Fastest each segment has been run on this seed, at the start of the run
+    golds: [u64; HOMES],
+    new_best: bool,
+    practice: bool,
+    split_book: SplitBook,
 }
 
 impl Frogger {
     pub fn new() -> Self {
+        let mut f = Self::with_rng(GameRng::from_entropy());
+        f.reload_splits();
+        f
+    }
+
+    /// Read the best splits again, e.g. after the saved progress was cleared.
+    pub fn reload_splits(&mut self) {
+        self.split_book = SplitBook::load();
+        self.golds = self.run_seed.map_or([0; HOMES], |seed| self.split_book.golds(seed));
+    }
+
+    /// Split on a pad filled: keep any gold and, on the last pad, the
+    /// clear if it's the best on this seed. Practice runs aren't kept.
+    fn split(&mut self) {
+        let index = self.splits.len();
+        if index >= HOMES { return; }
+        let segment = self.tick - self.splits.last().copied().unwrap_or(0);
+        self.splits.push(self.tick);
+        let seed = self.run_seed.filter(|_| !self.practice);
+        self.gold_splits.push(seed.is_some_and(|seed| self.split_book.split(seed, index, segment)));
+        if let (Some(seed), HOMES) = (seed, self.splits.len()) {
+            self.new_best = self.split_book.finish(seed, &self.splits);
+        }
+    }
+
+    /// Splits so far and the one running, each against its gold, for the
+    /// footer.
+    fn split_line(&self, theme: &Theme) -> Line<'static> {
+        let gold = Style::default().fg(theme.paint(Color::Rgb(255, 215, 0), Role::Yellow)).add_modifier(Modifier::BOLD);
+        let ahead = Style::default().fg(theme.paint(Color::Rgb(80, 220, 120), Role::Green));
+        let behind = Style::default().fg(theme.paint(Color::Rgb(255, 100, 100), Role::Red));
+        let dim = Style::default().fg(theme.paint(Color::DarkGray, Role::Faint));
+        let mut spans = vec![
+            Span::styled(format!(" ⏱ {} ", frogger_splits::format_time(self.tick)), Style::default().fg(theme.paint(Color::White, Role::Bright)).add_modifier(Modifier::BOLD)),
+        ];
+        if self.run_seed.is_none() {
+            spans.push(Span::styled("│ resumed run, splits not kept ", dim));
+            return Line::from(spans);
+        }
+        let mut start = 0;
+        for i in 0..HOMES {
+            spans.push(Span::styled("│ ", dim));
+            let (end, done) = match self.splits.get(i) {
+                Some(&end) => (end, true),
+                None if i == self.splits.len() => (self.tick, false),
+                None => {
+                    spans.push(Span::styled(format!("{} · ", i + 1), dim));
+                    continue;
+                }
+            };
+            let segment = end - start;
+            start = end;
+            let text = match self.golds[i] {
+                0 => frogger_splits::format_time(segment),
+                g => frogger_splits::format_delta(segment as i64 - g as i64),
+            };
+            let style = match (done, self.golds[i]) {
+                _ if self.gold_splits.get(i) == Some(&true) => gold,
+                (_, 0) => Style::default().fg(theme.paint(Color::Gray, Role::Dim)),
+                (false, g) if segment <= g => dim,
+                (_, g) if segment <= g => ahead,
+                _ => behind,
+            };
+            let mark = if done { "" } else { "▸" };
+            spans.push(Span::styled(format!("{} {}{} ", i + 1, mark, text), style));
+        }
+        if let Some(best) = self.run_seed.and_then(|seed| self.split_book.personal_best(seed)) {
+            spans.push(Span::styled(format!("│ best {} ", frogger_splits::format_time(best)), dim));
+        }
+        spans.push(Span::styled("│ T Hide ", dim));
+        Line::from(spans)
+    }
+
+    fn with_rng(rng: GameRng) -> Self {
         let fw = 80;
+        let start_x = fw as f32 / 2.0;
+        let start_y = (NUM_LANES - 1) as f32;
+        let run_seed = Some(rng.state());
         let mut f = Self {
-            frog_x: fw / 2,
+            frog_x: start_x,
             frog_y: NUM_LANES - 1,
+            draw_x: start_x,
+            draw_y: start_y,
+            prev_draw_x: start_x,
+            prev_draw_y: start_y,
+            render_alpha: 0.0,
             lanes: Vec::new(),
             score: 0,
             high_score: 0,
@@ -56,17 +178,50 @@ impl Frogger {
             game_over: false,
             won: false,
             paused: false,
+            countdown: Countdown::default(),
             tick: 0,
             goals_reached: [false; 5],
             field_width: fw,
+            show_hitboxes: false,
+            skin: 0,
+            seasonal: true,
+            picked_skin: None,
+            rng,
+            show_timer: false,
+            run_seed,
+            splits: Vec::new(),
+            gold_splits: Vec::new(),
+            golds: [0; HOMES],
+            new_best: false,
+            practice: false,
+            split_book: SplitBook::default(),
         };
         f.init_lanes();
         f
     }
 
+    /// Skin from `[frogger]` in the config; S still overrides it.
+    pub fn set_skin(&mut self, skin: usize, seasonal: bool) {
+        self.skin = skin.min(SKINS.len() - 1);
+        self.seasonal = seasonal;
+    }
+
+    fn skin_index(&self) -> usize {
+        let today = || {
+            std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map_or(0, |d| d.as_secs())
+        };
+        self.picked_skin
+            .or_else(|| if self.seasonal { frogger_skins::seasonal(today()) } else { None })
+            .unwrap_or(self.skin)
+    }
+
+    fn skin(&self) -> &'static Skin {
+        SKINS[self.skin_index()]
+    }
+
     fn init_lanes(&mut self) {
         self.lanes.clear();
-        let mut rng = rand::thread_rng();
+        let mut rng = self.rng.clone();
 
         for i in 0..NUM_LANES {
             let lane = match i {
@@ -86,6 +241,7 @@ impl Frogger {
             };
             self.lanes.push(lane);
         }
+        self.rng = rng;
     }
 
     fn make_water_lane(&self, speed: f32, log_width: i32, rng: &mut impl Rng) -> Lane {
@@ -93,7 +249,7 @@ impl Frogger {
         let mut x = rng.gen_range(0..10) as f32;
         let fw = self.field_width as f32;
         while x < fw + 30.0 {
-            objects.push(Obj { x, width: log_width });
+            objects.push(Obj { x, prev_x: x, width: log_width });
             x += (log_width as f32) + rng.gen_range(6.0..14.0);
         }
         Lane { lane_type: LaneType::Water, speed, objects }
@@ -104,7 +260,7 @@ impl Frogger {
         let mut x = rng.gen_range(0..10) as f32;
         let fw = self.field_width as f32;
         while x < fw + 30.0 {
-            objects.push(Obj { x, width: car_width });
+            objects.push(Obj { x, prev_x: x, width: car_width });
             x += (car_width as f32) + rng.gen_range(8.0..18.0);
         }
         Lane { lane_type: LaneType::Road, speed, objects }
@@ -126,7 +282,7 @@ impl Frogger {
                 let goals = self.goal_positions();
                 let mut scored = false;
                 for (i, &gx) in goals.iter().enumerate() {
-                    if i < 5 && (fx - gx).abs() <= 2 && !self.goals_reached[i] {
+                    if i < 5 && (fx - gx as f32).abs() <= 2.0 && !self.goals_reached[i] {
                         self.goals_reached[i] = true;
                         self.score += 100;
                         scored = true;
@@ -134,20 +290,19 @@ impl Frogger {
                     }
                 }
                 if scored {
+                    self.split();
                     if self.goals_reached.iter().all(|&g| g) {
                         self.won = true;
                         self.score += 500;
                     }
-                    self.frog_x = self.field_width / 2;
-                    self.frog_y = NUM_LANES - 1;
+                    self.respawn_frog();
                 } else {
                     self.lose_life();
                 }
             }
             LaneType::Road => {
                 for obj in &lane.objects {
-                    let ox = obj.x as i32;
-                    if fx >= ox && fx < ox + obj.width {
+                    if fx >= obj.x && fx < obj.x + obj.width as f32 {
                         self.lose_life();
                         return;
                     }
@@ -156,8 +311,7 @@ impl Frogger {
             LaneType::Water => {
                 let mut on_log = false;
                 for obj in &lane.objects {
-                    let ox = obj.x as i32;
-                    if fx >= ox && fx < ox + obj.width {
+                    if fx >= obj.x && fx < obj.x + obj.width as f32 {
                         on_log = true;
                         break;
                     }
@@ -177,9 +331,47 @@ impl Frogger {
             if self.score > self.high_score {
                 self.high_score = self.score;
             }
+        } else {
+            self.countdown.start();
         }
-        self.frog_x = self.field_width / 2;
+        self.respawn_frog();
+    }
+
+    /// Back to the start, with no hop animation from where the frog was.
+    fn respawn_frog(&mut self) {
+        self.frog_x = self.field_width as f32 / 2.0;
         self.frog_y = NUM_LANES - 1;
+        self.draw_x = self.frog_x;
+        self.draw_y = self.frog_y as f32;
+        self.prev_draw_x = self.draw_x;
+        self.prev_draw_y = self.draw_y;
+    }
+
+    /// Ease the drawn frog toward its logical cell so hops glide.
+    fn ease_frog(&mut self) {
+        let ease = |d: f32, target: f32| {
+            let next = d + (target - d) * HOP_EASE;
+            if (target - next).abs() < 0.05 { target } else { next }
+        };
+        self.draw_x = ease(self.draw_x, self.frog_x);
+        self.draw_y = ease(self.draw_y, self.frog_y as f32);
+    }
+
+    fn snapshot_positions(&mut self) {
+        self.prev_draw_x = self.draw_x;
+        self.prev_draw_y = self.draw_y;
+        for lane in &mut self.lanes {
+            for obj in &mut lane.objects {
+                obj.prev_x = obj.x;
+            }
+        }
+    }
+
+    /// Blend from the previous update toward the current value; jumps
+    /// (traffic wrapping around) are drawn at the new spot.
+    fn lerp(&self, prev: f32, cur: f32) -> f32 {
+        if (cur - prev).abs() > 10.0 { return cur; }
+        prev + (cur - prev) * self.render_alpha
     }
 
     fn move_frog_with_log(&mut self) {
@@ -187,16 +379,20 @@ impl Frogger {
             let lane = &self.lanes[self.frog_y];
             if lane.lane_type == LaneType::Water {
                 let speed = lane.speed;
-                self.frog_x = (self.frog_x as f32 + speed).round() as i32;
-                if self.frog_x < 0 || self.frog_x >= self.field_width {
+                self.frog_x += speed;
+                self.draw_x += speed;
+                if self.frog_x < 0.0 || self.frog_x >= self.field_width as f32 {
                     self.lose_life();
                 }
             }
         }
     }
 
-    fn render_lane(&self, lane_idx: usize, width: usize) -> Line<'static> {
+    /// Lane scenery for one row; vehicles, logs and the frog are drawn on top
+    /// through braille layers.
+    fn lane_background(&self, lane_idx: usize, width: usize, theme: &Theme) -> Vec<(char, Style)> {
         let lane = &self.lanes[lane_idx];
+        let skin = self.skin();
         let w = width;
         let mut chars: Vec<(char, Style)> = vec![(' ', Style::default()); w];
 
@@ -205,20 +401,15 @@ impl Frogger {
                 // Grass with texture
                 for (x, c) in chars.iter_mut().enumerate() {
                     let hash = (x.wrapping_mul(7) + lane_idx * 13) % 5;
-                    let (ch, green) = match hash {
-                        0 => ('"', 90),
-                        1 => ('.', 70),
-                        2 => (',', 80),
-                        _ => (' ', 60),
-                    };
-                    *c = (ch, Style::default().fg(Color::Rgb(30, green as u8, 20)).bg(Color::Rgb(15, 45, 10)));
+                    let (ch, fg) = skin.grass[hash.min(3)];
+                    *c = (ch, Style::default().fg(theme.ink(fg)).bg(theme.ink(skin.grass_bg)));
                 }
             }
             LaneType::Goal => {
                 // Water with goal pads
-                let water_style = Style::default().fg(Color::Rgb(40, 80, 180)).bg(Color::Rgb(10, 30, 100));
+                let water_style = Style::default().fg(theme.ink(skin.water_fg)).bg(theme.ink(skin.water_bg));
                 for (x, c) in chars.iter_mut().enumerate() {
-                    let ch = if (x + self.tick as usize / 3) % 3 == 0 { '~' } else { '≈' };
+                    let ch = if (x + self.tick as usize / 3) % 3 == 0 { skin.water[0] } else { skin.water[1] };
                     *c = (ch, water_style);
                 }
                 let goals = self.goal_positions();
@@ -228,15 +419,15 @@ impl Frogger {
                         let x = gx + dx;
                         if x >= 0 && (x as usize) < w {
                             if self.goals_reached[i] {
-                                chars[x as usize] = ('★', Style::default()
-                                    .fg(Color::Rgb(50, 220, 50))
-                                    .bg(Color::Rgb(15, 60, 15))
+                                chars[x as usize] = (skin.reached, Style::default()
+                                    .fg(theme.ink(skin.reached_fg))
+                                    .bg(theme.ink(skin.reached_bg))
                                     .add_modifier(Modifier::BOLD));
                             } else {
-                                let ch = if dx == -2 || dx == 2 { '┃' } else if dx == 0 { '▼' } else { '─' };
+                                let ch = if dx == -2 || dx == 2 { skin.pad[0] } else if dx == 0 { skin.pad[1] } else { skin.pad[2] };
                                 chars[x as usize] = (ch, Style::default()
-                                    .fg(Color::Rgb(200, 200, 50))
-                                    .bg(Color::Rgb(40, 40, 15)));
+                                    .fg(theme.ink(skin.pad_fg))
+                                    .bg(theme.ink(skin.pad_bg)));
                             }
                         }
                     }
@@ -244,112 +435,133 @@ impl Frogger {
             }
             LaneType::Road => {
                 // Asphalt road with markings
-                let road_bg = Color::Rgb(35, 35, 40);
+                let road_bg = skin.road_bg;
                 for (_x, c) in chars.iter_mut().enumerate() {
-                    *c = (' ', Style::default().bg(road_bg));
+                    *c = (' ', Style::default().bg(theme.ink(road_bg)));
                 }
                 // Lane markings
                 let marking_offset = if lane.speed > 0.0 { self.tick as usize / 2 } else { 1000usize.wrapping_sub(self.tick as usize / 2) };
                 for x in 0..w {
                     if (x + marking_offset) % 8 < 3 {
-                        chars[x] = ('─', Style::default().fg(Color::Rgb(120, 120, 40)).bg(road_bg));
-                    }
-                }
-                // Draw vehicles
-                let car_colors = [
-                    Color::Rgb(220, 50, 50),    // Red
-                    Color::Rgb(50, 120, 220),   // Blue
-                    Color::Rgb(220, 180, 30),   // Yellow
-                    Color::Rgb(180, 50, 200),   // Purple
-                    Color::Rgb(220, 120, 30),   // Orange
-                ];
-                let color = car_colors[lane_idx % car_colors.len()];
-                for obj in &lane.objects {
-                    let ox = obj.x as i32;
-                    for dx in 0..obj.width {
-                        let x = ox + dx;
-                        if x >= 0 && (x as usize) < w {
-                            let (ch, fg) = if dx == 0 {
-                                if lane.speed > 0.0 { ('▶', color) } else { ('◀', color) }
-                            } else if dx == obj.width - 1 {
-                                ('█', Color::Rgb(60, 60, 60))
-                            } else if dx == 1 {
-                                ('█', color)
-                            } else {
-                                ('▓', color)
-                            };
-                            chars[x as usize] = (ch, Style::default().fg(fg).bg(road_bg));
-                        }
+                        chars[x] = (skin.marking, Style::default().fg(theme.ink(skin.marking_fg)).bg(theme.ink(road_bg)));
                     }
                 }
             }
             LaneType::Water => {
                 // Animated water
-                let water_bg = Color::Rgb(10, 30, 100);
                 for (x, c) in chars.iter_mut().enumerate() {
                     let phase = (x + self.tick as usize / 2 + lane_idx * 3) % 4;
-                    let ch = match phase {
-                        0 => '~',
-                        1 => '≈',
-                        2 => '~',
-                        _ => '∽',
-                    };
-                    *c = (ch, Style::default().fg(Color::Rgb(50, 100, 200)).bg(water_bg));
+                    *c = (skin.water[phase], Style::default().fg(theme.ink(skin.water_fg)).bg(theme.ink(skin.water_bg)));
                 }
-                // Draw logs
-                let log_color = Color::Rgb(140, 90, 40);
-                let log_dark = Color::Rgb(100, 65, 25);
-                for obj in &lane.objects {
-                    let ox = obj.x as i32;
-                    for dx in 0..obj.width {
-                        let x = ox + dx;
-                        if x >= 0 && (x as usize) < w {
-                            let (ch, fg) = if dx == 0 {
-                                ('╣', log_dark)
-                            } else if dx == obj.width - 1 {
-                                ('╠', log_dark)
-                            } else if dx % 3 == 0 {
-                                ('█', log_dark)
-                            } else {
-                                ('▓', log_color)
-                            };
-                            chars[x as usize] = (ch, Style::default().fg(fg).bg(Color::Rgb(80, 50, 20)));
-                        }
-                    }
+            }
+        }
+
+        chars
+    }
+    /// Vehicles, logs and the frog at sub-cell positions. Each lane is
+    /// `rows_per_lane` cells, i.e. four braille dots per row, tall.
+    fn draw_movers(&self, grid: &mut [Vec<(char, Style)>], cols: usize, rows_per_lane: usize, theme: &Theme) {
+        let rows = grid.len();
+        let lane_dots = (rows_per_lane * 4) as i32;
+        let skin = self.skin();
+        for (i, lane) in self.lanes.iter().enumerate() {
+            let (color, bold) = match lane.lane_type {
+                LaneType::Road => (skin.cars[i % skin.cars.len()], true),
+                LaneType::Water => (skin.logs, false),
+                _ => continue,
+            };
+            let top = i as i32 * lane_dots;
+            let mut layer = BrailleCanvas::new(cols, rows);
+            for obj in &lane.objects {
+                let x = self.lerp(obj.prev_x, obj.x);
+                let x0 = (x * 2.0).round() as i32;
+                let x1 = ((x + obj.width as f32) * 2.0).round() as i32;
+                layer.fill_rect(x0, top, x1, top + lane_dots);
+            }
+            layer.draw(grid, theme.ink(color), None, bold);
+        }
+
+        // Frog, centred in its lane and gliding between lanes mid-hop
+        let fx = self.lerp(self.prev_draw_x, self.draw_x);
+        let fy = self.prev_draw_y + (self.draw_y - self.prev_draw_y) * self.render_alpha;
+        let left = (fx * 2.0).round() as i32 - 2;
+        let top = (fy * lane_dots as f32).round() as i32 + (lane_dots - 4) / 2;
+        let mut frog = BrailleCanvas::new(cols, rows);
+        for (dy, row) in skin.frog.iter().enumerate() {
+            for (dx, c) in row.chars().enumerate() {
+                if c == '#' {
+                    frog.set(left + dx as i32, top + dy as i32);
                 }
             }
         }
+        frog.draw(grid, theme.ink(skin.frog_fg), Some(theme.ink(skin.frog_bg)), true);
+
+        if self.show_hitboxes {
+            self.draw_hitboxes(grid, cols, lane_dots, theme);
+        }
+    }
 
-        // Draw frog
-        if lane_idx == self.frog_y {
-            let fx = self.frog_x;
-            if fx >= 0 && (fx as usize) < w {
-                let frog_style = Style::default()
-                    .fg(Color::Rgb(255, 255, 255))
-                    .bg(Color::Rgb(30, 180, 30))
-                    .add_modifier(Modifier::BOLD);
-                // Draw a 3-char wide frog
-                if fx > 0 && ((fx - 1) as usize) < w {
-                    chars[(fx - 1) as usize] = ('(', frog_style);
+    /// What `check_collision` tests: the frog is a single point at its
+    /// logical x, against each object's span or each goal's window.
+    fn draw_hitboxes(&self, grid: &mut [Vec<(char, Style)>], cols: usize, lane_dots: i32, theme: &Theme) {
+        let mut boxes = BrailleCanvas::new(cols, grid.len());
+        for (i, lane) in self.lanes.iter().enumerate() {
+            let top = i as i32 * lane_dots;
+            match lane.lane_type {
+                LaneType::Road | LaneType::Water => {
+                    for obj in &lane.objects {
+                        let x = self.lerp(obj.prev_x, obj.x);
+                        let x0 = (x * 2.0).round() as i32;
+                        let x1 = ((x + obj.width as f32) * 2.0).round() as i32;
+                        boxes.rect(x0, top, x1, top + lane_dots);
+                    }
                 }
-                chars[fx as usize] = ('▲', frog_style);
-                if ((fx + 1) as usize) < w {
-                    chars[(fx + 1) as usize] = (')', frog_style);
+                LaneType::Goal => {
+                    for (g, &gx) in self.goal_positions().iter().enumerate() {
+                        if !self.goals_reached[g] {
+                            boxes.rect((gx - 2) * 2, top, (gx + 2) * 2 + 1, top + lane_dots);
+                        }
+                    }
                 }
+                LaneType::Safe => {}
             }
         }
-
-        let spans: Vec<Span<'static>> = chars
-            .into_iter()
-            .map(|(ch, style)| Span::styled(String::from(ch), style))
-            .collect();
-        Line::from(spans)
+        let x = (self.frog_x * 2.0).round() as i32;
+        let y = self.frog_y as i32 * lane_dots + lane_dots / 2;
+        boxes.line(x - 2, y, x + 2, y);
+        boxes.line(x, y - 2, x, y + 2);
+        boxes.draw(grid, theme.ink(fx::HITBOX), None, false);
     }
 }
 
+pub const INFO: GameInfo = GameInfo {
+    name: "Frogger",
+    icon: "🐸",
+    author: "RustCade",
+    blurb: "Cross the road\nand river!",
+    description: "Help the frog cross safely!",
+    controls: &[
+        ("↑ ↓ ← →", "Move frog"),
+        ("k j h l", "Move frog (rebind with K on Home)"),
+        ("S", "Change skin"),
+        ("T", "Speedrun splits"),
+        ("R", "Restart"),
+        ("P", "Pause"),
+    ],
+    tags: &["arcade", "reflex", "retro"],
+    color: Ink(Color::Rgb(80, 220, 80), Role::Green),
+    border_color: Ink(Color::Rgb(40, 120, 40), Role::Green),
+};
+
 impl Game for Frogger {
+    fn info(&self) -> &'static GameInfo {
+        &INFO
+    }
+
     fn update(&mut self) {
+        self.snapshot_positions();
         if self.game_over || self.won || self.paused { return; }
+        if self.countdown.tick() { return; }
         self.tick += 1;
 
         for lane in &mut self.lanes {
@@ -366,14 +578,19 @@ impl Game for Frogger {
 
         self.move_frog_with_log();
         self.check_collision();
+        self.ease_frog();
     }
 
     fn handle_input(&mut self, key: KeyEvent) {
         match key.code {
             KeyCode::Char('r') | KeyCode::Char('R') => self.reset(),
+            KeyCode::Char('s') | KeyCode::Char('S') => {
+                self.picked_skin = Some((self.skin_index() + 1) % SKINS.len());
+            }
+            KeyCode::Char('t') | KeyCode::Char('T') => self.show_timer = !self.show_timer,
             KeyCode::Char('p') | KeyCode::Char('P') => {
                 if !self.game_over && !self.won {
-                    self.paused = !self.paused;
+                    self.countdown.toggle_pause(&mut self.paused);
                 }
             }
             _ => {
@@ -383,7 +600,7 @@ impl Game for Frogger {
                     }
                     return;
                 }
-                if self.paused { return; }
+                if self.paused || self.countdown.is_running() { return; }
                 match key.code {
                     KeyCode::Up => {
                         if self.frog_y > 0 {
@@ -398,10 +615,10 @@ impl Game for Frogger {
                         }
                     }
                     KeyCode::Left => {
-                        self.frog_x = (self.frog_x - 2).max(1);
+                        self.frog_x = (self.frog_x - 2.0).max(1.0);
                     }
                     KeyCode::Right => {
-                        self.frog_x = (self.frog_x + 2).min(self.field_width - 2);
+                        self.frog_x = (self.frog_x + 2.0).min((self.field_width - 2) as f32);
                     }
                     _ => {}
                 }
@@ -409,13 +626,8 @@ impl Game for Frogger {
         }
     }
 
-    fn render(&mut self, frame: &mut Frame, area: Rect) {
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Color::Rgb(50, 180, 50)))
-            .title(" 🐸 Frogger ")
-            .title_style(Style::default().fg(Color::Rgb(80, 220, 80)).add_modifier(Modifier::BOLD));
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = self.title_bar(theme);
 
         let inner = block.inner(area);
         frame.render_widget(block, area);
@@ -425,7 +637,9 @@ impl Game for Frogger {
         if new_fw != self.field_width && (!self.game_over && !self.won) {
             // Adjust frog position proportionally
             let ratio = new_fw as f32 / self.field_width as f32;
-            self.frog_x = (self.frog_x as f32 * ratio) as i32;
+            self.frog_x *= ratio;
+            self.draw_x *= ratio;
+            self.prev_draw_x *= ratio;
             self.field_width = new_fw;
         }
 
@@ -443,22 +657,22 @@ impl Game for Frogger {
             Span::styled(" 🐸 ", Style::default()),
             Span::styled(
                 format!("Score: {} ", self.score),
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             Span::styled(
                 format!("Lives: {} ", "♥ ".repeat(self.lives as usize)),
-                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme.paint(Color::Red, Role::Red)).add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             Span::styled(
                 format!("🏆 High: {} ", self.high_score),
-                Style::default().fg(Color::Cyan),
+                Style::default().fg(theme.paint(Color::Cyan, Role::Cyan)),
             ),
-            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             Span::styled(
                 format!("Goals: {}/5 ", self.goals_reached.iter().filter(|&&g| g).count()),
-                Style::default().fg(Color::Green),
+                Style::default().fg(theme.paint(Color::Green, Role::Green)),
             ),
         ]);
         frame.render_widget(Paragraph::new(status), chunks[0]);
@@ -467,68 +681,116 @@ impl Game for Frogger {
         let field_width = chunks[1].width as usize;
         let available_height = chunks[1].height as usize;
         let rows_per_lane = (available_height / NUM_LANES).max(1);
-        let mut lines: Vec<Line> = Vec::new();
+        let mut grid: Vec<Vec<(char, Style)>> = Vec::new();
         for i in 0..NUM_LANES {
             if i < self.lanes.len() {
-                let lane_line = self.render_lane(i, field_width);
+                let row = self.lane_background(i, field_width, theme);
                 for _ in 0..rows_per_lane {
-                    lines.push(lane_line.clone());
+                    grid.push(row.clone());
                 }
             }
         }
         // Fill any remaining rows with the last lane style
-        while lines.len() < available_height {
-            if let Some(last) = lines.last().cloned() {
-                lines.push(last);
+        while grid.len() < available_height {
+            if let Some(last) = grid.last().cloned() {
+                grid.push(last);
             } else {
                 break;
             }
         }
+        self.draw_movers(&mut grid, field_width, rows_per_lane, theme);
+        let lines: Vec<Line> = grid.into_iter()
+            .map(|row| {
+                let spans: Vec<Span<'static>> = row
+                    .into_iter()
+                    .map(|(ch, style)| Span::styled(String::from(ch), style))
+                    .collect();
+                Line::from(spans)
+            })
+            .collect();
         frame.render_widget(Paragraph::new(lines), chunks[1]);
 
+        self.countdown.render(frame, chunks[1], theme);
+
         // Help bar
-        if self.game_over {
-            let msg = Paragraph::new(Line::from(vec![
-                Span::styled(" 💀 GAME OVER! ", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
-                Span::styled("Press ENTER to restart, Esc for menu", Style::default().fg(Color::Gray)),
-            ]));
-            frame.render_widget(msg, chunks[2]);
+        let footer = if self.game_over {
+            hud::game_over("Press ENTER to restart, Esc for menu", theme)
         } else if self.won {
-            let msg = Paragraph::new(Line::from(vec![
-                Span::styled(" 🎉 YOU WIN! ", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
-                Span::styled("Press ENTER to play again", Style::default().fg(Color::Gray)),
-            ]));
-            frame.render_widget(msg, chunks[2]);
+            let best = if self.new_best { ", a new best" } else { "" };
+            let prompt = format!("Cleared in {}{} · Press ENTER to play again", frogger_splits::format_time(self.tick), best);
+            hud::outcome("🎉 YOU WIN!", theme.paint(Color::Green, Role::Green), &prompt, theme)
         } else if self.paused {
-            let msg = Paragraph::new(Line::from(vec![
-                Span::styled(" ⏸ PAUSED - Press P to resume ", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
-            ]));
-            frame.render_widget(msg, chunks[2]);
+            hud::pause_overlay(frame, chunks[1], theme);
+            hud::paused(theme)
+        } else if self.show_timer {
+            self.split_line(theme)
         } else {
-            let help = Paragraph::new(Line::from(vec![
-                Span::styled(" ↑↓←→ Move ", Style::default().fg(Color::DarkGray)),
-                Span::styled("│ ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("P Pause ", Style::default().fg(Color::DarkGray)),
-                Span::styled("│ ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("R Restart ", Style::default().fg(Color::DarkGray)),
-                Span::styled("│ ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("Esc Menu ", Style::default().fg(Color::DarkGray)),
-                Span::styled("│ ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("? Help", Style::default().fg(Color::DarkGray)),
-            ]));
-            frame.render_widget(help, chunks[2]);
-        }
+            hud::legend(None, &[("↑↓←→", "Move"), ("S", self.skin().name), ("T", "Splits")], theme)
+        };
+        frame.render_widget(Paragraph::new(footer), chunks[2]);
+    }
+
+    fn render_interpolated(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, alpha: f32) {
+        self.render_alpha = alpha.clamp(0.0, 1.0);
+        self.render(frame, area, theme);
     }
 
     fn get_score(&self) -> u32 { self.score }
     fn is_game_over(&self) -> bool { self.game_over }
+    fn is_paused(&self) -> bool { self.paused }
+    fn set_hitboxes(&mut self, on: bool) { self.show_hitboxes = on; }
+    fn set_practice(&mut self, on: bool) { self.practice = on; }
+
+    fn reseed(&mut self, rng: GameRng) {
+        self.rng = rng;
+        self.reset();
+    }
+
+    fn serialize_state(&self) -> Option<Vec<u8>> {
+        if self.game_over || self.won { return None; }
+        let mut w = StateWriter::new();
+        w.u32(self.score);
+        w.u32(self.high_score);
+        w.u32(self.lives);
+        for &g in &self.goals_reached {
+            w.bool(g);
+        }
+        Some(w.finish())
+    }
+
+    fn restore_state(&mut self, data: &[u8]) -> bool {
+        let mut r = StateReader::new(data);
+        let (Some(score), Some(high_score), Some(lives)) = (r.u32(), r.u32(), r.u32()) else { return false };
+        let mut goals = [false; 5];
+        for g in goals.iter_mut() {
+            let Some(v) = r.bool() else { return false };
+            *g = v;
+        }
+        // Fresh traffic, frog back at the start, paused
+        self.reset();
+        self.score = score;
+        self.high_score = self.high_score.max(high_score);
+        self.lives = lives.max(1);
+        self.goals_reached = goals;
+        // The lanes are new, so the splits would time a different layout
+        self.run_seed = None;
+        self.paused = true;
+        true
+    }
 
     fn reset(&mut self) {
         let hs = self.high_score;
         let fw = self.field_width;
-        *self = Frogger::new();
+        let show_hitboxes = self.show_hitboxes;
+        let (skin, seasonal, picked_skin) = (self.skin, self.seasonal, self.picked_skin);
+        let (show_timer, practice, split_book) = (self.show_timer, self.practice, std::mem::take(&mut self.split_book));
+        *self = Frogger::with_rng(self.rng.clone());
+        (self.show_timer, self.practice, self.split_book) = (show_timer, practice, split_book);
+        self.golds = self.run_seed.map_or([0; HOMES], |seed| self.split_book.golds(seed));
         self.high_score = hs;
         self.field_width = fw;
-        self.frog_x = fw / 2;
+        self.show_hitboxes = show_hitboxes;
+        (self.skin, self.seasonal, self.picked_skin) = (skin, seasonal, picked_skin);
+        self.respawn_frog();
     }
 }
@@ -0,0 +1,238 @@
+use ratatui::style::Color;
+
+use crate::ui::theme::{Ink, Role};
+
+use crate::seasons::civil_from_days;
+
+/// How Frogger's lanes, traffic and frog look: the glyphs as well as the
+/// colours. Everything is data, so a new skin is one more entry in `SKINS`.
+pub struct Skin {
+    pub name: &'static str,
+    /// Grass texture, picked per cell by a fixed hash, and its background
+    pub grass: [(char, Ink); 4],
+    pub grass_bg: Ink,
+    /// Water ripples, cycled as the river flows
+    pub water: [char; 4],
+    pub water_fg: Ink,
+    pub water_bg: Ink,
+    pub road_bg: Ink,
+    pub marking: char,
+    pub marking_fg: Ink,
+    /// Goal pad: edge, centre and fill glyphs, then a filled pad
+    pub pad: [char; 3],
+    pub pad_fg: Ink,
+    pub pad_bg: Ink,
+    pub reached: char,
+    pub reached_fg: Ink,
+    pub reached_bg: Ink,
+    /// One per road lane, repeating
+    pub cars: [Ink; 5],
+    pub logs: Ink,
+    /// Frog sprite in braille dots, 3 cells wide and one cell tall
+    pub frog: [&'static str; 4],
+    pub frog_fg: Ink,
+    pub frog_bg: Ink,
+}
+
+const FROG: [&str; 4] = [".#..#.", "######", ".####.", "#....#"];
+
+pub const CLASSIC: Skin = Skin {
+    name: "classic",
+    grass: [
+        ('"', Ink(Color::Rgb(30, 90, 20), Role::Green)),
+        ('.', Ink(Color::Rgb(30, 70, 20), Role::Panel)),
+        (',', Ink(Color::Rgb(30, 80, 20), Role::Panel)),
+        (' ', Ink(Color::Rgb(30, 60, 20), Role::Panel)),
+    ],
+    grass_bg: Ink(Color::Rgb(15, 45, 10), Role::Panel),
+    water: ['~', '≈', '~', '∽'],
+    water_fg: Ink(Color::Rgb(50, 100, 200), Role::Blue),
+    water_bg: Ink(Color::Rgb(10, 30, 100), Role::Blue),
+    road_bg: Ink(Color::Rgb(35, 35, 40), Role::Panel),
+    marking: '─',
+    marking_fg: Ink(Color::Rgb(120, 120, 40), Role::Yellow),
+    pad: ['┃', '▼', '─'],
+    pad_fg: Ink(Color::Rgb(200, 200, 50), Role::Yellow),
+    pad_bg: Ink(Color::Rgb(40, 40, 15), Role::Panel),
+    reached: '★',
+    reached_fg: Ink(Color::Rgb(50, 220, 50), Role::Green),
+    reached_bg: Ink(Color::Rgb(15, 60, 15), Role::Panel),
+    cars: [
+        Ink(Color::Rgb(220, 50, 50), Role::Red),
+        Ink(Color::Rgb(50, 120, 220), Role::Blue),
+        Ink(Color::Rgb(220, 180, 30), Role::Yellow),
+        Ink(Color::Rgb(180, 50, 200), Role::Magenta),
+        Ink(Color::Rgb(220, 120, 30), Role::Orange),
+    ],
+    logs: Ink(Color::Rgb(140, 90, 40), Role::Orange),
+    frog: FROG,
+    frog_fg: Ink(Color::Rgb(255, 255, 255), Role::Bright),
+    frog_bg: Ink(Color::Rgb(30, 180, 30), Role::Green),
+};
+
+pub const NEON: Skin = Skin {
+    name: "neon",
+    grass: [
+        ('·', Ink(Color::Rgb(200, 40, 220), Role::Magenta)),
+        (' ', Ink(Color::Rgb(120, 30, 160), Role::Violet)),
+        ('∙', Ink(Color::Rgb(140, 40, 200), Role::Violet)),
+        (' ', Ink(Color::Rgb(120, 30, 160), Role::Violet)),
+    ],
+    grass_bg: Ink(Color::Rgb(12, 0, 20), Role::Background),
+    water: ['≈', '∿', '≈', '~'],
+    water_fg: Ink(Color::Rgb(0, 230, 255), Role::Cyan),
+    water_bg: Ink(Color::Rgb(0, 10, 35), Role::Background),
+    road_bg: Ink(Color::Rgb(6, 6, 12), Role::Background),
+    marking: '━',
+    marking_fg: Ink(Color::Rgb(255, 40, 160), Role::Magenta),
+    pad: ['▐', '◆', '═'],
+    pad_fg: Ink(Color::Rgb(255, 240, 0), Role::Yellow),
+    pad_bg: Ink(Color::Rgb(30, 0, 40), Role::Background),
+    reached: '✦',
+    reached_fg: Ink(Color::Rgb(0, 255, 160), Role::Cyan),
+    reached_bg: Ink(Color::Rgb(0, 40, 30), Role::Panel),
+    cars: [
+        Ink(Color::Rgb(255, 40, 160), Role::Magenta),
+        Ink(Color::Rgb(0, 230, 255), Role::Cyan),
+        Ink(Color::Rgb(255, 240, 0), Role::Yellow),
+        Ink(Color::Rgb(160, 80, 255), Role::Violet),
+        Ink(Color::Rgb(255, 120, 0), Role::Orange),
+    ],
+    logs: Ink(Color::Rgb(120, 255, 60), Role::Green),
+    frog: FROG,
+    frog_fg: Ink(Color::Rgb(0, 0, 0), Role::Background),
+    frog_bg: Ink(Color::Rgb(0, 255, 200), Role::Cyan),
+};
+
+pub const WINTER: Skin = Skin {
+    name: "winter",
+    grass: [
+        ('*', Ink(Color::Rgb(250, 250, 255), Role::Bright)),
+        ('.', Ink(Color::Rgb(200, 210, 230), Role::Text)),
+        ('˙', Ink(Color::Rgb(220, 230, 245), Role::Bright)),
+        (' ', Ink(Color::Rgb(200, 210, 230), Role::Text)),
+    ],
+    grass_bg: Ink(Color::Rgb(170, 185, 205), Role::Text),
+    water: ['≈', '~', '≈', '-'],
+    water_fg: Ink(Color::Rgb(170, 210, 240), Role::Blue),
+    water_bg: Ink(Color::Rgb(20, 50, 80), Role::Panel),
+    road_bg: Ink(Color::Rgb(60, 60, 70), Role::Faint),
+    marking: '╌',
+    marking_fg: Ink(Color::Rgb(200, 200, 210), Role::Text),
+    pad: ['║', '▼', '─'],
+    pad_fg: Ink(Color::Rgb(230, 240, 255), Role::Bright),
+    pad_bg: Ink(Color::Rgb(60, 90, 130), Role::Blue),
+    reached: '❄',
+    reached_fg: Ink(Color::Rgb(255, 255, 255), Role::Bright),
+    reached_bg: Ink(Color::Rgb(70, 120, 170), Role::Blue),
+    cars: [
+        Ink(Color::Rgb(200, 40, 40), Role::Red),
+        Ink(Color::Rgb(40, 90, 160), Role::Blue),
+        Ink(Color::Rgb(230, 200, 80), Role::Yellow),
+        Ink(Color::Rgb(30, 120, 70), Role::Green),
+        Ink(Color::Rgb(120, 70, 40), Role::Orange),
+    ],
+    logs: Ink(Color::Rgb(210, 235, 250), Role::Bright),
+    frog: FROG,
+    frog_fg: Ink(Color::Rgb(255, 255, 255), Role::Bright),
+    frog_bg: Ink(Color::Rgb(20, 130, 60), Role::Green),
+};
+
+pub const SPOOKY: Skin = Skin {
+    name: "spooky",
+    grass: [
+        ('†', Ink(Color::Rgb(110, 90, 120), Role::Faint)),
+        ('.', Ink(Color::Rgb(80, 60, 90), Role::Faint)),
+        (',', Ink(Color::Rgb(90, 70, 60), Role::Faint)),
+        (' ', Ink(Color::Rgb(80, 60, 90), Role::Faint)),
+    ],
+    grass_bg: Ink(Color::Rgb(25, 10, 30), Role::Background),
+    water: ['~', '≈', '°', '≈'],
+    water_fg: Ink(Color::Rgb(120, 220, 60), Role::Green),
+    water_bg: Ink(Color::Rgb(10, 40, 15), Role::Panel),
+    road_bg: Ink(Color::Rgb(25, 20, 25), Role::Background),
+    marking: '┄',
+    marking_fg: Ink(Color::Rgb(255, 130, 0), Role::Orange),
+    pad: ['┃', '▼', '─'],
+    pad_fg: Ink(Color::Rgb(255, 130, 0), Role::Orange),
+    pad_bg: Ink(Color::Rgb(40, 20, 0), Role::Background),
+    reached: '☠',
+    reached_fg: Ink(Color::Rgb(240, 240, 230), Role::Bright),
+    reached_bg: Ink(Color::Rgb(60, 30, 0), Role::Panel),
+    cars: [
+        Ink(Color::Rgb(255, 130, 0), Role::Orange),
+        Ink(Color::Rgb(140, 60, 200), Role::Violet),
+        Ink(Color::Rgb(120, 220, 60), Role::Green),
+        Ink(Color::Rgb(230, 230, 220), Role::Bright),
+        Ink(Color::Rgb(200, 30, 30), Role::Red),
+    ],
+    logs: Ink(Color::Rgb(90, 60, 40), Role::Orange),
+    frog: FROG,
+    frog_fg: Ink(Color::Rgb(255, 130, 0), Role::Orange),
+    frog_bg: Ink(Color::Rgb(60, 120, 30), Role::Green),
+};
+
+pub const FESTIVE: Skin = Skin {
+    name: "festive",
+    grass: [
+        ('*', Ink(Color::Rgb(255, 255, 255), Role::Bright)),
+        ('.', Ink(Color::Rgb(220, 230, 240), Role::Bright)),
+        ('+', Ink(Color::Rgb(255, 210, 80), Role::Yellow)),
+        (' ', Ink(Color::Rgb(220, 230, 240), Role::Bright)),
+    ],
+    grass_bg: Ink(Color::Rgb(180, 195, 210), Role::Text),
+    water: ['≈', '~', '≈', '~'],
+    water_fg: Ink(Color::Rgb(170, 210, 240), Role::Blue),
+    water_bg: Ink(Color::Rgb(20, 50, 80), Role::Panel),
+    road_bg: Ink(Color::Rgb(50, 45, 50), Role::Panel),
+    marking: '─',
+    marking_fg: Ink(Color::Rgb(220, 40, 40), Role::Red),
+    pad: ['┃', '▼', '─'],
+    pad_fg: Ink(Color::Rgb(255, 210, 80), Role::Yellow),
+    pad_bg: Ink(Color::Rgb(20, 70, 30), Role::Panel),
+    reached: '★',
+    reached_fg: Ink(Color::Rgb(255, 220, 60), Role::Yellow),
+    reached_bg: Ink(Color::Rgb(150, 20, 20), Role::Red),
+    cars: [
+        Ink(Color::Rgb(220, 30, 30), Role::Red),
+        Ink(Color::Rgb(30, 150, 60), Role::Green),
+        Ink(Color::Rgb(255, 210, 80), Role::Yellow),
+        Ink(Color::Rgb(240, 240, 240), Role::Bright),
+        Ink(Color::Rgb(220, 30, 30), Role::Red),
+    ],
+    logs: Ink(Color::Rgb(120, 70, 40), Role::Orange),
+    frog: FROG,
+    frog_fg: Ink(Color::Rgb(255, 255, 255), Role::Bright),
+    frog_bg: Ink(Color::Rgb(30, 150, 60), Role::Green),
+};
+
+/// Every skin, in the order `S` cycles through them. The last two also
+/// turn up on their own around their dates.
+pub const SKINS: [&Skin; 5] = [&CLASSIC, &NEON, &WINTER, &SPOOKY, &FESTIVE];
+
+/// A skin that takes over between two dates, inclusive; the range may
+/// wrap past New Year.
+struct Event {
+    from: (u32, u32),
+    to: (u32, u32),
+    skin: usize,
+}
+
+const EVENTS: [Event; 2] = [
+    Event { from: (10, 24), to: (10, 31), skin: 3 },
+    Event { from: (12, 18), to: (1, 2), skin: 4 },
+];
+
+/// Index into `SKINS` of a skin name.
+pub fn find(name: &str) -> Option<usize> {
+    SKINS.iter().position(|s| s.name == name)
+}
+
+/// The seasonal skin for a unix timestamp, if one is running.
+pub fn seasonal(timestamp: u64) -> Option<usize> {
+    let (_, month, day) = civil_from_days((timestamp / 86_400) as i64);
+    let today = (month, day);
+    EVENTS.iter().find(|e| {
+        if e.from <= e.to { e.from <= today && today <= e.to } else { today >= e.from || today <= e.to }
+    }).map(|e| e.skin)
+}
@@ -0,0 +1,110 @@
+use crate::session::{load_progress, save_progress, StateReader, StateWriter};
+
+const SPLITS_SAVE: &str = "frogger_splits";
+/// One split per goal pad, in the order they're filled
+pub const HOMES: usize = 5;
+/// Simulation step, as the arcade runs it
+const STEP_SECS: f32 = 0.016;
+/// Seeds remembered; the longest unplayed is dropped first
+const MAX_SEEDS: usize = 64;
+
+/// Best times on one lane layout, in ticks; 0 where there's none yet.
+#[derive(Clone, Default)]
+struct SeedRecord {
+    seed: u64,
+    /// Fastest each segment has ever been run (the gold splits)
+    gold: [u64; HOMES],
+    /// Cumulative splits of the fastest clear of all five pads
+    best: [u64; HOMES],
+}
+
+/// Best splits for every layout played, kept in a progress file. A layout
+/// is the seed its lanes were drawn from, so `--seed` runs line up.
+#[derive(Clone, Default)]
+pub struct SplitBook {
+    records: Vec<SeedRecord>,
+}
+
+impl SplitBook {
+    pub fn load() -> Self {
+        let Some(data) = load_progress(SPLITS_SAVE) else { return Self::default() };
+        let mut r = StateReader::new(&data);
+        let read = |r: &mut StateReader| -> Option<SeedRecord> {
+            let seed = r.u64()?;
+            let mut record = SeedRecord { seed, ..SeedRecord::default() };
+            for t in record.gold.iter_mut().chain(record.best.iter_mut()) {
+                *t = r.u64()?;
+            }
+            Some(record)
+        };
+        let records = (0..r.u32().unwrap_or(0)).map_while(|_| read(&mut r)).collect();
+        SplitBook { records }
+    }
+
+    fn save(&self) {
+        let mut w = StateWriter::new();
+        w.u32(self.records.len() as u32);
+        for record in &self.records {
+            w.u64(record.seed);
+            for &t in record.gold.iter().chain(&record.best) {
+                w.u64(t);
+            }
+        }
+        save_progress(SPLITS_SAVE, &w.finish());
+    }
+
+    /// The record for `seed`, moved to the front as the latest played.
+    fn record_mut(&mut self, seed: u64) -> &mut SeedRecord {
+        let record = match self.records.iter().position(|r| r.seed == seed) {
+            Some(i) => self.records.remove(i),
+            None => SeedRecord { seed, ..SeedRecord::default() },
+        };
+        self.records.insert(0, record);
+        self.records.truncate(MAX_SEEDS);
+        &mut self.records[0]
+    }
+
+    pub fn golds(&self, seed: u64) -> [u64; HOMES] {
+        self.records.iter().find(|r| r.seed == seed).map_or([0; HOMES], |r| r.gold)
+    }
+
+    /// Finishing time of the best clear on `seed`.
+    pub fn personal_best(&self, seed: u64) -> Option<u64> {
+        self.records.iter().find(|r| r.seed == seed).map(|r| r.best[HOMES - 1]).filter(|&t| t > 0)
+    }
+
+    /// Keep a finished segment; true when it's a new gold.
+    pub fn split(&mut self, seed: u64, index: usize, segment: u64) -> bool {
+        let record = self.record_mut(seed);
+        let gold = record.gold[index] == 0 || segment < record.gold[index];
+        if gold {
+            record.gold[index] = segment;
+        }
+        self.save();
+        gold
+    }
+
+    /// Keep a clear of all five pads; true when it's a new best.
+    pub fn finish(&mut self, seed: u64, splits: &[u64]) -> bool {
+        let Ok(splits) = <[u64; HOMES]>::try_from(splits) else { return false };
+        let record = self.record_mut(seed);
+        let best = record.best[HOMES - 1] == 0 || splits[HOMES - 1] < record.best[HOMES - 1];
+        if best {
+            record.best = splits;
+            self.save();
+        }
+        best
+    }
+}
+
+/// `m:ss.s` from ticks.
+pub fn format_time(ticks: u64) -> String {
+    let secs = ticks as f32 * STEP_SECS;
+    format!("{}:{:04.1}", (secs / 60.0) as u32, secs % 60.0)
+}
+
+/// Signed seconds from a tick difference, e.g. `+1.2` or `−0.8`.
+pub fn format_delta(ticks: i64) -> String {
+    let secs = ticks.unsigned_abs() as f32 * STEP_SECS;
+    format!("{}{:.1}", if ticks < 0 { '−' } else { '+' }, secs)
+}
@@ -0,0 +1,92 @@
+use crate::session::{StateReader, StateWriter};
+
+/// Ticks a resting piece waits before it locks, at 60 updates a second
+pub const LOCK_TICKS: u32 = 30;
+/// Moves or turns that may restart the lock delay for one piece
+pub const MAX_RESETS: u32 = 15;
+
+/// What gravity did to the falling piece this tick.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Step {
+    Hold,
+    /// Move the piece down a row
+    Fall,
+    /// The lock delay ran out: fix the piece in the well
+    Lock,
+}
+
+/// Fall and lock timing for falling-piece games: the piece drops a row
+/// every `interval` ticks, and once it rests on something it locks after
+/// the lock delay, which moving or turning it restarts a limited number
+/// of times so it can't be held up forever.
+#[derive(Clone, Default)]
+pub struct Gravity {
+    /// Ticks since the piece last fell a row
+    fall: u32,
+    /// Ticks it has been resting
+    rest: u32,
+    /// Lock delay restarts this piece has used
+    resets: u32,
+}
+
+impl Gravity {
+    /// A new piece came in: timers start over.
+    pub fn spawn(&mut self) {
+        *self = Gravity::default();
+    }
+
+    /// Advance a tick for a piece that `grounded` can't fall any further.
+    pub fn tick(&mut self, interval: u32, grounded: bool) -> Step {
+        if grounded {
+            self.fall = 0;
+            self.rest += 1;
+            if self.rest >= LOCK_TICKS { Step::Lock } else { Step::Hold }
+        } else {
+            self.rest = 0;
+            self.fall += 1;
+            if self.fall >= interval.max(1) {
+                self.fall = 0;
+                Step::Fall
+            } else {
+                Step::Hold
+            }
+        }
+    }
+
+    /// The player moved or turned the piece: a resting piece gets its full
+    /// lock delay back, while it has restarts left.
+    pub fn moved(&mut self) {
+        if self.rest > 0 && self.resets < MAX_RESETS {
+            self.rest = 0;
+            self.resets += 1;
+        }
+    }
+
+    /// A soft drop took the piece down a row, so gravity's count starts over.
+    pub fn dropped(&mut self) {
+        self.fall = 0;
+    }
+
+    /// 0.0 while falling, rising to 1.0 as the lock delay runs out.
+    pub fn lock_progress(&self) -> f32 {
+        self.rest as f32 / LOCK_TICKS as f32
+    }
+
+    pub fn write(&self, w: &mut StateWriter) {
+        w.u32(self.fall);
+        w.u32(self.rest);
+        w.u32(self.resets);
+    }
+
+    pub fn read(r: &mut StateReader) -> Option<Self> {
+        Some(Gravity { fall: r.u32()?, rest: r.u32()?.min(LOCK_TICKS - 1), resets: r.u32()?.min(MAX_RESETS) })
+    }
+}
+
+/// Ticks per row at `level` (from 1): the usual falling-block curve,
+/// (0.8 - 0.007 × (level - 1)) ^ (level - 1) seconds, down to one a tick.
+pub fn level_ticks(level: u32) -> u32 {
+    let l = level.clamp(1, 20) as f32 - 1.0;
+    let seconds = (0.8 - 0.007 * l).powf(l);
+    ((seconds * 60.0).round() as u32).max(1)
+}
@@ -0,0 +1,298 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::style::Color;
+
+use crate::scores::data_path;
+use crate::ui::theme::{Ink, Role};
+
+const FILE_EXT: &str = "txt";
+/// Largest formation a wave file can describe
+pub const MAX_ROWS: usize = 8;
+pub const MAX_COLS: usize = 15;
+/// Slowest and fastest march and bomb paces, relative to the classic wave
+pub const MIN_PACE: f32 = 0.25;
+pub const MAX_PACE: f32 = 3.0;
+/// How far + / - and [ / ] move a pace in the editor
+const PACE_STEP: f32 = 0.25;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum AlienKind {
+    Top,    // small, 30 pts
+    Mid,    // medium, 20 pts
+    Bot,    // large, 10 pts
+}
+
+impl AlienKind {
+    pub fn points(&self) -> u32 {
+        match self {
+            AlienKind::Top => 30,
+            AlienKind::Mid => 20,
+            AlienKind::Bot => 10,
+        }
+    }
+
+    pub fn color(&self) -> Ink {
+        match self {
+            AlienKind::Top => Ink(Color::Rgb(255, 80, 80), Role::Red),
+            AlienKind::Mid => Ink(Color::Rgb(80, 255, 150), Role::Green),
+            AlienKind::Bot => Ink(Color::Rgb(200, 180, 255), Role::Violet),
+        }
+    }
+
+    fn letter(self) -> char {
+        match self {
+            AlienKind::Top => 'S',
+            AlienKind::Mid => 'C',
+            AlienKind::Bot => 'O',
+        }
+    }
+
+    fn from_letter(c: char) -> Option<AlienKind> {
+        match c.to_ascii_uppercase() {
+            'S' => Some(AlienKind::Top),
+            'C' => Some(AlienKind::Mid),
+            'O' => Some(AlienKind::Bot),
+            _ => None,
+        }
+    }
+}
+
+/// An invasion drawn as text, one character per alien.
+///
+/// ```text
+/// ; comments start with a semicolon
+/// speed 1.5      march half again as fast as the classic wave
+/// fire 0.5       and bomb half as often
+/// S.S.S.S.S.S
+/// CCCCCCCCCCC
+/// OOOOOOOOOOO
+/// ```
+///
+/// `S` is a squid (30 points), `C` a crab (20) and `O` an octopus (10);
+/// space and `.` leave a gap.
+#[derive(Clone)]
+pub struct Wave {
+    pub name: String,
+    /// Row by row from the top; `None` is a gap
+    pub rows: Vec<Vec<Option<AlienKind>>>,
+    /// March pace relative to the classic wave
+    pub speed: f32,
+    /// Bombing rate relative to the classic wave
+    pub fire: f32,
+}
+
+impl Wave {
+    /// The arcade's own formation: a row of squids, two of crabs, two of
+    /// octopuses.
+    pub fn classic() -> Wave {
+        let row = |kind| vec![Some(kind); 11];
+        Wave {
+            name: "Classic".to_string(),
+            rows: vec![row(AlienKind::Top), row(AlienKind::Mid), row(AlienKind::Mid), row(AlienKind::Bot), row(AlienKind::Bot)],
+            speed: 1.0,
+            fire: 1.0,
+        }
+    }
+
+    pub fn parse(name: &str, text: &str) -> Option<Wave> {
+        let mut wave = Wave { name: name.to_string(), rows: Vec::new(), speed: 1.0, fire: 1.0 };
+        let mut rows: Vec<&str> = Vec::new();
+        for line in text.lines() {
+            if line.starts_with(';') { continue; }
+            let mut fields = line.split_whitespace();
+            let pace = match fields.next() {
+                Some("speed") => &mut wave.speed,
+                Some("fire") => &mut wave.fire,
+                _ => {
+                    rows.push(line.trim_end());
+                    continue;
+                }
+            };
+            *pace = fields.next()?.parse::<f32>().ok()?.clamp(MIN_PACE, MAX_PACE);
+        }
+        // Blank lines around the formation don't count, blank lines inside do
+        while rows.last().is_some_and(|r| r.is_empty()) { rows.pop(); }
+        let first = rows.iter().position(|r| !r.is_empty())?;
+        wave.rows = rows[first..].iter()
+            .take(MAX_ROWS)
+            .map(|line| line.chars().take(MAX_COLS).map(AlienKind::from_letter).collect())
+            .collect();
+        if wave.alien_count() == 0 { return None; }
+        Some(wave)
+    }
+
+    /// The file text `parse` reads back, with gaps as `.` and the
+    /// formation pulled into its top left corner.
+    pub fn to_text(&self) -> String {
+        let used: Vec<&Vec<Option<AlienKind>>> = self.rows.iter()
+            .skip_while(|row| row.iter().all(Option::is_none))
+            .collect();
+        let last = used.iter().rposition(|row| row.iter().any(Option::is_some)).map_or(0, |i| i + 1);
+        let left = used.iter().filter_map(|row| row.iter().position(Option::is_some)).min().unwrap_or(0);
+        let mut text = format!("; {}\nspeed {}\nfire {}\n", self.name, self.speed, self.fire);
+        for row in &used[..last] {
+            let line: String = row.iter().skip(left).map(|c| c.map_or('.', AlienKind::letter)).collect();
+            text.push_str(line.trim_end_matches('.'));
+            text.push('\n');
+        }
+        text
+    }
+
+    pub fn columns(&self) -> usize {
+        self.rows.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    pub fn alien_count(&self) -> usize {
+        self.rows.iter().flatten().filter(|c| c.is_some()).count()
+    }
+
+    /// Wave files from the `waves` folder, sorted by file name; the
+    /// campaign plays them in this order.
+    pub fn load_all() -> Vec<Wave> {
+        let Ok(entries) = fs::read_dir(wave_dir()) else { return Vec::new() };
+        let mut files: Vec<PathBuf> = entries.flatten()
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|e| e == FILE_EXT))
+            .collect();
+        files.sort();
+        files.iter().filter_map(|p| Wave::parse(&file_stem(p), &fs::read_to_string(p).ok()?)).collect()
+    }
+
+    /// Write the wave to the `waves` folder under its name.
+    pub fn save(&self) -> io::Result<()> {
+        let dir = wave_dir();
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join(format!("{}.{}", self.name, FILE_EXT)), self.to_text())
+    }
+
+    /// A name no wave file has yet, numbered so it sorts after the rest.
+    pub fn free_name(waves: &[Wave]) -> String {
+        (waves.len() + 1..)
+            .map(|n| format!("wave-{:02}", n))
+            .find(|name| !waves.iter().any(|w| w.name == *name) && !wave_dir().join(format!("{}.{}", name, FILE_EXT)).exists())
+            .unwrap_or_default()
+    }
+}
+
+/// The formation editor: the full grid with a cursor, open on one wave
+/// file or a new one.
+pub struct WaveEditor {
+    /// Rows padded out to the full grid
+    pub wave: Wave,
+    /// Which loaded wave is open; `None` for a new file
+    index: Option<usize>,
+    /// Column, row
+    pub cursor: (usize, usize),
+    /// Edits since the last save
+    pub dirty: bool,
+    pub message: Option<String>,
+}
+
+impl WaveEditor {
+    /// Open `waves[index]`, or a new file starting from the classic
+    /// formation.
+    pub fn open(waves: &[Wave], index: Option<usize>) -> WaveEditor {
+        let source = match index.and_then(|i| waves.get(i)) {
+            Some(wave) => wave.clone(),
+            None => Wave { name: Wave::free_name(waves), ..Wave::classic() },
+        };
+        // Centred, the way the game lays it out
+        let left = (MAX_COLS - source.columns().min(MAX_COLS)) / 2;
+        let mut rows = vec![vec![None; MAX_COLS]; MAX_ROWS];
+        for (row, cells) in rows.iter_mut().zip(&source.rows) {
+            for (col, &cell) in cells.iter().enumerate().take(MAX_COLS - left) {
+                row[left + col] = cell;
+            }
+        }
+        WaveEditor {
+            wave: Wave { rows, ..source },
+            index: index.filter(|&i| i < waves.len()),
+            cursor: (MAX_COLS / 2, 0),
+            dirty: false,
+            message: None,
+        }
+    }
+
+    /// Which file is open, as the editor's title shows it.
+    pub fn title(&self) -> String {
+        let new = if self.index.is_none() { " (new)" } else { "" };
+        format!("{}{}{}", self.wave.name, new, if self.dirty { " *" } else { "" })
+    }
+
+    /// Act on a key; false once the editor should close. Saving adds
+    /// or updates the wave in `waves`.
+    pub fn handle_key(&mut self, key: KeyEvent, waves: &mut Vec<Wave>) -> bool {
+        let (col, row) = self.cursor;
+        let set = |editor: &mut WaveEditor, cell: Option<AlienKind>| {
+            editor.wave.rows[row][col] = cell;
+            editor.dirty = true;
+        };
+        match key.code {
+            KeyCode::Esc => return false,
+            KeyCode::Left => self.cursor.0 = col.saturating_sub(1),
+            KeyCode::Right => self.cursor.0 = (col + 1).min(MAX_COLS - 1),
+            KeyCode::Up => self.cursor.1 = row.saturating_sub(1),
+            KeyCode::Down => self.cursor.1 = (row + 1).min(MAX_ROWS - 1),
+            KeyCode::Char(' ') | KeyCode::Char('.') | KeyCode::Delete | KeyCode::Backspace => set(self, None),
+            KeyCode::Char(c) if AlienKind::from_letter(c).is_some() => set(self, AlienKind::from_letter(c)),
+            KeyCode::Char('x') | KeyCode::Char('X') => {
+                self.wave.rows = vec![vec![None; MAX_COLS]; MAX_ROWS];
+                self.dirty = true;
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') => self.step_pace(true, PACE_STEP),
+            KeyCode::Char('-') => self.step_pace(true, -PACE_STEP),
+            KeyCode::Char(']') => self.step_pace(false, PACE_STEP),
+            KeyCode::Char('[') => self.step_pace(false, -PACE_STEP),
+            KeyCode::Tab => {
+                // Through the files in campaign order, then a new one
+                let next = match self.index {
+                    Some(i) if i + 1 < waves.len() => Some(i + 1),
+                    Some(_) => None,
+                    None => (!waves.is_empty()).then_some(0),
+                };
+                *self = WaveEditor::open(waves, next);
+            }
+            KeyCode::Enter => self.save(waves),
+            _ => {}
+        }
+        true
+    }
+
+    fn step_pace(&mut self, march: bool, step: f32) {
+        let pace = if march { &mut self.wave.speed } else { &mut self.wave.fire };
+        *pace = (*pace + step).clamp(MIN_PACE, MAX_PACE);
+        self.dirty = true;
+    }
+
+    fn save(&mut self, waves: &mut Vec<Wave>) {
+        if self.wave.alien_count() == 0 {
+            self.message = Some("A wave needs at least one alien".to_string());
+            return;
+        }
+        if let Err(e) = self.wave.save() {
+            self.message = Some(format!("Couldn't save: {}", e));
+            return;
+        }
+        // Stored as the file reads back, so the campaign plays what was saved
+        let saved = Wave::parse(&self.wave.name, &self.wave.to_text()).unwrap_or_else(|| self.wave.clone());
+        match self.index {
+            Some(i) => waves[i] = saved,
+            None => waves.push(saved),
+        }
+        waves.sort_by(|a, b| a.name.cmp(&b.name));
+        self.index = waves.iter().position(|w| w.name == self.wave.name);
+        self.dirty = false;
+        self.message = Some(format!("Saved waves/{}.{}", self.wave.name, FILE_EXT));
+    }
+}
+
+fn wave_dir() -> PathBuf {
+    data_path("waves")
+}
+
+fn file_stem(path: &Path) -> String {
+    path.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default()
+}
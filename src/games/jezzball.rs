@@ -1,10 +1,108 @@
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::prelude::*;
 use ratatui::widgets::*;
 
-use crate::games::Game;
+use crate::games::jezzball_puzzles::{self, Puzzle};
+use crate::games::{Game, GameInfo};
+use crate::session::{load_progress, save_progress, StateReader, StateWriter};
+use crate::ui::braille::BrailleCanvas;
+use crate::ui::fx;
+use crate::ui::theme::{Ink, Role, Theme};
 
 const MAX_BALLS: usize = 8;
+/// Ticks between samples on the level-clear capture graph.
+const CAPTURE_SAMPLE_TICKS: u64 = 30;
+/// Steps the level-clear replay takes to play the whole level back.
+const REPLAY_STEPS: u64 = 180;
+const STATS_SAVE: &str = "jezzball_stats";
+/// Most rows the level-clear capture graph takes.
+const GRAPH_ROWS: usize = 10;
+/// Simulation step length, for showing level times in seconds.
+const STEP_SECS: f32 = 0.016;
+
+/// One completed wall: its cells and the regions it sealed off.
+struct WallEvent {
+    tick: u64, // ticks into the level
+    wall: Vec<(usize, usize)>,
+    filled: Vec<(usize, usize)>,
+}
+
+/// How the current level is going, for the replay when it's cleared.
+#[derive(Default)]
+struct LevelLog {
+    start_tick: u64,
+    events: Vec<WallEvent>,
+    capture: Vec<(u64, f32)>, // (ticks into the level, % filled)
+    launched: u32,
+    lost: u32,
+}
+
+impl LevelLog {
+    fn ticks(&self) -> u64 {
+        self.capture.last().map_or(0, |&(t, _)| t)
+    }
+}
+
+/// Lifetime totals across every game, kept in a progress file.
+#[derive(Clone, Default)]
+struct JezzStats {
+    levels: u32,
+    walls: u32,
+    walls_lost: u32,
+    ticks: u64,
+    fastest: u64,        // quickest level clear, in ticks (0 = none yet)
+    best_per_wall: f32,  // most capture % per wall on one level
+    /// Best stars earned on each puzzle, by name
+    puzzle_stars: Vec<(String, u8)>,
+}
+
+impl JezzStats {
+    fn load() -> Self {
+        let Some(data) = load_progress(STATS_SAVE) else { return Self::default() };
+        let mut r = StateReader::new(&data);
+        (|| Some(JezzStats {
+            levels: r.u32()?,
+            walls: r.u32()?,
+            walls_lost: r.u32()?,
+            ticks: r.u64()?,
+            fastest: r.u64()?,
+            best_per_wall: r.f32()?,
+            // Stats saved before puzzles came in stop here
+            puzzle_stars: (0..r.u32().unwrap_or(0))
+                .map_while(|_| Some((r.string()?, r.u8()?)))
+                .collect(),
+        }))()
+        .unwrap_or_default()
+    }
+
+    fn stars(&self, puzzle: &str) -> u8 {
+        self.puzzle_stars.iter().find(|(name, _)| name == puzzle).map_or(0, |&(_, s)| s)
+    }
+
+    /// Keep `stars` for a puzzle if it beats the best so far.
+    fn record_stars(&mut self, puzzle: &str, stars: u8) {
+        match self.puzzle_stars.iter_mut().find(|(name, _)| name == puzzle) {
+            Some((_, best)) => *best = (*best).max(stars),
+            None => self.puzzle_stars.push((puzzle.to_string(), stars)),
+        }
+    }
+
+    fn save(&self) {
+        let mut w = StateWriter::new();
+        w.u32(self.levels);
+        w.u32(self.walls);
+        w.u32(self.walls_lost);
+        w.u64(self.ticks);
+        w.u64(self.fastest);
+        w.f32(self.best_per_wall);
+        w.u32(self.puzzle_stars.len() as u32);
+        for (name, stars) in &self.puzzle_stars {
+            w.string(name);
+            w.u8(*stars);
+        }
+        save_progress(STATS_SAVE, &w.finish());
+    }
+}
 
 #[derive(Clone)]
 struct Ball {
@@ -20,6 +118,8 @@ enum CellState {
     Filled,
     WallGrowing,
     WallComplete,
+    /// A puzzle board's own walls; don't count towards the capture
+    Block,
 }
 
 #[derive(Clone, Copy, PartialEq)]
@@ -58,6 +158,20 @@ pub struct JezzBall {
     tick: u64,
     total_empty: usize,
     target_percent: f32,
+    /// Walls and capture over the level, replayed once it's cleared
+    log: LevelLog,
+    replay_step: u64,
+    levels_cleared: u32,
+    stats: JezzStats,
+    /// Where the field was last drawn, for mapping mouse clicks
+    field_area: Rect,
+    puzzles: Vec<Puzzle>,
+    /// Index into `puzzles` while playing the timed puzzle boards
+    puzzle: Option<usize>,
+    /// Out of time or lives on the current puzzle
+    puzzle_failed: bool,
+    /// Stars earned on the puzzle just cleared
+    stars: u8,
 }
 
 impl JezzBall {
@@ -86,6 +200,15 @@ impl JezzBall {
             tick: 0,
             total_empty,
             target_percent: 75.0,
+            log: LevelLog::default(),
+            replay_step: 0,
+            levels_cleared: 0,
+            stats: JezzStats::load(),
+            field_area: Rect::default(),
+            puzzles: Vec::new(),
+            puzzle: None,
+            puzzle_failed: false,
+            stars: 0,
         };
         s.spawn_balls(2);
         s
@@ -156,7 +279,7 @@ impl JezzBall {
                         ball.x = (ball.x + ball.dx * 2.0).clamp(0.0, self.field_width as f32 - 0.01);
                         ball.y = (ball.y + ball.dy * 2.0).clamp(0.0, self.field_height as f32 - 0.01);
                     }
-                    CellState::Filled | CellState::WallComplete => {
+                    CellState::Filled | CellState::WallComplete | CellState::Block => {
                         // Bounce off completed walls
                         // Check which direction to bounce
                         let prev_x = (ball.x - ball.dx) as usize;
@@ -208,7 +331,7 @@ impl JezzBall {
                     let ux = cx as usize;
                     let uy = cy as usize;
                     match self.grid[uy][ux] {
-                        CellState::Filled | CellState::WallComplete => {
+                        CellState::Filled | CellState::WallComplete | CellState::Block => {
                             wall.done_a = true;
                         }
                         _ => {
@@ -231,7 +354,7 @@ impl JezzBall {
                     let ux = cx as usize;
                     let uy = cy as usize;
                     match self.grid[uy][ux] {
-                        CellState::Filled | CellState::WallComplete => {
+                        CellState::Filled | CellState::WallComplete | CellState::Block => {
                             wall.done_b = true;
                         }
                         _ => {
@@ -249,6 +372,7 @@ impl JezzBall {
         // Complete finished walls
         for &i in walls_to_remove.iter().rev() {
             let wall = &self.growing_walls[i];
+            let mut cells = Vec::new();
             // Convert growing cells to complete
             match wall.direction {
                 WallDirection::Horizontal => {
@@ -256,6 +380,7 @@ impl JezzBall {
                     for x in 0..self.field_width {
                         if y < self.field_height && self.grid[y][x] == CellState::WallGrowing {
                             self.grid[y][x] = CellState::WallComplete;
+                            cells.push((x, y));
                         }
                     }
                 }
@@ -264,6 +389,7 @@ impl JezzBall {
                     for y in 0..self.field_height {
                         if x < self.field_width && self.grid[y][x] == CellState::WallGrowing {
                             self.grid[y][x] = CellState::WallComplete;
+                            cells.push((x, y));
                         }
                     }
                 }
@@ -271,7 +397,8 @@ impl JezzBall {
             self.growing_walls.remove(i);
 
             // After completing a wall, fill regions that don't contain balls
-            self.fill_empty_regions();
+            let filled = self.fill_empty_regions();
+            self.log.events.push(WallEvent { tick: self.tick - self.log.start_tick, wall: cells, filled });
 
             // Award score for filling
             self.score += 10;
@@ -290,7 +417,8 @@ impl JezzBall {
         self.growing_walls.clear();
     }
 
-    fn fill_empty_regions(&mut self) {
+    /// Fill every region without a ball in it; returns the cells filled.
+    fn fill_empty_regions(&mut self) -> Vec<(usize, usize)> {
         // Flood fill to find regions, then fill any region that doesn't contain a ball
         let w = self.field_width;
         let h = self.field_height;
@@ -327,6 +455,7 @@ impl JezzBall {
         }
 
         // Check each region for balls
+        let mut filled = Vec::new();
         for region in &regions {
             let has_ball = self.balls.iter().any(|ball| {
                 let bx = ball.x as usize;
@@ -340,9 +469,11 @@ impl JezzBall {
                 for &(rx, ry) in region {
                     self.grid[ry][rx] = CellState::Filled;
                 }
+                filled.extend_from_slice(region);
                 self.score += region_size as u32;
             }
         }
+        filled
     }
 
     fn launch_wall(&mut self) {
@@ -358,6 +489,7 @@ impl JezzBall {
 
         // Place origin
         self.grid[cy][cx] = CellState::WallGrowing;
+        self.log.launched += 1;
 
         self.growing_walls.push(GrowingWall {
             origin_x: cx,
@@ -370,16 +502,154 @@ impl JezzBall {
         });
     }
 
+    /// Grid cell under a terminal position, if it is inside the field.
+    fn cell_at(&self, col: u16, row: u16) -> Option<(usize, usize)> {
+        let area = self.field_area;
+        if !area.contains(Position::new(col, row)) { return None; }
+        // The field is drawn scaled down when the area is smaller than the grid
+        let w = (area.width as usize).min(self.field_width);
+        let h = (area.height as usize).min(self.field_height);
+        let (dx, dy) = ((col - area.x) as usize, (row - area.y) as usize);
+        if dx >= w || dy >= h { return None; }
+        Some((dx * self.field_width / w, dy * self.field_height / h))
+    }
+
     fn advance_level(&mut self) {
         self.level += 1;
         let num_balls = (self.level as usize + 1).min(MAX_BALLS);
         self.grid = vec![vec![CellState::Empty; self.field_width]; self.field_height];
         self.growing_walls.clear();
         self.won_level = false;
+        self.log = LevelLog { start_tick: self.tick, ..LevelLog::default() };
         self.spawn_balls(num_balls);
     }
 
-    fn render_field(&self, width: usize, height: usize) -> Vec<Line<'static>> {
+    /// Lay out a puzzle board over the field, scaled to fit, and start
+    /// its clock.
+    fn start_puzzle(&mut self, idx: usize) {
+        let Some(puzzle) = self.puzzles.get(idx) else { return };
+        let (fw, fh) = (self.field_width, self.field_height);
+        let (cols, rows) = (puzzle.columns().max(1), puzzle.rows().max(1));
+        self.grid = (0..fh)
+            .map(|y| (0..fw).map(|x| {
+                if puzzle.is_wall(x * cols / fw, y * rows / fh) { CellState::Block } else { CellState::Empty }
+            }).collect())
+            .collect();
+        self.total_empty = self.grid.iter().flatten().filter(|c| **c == CellState::Empty).count();
+        self.balls = puzzle.balls.iter().take(MAX_BALLS).enumerate().map(|(i, &(bx, by))| {
+            let angle = std::f32::consts::PI * (0.3 + 0.5 * i as f32);
+            Ball {
+                x: ((bx as f32 + 0.5) * fw as f32 / cols as f32).min(fw as f32 - 0.01),
+                y: ((by as f32 + 0.5) * fh as f32 / rows as f32).min(fh as f32 - 0.01),
+                dx: 0.4 * angle.cos(),
+                dy: 0.4 * angle.sin(),
+            }
+        }).collect();
+        self.target_percent = puzzle.target;
+        self.lives = puzzle.lives;
+        self.puzzle = Some(idx);
+        self.puzzle_failed = false;
+        self.stars = 0;
+        self.growing_walls.clear();
+        self.won_level = false;
+        self.paused = false;
+        self.log = LevelLog { start_tick: self.tick, ..LevelLog::default() };
+    }
+
+    /// Switch between the endless levels and the puzzle boards.
+    fn toggle_puzzles(&mut self) {
+        if self.puzzle.is_some() {
+            self.reset_classic();
+            return;
+        }
+        self.puzzles = Puzzle::load_all();
+        let first = self.puzzles.iter()
+            .position(|p| self.stats.stars(&p.name) == 0)
+            .unwrap_or(0);
+        self.start_puzzle(first);
+    }
+
+    fn next_puzzle(&mut self) {
+        if let Some(idx) = self.puzzle {
+            self.start_puzzle((idx + 1) % self.puzzles.len().max(1));
+        }
+    }
+
+    fn current_puzzle(&self) -> Option<&Puzzle> {
+        self.puzzle.and_then(|i| self.puzzles.get(i))
+    }
+
+    /// Seconds left on the puzzle clock.
+    fn time_left(&self) -> Option<f32> {
+        let puzzle = self.current_puzzle()?;
+        let used = (self.tick - self.log.start_tick) as f32 * STEP_SECS;
+        Some((puzzle.time_secs as f32 - used).max(0.0))
+    }
+
+    fn reset_classic(&mut self) {
+        let hs = self.high_score;
+        *self = JezzBall::new();
+        self.high_score = hs;
+    }
+
+    /// Levels cleared this game, kept with the score.
+    pub fn levels_cleared(&self) -> u32 {
+        self.levels_cleared
+    }
+
+    /// Wipe the lifetime totals after the saved progress was cleared.
+    pub fn reload_stats(&mut self) {
+        self.stats = JezzStats::load();
+    }
+
+    /// Close the level's log and add it to the lifetime totals.
+    fn finish_level(&mut self) {
+        let ticks = self.tick - self.log.start_tick;
+        let pct = self.filled_percent();
+        self.log.capture.push((ticks, pct));
+        self.replay_step = 0;
+        self.levels_cleared += 1;
+
+        let walls = self.log.events.len() as u32;
+        let stats = &mut self.stats;
+        stats.levels += 1;
+        stats.walls += walls;
+        stats.walls_lost += self.log.lost;
+        stats.ticks += ticks;
+        if stats.fastest == 0 || ticks < stats.fastest {
+            stats.fastest = ticks;
+        }
+        if walls > 0 {
+            stats.best_per_wall = stats.best_per_wall.max(pct / walls as f32);
+        }
+        if let (Some(puzzle), Some(left)) = (self.current_puzzle(), self.time_left()) {
+            let (stars, name) = (jezzball_puzzles::stars(self.log.lost, left, puzzle.time_secs), puzzle.name.clone());
+            self.stars = stars;
+            self.stats.record_stars(&name, stars);
+        }
+        self.stats.save();
+    }
+
+    /// The board as it stood at this point of the replay.
+    fn replay_grid(&self) -> Vec<Vec<CellState>> {
+        // A puzzle's own walls were there from the start
+        let mut grid: Vec<Vec<CellState>> = self.grid.iter()
+            .map(|row| row.iter().map(|&c| if c == CellState::Block { c } else { CellState::Empty }).collect())
+            .collect();
+        let until = self.log.ticks() * self.replay_step / REPLAY_STEPS;
+        for event in self.log.events.iter().take_while(|e| e.tick <= until) {
+            for &(x, y) in &event.wall {
+                grid[y][x] = CellState::WallComplete;
+            }
+            for &(x, y) in &event.filled {
+                grid[y][x] = CellState::Filled;
+            }
+        }
+        grid
+    }
+
+    /// Draw `cells` scaled into the field; `live` adds the balls and cursor.
+    fn render_field(&self, cells: &[Vec<CellState>], live: bool, width: usize, height: usize, theme: &Theme) -> Vec<Line<'static>> {
         let w = width.min(self.field_width);
         let h = height.min(self.field_height);
 
@@ -387,48 +657,57 @@ impl JezzBall {
         let sy = h as f32 / self.field_height as f32;
 
         let mut grid: Vec<Vec<(char, Style)>> =
-            vec![vec![(' ', Style::default().bg(Color::Rgb(5, 5, 15))); w]; h];
+            vec![vec![(' ', Style::default().bg(theme.paint(Color::Rgb(5, 5, 15), Role::Background))); w]; h];
 
         // Draw grid cells
-        for gy in 0..self.field_height {
-            for gx in 0..self.field_width {
+        for (gy, row) in cells.iter().enumerate() {
+            for (gx, cell) in row.iter().enumerate() {
                 let px = (gx as f32 * sx) as usize;
                 let py = (gy as f32 * sy) as usize;
                 if px >= w || py >= h {
                     continue;
                 }
-                match self.grid[gy][gx] {
+                match cell {
                     CellState::Empty => {}
                     CellState::Filled => {
                         grid[py][px] = ('█', Style::default()
-                            .fg(Color::Rgb(30, 60, 120))
-                            .bg(Color::Rgb(15, 30, 60)));
+                            .fg(theme.paint(Color::Rgb(30, 60, 120), Role::Blue))
+                            .bg(theme.paint(Color::Rgb(15, 30, 60), Role::Panel)));
                     }
                     CellState::WallGrowing => {
-                        let blink = if self.tick % 4 < 2 { '▓' } else { '░' };
+                        let blink = if fx::blink(self.tick, 4) { '▓' } else { '░' };
                         grid[py][px] = (blink, Style::default()
-                            .fg(Color::Rgb(255, 200, 50))
-                            .bg(Color::Rgb(80, 60, 10)));
+                            .fg(theme.paint(Color::Rgb(255, 200, 50), Role::Yellow))
+                            .bg(theme.paint(Color::Rgb(80, 60, 10), Role::Faint)));
                     }
                     CellState::WallComplete => {
                         grid[py][px] = ('▓', Style::default()
-                            .fg(Color::Rgb(50, 100, 180))
-                            .bg(Color::Rgb(20, 40, 80)));
+                            .fg(theme.paint(Color::Rgb(50, 100, 180), Role::Blue))
+                            .bg(theme.paint(Color::Rgb(20, 40, 80), Role::Panel)));
+                    }
+                    CellState::Block => {
+                        grid[py][px] = ('▒', Style::default()
+                            .fg(theme.paint(Color::Rgb(140, 120, 90), Role::Orange))
+                            .bg(theme.paint(Color::Rgb(50, 40, 30), Role::Panel)));
                     }
                 }
             }
         }
 
+        if !live {
+            return to_lines(grid);
+        }
+
         // Draw balls
         let ball_colors = [
-            Color::Rgb(255, 80, 80),
-            Color::Rgb(80, 255, 80),
-            Color::Rgb(80, 80, 255),
-            Color::Rgb(255, 255, 80),
-            Color::Rgb(255, 80, 255),
-            Color::Rgb(80, 255, 255),
-            Color::Rgb(255, 160, 80),
-            Color::Rgb(200, 200, 200),
+            theme.paint(Color::Rgb(255, 80, 80), Role::Red),
+            theme.paint(Color::Rgb(80, 255, 80), Role::Green),
+            theme.paint(Color::Rgb(80, 80, 255), Role::Blue),
+            theme.paint(Color::Rgb(255, 255, 80), Role::Yellow),
+            theme.paint(Color::Rgb(255, 80, 255), Role::Magenta),
+            theme.paint(Color::Rgb(80, 255, 255), Role::Cyan),
+            theme.paint(Color::Rgb(255, 160, 80), Role::Orange),
+            theme.paint(Color::Rgb(200, 200, 200), Role::Text),
         ];
         for (i, ball) in self.balls.iter().enumerate() {
             let px = (ball.x * sx) as usize;
@@ -437,7 +716,7 @@ impl JezzBall {
                 let color = ball_colors[i % ball_colors.len()];
                 grid[py][px] = ('●', Style::default()
                     .fg(color)
-                    .bg(Color::Rgb(5, 5, 15))
+                    .bg(theme.paint(Color::Rgb(5, 5, 15), Role::Background))
                     .add_modifier(Modifier::BOLD));
             }
         }
@@ -450,15 +729,15 @@ impl JezzBall {
                 WallDirection::Horizontal => '─',
                 WallDirection::Vertical => '│',
             };
-            let blink = self.tick % 6 < 4;
+            let blink = fx::blink(self.tick, 6);
             let cursor_color = if blink {
-                Color::Rgb(255, 255, 255)
+                theme.paint(Color::Rgb(255, 255, 255), Role::Bright)
             } else {
-                Color::Rgb(150, 150, 150)
+                theme.paint(Color::Rgb(150, 150, 150), Role::Dim)
             };
             grid[cpy][cpx] = (cursor_char, Style::default()
                 .fg(cursor_color)
-                .bg(Color::Rgb(40, 40, 60))
+                .bg(theme.paint(Color::Rgb(40, 40, 60), Role::Panel))
                 .add_modifier(Modifier::BOLD));
 
             // Draw direction indicator
@@ -466,45 +745,154 @@ impl JezzBall {
                 WallDirection::Horizontal => {
                     if cpx > 0 {
                         grid[cpy][cpx - 1] = ('╶', Style::default()
-                            .fg(Color::Rgb(100, 100, 140))
-                            .bg(Color::Rgb(5, 5, 15)));
+                            .fg(theme.paint(Color::Rgb(100, 100, 140), Role::Faint))
+                            .bg(theme.paint(Color::Rgb(5, 5, 15), Role::Background)));
                     }
                     if cpx + 1 < w {
                         grid[cpy][cpx + 1] = ('╴', Style::default()
-                            .fg(Color::Rgb(100, 100, 140))
-                            .bg(Color::Rgb(5, 5, 15)));
+                            .fg(theme.paint(Color::Rgb(100, 100, 140), Role::Faint))
+                            .bg(theme.paint(Color::Rgb(5, 5, 15), Role::Background)));
                     }
                 }
                 WallDirection::Vertical => {
                     if cpy > 0 {
                         grid[cpy - 1][cpx] = ('╵', Style::default()
-                            .fg(Color::Rgb(100, 100, 140))
-                            .bg(Color::Rgb(5, 5, 15)));
+                            .fg(theme.paint(Color::Rgb(100, 100, 140), Role::Faint))
+                            .bg(theme.paint(Color::Rgb(5, 5, 15), Role::Background)));
                     }
                     if cpy + 1 < h {
                         grid[cpy + 1][cpx] = ('╷', Style::default()
-                            .fg(Color::Rgb(100, 100, 140))
-                            .bg(Color::Rgb(5, 5, 15)));
+                            .fg(theme.paint(Color::Rgb(100, 100, 140), Role::Faint))
+                            .bg(theme.paint(Color::Rgb(5, 5, 15), Role::Background)));
                     }
                 }
             }
         }
 
-        grid.into_iter()
-            .map(|row| {
-                let spans: Vec<Span<'static>> = row
-                    .into_iter()
-                    .map(|(ch, style)| Span::styled(String::from(ch), style))
-                    .collect();
-                Line::from(spans)
+        to_lines(grid)
+    }
+
+    /// Level-clear panel: how the walls went and the capture curve.
+    fn render_level_stats(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.paint(Color::Green, Role::Green)))
+            .title(match self.current_puzzle() {
+                Some(puzzle) => format!(" {} solved ", puzzle.name),
+                None => format!(" Level {} cleared ", self.level),
             })
-            .collect()
+            .title_style(Style::default().fg(theme.paint(Color::Green, Role::Green)).add_modifier(Modifier::BOLD))
+            .style(Style::default().bg(theme.paint(Color::Rgb(10, 10, 20), Role::Background)));
+        let inner = block.inner(area);
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+
+        let walls = self.log.events.len();
+        let pct = self.filled_percent();
+        let label = Style::default().fg(theme.paint(Color::Gray, Role::Dim));
+        let value = Style::default().fg(theme.paint(Color::White, Role::Bright)).add_modifier(Modifier::BOLD);
+        let row = |name: &str, v: String| Line::from(vec![
+            Span::styled(format!(" {:<10}", name), label),
+            Span::styled(v, value),
+        ]);
+        let per_wall = if walls > 0 { format!("{:.1}%", pct / walls as f32) } else { "-".to_string() };
+        let stats = &self.stats;
+        let mut text = vec![
+            row("Walls", format!("{} built, {} lost", walls, self.log.lost)),
+            row("Time", format!("{:.1}s", self.log.ticks() as f32 * STEP_SECS)),
+            row("Captured", format!("{:.0}%", pct)),
+            row("Per wall", per_wall),
+        ];
+        if let Some(puzzle) = self.current_puzzle() {
+            text.push(row("Stars", format!("{} (best {})",
+                jezzball_puzzles::star_text(self.stars),
+                jezzball_puzzles::star_text(self.stats.stars(&puzzle.name)))));
+        }
+        text.extend([
+            Line::raw(""),
+            Line::from(Span::styled(" All time", label)),
+            row("Levels", format!("{}", stats.levels)),
+            row("Best", format!("{:.1}% per wall", stats.best_per_wall)),
+            row("Fastest", format!("{:.1}s", stats.fastest as f32 * STEP_SECS)),
+            Line::raw(""),
+        ]);
+
+        // Capture % over the level, with the target as a dotted line
+        let graph_rows = (inner.height as usize).saturating_sub(text.len() + 1).min(GRAPH_ROWS);
+        let cols = inner.width.saturating_sub(2) as usize;
+        if graph_rows >= 2 && cols >= 8 {
+            text.push(Line::from(Span::styled(" Capture over time", label)));
+            let mut curve = BrailleCanvas::new(cols, graph_rows);
+            let (dw, dh) = (curve.dot_width(), curve.dot_height());
+            let span = self.log.ticks().max(1) as f32;
+            let to_dot = |t: u64, p: f32| {
+                ((t as f32 / span * (dw - 1) as f32) as i32, dh - 1 - (p / 100.0 * (dh - 1) as f32) as i32)
+            };
+            let mut prev = (0, dh - 1);
+            for &(t, p) in &self.log.capture {
+                let next = to_dot(t, p);
+                curve.line(prev.0, prev.1, next.0, next.1);
+                prev = next;
+            }
+            let mut target = BrailleCanvas::new(cols, graph_rows);
+            let ty = to_dot(0, self.target_percent).1;
+            for x in (0..dw).step_by(4) {
+                target.set(x, ty);
+            }
+            let mut grid = vec![vec![(' ', Style::default()); cols]; graph_rows];
+            target.draw(&mut grid, theme.paint(Color::Rgb(120, 110, 40), Role::Yellow), None, false);
+            curve.draw(&mut grid, theme.paint(Color::Rgb(80, 200, 255), Role::Blue), None, true);
+            for line in to_lines(grid) {
+                let mut spans = vec![Span::raw(" ")];
+                spans.extend(line.spans);
+                text.push(Line::from(spans));
+            }
+        }
+        frame.render_widget(Paragraph::new(text), inner);
     }
 }
 
+fn to_lines(grid: Vec<Vec<(char, Style)>>) -> Vec<Line<'static>> {
+    grid.into_iter()
+        .map(|row| {
+            let spans: Vec<Span<'static>> = row
+                .into_iter()
+                .map(|(ch, style)| Span::styled(String::from(ch), style))
+                .collect();
+            Line::from(spans)
+        })
+        .collect()
+}
+
+pub const INFO: GameInfo = GameInfo {
+    name: "JezzBall",
+    icon: "🟦",
+    author: "RustCade",
+    blurb: "Build walls to\ntrap the balls!",
+    description: "Build walls to trap balls!",
+    controls: &[
+        ("↑ ↓ ← →", "Move cursor"),
+        ("Space", "Place wall"),
+        ("R", "Restart"),
+        ("P", "Pause"),
+    ],
+    tags: &["puzzle", "physics"],
+    color: Ink(Color::Rgb(100, 180, 255), Role::Blue),
+    border_color: Ink(Color::Rgb(50, 90, 140), Role::Blue),
+};
+
 impl Game for JezzBall {
+    fn info(&self) -> &'static GameInfo {
+        &INFO
+    }
+
     fn update(&mut self) {
-        if self.game_over || self.won_level || self.paused {
+        if self.won_level {
+            self.replay_step = (self.replay_step + 1).min(REPLAY_STEPS);
+            return;
+        }
+        if self.game_over || self.paused || self.puzzle_failed {
             return;
         }
         self.tick += 1;
@@ -512,8 +900,13 @@ impl Game for JezzBall {
         // Move balls and check for wall hits
         let wall_hit = self.move_balls();
         if wall_hit {
+            self.log.lost += self.growing_walls.len() as u32;
             self.destroy_growing_walls();
             self.lives = self.lives.saturating_sub(1);
+            if self.lives == 0 && self.puzzle.is_some() {
+                self.puzzle_failed = true;
+                return;
+            }
             if self.lives == 0 {
                 self.game_over = true;
                 if self.score > self.high_score {
@@ -526,6 +919,10 @@ impl Game for JezzBall {
         if self.tick % 2 == 0 {
             self.grow_walls();
         }
+        let level_ticks = self.tick - self.log.start_tick;
+        if level_ticks.is_multiple_of(CAPTURE_SAMPLE_TICKS) {
+            self.log.capture.push((level_ticks, self.filled_percent()));
+        }
 
         // Check win condition
         if self.filled_percent() >= self.target_percent {
@@ -534,6 +931,10 @@ impl Game for JezzBall {
             if self.score > self.high_score {
                 self.high_score = self.score;
             }
+            self.finish_level();
+        } else if self.time_left() == Some(0.0) {
+            self.destroy_growing_walls();
+            self.puzzle_failed = true;
         }
     }
 
@@ -541,10 +942,12 @@ impl Game for JezzBall {
         match key.code {
             KeyCode::Char('r') | KeyCode::Char('R') => self.reset(),
             KeyCode::Char('p') | KeyCode::Char('P') => {
-                if !self.game_over && !self.won_level {
+                if !self.game_over && !self.won_level && !self.puzzle_failed {
                     self.paused = !self.paused;
                 }
             }
+            KeyCode::Char('t') | KeyCode::Char('T') if !self.paused => self.toggle_puzzles(),
+            KeyCode::Char('n') | KeyCode::Char('N') if !self.paused && self.puzzle.is_some() => self.next_puzzle(),
             _ => {
                 if self.game_over {
                     if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
@@ -552,9 +955,18 @@ impl Game for JezzBall {
                     }
                     return;
                 }
+                if self.puzzle_failed {
+                    if let (KeyCode::Enter | KeyCode::Char(' '), Some(idx)) = (key.code, self.puzzle) {
+                        self.start_puzzle(idx);
+                    }
+                    return;
+                }
                 if self.won_level {
-                    if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
-                        self.advance_level();
+                    match key.code {
+                        KeyCode::Enter | KeyCode::Char(' ') if self.puzzle.is_some() => self.next_puzzle(),
+                        KeyCode::Enter | KeyCode::Char(' ') => self.advance_level(),
+                        KeyCode::Char('v') | KeyCode::Char('V') => self.replay_step = 0,
+                        _ => {}
                     }
                     return;
                 }
@@ -598,13 +1010,8 @@ impl Game for JezzBall {
         }
     }
 
-    fn render(&mut self, frame: &mut Frame, area: Rect) {
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Color::Rgb(80, 150, 220)))
-            .title(" 🟦 JezzBall ")
-            .title_style(Style::default().fg(Color::Rgb(100, 180, 255)).add_modifier(Modifier::BOLD));
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = self.title_bar(theme);
 
         let inner = block.inner(area);
         frame.render_widget(block, area);
@@ -612,7 +1019,7 @@ impl Game for JezzBall {
         // Resize grid if needed
         let new_fw = inner.width as usize;
         let new_fh = inner.height.saturating_sub(2) as usize;
-        if new_fw > 4 && new_fh > 4 && !self.game_over && !self.won_level && self.growing_walls.is_empty() {
+        if new_fw > 4 && new_fh > 4 && !self.game_over && !self.won_level && !self.puzzle_failed && self.growing_walls.is_empty() {
             if new_fw != self.field_width || new_fh != self.field_height {
                 self.field_width = new_fw;
                 self.field_height = new_fh;
@@ -624,6 +1031,11 @@ impl Game for JezzBall {
                     ball.x = ball.x.min(new_fw as f32 - 0.01);
                     ball.y = ball.y.min(new_fh as f32 - 0.01);
                 }
+                // The logged walls no longer fit the new board
+                self.log = LevelLog { start_tick: self.tick, ..LevelLog::default() };
+                if let Some(idx) = self.puzzle {
+                    self.start_puzzle(idx);
+                }
             }
         }
 
@@ -642,98 +1054,239 @@ impl Game for JezzBall {
             WallDirection::Vertical => "Vert │",
         };
         let pct = self.filled_percent();
-        let status = Line::from(vec![
+        let stage = match self.current_puzzle() {
+            Some(puzzle) => format!("{} {} ", puzzle.name, jezzball_puzzles::star_text(self.stats.stars(&puzzle.name))),
+            None => format!("Level: {} ", self.level),
+        };
+        let mut status = Line::from(vec![
             Span::styled(" 🟦 ", Style::default()),
             Span::styled(
                 format!("Score: {} ", self.score),
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             Span::styled(
                 format!("Lives: {} ", "♥ ".repeat(self.lives as usize)),
-                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
-            ),
-            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
-            Span::styled(
-                format!("Level: {} ", self.level),
-                Style::default().fg(Color::Cyan),
+                Style::default().fg(theme.paint(Color::Red, Role::Red)).add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+            Span::styled(stage, Style::default().fg(theme.paint(Color::Cyan, Role::Cyan))),
+            Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             Span::styled(
                 format!("Filled: {:.0}%/{:.0}% ", pct, self.target_percent),
-                Style::default().fg(if pct >= self.target_percent * 0.8 { Color::Green } else { Color::White }),
+                Style::default().fg(if pct >= self.target_percent * 0.8 { theme.paint(Color::Green, Role::Green) } else { theme.paint(Color::White, Role::Bright) }),
             ),
-            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             Span::styled(
                 format!("🏆 {} ", self.high_score),
-                Style::default().fg(Color::Rgb(180, 140, 50)),
+                Style::default().fg(theme.paint(Color::Rgb(180, 140, 50), Role::Orange)),
             ),
-            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             Span::styled(
                 format!("Dir: {} ", dir_label),
-                Style::default().fg(Color::Rgb(150, 200, 255)),
+                Style::default().fg(theme.paint(Color::Rgb(150, 200, 255), Role::Blue)),
             ),
         ]);
+        if let Some(left) = self.time_left() {
+            let color = if left < 10.0 && fx::blink(self.tick, 8) { theme.ink(fx::alert_color()) } else { theme.paint(Color::White, Role::Bright) };
+            status.spans.push(Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))));
+            status.spans.push(Span::styled(
+                format!("⏱ {:.0}s ", left.ceil()),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ));
+        }
         frame.render_widget(Paragraph::new(status), chunks[0]);
 
         // Game field
         let fw = chunks[1].width as usize;
         let fh = chunks[1].height as usize;
-        if fw > 0 && fh > 0 {
-            let lines = self.render_field(fw, fh);
+        if self.won_level && fw >= 40 && fh >= 8 {
+            // Replay the walls going up beside the level's stats
+            let cols = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Min(10), Constraint::Length((fw as u16 / 2).min(48))])
+                .split(chunks[1]);
+            let replay = self.replay_grid();
+            let lines = self.render_field(&replay, false, cols[0].width as usize, fh, theme);
+            frame.render_widget(Paragraph::new(lines), cols[0]);
+            self.render_level_stats(frame, cols[1], theme);
+        } else if fw > 0 && fh > 0 {
+            let lines = self.render_field(&self.grid, true, fw, fh, theme);
             frame.render_widget(Paragraph::new(lines), chunks[1]);
         }
+        self.field_area = chunks[1];
 
         // Help/status bar
         if self.game_over {
             let msg = Paragraph::new(Line::from(vec![
-                Span::styled(" 💀 GAME OVER! ", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+                Span::styled(" 💀 GAME OVER! ", Style::default().fg(theme.ink(fx::alert_color())).add_modifier(Modifier::BOLD)),
                 Span::styled(
                     format!("Score: {} │ Press ENTER to restart, Esc for menu", self.score),
-                    Style::default().fg(Color::Gray),
+                    Style::default().fg(theme.paint(Color::Gray, Role::Dim)),
+                ),
+            ]));
+            frame.render_widget(msg, chunks[2]);
+        } else if self.puzzle_failed {
+            let reason = if self.lives == 0 { "OUT OF LIVES" } else { "TIME'S UP" };
+            let msg = Paragraph::new(Line::from(vec![
+                Span::styled(format!(" ⏱ {}! ", reason), Style::default().fg(theme.ink(fx::alert_color())).add_modifier(Modifier::BOLD)),
+                Span::styled(
+                    format!("Filled {:.0}% of {:.0}% │ Press ENTER to retry, N for next puzzle, T for levels", pct, self.target_percent),
+                    Style::default().fg(theme.paint(Color::Gray, Role::Dim)),
                 ),
             ]));
             frame.render_widget(msg, chunks[2]);
         } else if self.won_level {
+            let (title, next) = match self.puzzle {
+                Some(_) => (format!(" 🎉 PUZZLE SOLVED {} ", jezzball_puzzles::star_text(self.stars)), "next puzzle"),
+                None => (" 🎉 LEVEL COMPLETE! ".to_string(), "next level"),
+            };
             let msg = Paragraph::new(Line::from(vec![
-                Span::styled(" 🎉 LEVEL COMPLETE! ", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::styled(title, Style::default().fg(theme.paint(Color::Green, Role::Green)).add_modifier(Modifier::BOLD)),
                 Span::styled(
-                    format!("Score: {} │ Press ENTER for next level", self.score),
-                    Style::default().fg(Color::Gray),
+                    format!("Score: {} │ Press ENTER for {}, V to replay", self.score, next),
+                    Style::default().fg(theme.paint(Color::Gray, Role::Dim)),
                 ),
             ]));
             frame.render_widget(msg, chunks[2]);
         } else if self.paused {
             let msg = Paragraph::new(Line::from(vec![
-                Span::styled(" ⏸ PAUSED - Press P to resume ", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                Span::styled(" ⏸ PAUSED - Press P to resume ", Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD)),
             ]));
             frame.render_widget(msg, chunks[2]);
         } else {
             let help = Paragraph::new(Line::from(vec![
-                Span::styled(" ←↑↓→ Move ", Style::default().fg(Color::DarkGray)),
-                Span::styled("│ ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("SPACE Launch Wall ", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
-                Span::styled("│ ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("D Toggle Dir ", Style::default().fg(Color::DarkGray)),
-                Span::styled("│ ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("P Pause ", Style::default().fg(Color::DarkGray)),
-                Span::styled("│ ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("R Reset ", Style::default().fg(Color::DarkGray)),
-                Span::styled("│ ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("Esc Menu ", Style::default().fg(Color::DarkGray)),
-                Span::styled("│ ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("? Help", Style::default().fg(Color::DarkGray)),
+                Span::styled(" ←↑↓→ Move ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                Span::styled("│ ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint))),
+                Span::styled("SPACE Launch Wall ", Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD)),
+                Span::styled("│ ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint))),
+                Span::styled("D Toggle Dir ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                Span::styled("│ ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint))),
+                Span::styled("Click L/R Wall ─/│ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                Span::styled("│ ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint))),
+                Span::styled(if self.puzzle.is_some() { "T Levels N Next " } else { "T Puzzles " }, Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                Span::styled("│ ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint))),
+                Span::styled("P Pause ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                Span::styled("│ ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint))),
+                Span::styled("R Reset ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                Span::styled("│ ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint))),
+                Span::styled("Esc Menu ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                Span::styled("│ ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint))),
+                Span::styled("? Help", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             ]));
             frame.render_widget(help, chunks[2]);
         }
     }
 
+    /// Left click launches a horizontal wall from the clicked cell, right
+    /// click a vertical one, as in the original game.
+    fn handle_mouse(&mut self, event: MouseEvent) {
+        if self.game_over || self.won_level || self.paused || self.puzzle_failed { return; }
+        let dir = match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => WallDirection::Horizontal,
+            MouseEventKind::Down(MouseButton::Right) => WallDirection::Vertical,
+            _ => return,
+        };
+        let Some((x, y)) = self.cell_at(event.column, event.row) else { return };
+        self.cursor_x = x;
+        self.cursor_y = y;
+        self.wall_dir = dir;
+        self.launch_wall();
+    }
+
     fn get_score(&self) -> u32 { self.score }
     fn is_game_over(&self) -> bool { self.game_over }
+    fn is_paused(&self) -> bool { self.paused }
+
+    /// Classic mode only; a wall still growing is dropped from the save.
+    fn serialize_state(&self) -> Option<Vec<u8>> {
+        if self.game_over || self.won_level || self.puzzle.is_some() { return None; }
+        let mut w = StateWriter::new();
+        w.u32(self.field_width as u32);
+        w.u32(self.field_height as u32);
+        for row in &self.grid {
+            for &cell in row {
+                w.u8(match cell {
+                    CellState::Filled => 1,
+                    CellState::WallComplete => 2,
+                    CellState::Block => 3,
+                    CellState::Empty | CellState::WallGrowing => 0,
+                });
+            }
+        }
+        w.u32(self.balls.len() as u32);
+        for b in &self.balls {
+            for v in [b.x, b.y, b.dx, b.dy] {
+                w.f32(v);
+            }
+        }
+        w.u32(self.score);
+        w.u32(self.high_score);
+        w.u32(self.level);
+        w.u32(self.lives);
+        w.u64(self.tick);
+        w.f32(self.target_percent);
+        w.u32(self.levels_cleared);
+        Some(w.finish())
+    }
+
+    fn restore_state(&mut self, data: &[u8]) -> bool {
+        let mut r = StateReader::new(data);
+        let (Some(fw), Some(fh)) = (r.u32(), r.u32()) else { return false };
+        let (fw, fh) = (fw as usize, fh as usize);
+        if !(5..=1000).contains(&fw) || !(5..=1000).contains(&fh) { return false; }
+        let mut grid = vec![vec![CellState::Empty; fw]; fh];
+        for row in grid.iter_mut() {
+            for cell in row.iter_mut() {
+                *cell = match r.u8() {
+                    Some(0) => CellState::Empty,
+                    Some(1) => CellState::Filled,
+                    Some(2) => CellState::WallComplete,
+                    Some(3) => CellState::Block,
+                    _ => return false,
+                };
+            }
+        }
+        let Some(count) = r.u32() else { return false };
+        if count == 0 || count > 64 { return false; }
+        let mut balls = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let (Some(x), Some(y), Some(dx), Some(dy)) = (r.f32(), r.f32(), r.f32(), r.f32()) else { return false };
+            balls.push(Ball { x, y, dx, dy });
+        }
+        let (Some(score), Some(high_score), Some(level), Some(lives), Some(tick), Some(target), Some(cleared)) =
+            (r.u32(), r.u32(), r.u32(), r.u32(), r.u64(), r.f32(), r.u32())
+        else { return false };
+        if lives == 0 { return false; }
+
+        self.reset_classic();
+        self.total_empty = fw * fh;
+        self.field_width = fw;
+        self.field_height = fh;
+        self.grid = grid;
+        self.balls = balls;
+        self.cursor_x = fw / 2;
+        self.cursor_y = fh / 2;
+        self.score = score;
+        self.high_score = self.high_score.max(high_score);
+        self.level = level;
+        self.lives = lives;
+        self.tick = tick;
+        self.target_percent = target;
+        self.levels_cleared = cleared;
+        self.log = LevelLog { start_tick: tick, ..LevelLog::default() };
+        self.paused = true;
+        true
+    }
 
+    /// Starts over in the same mode: a puzzle restarts its board.
     fn reset(&mut self) {
-        let hs = self.high_score;
-        *self = JezzBall::new();
-        self.high_score = hs;
+        let puzzle = self.puzzle;
+        let puzzles = std::mem::take(&mut self.puzzles);
+        self.reset_classic();
+        if let Some(idx) = puzzle {
+            self.puzzles = puzzles;
+            self.start_puzzle(idx);
+        }
     }
 }
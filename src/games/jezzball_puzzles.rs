@@ -0,0 +1,171 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::scores::data_path;
+
+const FILE_EXT: &str = "txt";
+/// Largest board a puzzle file can describe
+pub const MAX_ROWS: usize = 40;
+pub const MAX_COLS: usize = 120;
+
+/// A hand-made JezzBall board: walls already standing, where the balls
+/// start, and the capture to reach before the clock runs out.
+///
+/// ```text
+/// ; comments start with a semicolon
+/// target 70     % of the open field to capture
+/// time 60       seconds
+/// lives 3
+/// ##########
+/// #...o....#
+/// #....##..#
+/// #..o.....#
+/// ##########
+/// ```
+///
+/// `#` is a wall and `o` a ball's starting cell; anything else is open
+/// field. The art is stretched over the whole field, whatever its size.
+#[derive(Clone)]
+pub struct Puzzle {
+    pub name: String,
+    pub target: f32,
+    pub time_secs: u32,
+    pub lives: u32,
+    /// Row by row; `true` is a wall
+    pub walls: Vec<Vec<bool>>,
+    /// Starting cells in art coordinates
+    pub balls: Vec<(usize, usize)>,
+}
+
+const BUILTIN: [(&str, &str); 4] = [
+    ("Pillars", "\
+target 65
+time 60
+..........................
+....##.....##.....##......
+....##.....##.....##......
+..o.....................o.
+..........................
+....##.....##.....##......
+....##.....##.....##......
+.........................."),
+    ("Corridor", "\
+target 70
+time 50
+##########################
+..o.......................
+##########..##############
+..........................
+..........................
+##############..##########
+.......................o..
+##########################"),
+    ("Cross", "\
+target 75
+time 60
+lives 2
+.............#............
+..o..........#............
+.............#.......o....
+.............#............
+#######...........########
+.............#............
+.....o.......#............
+.............#............"),
+    ("Chambers", "\
+target 80
+time 75
+.........#.......#........
+..o......#...o...#....o...
+.........#.......#........
+.........#.......#........
+..........................
+.........#.......#........
+.........#.......#........
+.........#.......#........"),
+];
+
+impl Puzzle {
+    pub fn parse(name: &str, text: &str) -> Option<Puzzle> {
+        let mut puzzle = Puzzle {
+            name: name.to_string(),
+            target: 75.0,
+            time_secs: 60,
+            lives: 3,
+            walls: Vec::new(),
+            balls: Vec::new(),
+        };
+        let mut rows: Vec<&str> = Vec::new();
+        for line in text.lines() {
+            if line.starts_with(';') { continue; }
+            let mut words = line.split_whitespace();
+            let value = |v: Option<&str>| v.and_then(|v| v.parse::<f32>().ok());
+            match (words.next(), value(words.next())) {
+                (Some("target"), Some(v)) => puzzle.target = v.clamp(1.0, 100.0),
+                (Some("time"), Some(v)) => puzzle.time_secs = (v as u32).max(1),
+                (Some("lives"), Some(v)) => puzzle.lives = (v as u32).max(1),
+                _ => rows.push(line.trim_end()),
+            }
+        }
+        while rows.last().is_some_and(|r| r.is_empty()) { rows.pop(); }
+        let first = rows.iter().position(|r| !r.is_empty())?;
+
+        for (y, line) in rows[first..].iter().take(MAX_ROWS).enumerate() {
+            let mut row = Vec::new();
+            for (x, c) in line.chars().take(MAX_COLS).enumerate() {
+                if c == 'o' { puzzle.balls.push((x, y)); }
+                row.push(c == '#');
+            }
+            puzzle.walls.push(row);
+        }
+        // A board with no balls would be cleared by its first wall
+        if puzzle.balls.is_empty() { return None; }
+        Some(puzzle)
+    }
+
+    pub fn columns(&self) -> usize {
+        self.walls.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.walls.len()
+    }
+
+    pub fn is_wall(&self, x: usize, y: usize) -> bool {
+        self.walls.get(y).and_then(|row| row.get(x)).copied().unwrap_or(false)
+    }
+
+    /// The built-in boards, then any from the `puzzles` folder sorted by
+    /// file name.
+    pub fn load_all() -> Vec<Puzzle> {
+        let mut puzzles: Vec<Puzzle> = BUILTIN.iter().filter_map(|(name, text)| Puzzle::parse(name, text)).collect();
+        let Ok(entries) = fs::read_dir(puzzle_dir()) else { return puzzles };
+        let mut files: Vec<PathBuf> = entries.flatten()
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|e| e == FILE_EXT))
+            .collect();
+        files.sort();
+        puzzles.extend(files.iter().filter_map(|p| Puzzle::parse(&file_stem(p), &fs::read_to_string(p).ok()?)));
+        puzzles
+    }
+}
+
+/// Stars for a cleared puzzle: one for reaching the target in time, one
+/// more for losing no walls, and a third for doing both with at least
+/// half the clock left.
+pub fn stars(walls_lost: u32, time_left: f32, time_secs: u32) -> u8 {
+    let clean = walls_lost == 0;
+    1 + clean as u8 + (clean && time_left * 2.0 >= time_secs as f32) as u8
+}
+
+pub fn star_text(stars: u8) -> String {
+    (0..3).map(|i| if i < stars { '★' } else { '☆' }).collect()
+}
+
+fn file_stem(path: &Path) -> String {
+    path.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default()
+}
+
+fn puzzle_dir() -> PathBuf {
+    data_path("puzzles")
+}
@@ -0,0 +1,670 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::games::life_patterns::LifePattern;
+use crate::games::{Game, GameInfo};
+use crate::session::{StateReader, StateWriter};
+use crate::ui::braille::BrailleCanvas;
+use crate::ui::hud::{self, Countdown};
+use crate::ui::theme::{Ink, Role, Theme};
+
+/// Universe size in cells: braille packs 2×4 cells into each character,
+/// so this draws 80 columns by 24 rows. Cells beyond the edge are dead.
+const W: i32 = 160;
+const H: i32 = 96;
+/// Generations a second the run speed steps through
+const SPEEDS: [u32; 7] = [1, 2, 5, 10, 20, 30, 60];
+const DEFAULT_SPEED: usize = 3;
+/// Ticks a notice stays on the status line
+const NOTICE_TICKS: u32 = 150;
+const ACCENT: Ink = Ink(Color::Rgb(120, 230, 140), Role::Green);
+const FIELD_BG: Ink = Ink(Color::Rgb(10, 16, 12), Role::Background);
+const ZONE_BG: Ink = Ink(Color::Rgb(20, 38, 26), Role::Panel);
+const TARGET_BG: Ink = Ink(Color::Rgb(55, 22, 26), Role::Panel);
+const CURSOR_BG: Ink = Ink(Color::Rgb(60, 60, 100), Role::Faint);
+
+#[derive(Clone, Copy)]
+enum Goal {
+    /// Any live cell inside (x0, y0, x1, y1)
+    Reach(i32, i32, i32, i32),
+    /// At least this many live cells at the generation limit
+    Population(u32),
+    /// Every cell dead
+    Clear,
+}
+
+/// A challenge: cells may only be placed inside the build zone, no more
+/// than `budget` of them, and the goal must be met by generation `limit`.
+struct Challenge {
+    name: &'static str,
+    brief: &'static str,
+    /// (x0, y0, x1, y1)
+    zone: (i32, i32, i32, i32),
+    budget: u32,
+    limit: u32,
+    goal: Goal,
+    /// Cells on the field from the start, which can't be edited: (x, y, `.cells` art)
+    fixed: Option<(i32, i32, &'static str)>,
+}
+
+const CHALLENGES: [Challenge; 4] = [
+    Challenge {
+        name: "Delivery",
+        brief: "Send something to the red square",
+        zone: (4, 4, 20, 20),
+        budget: 5,
+        limit: 300,
+        goal: Goal::Reach(60, 60, 76, 76),
+        fixed: None,
+    },
+    Challenge {
+        name: "Long Fuse",
+        brief: "150 cells alive at generation 400",
+        zone: (74, 42, 86, 54),
+        budget: 5,
+        limit: 400,
+        goal: Goal::Population(150),
+        fixed: None,
+    },
+    Challenge {
+        name: "Crossing",
+        brief: "Reach the far side of the field",
+        zone: (4, 40, 20, 56),
+        budget: 9,
+        limit: 400,
+        goal: Goal::Reach(140, 40, 156, 56),
+        fixed: None,
+    },
+    Challenge {
+        name: "Demolition",
+        brief: "Leave nothing alive, block included",
+        zone: (16, 6, 40, 30),
+        budget: 5,
+        limit: 300,
+        goal: Goal::Clear,
+        fixed: Some((60, 50, "OO\nOO")),
+    },
+];
+
+/// How a challenge run ended.
+#[derive(Clone, Copy, PartialEq)]
+enum Outcome {
+    Won,
+    Failed,
+}
+
+/// Conway's Game of Life: a sandbox to draw and stamp patterns in and
+/// run them, plus challenges that ask for a goal from a few cells.
+pub struct LifeGame {
+    cells: Vec<bool>,
+    /// The field as it was before the run, for rewinding
+    start: Vec<bool>,
+    /// Cells that came with the challenge
+    fixed: Vec<bool>,
+    generation: u32,
+    running: bool,
+    speed: usize,
+    /// Ticks since the last generation while running
+    clock: u32,
+    cursor: (i32, i32),
+    patterns: Vec<LifePattern>,
+    /// Library pattern Space stamps; None places single cells
+    pattern: Option<usize>,
+    /// Quarter turns clockwise for the stamp
+    turns: u8,
+    /// None is the sandbox
+    challenge: Option<usize>,
+    cleared: [bool; CHALLENGES.len()],
+    outcome: Option<Outcome>,
+    score: u32,
+    high_score: u32,
+    /// Every challenge cleared
+    game_over: bool,
+    paused: bool,
+    countdown: Countdown,
+    /// Status line notice and ticks left
+    notice: Option<(String, u32)>,
+}
+
+fn index(x: i32, y: i32) -> Option<usize> {
+    (x >= 0 && y >= 0 && x < W && y < H).then(|| (y * W + x) as usize)
+}
+
+impl LifeGame {
+    pub fn new() -> Self {
+        Self {
+            cells: vec![false; (W * H) as usize],
+            start: vec![false; (W * H) as usize],
+            fixed: vec![false; (W * H) as usize],
+            generation: 0,
+            running: false,
+            speed: DEFAULT_SPEED,
+            clock: 0,
+            cursor: (W / 2, H / 2),
+            patterns: LifePattern::load_all(),
+            pattern: None,
+            turns: 0,
+            challenge: None,
+            cleared: [false; CHALLENGES.len()],
+            outcome: None,
+            score: 0,
+            high_score: 0,
+            game_over: false,
+            paused: false,
+            countdown: Countdown::default(),
+            notice: None,
+        }
+    }
+
+    /// Challenges cleared so far.
+    pub fn challenges_cleared(&self) -> u32 {
+        self.cleared.iter().filter(|&&c| c).count() as u32
+    }
+
+    fn alive(&self, x: i32, y: i32) -> bool {
+        index(x, y).is_some_and(|i| self.cells[i])
+    }
+
+    fn population(&self) -> u32 {
+        self.cells.iter().filter(|&&c| c).count() as u32
+    }
+
+    fn current(&self) -> Option<&'static Challenge> {
+        self.challenge.map(|c| &CHALLENGES[c])
+    }
+
+    /// Cells the player has placed in the current challenge.
+    fn used(&self) -> u32 {
+        self.cells.iter().zip(&self.fixed).filter(|(&c, &f)| c && !f).count() as u32
+    }
+
+    /// Whether the player may edit (x, y): on the field, and in a
+    /// challenge inside the build zone.
+    fn editable(&self, x: i32, y: i32) -> bool {
+        let Some(i) = index(x, y) else { return false };
+        match self.current() {
+            Some(c) => {
+                let (x0, y0, x1, y1) = c.zone;
+                !self.fixed[i] && x >= x0 && x < x1 && y >= y0 && y < y1
+            }
+            None => true,
+        }
+    }
+
+    fn notify(&mut self, text: impl Into<String>) {
+        self.notice = Some((text.into(), NOTICE_TICKS));
+    }
+
+    /// Open the sandbox (None) or a challenge on a fresh field.
+    fn load(&mut self, challenge: Option<usize>) {
+        self.challenge = challenge;
+        self.cells.iter_mut().for_each(|c| *c = false);
+        self.fixed.iter_mut().for_each(|c| *c = false);
+        if let Some((fx, fy, art)) = self.current().and_then(|c| c.fixed) {
+            if let Some(pattern) = LifePattern::parse("", art) {
+                for (x, y) in pattern.cells {
+                    if let Some(i) = index(fx + x, fy + y) {
+                        self.cells[i] = true;
+                        self.fixed[i] = true;
+                    }
+                }
+            }
+        }
+        if let Some(c) = self.current() {
+            let (x0, y0, x1, y1) = c.zone;
+            self.cursor = ((x0 + x1) / 2, (y0 + y1) / 2);
+        }
+        self.start = self.cells.clone();
+        self.generation = 0;
+        self.running = false;
+        self.outcome = None;
+    }
+
+    /// Back to the field as it was before the run.
+    fn rewind(&mut self) {
+        self.cells = self.start.clone();
+        self.generation = 0;
+        self.running = false;
+        self.outcome = None;
+    }
+
+    /// Stopped, and in a challenge not yet run: the sandbox can be drawn
+    /// on between generations too.
+    fn editing(&self) -> bool {
+        !self.running && self.outcome.is_none() && (self.challenge.is_none() || self.generation == 0)
+    }
+
+    /// Space at the cursor: flip one cell, or stamp the library pattern.
+    fn place(&mut self) {
+        if !self.editing() {
+            self.notify("R rewinds to edit");
+            return;
+        }
+        let (cx, cy) = self.cursor;
+        let Some(p) = self.pattern else {
+            if !self.editable(cx, cy) {
+                self.notify("Outside the build zone");
+                return;
+            }
+            let Some(i) = index(cx, cy) else { return };
+            if !self.cells[i] && self.current().is_some_and(|c| self.used() >= c.budget) {
+                self.notify("No cells left in the budget");
+                return;
+            }
+            self.cells[i] = !self.cells[i];
+            self.edited();
+            return;
+        };
+        let cells: Vec<(i32, i32)> = self.patterns[p].rotated(self.turns).into_iter().map(|(x, y)| (cx + x, cy + y)).collect();
+        if cells.iter().any(|&(x, y)| !self.editable(x, y)) {
+            self.notify(if self.challenge.is_some() { "Doesn't fit in the build zone" } else { "Doesn't fit on the field" });
+            return;
+        }
+        let new = cells.iter().filter(|&&(x, y)| !self.alive(x, y)).count() as u32;
+        if let Some(c) = self.current() {
+            if self.used() + new > c.budget {
+                self.notify(format!("Needs {} cells, {} left", new, c.budget - self.used()));
+                return;
+            }
+        }
+        for (x, y) in cells {
+            if let Some(i) = index(x, y) { self.cells[i] = true; }
+        }
+        self.edited();
+    }
+
+    /// An edit makes a new layout to run, and rewind, from.
+    fn edited(&mut self) {
+        self.start = self.cells.clone();
+        self.generation = 0;
+    }
+
+    /// Clear what the player placed: the whole sandbox, or a challenge
+    /// back to its fixed cells.
+    fn clear(&mut self) {
+        if self.running { return; }
+        let fixed = self.fixed.clone();
+        self.cells = fixed;
+        self.start = self.cells.clone();
+        self.generation = 0;
+        self.outcome = None;
+    }
+
+    /// One generation, bounded: the edge is dead all round.
+    fn step(&mut self) {
+        let mut next = vec![false; self.cells.len()];
+        for y in 0..H {
+            for x in 0..W {
+                let mut n = 0;
+                for (dx, dy) in [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)] {
+                    n += self.alive(x + dx, y + dy) as u32;
+                }
+                let i = (y * W + x) as usize;
+                next[i] = n == 3 || (n == 2 && self.cells[i]);
+            }
+        }
+        self.cells = next;
+        self.generation += 1;
+        self.judge();
+    }
+
+    /// In a challenge, see whether this generation meets the goal or runs
+    /// out the limit.
+    fn judge(&mut self) {
+        let (Some(ci), None) = (self.challenge, self.outcome) else { return };
+        let c = &CHALLENGES[ci];
+        let won = match c.goal {
+            Goal::Reach(x0, y0, x1, y1) => (y0..y1).any(|y| (x0..x1).any(|x| self.alive(x, y))),
+            Goal::Population(min) => self.generation == c.limit && self.population() >= min,
+            Goal::Clear => self.population() == 0,
+        };
+        if won {
+            self.outcome = Some(Outcome::Won);
+            self.running = false;
+            if !self.cleared[ci] {
+                self.cleared[ci] = true;
+                // Cells to spare and generations to spare both count
+                let used = self.start.iter().zip(&self.fixed).filter(|(&c, &f)| c && !f).count() as u32;
+                self.score += 100 * (ci as u32 + 1) + 20 * c.budget.saturating_sub(used) + (c.limit - self.generation.min(c.limit)) / 2;
+                self.high_score = self.high_score.max(self.score);
+                if self.cleared.iter().all(|&c| c) {
+                    self.game_over = true;
+                }
+            }
+        } else if self.generation >= c.limit {
+            self.outcome = Some(Outcome::Failed);
+            self.running = false;
+        }
+    }
+
+    fn toggle_run(&mut self) {
+        if self.outcome.is_some() { return; }
+        self.running = !self.running;
+        self.clock = 0;
+    }
+
+    /// M: sandbox, then each challenge in turn.
+    fn next_mode(&mut self) {
+        let next = match self.challenge {
+            None => Some(0),
+            Some(c) if c + 1 < CHALLENGES.len() => Some(c + 1),
+            Some(_) => None,
+        };
+        self.load(next);
+    }
+
+    fn cycle_pattern(&mut self, forward: bool) {
+        let n = self.patterns.len();
+        // None sits between the last pattern and the first
+        self.pattern = match (self.pattern, forward) {
+            (None, true) => (n > 0).then_some(0),
+            (None, false) => n.checked_sub(1),
+            (Some(p), true) => (p + 1 < n).then_some(p + 1),
+            (Some(p), false) => p.checked_sub(1),
+        };
+    }
+
+    fn move_cursor(&mut self, dx: i32, dy: i32) {
+        self.cursor = ((self.cursor.0 + dx).clamp(0, W - 1), (self.cursor.1 + dy).clamp(0, H - 1));
+    }
+
+    fn render_field(&self, theme: &Theme) -> Vec<Line<'static>> {
+        let (cols, rows) = ((W / 2) as usize, (H / 4) as usize);
+        let mut grid: Vec<Vec<(char, Style)>> = vec![vec![(' ', Style::default().bg(theme.ink(FIELD_BG))); cols]; rows];
+        let mut tint = |x0: i32, y0: i32, x1: i32, y1: i32, bg: Color| {
+            for row in grid.iter_mut().take(((y1 + 3) / 4) as usize).skip((y0 / 4) as usize) {
+                for cell in row.iter_mut().take(((x1 + 1) / 2) as usize).skip((x0 / 2) as usize) {
+                    cell.1 = cell.1.bg(bg);
+                }
+            }
+        };
+        if let Some(c) = self.current() {
+            let (x0, y0, x1, y1) = c.zone;
+            if self.editing() { tint(x0, y0, x1, y1, theme.ink(ZONE_BG)); }
+            if let Goal::Reach(x0, y0, x1, y1) = c.goal { tint(x0, y0, x1, y1, theme.ink(TARGET_BG)); }
+        }
+
+        let mut live = BrailleCanvas::new(cols, rows);
+        let mut fixed = BrailleCanvas::new(cols, rows);
+        for y in 0..H {
+            for x in 0..W {
+                let i = (y * W + x) as usize;
+                if !self.cells[i] { continue; }
+                // Challenge cells stand out until the run starts
+                if self.fixed[i] && self.editing() { fixed.set(x, y) } else { live.set(x, y) }
+            }
+        }
+        let mut ghost = BrailleCanvas::new(cols, rows);
+        if let (Some(p), true) = (self.pattern, self.editing()) {
+            for (x, y) in self.patterns[p].rotated(self.turns) {
+                ghost.set(self.cursor.0 + x, self.cursor.1 + y);
+            }
+        } else if self.editing() {
+            ghost.set(self.cursor.0, self.cursor.1);
+        }
+        ghost.mask(&live);
+        ghost.mask(&fixed);
+        ghost.draw(&mut grid, theme.paint(Color::Rgb(90, 140, 200), Role::Blue), None, false);
+        fixed.draw(&mut grid, theme.paint(Color::Rgb(170, 170, 180), Role::Text), None, true);
+        live.draw(&mut grid, theme.ink(ACCENT), None, true);
+
+        if self.editing() {
+            let (cx, cy) = ((self.cursor.0 / 2) as usize, (self.cursor.1 / 4) as usize);
+            if let Some(cell) = grid.get_mut(cy).and_then(|row| row.get_mut(cx)) {
+                cell.1 = cell.1.bg(theme.ink(CURSOR_BG));
+            }
+        }
+        grid.into_iter()
+            .map(|row| Line::from(row.into_iter().map(|(ch, style)| Span::styled(String::from(ch), style)).collect::<Vec<_>>()))
+            .collect()
+    }
+
+    fn render_side(&self, theme: &Theme) -> Vec<Line<'static>> {
+        let head = |s: &str| Line::from(Span::styled(s.to_string(), Style::default().fg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD)));
+        let text = |s: String| Line::from(Span::styled(s, Style::default().fg(theme.paint(Color::Gray, Role::Dim))));
+        let mut lines = vec![head("STAMP")];
+        lines.push(match self.pattern {
+            Some(p) => text(format!("{} ↻{}", self.patterns[p].name, self.turns as u32 * 90)),
+            None => text("Single cell".to_string()),
+        });
+        lines.push(Line::default());
+        match self.current() {
+            None => {
+                lines.push(head("SANDBOX"));
+                lines.push(text(format!("Challenges {}/{}", self.challenges_cleared(), CHALLENGES.len())));
+                lines.push(text("M to try them".to_string()));
+            }
+            Some(c) => {
+                let ci = self.challenge.unwrap_or(0);
+                lines.push(head(&format!("CHALLENGE {}: {}", ci + 1, c.name.to_uppercase())));
+                lines.push(text(c.brief.to_string()));
+                let used = if self.editing() { self.used() } else { self.start.iter().zip(&self.fixed).filter(|(&c, &f)| c && !f).count() as u32 };
+                lines.push(text(format!("Cells: {}/{}", used, c.budget)));
+                lines.push(text(format!("By generation {}", c.limit)));
+                if self.cleared[ci] {
+                    lines.push(Line::from(Span::styled("✓ Cleared", Style::default().fg(theme.paint(Color::Green, Role::Green)))));
+                }
+            }
+        }
+        lines
+    }
+}
+
+pub const INFO: GameInfo = GameInfo {
+    name: "Life",
+    icon: "🧫",
+    author: "RustCade",
+    blurb: "Conway's Game of Life,\nwith challenges",
+    description: "Draw and stamp patterns, run Conway's Game of Life, and solve challenges from a few cells",
+    controls: &[
+        ("←↑↓→", "Move cursor (Shift: 8)"),
+        ("Space", "Place cell or stamp"),
+        ("[ / ]", "Pick a stamp"),
+        ("O", "Turn the stamp"),
+        ("Enter / N", "Run or stop / step"),
+        ("+ / -", "Speed"),
+        ("R / C", "Rewind / clear"),
+        ("M", "Sandbox or challenges"),
+    ],
+    tags: &["simulation", "puzzle", "sandbox"],
+    color: ACCENT,
+    border_color: Ink(Color::Rgb(40, 110, 60), Role::Green),
+};
+
+impl Game for LifeGame {
+    fn info(&self) -> &'static GameInfo {
+        &INFO
+    }
+
+    fn update(&mut self) {
+        if self.paused || self.countdown.tick() { return; }
+        if let Some((_, ticks)) = &mut self.notice {
+            *ticks = ticks.saturating_sub(1);
+            if *ticks == 0 { self.notice = None; }
+        }
+        if !self.running { return; }
+        self.clock += 1;
+        if self.clock >= 60 / SPEEDS[self.speed] {
+            self.clock = 0;
+            self.step();
+        }
+    }
+
+    fn handle_input(&mut self, key: KeyEvent) {
+        let far = if key.modifiers.contains(KeyModifiers::SHIFT) { 8 } else { 1 };
+        match key.code {
+            KeyCode::Char('p') | KeyCode::Char('P') if !self.game_over => {
+                self.countdown.toggle_pause(&mut self.paused);
+            }
+            _ if self.game_over => {
+                if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+                    self.reset();
+                }
+            }
+            _ if self.paused || self.countdown.is_running() => {}
+            KeyCode::Up => self.move_cursor(0, -far),
+            KeyCode::Down => self.move_cursor(0, far),
+            KeyCode::Left => self.move_cursor(-far, 0),
+            KeyCode::Right => self.move_cursor(far, 0),
+            KeyCode::Char(' ') => self.place(),
+            KeyCode::Enter => match self.outcome {
+                // On to the next challenge, or back to edit after a miss
+                Some(Outcome::Won) => self.next_mode(),
+                Some(Outcome::Failed) => self.rewind(),
+                None => self.toggle_run(),
+            },
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Char('.') => {
+                self.running = false;
+                if self.outcome.is_none() { self.step(); }
+            }
+            KeyCode::Char('[') => self.cycle_pattern(false),
+            KeyCode::Char(']') => self.cycle_pattern(true),
+            KeyCode::Char('o') | KeyCode::Char('O') => self.turns = (self.turns + 1) % 4,
+            KeyCode::Char('+') | KeyCode::Char('=') => self.speed = (self.speed + 1).min(SPEEDS.len() - 1),
+            KeyCode::Char('-') => self.speed = self.speed.saturating_sub(1),
+            KeyCode::Char('r') | KeyCode::Char('R') | KeyCode::Backspace => self.rewind(),
+            KeyCode::Char('c') | KeyCode::Char('C') => self.clear(),
+            KeyCode::Char('m') | KeyCode::Char('M') => self.next_mode(),
+            _ => {}
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = self.title_bar(theme);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(6), Constraint::Length(1)])
+            .split(inner);
+
+        let sep = || Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint)));
+        let state = if self.running { "▶ Running" } else { "■ Stopped" };
+        let mut status = vec![
+            Span::styled(" 🧫 ", Style::default()),
+            Span::styled(format!("{} ", state), Style::default().fg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD)),
+            sep(),
+            Span::styled(format!("Gen {} ", self.generation), Style::default().fg(theme.paint(Color::White, Role::Bright))),
+            sep(),
+            Span::styled(format!("Pop {} ", self.population()), Style::default().fg(theme.paint(Color::White, Role::Bright))),
+            sep(),
+            Span::styled(format!("{}/s ", SPEEDS[self.speed]), Style::default().fg(theme.paint(Color::Gray, Role::Dim))),
+            sep(),
+            Span::styled(format!("Score: {} ", self.score), Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD)),
+            sep(),
+            Span::styled(format!("({}, {}) ", self.cursor.0, self.cursor.1), Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+        ];
+        if let Some((text, _)) = &self.notice {
+            status.push(sep());
+            status.push(Span::styled(text.clone(), Style::default().fg(theme.paint(Color::Cyan, Role::Cyan))));
+        }
+        frame.render_widget(Paragraph::new(Line::from(status)), chunks[0]);
+
+        let field = chunks[1];
+        let (fw, fh) = ((W / 2) as u16 + 2, (H / 4) as u16 + 2);
+        let side_w = 30u16;
+        let field_area = Rect::new(
+            field.x + field.width.saturating_sub(fw + side_w + 2) / 2,
+            field.y + field.height.saturating_sub(fh) / 2,
+            fw.min(field.width),
+            fh.min(field.height),
+        );
+        let border = Block::default().borders(Borders::ALL).border_type(BorderType::Rounded).border_style(Style::default().fg(theme.paint(Color::Rgb(40, 90, 50), Role::Green)));
+        let field_inner = border.inner(field_area);
+        frame.render_widget(border, field_area);
+        frame.render_widget(Paragraph::new(self.render_field(theme)), field_inner);
+        let side = Rect::new(field_area.right() + 2, field_area.y + 1, side_w.min(field.right().saturating_sub(field_area.right() + 2)), field_area.height);
+        frame.render_widget(Paragraph::new(self.render_side(theme)).wrap(Wrap { trim: true }), side);
+        self.countdown.render(frame, field, theme);
+
+        let footer = if self.game_over {
+            hud::outcome("🏆 ALL CHALLENGES CLEARED!", theme.paint(Color::Green, Role::Green), &format!("Score {} │ ENTER to start over, Esc for menu", self.score), theme)
+        } else if self.paused {
+            hud::pause_overlay(frame, field, theme);
+            hud::paused(theme)
+        } else if self.outcome == Some(Outcome::Won) {
+            hud::outcome("✓ CHALLENGE CLEARED!", theme.paint(Color::Green, Role::Green), &format!("Generation {} │ ENTER for the next one", self.generation), theme)
+        } else if self.outcome == Some(Outcome::Failed) {
+            hud::outcome("✗ NOT THIS TIME", theme.paint(Color::Rgb(255, 120, 90), Role::Red), "ENTER to rewind and try again", theme)
+        } else if self.editing() {
+            hud::legend(None, &[("Space", "Place"), ("[ ]", "Stamp"), ("O", "Turn"), ("Enter", "Run"), ("N", "Step"), ("M", "Mode")], theme)
+        } else {
+            hud::legend(None, &[("Enter", "Run/stop"), ("N", "Step"), ("+ -", "Speed"), ("R", "Rewind")], theme)
+        };
+        frame.render_widget(Paragraph::new(footer), chunks[2]);
+    }
+
+    fn get_score(&self) -> u32 { self.score }
+    fn is_game_over(&self) -> bool { self.game_over }
+    fn is_paused(&self) -> bool { self.paused }
+
+    fn script_state(&self) -> Vec<(&'static str, f64)> {
+        vec![
+            ("generation", self.generation as f64),
+            ("population", self.population() as f64),
+            ("running", self.running as u8 as f64),
+            ("cursor_x", self.cursor.0 as f64),
+            ("cursor_y", self.cursor.1 as f64),
+            ("challenge", self.challenge.map_or(-1.0, |c| c as f64)),
+            ("cleared", self.challenges_cleared() as f64),
+        ]
+    }
+
+    fn serialize_state(&self) -> Option<Vec<u8>> {
+        if self.game_over { return None; }
+        let pack = |cells: &[bool]| cells.chunks(8).map(|c| c.iter().enumerate().fold(0u8, |b, (i, &on)| b | (on as u8) << i)).collect::<Vec<u8>>();
+        let mut w = StateWriter::new();
+        w.u32(self.score);
+        w.u32(self.high_score);
+        w.u8(self.challenge.map_or(0, |c| c as u8 + 1));
+        w.bytes(&self.cleared.map(u8::from));
+        w.bytes(&pack(&self.start));
+        w.bytes(&pack(&self.cells));
+        w.u32(self.generation);
+        w.u8(match self.outcome { None => 0, Some(Outcome::Won) => 1, Some(Outcome::Failed) => 2 });
+        w.u32(self.cursor.0 as u32);
+        w.u32(self.cursor.1 as u32);
+        w.u8(self.speed as u8);
+        Some(w.finish())
+    }
+
+    fn restore_state(&mut self, data: &[u8]) -> bool {
+        let unpack = |bytes: &[u8]| -> Option<Vec<bool>> {
+            if bytes.len() * 8 < (W * H) as usize { return None; }
+            Some((0..(W * H) as usize).map(|i| bytes[i / 8] >> (i % 8) & 1 == 1).collect())
+        };
+        let mut r = StateReader::new(data);
+        let (Some(score), Some(high_score), Some(mode), Some(cleared), Some(start), Some(cells)) =
+            (r.u32(), r.u32(), r.u8(), r.bytes(), r.bytes(), r.bytes())
+        else { return false };
+        let (Some(generation), Some(outcome), Some(cx), Some(cy), Some(speed)) = (r.u32(), r.u8(), r.u32(), r.u32(), r.u8())
+        else { return false };
+        let (Some(start), Some(cells)) = (unpack(&start), unpack(&cells)) else { return false };
+        if mode as usize > CHALLENGES.len() || cleared.len() != CHALLENGES.len() { return false; }
+
+        let mut restored = LifeGame::new();
+        restored.load(mode.checked_sub(1).map(usize::from));
+        restored.start = start;
+        restored.cells = cells;
+        restored.generation = generation;
+        restored.outcome = match outcome { 1 => Some(Outcome::Won), 2 => Some(Outcome::Failed), _ => None };
+        restored.cursor = ((cx as i32).clamp(0, W - 1), (cy as i32).clamp(0, H - 1));
+        restored.speed = (speed as usize).min(SPEEDS.len() - 1);
+        for (slot, &c) in restored.cleared.iter_mut().zip(&cleared) {
+            *slot = c != 0;
+        }
+        restored.score = score;
+        restored.high_score = self.high_score.max(high_score);
+        restored.paused = true;
+        *self = restored;
+        true
+    }
+
+    fn reset(&mut self) {
+        let high_score = self.high_score;
+        *self = LifeGame::new();
+        self.high_score = high_score;
+    }
+}
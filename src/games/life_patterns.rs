@@ -0,0 +1,128 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::scores::data_path;
+
+const FILE_EXT: &str = "cells";
+/// Largest pattern a file can describe, in cells each way
+const MAX_SIZE: i32 = 96;
+
+/// A Life pattern for the stamp library, in the plaintext `.cells`
+/// format most pattern collections offer:
+///
+/// ```text
+/// !Name: Glider
+/// ! lines starting with ! are comments
+/// .O.
+/// ..O
+/// OOO
+/// ```
+///
+/// `O` (or `*`) is a live cell and anything else a dead one.
+#[derive(Clone)]
+pub struct LifePattern {
+    pub name: String,
+    /// Live cells as (x, y) from the top-left
+    pub cells: Vec<(i32, i32)>,
+    pub width: i32,
+    pub height: i32,
+}
+
+const BUILTIN: [(&str, &str); 13] = [
+    ("Glider", ".O.\n..O\nOOO"),
+    ("Lightweight spaceship", ".O..O\nO....\nO...O\nOOOO."),
+    ("Blinker", "OOO"),
+    ("Toad", ".OOO\nOOO."),
+    ("Beacon", "OO..\nOO..\n..OO\n..OO"),
+    ("Pulsar", "\
+..OOO...OOO..
+.............
+O....O.O....O
+O....O.O....O
+O....O.O....O
+..OOO...OOO..
+.............
+..OOO...OOO..
+O....O.O....O
+O....O.O....O
+O....O.O....O
+.............
+..OOO...OOO.."),
+    ("Pentadecathlon", "..O....O..\nOO.OOOO.OO\n..O....O.."),
+    ("R-pentomino", ".OO\nOO.\n.O."),
+    ("Acorn", ".O.....\n...O...\nOO..OOO"),
+    ("Diehard", "......O.\nOO......\n.O...OOO"),
+    ("Gosper glider gun", "\
+........................O...........
+......................O.O...........
+............OO......OO............OO
+...........O...O....OO............OO
+OO........O.....O...OO..............
+OO........O...O.OO....O.O...........
+..........O.....O.......O...........
+...........O...O....................
+............OO......................"),
+    ("Block", "OO\nOO"),
+    ("Beehive", ".OO.\nO..O\n.OO."),
+];
+
+impl LifePattern {
+    /// Read `.cells` text; None if it has no live cells or is too big.
+    pub fn parse(name: &str, text: &str) -> Option<LifePattern> {
+        let mut name = name.to_string();
+        let mut cells = Vec::new();
+        let mut y = 0;
+        for line in text.lines() {
+            if let Some(comment) = line.strip_prefix('!') {
+                if let Some(n) = comment.strip_prefix("Name:") {
+                    name = n.trim().to_string();
+                }
+                continue;
+            }
+            for (x, c) in line.trim_end().chars().enumerate() {
+                if matches!(c, 'O' | '*') {
+                    cells.push((x as i32, y));
+                }
+            }
+            y += 1;
+        }
+        let width = cells.iter().map(|c| c.0 + 1).max()?;
+        let height = cells.iter().map(|c| c.1 + 1).max()?;
+        if width > MAX_SIZE || height > MAX_SIZE { return None; }
+        Some(LifePattern { name, cells, width, height })
+    }
+
+    /// Live cells turned a quarter clockwise `quarters` times, still
+    /// measured from the top-left.
+    pub fn rotated(&self, quarters: u8) -> Vec<(i32, i32)> {
+        let (w, h) = (self.width, self.height);
+        self.cells.iter().map(|&(x, y)| match quarters % 4 {
+            0 => (x, y),
+            1 => (h - 1 - y, x),
+            2 => (w - 1 - x, h - 1 - y),
+            _ => (y, w - 1 - x),
+        }).collect()
+    }
+
+    /// The built-in patterns, then any from the `life` folder sorted by
+    /// file name.
+    pub fn load_all() -> Vec<LifePattern> {
+        let mut patterns: Vec<LifePattern> = BUILTIN.iter().filter_map(|(name, text)| LifePattern::parse(name, text)).collect();
+        let Ok(entries) = fs::read_dir(pattern_dir()) else { return patterns };
+        let mut files: Vec<PathBuf> = entries.flatten()
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|e| e == FILE_EXT))
+            .collect();
+        files.sort();
+        patterns.extend(files.iter().filter_map(|p| LifePattern::parse(&file_stem(p), &fs::read_to_string(p).ok()?)));
+        patterns
+    }
+}
+
+fn file_stem(path: &Path) -> String {
+    path.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default()
+}
+
+fn pattern_dir() -> PathBuf {
+    data_path("life")
+}
@@ -0,0 +1,545 @@
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use rand::seq::SliceRandom;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::games::tile_grid::{CellArt, TileGrid};
+use crate::games::{Game, GameInfo};
+use crate::rng::GameRng;
+use crate::session::{StateReader, StateWriter};
+use crate::ui::hud::{self, Countdown};
+use crate::ui::theme::{Ink, Role, Theme};
+
+/// Terminal (columns, rows) each square is drawn in, and the narrower
+/// cell used when the board doesn't fit
+const CELL: (usize, usize) = (3, 1);
+const NARROW_CELL: (usize, usize) = (2, 1);
+/// Scoring: `mult × SCORE_BASE / (seconds + 10)`, so every second counts
+/// but a slow clear still scores
+const SCORE_BASE: u32 = 10_000;
+const ACCENT: Ink = Ink(Color::Rgb(150, 170, 200), Role::Blue);
+const HIDDEN_BG: [Ink; 2] = [Ink(Color::Rgb(70, 78, 100), Role::Faint), Ink(Color::Rgb(62, 70, 92), Role::Faint)];
+const OPEN_BG: Ink = Ink(Color::Rgb(22, 22, 30), Role::Background);
+const CURSOR_BG: Ink = Ink(Color::Rgb(200, 170, 60), Role::Yellow);
+const FLAG_COLOR: Ink = Ink(Color::Rgb(255, 80, 70), Role::Red);
+/// Classic colours for 1 to 8 neighbouring mines
+const NUMBER_COLORS: [Ink; 8] = [
+    Ink(Color::Rgb(90, 140, 255), Role::Blue),
+    Ink(Color::Rgb(90, 200, 90), Role::Green),
+    Ink(Color::Rgb(255, 90, 90), Role::Red),
+    Ink(Color::Rgb(170, 110, 255), Role::Violet),
+    Ink(Color::Rgb(220, 120, 60), Role::Orange),
+    Ink(Color::Rgb(60, 200, 200), Role::Cyan),
+    Ink(Color::Rgb(230, 230, 230), Role::Bright),
+    Ink(Color::Rgb(150, 150, 150), Role::Dim),
+];
+
+#[derive(Clone, Copy, PartialEq)]
+enum Size {
+    Beginner,
+    Intermediate,
+    Expert,
+}
+
+impl Size {
+    const ALL: [Size; 3] = [Size::Beginner, Size::Intermediate, Size::Expert];
+
+    fn name(self) -> &'static str {
+        match self {
+            Size::Beginner => "Beginner",
+            Size::Intermediate => "Intermediate",
+            Size::Expert => "Expert",
+        }
+    }
+
+    /// (width, height, mines)
+    fn board(self) -> (usize, usize, usize) {
+        match self {
+            Size::Beginner => (9, 9, 10),
+            Size::Intermediate => (16, 16, 40),
+            Size::Expert => (30, 16, 99),
+        }
+    }
+
+    fn multiplier(self) -> u32 {
+        match self {
+            Size::Beginner => 1,
+            Size::Intermediate => 4,
+            Size::Expert => 10,
+        }
+    }
+
+    fn next(self) -> Size {
+        Size::ALL[(self as usize + 1) % Size::ALL.len()]
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mark {
+    Hidden,
+    Flagged,
+    Open,
+}
+
+#[derive(Clone, Copy)]
+struct Square {
+    mine: bool,
+    mark: Mark,
+    /// Mines in the eight squares around it
+    near: u8,
+}
+
+const BLANK: Square = Square { mine: false, mark: Mark::Hidden, near: 0 };
+
+pub struct MinesweeperGame {
+    grid: TileGrid<Square>,
+    size: Size,
+    /// Mines go down on the first reveal, clear of the square opened
+    placed: bool,
+    /// Ticks since the first reveal, stopped when the board's cleared or lost
+    ticks: u32,
+    score: u32,
+    high_score: u32,
+    won: bool,
+    game_over: bool,
+    /// The mine that went off
+    exploded: Option<(usize, usize)>,
+    paused: bool,
+    countdown: Countdown,
+    /// Practice: a mine you open is flagged for you instead of ending the game
+    practice: bool,
+    /// Mines opened under practice
+    saves: u32,
+    /// Top-left of the drawn grid and the cell size it was drawn at, for mouse clicks
+    grid_origin: Position,
+    cell: (usize, usize),
+    /// Where the mines go
+    rng: GameRng,
+}
+
+impl MinesweeperGame {
+    pub fn new() -> Self {
+        let mut game = Self {
+            grid: TileGrid::new(0, 0, BLANK),
+            size: Size::Beginner,
+            placed: false,
+            ticks: 0,
+            score: 0,
+            high_score: 0,
+            won: false,
+            game_over: false,
+            exploded: None,
+            paused: false,
+            countdown: Countdown::default(),
+            practice: false,
+            saves: 0,
+            grid_origin: Position::default(),
+            cell: CELL,
+            rng: GameRng::from_entropy(),
+        };
+        game.new_board(Size::Beginner);
+        game
+    }
+
+    fn new_board(&mut self, size: Size) {
+        let (w, h, _) = size.board();
+        self.size = size;
+        self.grid = TileGrid::new(w, h, BLANK);
+        self.grid.cursor = (w / 2, h / 2);
+        self.placed = false;
+        self.ticks = 0;
+        self.score = 0;
+        self.won = false;
+        self.game_over = false;
+        self.exploded = None;
+        self.saves = 0;
+    }
+
+    /// Whole seconds on the clock, rounded up, for the score tables.
+    pub fn seconds(&self) -> u32 {
+        self.ticks.div_ceil(60)
+    }
+
+    /// Bit 0: intermediate board, bit 1: expert board (see `scores::flag_labels`).
+    pub fn modifier_flags(&self) -> u32 {
+        match self.size {
+            Size::Beginner => 0,
+            Size::Intermediate => 1,
+            Size::Expert => 2,
+        }
+    }
+
+    fn mines(&self) -> usize {
+        self.size.board().2
+    }
+
+    fn flags(&self) -> usize {
+        self.grid.iter().filter(|(_, _, s)| s.mark == Mark::Flagged).count()
+    }
+
+    /// The up to eight squares around (x, y).
+    fn around(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        let (w, h) = (self.grid.width() as i32, self.grid.height() as i32);
+        let (x, y) = (x as i32, y as i32);
+        (-1..=1).flat_map(move |dy| (-1..=1).map(move |dx| (x + dx, y + dy)))
+            .filter(move |&(nx, ny)| (nx, ny) != (x, y) && nx >= 0 && ny >= 0 && nx < w && ny < h)
+            .map(|(nx, ny)| (nx as usize, ny as usize))
+    }
+
+    /// Lay the mines anywhere but the 3×3 around the first square opened,
+    /// so the first reveal is always safe and always opens some ground.
+    fn place_mines(&mut self, x: usize, y: usize) {
+        let mut spots: Vec<(usize, usize)> = self.grid.iter()
+            .map(|(sx, sy, _)| (sx, sy))
+            .filter(|&(sx, sy)| sx.abs_diff(x) > 1 || sy.abs_diff(y) > 1)
+            .collect();
+        spots.shuffle(&mut self.rng);
+        for &(sx, sy) in spots.iter().take(self.mines()) {
+            if let Some(s) = self.grid.get_mut(sx, sy) { s.mine = true; }
+        }
+        self.count_neighbours();
+        self.placed = true;
+    }
+
+    fn count_neighbours(&mut self) {
+        let counts: Vec<(usize, usize, u8)> = self.grid.iter()
+            .map(|(x, y, _)| {
+                let near = self.around(x, y).filter(|&(nx, ny)| self.grid.get(nx, ny).is_some_and(|s| s.mine)).count();
+                (x, y, near as u8)
+            })
+            .collect();
+        for (x, y, near) in counts {
+            if let Some(s) = self.grid.get_mut(x, y) { s.near = near; }
+        }
+    }
+
+    /// Open (x, y); an empty square opens everything around it too.
+    fn reveal(&mut self, x: usize, y: usize) {
+        if self.game_over || self.won { return; }
+        if !self.placed { self.place_mines(x, y); }
+        let Some(&square) = self.grid.get(x, y) else { return };
+        if square.mark != Mark::Hidden { return; }
+        if square.mine {
+            if self.practice {
+                self.saves += 1;
+                if let Some(s) = self.grid.get_mut(x, y) { s.mark = Mark::Flagged; }
+                return;
+            }
+            self.exploded = Some((x, y));
+            self.lose();
+            return;
+        }
+        let mut stack = vec![(x, y)];
+        while let Some((cx, cy)) = stack.pop() {
+            let Some(s) = self.grid.get_mut(cx, cy) else { continue };
+            if s.mark != Mark::Hidden || s.mine { continue; }
+            s.mark = Mark::Open;
+            if s.near == 0 {
+                stack.extend(self.around(cx, cy));
+            }
+        }
+        self.check_cleared();
+    }
+
+    /// On an open number with as many flags around it as mines, open the
+    /// rest of its neighbours; a wrong flag sets off the mine it hid.
+    fn chord(&mut self, x: usize, y: usize) {
+        let Some(&square) = self.grid.get(x, y) else { return };
+        if square.mark != Mark::Open || square.near == 0 { return; }
+        let flagged = self.around(x, y).filter(|&(nx, ny)| self.grid.get(nx, ny).is_some_and(|s| s.mark == Mark::Flagged)).count();
+        if flagged != square.near as usize { return; }
+        let hidden: Vec<(usize, usize)> = self.around(x, y).collect();
+        for (nx, ny) in hidden {
+            self.reveal(nx, ny);
+        }
+    }
+
+    /// Space, Enter and left-click: open a hidden square or chord an open one.
+    fn open(&mut self, x: usize, y: usize) {
+        match self.grid.get(x, y).map(|s| s.mark) {
+            Some(Mark::Hidden) => self.reveal(x, y),
+            Some(Mark::Open) => self.chord(x, y),
+            _ => {}
+        }
+    }
+
+    fn toggle_flag(&mut self, x: usize, y: usize) {
+        if !self.placed || self.game_over || self.won { return; }
+        if let Some(s) = self.grid.get_mut(x, y) {
+            s.mark = match s.mark {
+                Mark::Hidden => Mark::Flagged,
+                Mark::Flagged => Mark::Hidden,
+                Mark::Open => Mark::Open,
+            };
+        }
+    }
+
+    fn check_cleared(&mut self) {
+        if self.grid.iter().any(|(_, _, s)| !s.mine && s.mark != Mark::Open) { return; }
+        self.won = true;
+        for (_, _, s) in self.grid.iter_mut() {
+            if s.mine { s.mark = Mark::Flagged; }
+        }
+        // Practice clears don't score
+        if !self.practice {
+            self.score = self.size.multiplier() * SCORE_BASE / (self.seconds() + 10);
+            self.high_score = self.high_score.max(self.score);
+        }
+        self.game_over = true;
+    }
+
+    fn lose(&mut self) {
+        self.game_over = true;
+        self.high_score = self.high_score.max(self.score);
+    }
+
+    fn draw_square(&self, x: usize, y: usize, square: Square, art: &mut CellArt, theme: &Theme) {
+        let hidden_bg = HIDDEN_BG[(x + y) % 2];
+        let ended = self.game_over && !self.won;
+        let (glyph, style) = match square.mark {
+            Mark::Flagged if ended && !square.mine => ('✗', Style::default().fg(theme.ink(FLAG_COLOR)).bg(theme.ink(OPEN_BG)).add_modifier(Modifier::BOLD)),
+            Mark::Flagged => ('⚑', Style::default().fg(theme.ink(FLAG_COLOR)).bg(theme.ink(hidden_bg)).add_modifier(Modifier::BOLD)),
+            Mark::Hidden if ended && square.mine => {
+                let bg = if self.exploded == Some((x, y)) { FLAG_COLOR } else { OPEN_BG };
+                ('●', Style::default().fg(theme.paint(Color::Rgb(230, 230, 230), Role::Bright)).bg(theme.ink(bg)).add_modifier(Modifier::BOLD))
+            }
+            Mark::Hidden => (' ', Style::default().bg(theme.ink(hidden_bg))),
+            Mark::Open if square.near == 0 => ('·', Style::default().fg(theme.paint(Color::Rgb(50, 50, 62), Role::Panel)).bg(theme.ink(OPEN_BG))),
+            Mark::Open => (
+                (b'0' + square.near) as char,
+                Style::default().fg(theme.ink(NUMBER_COLORS[square.near as usize - 1])).bg(theme.ink(OPEN_BG)).add_modifier(Modifier::BOLD),
+            ),
+        };
+        for cell in art.iter_mut().flatten() {
+            *cell = (' ', style);
+        }
+        let mid = art[0].len() / 2;
+        art[0][mid].0 = glyph;
+        if self.grid.cursor == (x, y) && !self.game_over {
+            art.iter_mut().flatten().for_each(|(_, style)| *style = style.bg(theme.ink(CURSOR_BG)));
+            if glyph == ' ' || glyph == '·' {
+                art[0][mid] = ('·', Style::default().fg(theme.paint(Color::Black, Role::Background)).bg(theme.ink(CURSOR_BG)));
+            }
+        }
+    }
+}
+
+pub const INFO: GameInfo = GameInfo {
+    name: "Minesweeper",
+    icon: "💣",
+    author: "RustCade",
+    blurb: "Clear the field,\nflag every mine",
+    description: "Open every square that isn't a mine, reading the numbers for where they hide",
+    controls: &[
+        ("←↑↓→", "Move cursor"),
+        ("Space / Enter", "Open (on a number: open around it)"),
+        ("F", "Flag"),
+        ("Click", "Open (right-click flag)"),
+        ("D", "Board size (before the first square)"),
+        ("R", "Restart"),
+        ("P", "Pause"),
+    ],
+    tags: &["puzzle", "logic", "grid", "mouse"],
+    color: ACCENT,
+    border_color: Ink(Color::Rgb(60, 70, 100), Role::Faint),
+};
+
+impl Game for MinesweeperGame {
+    fn info(&self) -> &'static GameInfo {
+        &INFO
+    }
+
+    fn update(&mut self) {
+        if self.paused || self.countdown.tick() { return; }
+        if self.placed && !self.game_over {
+            self.ticks += 1;
+        }
+    }
+
+    fn handle_input(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('r') | KeyCode::Char('R') => self.reset(),
+            KeyCode::Char('p') | KeyCode::Char('P') if self.placed && !self.game_over => {
+                self.countdown.toggle_pause(&mut self.paused);
+            }
+            _ if self.game_over => {
+                if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+                    self.reset();
+                }
+            }
+            _ if self.paused || self.countdown.is_running() => {}
+            KeyCode::Char('d') | KeyCode::Char('D') if !self.placed => self.new_board(self.size.next()),
+            KeyCode::Up | KeyCode::Down | KeyCode::Left | KeyCode::Right => {
+                self.grid.cursor_key(key.code, false);
+            }
+            KeyCode::Char(' ') | KeyCode::Enter => {
+                let (x, y) = self.grid.cursor;
+                self.open(x, y);
+            }
+            KeyCode::Char('f') | KeyCode::Char('F') => {
+                let (x, y) = self.grid.cursor;
+                self.toggle_flag(x, y);
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent) {
+        let Some((x, y)) = self.grid.cell_at(self.grid_origin, self.cell, event.column, event.row) else { return };
+        if self.game_over || self.paused || self.countdown.is_running() { return; }
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => self.open(x, y),
+            MouseEventKind::Down(MouseButton::Right) => self.toggle_flag(x, y),
+            MouseEventKind::Down(MouseButton::Middle) => self.chord(x, y),
+            _ => return,
+        }
+        self.grid.cursor = (x, y);
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = self.title_bar(theme);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(6), Constraint::Length(1)])
+            .split(inner);
+
+        let sep = || Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint)));
+        let (w, h, mines) = self.size.board();
+        let left = mines as i32 - self.flags() as i32;
+        let mut status = vec![
+            Span::styled(" 💣 ", Style::default()),
+            Span::styled(format!("{} {}×{} ", self.size.name(), w, h), Style::default().fg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD)),
+            sep(),
+            Span::styled(format!("Score: {} ", self.score), Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD)),
+            sep(),
+            Span::styled(format!("Mines: {} ", left), Style::default().fg(theme.ink(FLAG_COLOR))),
+            sep(),
+            Span::styled(format!("Time: {}.{}s ", self.ticks / 60, self.ticks % 60 / 6), Style::default().fg(theme.paint(Color::Cyan, Role::Cyan))),
+        ];
+        if self.practice {
+            status.push(sep());
+            status.push(Span::styled(format!("Practice: {} caught ", self.saves), Style::default().fg(theme.paint(Color::Green, Role::Green))));
+        }
+        frame.render_widget(Paragraph::new(Line::from(status)), chunks[0]);
+
+        let field = chunks[1];
+        self.cell = if w * CELL.0 <= field.width as usize { CELL } else { NARROW_CELL };
+        let (gw, gh) = ((w * self.cell.0) as u16, (h * self.cell.1) as u16);
+        let grid_area = Rect::new(
+            field.x + field.width.saturating_sub(gw) / 2,
+            field.y + field.height.saturating_sub(gh) / 2,
+            gw.min(field.width),
+            gh.min(field.height),
+        );
+        self.grid_origin = grid_area.as_position();
+        let lines = self.grid.render(self.cell, Style::default(), |x, y, square, art| self.draw_square(x, y, *square, art, theme));
+        frame.render_widget(Paragraph::new(lines), grid_area);
+        self.countdown.render(frame, field, theme);
+
+        let footer = if self.won {
+            let detail = if self.practice {
+                "Practice clear, not scored │ ENTER to play again".to_string()
+            } else {
+                format!("{} in {}s │ ENTER to play again", self.size.name(), self.seconds())
+            };
+            hud::outcome("🚩 FIELD CLEARED!", theme.paint(Color::Green, Role::Green), &detail, theme)
+        } else if self.game_over {
+            hud::game_over(&format!("Boom! {}s in │ ENTER to restart, Esc for menu", self.seconds()), theme)
+        } else if self.paused {
+            hud::pause_overlay(frame, field, theme);
+            hud::paused(theme)
+        } else if !self.placed {
+            hud::legend(Some("Open any square to start"), &[("←↑↓→", "Move"), ("Space", "Open"), ("F", "Flag"), ("D", "Board size")], theme)
+        } else {
+            hud::legend(None, &[("←↑↓→", "Move"), ("Space", "Open"), ("F", "Flag"), ("Click", "Open"), ("Right-click", "Flag")], theme)
+        };
+        frame.render_widget(Paragraph::new(footer), chunks[2]);
+    }
+
+    fn get_score(&self) -> u32 { self.score }
+    fn is_game_over(&self) -> bool { self.game_over }
+    fn is_paused(&self) -> bool { self.paused }
+    fn set_practice(&mut self, on: bool) { self.practice = on; }
+
+    fn reseed(&mut self, rng: GameRng) {
+        self.rng = rng;
+        self.reset();
+    }
+
+    fn script_state(&self) -> Vec<(&'static str, f64)> {
+        let opened = self.grid.iter().filter(|(_, _, s)| s.mark == Mark::Open).count();
+        vec![
+            ("size", self.size as usize as f64),
+            ("cursor_x", self.grid.cursor.0 as f64),
+            ("cursor_y", self.grid.cursor.1 as f64),
+            ("opened", opened as f64),
+            ("flags", self.flags() as f64),
+            ("mines", self.mines() as f64),
+            ("seconds", self.seconds() as f64),
+        ]
+    }
+
+    fn serialize_state(&self) -> Option<Vec<u8>> {
+        if self.game_over || !self.placed { return None; }
+        let mut w = StateWriter::new();
+        w.u8(self.size as u8);
+        w.u32(self.ticks);
+        w.u32(self.high_score);
+        w.u32(self.saves);
+        w.u32(self.grid.cursor.0 as u32);
+        w.u32(self.grid.cursor.1 as u32);
+        for (_, _, s) in self.grid.iter() {
+            w.u8(s.mine as u8 | (s.mark as u8) << 1);
+        }
+        Some(w.finish())
+    }
+
+    fn restore_state(&mut self, data: &[u8]) -> bool {
+        let mut r = StateReader::new(data);
+        let (Some(size), Some(ticks), Some(high_score), Some(saves), Some(cx), Some(cy)) =
+            (r.u8(), r.u32(), r.u32(), r.u32(), r.u32(), r.u32())
+        else { return false };
+        let Some(&size) = Size::ALL.get(size as usize) else { return false };
+        let mut restored = MinesweeperGame::new();
+        restored.new_board(size);
+        let (w, h) = (restored.grid.width(), restored.grid.height());
+        for i in 0..w * h {
+            let Some(b) = r.u8() else { return false };
+            let mark = match b >> 1 {
+                0 => Mark::Hidden,
+                1 => Mark::Flagged,
+                2 => Mark::Open,
+                _ => return false,
+            };
+            if let Some(s) = restored.grid.get_mut(i % w, i / w) {
+                *s = Square { mine: b & 1 != 0, mark, near: 0 };
+            }
+        }
+        restored.count_neighbours();
+        restored.placed = true;
+        restored.ticks = ticks;
+        restored.high_score = self.high_score.max(high_score);
+        restored.saves = saves;
+        restored.grid.cursor = ((cx as usize).min(w - 1), (cy as usize).min(h - 1));
+        restored.practice = self.practice;
+        restored.rng = self.rng.clone();
+        // Resume paused, with a countdown before the clock runs again
+        restored.paused = true;
+        *self = restored;
+        true
+    }
+
+    fn reset(&mut self) {
+        // A restart keeps the board size and the practice switch
+        let (high_score, size, practice) = (self.high_score, self.size, self.practice);
+        let rng = self.rng.clone();
+        *self = MinesweeperGame::new();
+        self.rng = rng;
+        self.new_board(size);
+        self.high_score = high_score;
+        self.practice = practice;
+    }
+}
@@ -1,20 +1,157 @@
 pub mod asteroids;
 pub mod beam;
+pub mod beam_dump;
+pub mod beam_challenge;
+pub mod beam_compare;
+pub mod beam_hint;
 pub mod booster;
+pub mod booster_console;
+pub mod booster_match;
+pub mod booster_palette;
+pub mod booster_report;
 pub mod breakout;
+pub mod breakout_levels;
+pub mod columns;
+pub mod delve;
 pub mod dino_run;
 pub mod frogger;
+pub mod frogger_skins;
+pub mod frogger_splits;
+pub mod gravity;
+pub mod invader_waves;
 pub mod jezzball;
+pub mod jezzball_puzzles;
+pub mod life;
+pub mod life_patterns;
+pub mod minesweeper;
+pub mod pong;
+pub mod racer;
+pub mod router;
+pub mod scope;
+pub mod scope_patterns;
 pub mod space_invaders;
+pub mod tile_grid;
+pub mod whack;
 
-use crossterm::event::KeyEvent;
+use crossterm::event::{KeyEvent, MouseEvent};
 use ratatui::prelude::*;
+use ratatui::widgets::Block;
+
+use crate::achievements::Badge;
+use crate::rng::GameRng;
+use crate::ui::hud;
+use crate::ui::theme::{Ink, Theme};
+
+/// Built-in games, one Home tile each.
+pub const GAME_COUNT: usize = 18;
+
+/// How a game describes itself to the arcade: its Home tile and controls
+/// card, what Home search matches, and the help overlay's header.
+pub struct GameInfo {
+    pub name: &'static str,
+    pub icon: &'static str,
+    pub author: &'static str,
+    /// Two short lines for the Home tile
+    pub blurb: &'static str,
+    /// One line for the controls card and help
+    pub description: &'static str,
+    /// (keys, action) pairs for the Home controls card
+    pub controls: &'static [(&'static str, &'static str)],
+    /// Lower-case genre tags, e.g. "physics", "arcade", "puzzle"
+    pub tags: &'static [&'static str],
+    /// Accent for the Home tile and the in-game title bar
+    pub color: Ink,
+    /// Home tile border
+    pub border_color: Ink,
+}
+
+impl GameInfo {
+    /// Case-insensitive match of a Home search against the name, tags,
+    /// author and description.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        query.is_empty()
+            || self.tags.iter().any(|t| t.starts_with(&query))
+            || [self.name, self.author, self.description].iter().any(|s| s.to_lowercase().contains(&query))
+    }
+}
 
 pub trait Game {
+    /// Name, controls and tags; built-in games return a `const`.
+    fn info(&self) -> &'static GameInfo;
+    /// The frame `render` draws in, the same for every game: see `hud::title_bar`.
+    fn title_bar(&self, theme: &Theme) -> Block<'static> {
+        let badge = if self.is_demo() {
+            Some("DEMO")
+        } else if self.is_game_over() {
+            Some("GAME OVER")
+        } else if self.is_paused() {
+            Some("PAUSED")
+        } else {
+            None
+        };
+        hud::title_bar(self.info(), self.get_score(), badge, theme)
+    }
     fn update(&mut self);
     fn handle_input(&mut self, key: KeyEvent);
-    fn render(&mut self, frame: &mut Frame, area: Rect);
+    /// Mouse clicks and wheel scrolls, in terminal cell coordinates.
+    fn handle_mouse(&mut self, _event: MouseEvent) {}
+    /// Draw into `area`, every colour picked through `theme`.
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme);
+    /// The terminal was resized, and once at startup; `area` is where the
+    /// game's tab draws it from now on. Games with a field sized to the
+    /// screen rescale it here rather than on the next `render`.
+    fn on_resize(&mut self, _area: Rect) {}
+    /// Start over drawing randomness from `rng`. The arcade hands each game
+    /// its own stream under `--seed`, so a run can be played again exactly.
+    fn reseed(&mut self, _rng: GameRng) {}
+    /// Render with `alpha` (0.0-1.0) of the next simulation step elapsed.
+    /// Games that keep previous positions can blend toward the current ones.
+    fn render_interpolated(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, _alpha: f32) {
+        self.render(frame, area, theme);
+    }
     fn reset(&mut self);
     fn get_score(&self) -> u32;
     fn is_game_over(&self) -> bool;
+    /// True while the game has a text prompt open; every key but Ctrl+C
+    /// then goes straight to it, Esc, q and ? included.
+    fn wants_text_input(&self) -> bool {
+        false
+    }
+    /// Named values for bot scripts and the control socket's `state` call.
+    fn script_state(&self) -> Vec<(&'static str, f64)> {
+        Vec::new()
+    }
+    /// True while an AI is playing an attract-mode demo; its scores are
+    /// never offered to the tables.
+    fn is_demo(&self) -> bool {
+        false
+    }
+    /// Reaction times in ms over the run, for games that time them; the
+    /// play stats keep them from each scored run.
+    fn reaction_times(&self) -> &[u32] {
+        &[]
+    }
+    /// Badges earned since the last call; the arcade keeps the new ones
+    /// and shows their lessons.
+    fn take_badges(&mut self) -> Vec<Badge> {
+        Vec::new()
+    }
+    /// Practice mode: the score won't be kept, so the game may show aids.
+    fn set_practice(&mut self, _on: bool) {}
+    /// Outline what the game's collision checks test against.
+    fn set_hitboxes(&mut self, _on: bool) {}
+    /// Paused games let the event loop drop to its idle poll rate.
+    fn is_paused(&self) -> bool {
+        false
+    }
+    /// Snapshot enough state to resume this game later (session autosave
+    /// and the Continue snapshot taken on exit).
+    fn serialize_state(&self) -> Option<Vec<u8>> {
+        None
+    }
+    /// Restore a `serialize_state` snapshot; returns false if it was rejected.
+    fn restore_state(&mut self, _data: &[u8]) -> bool {
+        false
+    }
 }
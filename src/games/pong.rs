@@ -0,0 +1,690 @@
+use std::collections::VecDeque;
+
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use rand::Rng;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::audio::{self, AudioEvent};
+use crate::games::{Game, GameInfo};
+use crate::rng::GameRng;
+use crate::session::{StateReader, StateWriter};
+use crate::ui::braille::BrailleCanvas;
+use crate::ui::fx;
+use crate::ui::hud::{self, Countdown};
+use crate::ui::theme::{Ink, Role, Theme};
+
+/// Court in play units; drawn at the largest scale that fits
+const COURT_W: f32 = 100.0;
+const COURT_H: f32 = 50.0;
+/// Paddle centres: the player on the left, the CPU on the right
+const PLAYER_X: f32 = 4.0;
+const CPU_X: f32 = COURT_W - 4.0;
+const PADDLE_H: f32 = 10.0;
+const PADDLE_W: f32 = 1.5;
+const BALL_R: f32 = 0.8;
+/// Ball speed in units a tick at the serve, and the most it reaches
+const SERVE_SPEED: f32 = 0.9;
+const MAX_SPEED: f32 = 2.0;
+/// Speed-up on every return
+const SPEED_UP: f32 = 1.04;
+/// Return angle off a paddle's very end, in radians
+const MAX_ANGLE: f32 = 1.0;
+/// Player paddle: distance per key press, and the speed it glides there at
+const KEY_STEP: f32 = 4.0;
+const PLAYER_SPEED: f32 = 1.5;
+/// Share of the paddle's speed passed straight on to the ball
+const ENGLISH: f32 = 0.25;
+/// Spin per unit of paddle speed; each tick it bends the ball's path
+const SPIN_GAIN: f32 = 0.008;
+const SPIN_DECAY: f32 = 0.99;
+const POINTS_TO_WIN: u32 = 11;
+/// Ticks the ball sits at the centre before it's served
+const SERVE_TICKS: u32 = 60;
+const CALLOUT_TICKS: u32 = 50;
+const TRAIL_LEN: usize = 8;
+/// 3×5 digits for the score, a row of bits each
+const FONT: [[u8; 5]; 10] = [
+    [7, 5, 5, 5, 7],
+    [1, 1, 1, 1, 1],
+    [7, 1, 7, 4, 7],
+    [7, 1, 7, 1, 7],
+    [5, 5, 7, 1, 1],
+    [7, 4, 7, 1, 7],
+    [7, 4, 7, 5, 7],
+    [7, 1, 1, 1, 1],
+    [7, 5, 7, 5, 7],
+    [7, 5, 7, 1, 7],
+];
+const ACCENT: Ink = Ink(Color::Rgb(230, 230, 240), Role::Bright);
+const COURT_LINE: Ink = Ink(Color::Rgb(90, 90, 110), Role::Faint);
+const DIGIT_COLOR: Ink = Ink(Color::Rgb(60, 60, 80), Role::Faint);
+const PLAYER_COLOR: Ink = Ink(Color::Rgb(110, 200, 255), Role::Blue);
+const CPU_COLOR: Ink = Ink(Color::Rgb(255, 120, 110), Role::Red);
+const BALL_COLOR: Ink = Ink(Color::Rgb(255, 235, 120), Role::Yellow);
+
+/// How the CPU paddle plays.
+#[derive(Clone, Copy, PartialEq)]
+enum Difficulty {
+    Easy,
+    Normal,
+    /// Reads spin, never misjudges, and angles returns away from the player;
+    /// only a ball too fast and steep for its paddle gets past
+    Impossible,
+}
+
+impl Difficulty {
+    const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Impossible];
+
+    fn label(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Impossible => "Impossible",
+        }
+    }
+
+    fn color(self) -> Ink {
+        match self {
+            Difficulty::Easy => Ink(Color::Rgb(80, 255, 80), Role::Green),
+            Difficulty::Normal => Ink(Color::Rgb(255, 220, 80), Role::Yellow),
+            Difficulty::Impossible => Ink(Color::Rgb(255, 60, 60), Role::Red),
+        }
+    }
+
+    fn next(self) -> Difficulty {
+        Difficulty::ALL[(self as usize + 1) % Difficulty::ALL.len()]
+    }
+
+    /// Paddle speed in units a tick.
+    fn speed(self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.55,
+            Difficulty::Normal => 0.7,
+            Difficulty::Impossible => 1.6,
+        }
+    }
+
+    /// Furthest off-centre it may meet the ball; past half a paddle it misses.
+    fn error(self) -> f32 {
+        match self {
+            Difficulty::Easy => 7.0,
+            Difficulty::Normal => 5.0,
+            Difficulty::Impossible => 0.0,
+        }
+    }
+
+    /// Ticks between its reads of where the ball is going.
+    fn read_ticks(self) -> u32 {
+        match self {
+            Difficulty::Easy => 30,
+            Difficulty::Normal => 12,
+            Difficulty::Impossible => 1,
+        }
+    }
+
+    /// How far across the ball has to come before the CPU goes for it.
+    fn reacts_at(self) -> f32 {
+        match self {
+            Difficulty::Easy => COURT_W * 0.6,
+            Difficulty::Normal => COURT_W * 0.5,
+            Difficulty::Impossible => 0.0,
+        }
+    }
+
+    /// Points are worth this many times more.
+    fn multiplier(self) -> u32 {
+        match self {
+            Difficulty::Easy => 1,
+            Difficulty::Normal => 2,
+            Difficulty::Impossible => 4,
+        }
+    }
+}
+
+/// Move a ball one tick: spin bends its path, and it bounces off the top
+/// and bottom walls, which scrub off half the spin.
+fn advance(ball: &mut (f32, f32), vel: &mut (f32, f32), spin: &mut f32) {
+    vel.1 = (vel.1 + *spin).clamp(-MAX_SPEED, MAX_SPEED);
+    *spin *= SPIN_DECAY;
+    ball.0 += vel.0;
+    ball.1 += vel.1;
+    if ball.1 < BALL_R {
+        ball.1 = 2.0 * BALL_R - ball.1;
+        vel.1 = vel.1.abs();
+        *spin *= 0.5;
+    } else if ball.1 > COURT_H - BALL_R {
+        ball.1 = 2.0 * (COURT_H - BALL_R) - ball.1;
+        vel.1 = -vel.1.abs();
+        *spin *= 0.5;
+    }
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+/// Pong against a CPU paddle, first to 11. Where the ball meets the paddle
+/// sets its angle, and a paddle on the move puts spin on it that bends
+/// its path, which only the Impossible CPU reads.
+pub struct PongGame {
+    ball: (f32, f32),
+    vel: (f32, f32),
+    spin: f32,
+    trail: VecDeque<(f32, f32)>,
+    player_y: f32,
+    /// Where the player's paddle is gliding to
+    player_target: f32,
+    /// Smoothed paddle speed, for spin
+    player_vy: f32,
+    cpu_y: f32,
+    cpu_vy: f32,
+    /// Where on its paddle the CPU means to meet the ball, from the centre
+    cpu_aim: f32,
+    /// Where the CPU last judged the ball would arrive, and ticks until it
+    /// looks again
+    cpu_read: Option<f32>,
+    read_in: u32,
+    difficulty: Difficulty,
+    /// (player, CPU)
+    points: (u32, u32),
+    rally: u32,
+    longest_rally: u32,
+    /// Ticks until the next serve; 0 while the ball is in play
+    serve_in: u32,
+    /// +1 serves toward the CPU, -1 toward the player
+    serve_dir: f32,
+    score: u32,
+    high_score: u32,
+    callout: Option<(&'static str, u32)>,
+    game_over: bool,
+    paused: bool,
+    countdown: Countdown,
+    practice: bool,
+    /// Positions at the start of the last tick, to draw between
+    prev: (f32, f32, f32, f32),
+    render_alpha: f32,
+    /// Court rows on screen, its top in dots and dots per unit, for the mouse
+    view: (Rect, f32, f32),
+    /// Serve side and angle, and where the CPU aims
+    rng: GameRng,
+}
+
+impl PongGame {
+    pub fn new() -> Self {
+        Self::with_rng(GameRng::from_entropy())
+    }
+
+    fn with_rng(mut rng: GameRng) -> Self {
+        Self {
+            ball: (COURT_W / 2.0, COURT_H / 2.0),
+            vel: (0.0, 0.0),
+            spin: 0.0,
+            trail: VecDeque::new(),
+            player_y: COURT_H / 2.0,
+            player_target: COURT_H / 2.0,
+            player_vy: 0.0,
+            cpu_y: COURT_H / 2.0,
+            cpu_vy: 0.0,
+            cpu_aim: 0.0,
+            cpu_read: None,
+            read_in: 0,
+            difficulty: Difficulty::Normal,
+            points: (0, 0),
+            rally: 0,
+            longest_rally: 0,
+            serve_in: SERVE_TICKS,
+            serve_dir: if rng.gen_bool(0.5) { 1.0 } else { -1.0 },
+            score: 0,
+            high_score: 0,
+            callout: None,
+            game_over: false,
+            paused: false,
+            countdown: Countdown::default(),
+            practice: false,
+            prev: (COURT_W / 2.0, COURT_H / 2.0, COURT_H / 2.0, COURT_H / 2.0),
+            render_alpha: 1.0,
+            view: (Rect::default(), 0.0, 1.0),
+            rng,
+        }
+    }
+
+    /// Points the CPU scored, for the score tables.
+    pub fn points_conceded(&self) -> u32 {
+        self.points.1
+    }
+
+    /// Bit 0: easy CPU, bit 1: impossible CPU (see `scores::flag_labels`).
+    pub fn modifier_flags(&self) -> u32 {
+        match self.difficulty {
+            Difficulty::Easy => 1,
+            Difficulty::Normal => 0,
+            Difficulty::Impossible => 2,
+        }
+    }
+
+    fn center_ball(&mut self) {
+        self.ball = (COURT_W / 2.0, COURT_H / 2.0);
+        self.vel = (0.0, 0.0);
+        self.spin = 0.0;
+        self.trail.clear();
+        self.prev.0 = self.ball.0;
+        self.prev.1 = self.ball.1;
+        self.serve_in = SERVE_TICKS;
+    }
+
+    fn serve(&mut self) {
+        let angle = self.rng.gen_range(-0.5..0.5f32);
+        self.vel = (self.serve_dir * SERVE_SPEED * angle.cos(), SERVE_SPEED * angle.sin());
+        self.cpu_aim = self.pick_aim();
+    }
+
+    fn pick_aim(&mut self) -> f32 {
+        match self.difficulty {
+            // Angle it away from wherever the player is
+            Difficulty::Impossible => if self.player_y < COURT_H / 2.0 { PADDLE_H * 0.35 } else { -PADDLE_H * 0.35 },
+            d => self.rng.gen_range(-d.error()..=d.error()),
+        }
+    }
+
+    /// Where the ball will be when it reaches `to_x`, following the walls
+    /// and, if `with_spin`, the bend spin puts on it.
+    fn predict_y(&self, to_x: f32, with_spin: bool) -> f32 {
+        let (mut ball, mut vel) = (self.ball, self.vel);
+        let mut spin = if with_spin { self.spin } else { 0.0 };
+        for _ in 0..1000 {
+            if (vel.0 > 0.0 && ball.0 >= to_x) || (vel.0 < 0.0 && ball.0 <= to_x) || vel.0 == 0.0 { break; }
+            advance(&mut ball, &mut vel, &mut spin);
+        }
+        ball.1
+    }
+
+    fn nudge(&mut self, dy: f32) {
+        let lead = 2.0 * KEY_STEP;
+        let from = self.player_target.clamp(self.player_y - lead, self.player_y + lead);
+        self.player_target = (from + dy).clamp(PADDLE_H / 2.0, COURT_H - PADDLE_H / 2.0);
+    }
+
+    fn move_paddles(&mut self) {
+        let dy = (self.player_target - self.player_y).clamp(-PLAYER_SPEED, PLAYER_SPEED);
+        self.player_y += dy;
+        self.player_vy = (self.player_vy + dy) / 2.0;
+
+        let d = self.difficulty;
+        if self.serve_in == 0 && self.vel.0 > 0.0 && self.ball.0 >= d.reacts_at() {
+            self.read_in = self.read_in.saturating_sub(1);
+            if self.cpu_read.is_none() || self.read_in == 0 {
+                let face = CPU_X - PADDLE_W / 2.0 - BALL_R;
+                self.cpu_read = Some(self.predict_y(face, d == Difficulty::Impossible));
+                self.read_in = d.read_ticks();
+            }
+        } else {
+            self.cpu_read = None;
+        }
+        let target = self.cpu_read.map_or(COURT_H / 2.0, |y| y - self.cpu_aim);
+        let dy = (target - self.cpu_y).clamp(-d.speed(), d.speed());
+        self.cpu_y = (self.cpu_y + dy).clamp(PADDLE_H / 2.0, COURT_H - PADDLE_H / 2.0);
+        self.cpu_vy = dy;
+    }
+
+    fn move_ball(&mut self) {
+        let was = self.ball.0;
+        advance(&mut self.ball, &mut self.vel, &mut self.spin);
+        self.trail.push_back(self.ball);
+        if self.trail.len() > TRAIL_LEN {
+            self.trail.pop_front();
+        }
+
+        let reach = PADDLE_H / 2.0 + BALL_R;
+        let face = PLAYER_X + PADDLE_W / 2.0 + BALL_R;
+        if self.vel.0 < 0.0 && was >= face && self.ball.0 < face && (self.ball.1 - self.player_y).abs() <= reach {
+            self.ball.0 = face;
+            self.return_ball(self.player_y, self.player_vy, 1.0);
+            self.cpu_aim = self.pick_aim();
+        }
+        let face = CPU_X - PADDLE_W / 2.0 - BALL_R;
+        if self.vel.0 > 0.0 && was <= face && self.ball.0 > face && (self.ball.1 - self.cpu_y).abs() <= reach {
+            self.ball.0 = face;
+            self.return_ball(self.cpu_y, self.cpu_vy, -1.0);
+        }
+
+        if self.ball.0 < 0.0 {
+            self.point(false);
+        } else if self.ball.0 > COURT_W {
+            self.point(true);
+        }
+    }
+
+    /// Send the ball back off a paddle at `paddle_y` moving at `paddle_vy`:
+    /// the further from the centre it hits, the steeper it goes.
+    fn return_ball(&mut self, paddle_y: f32, paddle_vy: f32, dir: f32) {
+        let speed = (self.vel.0.hypot(self.vel.1) * SPEED_UP).clamp(SERVE_SPEED, MAX_SPEED);
+        let angle = ((self.ball.1 - paddle_y) / (PADDLE_H / 2.0)).clamp(-1.0, 1.0) * MAX_ANGLE;
+        self.vel = (dir * speed * angle.cos(), speed * angle.sin() + paddle_vy * ENGLISH);
+        self.spin = paddle_vy * SPIN_GAIN;
+        audio::emit(AudioEvent::Bounce);
+        self.rally += 1;
+        self.longest_rally = self.longest_rally.max(self.rally);
+    }
+
+    fn point(&mut self, player: bool) {
+        let mult = self.difficulty.multiplier();
+        if player {
+            self.points.0 += 1;
+            self.score += 100 * mult;
+            self.callout = Some(("YOUR POINT", CALLOUT_TICKS));
+        } else {
+            self.points.1 += 1;
+            self.callout = Some(("CPU'S POINT", CALLOUT_TICKS));
+        }
+        self.rally = 0;
+        // The next serve goes to whoever lost the point
+        self.serve_dir = if player { 1.0 } else { -1.0 };
+        self.center_ball();
+        if self.points.0 >= POINTS_TO_WIN || self.points.1 >= POINTS_TO_WIN {
+            if self.points.0 >= POINTS_TO_WIN {
+                self.score += 1000 * mult;
+            }
+            self.callout = None;
+            self.game_over = true;
+            self.high_score = self.high_score.max(self.score);
+        }
+    }
+
+    fn render_court(&mut self, area: Rect, theme: &Theme) -> Vec<Line<'static>> {
+        let (cols, rows) = (area.width as usize, area.height as usize);
+        let mut grid: Vec<Vec<(char, Style)>> = vec![vec![(' ', Style::default()); cols]; rows];
+        let (dw, dh) = ((cols * 2) as f32, (rows * 4) as f32);
+        let s = (dw / COURT_W).min(dh / COURT_H);
+        let (ox, oy) = ((dw - COURT_W * s) / 2.0, (dh - COURT_H * s) / 2.0);
+        self.view = (area, oy, s);
+        let dot = |x: f32, y: f32| ((ox + x * s).round() as i32, (oy + y * s).round() as i32);
+
+        // Scores in big digits either side of the net
+        if rows >= 14 {
+            let mut digits = |n: u32, right_edge: Option<usize>, left_edge: Option<usize>| {
+                let text = n.to_string();
+                let width = text.len() * 7 - 1;
+                let x0 = right_edge.map_or_else(|| left_edge.unwrap_or(0), |r| r.saturating_sub(width));
+                let y0 = ((oy / 4.0) as usize + 1).min(rows.saturating_sub(5));
+                for (i, c) in text.chars().enumerate() {
+                    let glyph = FONT[c.to_digit(10).unwrap_or(0) as usize];
+                    for (r, bits) in glyph.iter().enumerate() {
+                        for b in 0..3 {
+                            if bits & (4 >> b) == 0 { continue; }
+                            for half in 0..2 {
+                                let x = x0 + i * 7 + b * 2 + half;
+                                if let Some(cell) = grid.get_mut(y0 + r).and_then(|row| row.get_mut(x)) {
+                                    *cell = ('█', Style::default().fg(theme.ink(DIGIT_COLOR)));
+                                }
+                            }
+                        }
+                    }
+                }
+            };
+            digits(self.points.0, Some(cols / 2 - 4), None);
+            digits(self.points.1, None, Some(cols / 2 + 4));
+        }
+
+        let mut lines = BrailleCanvas::new(cols, rows);
+        let (x0, y0) = dot(0.0, 0.0);
+        let (x1, y1) = dot(COURT_W, COURT_H);
+        lines.line(x0, y0, x1 - 1, y0);
+        lines.line(x0, y1 - 1, x1 - 1, y1 - 1);
+        let (nx, _) = dot(COURT_W / 2.0, 0.0);
+        for y in (y0..y1).step_by(4) {
+            lines.line(nx, y, nx, y + 1);
+        }
+
+        let t = self.render_alpha;
+        let paddle = |canvas: &mut BrailleCanvas, x: f32, y: f32| {
+            let (px0, py0) = dot(x - PADDLE_W / 2.0, y - PADDLE_H / 2.0);
+            let (px1, py1) = dot(x + PADDLE_W / 2.0, y + PADDLE_H / 2.0);
+            canvas.fill_rect(px0, py0, px1.max(px0 + 2), py1);
+        };
+        let mut player = BrailleCanvas::new(cols, rows);
+        paddle(&mut player, PLAYER_X, lerp(self.prev.2, self.player_y, t));
+        let mut cpu = BrailleCanvas::new(cols, rows);
+        paddle(&mut cpu, CPU_X, lerp(self.prev.3, self.cpu_y, t));
+
+        let mut trail = BrailleCanvas::new(cols, rows);
+        for &(x, y) in &self.trail {
+            let (tx, ty) = dot(x, y);
+            trail.set(tx, ty);
+        }
+        // Practice: where the ball will cross the player's paddle line
+        if self.practice && self.serve_in == 0 && self.vel.0 < 0.0 {
+            let y = self.predict_y(PLAYER_X, true);
+            let (gx, gy) = dot(PLAYER_X - PADDLE_W, y);
+            trail.fill_rect(gx - 1, gy - 1, gx + 1, gy + 2);
+        }
+        let mut ball = BrailleCanvas::new(cols, rows);
+        let (bx, by) = dot(lerp(self.prev.0, self.ball.0, t), lerp(self.prev.1, self.ball.1, t));
+        let r = ((BALL_R * s).round() as i32).max(1);
+        ball.fill_rect(bx - r, by - r, bx + r, by + r);
+
+        lines.draw(&mut grid, theme.ink(COURT_LINE), None, false);
+        trail.draw(&mut grid, theme.paint(Color::Rgb(120, 110, 60), Role::Yellow), None, false);
+        player.draw(&mut grid, theme.ink(PLAYER_COLOR), None, true);
+        cpu.draw(&mut grid, theme.ink(CPU_COLOR), None, true);
+        ball.draw(&mut grid, theme.ink(BALL_COLOR), None, true);
+
+        let callout = if self.game_over {
+            Some(if self.points.0 >= POINTS_TO_WIN { "YOU WIN" } else { "CPU WINS" })
+        } else {
+            self.callout.map(|(text, _)| text)
+        };
+        if let Some(text) = callout {
+            let y = rows / 2 + 2;
+            let x0 = (cols / 2).saturating_sub(text.len() / 2 + 1);
+            for (i, c) in format!(" {} ", text).chars().enumerate() {
+                if let Some(cell) = grid.get_mut(y).and_then(|row| row.get_mut(x0 + i)) {
+                    *cell = (c, Style::default().fg(theme.paint(Color::Black, Role::Background)).bg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD));
+                }
+            }
+        }
+
+        grid.into_iter()
+            .map(|row| Line::from(row.into_iter().map(|(ch, style)| Span::styled(String::from(ch), style)).collect::<Vec<_>>()))
+            .collect()
+    }
+}
+
+pub const INFO: GameInfo = GameInfo {
+    name: "Pong",
+    icon: "🏓",
+    author: "RustCade",
+    blurb: "First to 11\nagainst the CPU",
+    description: "Classic Pong against a CPU paddle on Easy, Normal or Impossible; move as you hit to put spin on the ball",
+    controls: &[
+        ("↑ ↓ / W S", "Move paddle"),
+        ("Mouse", "Move paddle"),
+        ("D", "CPU difficulty (before the first point)"),
+        ("R", "Restart"),
+        ("P", "Pause"),
+    ],
+    tags: &["arcade", "classic", "versus"],
+    color: ACCENT,
+    border_color: Ink(Color::Rgb(90, 90, 110), Role::Faint),
+};
+
+impl Game for PongGame {
+    fn info(&self) -> &'static GameInfo {
+        &INFO
+    }
+
+    fn update(&mut self) {
+        if self.paused || self.countdown.tick() || self.game_over { return; }
+        self.prev = (self.ball.0, self.ball.1, self.player_y, self.cpu_y);
+        if let Some((_, ticks)) = &mut self.callout {
+            *ticks = ticks.saturating_sub(1);
+            if *ticks == 0 { self.callout = None; }
+        }
+        self.move_paddles();
+        if self.serve_in > 0 {
+            self.serve_in -= 1;
+            if self.serve_in == 0 {
+                self.serve();
+            }
+            return;
+        }
+        self.move_ball();
+    }
+
+    fn handle_input(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('r') | KeyCode::Char('R') => self.reset(),
+            KeyCode::Char('p') | KeyCode::Char('P') if !self.game_over => {
+                self.countdown.toggle_pause(&mut self.paused);
+            }
+            _ if self.game_over => {
+                if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+                    self.reset();
+                }
+            }
+            _ if self.paused => {}
+            KeyCode::Up | KeyCode::Char('w') | KeyCode::Char('W') => self.nudge(-KEY_STEP),
+            KeyCode::Down | KeyCode::Char('s') | KeyCode::Char('S') => self.nudge(KEY_STEP),
+            // A match keeps the difficulty it was won or lost at
+            KeyCode::Char('d') | KeyCode::Char('D') if self.points == (0, 0) => {
+                self.difficulty = self.difficulty.next();
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent) {
+        if self.paused || self.game_over { return; }
+        if !matches!(event.kind, MouseEventKind::Moved | MouseEventKind::Drag(MouseButton::Left)) { return; }
+        let (area, oy, s) = self.view;
+        if !area.contains(Position::new(event.column, event.row)) { return; }
+        let y = ((event.row - area.y) as f32 * 4.0 + 2.0 - oy) / s;
+        self.player_target = y.clamp(PADDLE_H / 2.0, COURT_H - PADDLE_H / 2.0);
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = self.title_bar(theme);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(6), Constraint::Length(1)])
+            .split(inner);
+
+        let sep = || Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint)));
+        let mut status = vec![
+            Span::styled(" 🏓 ", Style::default()),
+            Span::styled("You ", Style::default().fg(theme.ink(PLAYER_COLOR))),
+            Span::styled(format!("{} – {}", self.points.0, self.points.1), Style::default().fg(theme.paint(Color::White, Role::Bright)).add_modifier(Modifier::BOLD)),
+            Span::styled(" CPU ", Style::default().fg(theme.ink(CPU_COLOR))),
+            sep(),
+            Span::styled(format!("Score: {} ", self.score), Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD)),
+            sep(),
+            Span::styled(format!("[{}] ", self.difficulty.label()), Style::default().fg(theme.ink(self.difficulty.color())).add_modifier(Modifier::BOLD)),
+            sep(),
+            Span::styled(format!("Rally {} (best {}) ", self.rally, self.longest_rally), Style::default().fg(theme.paint(Color::Gray, Role::Dim))),
+            sep(),
+            Span::styled(format!("First to {} ", POINTS_TO_WIN), Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+        ];
+        if self.spin.abs() > 0.002 && self.serve_in == 0 {
+            status.push(sep());
+            status.push(Span::styled(if self.spin > 0.0 { "Spin ↓ " } else { "Spin ↑ " }, Style::default().fg(theme.ink(BALL_COLOR))));
+        }
+        frame.render_widget(Paragraph::new(Line::from(status)), chunks[0]);
+
+        let field = chunks[1];
+        let court = self.render_court(field, theme);
+        frame.render_widget(Paragraph::new(court), field);
+        self.countdown.render(frame, field, theme);
+
+        let footer = if self.game_over {
+            let (headline, color) = if self.points.0 >= POINTS_TO_WIN {
+                ("🏆 YOU WIN!", theme.paint(Color::Rgb(80, 255, 120), Role::Green))
+            } else {
+                ("💀 CPU WINS", theme.ink(fx::alert_color()))
+            };
+            hud::outcome(headline, color, &format!(
+                "{}–{} on {}, longest rally {} │ ENTER to play again, Esc for menu",
+                self.points.0, self.points.1, self.difficulty.label(), self.longest_rally
+            ), theme)
+        } else if self.paused {
+            hud::pause_overlay(frame, field, theme);
+            hud::paused(theme)
+        } else if self.points == (0, 0) {
+            hud::legend(None, &[("↑↓/WS", "Move"), ("D", "CPU level")], theme)
+        } else {
+            hud::legend(None, &[("↑↓/WS", "Move"), ("Mouse", "Move")], theme)
+        };
+        frame.render_widget(Paragraph::new(footer), chunks[2]);
+    }
+
+    fn render_interpolated(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, alpha: f32) {
+        self.render_alpha = if self.paused || self.game_over { 1.0 } else { alpha.clamp(0.0, 1.0) };
+        self.render(frame, area, theme);
+    }
+
+    fn get_score(&self) -> u32 { self.score }
+    fn is_game_over(&self) -> bool { self.game_over }
+    fn is_paused(&self) -> bool { self.paused }
+    fn set_practice(&mut self, on: bool) { self.practice = on; }
+
+    fn reseed(&mut self, rng: GameRng) {
+        self.rng = rng;
+        self.reset();
+    }
+
+    fn script_state(&self) -> Vec<(&'static str, f64)> {
+        vec![
+            ("ball_x", self.ball.0 as f64),
+            ("ball_y", self.ball.1 as f64),
+            ("ball_vx", self.vel.0 as f64),
+            ("ball_vy", self.vel.1 as f64),
+            ("paddle_y", self.player_y as f64),
+            ("cpu_y", self.cpu_y as f64),
+            ("points", self.points.0 as f64),
+            ("cpu_points", self.points.1 as f64),
+        ]
+    }
+
+    fn serialize_state(&self) -> Option<Vec<u8>> {
+        if self.game_over { return None; }
+        let mut w = StateWriter::new();
+        w.u32(self.score);
+        w.u32(self.high_score);
+        w.u32(self.points.0);
+        w.u32(self.points.1);
+        w.u8(self.difficulty as u8);
+        w.u32(self.longest_rally);
+        Some(w.finish())
+    }
+
+    fn restore_state(&mut self, data: &[u8]) -> bool {
+        let mut r = StateReader::new(data);
+        let (Some(score), Some(high_score), Some(player), Some(cpu), Some(difficulty), Some(longest)) =
+            (r.u32(), r.u32(), r.u32(), r.u32(), r.u8(), r.u32())
+        else { return false };
+        let Some(&difficulty) = Difficulty::ALL.get(difficulty as usize) else { return false };
+        if player >= POINTS_TO_WIN || cpu >= POINTS_TO_WIN { return false; }
+
+        let mut restored = PongGame::with_rng(self.rng.clone());
+        restored.score = score;
+        restored.high_score = self.high_score.max(high_score);
+        restored.points = (player, cpu);
+        restored.difficulty = difficulty;
+        restored.longest_rally = longest;
+        restored.practice = self.practice;
+        // Resume paused, with the ball back at the centre for a serve
+        restored.paused = true;
+        *self = restored;
+        true
+    }
+
+    fn reset(&mut self) {
+        let (high_score, difficulty, practice) = (self.high_score, self.difficulty, self.practice);
+        *self = PongGame::with_rng(self.rng.clone());
+        self.high_score = high_score;
+        self.difficulty = difficulty;
+        self.practice = practice;
+    }
+}
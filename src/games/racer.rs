@@ -0,0 +1,664 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use rand::Rng;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::games::{Game, GameInfo};
+use crate::rng::GameRng;
+use crate::session::{StateReader, StateWriter};
+use crate::ui::fx;
+use crate::ui::hud::{self, Countdown};
+use crate::ui::theme::{Ink, Role, Theme};
+
+/// Top speed at full throttle, km/h
+const MAX_SPEED: f32 = 290.0;
+/// Speed gained per tick at the bottom of the rev range, km/h
+const ACCEL: f32 = 0.9;
+const COAST: f32 = 0.25;
+const BRAKE: f32 = 2.5;
+/// Throttle positions above idle
+const THROTTLE_NOTCHES: u8 = 4;
+/// Ticks a steering or brake key counts as held after each press or repeat
+const HOLD_TICKS: u32 = 8;
+/// Road half-widths a full-lock tick moves the car at speed
+const STEER_RATE: f32 = 0.03;
+/// How hard bends push the car outward at top speed
+const DRIFT: f32 = 0.028;
+/// Off the tarmac the car can't go faster than this
+const OFFROAD_SPEED: f32 = 80.0;
+const OFFROAD_DRAG: f32 = 1.2;
+/// Lateral bend of the road per metre², per unit of curve
+const CURVE_SCALE: f32 = 0.0016;
+/// Depth of the bottom screen row and the farthest drawn, metres
+const NEAR: f32 = 4.0;
+const FAR: f32 = 220.0;
+/// Where the player's car sits, as a fraction of the road's bottom-row scale
+const PLAYER_T: f32 = 0.75;
+/// Road half-width on the bottom row, as a fraction of the screen width
+const ROAD_SCALE: f32 = 0.42;
+/// Horizon, as a fraction of the screen from the top
+const HORIZON: f32 = 0.42;
+/// Length of one rumble/grass stripe, metres
+const STRIPE_LEN: f32 = 6.0;
+/// Metres between roadside posts
+const POST_GAP: f32 = 50.0;
+/// Car half-width, in road half-widths
+const CAR_HALF: f32 = 0.22;
+const LANES: [f32; 3] = [-0.66, 0.0, 0.66];
+const TRAFFIC: usize = 10;
+const CHECKPOINT_LEN: f32 = 1200.0;
+const START_TIME: u32 = 50 * 60;
+/// Seconds a checkpoint adds, one fewer each time down to the floor
+const EXTEND_SECS: u32 = 30;
+const MIN_EXTEND_SECS: u32 = 18;
+const CRASH_TICKS: u32 = 40;
+const CAR_COLORS: [Ink; 5] = [
+    Ink(Color::Rgb(60, 120, 230), Role::Blue),
+    Ink(Color::Rgb(240, 200, 40), Role::Yellow),
+    Ink(Color::Rgb(230, 230, 230), Role::Bright),
+    Ink(Color::Rgb(60, 190, 90), Role::Green),
+    Ink(Color::Rgb(170, 80, 200), Role::Violet),
+];
+const PLAYER_COLOR: Ink = Ink(Color::Rgb(220, 30, 30), Role::Red);
+
+/// The circuit as (length in metres, curve) sections, looping; a positive
+/// curve bends right.
+const TRACK: [(f32, f32); 14] = [
+    (300.0, 0.0),
+    (200.0, 0.4),
+    (150.0, 0.0),
+    (250.0, -0.6),
+    (200.0, 0.0),
+    (300.0, 0.8),
+    (100.0, 0.0),
+    (200.0, -0.3),
+    (150.0, -0.9),
+    (250.0, 0.0),
+    (200.0, 0.5),
+    (120.0, 1.0),
+    (300.0, -0.5),
+    (250.0, 0.0),
+];
+
+fn track_len() -> f32 {
+    TRACK.iter().map(|s| s.0).sum()
+}
+
+/// Curve of the section `d` metres round the circuit.
+fn curve_at(d: f32) -> f32 {
+    let mut d = d.rem_euclid(track_len());
+    for &(len, curve) in &TRACK {
+        if d < len { return curve; }
+        d -= len;
+    }
+    0.0
+}
+
+fn lerp(a: Color, b: Color, t: f32) -> Color {
+    let (Color::Rgb(r1, g1, b1), Color::Rgb(r2, g2, b2)) = (a, b) else { return a };
+    let mix = |x: u8, y: u8| (x as f32 + (y as f32 - x as f32) * t.clamp(0.0, 1.0)) as u8;
+    Color::Rgb(mix(r1, r2), mix(g1, g2), mix(b1, b2))
+}
+
+/// Square-ish pixels two to a character cell, drawn with upper half blocks.
+struct Pixels {
+    width: usize,
+    height: usize,
+    data: Vec<Color>,
+}
+
+impl Pixels {
+    fn new(width: usize, height: usize, theme: &Theme) -> Self {
+        Pixels { width, height, data: vec![theme.paint(Color::Black, Role::Background); width * height] }
+    }
+
+    fn set(&mut self, x: i32, y: i32, color: Color) {
+        if x < 0 || y < 0 || x >= self.width as i32 || y >= self.height as i32 { return; }
+        self.data[y as usize * self.width + x as usize] = color;
+    }
+
+    /// Fill `x0..x1` × `y0..y1`, rounded to whole pixels and clipped.
+    fn fill(&mut self, x0: f32, y0: f32, x1: f32, y1: f32, color: Color) {
+        let (x0, x1) = (x0.round().max(0.0) as i32, x1.round().min(self.width as f32) as i32);
+        let (y0, y1) = (y0.round().max(0.0) as i32, y1.round().min(self.height as f32) as i32);
+        for y in y0..y1 {
+            for x in x0..x1 {
+                self.set(x, y, color);
+            }
+        }
+    }
+
+    fn lines(&self) -> Vec<Line<'static>> {
+        (0..self.height / 2).map(|row| {
+            let spans: Vec<Span> = (0..self.width).map(|x| {
+                let top = self.data[row * 2 * self.width + x];
+                let bottom = self.data[(row * 2 + 1) * self.width + x];
+                Span::styled("▀", Style::default().fg(top).bg(bottom))
+            }).collect();
+            Line::from(spans)
+        }).collect()
+    }
+}
+
+#[derive(Clone)]
+struct Car {
+    /// Metres round the circuit
+    d: f32,
+    /// Across the road, in road half-widths
+    x: f32,
+    speed: f32,
+    color: u8,
+}
+
+/// Something drawn standing on the road: a car, a post or a gantry.
+enum Sprite {
+    Car(Ink),
+    Post,
+    Gantry,
+}
+
+/// Pseudo-3D racer: a circuit drawn row by row in perspective, traffic to
+/// weave through, and checkpoints that top up a running clock.
+pub struct RacerGame {
+    /// Metres round the circuit, and in all
+    pos: f32,
+    odometer: f32,
+    /// km/h
+    speed: f32,
+    /// Across the road, in road half-widths; beyond ±1 is grass
+    x: f32,
+    throttle: u8,
+    /// -1, 0 or 1, and ticks the steering key stays held
+    steer: i8,
+    steer_ticks: u32,
+    brake_ticks: u32,
+    cars: Vec<Car>,
+    time_left: u32,
+    checkpoints: u32,
+    /// Bend and horizon scroll, for the mountains
+    sky_scroll: f32,
+    crash_ticks: u32,
+    /// Checkpoint banner ticks left and seconds it added
+    extended: Option<(u32, u32)>,
+    started: bool,
+    game_over: bool,
+    paused: bool,
+    countdown: Countdown,
+    high_score: u32,
+    /// Traffic lanes, speeds and colours
+    rng: GameRng,
+}
+
+impl RacerGame {
+    pub fn new() -> Self {
+        Self::with_rng(GameRng::from_entropy())
+    }
+
+    fn with_rng(mut rng: GameRng) -> Self {
+        let spacing = track_len() / TRAFFIC as f32;
+        let cars = (0..TRAFFIC).map(|i| Car {
+            d: 120.0 + i as f32 * spacing,
+            x: LANES[rng.gen_range(0..LANES.len())],
+            speed: rng.gen_range(90.0..160.0),
+            color: rng.gen_range(0..CAR_COLORS.len()) as u8,
+        }).collect();
+        Self {
+            pos: 0.0,
+            odometer: 0.0,
+            speed: 0.0,
+            x: 0.0,
+            throttle: 0,
+            steer: 0,
+            steer_ticks: 0,
+            brake_ticks: 0,
+            cars,
+            time_left: START_TIME,
+            checkpoints: 0,
+            sky_scroll: 0.0,
+            crash_ticks: 0,
+            extended: None,
+            started: false,
+            game_over: false,
+            paused: false,
+            countdown: Countdown::default(),
+            high_score: 0,
+            rng,
+        }
+    }
+
+    fn score(&self) -> u32 {
+        self.odometer as u32 / 10 + self.checkpoints * 500
+    }
+
+    /// Checkpoints passed this run.
+    pub fn checkpoints(&self) -> u32 {
+        self.checkpoints
+    }
+
+    fn next_checkpoint(&self) -> f32 {
+        (self.checkpoints + 1) as f32 * CHECKPOINT_LEN
+    }
+
+    fn finish(&mut self) {
+        self.game_over = true;
+        self.high_score = self.high_score.max(self.score());
+    }
+
+    fn drive(&mut self) {
+        let target = MAX_SPEED * self.throttle as f32 / THROTTLE_NOTCHES as f32;
+        if self.brake_ticks > 0 {
+            self.speed -= BRAKE;
+        } else if self.speed < target {
+            // Less pull near the top of the range
+            self.speed += ACCEL * (1.0 - 0.6 * self.speed / MAX_SPEED);
+            self.speed = self.speed.min(target);
+        } else {
+            self.speed -= COAST;
+        }
+        let off_road = self.x.abs() > 1.0 + CAR_HALF;
+        if off_road && self.speed > OFFROAD_SPEED {
+            self.speed -= OFFROAD_DRAG;
+        }
+        self.speed = self.speed.clamp(0.0, MAX_SPEED);
+
+        let pace = self.speed / MAX_SPEED;
+        self.x += self.steer as f32 * STEER_RATE * (self.speed / 60.0).min(1.0);
+        // Bends carry the car to the outside
+        let curve = curve_at(self.pos);
+        self.x -= curve * pace * pace * DRIFT;
+        self.x = self.x.clamp(-2.5, 2.5);
+
+        let metres = self.speed / 3.6 / 60.0;
+        self.pos = (self.pos + metres).rem_euclid(track_len());
+        self.odometer += metres;
+        self.sky_scroll += curve * metres * 0.4;
+    }
+
+    fn move_traffic(&mut self) {
+        let len = track_len();
+        let player_z = NEAR / PLAYER_T;
+        for car in &mut self.cars {
+            car.d = (car.d + car.speed / 3.6 / 60.0).rem_euclid(len);
+        }
+        if self.crash_ticks > 0 { return; }
+        let hit = self.cars.iter().position(|c| {
+            let z = (c.d - self.pos).rem_euclid(len);
+            (player_z - 1.0..player_z + 2.5).contains(&z) && (c.x - self.x).abs() < CAR_HALF * 2.0
+        });
+        if let Some(i) = hit {
+            let car = &mut self.cars[i];
+            self.speed = self.speed.min(car.speed * 0.6);
+            // Shunted up the road
+            car.d = (car.d + 8.0).rem_euclid(len);
+            self.throttle = self.throttle.min(2);
+            self.crash_ticks = CRASH_TICKS;
+        }
+    }
+
+    fn render_road(&self, width: usize, rows: usize, theme: &Theme) -> Vec<Line<'static>> {
+        if width == 0 || rows == 0 { return Vec::new(); }
+        let (w, h) = (width, rows * 2);
+        let mut px = Pixels::new(w, h, theme);
+        let hz = ((h as f32 * HORIZON) as usize).min(h.saturating_sub(2));
+        let wf = w as f32;
+
+        // Sky and a mountain ridge that slides round with the bends
+        let sky_top = theme.paint(Color::Rgb(30, 40, 110), Role::Blue);
+        let sky_low = theme.paint(Color::Rgb(240, 140, 90), Role::Orange);
+        for y in 0..=hz {
+            let c = lerp(sky_top, sky_low, y as f32 / hz.max(1) as f32);
+            px.fill(0.0, y as f32, wf, y as f32 + 1.0, c);
+        }
+        let ridge = h as f32 * 0.12;
+        for x in 0..w {
+            let s = x as f32 + self.sky_scroll;
+            let peak = ridge * (0.55 + 0.3 * (s * 0.045).sin() + 0.15 * (s * 0.13 + 1.7).sin());
+            px.fill(x as f32, hz as f32 + 1.0 - peak, x as f32 + 1.0, hz as f32 + 1.0, theme.paint(Color::Rgb(80, 50, 110), Role::Violet));
+        }
+
+        // Ground, one row at a time from the bottom: each row's depth sets
+        // the road's scale, and the bends ahead add up into its offset
+        let span = (h - hz - 1) as f32;
+        let mut road_x = vec![0.0f32; h];
+        let (mut lateral, mut slope, mut prev_z) = (0.0f32, 0.0f32, NEAR);
+        for y in (hz + 1..h).rev() {
+            let t = (y - hz) as f32 / span;
+            let z = NEAR / t;
+            // Rows near the horizon span tens of metres; step through them
+            // a couple of metres at a time so the bends stay smooth
+            let steps = ((z - prev_z) / 2.0).ceil().max(1.0);
+            let dz = (z - prev_z) / steps;
+            for i in 1..=steps as usize {
+                slope += curve_at(self.pos + prev_z + dz * i as f32) * CURVE_SCALE * dz;
+                lateral += slope * dz;
+            }
+            prev_z = z;
+            road_x[y] = lateral;
+
+            let half = t * wf * ROAD_SCALE;
+            let stripe = ((self.pos + z) / STRIPE_LEN).floor() as i64 % 2 == 0;
+            let fog = (z / FAR).min(1.0) * 0.5;
+            let (grass, rumble, tarmac) = if stripe {
+                (theme.paint(Color::Rgb(40, 140, 60), Role::Green), theme.paint(Color::Rgb(210, 40, 40), Role::Red), theme.paint(Color::Rgb(100, 100, 110), Role::Faint))
+            } else {
+                (theme.paint(Color::Rgb(30, 120, 50), Role::Green), theme.paint(Color::Rgb(235, 235, 235), Role::Bright), theme.paint(Color::Rgb(92, 92, 102), Role::Faint))
+            };
+            for x in 0..w {
+                let across = (x as f32 + 0.5 - wf / 2.0) / half + self.x - lateral;
+                let a = across.abs();
+                let color = if a < 1.0 {
+                    if stripe && ((a - 1.0 / 3.0).abs() < 0.025) { theme.paint(Color::Rgb(230, 230, 230), Role::Bright) } else { tarmac }
+                } else if a < 1.12 {
+                    rumble
+                } else {
+                    grass
+                };
+                px.set(x as i32, y as i32, lerp(color, sky_low, fog));
+            }
+        }
+
+        // Screen position of a point `z` metres ahead and `across` half-widths over
+        let project = |z: f32, across: f32| -> Option<(f32, f32, f32)> {
+            if !(NEAR..FAR).contains(&z) { return None; }
+            let t = NEAR / z;
+            let y = hz as f32 + t * span;
+            let lateral = road_x[(y as usize).clamp(hz + 1, h - 1)];
+            let half = t * wf * ROAD_SCALE;
+            Some((wf / 2.0 + (lateral + across - self.x) * half, y, half))
+        };
+
+        // Posts, the next checkpoint and traffic, far to near
+        let len = track_len();
+        let mut sprites: Vec<(f32, f32, Sprite)> = Vec::new();
+        let first_post = (self.pos / POST_GAP).ceil() * POST_GAP;
+        let mut d = first_post;
+        while d - self.pos < FAR {
+            sprites.push((d - self.pos, -1.5, Sprite::Post));
+            sprites.push((d - self.pos, 1.5, Sprite::Post));
+            d += POST_GAP;
+        }
+        sprites.push((self.next_checkpoint() - self.odometer, 0.0, Sprite::Gantry));
+        for car in &self.cars {
+            sprites.push(((car.d - self.pos).rem_euclid(len), car.x, Sprite::Car(CAR_COLORS[car.color as usize])));
+        }
+        sprites.sort_by(|a, b| b.0.total_cmp(&a.0));
+        for (z, across, sprite) in sprites {
+            let Some((cx, y, half)) = project(z, across) else { continue };
+            match sprite {
+                Sprite::Car(color) => draw_car(&mut px, cx, y, half * CAR_HALF, theme.ink(color), 0, theme),
+                Sprite::Post => {
+                    let pw = (half * 0.04).max(1.0);
+                    let ph = half * 0.35;
+                    px.fill(cx - pw / 2.0, y - ph, cx + pw / 2.0, y, theme.paint(Color::Rgb(235, 235, 235), Role::Bright));
+                    px.fill(cx - pw / 2.0, y - ph, cx + pw / 2.0, y - ph * 0.8, if across < 0.0 { theme.paint(Color::Rgb(210, 40, 40), Role::Red) } else { theme.paint(Color::Rgb(240, 200, 40), Role::Yellow) });
+                }
+                Sprite::Gantry => {
+                    let (left, right) = (cx - half * 1.2, cx + half * 1.2);
+                    let (pw, ph) = ((half * 0.05).max(1.0), half * 0.8);
+                    px.fill(left - pw, y - ph, left, y, theme.paint(Color::Rgb(60, 60, 70), Role::Faint));
+                    px.fill(right, y - ph, right + pw, y, theme.paint(Color::Rgb(60, 60, 70), Role::Faint));
+                    let band = (half * 0.12).max(1.0);
+                    let checks = ((right - left) / band.max(1.0)).max(1.0) as usize;
+                    for i in 0..checks {
+                        let color = if i % 2 == 0 { theme.paint(Color::White, Role::Bright) } else { theme.paint(Color::Black, Role::Background) };
+                        let bx = left + i as f32 * band;
+                        px.fill(bx, y - ph, bx + band, y - ph + band, color);
+                    }
+                }
+            }
+        }
+
+        // The player's car, blinking after a crash
+        if (self.crash_ticks / 4).is_multiple_of(2) {
+            let half = PLAYER_T * wf * ROAD_SCALE;
+            draw_car(&mut px, wf / 2.0, h as f32 - 1.0, half * CAR_HALF, theme.ink(PLAYER_COLOR), self.steer, theme);
+        }
+        px.lines()
+    }
+}
+
+/// A car seen from behind, standing on `bottom` and `half` pixels each side
+/// of `cx`; `lean` shifts the cabin the way it's steering.
+fn draw_car(px: &mut Pixels, cx: f32, bottom: f32, half: f32, color: Color, lean: i8, theme: &Theme) {
+    let h = half * 1.1;
+    let dark = lerp(color, theme.paint(Color::Rgb(0, 0, 0), Role::Background), 0.45);
+    let cabin = cx + lean as f32 * half * 0.12;
+    px.fill(cx - half, bottom - h * 0.6, cx + half, bottom, color);
+    px.fill(cabin - half * 0.7, bottom - h, cabin + half * 0.7, bottom - h * 0.6, dark);
+    if half >= 3.0 {
+        px.fill(cabin - half * 0.55, bottom - h * 0.92, cabin + half * 0.55, bottom - h * 0.68, theme.paint(Color::Rgb(120, 170, 210), Role::Blue));
+        px.fill(cx - half * 0.9, bottom - h * 0.45, cx - half * 0.6, bottom - h * 0.3, theme.paint(Color::Rgb(255, 60, 60), Role::Red));
+        px.fill(cx + half * 0.6, bottom - h * 0.45, cx + half * 0.9, bottom - h * 0.3, theme.paint(Color::Rgb(255, 60, 60), Role::Red));
+    }
+    let wheel = (half * 0.3).max(1.0);
+    px.fill(cx - half, bottom - h * 0.15, cx - half + wheel, bottom + 1.0, theme.paint(Color::Rgb(20, 20, 20), Role::Background));
+    px.fill(cx + half - wheel, bottom - h * 0.15, cx + half, bottom + 1.0, theme.paint(Color::Rgb(20, 20, 20), Role::Background));
+}
+
+pub const INFO: GameInfo = GameInfo {
+    name: "Racer",
+    icon: "🏎",
+    author: "RustCade",
+    blurb: "Flat out on a\npseudo-3D circuit",
+    description: "Weave through traffic and reach each checkpoint before the clock runs out",
+    controls: &[
+        ("↑ / W", "More throttle"),
+        ("↓ / S", "Less throttle"),
+        ("← → / A D", "Steer"),
+        ("Space", "Brake"),
+        ("R", "Restart"),
+        ("P", "Pause"),
+    ],
+    tags: &["racing", "arcade", "3d"],
+    color: Ink(Color::Rgb(255, 90, 90), Role::Red),
+    border_color: Ink(Color::Rgb(130, 30, 30), Role::Red),
+};
+
+impl Game for RacerGame {
+    fn info(&self) -> &'static GameInfo {
+        &INFO
+    }
+
+    fn update(&mut self) {
+        if self.paused || self.countdown.tick() { return; }
+        if let Some((ticks, _)) = &mut self.extended {
+            *ticks = ticks.saturating_sub(1);
+            if *ticks == 0 { self.extended = None; }
+        }
+        if !self.started || self.game_over { return; }
+        self.steer_ticks = self.steer_ticks.saturating_sub(1);
+        if self.steer_ticks == 0 { self.steer = 0; }
+        self.brake_ticks = self.brake_ticks.saturating_sub(1);
+        self.crash_ticks = self.crash_ticks.saturating_sub(1);
+
+        self.drive();
+        self.move_traffic();
+        if self.odometer >= self.next_checkpoint() {
+            self.checkpoints += 1;
+            let secs = EXTEND_SECS.saturating_sub(self.checkpoints - 1).max(MIN_EXTEND_SECS);
+            self.time_left += secs * 60;
+            self.extended = Some((120, secs));
+        }
+        self.time_left = self.time_left.saturating_sub(1);
+        if self.time_left == 0 {
+            self.finish();
+        }
+    }
+
+    fn handle_input(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('r') | KeyCode::Char('R') => self.reset(),
+            KeyCode::Char('p') | KeyCode::Char('P') if self.started && !self.game_over => {
+                self.countdown.toggle_pause(&mut self.paused);
+            }
+            _ if self.game_over => {
+                if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+                    self.reset();
+                }
+            }
+            _ if self.paused || self.countdown.is_running() => {}
+            KeyCode::Up | KeyCode::Char('w') | KeyCode::Char('W') => {
+                self.started = true;
+                self.throttle = (self.throttle + 1).min(THROTTLE_NOTCHES);
+            }
+            KeyCode::Down | KeyCode::Char('s') | KeyCode::Char('S') => self.throttle = self.throttle.saturating_sub(1),
+            KeyCode::Left | KeyCode::Char('a') | KeyCode::Char('A') => {
+                self.steer = -1;
+                self.steer_ticks = HOLD_TICKS;
+            }
+            KeyCode::Right | KeyCode::Char('d') | KeyCode::Char('D') => {
+                self.steer = 1;
+                self.steer_ticks = HOLD_TICKS;
+            }
+            KeyCode::Char(' ') => {
+                self.brake_ticks = HOLD_TICKS;
+                self.throttle = 0;
+            }
+            _ => {}
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = self.title_bar(theme);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(8), Constraint::Length(1)])
+            .split(inner);
+
+        let sep = || Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint)));
+        let secs = self.time_left.div_ceil(60);
+        let time_color = if secs <= 10 { theme.ink(fx::alert_color()) } else { theme.paint(Color::Cyan, Role::Cyan) };
+        let notches = THROTTLE_NOTCHES as usize;
+        let throttle = format!("{}{}", "▮".repeat(self.throttle as usize), "▯".repeat(notches - self.throttle as usize));
+        let mut status = vec![
+            Span::styled(" 🏎 ", Style::default()),
+            Span::styled(format!("{:>3} km/h ", self.speed.round() as u32), Style::default().fg(theme.paint(Color::White, Role::Bright)).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{} ", throttle), Style::default().fg(theme.paint(Color::Rgb(255, 90, 90), Role::Red))),
+            sep(),
+            Span::styled(format!("Time: {} ", secs), Style::default().fg(time_color).add_modifier(Modifier::BOLD)),
+            sep(),
+            Span::styled(format!("Score: {} ", self.score()), Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD)),
+            sep(),
+            Span::styled(format!("{:.2} km ", self.odometer / 1000.0), Style::default().fg(theme.paint(Color::Gray, Role::Dim))),
+            sep(),
+            Span::styled(
+                format!("Checkpoint {} in {} m ", self.checkpoints + 1, (self.next_checkpoint() - self.odometer).max(0.0) as u32),
+                Style::default().fg(theme.paint(Color::Rgb(120, 230, 120), Role::Green)),
+            ),
+        ];
+        if let Some((_, secs)) = self.extended {
+            status.push(sep());
+            status.push(Span::styled(format!("EXTENDED +{}s ", secs), Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD)));
+        } else if self.crash_ticks > 0 {
+            status.push(sep());
+            status.push(Span::styled("CRASH! ", Style::default().fg(theme.ink(fx::alert_color())).add_modifier(Modifier::BOLD)));
+        }
+        frame.render_widget(Paragraph::new(Line::from(status)), chunks[0]);
+
+        let lines = self.render_road(chunks[1].width as usize, chunks[1].height as usize, theme);
+        frame.render_widget(Paragraph::new(lines), chunks[1]);
+        self.countdown.render(frame, chunks[1], theme);
+
+        let footer = if self.game_over {
+            hud::game_over(&format!(
+                "Time up! {:.2} km, {} checkpoints │ ENTER to restart, Esc for menu",
+                self.odometer / 1000.0, self.checkpoints,
+            ), theme)
+        } else if self.paused {
+            hud::pause_overlay(frame, chunks[1], theme);
+            hud::paused(theme)
+        } else if !self.started {
+            hud::menu_legend(Some("↑ Start"), &[("↑↓", "Throttle"), ("←→", "Steer"), ("Space", "Brake")], theme)
+        } else {
+            hud::legend(None, &[("↑↓", "Throttle"), ("←→", "Steer"), ("Space", "Brake")], theme)
+        };
+        frame.render_widget(Paragraph::new(footer), chunks[2]);
+    }
+
+    fn get_score(&self) -> u32 { self.score() }
+    fn is_game_over(&self) -> bool { self.game_over }
+    fn is_paused(&self) -> bool { self.paused }
+
+    fn reseed(&mut self, rng: GameRng) {
+        self.rng = rng;
+        self.reset();
+    }
+
+    fn script_state(&self) -> Vec<(&'static str, f64)> {
+        // Nearest car ahead: distance and how far across from the player
+        let len = track_len();
+        let ahead = self.cars.iter()
+            .map(|c| ((c.d - self.pos).rem_euclid(len), c.x - self.x))
+            .min_by(|a, b| a.0.total_cmp(&b.0));
+        vec![
+            ("speed", self.speed as f64),
+            ("x", self.x as f64),
+            ("curve", curve_at(self.pos + 40.0) as f64),
+            ("throttle", self.throttle as f64),
+            ("time_left", self.time_left as f64 / 60.0),
+            ("car_ahead", ahead.map_or(-1.0, |a| a.0 as f64)),
+            ("car_ahead_dx", ahead.map_or(0.0, |a| a.1 as f64)),
+            ("checkpoints", self.checkpoints as f64),
+        ]
+    }
+
+    fn serialize_state(&self) -> Option<Vec<u8>> {
+        if !self.started || self.game_over { return None; }
+        let mut w = StateWriter::new();
+        w.f32(self.pos);
+        w.f32(self.odometer);
+        w.f32(self.speed);
+        w.f32(self.x);
+        w.u8(self.throttle);
+        w.u32(self.time_left);
+        w.u32(self.checkpoints);
+        w.f32(self.sky_scroll);
+        w.u32(self.high_score);
+        w.u32(self.cars.len() as u32);
+        for car in &self.cars {
+            w.f32(car.d);
+            w.f32(car.x);
+            w.f32(car.speed);
+            w.u8(car.color);
+        }
+        Some(w.finish())
+    }
+
+    fn restore_state(&mut self, data: &[u8]) -> bool {
+        let mut r = StateReader::new(data);
+        let (Some(pos), Some(odometer), Some(speed), Some(x), Some(throttle), Some(time_left), Some(checkpoints), Some(sky_scroll), Some(high_score), Some(count)) =
+            (r.f32(), r.f32(), r.f32(), r.f32(), r.u8(), r.u32(), r.u32(), r.f32(), r.u32(), r.u32())
+        else { return false };
+        if count as usize > TRAFFIC * 2 { return false; }
+        let mut cars = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let (Some(d), Some(cx), Some(cs), Some(color)) = (r.f32(), r.f32(), r.f32(), r.u8()) else { return false };
+            cars.push(Car { d, x: cx, speed: cs, color: color % CAR_COLORS.len() as u8 });
+        }
+
+        self.reset();
+        self.pos = pos.rem_euclid(track_len());
+        self.odometer = odometer.max(0.0);
+        self.speed = speed.clamp(0.0, MAX_SPEED);
+        self.x = x.clamp(-2.5, 2.5);
+        self.throttle = throttle.min(THROTTLE_NOTCHES);
+        self.time_left = time_left.max(1);
+        self.checkpoints = checkpoints;
+        self.sky_scroll = sky_scroll;
+        self.high_score = self.high_score.max(high_score);
+        self.cars = cars;
+        self.started = true;
+        // Resume paused, with a countdown before the clock runs again
+        self.paused = true;
+        true
+    }
+
+    fn reset(&mut self) {
+        let high_score = self.high_score;
+        *self = RacerGame::with_rng(self.rng.clone());
+        self.high_score = high_score;
+    }
+}
@@ -0,0 +1,667 @@
+use std::collections::VecDeque;
+
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use rand::Rng;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::games::tile_grid::{rotate_mask, CellArt, Dir, TileGrid};
+use crate::games::{Game, GameInfo};
+use crate::rng::GameRng;
+use crate::session::{StateReader, StateWriter};
+use crate::ui::fx;
+use crate::ui::hud::{self, Countdown};
+use crate::ui::theme::{Ink, Role, Theme};
+
+/// Terminal (columns, rows) each tile is drawn in
+const CELL: (usize, usize) = (5, 3);
+/// Packets a source can hold before it overflows
+const QUEUE_CAP: usize = 5;
+/// Packets that may be dropped before the network goes down
+const MAX_LOST: u32 = 5;
+/// Hops a packet waits at a dead end before it's dropped
+const PATIENCE: u32 = 6;
+/// Ticks before the first packets, to start on the wiring
+const GRACE_TICKS: u32 = 300;
+/// Ticks a delivered or dropped packet's tile stays lit
+const FLASH_TICKS: u32 = 20;
+/// A crossing: four openings, packets go straight over
+const CROSS: u8 = 0x0F;
+/// Box-drawing glyph for each connection mask (Up 1, Right 2, Down 4, Left 8)
+const GLYPHS: [char; 16] = [' ', '╵', '╶', '└', '╷', '│', '┌', '├', '╴', '┘', '─', '┴', '┐', '┤', '┬', '┼'];
+/// Packet and sink colours, by sink
+const DEST_COLORS: [Ink; 4] = [
+    Ink(Color::Rgb(255, 100, 100), Role::Red),
+    Ink(Color::Rgb(120, 230, 120), Role::Green),
+    Ink(Color::Rgb(110, 160, 255), Role::Blue),
+    Ink(Color::Rgb(255, 220, 90), Role::Yellow),
+];
+const ACCENT: Ink = Ink(Color::Rgb(255, 160, 60), Role::Orange);
+const LIVE_COLOR: Ink = Ink(Color::Rgb(90, 220, 220), Role::Cyan);
+const DEAD_COLOR: Ink = Ink(Color::Rgb(110, 70, 70), Role::Faint);
+const CURSOR_BG: Ink = Ink(Color::Rgb(50, 50, 85), Role::Panel);
+
+/// Network layouts as solved. Box-drawing characters are router tiles,
+/// `1`-`4` sources and `a`-`d` sinks, each opening toward its one wired
+/// neighbour; anything else is empty. Tiles are scrambled on load.
+const LEVELS: [(&str, &str); 4] = [
+    ("Uplink", "\
+1───┬──a
+....│...
+....└──b"),
+    ("Backbone", "\
+1──┐....a
+...├──┬─┘
+2──┘..│..
+......└─b"),
+    ("Exchange", "\
+1──┐..┌──a
+...│..│...
+2──┼──┤...
+...│..└──b
+...└─────c"),
+    ("Mesh", "\
+1──┬───┐...
+...│...├──a
+2──┼─┐.│...
+...│.└─┴──b
+3──┘......."),
+];
+
+#[derive(Clone, Copy, PartialEq)]
+enum Tile {
+    Empty,
+    /// `mask` is the openings as laid out, `turns` quarter turns clockwise on top
+    Wire { mask: u8, turns: u8 },
+    /// Source number and the side it sends from
+    Source(u8, Dir),
+    /// Sink number and the side it takes packets in by
+    Sink(u8, Dir),
+}
+
+impl Tile {
+    fn openings(self) -> u8 {
+        match self {
+            Tile::Empty => 0,
+            Tile::Wire { mask, turns } => rotate_mask(mask, turns),
+            Tile::Source(_, dir) | Tile::Sink(_, dir) => dir.bit(),
+        }
+    }
+}
+
+fn glyph_mask(c: char) -> Option<u8> {
+    GLYPHS.iter().position(|&g| g == c).filter(|&m| m > 0).map(|m| m as u8)
+}
+
+fn parse_level(art: &str) -> TileGrid<Tile> {
+    let chars: Vec<Vec<char>> = art.lines().map(|l| l.chars().collect()).collect();
+    let width = chars.iter().map(Vec::len).max().unwrap_or(0);
+    let at = |x: i32, y: i32| -> char {
+        if x < 0 || y < 0 { return '.'; }
+        chars.get(y as usize).and_then(|r| r.get(x as usize)).copied().unwrap_or('.')
+    };
+    let rows = (0..chars.len() as i32).map(|y| (0..width as i32).map(|x| {
+        let c = at(x, y);
+        // Sources and sinks open toward their wired neighbour
+        let side = || Dir::ALL.into_iter()
+            .find(|d| { let (dx, dy) = d.delta(); glyph_mask(at(x + dx, y + dy)).is_some() })
+            .unwrap_or(Dir::Right);
+        match c {
+            '1'..='4' => Tile::Source(c as u8 - b'1', side()),
+            'a'..='d' => Tile::Sink(c as u8 - b'a', side()),
+            _ => glyph_mask(c).map_or(Tile::Empty, |mask| Tile::Wire { mask, turns: 0 }),
+        }
+    }).collect()).collect();
+    TileGrid::from_rows(rows)
+}
+
+struct Packet {
+    x: usize,
+    y: usize,
+    /// Side it came into this tile by
+    from: Dir,
+    /// Sink it's addressed to
+    dest: u8,
+    /// Hops spent waiting for a way on
+    stalled: u32,
+}
+
+/// Packet router puzzle: packets queue at sources and hop along the wires,
+/// and the player turns router tiles so each reaches its sink before the
+/// queues overflow.
+pub struct RouterGame {
+    grid: TileGrid<Tile>,
+    level: u32,
+    /// (x, y, number, side) of each source
+    sources: Vec<(usize, usize, u8, Dir)>,
+    /// Sinks each source's packets can be addressed to, by source number
+    routes: [Vec<u8>; 4],
+    queues: [VecDeque<u8>; 4],
+    packets: Vec<Packet>,
+    /// Ticks since the level started
+    clock: u32,
+    /// Packets delivered this level and in all
+    delivered: u32,
+    total_delivered: u32,
+    lost: u32,
+    score: u32,
+    high_score: u32,
+    cleared: bool,
+    game_over: bool,
+    /// The network went down on a full queue rather than lost packets
+    overflowed: bool,
+    paused: bool,
+    countdown: Countdown,
+    /// (x, y, ticks left, colour) of lit tiles
+    flashes: Vec<(usize, usize, u32, Ink)>,
+    /// Top-left of the drawn grid, for mouse clicks
+    grid_origin: Position,
+    /// How the wires start out turned, and where packets are addressed
+    rng: GameRng,
+}
+
+impl RouterGame {
+    pub fn new() -> Self {
+        Self::with_rng(GameRng::from_entropy())
+    }
+
+    fn with_rng(rng: GameRng) -> Self {
+        let mut game = Self {
+            grid: TileGrid::new(0, 0, Tile::Empty),
+            level: 0,
+            sources: Vec::new(),
+            routes: Default::default(),
+            queues: Default::default(),
+            packets: Vec::new(),
+            clock: 0,
+            delivered: 0,
+            total_delivered: 0,
+            lost: 0,
+            score: 0,
+            high_score: 0,
+            cleared: false,
+            game_over: false,
+            overflowed: false,
+            paused: false,
+            countdown: Countdown::default(),
+            flashes: Vec::new(),
+            grid_origin: Position::default(),
+            rng,
+        };
+        game.load_level(0);
+        game
+    }
+
+    fn load_level(&mut self, level: u32) {
+        let (_, art) = LEVELS[level as usize % LEVELS.len()];
+        self.level = level;
+        self.grid = parse_level(art);
+        self.sources = self.grid.iter()
+            .filter_map(|(x, y, t)| match *t { Tile::Source(id, dir) => Some((x, y, id, dir)), _ => None })
+            .collect();
+        let sinks: Vec<u8> = self.grid.iter()
+            .filter_map(|(_, _, t)| match *t { Tile::Sink(id, _) => Some(id), _ => None })
+            .collect();
+        // Address packets only to sinks the solved layout can reach
+        self.routes = Default::default();
+        for &(x, y, id, dir) in &self.sources {
+            self.routes[id as usize] = sinks.iter().copied().filter(|&s| self.reaches(x, y, dir, s)).collect();
+        }
+        for (_, _, tile) in self.grid.iter_mut() {
+            if let Tile::Wire { mask, turns } = tile {
+                if *mask != CROSS { *turns = self.rng.gen_range(0..4); }
+            }
+        }
+        let first_wire = self.grid.iter().find(|(_, _, t)| matches!(t, Tile::Wire { .. })).map(|(x, y, _)| (x, y));
+        self.grid.cursor = first_wire.unwrap_or((0, 0));
+        self.queues = Default::default();
+        self.packets.clear();
+        self.flashes.clear();
+        self.clock = 0;
+        self.delivered = 0;
+        self.cleared = false;
+    }
+
+    fn level_name(&self) -> &'static str {
+        LEVELS[self.level as usize % LEVELS.len()].0
+    }
+
+    /// Packets to deliver to clear the level.
+    fn target(&self) -> u32 {
+        8 + 4 * self.level
+    }
+
+    /// Ticks between hops; traffic speeds up every level.
+    fn hop_ticks(&self) -> u32 {
+        20u32.saturating_sub(self.level * 2).max(8)
+    }
+
+    /// Ticks between packets from each source.
+    fn emit_ticks(&self) -> u32 {
+        240u32.saturating_sub(self.level * 20).max(100)
+    }
+
+    /// Packets delivered over the whole run.
+    pub fn packets_delivered(&self) -> u32 {
+        self.total_delivered
+    }
+
+    /// The neighbour `dir` of (x, y), if it opens back toward (x, y).
+    fn step(&self, x: usize, y: usize, dir: Dir) -> Option<(usize, usize)> {
+        let (nx, ny) = self.grid.neighbor(x, y, dir)?;
+        let back = self.grid.get(nx, ny)?.openings() & dir.opposite().bit() != 0;
+        back.then_some((nx, ny))
+    }
+
+    /// Sides a packet that came in by `from` may leave by, straight on
+    /// first: crossings only go straight over, other tiles anywhere but back.
+    fn exits(&self, x: usize, y: usize, from: Dir) -> Vec<Dir> {
+        let open = self.grid.get(x, y).map_or(0, |t| t.openings());
+        if open == CROSS { return vec![from.opposite()]; }
+        [from.opposite(), from.turn(1), from.turn(3)].into_iter().filter(|d| open & d.bit() != 0).collect()
+    }
+
+    /// Whether a packet leaving (x, y) by `dir` can get to sink `dest` along
+    /// the wires as they're turned now.
+    fn reaches(&self, x: usize, y: usize, dir: Dir, dest: u8) -> bool {
+        let w = self.grid.width();
+        let mut seen = vec![false; w * self.grid.height() * 4];
+        let mut stack = vec![(x, y, dir)];
+        while let Some((x, y, dir)) = stack.pop() {
+            let Some((nx, ny)) = self.step(x, y, dir) else { continue };
+            let from = dir.opposite();
+            match self.grid.get(nx, ny) {
+                Some(Tile::Sink(id, _)) if *id == dest => return true,
+                Some(Tile::Wire { .. }) => {
+                    let i = (ny * w + nx) * 4 + from as usize;
+                    if seen[i] { continue; }
+                    seen[i] = true;
+                    stack.extend(self.exits(nx, ny, from).into_iter().map(|d| (nx, ny, d)));
+                }
+                _ => {}
+            }
+        }
+        false
+    }
+
+    /// Where a router sends `p` next: its only way on, or at a junction the
+    /// first way that leads to its sink.
+    fn route(&self, p: &Packet) -> Option<Dir> {
+        let exits = self.exits(p.x, p.y, p.from);
+        match exits[..] {
+            [] => None,
+            [only] => Some(only),
+            _ => exits.into_iter().find(|&d| self.reaches(p.x, p.y, d, p.dest)),
+        }
+    }
+
+    fn rotate(&mut self, x: usize, y: usize, quarters: u8) {
+        if self.game_over || self.cleared || self.paused { return; }
+        if let Some(Tile::Wire { turns, .. }) = self.grid.get_mut(x, y) {
+            *turns = (*turns + quarters) % 4;
+        }
+    }
+
+    fn deliver(&mut self, x: usize, y: usize) {
+        self.delivered += 1;
+        self.total_delivered += 1;
+        self.score += 10 * (self.level + 1);
+        self.flashes.push((x, y, FLASH_TICKS, Ink(Color::Rgb(30, 90, 40), Role::Green)));
+        if self.delivered >= self.target() {
+            self.cleared = true;
+            self.score += 100 * (self.level + 1);
+            self.high_score = self.high_score.max(self.score);
+        }
+    }
+
+    fn drop_packet(&mut self, x: usize, y: usize) {
+        self.lost += 1;
+        self.flashes.push((x, y, FLASH_TICKS, Ink(Color::Rgb(110, 30, 30), Role::Red)));
+        if self.lost >= MAX_LOST {
+            self.finish();
+        }
+    }
+
+    fn finish(&mut self) {
+        self.game_over = true;
+        self.high_score = self.high_score.max(self.score);
+    }
+
+    /// Every packet moves one tile, then each source puts its next one on
+    /// the wire if the wire's connected.
+    fn hop(&mut self) {
+        let mut kept = Vec::with_capacity(self.packets.len());
+        for mut p in std::mem::take(&mut self.packets) {
+            let next = self.route(&p).and_then(|d| self.step(p.x, p.y, d).map(|cell| (cell, d)));
+            match next {
+                None => {
+                    p.stalled += 1;
+                    if p.stalled > PATIENCE {
+                        self.drop_packet(p.x, p.y);
+                    } else {
+                        kept.push(p);
+                    }
+                }
+                Some(((nx, ny), d)) => match self.grid.get(nx, ny).copied() {
+                    Some(Tile::Sink(id, _)) if id == p.dest => self.deliver(nx, ny),
+                    Some(Tile::Wire { .. }) => kept.push(Packet { x: nx, y: ny, from: d.opposite(), stalled: 0, ..p }),
+                    // The wrong sink, or back into a source
+                    _ => self.drop_packet(nx, ny),
+                },
+            }
+        }
+        self.packets = kept;
+
+        for i in 0..self.sources.len() {
+            let (x, y, id, dir) = self.sources[i];
+            if self.queues[id as usize].is_empty() { continue; }
+            let Some((nx, ny)) = self.step(x, y, dir) else { continue };
+            if !matches!(self.grid.get(nx, ny), Some(Tile::Wire { .. })) { continue; }
+            if let Some(dest) = self.queues[id as usize].pop_front() {
+                self.packets.push(Packet { x: nx, y: ny, from: dir.opposite(), dest, stalled: 0 });
+            }
+        }
+    }
+
+    /// Sources take on new packets, staggered so they don't all arrive at once.
+    fn emit(&mut self) {
+        if self.clock < GRACE_TICKS { return; }
+        let every = self.emit_ticks();
+        for i in 0..self.sources.len() {
+            let id = self.sources[i].2 as usize;
+            let offset = id as u32 * every / 3;
+            if !(self.clock - GRACE_TICKS + offset).is_multiple_of(every) { continue; }
+            let routes = &self.routes[id];
+            if routes.is_empty() { continue; }
+            if self.queues[id].len() >= QUEUE_CAP {
+                self.overflowed = true;
+                self.finish();
+                return;
+            }
+            let dest = routes[self.rng.gen_range(0..routes.len())];
+            self.queues[id].push_back(dest);
+        }
+    }
+
+    fn draw_tile(&self, x: usize, y: usize, tile: Tile, art: &mut CellArt, theme: &Theme) {
+        let (cx, cy) = (CELL.0 / 2, CELL.1 / 2);
+        let open = tile.openings();
+        for d in Dir::ALL.into_iter().filter(|d| open & d.bit() != 0) {
+            // Ends that meet a neighbour's are lit
+            let style = Style::default().fg(if self.step(x, y, d).is_some() { theme.ink(LIVE_COLOR) } else { theme.ink(DEAD_COLOR) });
+            match d {
+                Dir::Up => art[0][cx] = ('│', style),
+                Dir::Down => art[CELL.1 - 1][cx] = ('│', style),
+                Dir::Left => (0..cx).for_each(|i| art[cy][i] = ('─', style)),
+                Dir::Right => (cx + 1..CELL.0).for_each(|i| art[cy][i] = ('─', style)),
+            }
+        }
+        art[cy][cx] = match tile {
+            Tile::Empty => ('·', Style::default().fg(theme.paint(Color::Rgb(50, 50, 60), Role::Panel))),
+            Tile::Wire { .. } => (GLYPHS[open as usize], Style::default().fg(theme.paint(Color::Rgb(220, 220, 230), Role::Text)).add_modifier(Modifier::BOLD)),
+            Tile::Source(id, _) => ((b'1' + id) as char, Style::default().fg(theme.paint(Color::Black, Role::Background)).bg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD)),
+            Tile::Sink(id, _) => ((b'a' + id) as char, Style::default().fg(theme.paint(Color::Black, Role::Background)).bg(theme.ink(DEST_COLORS[id as usize])).add_modifier(Modifier::BOLD)),
+        };
+        if let Tile::Source(id, dir) = tile {
+            // Queue pips on the row the wire doesn't use
+            let row = if dir == Dir::Up { CELL.1 - 1 } else { 0 };
+            let queued = self.queues[id as usize].len();
+            let color = if queued + 1 >= QUEUE_CAP { theme.ink(fx::alert_color()) } else { theme.ink(ACCENT) };
+            for (i, cell) in art[row].iter_mut().take(QUEUE_CAP).enumerate() {
+                *cell = (if i < queued { '▮' } else { '▯' }, Style::default().fg(color));
+            }
+        }
+        if let Some(p) = self.packets.iter().find(|p| p.x == x && p.y == y) {
+            art[cy][cx] = ('●', Style::default().fg(theme.ink(DEST_COLORS[p.dest as usize])).add_modifier(Modifier::BOLD));
+        }
+        let flash = self.flashes.iter().find(|f| f.0 == x && f.1 == y).map(|f| f.3);
+        let bg = if self.grid.cursor == (x, y) && !self.game_over { Some(CURSOR_BG) } else { flash };
+        if let Some(bg) = bg {
+            art.iter_mut().flatten().for_each(|(_, style)| *style = style.bg(theme.ink(bg)));
+        }
+    }
+}
+
+pub const INFO: GameInfo = GameInfo {
+    name: "Router",
+    icon: "🔀",
+    author: "RustCade",
+    blurb: "Turn the routers,\ndeliver the packets",
+    description: "Rotate router tiles so packets reach their sinks before the queues overflow",
+    controls: &[
+        ("←↑↓→", "Move cursor"),
+        ("Space / Enter", "Rotate clockwise"),
+        ("Z / X", "Rotate anticlockwise"),
+        ("Click", "Rotate (right-click back)"),
+        ("R", "Restart"),
+        ("P", "Pause"),
+    ],
+    tags: &["puzzle", "network", "grid"],
+    color: ACCENT,
+    border_color: Ink(Color::Rgb(120, 70, 20), Role::Orange),
+};
+
+impl Game for RouterGame {
+    fn info(&self) -> &'static GameInfo {
+        &INFO
+    }
+
+    fn update(&mut self) {
+        if self.paused || self.countdown.tick() { return; }
+        self.flashes.retain_mut(|f| { f.2 = f.2.saturating_sub(1); f.2 > 0 });
+        if self.game_over || self.cleared { return; }
+        self.clock += 1;
+        self.emit();
+        if self.game_over { return; }
+        if self.clock.is_multiple_of(self.hop_ticks()) {
+            self.hop();
+        }
+    }
+
+    fn handle_input(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('r') | KeyCode::Char('R') => self.reset(),
+            KeyCode::Char('p') | KeyCode::Char('P') if !self.game_over && !self.cleared => {
+                self.countdown.toggle_pause(&mut self.paused);
+            }
+            _ if self.game_over => {
+                if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+                    self.reset();
+                }
+            }
+            _ if self.cleared => {
+                if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+                    self.load_level(self.level + 1);
+                }
+            }
+            _ if self.paused || self.countdown.is_running() => {}
+            KeyCode::Up | KeyCode::Down | KeyCode::Left | KeyCode::Right => {
+                self.grid.cursor_key(key.code, true);
+            }
+            KeyCode::Char(' ') | KeyCode::Enter => {
+                let (x, y) = self.grid.cursor;
+                self.rotate(x, y, 1);
+            }
+            KeyCode::Char('z') | KeyCode::Char('Z') | KeyCode::Char('x') | KeyCode::Char('X') | KeyCode::Backspace => {
+                let (x, y) = self.grid.cursor;
+                self.rotate(x, y, 3);
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent) {
+        let quarters = match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => 1,
+            MouseEventKind::Down(MouseButton::Right) => 3,
+            _ => return,
+        };
+        let Some((x, y)) = self.grid.cell_at(self.grid_origin, CELL, event.column, event.row) else { return };
+        if self.game_over || self.cleared || self.paused { return; }
+        self.grid.cursor = (x, y);
+        self.rotate(x, y, quarters);
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = self.title_bar(theme);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(6), Constraint::Length(1)])
+            .split(inner);
+
+        let sep = || Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint)));
+        let lost_color = if self.lost + 1 >= MAX_LOST { theme.ink(fx::alert_color()) } else { theme.paint(Color::Gray, Role::Dim) };
+        let mut status = vec![
+            Span::styled(" 🔀 ", Style::default()),
+            Span::styled(
+                format!("Level {}: {} ", self.level + 1, self.level_name()),
+                Style::default().fg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD),
+            ),
+            sep(),
+            Span::styled(format!("Score: {} ", self.score), Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD)),
+            sep(),
+            Span::styled(format!("Delivered: {}/{} ", self.delivered, self.target()), Style::default().fg(theme.paint(Color::Rgb(120, 230, 120), Role::Green))),
+            sep(),
+            Span::styled(format!("Lost: {}/{} ", self.lost, MAX_LOST), Style::default().fg(lost_color)),
+        ];
+        if self.clock < GRACE_TICKS && !self.game_over {
+            status.push(sep());
+            status.push(Span::styled(
+                format!("Traffic in {}s ", (GRACE_TICKS - self.clock).div_ceil(60)),
+                Style::default().fg(theme.paint(Color::Cyan, Role::Cyan)),
+            ));
+        }
+        frame.render_widget(Paragraph::new(Line::from(status)), chunks[0]);
+
+        let field = chunks[1];
+        let (gw, gh) = ((self.grid.width() * CELL.0) as u16, (self.grid.height() * CELL.1) as u16);
+        let grid_area = Rect::new(
+            field.x + field.width.saturating_sub(gw) / 2,
+            field.y + field.height.saturating_sub(gh) / 2,
+            gw.min(field.width),
+            gh.min(field.height),
+        );
+        self.grid_origin = grid_area.as_position();
+        let lines = self.grid.render(CELL, Style::default(), |x, y, tile, art| self.draw_tile(x, y, *tile, art, theme));
+        frame.render_widget(Paragraph::new(lines), grid_area);
+        self.countdown.render(frame, field, theme);
+
+        let footer = if self.game_over {
+            let why = if self.overflowed { "A queue overflowed!" } else { "Too many packets lost!" };
+            hud::game_over(&format!("{} {} delivered │ ENTER to restart, Esc for menu", why, self.total_delivered), theme)
+        } else if self.cleared {
+            hud::outcome(
+                "📶 NETWORK UP!",
+                theme.paint(Color::Green, Role::Green),
+                &format!("Level {} cleared │ ENTER for the next network", self.level + 1),
+                theme,
+            )
+        } else if self.paused {
+            hud::pause_overlay(frame, field, theme);
+            hud::paused(theme)
+        } else {
+            hud::legend(None, &[("←↑↓→", "Move"), ("Space", "Rotate ↻"), ("Z", "Rotate ↺")], theme)
+        };
+        frame.render_widget(Paragraph::new(footer), chunks[2]);
+    }
+
+    fn get_score(&self) -> u32 { self.score }
+    fn is_game_over(&self) -> bool { self.game_over }
+    fn is_paused(&self) -> bool { self.paused }
+
+    fn reseed(&mut self, rng: GameRng) {
+        self.rng = rng;
+        self.reset();
+    }
+
+    fn script_state(&self) -> Vec<(&'static str, f64)> {
+        let longest = self.queues.iter().map(VecDeque::len).max().unwrap_or(0);
+        vec![
+            ("level", self.level as f64),
+            ("cursor_x", self.grid.cursor.0 as f64),
+            ("cursor_y", self.grid.cursor.1 as f64),
+            ("delivered", self.delivered as f64),
+            ("target", self.target() as f64),
+            ("lost", self.lost as f64),
+            ("in_flight", self.packets.len() as f64),
+            ("longest_queue", longest as f64),
+        ]
+    }
+
+    fn serialize_state(&self) -> Option<Vec<u8>> {
+        if self.game_over { return None; }
+        let mut w = StateWriter::new();
+        w.u32(self.level);
+        w.u32(self.clock);
+        w.u32(self.score);
+        w.u32(self.high_score);
+        w.u32(self.delivered);
+        w.u32(self.total_delivered);
+        w.u32(self.lost);
+        w.bool(self.cleared);
+        for (_, _, tile) in self.grid.iter() {
+            w.u8(match tile { Tile::Wire { turns, .. } => *turns, _ => 0 });
+        }
+        for queue in &self.queues {
+            w.bytes(&queue.iter().copied().collect::<Vec<_>>());
+        }
+        w.u32(self.packets.len() as u32);
+        for p in &self.packets {
+            w.u32(p.x as u32);
+            w.u32(p.y as u32);
+            w.u8(p.from as u8);
+            w.u8(p.dest);
+            w.u32(p.stalled);
+        }
+        Some(w.finish())
+    }
+
+    fn restore_state(&mut self, data: &[u8]) -> bool {
+        let mut r = StateReader::new(data);
+        let (Some(level), Some(clock), Some(score), Some(high_score), Some(delivered), Some(total), Some(lost), Some(cleared)) =
+            (r.u32(), r.u32(), r.u32(), r.u32(), r.u32(), r.u32(), r.u32(), r.bool())
+        else { return false };
+        let mut restored = RouterGame::with_rng(self.rng.clone());
+        restored.load_level(level);
+        let (w, h) = (restored.grid.width(), restored.grid.height());
+        for i in 0..w * h {
+            let Some(t) = r.u8() else { return false };
+            if let Some(Tile::Wire { turns, .. }) = restored.grid.get_mut(i % w, i / w) {
+                *turns = t % 4;
+            }
+        }
+        for queue in &mut restored.queues {
+            let Some(bytes) = r.bytes() else { return false };
+            *queue = bytes.into_iter().filter(|&d| (d as usize) < DEST_COLORS.len()).collect();
+        }
+        let Some(count) = r.u32() else { return false };
+        for _ in 0..count {
+            let (Some(x), Some(y), Some(from), Some(dest), Some(stalled)) = (r.u32(), r.u32(), r.u8(), r.u8(), r.u32())
+            else { return false };
+            let (x, y) = (x as usize, y as usize);
+            if x >= w || y >= h || dest as usize >= DEST_COLORS.len() { return false; }
+            restored.packets.push(Packet { x, y, from: Dir::ALL[from as usize % 4], dest, stalled });
+        }
+
+        restored.clock = clock;
+        restored.score = score;
+        restored.high_score = self.high_score.max(high_score);
+        restored.delivered = delivered;
+        restored.total_delivered = total;
+        restored.lost = lost.min(MAX_LOST - 1);
+        restored.cleared = cleared;
+        // Resume paused, with a countdown before the traffic moves again
+        restored.paused = !cleared;
+        *self = restored;
+        true
+    }
+
+    fn reset(&mut self) {
+        let high_score = self.high_score;
+        *self = RouterGame::with_rng(self.rng.clone());
+        self.high_score = high_score;
+    }
+}
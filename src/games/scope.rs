@@ -0,0 +1,558 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::games::scope_patterns::Pattern;
+use crate::games::{Game, GameInfo};
+use crate::session::{StateReader, StateWriter};
+use crate::ui::braille::BrailleCanvas;
+use crate::ui::fx;
+use crate::ui::hud::{self, Countdown};
+use crate::ui::theme::{Ink, Role, Theme};
+
+/// Ticks before the first step, to find the beat
+const LEAD_IN: f32 = 120.0;
+/// Ticks after the last pulse before the run ends
+const RUN_OUT: f32 = 60.0;
+/// Hit windows either side of a pulse, in ticks (60 a second)
+const PERFECT_WINDOW: f32 = 3.0;
+const GREAT_WINDOW: f32 = 6.0;
+const GOOD_WINDOW: f32 = 10.0;
+/// Simulation ticks one braille dot column of trace covers
+const TICKS_PER_DOT: f32 = 0.5;
+/// The trigger line, as a fraction of the screen from the left
+const TRIGGER_AT: f32 = 0.15;
+/// Pulse half-width, in ticks
+const PULSE_WIDTH: f32 = 2.5;
+/// Height of the idle trace against a pulse's
+const IDLE_AMPLITUDE: f32 = 0.08;
+/// Signal lost to a miss or a trigger with nothing under it, and regained per hit
+const MISS_DRAIN: f32 = 8.0;
+const STRAY_DRAIN: f32 = 4.0;
+const HIT_GAIN: f32 = 2.0;
+/// Ticks a judgement and a trigger flash stay up
+const JUDGEMENT_TICKS: u32 = 30;
+const FLASH_TICKS: u32 = 6;
+const SCREEN_BG: Ink = Ink(Color::Rgb(6, 14, 10), Role::Background);
+const CHANNEL_COLORS: [Ink; 2] = [Ink(Color::Rgb(255, 230, 80), Role::Yellow), Ink(Color::Rgb(80, 220, 255), Role::Cyan)];
+
+#[derive(Clone, Copy, PartialEq)]
+enum Judgement {
+    Perfect,
+    Great,
+    Good,
+    Miss,
+}
+
+impl Judgement {
+    fn points(self) -> u32 {
+        match self {
+            Judgement::Perfect => 300,
+            Judgement::Great => 200,
+            Judgement::Good => 100,
+            Judgement::Miss => 0,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Judgement::Perfect => "PERFECT",
+            Judgement::Great => "GREAT",
+            Judgement::Good => "GOOD",
+            Judgement::Miss => "MISS",
+        }
+    }
+
+    fn color(self) -> Ink {
+        match self {
+            Judgement::Perfect => Ink(Color::Rgb(120, 255, 140), Role::Green),
+            Judgement::Great => Ink(Color::Rgb(120, 200, 255), Role::Blue),
+            Judgement::Good => Ink(Color::Rgb(255, 220, 120), Role::Yellow),
+            Judgement::Miss => fx::alert_color(),
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn from_index(i: u8) -> Option<Judgement> {
+        [Judgement::Perfect, Judgement::Great, Judgement::Good, Judgement::Miss].get(i as usize).copied()
+    }
+}
+
+struct Note {
+    /// Ticks from the start of the run
+    time: f32,
+    channel: u8,
+    judged: Option<Judgement>,
+}
+
+/// Rhythm game on an oscilloscope: pulses scroll along two traces and the
+/// player fires each channel's trigger as a pulse crosses the trigger line.
+pub struct ScopeGame {
+    patterns: Vec<Pattern>,
+    pattern: usize,
+    notes: Vec<Note>,
+    /// Ticks since the run started
+    clock: f32,
+    started: bool,
+    game_over: bool,
+    /// Signal ran out before the end of the pattern
+    failed: bool,
+    paused: bool,
+    countdown: Countdown,
+    score: u32,
+    high_score: u32,
+    combo: u32,
+    max_combo: u32,
+    /// Perfect, great, good and miss counts
+    counts: [u32; 4],
+    /// 0-100; the run fails when it's gone
+    signal: f32,
+    /// Latest judgement, its channel and ticks left on screen
+    last: Option<(Judgement, u8, u32)>,
+    flash: [u32; 2],
+}
+
+impl ScopeGame {
+    pub fn new() -> Self {
+        let mut game = Self {
+            patterns: Pattern::load_all(),
+            pattern: 0,
+            notes: Vec::new(),
+            clock: 0.0,
+            started: false,
+            game_over: false,
+            failed: false,
+            paused: false,
+            countdown: Countdown::default(),
+            score: 0,
+            high_score: 0,
+            combo: 0,
+            max_combo: 0,
+            counts: [0; 4],
+            signal: 100.0,
+            last: None,
+            flash: [0; 2],
+        };
+        game.load_notes();
+        game
+    }
+
+    fn current_pattern(&self) -> &Pattern {
+        &self.patterns[self.pattern]
+    }
+
+    fn load_notes(&mut self) {
+        let tps = self.current_pattern().ticks_per_step();
+        self.notes = self.current_pattern().notes.iter()
+            .map(|&(step, channel)| Note { time: LEAD_IN + step as f32 * tps, channel, judged: None })
+            .collect();
+    }
+
+    fn cycle_pattern(&mut self, step: isize) {
+        let n = self.patterns.len() as isize;
+        self.pattern = (self.pattern as isize + step).rem_euclid(n) as usize;
+        self.load_notes();
+    }
+
+    /// Accuracy so far, in percent of a perfect run over the judged pulses.
+    pub fn accuracy(&self) -> u32 {
+        let judged: u32 = self.counts.iter().sum();
+        if judged == 0 { return 0; }
+        let points = self.counts[0] * 300 + self.counts[1] * 200 + self.counts[2] * 100;
+        points * 100 / (judged * 300)
+    }
+
+    fn multiplier(&self) -> u32 {
+        10 + self.combo.min(30)
+    }
+
+    fn judge(&mut self, idx: usize, judgement: Judgement) {
+        let note = &mut self.notes[idx];
+        note.judged = Some(judgement);
+        self.counts[judgement.index()] += 1;
+        self.last = Some((judgement, note.channel, JUDGEMENT_TICKS));
+        if judgement == Judgement::Miss {
+            self.combo = 0;
+            self.drain(MISS_DRAIN);
+        } else {
+            self.combo += 1;
+            self.max_combo = self.max_combo.max(self.combo);
+            // Combo multiplier in tenths, up to 4×
+            self.score += judgement.points() * self.multiplier() / 10;
+            self.signal = (self.signal + HIT_GAIN).min(100.0);
+        }
+    }
+
+    fn drain(&mut self, amount: f32) {
+        self.signal -= amount;
+        if self.signal <= 0.0 {
+            self.signal = 0.0;
+            self.failed = true;
+            self.finish();
+        }
+    }
+
+    fn finish(&mut self) {
+        self.game_over = true;
+        self.high_score = self.high_score.max(self.score);
+    }
+
+    /// The player fired channel `channel`'s trigger.
+    fn trigger(&mut self, channel: u8) {
+        self.flash[channel as usize] = FLASH_TICKS;
+        let clock = self.clock;
+        let next = self.notes.iter().position(|n| {
+            n.judged.is_none() && n.channel == channel && (n.time - clock).abs() <= GOOD_WINDOW
+        });
+        let Some(idx) = next else {
+            // Nothing under the trigger line
+            self.combo = 0;
+            self.drain(STRAY_DRAIN);
+            return;
+        };
+        let judgement = match (self.notes[idx].time - clock).abs() {
+            d if d <= PERFECT_WINDOW => Judgement::Perfect,
+            d if d <= GREAT_WINDOW => Judgement::Great,
+            _ => Judgement::Good,
+        };
+        self.judge(idx, judgement);
+    }
+
+    /// Height of channel `channel`'s trace at run time `t`, from -1 to 1.
+    fn trace(&self, channel: u8, t: f32) -> f32 {
+        let pattern = self.current_pattern();
+        // The idle trace runs at one cycle a beat
+        let beat = t / (pattern.ticks_per_step() * pattern.steps_per_beat as f32);
+        let mut y = pattern.wave.sample(beat + channel as f32 * 0.25) * IDLE_AMPLITUDE;
+        for note in &self.notes {
+            if note.channel != channel || note.judged.is_some_and(|j| j != Judgement::Miss) { continue; }
+            let d = (note.time - t) / PULSE_WIDTH;
+            if d.abs() < 4.0 {
+                y += (-d * d).exp() * (1.0 - IDLE_AMPLITUDE);
+            }
+        }
+        y
+    }
+
+    fn render_screen(&self, width: usize, height: usize, theme: &Theme) -> Vec<Line<'static>> {
+        let (w, h) = (width, height);
+        if w < 4 || h < 4 { return Vec::new(); }
+        let bg = Style::default().bg(theme.ink(SCREEN_BG));
+        let mut grid: Vec<Vec<(char, Style)>> = vec![vec![(' ', bg); w]; h];
+
+        // Graticule: ten divisions across, eight down
+        let grat = Style::default().fg(theme.paint(Color::Rgb(25, 55, 40), Role::Panel)).bg(theme.ink(SCREEN_BG));
+        for (y, row) in grid.iter_mut().enumerate() {
+            for (x, cell) in row.iter_mut().enumerate() {
+                let on_x = x * 10 % w < 10;
+                let on_y = y * 8 % h < 8;
+                *cell = match (on_x, on_y) {
+                    (true, true) => ('┼', grat),
+                    (true, false) => ('┊', grat),
+                    (false, true) => ('┄', grat),
+                    _ => (' ', bg),
+                };
+            }
+        }
+
+        // Trigger line, lit while a trigger fires
+        let trigger_col = (w as f32 * TRIGGER_AT) as usize;
+        let half = h / 2;
+        for (y, row) in grid.iter_mut().enumerate() {
+            let channel = usize::from(y >= half);
+            let color = if self.flash[channel] > 0 { theme.paint(Color::Rgb(255, 255, 255), Role::Bright) } else { theme.paint(Color::Rgb(200, 60, 60), Role::Red) };
+            row[trigger_col] = ('│', Style::default().fg(color).bg(theme.ink(SCREEN_BG)));
+        }
+
+        // One trace per half of the screen
+        let trigger_dot = trigger_col as i32 * 2 + 1;
+        for channel in 0..2u8 {
+            let mut canvas = BrailleCanvas::new(w, h);
+            let band = canvas.dot_height() / 2;
+            let top = band * channel as i32;
+            let baseline = top + band * 3 / 4;
+            let amplitude = band as f32 * 0.6;
+            let mut prev: Option<(i32, i32)> = None;
+            for xd in 0..canvas.dot_width() {
+                let t = self.clock + (xd - trigger_dot) as f32 * TICKS_PER_DOT;
+                let y = baseline - (self.trace(channel, t) * amplitude) as i32;
+                match prev {
+                    Some((px, py)) => canvas.line(px, py, xd, y),
+                    None => canvas.set(xd, y),
+                }
+                prev = Some((xd, y));
+            }
+            canvas.draw(&mut grid, theme.ink(CHANNEL_COLORS[channel as usize]), None, true);
+        }
+
+        // Channel labels and the latest judgement beside the trigger line
+        let label = |grid: &mut [Vec<(char, Style)>], x: usize, y: usize, text: &str, style: Style| {
+            for (i, ch) in text.chars().enumerate() {
+                if let Some(cell) = grid.get_mut(y).and_then(|row| row.get_mut(x + i)) {
+                    *cell = (ch, style);
+                }
+            }
+        };
+        for (channel, color) in CHANNEL_COLORS.into_iter().enumerate() {
+            let style = Style::default().fg(theme.ink(color)).bg(theme.ink(SCREEN_BG)).add_modifier(Modifier::BOLD);
+            label(&mut grid, 1, channel * half, &format!("CH{}", channel + 1), style);
+        }
+        if let Some((judgement, channel, _)) = self.last {
+            let style = Style::default().fg(theme.ink(judgement.color())).bg(theme.ink(SCREEN_BG)).add_modifier(Modifier::BOLD);
+            let y = channel as usize * half + half / 4;
+            label(&mut grid, trigger_col + 2, y, judgement.label(), style);
+            if self.combo > 1 {
+                label(&mut grid, trigger_col + 2, y + 1, &format!("{} combo", self.combo), style);
+            }
+        }
+
+        grid.into_iter()
+            .map(|row| Line::from(row.into_iter().map(|(ch, style)| Span::styled(String::from(ch), style)).collect::<Vec<_>>()))
+            .collect()
+    }
+
+    /// Pattern picker drawn over the screen before a run.
+    fn render_picker(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let w = 40u16.min(area.width);
+        let h = 7u16.min(area.height);
+        if w < 20 || h < 5 { return; }
+        let rect = Rect::new(area.x + (area.width - w) / 2, area.y + (area.height - h) / 2, w, h);
+        frame.render_widget(Clear, rect);
+        let pattern = self.current_pattern();
+        let text = vec![
+            Line::from(Span::styled(
+                format!("◀ {} ▶", pattern.name),
+                Style::default().fg(theme.paint(Color::Rgb(120, 255, 140), Role::Green)).add_modifier(Modifier::BOLD),
+            )),
+            Line::from(Span::styled(
+                format!("{:.0} bpm · {} pulses · {} wave", pattern.bpm, pattern.notes.len(), pattern.wave.name()),
+                Style::default().fg(theme.paint(Color::Gray, Role::Dim)),
+            )),
+            Line::from(""),
+            Line::from(Span::styled(
+                format!("Pattern {}/{}", self.pattern + 1, self.patterns.len()),
+                Style::default().fg(theme.paint(Color::DarkGray, Role::Faint)),
+            )),
+        ];
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.paint(Color::Rgb(120, 255, 140), Role::Green)))
+            .title(" Beat map ")
+            .style(Style::default().bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
+        frame.render_widget(Paragraph::new(text).alignment(Alignment::Center).block(block), rect);
+    }
+}
+
+pub const INFO: GameInfo = GameInfo {
+    name: "Scope",
+    icon: "📈",
+    author: "RustCade",
+    blurb: "Trigger on the beat\nof the waveform",
+    description: "Fire each channel's trigger as its pulse crosses the line",
+    controls: &[
+        ("F / ←", "Channel 1 trigger"),
+        ("J / →", "Channel 2 trigger"),
+        ("Space", "Start (pick a pattern with ← →)"),
+        ("R", "Restart"),
+        ("P", "Pause"),
+    ],
+    tags: &["rhythm", "music", "timing"],
+    color: Ink(Color::Rgb(120, 255, 140), Role::Green),
+    border_color: Ink(Color::Rgb(40, 120, 60), Role::Green),
+};
+
+impl Game for ScopeGame {
+    fn info(&self) -> &'static GameInfo {
+        &INFO
+    }
+
+    fn update(&mut self) {
+        if self.paused || self.countdown.tick() { return; }
+        self.flash = self.flash.map(|f| f.saturating_sub(1));
+        if let Some((_, _, ticks)) = &mut self.last {
+            *ticks = ticks.saturating_sub(1);
+            if *ticks == 0 { self.last = None; }
+        }
+        if !self.started || self.game_over { return; }
+        self.clock += 1.0;
+        let late = self.clock - GOOD_WINDOW;
+        while let Some(idx) = self.notes.iter().position(|n| n.judged.is_none() && n.time < late) {
+            self.judge(idx, Judgement::Miss);
+            if self.game_over { return; }
+        }
+        let end = self.notes.last().map_or(0.0, |n| n.time) + RUN_OUT;
+        if self.clock >= end {
+            self.finish();
+        }
+    }
+
+    fn handle_input(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('r') | KeyCode::Char('R') => self.reset(),
+            KeyCode::Char('p') | KeyCode::Char('P') if self.started && !self.game_over => {
+                self.countdown.toggle_pause(&mut self.paused);
+            }
+            _ if self.game_over => {
+                if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+                    self.reset();
+                }
+            }
+            _ if !self.started => match key.code {
+                KeyCode::Left | KeyCode::Up => self.cycle_pattern(-1),
+                KeyCode::Right | KeyCode::Down => self.cycle_pattern(1),
+                KeyCode::Enter | KeyCode::Char(' ') => self.started = true,
+                _ => {}
+            },
+            _ if self.paused || self.countdown.is_running() => {}
+            KeyCode::Char('f') | KeyCode::Char('F') | KeyCode::Left => self.trigger(0),
+            KeyCode::Char('j') | KeyCode::Char('J') | KeyCode::Right => self.trigger(1),
+            _ => {}
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = self.title_bar(theme);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(8), Constraint::Length(1)])
+            .split(inner);
+
+        let sep = || Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint)));
+        let signal_color = if self.signal < 30.0 { theme.ink(fx::alert_color()) } else { theme.paint(Color::Rgb(120, 255, 140), Role::Green) };
+        let filled = (self.signal / 10.0).round().clamp(0.0, 10.0) as usize;
+        let pattern = self.current_pattern();
+        let status = Line::from(vec![
+            Span::styled(" 📈 ", Style::default()),
+            Span::styled(format!("{} ", pattern.name), Style::default().fg(theme.paint(Color::Rgb(120, 255, 140), Role::Green)).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{:.0} bpm ", pattern.bpm), Style::default().fg(theme.paint(Color::Gray, Role::Dim))),
+            sep(),
+            Span::styled(format!("Score: {} ", self.score), Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD)),
+            sep(),
+            Span::styled(format!("Combo: {} ×{:.1} ", self.combo, self.multiplier() as f32 / 10.0), Style::default().fg(theme.paint(Color::Rgb(120, 200, 255), Role::Blue))),
+            sep(),
+            Span::styled(format!("Accuracy: {}% ", self.accuracy()), Style::default().fg(theme.paint(Color::Cyan, Role::Cyan))),
+            sep(),
+            Span::styled(format!("Signal {}{} ", "█".repeat(filled), "░".repeat(10 - filled)), Style::default().fg(signal_color)),
+        ]);
+        frame.render_widget(Paragraph::new(status), chunks[0]);
+
+        let lines = self.render_screen(chunks[1].width as usize, chunks[1].height as usize, theme);
+        frame.render_widget(Paragraph::new(lines), chunks[1]);
+        self.countdown.render(frame, chunks[1], theme);
+        if !self.started {
+            self.render_picker(frame, chunks[1], theme);
+        }
+
+        let [perfect, great, good, miss] = self.counts;
+        let footer = if self.game_over && self.failed {
+            hud::game_over("Signal lost! Press ENTER to restart, Esc for menu", theme)
+        } else if self.game_over {
+            hud::outcome(
+                "🎵 PATTERN COMPLETE!",
+                theme.paint(Color::Green, Role::Green),
+                &format!(
+                    "{}% │ {} perfect · {} great · {} good · {} miss │ best combo {} │ ENTER to restart",
+                    self.accuracy(), perfect, great, good, miss, self.max_combo,
+                ),
+                theme,
+            )
+        } else if self.paused {
+            hud::pause_overlay(frame, chunks[1], theme);
+            hud::paused(theme)
+        } else if !self.started {
+            hud::menu_legend(Some("SPACE Start"), &[("←→", "Pattern")], theme)
+        } else {
+            hud::legend(None, &[("F ←", "CH1 trigger"), ("J →", "CH2 trigger")], theme)
+        };
+        frame.render_widget(Paragraph::new(footer), chunks[2]);
+    }
+
+    fn get_score(&self) -> u32 { self.score }
+    fn is_game_over(&self) -> bool { self.game_over }
+    fn is_paused(&self) -> bool { self.paused }
+
+    fn script_state(&self) -> Vec<(&'static str, f64)> {
+        // Ticks until each channel's next pulse reaches the trigger line
+        let next = |channel: u8| {
+            self.notes.iter()
+                .find(|n| n.judged.is_none() && n.channel == channel)
+                .map_or(-1.0, |n| (n.time - self.clock) as f64)
+        };
+        vec![
+            ("clock", self.clock as f64),
+            ("started", self.started as u8 as f64),
+            ("next_ch1", next(0)),
+            ("next_ch2", next(1)),
+            ("combo", self.combo as f64),
+            ("signal", self.signal as f64),
+            ("accuracy", self.accuracy() as f64),
+        ]
+    }
+
+    fn serialize_state(&self) -> Option<Vec<u8>> {
+        if !self.started || self.game_over { return None; }
+        let mut w = StateWriter::new();
+        w.string(&self.current_pattern().name);
+        w.f32(self.clock);
+        w.u32(self.score);
+        w.u32(self.high_score);
+        w.u32(self.combo);
+        w.u32(self.max_combo);
+        w.f32(self.signal);
+        w.u32(self.notes.len() as u32);
+        for note in &self.notes {
+            w.u8(note.judged.map_or(u8::MAX, |j| j as u8));
+        }
+        Some(w.finish())
+    }
+
+    fn restore_state(&mut self, data: &[u8]) -> bool {
+        let mut r = StateReader::new(data);
+        let (Some(name), Some(clock), Some(score), Some(high_score), Some(combo), Some(max_combo), Some(signal), Some(count)) =
+            (r.string(), r.f32(), r.u32(), r.u32(), r.u32(), r.u32(), r.f32(), r.u32())
+        else { return false };
+        let Some(pattern) = self.patterns.iter().position(|p| p.name == name) else { return false };
+        if count as usize != self.patterns[pattern].notes.len() { return false; }
+        let mut judged = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let Some(j) = r.u8() else { return false };
+            judged.push(Judgement::from_index(j));
+        }
+
+        self.reset();
+        self.pattern = pattern;
+        self.load_notes();
+        for (note, j) in self.notes.iter_mut().zip(judged) {
+            note.judged = j;
+            if let Some(j) = j {
+                self.counts[j.index()] += 1;
+            }
+        }
+        self.clock = clock;
+        self.score = score;
+        self.high_score = self.high_score.max(high_score);
+        self.combo = combo;
+        self.max_combo = max_combo;
+        self.signal = signal.clamp(1.0, 100.0);
+        self.started = true;
+        // Resume paused, with a countdown to find the beat again
+        self.paused = true;
+        true
+    }
+
+    fn reset(&mut self) {
+        let high_score = self.high_score;
+        let pattern = self.pattern;
+        *self = ScopeGame::new();
+        self.high_score = high_score;
+        self.pattern = pattern.min(self.patterns.len() - 1);
+        self.load_notes();
+    }
+}
@@ -0,0 +1,164 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::scores::data_path;
+
+const FILE_EXT: &str = "txt";
+/// Longest beat map a pattern file can describe, in steps
+const MAX_STEPS: usize = 4096;
+
+/// Shape of the idle trace between pulses.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Wave {
+    Sine,
+    Square,
+    Saw,
+    Noise,
+}
+
+impl Wave {
+    pub fn name(self) -> &'static str {
+        match self {
+            Wave::Sine => "sine",
+            Wave::Square => "square",
+            Wave::Saw => "saw",
+            Wave::Noise => "noise",
+        }
+    }
+
+    fn parse(name: &str) -> Option<Wave> {
+        [Wave::Sine, Wave::Square, Wave::Saw, Wave::Noise].into_iter().find(|w| w.name() == name)
+    }
+
+    /// The trace at `phase` (cycles), from -1 to 1.
+    pub fn sample(self, phase: f32) -> f32 {
+        let frac = phase.rem_euclid(1.0);
+        match self {
+            Wave::Sine => (phase * std::f32::consts::TAU).sin(),
+            Wave::Square => if frac < 0.5 { 1.0 } else { -1.0 },
+            Wave::Saw => frac * 2.0 - 1.0,
+            Wave::Noise => {
+                // Cheap hash of the step, so the noise scrolls with the trace
+                let n = ((phase * 8.0).floor() as i32 as u32).wrapping_mul(2_654_435_761);
+                (n >> 16) as f32 / 32_768.0 - 1.0
+            }
+        }
+    }
+}
+
+/// A beat map for the scope: tempo, trace shape and the steps that fire a
+/// pulse on each channel.
+///
+/// ```text
+/// ; comments start with a semicolon
+/// bpm 120
+/// steps 4       steps per beat
+/// wave sine     sine, square, saw or noise
+/// 1...1...2...1.2.
+/// 3.......1.1.2...
+/// ```
+///
+/// `1` pulses channel 1, `2` channel 2 and `3` both; anything else is a
+/// rest. Lines run on from one to the next.
+#[derive(Clone)]
+pub struct Pattern {
+    pub name: String,
+    pub bpm: f32,
+    pub steps_per_beat: u32,
+    pub wave: Wave,
+    /// (step, channel) for every pulse, in order
+    pub notes: Vec<(usize, u8)>,
+}
+
+const BUILTIN: [(&str, &str); 4] = [
+    ("Calibration", "\
+bpm 100
+wave sine
+1...1...1...1...
+2...2...2...2...
+1...2...1...2...
+1.1.2...1.1.2...
+3.......3......."),
+    ("Square Dance", "\
+bpm 120
+wave square
+1...2...1...2...
+1.1.2...1.1.2...
+1...2.2.1...2.2.
+3...1.2.3...2.1.
+1.2.1.2.1.2.3..."),
+    ("Sawtooth Sync", "\
+bpm 132
+wave saw
+1..1..1.2..2..2.
+1..1..2.1..2..3.
+12..12..21..21..
+1.2.2.1.1.2.2.1.
+3..3..3.1.2.1.2."),
+    ("Noise Floor", "\
+bpm 144
+wave noise
+1.1.2...1.1.2...
+1.2.1.2.1.2.1.2.
+3...3...1.1.2.2.
+12121212..3...3.
+1.2..21.2.1..12.
+3.3.3.3.3......."),
+];
+
+impl Pattern {
+    pub fn parse(name: &str, text: &str) -> Option<Pattern> {
+        let mut pattern = Pattern { name: name.to_string(), bpm: 120.0, steps_per_beat: 4, wave: Wave::Sine, notes: Vec::new() };
+        let mut step = 0;
+        for line in text.lines() {
+            let line = line.split(';').next().unwrap_or("").trim();
+            let mut words = line.split_whitespace();
+            match (words.next(), words.next()) {
+                (Some("bpm"), Some(v)) => pattern.bpm = v.parse::<f32>().ok()?.clamp(40.0, 240.0),
+                (Some("steps"), Some(v)) => pattern.steps_per_beat = v.parse::<u32>().ok()?.clamp(1, 8),
+                (Some("wave"), Some(v)) => pattern.wave = Wave::parse(v)?,
+                _ => {
+                    for c in line.chars().filter(|c| !c.is_whitespace()) {
+                        if step >= MAX_STEPS { break; }
+                        match c {
+                            '1' => pattern.notes.push((step, 0)),
+                            '2' => pattern.notes.push((step, 1)),
+                            '3' => pattern.notes.extend([(step, 0), (step, 1)]),
+                            _ => {}
+                        }
+                        step += 1;
+                    }
+                }
+            }
+        }
+        if pattern.notes.is_empty() { return None; }
+        Some(pattern)
+    }
+
+    /// Simulation ticks (60 a second) from one step to the next.
+    pub fn ticks_per_step(&self) -> f32 {
+        3600.0 / (self.bpm * self.steps_per_beat as f32)
+    }
+
+    /// The built-in patterns, then any from the `patterns` folder sorted by
+    /// file name.
+    pub fn load_all() -> Vec<Pattern> {
+        let mut patterns: Vec<Pattern> = BUILTIN.iter().filter_map(|(name, text)| Pattern::parse(name, text)).collect();
+        let Ok(entries) = fs::read_dir(pattern_dir()) else { return patterns };
+        let mut files: Vec<PathBuf> = entries.flatten()
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|e| e == FILE_EXT))
+            .collect();
+        files.sort();
+        patterns.extend(files.iter().filter_map(|p| Pattern::parse(&file_stem(p), &fs::read_to_string(p).ok()?)));
+        patterns
+    }
+}
+
+fn file_stem(path: &Path) -> String {
+    path.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default()
+}
+
+fn pattern_dir() -> PathBuf {
+    data_path("patterns")
+}
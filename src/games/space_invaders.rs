@@ -1,38 +1,60 @@
 use crossterm::event::{KeyCode, KeyEvent};
+use rand::Rng;
 use ratatui::prelude::*;
 use ratatui::widgets::*;
 
 use std::collections::HashMap;
 
-use crate::games::Game;
+use crate::audio::{self, AudioEvent};
+use crate::games::invader_waves::{AlienKind, Wave, WaveEditor, MAX_COLS, MAX_PACE, MAX_ROWS, MIN_PACE};
+use crate::games::{Game, GameInfo};
+use crate::rng::GameRng;
+use crate::scores::format_flags;
+use crate::session::{StateReader, StateWriter};
+use crate::ui::braille::BrailleCanvas;
+use crate::ui::fx::{self, Emitter, Particles};
+use crate::ui::starfield::Starfield;
+use crate::ui::theme::{Ink, Role, Theme};
 
 const PLAYER_SPEED: f32 = 1.5;
 const PLAYER_BULLET_SPEED: f32 = 0.8;
 const ALIEN_BULLET_SPEED: f32 = 0.4;
 const MAX_PLAYER_BULLETS: usize = 3;
 const MAX_ALIEN_BULLETS: usize = 5;
-const ALIEN_COLS: usize = 11;
-const ALIEN_ROWS: usize = 5;
 const ALIEN_H_SPACING: f32 = 4.5;
 const ALIEN_V_SPACING: f32 = 3.5;
 const SHIELD_COUNT: usize = 4;
 const SHIELD_WIDTH: f32 = 6.0;
 const SHIELD_HEIGHT: f32 = 3.0;
+/// Sparks thrown back up the bullet's path when an alien pops
+const ALIEN_POP: Emitter = Emitter::spray(10, 0.7, 14, -std::f32::consts::FRAC_PI_2, 2.4);
+const PLAYER_HIT: Emitter = Emitter::burst(18, 0.5, 30);
+/// With invisible invaders, ticks they show for after each march step
+const MARCH_FLASH_TICKS: u64 = 4;
+/// Stars falling slowly past, as though the cannon were climbing
+const STARS: Starfield = Starfield::new(6.0, (0.0, 0.03)).with_seed(0x1A7);
 
-#[derive(Clone, Copy, PartialEq)]
-enum AlienKind {
-    Top,    // small, 30 pts
-    Mid,    // medium, 20 pts
-    Bot,    // large, 10 pts
+/// Hard-mode toggles, each adding a share to the final score. Stored with
+/// the score as bit flags, in the order of `scores::flag_labels`.
+#[derive(Clone, Copy, Default)]
+struct Modifiers {
+    no_shields: bool,
+    invisible: bool,
+    fast_bombs: bool,
 }
 
-impl AlienKind {
-    fn points(&self) -> u32 {
-        match self {
-            AlienKind::Top => 30,
-            AlienKind::Mid => 20,
-            AlienKind::Bot => 10,
-        }
+impl Modifiers {
+    fn bits(&self) -> u32 {
+        self.no_shields as u32 | (self.invisible as u32) << 1 | (self.fast_bombs as u32) << 2
+    }
+
+    fn from_bits(bits: u32) -> Self {
+        Modifiers { no_shields: bits & 1 != 0, invisible: bits & 2 != 0, fast_bombs: bits & 4 != 0 }
+    }
+
+    /// Final score multiplier, in percent.
+    fn score_percent(&self) -> u32 {
+        100 + 25 * self.no_shields as u32 + 50 * self.invisible as u32 + 25 * self.fast_bombs as u32
     }
 }
 
@@ -129,7 +151,8 @@ pub struct SpaceInvaders {
     alien_move_interval: u64,
     alien_fire_timer: u64,
     score: u32,
-    high_score: u32,
+    /// Top of the shared Invaders table, kept in sync by the app
+    best: u32,
     lives: u32,
     level: u32,
     game_over: bool,
@@ -137,7 +160,19 @@ pub struct SpaceInvaders {
     tick: u64,
     field_width: f32,
     field_height: f32,
-    rng_state: u32,
+    rng: GameRng,
+    particles: Particles,
+    show_hitboxes: bool,
+    modifiers: Modifiers,
+    reduced_motion: bool,
+    /// Wave files from the `waves` folder, played in turn; empty plays
+    /// the classic formation every wave
+    waves: Vec<Wave>,
+    /// March and bombing pace of the wave in play
+    march_pace: f32,
+    fire_pace: f32,
+    /// Formation editor, open over the field
+    editor: Option<WaveEditor>,
 }
 
 impl SpaceInvaders {
@@ -156,7 +191,7 @@ impl SpaceInvaders {
             alien_move_interval: 30,
             alien_fire_timer: 0,
             score: 0,
-            high_score: 0,
+            best: 0,
             lives: 3,
             level: 1,
             game_over: false,
@@ -164,34 +199,103 @@ impl SpaceInvaders {
             tick: 0,
             field_width: fw,
             field_height: fh,
-            rng_state: 12345,
+            rng: GameRng::from_entropy(),
+            particles: Particles::new(99),
+            show_hitboxes: false,
+            modifiers: Modifiers::default(),
+            reduced_motion: false,
+            waves: Wave::load_all(),
+            march_pace: 1.0,
+            fire_pace: 1.0,
+            editor: None,
         };
         s.init_aliens();
         s.init_shields();
         s
     }
 
-    fn cheap_rand(&mut self) -> u32 {
-        self.rng_state ^= self.rng_state << 13;
-        self.rng_state ^= self.rng_state >> 17;
-        self.rng_state ^= self.rng_state << 5;
-        self.rng_state
+    /// Holds the starfield still.
+    pub fn set_reduced_motion(&mut self, on: bool) {
+        self.reduced_motion = on;
+    }
+
+    /// Best score on the shared Invaders table.
+    pub fn set_best(&mut self, best: u32) {
+        self.best = best;
+    }
+
+    /// Waves fully cleared this game, recorded alongside the score.
+    pub fn waves_cleared(&self) -> u32 {
+        self.level - 1
+    }
+
+    /// Modifier bit flags to store with the score; a campaign of wave
+    /// files is flagged too.
+    pub fn modifier_flags(&self) -> u32 {
+        self.modifiers.bits() | (!self.waves.is_empty() as u32) << 3
+    }
+
+    /// Modifiers and the editor are only open before the first point or
+    /// after game over.
+    fn between_games(&self) -> bool {
+        self.game_over || (self.score == 0 && self.level == 1)
+    }
+
+    /// Modifiers can only change before the first point or after game over;
+    /// changing one starts a fresh game.
+    fn toggle_modifier(&mut self, key: char) {
+        if !self.between_games() { return; }
+        let m = &mut self.modifiers;
+        match key {
+            '1' => m.no_shields = !m.no_shields,
+            '2' => m.invisible = !m.invisible,
+            '3' => m.fast_bombs = !m.fast_bombs,
+            _ => return,
+        }
+        self.reset();
+    }
+
+    /// Open the formation editor on the first wave file, or a new one.
+    fn open_editor(&mut self) {
+        self.editor = Some(WaveEditor::open(&self.waves, (!self.waves.is_empty()).then_some(0)));
+    }
+
+    /// Lose, applying the modifiers' bonus to the final score.
+    fn end_game(&mut self) {
+        if self.game_over { return; }
+        self.game_over = true;
+        self.score = self.score * self.modifiers.score_percent() / 100;
+    }
+
+    /// The wave for the current level: the wave files in turn, round
+    /// again after the last, or the classic formation without any.
+    fn current_wave(&self) -> Wave {
+        match self.waves.len() {
+            0 => Wave::classic(),
+            n => self.waves[(self.level as usize - 1) % n].clone(),
+        }
+    }
+
+    /// Top left alien's centre and the spacing for a `cols` × `rows`
+    /// formation, squeezed to fit a small field.
+    fn formation(&self, cols: usize, rows: usize) -> (f32, f32, f32, f32) {
+        let h_spacing = ALIEN_H_SPACING.min((self.field_width - 8.0) / (cols.max(2) - 1) as f32).max(1.0);
+        let v_spacing = ALIEN_V_SPACING.min(self.field_height * 0.5 / rows.max(1) as f32).max(1.0);
+        ((self.field_width - (cols as f32 - 1.0) * h_spacing) / 2.0, 3.0, h_spacing, v_spacing)
     }
 
     fn init_aliens(&mut self) {
+        let wave = self.current_wave();
+        let (start_x, start_y, h_spacing, v_spacing) = self.formation(wave.columns(), wave.rows.len());
+        self.march_pace = wave.speed;
+        self.fire_pace = wave.fire;
         self.aliens.clear();
-        let start_x = (self.field_width - (ALIEN_COLS as f32 - 1.0) * ALIEN_H_SPACING) / 2.0;
-        let start_y = 3.0;
-        for row in 0..ALIEN_ROWS {
-            let kind = match row {
-                0 => AlienKind::Top,
-                1 | 2 => AlienKind::Mid,
-                _ => AlienKind::Bot,
-            };
-            for col in 0..ALIEN_COLS {
+        for (row, cells) in wave.rows.iter().enumerate() {
+            for (col, kind) in cells.iter().enumerate() {
+                let Some(kind) = *kind else { continue };
                 self.aliens.push(Alien {
-                    x: start_x + col as f32 * ALIEN_H_SPACING,
-                    y: start_y + row as f32 * ALIEN_V_SPACING,
+                    x: start_x + col as f32 * h_spacing,
+                    y: start_y + row as f32 * v_spacing,
                     kind,
                     alive: true,
                 });
@@ -201,6 +305,7 @@ impl SpaceInvaders {
 
     fn init_shields(&mut self) {
         self.shields.clear();
+        if self.modifiers.no_shields { return; }
         let shield_y = self.field_height - 8.0;
         let total_w = SHIELD_COUNT as f32 * SHIELD_WIDTH + (SHIELD_COUNT as f32 - 1.0) * 8.0;
         let start_x = (self.field_width - total_w) / 2.0;
@@ -233,8 +338,8 @@ impl SpaceInvaders {
 
         // Speed up as fewer aliens remain
         let alive = self.aliens.iter().filter(|a| a.alive).count();
-        let total = ALIEN_ROWS * ALIEN_COLS;
-        self.alien_move_interval = if alive <= 1 {
+        let total = self.aliens.len();
+        let interval = if alive <= 1 {
             3
         } else if alive <= total / 8 {
             5
@@ -245,6 +350,7 @@ impl SpaceInvaders {
         } else {
             (22u64).saturating_sub(self.level as u64 * 2).max(6)
         };
+        self.alien_move_interval = ((interval as f32 / self.march_pace).round() as u64).max(2);
 
         if self.alien_move_timer >= self.alien_move_interval {
             self.alien_move_timer = 0;
@@ -280,31 +386,28 @@ impl SpaceInvaders {
 
         // Alien shooting
         self.alien_fire_timer += 1;
-        let fire_interval = (60u64).saturating_sub(self.level as u64 * 5).max(15);
+        let fire_interval = (60u64).saturating_sub(self.level as u64 * 5).max(15) as f32 / self.fire_pace;
+        let fire_interval = (fire_interval.round() as u64).max(5);
         if self.alien_fire_timer >= fire_interval && self.alien_bullets.len() < MAX_ALIEN_BULLETS {
             self.alien_fire_timer = 0;
-            // Pick a random alive alien from the bottom of each column
+            // Pick a random alive alien from the bottom of each column;
+            // a column's aliens march together, so they share an x
             let mut bottom_aliens: Vec<usize> = Vec::new();
-            for col in 0..ALIEN_COLS {
-                let mut lowest: Option<usize> = None;
-                for (i, alien) in self.aliens.iter().enumerate() {
-                    if !alien.alive { continue; }
-                    let start_x = (self.field_width - (ALIEN_COLS as f32 - 1.0) * ALIEN_H_SPACING) / 2.0;
-                    let acol = ((alien.x - start_x) / ALIEN_H_SPACING).round() as usize;
-                    if acol == col {
-                        if lowest.is_none() || alien.y > self.aliens[lowest.unwrap()].y {
-                            lowest = Some(i);
-                        }
-                    }
-                }
-                if let Some(idx) = lowest {
-                    bottom_aliens.push(idx);
+            for (i, alien) in self.aliens.iter().enumerate() {
+                if !alien.alive { continue; }
+                match bottom_aliens.iter_mut().find(|j| (self.aliens[**j].x - alien.x).abs() < 0.5) {
+                    Some(j) if alien.y > self.aliens[*j].y => *j = i,
+                    Some(_) => {}
+                    None => bottom_aliens.push(i),
                 }
             }
             if !bottom_aliens.is_empty() {
-                let pick = self.cheap_rand() as usize % bottom_aliens.len();
+                let pick = self.rng.gen_range(0..bottom_aliens.len());
                 let alien = &self.aliens[bottom_aliens[pick]];
-                let speed = ALIEN_BULLET_SPEED + self.level as f32 * 0.03;
+                let mut speed = ALIEN_BULLET_SPEED + self.level as f32 * 0.03;
+                if self.modifiers.fast_bombs {
+                    speed *= 2.0;
+                }
                 self.alien_bullets.push(Bullet {
                     x: alien.x,
                     y: alien.y + 1.0,
@@ -328,6 +431,8 @@ impl SpaceInvaders {
                 if dx < 2.0 && dy < 1.5 {
                     alien.alive = false;
                     self.score += alien.kind.points();
+                    self.particles.emit(&ALIEN_POP, alien.x, alien.y, &[alien.kind.color(), Ink(Color::Rgb(255, 255, 200), Role::Bright)]);
+                    audio::emit(AudioEvent::Explosion);
                     bullets_remove.push(bi);
                     break;
                 }
@@ -389,33 +494,28 @@ impl SpaceInvaders {
             let dy = (bullet.y - py).abs();
             if dx < 2.5 && dy < 1.2 {
                 bullets_remove.push(bi);
+                self.particles.emit(&PLAYER_HIT, self.player_x, py, fx::FIRE);
+                audio::emit(AudioEvent::Explosion);
                 self.lives = self.lives.saturating_sub(1);
-                if self.lives == 0 {
-                    self.game_over = true;
-                    if self.score > self.high_score {
-                        self.high_score = self.score;
-                    }
-                }
             }
         }
+        if self.lives == 0 {
+            self.end_game();
+        }
         for &i in bullets_remove.iter().rev() {
             if i < self.alien_bullets.len() { self.alien_bullets.remove(i); }
         }
 
         // Aliens reaching bottom
-        for alien in &self.aliens {
-            if alien.alive && alien.y >= self.field_height - 4.0 {
-                self.game_over = true;
-                if self.score > self.high_score {
-                    self.high_score = self.score;
-                }
-                break;
-            }
+        if self.aliens.iter().any(|a| a.alive && a.y >= self.field_height - 4.0) {
+            self.end_game();
         }
 
         // All aliens dead = win level
         if self.aliens.iter().all(|a| !a.alive) {
             self.level += 1;
+            audio::emit(AudioEvent::LevelUp);
+            self.particles.clear();
             self.init_aliens();
             self.init_shields();
             self.player_bullets.clear();
@@ -554,7 +654,7 @@ impl SpaceInvaders {
         }
     }
 
-    fn render_field(&self, width: usize, height: usize) -> Vec<Line<'static>> {
+    fn render_field(&self, width: usize, height: usize, theme: &Theme) -> Vec<Line<'static>> {
         let w = width;
         let h = height;
         let bw = (w * 2) as i32;
@@ -562,26 +662,24 @@ impl SpaceInvaders {
         let bsx = bw as f32 / self.field_width;
         let bsy = bh as f32 / self.field_height;
 
-        let bg = Color::Rgb(0, 0, 5);
+        let bg = theme.paint(Color::Rgb(0, 0, 5), Role::Background);
         let mut grid: Vec<Vec<(char, Style)>> =
             vec![vec![(' ', Style::default().bg(bg)); w]; h];
 
+        STARS.draw(&mut grid, bg, if self.reduced_motion { 0 } else { self.tick }, theme);
+
         let anim_frame = (self.tick / 15) % 2 == 0;
 
         // ── Aliens ─────────────────────────────────────────────────────
+        let hidden = self.modifiers.invisible && !self.game_over && self.alien_move_timer >= MARCH_FLASH_TICKS;
         for alien in &self.aliens {
-            if !alien.alive { continue; }
+            if !alien.alive || hidden { continue; }
             let mut amap: HashMap<(usize, usize), u8> = HashMap::new();
             let cx = (alien.x * bsx) as i32;
             let cy = (alien.y * bsy) as i32;
             Self::render_alien_sprite(&mut amap, cx, cy, alien.kind, anim_frame, bw, bh);
 
-            let color = match alien.kind {
-                AlienKind::Top => Color::Rgb(255, 80, 80),
-                AlienKind::Mid => Color::Rgb(80, 255, 150),
-                AlienKind::Bot => Color::Rgb(200, 180, 255),
-            };
-            Self::write_layer(&mut grid, &amap, w, h, color, bg, false);
+            Self::write_layer(&mut grid, &amap, w, h, theme.ink(alien.kind.color()), bg, false);
         }
 
         // ── Shields ────────────────────────────────────────────────────
@@ -596,7 +694,7 @@ impl SpaceInvaders {
                     }
                 }
             }
-            Self::write_layer(&mut grid, &smap, w, h, Color::Rgb(40, 200, 40), bg, false);
+            Self::write_layer(&mut grid, &smap, w, h, theme.paint(Color::Rgb(40, 200, 40), Role::Green), bg, false);
         }
 
         // ── Player bullets ─────────────────────────────────────────────
@@ -607,7 +705,7 @@ impl SpaceInvaders {
             for dy in 0..3 {
                 Self::set_dot(&mut bmap, bx, by + dy, bw, bh);
             }
-            Self::write_layer(&mut grid, &bmap, w, h, Color::Rgb(255, 255, 200), bg, true);
+            Self::write_layer(&mut grid, &bmap, w, h, theme.paint(Color::Rgb(255, 255, 200), Role::Yellow), bg, true);
         }
 
         // ── Alien bullets ──────────────────────────────────────────────
@@ -624,26 +722,33 @@ impl SpaceInvaders {
             for &(dx, dy) in &zigzag {
                 Self::set_dot(&mut bmap, bx + dx, by + dy, bw, bh);
             }
-            Self::write_layer(&mut grid, &bmap, w, h, Color::Rgb(255, 100, 100), bg, true);
+            Self::write_layer(&mut grid, &bmap, w, h, theme.paint(Color::Rgb(255, 100, 100), Role::Red), bg, true);
         }
 
+        // ── Explosions ─────────────────────────────────────────────────
+        self.particles.draw(&mut grid, (bsx, bsy), 1.0, theme);
+
         // ── Player ship ────────────────────────────────────────────────
         if !self.game_over {
             let mut pmap: HashMap<(usize, usize), u8> = HashMap::new();
             let px = (self.player_x * bsx) as i32;
             let py = (self.player_y() * bsy) as i32;
             Self::render_player_ship(&mut pmap, px, py, bw, bh);
-            Self::write_layer(&mut grid, &pmap, w, h, Color::Rgb(80, 255, 80), bg, true);
+            Self::write_layer(&mut grid, &pmap, w, h, theme.paint(Color::Rgb(80, 255, 80), Role::Green), bg, true);
         }
 
         // ── Ground line ────────────────────────────────────────────────
         let ground_y = h.saturating_sub(1);
         if ground_y < h {
             for x in 0..w {
-                grid[ground_y][x] = ('\u{2500}', Style::default().fg(Color::Rgb(40, 80, 40)).bg(bg));
+                grid[ground_y][x] = ('\u{2500}', Style::default().fg(theme.paint(Color::Rgb(40, 80, 40), Role::Faint)).bg(bg));
             }
         }
 
+        if self.show_hitboxes {
+            self.draw_hitboxes(&mut grid, bsx, bsy, theme);
+        }
+
         grid.into_iter()
             .map(|row| {
                 let spans: Vec<Span<'static>> = row
@@ -656,9 +761,138 @@ impl SpaceInvaders {
     }
 }
 
+impl SpaceInvaders {
+    /// The formation editor in place of the field: the whole grid laid out
+    /// as the game lays out a wave, gaps as faint dots, and the cursor
+    /// boxed.
+    fn render_editor(&self, frame: &mut Frame, chunks: &[Rect], theme: &Theme) {
+        let Some(editor) = &self.editor else { return };
+        let wave = &editor.wave;
+        let mut status = Line::from(vec![
+            Span::styled(" \u{1f47e} Wave editor: ", Style::default().fg(theme.paint(Color::Rgb(80, 255, 80), Role::Green)).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{} ", editor.title()), Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD)),
+            Span::styled(" | ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+            Span::styled(format!("March ×{:.2} ", wave.speed), Style::default().fg(theme.paint(Color::Cyan, Role::Cyan))),
+            Span::styled(" | ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+            Span::styled(format!("Bombs ×{:.2} ", wave.fire), Style::default().fg(theme.paint(Color::Rgb(255, 100, 100), Role::Red))),
+            Span::styled(" | ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+            Span::styled(format!("Aliens: {} ", wave.alien_count()), Style::default().fg(theme.paint(Color::Green, Role::Green))),
+        ]);
+        if let Some(message) = &editor.message {
+            status.spans.push(Span::styled(" | ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))));
+            status.spans.push(Span::styled(message.clone(), Style::default().fg(theme.paint(Color::Rgb(255, 160, 60), Role::Orange))));
+        }
+        frame.render_widget(Paragraph::new(status), chunks[0]);
+
+        let (w, h) = (chunks[1].width as usize, chunks[1].height as usize);
+        let (bw, bh) = ((w * 2) as i32, (h * 4) as i32);
+        let (bsx, bsy) = (bw as f32 / self.field_width, bh as f32 / self.field_height);
+        let bg = theme.paint(Color::Rgb(0, 0, 5), Role::Background);
+        let mut grid: Vec<Vec<(char, Style)>> = vec![vec![(' ', Style::default().bg(bg)); w]; h];
+        let (x0, y0, h_spacing, v_spacing) = self.formation(MAX_COLS, MAX_ROWS);
+        let mut gaps = BrailleCanvas::new(w, h);
+        for (row, cells) in wave.rows.iter().enumerate() {
+            for (col, cell) in cells.iter().enumerate() {
+                let (cx, cy) = (((x0 + col as f32 * h_spacing) * bsx) as i32, ((y0 + row as f32 * v_spacing) * bsy) as i32);
+                let Some(kind) = cell else {
+                    gaps.set(cx, cy);
+                    continue;
+                };
+                let mut amap: HashMap<(usize, usize), u8> = HashMap::new();
+                Self::render_alien_sprite(&mut amap, cx, cy, *kind, true, bw, bh);
+                Self::write_layer(&mut grid, &amap, w, h, theme.ink(kind.color()), bg, false);
+            }
+        }
+        gaps.draw(&mut grid, theme.paint(Color::Rgb(60, 60, 90), Role::Faint), None, false);
+        let (col, row) = editor.cursor;
+        let (cx, cy) = (x0 + col as f32 * h_spacing, y0 + row as f32 * v_spacing);
+        let mut cursor = BrailleCanvas::new(w, h);
+        cursor.rect(
+            ((cx - h_spacing / 2.0) * bsx) as i32,
+            ((cy - v_spacing / 2.0) * bsy) as i32,
+            ((cx + h_spacing / 2.0) * bsx) as i32,
+            ((cy + v_spacing / 2.0) * bsy) as i32,
+        );
+        cursor.draw(&mut grid, theme.paint(Color::Yellow, Role::Yellow), None, true);
+        let lines: Vec<Line> = grid.into_iter()
+            .map(|row| Line::from(row.into_iter().map(|(ch, style)| Span::styled(String::from(ch), style)).collect::<Vec<_>>()))
+            .collect();
+        frame.render_widget(Paragraph::new(lines), chunks[1]);
+
+        let key = |k: &'static str| Span::styled(k, Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD));
+        let what = |t: &'static str| Span::styled(t, Style::default().fg(theme.paint(Color::DarkGray, Role::Faint)));
+        frame.render_widget(Paragraph::new(Line::from(vec![
+            key(" \u{2190}\u{2191}\u{2193}\u{2192}"), what(" Move | "),
+            key("S C O"), what(" Alien | "),
+            key("Space"), what(" Gap | "),
+            key("X"), what(" Clear | "),
+            key("+ -"), what(" March | "),
+            key("[ ]"), what(" Bombs | "),
+            key("Tab"), what(" Next file | "),
+            key("Enter"), what(" Save | "),
+            key("Esc"), what(" Close"),
+        ])), chunks[2]);
+    }
+
+    /// The boxes `check_collisions` uses: bullets are points, tested against
+    /// each alien's and the player's half-extents and each shield's bounds.
+    fn draw_hitboxes(&self, grid: &mut [Vec<(char, Style)>], bsx: f32, bsy: f32, theme: &Theme) {
+        let cols = grid.first().map_or(0, |row| row.len());
+        let mut boxes = BrailleCanvas::new(cols, grid.len());
+        let mut zone = |x0: f32, y0: f32, x1: f32, y1: f32| {
+            boxes.rect((x0 * bsx) as i32, (y0 * bsy) as i32, (x1 * bsx) as i32 + 1, (y1 * bsy) as i32 + 1);
+        };
+        for alien in self.aliens.iter().filter(|a| a.alive) {
+            zone(alien.x - 2.0, alien.y - 1.5, alien.x + 2.0, alien.y + 1.5);
+        }
+        for shield in &self.shields {
+            zone(shield.x, shield.y, shield.x + SHIELD_WIDTH, shield.y + SHIELD_HEIGHT);
+        }
+        if !self.game_over {
+            let py = self.player_y();
+            zone(self.player_x - 2.5, py - 1.2, self.player_x + 2.5, py + 1.2);
+        }
+        for bullet in self.player_bullets.iter().chain(&self.alien_bullets) {
+            let (x, y) = ((bullet.x * bsx) as i32, (bullet.y * bsy) as i32);
+            boxes.line(x - 1, y, x + 1, y);
+            boxes.line(x, y - 1, x, y + 1);
+        }
+        // Aliens reaching this line end the game
+        let invade_y = ((self.field_height - 4.0) * bsy) as i32;
+        for x in (0..boxes.dot_width()).step_by(4) {
+            boxes.line(x, invade_y, x + 1, invade_y);
+        }
+        boxes.draw(grid, theme.ink(fx::HITBOX), None, false);
+    }
+}
+
+pub const INFO: GameInfo = GameInfo {
+    name: "Invaders",
+    icon: "👾",
+    author: "RustCade",
+    blurb: "Defend Earth\nfrom aliens!",
+    description: "Defend Earth from alien waves!",
+    controls: &[
+        ("← / →", "Move cannon"),
+        ("Space / ↑", "Shoot"),
+        ("E", "Wave editor (before the first point)"),
+        ("R", "Restart"),
+        ("P", "Pause"),
+    ],
+    tags: &["arcade", "shooter", "retro"],
+    color: Ink(Color::Rgb(80, 255, 80), Role::Green),
+    border_color: Ink(Color::Rgb(40, 140, 40), Role::Green),
+};
+
 impl Game for SpaceInvaders {
+    fn info(&self) -> &'static GameInfo {
+        &INFO
+    }
+
     fn update(&mut self) {
-        if self.game_over || self.paused { return; }
+        if self.paused || self.editor.is_some() { return; }
+        self.particles.update();
+        if self.game_over { return; }
         self.tick += 1;
         self.update_bullets();
         self.update_aliens();
@@ -666,6 +900,14 @@ impl Game for SpaceInvaders {
     }
 
     fn handle_input(&mut self, key: KeyEvent) {
+        if let Some(editor) = self.editor.as_mut() {
+            if !editor.handle_key(key, &mut self.waves) {
+                // Start over on whatever the folder holds now
+                self.editor = None;
+                self.reset();
+            }
+            return;
+        }
         match key.code {
             KeyCode::Char('r') | KeyCode::Char('R') => self.reset(),
             KeyCode::Char('p') | KeyCode::Char('P') => {
@@ -675,13 +917,18 @@ impl Game for SpaceInvaders {
             }
             _ => {
                 if self.game_over {
-                    if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
-                        self.reset();
+                    match key.code {
+                        KeyCode::Enter | KeyCode::Char(' ') => self.reset(),
+                        KeyCode::Char(c @ '1'..='3') => self.toggle_modifier(c),
+                        KeyCode::Char('e') | KeyCode::Char('E') => self.open_editor(),
+                        _ => {}
                     }
                     return;
                 }
                 if self.paused { return; }
                 match key.code {
+                    KeyCode::Char(c @ '1'..='3') => self.toggle_modifier(c),
+                    KeyCode::Char('e') | KeyCode::Char('E') if self.between_games() => self.open_editor(),
                     KeyCode::Left => {
                         self.player_x = (self.player_x - PLAYER_SPEED).max(3.0);
                     }
@@ -703,13 +950,8 @@ impl Game for SpaceInvaders {
         }
     }
 
-    fn render(&mut self, frame: &mut Frame, area: Rect) {
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Color::Rgb(80, 255, 80)))
-            .title(" Space Invaders ")
-            .title_style(Style::default().fg(Color::Rgb(100, 255, 100)).add_modifier(Modifier::BOLD));
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = self.title_bar(theme);
 
         let inner = block.inner(area);
         frame.render_widget(block, area);
@@ -737,83 +979,231 @@ impl Game for SpaceInvaders {
             ])
             .split(inner);
 
+        if self.editor.is_some() {
+            self.render_editor(frame, &chunks, theme);
+            return;
+        }
+
         // Status bar
         let lives_str = "\u{2666} ".repeat(self.lives as usize);
         let alive = self.aliens.iter().filter(|a| a.alive).count();
-        let status = Line::from(vec![
+        let mut status = Line::from(vec![
             Span::styled(" \u{1f47e} ", Style::default()),
             Span::styled(
                 format!("Score: {} ", self.score),
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" | ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" | ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             Span::styled(
                 format!("Lives: {}", lives_str),
-                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme.paint(Color::Red, Role::Red)).add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" | ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" | ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             Span::styled(
-                format!("High: {} ", self.high_score),
-                Style::default().fg(Color::Cyan),
+                format!("High: {} ", self.best.max(self.score)),
+                Style::default().fg(theme.paint(Color::Cyan, Role::Cyan)),
             ),
-            Span::styled(" | ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" | ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             Span::styled(
                 format!("Wave: {} ", self.level),
-                Style::default().fg(Color::Green),
+                Style::default().fg(theme.paint(Color::Green, Role::Green)),
             ),
-            Span::styled(" | ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" | ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
             Span::styled(
                 format!("Aliens: {} ", alive),
-                Style::default().fg(Color::Rgb(255, 80, 80)),
+                Style::default().fg(theme.paint(Color::Rgb(255, 80, 80), Role::Red)),
             ),
         ]);
+        if self.modifiers.bits() != 0 {
+            status.spans.push(Span::styled(" | ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))));
+            status.spans.push(Span::styled(
+                format!("{} ×{:.2} ", format_flags(3, self.modifiers.bits()), self.modifiers.score_percent() as f32 / 100.0),
+                Style::default().fg(theme.paint(Color::Rgb(255, 160, 60), Role::Orange)).add_modifier(Modifier::BOLD),
+            ));
+        }
         frame.render_widget(Paragraph::new(status), chunks[0]);
 
         let fw = chunks[1].width as usize;
         let fh = chunks[1].height as usize;
         if fw > 0 && fh > 0 {
-            let lines = self.render_field(fw, fh);
+            let lines = self.render_field(fw, fh, theme);
             frame.render_widget(Paragraph::new(lines), chunks[1]);
         }
 
         if self.game_over {
             let msg = Paragraph::new(Line::from(vec![
-                Span::styled(" GAME OVER! ", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
-                Span::styled("Press ENTER to restart, Esc for menu", Style::default().fg(Color::Gray)),
+                Span::styled(" GAME OVER! ", Style::default().fg(theme.ink(fx::alert_color())).add_modifier(Modifier::BOLD)),
+                Span::styled(
+                    format!("Waves cleared: {} | Press ENTER to restart, 1-3 modifiers, Esc for menu", self.waves_cleared()),
+                    Style::default().fg(theme.paint(Color::Gray, Role::Dim)),
+                ),
             ]));
             frame.render_widget(msg, chunks[2]);
         } else if self.paused {
             let msg = Paragraph::new(Line::from(vec![
-                Span::styled(" PAUSED - Press P to resume ", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                Span::styled(" PAUSED - Press P to resume ", Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD)),
             ]));
             frame.render_widget(msg, chunks[2]);
         } else {
-            let help = Paragraph::new(Line::from(vec![
-                Span::styled(" \u{2190}\u{2192} Move ", Style::default().fg(Color::DarkGray)),
-                Span::styled("| ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("Space Shoot ", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
-                Span::styled("| ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("P Pause ", Style::default().fg(Color::DarkGray)),
-                Span::styled("| ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("R Restart ", Style::default().fg(Color::DarkGray)),
-                Span::styled("| ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("Esc Menu ", Style::default().fg(Color::DarkGray)),
-                Span::styled("| ", Style::default().fg(Color::Rgb(60, 60, 60))),
-                Span::styled("? Help", Style::default().fg(Color::DarkGray)),
-            ]));
-            frame.render_widget(help, chunks[2]);
+            let mut keys = vec![
+                Span::styled(" \u{2190}\u{2192} Move ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                Span::styled("| ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint))),
+                Span::styled("Space Shoot ", Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD)),
+                Span::styled("| ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint))),
+            ];
+            if self.score == 0 && self.level == 1 {
+                keys.push(Span::styled("1-3 Modifiers ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))));
+                keys.push(Span::styled("| ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint))));
+            }
+            keys.extend([
+                Span::styled("P Pause ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                Span::styled("| ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint))),
+                Span::styled("R Restart ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                Span::styled("| ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint))),
+                Span::styled("Esc Menu ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+                Span::styled("| ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint))),
+                Span::styled("? Help", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+            ]);
+            frame.render_widget(Paragraph::new(Line::from(keys)), chunks[2]);
         }
     }
 
     fn get_score(&self) -> u32 { self.score }
     fn is_game_over(&self) -> bool { self.game_over }
+    fn is_paused(&self) -> bool { self.paused }
+    fn set_hitboxes(&mut self, on: bool) { self.show_hitboxes = on; }
+    fn wants_text_input(&self) -> bool { self.editor.is_some() }
+
+    fn reseed(&mut self, rng: GameRng) {
+        self.rng = rng;
+        self.reset();
+    }
+
+    fn serialize_state(&self) -> Option<Vec<u8>> {
+        if self.game_over { return None; }
+        let mut w = StateWriter::new();
+        for v in [self.field_width, self.field_height, self.player_x, self.alien_dir, self.alien_speed] {
+            w.f32(v);
+        }
+        for v in [self.alien_move_timer, self.alien_move_interval, self.alien_fire_timer, self.tick] {
+            w.u64(v);
+        }
+        w.u32(self.score);
+        w.u32(self.lives);
+        w.u32(self.level);
+        w.u64(self.rng.state());
+        w.u32(self.modifiers.bits());
+        w.u32(self.aliens.len() as u32);
+        for a in &self.aliens {
+            w.f32(a.x);
+            w.f32(a.y);
+            w.u8(a.kind as u8);
+            w.bool(a.alive);
+        }
+        for bullets in [&self.player_bullets, &self.alien_bullets] {
+            w.u32(bullets.len() as u32);
+            for b in bullets {
+                w.f32(b.x);
+                w.f32(b.y);
+                w.f32(b.dy);
+            }
+        }
+        w.u32(self.shields.len() as u32);
+        for s in &self.shields {
+            w.f32(s.x);
+            w.f32(s.y);
+            w.bytes(&s.pixels.iter().flatten().map(|&p| p as u8).collect::<Vec<_>>());
+        }
+        w.f32(self.march_pace);
+        w.f32(self.fire_pace);
+        Some(w.finish())
+    }
+
+    fn restore_state(&mut self, data: &[u8]) -> bool {
+        let mut r = StateReader::new(data);
+        let (Some(fw), Some(fh), Some(player_x), Some(alien_dir), Some(alien_speed)) = (r.f32(), r.f32(), r.f32(), r.f32(), r.f32())
+        else { return false };
+        let (Some(move_timer), Some(move_interval), Some(fire_timer), Some(tick)) = (r.u64(), r.u64(), r.u64(), r.u64())
+        else { return false };
+        let (Some(score), Some(lives), Some(level), Some(rng_state), Some(modifiers), Some(count)) =
+            (r.u32(), r.u32(), r.u32(), r.u64(), r.u32(), r.u32())
+        else { return false };
+        if !(fw >= 1.0 && fh >= 1.0) || lives == 0 || level == 0 || count == 0 || count as usize > MAX_ROWS * MAX_COLS { return false; }
+        let mut aliens = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let (Some(x), Some(y), Some(kind), Some(alive)) = (r.f32(), r.f32(), r.u8(), r.bool()) else { return false };
+            let kind = match kind {
+                0 => AlienKind::Top,
+                1 => AlienKind::Mid,
+                _ => AlienKind::Bot,
+            };
+            aliens.push(Alien { x, y, kind, alive });
+        }
+        let mut bullets = [Vec::new(), Vec::new()];
+        for list in &mut bullets {
+            let Some(count) = r.u32() else { return false };
+            if count > 64 { return false; }
+            for _ in 0..count {
+                let (Some(x), Some(y), Some(dy)) = (r.f32(), r.f32(), r.f32()) else { return false };
+                list.push(Bullet { x, y, dy });
+            }
+        }
+        let Some(count) = r.u32() else { return false };
+        if count as usize > SHIELD_COUNT { return false; }
+        let mut shields = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let (Some(x), Some(y), Some(bits)) = (r.f32(), r.f32(), r.bytes()) else { return false };
+            let mut shield = Shield::new(x, y);
+            if bits.len() != shield.pw * shield.ph { return false; }
+            for (p, &b) in shield.pixels.iter_mut().flatten().zip(&bits) {
+                *p = b != 0;
+            }
+            shields.push(shield);
+        }
+        // Snapshots from before wave files are classic waves
+        let march_pace = r.f32().unwrap_or(1.0).clamp(MIN_PACE, MAX_PACE);
+        let fire_pace = r.f32().unwrap_or(1.0).clamp(MIN_PACE, MAX_PACE);
+
+        self.modifiers = Modifiers::from_bits(modifiers);
+        self.reset();
+        self.field_width = fw;
+        self.field_height = fh;
+        self.player_x = player_x;
+        self.alien_dir = alien_dir;
+        self.alien_speed = alien_speed;
+        self.alien_move_timer = move_timer;
+        self.alien_move_interval = move_interval.max(1);
+        self.alien_fire_timer = fire_timer;
+        self.tick = tick;
+        self.score = score;
+        self.lives = lives;
+        self.level = level;
+        self.rng = GameRng::new(rng_state);
+        self.aliens = aliens;
+        let [player_bullets, alien_bullets] = bullets;
+        self.player_bullets = player_bullets;
+        self.alien_bullets = alien_bullets;
+        self.shields = shields;
+        self.march_pace = march_pace;
+        self.fire_pace = fire_pace;
+        self.paused = true;
+        true
+    }
 
     fn reset(&mut self) {
-        let hs = self.high_score;
+        let best = self.best;
         let fw = self.field_width;
         let fh = self.field_height;
+        let show_hitboxes = self.show_hitboxes;
+        let modifiers = self.modifiers;
+        let reduced_motion = self.reduced_motion;
+        let rng = self.rng.clone();
         *self = SpaceInvaders::new();
-        self.high_score = hs;
+        self.rng = rng;
+        self.best = best;
+        self.reduced_motion = reduced_motion;
+        self.show_hitboxes = show_hitboxes;
+        self.modifiers = modifiers;
         self.field_width = fw;
         self.field_height = fh;
         self.player_x = fw / 2.0;
@@ -0,0 +1,166 @@
+use crossterm::event::KeyCode;
+use ratatui::prelude::*;
+
+/// One cell's look: `cell_h` rows of `cell_w` characters.
+pub type CellArt = Vec<Vec<(char, Style)>>;
+
+/// The four sides of a grid cell, clockwise from the top.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir {
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
+    pub fn delta(self) -> (i32, i32) {
+        match self {
+            Dir::Up => (0, -1),
+            Dir::Right => (1, 0),
+            Dir::Down => (0, 1),
+            Dir::Left => (-1, 0),
+        }
+    }
+
+    pub fn opposite(self) -> Dir {
+        self.turn(2)
+    }
+
+    /// A quarter turn clockwise `quarters` times.
+    pub fn turn(self, quarters: u8) -> Dir {
+        Dir::ALL[(self as usize + quarters as usize) % 4]
+    }
+
+    /// Bit for connection masks: Up 1, Right 2, Down 4, Left 8.
+    pub fn bit(self) -> u8 {
+        1 << self as u8
+    }
+
+    /// The arrow key pointing this way.
+    pub fn from_key(code: KeyCode) -> Option<Dir> {
+        match code {
+            KeyCode::Up => Some(Dir::Up),
+            KeyCode::Right => Some(Dir::Right),
+            KeyCode::Down => Some(Dir::Down),
+            KeyCode::Left => Some(Dir::Left),
+            _ => None,
+        }
+    }
+}
+
+/// A connection mask turned clockwise `quarters` times.
+pub fn rotate_mask(mask: u8, quarters: u8) -> u8 {
+    let q = quarters % 4;
+    ((mask << q) | (mask >> (4 - q))) & 0x0F
+}
+
+/// A rectangle of cells with a keyboard cursor, for board and puzzle
+/// games: storage, neighbours, cursor movement, mouse hit-testing and
+/// drawing each cell as a fixed-size block of characters.
+#[derive(Clone)]
+pub struct TileGrid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+    pub cursor: (usize, usize),
+}
+
+impl<T: Clone> TileGrid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        TileGrid { width, height, cells: vec![fill; width * height], cursor: (0, 0) }
+    }
+
+    /// Rows of cells, which must all be the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        TileGrid { width, height, cells: rows.into_iter().flatten().collect(), cursor: (0, 0) }
+    }
+}
+
+impl<T> TileGrid<T> {
+    pub fn width(&self) -> usize { self.width }
+    pub fn height(&self) -> usize { self.height }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x >= self.width || y >= self.height { return None; }
+        self.cells.get(y * self.width + x)
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x >= self.width || y >= self.height { return None; }
+        self.cells.get_mut(y * self.width + x)
+    }
+
+    /// The cell one step `dir` from (x, y), if it's on the grid.
+    pub fn neighbor(&self, x: usize, y: usize, dir: Dir) -> Option<(usize, usize)> {
+        let (dx, dy) = dir.delta();
+        let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+        if nx < 0 || ny < 0 || nx >= self.width as i32 || ny >= self.height as i32 { return None; }
+        Some((nx as usize, ny as usize))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.cells.iter().enumerate().map(move |(i, c)| (i % self.width, i / self.width, c))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (usize, usize, &mut T)> {
+        let width = self.width;
+        self.cells.iter_mut().enumerate().map(move |(i, c)| (i % width, i / width, c))
+    }
+
+    /// Step the cursor, stopping at the edges or wrapping round them.
+    pub fn move_cursor(&mut self, dir: Dir, wrap: bool) {
+        if self.width == 0 || self.height == 0 { return; }
+        let (x, y) = self.cursor;
+        self.cursor = match self.neighbor(x, y, dir) {
+            Some(cell) => cell,
+            None if wrap => {
+                let (dx, dy) = dir.delta();
+                ((x as i32 + dx).rem_euclid(self.width as i32) as usize, (y as i32 + dy).rem_euclid(self.height as i32) as usize)
+            }
+            None => (x, y),
+        };
+    }
+
+    /// Arrow keys move the cursor; true if `code` was one.
+    pub fn cursor_key(&mut self, code: KeyCode, wrap: bool) -> bool {
+        let Some(dir) = Dir::from_key(code) else { return false };
+        self.move_cursor(dir, wrap);
+        true
+    }
+
+    /// The cell under terminal position (column, row), for a grid drawn
+    /// by `render` at `origin` with `cell` (width, height) blocks.
+    pub fn cell_at(&self, origin: Position, cell: (usize, usize), column: u16, row: u16) -> Option<(usize, usize)> {
+        let (cw, ch) = cell;
+        if column < origin.x || row < origin.y || cw == 0 || ch == 0 { return None; }
+        let x = (column - origin.x) as usize / cw;
+        let y = (row - origin.y) as usize / ch;
+        (x < self.width && y < self.height).then_some((x, y))
+    }
+
+    /// Lines of the whole grid, each cell drawn by `draw` into a `cell`
+    /// (width, height) block of blanks styled `bg`.
+    pub fn render<F>(&self, cell: (usize, usize), bg: Style, mut draw: F) -> Vec<Line<'static>>
+    where
+        F: FnMut(usize, usize, &T, &mut CellArt),
+    {
+        let (cw, ch) = cell;
+        let mut rows: Vec<Vec<(char, Style)>> = vec![Vec::with_capacity(self.width * cw); self.height * ch];
+        for (x, y, value) in self.iter() {
+            let mut art: CellArt = vec![vec![(' ', bg); cw]; ch];
+            draw(x, y, value, &mut art);
+            for (dy, line) in art.into_iter().enumerate() {
+                rows[y * ch + dy].extend(line.into_iter().take(cw));
+            }
+        }
+        rows.into_iter()
+            .map(|row| Line::from(row.into_iter().map(|(c, style)| Span::styled(String::from(c), style)).collect::<Vec<_>>()))
+            .collect()
+    }
+}
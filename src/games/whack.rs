@@ -0,0 +1,468 @@
+use std::time::Instant;
+
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use rand::Rng;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::games::tile_grid::{CellArt, TileGrid};
+use crate::games::{Game, GameInfo};
+use crate::rng::GameRng;
+use crate::session::{StateReader, StateWriter};
+use crate::stats::percentile;
+use crate::ui::fx;
+use crate::ui::hud::{self, Countdown};
+use crate::ui::theme::{Ink, Role, Theme};
+
+/// Terminal (columns, rows) each hole is drawn in
+const CELL: (usize, usize) = (14, 6);
+const SIZE: usize = 3;
+/// Keys for each hole, row by row: letters on the left hand, and the
+/// number pad laid out the same way
+const LETTERS: [char; SIZE * SIZE] = ['q', 'w', 'e', 'a', 's', 'd', 'z', 'x', 'c'];
+const DIGITS: [char; SIZE * SIZE] = ['7', '8', '9', '4', '5', '6', '1', '2', '3'];
+const LIVES: u32 = 3;
+/// Hits per level
+const HITS_PER_LEVEL: u32 = 10;
+/// Ticks before the first mole
+const GRACE_TICKS: u32 = 60;
+/// Ticks a hit, miss or escape stays marked on its hole
+const MARK_TICKS: u32 = 30;
+/// Longest gap counted between updates, so time away isn't reaction time
+const MAX_STEP_MS: f32 = 50.0;
+const ACCENT: Ink = Ink(Color::Rgb(200, 150, 90), Role::Orange);
+const DIRT: Ink = Ink(Color::Rgb(110, 75, 40), Role::Orange);
+const MOLE_COLOR: Ink = Ink(Color::Rgb(150, 110, 80), Role::Orange);
+const GOLD: Ink = Ink(Color::Rgb(255, 210, 60), Role::Yellow);
+const FUSE: Ink = Ink(Color::Rgb(255, 110, 60), Role::Orange);
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Mole,
+    /// Worth triple, and ducks back sooner
+    Golden,
+    /// Leave it: hitting it costs a life
+    Bomb,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Hole {
+    Empty,
+    Up {
+        kind: Kind,
+        /// Ticks before it ducks back down
+        ticks: u32,
+        /// Real time it's been up, paused time left out
+        age_ms: f32,
+    },
+    /// Hit, with the reaction time in ms
+    Whacked { ms: u32, ticks: u32 },
+    /// Got away, or a bomb went off
+    Missed { ticks: u32 },
+}
+
+/// Whack-a-mole reaction trainer: moles pop up in a 3×3 field and each
+/// hole has its own key; hit them before they duck back down, in a window
+/// that shrinks every level. Every hit's reaction time is recorded.
+pub struct WhackGame {
+    holes: TileGrid<Hole>,
+    hits: u32,
+    lives: u32,
+    score: u32,
+    high_score: u32,
+    /// Hits in a row without a miss
+    streak: u32,
+    /// Reaction times in ms this run
+    reactions: Vec<u32>,
+    /// Ticks until the next mole may come up
+    spawn_in: u32,
+    game_over: bool,
+    paused: bool,
+    countdown: Countdown,
+    /// When `update` last ran, to time reactions between ticks
+    last_update: Option<Instant>,
+    /// Top-left of the drawn field, for mouse clicks
+    grid_origin: Position,
+    /// Which hole a mole comes up in, and what kind
+    rng: GameRng,
+}
+
+impl WhackGame {
+    pub fn new() -> Self {
+        Self {
+            holes: TileGrid::new(SIZE, SIZE, Hole::Empty),
+            hits: 0,
+            lives: LIVES,
+            score: 0,
+            high_score: 0,
+            streak: 0,
+            reactions: Vec::new(),
+            spawn_in: GRACE_TICKS,
+            game_over: false,
+            paused: false,
+            countdown: Countdown::default(),
+            last_update: None,
+            grid_origin: Position::default(),
+            rng: GameRng::from_entropy(),
+        }
+    }
+
+    fn level(&self) -> u32 {
+        1 + self.hits / HITS_PER_LEVEL
+    }
+
+    /// Ticks a mole stays up: 1.5 s at first, down to 0.4 s.
+    fn window_ticks(&self) -> u32 {
+        90u32.saturating_sub(6 * (self.level() - 1)).max(24)
+    }
+
+    /// Ticks between moles.
+    fn spawn_ticks(&self) -> u32 {
+        60u32.saturating_sub(4 * (self.level() - 1)).max(18)
+    }
+
+    /// Moles that may be up at once.
+    fn max_up(&self) -> usize {
+        (1 + (self.level() as usize - 1) / 3).min(3)
+    }
+
+    /// Median reaction time this run in ms; 0 before the first hit.
+    pub fn median_reaction(&self) -> u32 {
+        percentile(&self.reactions, 50).unwrap_or(0)
+    }
+
+    fn spawn(&mut self) {
+        let up = self.holes.iter().filter(|(_, _, h)| matches!(h, Hole::Up { kind: Kind::Mole | Kind::Golden, .. })).count();
+        let free: Vec<(usize, usize)> = self.holes.iter().filter(|(_, _, h)| **h == Hole::Empty).map(|(x, y, _)| (x, y)).collect();
+        if up >= self.max_up() || free.is_empty() { return; }
+        let (x, y) = free[self.rng.gen_range(0..free.len())];
+        let roll = self.rng.gen_range(0..100);
+        let kind = if self.level() > 1 && roll < 12 {
+            Kind::Bomb
+        } else if roll >= 90 {
+            Kind::Golden
+        } else {
+            Kind::Mole
+        };
+        let ticks = if kind == Kind::Golden { self.window_ticks() * 2 / 3 } else { self.window_ticks() };
+        if let Some(hole) = self.holes.get_mut(x, y) {
+            *hole = Hole::Up { kind, ticks, age_ms: 0.0 };
+        }
+        self.spawn_in = self.spawn_ticks();
+    }
+
+    fn whack(&mut self, x: usize, y: usize) {
+        if self.game_over || self.paused || self.countdown.is_running() { return; }
+        self.holes.cursor = (x, y);
+        let since = self.last_update.map_or(0.0, |t| (t.elapsed().as_secs_f32() * 1000.0).min(MAX_STEP_MS));
+        let Some(hole) = self.holes.get_mut(x, y) else { return };
+        match *hole {
+            Hole::Up { kind: Kind::Bomb, .. } => {
+                *hole = Hole::Missed { ticks: MARK_TICKS };
+                self.lose_life();
+            }
+            Hole::Up { kind, ticks, age_ms } => {
+                let ms = (age_ms + since).round() as u32;
+                *hole = Hole::Whacked { ms, ticks: MARK_TICKS };
+                self.reactions.push(ms);
+                self.hits += 1;
+                self.streak += 1;
+                // Faster hits are worth more, and golden moles triple it
+                let level = self.level();
+                let quick = 10 * ticks / self.window_ticks().max(1);
+                let points = (10 * level + quick) * if kind == Kind::Golden { 3 } else { 1 };
+                self.score += points + 5 * self.streak.min(10);
+            }
+            // An empty hole just breaks the streak
+            _ => self.streak = 0,
+        }
+    }
+
+    fn lose_life(&mut self) {
+        self.streak = 0;
+        self.lives = self.lives.saturating_sub(1);
+        if self.lives == 0 {
+            self.game_over = true;
+            self.high_score = self.high_score.max(self.score);
+        }
+    }
+
+    fn key_hole(c: char) -> Option<(usize, usize)> {
+        let c = c.to_ascii_lowercase();
+        let i = LETTERS.iter().position(|&k| k == c).or_else(|| DIGITS.iter().position(|&k| k == c))?;
+        Some((i % SIZE, i / SIZE))
+    }
+
+    fn draw_hole(&self, x: usize, y: usize, hole: Hole, art: &mut CellArt, theme: &Theme) {
+        let put = |art: &mut CellArt, row: usize, text: &str, style: Style| {
+            let width = text.chars().count();
+            let start = CELL.0.saturating_sub(width) / 2;
+            for (i, c) in text.chars().enumerate() {
+                if let Some(cell) = art[row].get_mut(start + i) { *cell = (c, style); }
+            }
+        };
+        let i = y * SIZE + x;
+        let label = format!("[{}/{}]", LETTERS[i].to_ascii_uppercase(), DIGITS[i]);
+        let dirt = Style::default().fg(theme.ink(DIRT));
+        match hole {
+            Hole::Up { kind, .. } => {
+                let (body, face) = match kind {
+                    Kind::Mole => (Style::default().fg(theme.ink(MOLE_COLOR)), " ● ▾ ● "),
+                    Kind::Golden => (Style::default().fg(theme.ink(GOLD)).add_modifier(Modifier::BOLD), " ★ ▾ ★ "),
+                    Kind::Bomb => (Style::default().fg(theme.paint(Color::Rgb(200, 200, 210), Role::Text)), "  ✹✹✹  "),
+                };
+                if kind == Kind::Bomb {
+                    put(art, 0, "  ╻  ", Style::default().fg(theme.ink(FUSE)));
+                    put(art, 1, "▗███▖", body);
+                    put(art, 2, face, Style::default().fg(theme.ink(FUSE)).add_modifier(Modifier::BOLD));
+                } else {
+                    put(art, 0, "▄▆██▆▄", body);
+                    put(art, 1, face, body.add_modifier(Modifier::REVERSED));
+                    put(art, 2, "▐█▀▀▀█▌", body);
+                }
+                put(art, 3, "▀▀▀▀▀▀▀▀▀▀", dirt);
+            }
+            Hole::Whacked { ms, .. } => {
+                put(art, 1, "✶ WHACK ✶", Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD));
+                put(art, 2, &format!("{} ms", ms), Style::default().fg(theme.paint(Color::White, Role::Bright)));
+                put(art, 3, "▀▀▀▀▀▀▀▀▀▀", dirt);
+            }
+            Hole::Missed { .. } => {
+                put(art, 2, "✗", Style::default().fg(theme.ink(fx::alert_color())).add_modifier(Modifier::BOLD));
+                put(art, 3, "▀▀▀▀▀▀▀▀▀▀", dirt);
+            }
+            Hole::Empty => {
+                put(art, 2, "▁▁▁▁▁▁", Style::default().fg(theme.paint(Color::Rgb(40, 30, 20), Role::Panel)));
+                put(art, 3, "▀▀▀▀▀▀▀▀▀▀", dirt);
+            }
+        }
+        let key_style = if self.holes.cursor == (x, y) { Style::default().fg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD) } else { Style::default().fg(theme.paint(Color::DarkGray, Role::Faint)) };
+        put(art, 4, &label, key_style);
+    }
+}
+
+pub const INFO: GameInfo = GameInfo {
+    name: "Whack",
+    icon: "🔨",
+    author: "RustCade",
+    blurb: "Whack the moles,\ntime your reflexes",
+    description: "Hit each mole with its hole's key before it ducks; every hit's reaction time is kept",
+    controls: &[
+        ("Q W E / A S D / Z X C", "Whack a hole"),
+        ("7 8 9 / 4 5 6 / 1 2 3", "Same, on the number pad"),
+        ("Click", "Whack a hole"),
+        ("R", "Restart"),
+        ("P", "Pause"),
+    ],
+    tags: &["reaction", "arcade", "trainer"],
+    color: ACCENT,
+    border_color: Ink(Color::Rgb(100, 70, 40), Role::Orange),
+};
+
+impl Game for WhackGame {
+    fn info(&self) -> &'static GameInfo {
+        &INFO
+    }
+
+    fn update(&mut self) {
+        if self.paused || self.countdown.tick() || self.game_over {
+            self.last_update = None;
+            return;
+        }
+        let now = Instant::now();
+        let step_ms = self.last_update.map_or(1000.0 / 60.0, |t| (now - t).as_secs_f32() * 1000.0).min(MAX_STEP_MS);
+        self.last_update = Some(now);
+
+        let mut escaped = 0;
+        for (_, _, hole) in self.holes.iter_mut() {
+            *hole = match *hole {
+                Hole::Up { ticks: 0, kind, .. } => {
+                    if kind != Kind::Bomb { escaped += 1; }
+                    if kind == Kind::Bomb { Hole::Empty } else { Hole::Missed { ticks: MARK_TICKS } }
+                }
+                Hole::Up { kind, ticks, age_ms } => Hole::Up { kind, ticks: ticks - 1, age_ms: age_ms + step_ms },
+                Hole::Whacked { ticks: 0, .. } | Hole::Missed { ticks: 0 } => Hole::Empty,
+                Hole::Whacked { ms, ticks } => Hole::Whacked { ms, ticks: ticks - 1 },
+                Hole::Missed { ticks } => Hole::Missed { ticks: ticks - 1 },
+                Hole::Empty => Hole::Empty,
+            };
+        }
+        for _ in 0..escaped {
+            self.lose_life();
+        }
+        if self.game_over { return; }
+        self.spawn_in = self.spawn_in.saturating_sub(1);
+        if self.spawn_in == 0 {
+            self.spawn();
+        }
+    }
+
+    fn handle_input(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('r') | KeyCode::Char('R') => self.reset(),
+            KeyCode::Char('p') | KeyCode::Char('P') if !self.game_over => {
+                self.countdown.toggle_pause(&mut self.paused);
+            }
+            _ if self.game_over => {
+                if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+                    self.reset();
+                }
+            }
+            KeyCode::Char(c) => {
+                if let Some((x, y)) = Self::key_hole(c) {
+                    self.whack(x, y);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent) {
+        if event.kind != MouseEventKind::Down(MouseButton::Left) { return; }
+        if let Some((x, y)) = self.holes.cell_at(self.grid_origin, CELL, event.column, event.row) {
+            self.whack(x, y);
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = self.title_bar(theme);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(6), Constraint::Length(1)])
+            .split(inner);
+
+        let sep = || Span::styled(" │ ", Style::default().fg(theme.paint(Color::DarkGray, Role::Faint)));
+        let hearts: String = (0..LIVES).map(|i| if i < self.lives { '♥' } else { '♡' }).collect();
+        let mut status = vec![
+            Span::styled(" 🔨 ", Style::default()),
+            Span::styled(format!("Level {} ", self.level()), Style::default().fg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD)),
+            sep(),
+            Span::styled(format!("Score: {} ", self.score), Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD)),
+            sep(),
+            Span::styled(format!("{} ", hearts), Style::default().fg(theme.paint(Color::Rgb(255, 90, 110), Role::Red))),
+            sep(),
+            Span::styled(format!("Window: {} ms ", self.window_ticks() * 1000 / 60), Style::default().fg(theme.paint(Color::Gray, Role::Dim))),
+        ];
+        if self.streak >= 3 {
+            status.push(sep());
+            status.push(Span::styled(format!("Streak ×{} ", self.streak), Style::default().fg(theme.paint(Color::Cyan, Role::Cyan))));
+        }
+        frame.render_widget(Paragraph::new(Line::from(status)), chunks[0]);
+
+        let field = chunks[1];
+        let (gw, gh) = ((SIZE * CELL.0) as u16, (SIZE * CELL.1) as u16);
+        let side_w = 26u16;
+        let grid_area = Rect::new(
+            field.x + field.width.saturating_sub(gw + side_w + 2) / 2,
+            field.y + field.height.saturating_sub(gh) / 2,
+            gw.min(field.width),
+            gh.min(field.height),
+        );
+        self.grid_origin = grid_area.as_position();
+        let lines = self.holes.render(CELL, Style::default(), |x, y, hole, art| self.draw_hole(x, y, *hole, art, theme));
+        frame.render_widget(Paragraph::new(lines), grid_area);
+
+        // This run's reaction times
+        let label = |s: &str| Span::styled(format!("{:<9}", s), Style::default().fg(theme.paint(Color::Gray, Role::Dim)));
+        let ms = |v: Option<u32>| Span::styled(v.map_or("—".to_string(), |v| format!("{} ms", v)), Style::default().fg(theme.paint(Color::White, Role::Bright)).add_modifier(Modifier::BOLD));
+        let times = &self.reactions;
+        let side_lines = vec![
+            Line::from(Span::styled("REACTION", Style::default().fg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD))),
+            Line::default(),
+            Line::from(vec![label("Hits"), Span::styled(times.len().to_string(), Style::default().fg(theme.paint(Color::White, Role::Bright)))]),
+            Line::from(vec![label("Best"), ms(times.iter().copied().min())]),
+            Line::from(vec![label("Median"), ms(percentile(times, 50))]),
+            Line::from(vec![label("90%"), ms(percentile(times, 90))]),
+            Line::from(vec![label("Last"), ms(times.last().copied())]),
+        ];
+        let side = Rect::new(grid_area.right() + 2, grid_area.y, side_w.min(field.right().saturating_sub(grid_area.right() + 2)), grid_area.height);
+        frame.render_widget(Paragraph::new(side_lines), side);
+        self.countdown.render(frame, field, theme);
+
+        let footer = if self.game_over {
+            hud::game_over(&format!(
+                "{} hits, median {} ms │ ENTER to restart, Esc for menu",
+                self.hits,
+                self.median_reaction()
+            ), theme)
+        } else if self.paused {
+            hud::pause_overlay(frame, field, theme);
+            hud::paused(theme)
+        } else {
+            hud::legend(None, &[("QWE/ASD/ZXC", "Whack"), ("Numpad", "Whack")], theme)
+        };
+        frame.render_widget(Paragraph::new(footer), chunks[2]);
+    }
+
+    fn get_score(&self) -> u32 { self.score }
+    fn is_game_over(&self) -> bool { self.game_over }
+    fn is_paused(&self) -> bool { self.paused }
+    fn reaction_times(&self) -> &[u32] { &self.reactions }
+
+    fn reseed(&mut self, rng: GameRng) {
+        self.rng = rng;
+        self.reset();
+    }
+
+    fn script_state(&self) -> Vec<(&'static str, f64)> {
+        // Hole number (0-8, row by row) of a mole to hit, or -1
+        let target = self.holes.iter()
+            .find(|(_, _, h)| matches!(h, Hole::Up { kind: Kind::Mole | Kind::Golden, .. }))
+            .map_or(-1.0, |(x, y, _)| (y * SIZE + x) as f64);
+        vec![
+            ("level", self.level() as f64),
+            ("lives", self.lives as f64),
+            ("hits", self.hits as f64),
+            ("target", target),
+            ("window_ms", (self.window_ticks() * 1000 / 60) as f64),
+            ("median_ms", self.median_reaction() as f64),
+        ]
+    }
+
+    fn serialize_state(&self) -> Option<Vec<u8>> {
+        if self.game_over { return None; }
+        let mut w = StateWriter::new();
+        w.u32(self.score);
+        w.u32(self.high_score);
+        w.u32(self.hits);
+        w.u32(self.lives);
+        w.u32(self.streak);
+        w.u32(self.reactions.len() as u32);
+        self.reactions.iter().for_each(|&t| w.u32(t));
+        Some(w.finish())
+    }
+
+    fn restore_state(&mut self, data: &[u8]) -> bool {
+        let mut r = StateReader::new(data);
+        let (Some(score), Some(high_score), Some(hits), Some(lives), Some(streak), Some(count)) =
+            (r.u32(), r.u32(), r.u32(), r.u32(), r.u32(), r.u32())
+        else { return false };
+        let reactions: Option<Vec<u32>> = (0..count).map(|_| r.u32()).collect();
+        let Some(reactions) = reactions else { return false };
+        if lives == 0 || lives > LIVES { return false; }
+
+        let mut restored = WhackGame::new();
+        restored.score = score;
+        restored.high_score = self.high_score.max(high_score);
+        restored.hits = hits;
+        restored.lives = lives;
+        restored.streak = streak;
+        restored.reactions = reactions;
+        restored.rng = self.rng.clone();
+        // Resume paused, with the field clear and a countdown
+        restored.paused = true;
+        *self = restored;
+        true
+    }
+
+    fn reset(&mut self) {
+        let high_score = self.high_score;
+        let rng = self.rng.clone();
+        *self = WhackGame::new();
+        self.high_score = high_score;
+        self.rng = rng;
+    }
+}
@@ -0,0 +1,378 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::Tab;
+use crate::scores::data_path;
+
+/// A key plus the modifiers held with it. Shift is folded into the
+/// character for `Char` keys, since terminals disagree on reporting it.
+#[derive(Clone, Copy, PartialEq)]
+pub struct KeyBind {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyBind {
+    pub const fn plain(c: char) -> Self {
+        KeyBind { code: KeyCode::Char(c), modifiers: KeyModifiers::NONE }
+    }
+
+    pub fn from_event(key: KeyEvent) -> Self {
+        let mut modifiers = key.modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT);
+        if matches!(key.code, KeyCode::Char(_)) {
+            modifiers.remove(KeyModifiers::SHIFT);
+        }
+        KeyBind { code: key.code, modifiers }
+    }
+
+    /// `Ctrl+Alt+Shift+<key>`, e.g. `Ctrl+Right`, `(`, `Space`, `F5`.
+    pub fn label(&self) -> String {
+        let mut out = String::new();
+        if self.modifiers.contains(KeyModifiers::CONTROL) { out.push_str("Ctrl+"); }
+        if self.modifiers.contains(KeyModifiers::ALT) { out.push_str("Alt+"); }
+        if self.modifiers.contains(KeyModifiers::SHIFT) { out.push_str("Shift+"); }
+        let key = match self.code {
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char('+') => "Plus".to_string(),
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::F(n) => format!("F{}", n),
+            KeyCode::Left => "Left".to_string(),
+            KeyCode::Right => "Right".to_string(),
+            KeyCode::Up => "Up".to_string(),
+            KeyCode::Down => "Down".to_string(),
+            KeyCode::Home => "Home".to_string(),
+            KeyCode::End => "End".to_string(),
+            KeyCode::PageUp => "PageUp".to_string(),
+            KeyCode::PageDown => "PageDown".to_string(),
+            KeyCode::Insert => "Insert".to_string(),
+            KeyCode::Delete => "Delete".to_string(),
+            KeyCode::Backspace => "Backspace".to_string(),
+            KeyCode::Enter => "Enter".to_string(),
+            _ => "?".to_string(),
+        };
+        out.push_str(&key);
+        out
+    }
+
+    /// Inverse of `label`.
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut rest = text.trim();
+        loop {
+            let lower = rest.to_ascii_lowercase();
+            let (flag, len) = if lower.starts_with("ctrl+") {
+                (KeyModifiers::CONTROL, 5)
+            } else if lower.starts_with("alt+") {
+                (KeyModifiers::ALT, 4)
+            } else if lower.starts_with("shift+") && rest.len() > 6 {
+                (KeyModifiers::SHIFT, 6)
+            } else {
+                break;
+            };
+            modifiers |= flag;
+            rest = &rest[len..];
+        }
+        let code = match rest.to_ascii_lowercase().as_str() {
+            "space" => KeyCode::Char(' '),
+            "plus" => KeyCode::Char('+'),
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            "insert" => KeyCode::Insert,
+            "delete" => KeyCode::Delete,
+            "backspace" => KeyCode::Backspace,
+            "enter" => KeyCode::Enter,
+            f if f.len() > 1 && f.starts_with('f') => KeyCode::F(f[1..].parse().ok()?),
+            _ => {
+                let mut chars = rest.chars();
+                let c = chars.next()?;
+                if chars.next().is_some() { return None; }
+                KeyCode::Char(c)
+            }
+        };
+        Some(KeyBind::from_event(KeyEvent::new(code, modifiers)))
+    }
+}
+
+/// Keys that stay with the arcade itself and can't be bound to a game action.
+pub fn is_reserved(bind: KeyBind) -> bool {
+    matches!(bind.code, KeyCode::Tab | KeyCode::BackTab | KeyCode::Esc)
+        || bind == KeyBind::plain('?')
+        || (bind.code == KeyCode::Char('c') && bind.modifiers.contains(KeyModifiers::CONTROL))
+}
+
+/// A game control that can be moved to another key. `key` is the one the
+/// game itself listens for; bound keys are translated to it.
+pub struct Action {
+    pub id: &'static str,
+    pub tab: Tab,
+    pub name: &'static str,
+    pub key: KeyCode,
+    /// Binding on the QWERTY preset
+    pub default: char,
+}
+
+impl Action {
+    /// A punctuation control bound to its own key by default.
+    const fn same(id: &'static str, tab: Tab, name: &'static str, key: char) -> Self {
+        Action { id, tab, name, key: KeyCode::Char(key), default: key }
+    }
+}
+
+/// Punctuation controls, which move around or need AltGr on many layouts,
+/// and vi-style Frogger hops for terminals whose multiplexer takes the
+/// arrows. The arrows themselves always work.
+pub const ACTIONS: &[Action] = &[
+    Action::same("booster.prev_cell", Tab::Booster, "Booster: previous cell", '['),
+    Action::same("booster.next_cell", Tab::Booster, "Booster: next cell", ']'),
+    Action::same("booster.step_up", Tab::Booster, "Booster: bigger step", '+'),
+    Action::same("booster.step_down", Tab::Booster, "Booster: smaller step", '-'),
+    Action::same("booster.sim_speed", Tab::Booster, "Booster: sim speed", '.'),
+    Action::same("beam.prev_section", Tab::Beam, "Beam: previous section", '['),
+    Action::same("beam.next_section", Tab::Beam, "Beam: next section", ']'),
+    Action::same("beam.step_up", Tab::Beam, "Beam: bigger step", '+'),
+    Action::same("beam.step_down", Tab::Beam, "Beam: smaller step", '-'),
+    Action { id: "frogger.hop_up", tab: Tab::Frogger, name: "Frogger: hop up", key: KeyCode::Up, default: 'k' },
+    Action { id: "frogger.hop_down", tab: Tab::Frogger, name: "Frogger: hop down", key: KeyCode::Down, default: 'j' },
+    Action { id: "frogger.hop_left", tab: Tab::Frogger, name: "Frogger: hop left", key: KeyCode::Left, default: 'h' },
+    Action { id: "frogger.hop_right", tab: Tab::Frogger, name: "Frogger: hop right", key: KeyCode::Right, default: 'l' },
+];
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Layout {
+    Qwerty,
+    Azerty,
+    Qwertz,
+    Dvorak,
+}
+
+impl Layout {
+    pub const ALL: [Layout; 4] = [Layout::Qwerty, Layout::Azerty, Layout::Qwertz, Layout::Dvorak];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Layout::Qwerty => "QWERTY",
+            Layout::Azerty => "AZERTY",
+            Layout::Qwertz => "QWERTZ",
+            Layout::Dvorak => "Dvorak",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let i = Self::ALL.iter().position(|&l| l == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+
+    /// Likely layout for a locale such as `fr_FR.UTF-8`: French and
+    /// Belgian keyboards are AZERTY, German-speaking and most central
+    /// European ones QWERTZ. Dvorak can't be told apart from QWERTY.
+    pub fn for_locale(locale: &str) -> Self {
+        let base = locale.split(['.', '@']).next().unwrap_or("");
+        let (lang, region) = base.split_once('_').unwrap_or((base, ""));
+        match (lang, region) {
+            ("fr", "FR" | "BE") | ("nl", "BE") => Layout::Azerty,
+            ("de" | "cs" | "sk" | "hu" | "sl" | "hr" | "sq", _) | (_, "CH") => Layout::Qwertz,
+            _ => Layout::Qwerty,
+        }
+    }
+
+    fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|l| l.name().eq_ignore_ascii_case(name))
+    }
+
+    /// The layout's default for an action, where it differs from QWERTY.
+    fn preset(self, id: &str) -> Option<char> {
+        let key = match (self, id.split_once('.').map_or(id, |(_, a)| a)) {
+            // Brackets need AltGr; the parentheses sit unshifted on 5 and °
+            (Layout::Azerty, "prev_cell" | "prev_section") => '(',
+            (Layout::Azerty, "next_cell" | "next_section") => ')',
+            (Layout::Azerty, "step_up") => '=',
+            (Layout::Azerty, "sim_speed") => ';',
+            // Umlauts sit where QWERTY has the brackets
+            (Layout::Qwertz, "prev_cell" | "prev_section") => 'ö',
+            (Layout::Qwertz, "next_cell" | "next_section") => 'ä',
+            // Same physical keys as on QWERTY
+            (Layout::Dvorak, "prev_cell" | "prev_section") => '/',
+            (Layout::Dvorak, "next_cell" | "next_section") => '=',
+            (Layout::Dvorak, "step_up") => ']',
+            (Layout::Dvorak, "step_down") => '[',
+            _ => return None,
+        };
+        Some(key)
+    }
+}
+
+/// Keys swapped by the left-handed preset: the arrows and WASD trade
+/// places, and the brackets move next to WASD on Q and E.
+const MIRRORED: [(KeyCode, char); 6] = [
+    (KeyCode::Up, 'w'),
+    (KeyCode::Left, 'a'),
+    (KeyCode::Down, 's'),
+    (KeyCode::Right, 'd'),
+    (KeyCode::Char('['), 'q'),
+    (KeyCode::Char(']'), 'e'),
+];
+
+/// The key on the other side of the mirror, either way round; letters
+/// match in either case. Anything else stays put.
+fn mirror(code: KeyCode) -> KeyCode {
+    for (key, letter) in MIRRORED {
+        if code == key {
+            return KeyCode::Char(letter);
+        }
+        if let KeyCode::Char(c) = code {
+            if c.to_ascii_lowercase() == letter {
+                return key;
+            }
+        }
+    }
+    code
+}
+
+/// Layout preset plus the player's own bindings, saved as
+/// `rustcade.keys` next to the executable.
+pub struct Keymap {
+    pub layout: Layout,
+    /// Mirror every game's keys for the left hand (see `MIRRORED`)
+    pub left_handed: bool,
+    overrides: HashMap<&'static str, KeyBind>,
+    /// `[keys]` bindings from config.toml, used where there's no override
+    config_binds: HashMap<&'static str, KeyBind>,
+    path: PathBuf,
+}
+
+impl Keymap {
+    pub fn load() -> Self {
+        let mut keymap = Keymap {
+            layout: Layout::Qwerty,
+            left_handed: false,
+            overrides: HashMap::new(),
+            config_binds: HashMap::new(),
+            path: Self::keys_path(),
+        };
+        let Ok(text) = fs::read_to_string(&keymap.path) else { return keymap };
+        for line in text.lines() {
+            let Some((key, value)) = line.split_once('=') else { continue };
+            let (key, value) = (key.trim(), value.trim());
+            if key == "layout" {
+                if let Some(layout) = Layout::parse(value) {
+                    keymap.layout = layout;
+                }
+            } else if key == "left_handed" {
+                keymap.left_handed = matches!(value, "on" | "true" | "yes");
+            } else if let Some(action) = ACTIONS.iter().find(|a| a.id == key) {
+                if let Some(bind) = KeyBind::parse(value) {
+                    keymap.overrides.insert(action.id, bind);
+                }
+            }
+        }
+        keymap
+    }
+
+    fn keys_path() -> PathBuf {
+        data_path("rustcade.keys")
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Pick up a hand edit of the bindings file. False if it still says
+    /// what we have, as it does right after we saved it ourselves.
+    pub fn reload(&mut self) -> bool {
+        let fresh = Keymap::load();
+        if fresh.to_text() == self.to_text() { return false; }
+        let config_binds = std::mem::take(&mut self.config_binds);
+        *self = Keymap { config_binds, ..fresh };
+        true
+    }
+
+    /// Take the `[keys]` bindings from a newly read config.toml.
+    pub fn set_config_binds(&mut self, binds: &[(&'static str, KeyBind)]) {
+        self.config_binds = binds.iter().copied().collect();
+    }
+
+    fn to_text(&self) -> String {
+        let mut text = format!("layout = {}\n", self.layout.name());
+        if self.left_handed {
+            text.push_str("left_handed = on\n");
+        }
+        for action in ACTIONS {
+            if let Some(bind) = self.overrides.get(action.id) {
+                text.push_str(&format!("{} = {}\n", action.id, bind.label()));
+            }
+        }
+        text
+    }
+
+    fn save(&self) {
+        let _ = fs::write(&self.path, self.to_text());
+    }
+
+    /// Key currently driving an action: the player's own binding, then
+    /// config.toml's, then the layout preset.
+    pub fn binding(&self, action: &Action) -> KeyBind {
+        self.overrides.get(action.id).or(self.config_binds.get(action.id)).copied().unwrap_or_else(|| {
+            let preset = self.layout.preset(action.id).unwrap_or(action.default);
+            match (self.left_handed, mirror(KeyCode::Char(preset))) {
+                (true, KeyCode::Char(c)) => KeyBind::plain(c),
+                _ => KeyBind::plain(preset),
+            }
+        })
+    }
+
+    pub fn is_custom(&self, action: &Action) -> bool {
+        self.overrides.contains_key(action.id)
+    }
+
+    /// Bound in config.toml and not rebound since.
+    pub fn is_from_config(&self, action: &Action) -> bool {
+        !self.is_custom(action) && self.config_binds.contains_key(action.id)
+    }
+
+    pub fn bind(&mut self, action: &Action, bind: KeyBind) {
+        self.overrides.insert(action.id, bind);
+        self.save();
+    }
+
+    /// Back to config.toml's binding, or the layout preset.
+    pub fn unbind(&mut self, action: &Action) {
+        self.overrides.remove(action.id);
+        self.save();
+    }
+
+    /// Switch presets; custom bindings are dropped.
+    pub fn set_layout(&mut self, layout: Layout) {
+        self.layout = layout;
+        self.overrides.clear();
+        self.save();
+    }
+
+    /// Flip the left-handed mirror; custom bindings are kept.
+    pub fn toggle_left_handed(&mut self) {
+        self.left_handed = !self.left_handed;
+        self.save();
+    }
+
+    /// Turn a key pressed in `tab` into the key its game expects. Keys that
+    /// aren't bound to anything pass through unchanged, or mirrored when
+    /// playing left-handed.
+    pub fn translate(&self, tab: Tab, key: KeyEvent) -> KeyEvent {
+        let pressed = KeyBind::from_event(key);
+        if let Some(action) = ACTIONS.iter().filter(|a| a.tab == tab).find(|a| self.binding(a) == pressed) {
+            return KeyEvent::new(action.key, KeyModifiers::NONE);
+        }
+        if self.left_handed && tab != Tab::Home && !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) {
+            return KeyEvent::new(mirror(key.code), key.modifiers);
+        }
+        key
+    }
+}
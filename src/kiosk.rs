@@ -0,0 +1,85 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::config::Config;
+use crate::scores::{data_path, format_datetime, now_secs};
+
+/// `--kiosk`: the arcade on a shared terminal. Nothing quits to the shell,
+/// the menus that delete or rewrite files are off, an abandoned arcade
+/// falls back to the attract demo, and every visit is appended to a log.
+pub struct Kiosk {
+    idle_timeout: Duration,
+    log_path: PathBuf,
+    last_input: Instant,
+    visit: Option<Visit>,
+}
+
+/// One person at the terminal: from the first key after the attract demo
+/// until they leave it alone for `idle_timeout`.
+struct Visit {
+    started: u64,
+    /// Finished games as (name, score), in the order they ended
+    games: Vec<(&'static str, u32)>,
+}
+
+impl Kiosk {
+    pub fn new(config: &Config) -> Self {
+        Kiosk {
+            idle_timeout: Duration::from_secs(config.kiosk_idle_secs),
+            log_path: config.kiosk_log.clone().unwrap_or_else(Self::default_log_path),
+            last_input: Instant::now(),
+            visit: None,
+        }
+    }
+
+    fn default_log_path() -> PathBuf {
+        data_path("rustcade.kiosk.log")
+    }
+
+    /// A key was pressed; the first one after the demo starts a visit.
+    pub fn input(&mut self) {
+        self.last_input = Instant::now();
+        if self.visit.is_none() {
+            let started = now_secs();
+            self.log(&format!("{}  start", format_datetime(started)));
+            self.visit = Some(Visit { started, games: Vec::new() });
+        }
+    }
+
+    /// Whether the current visit has gone quiet for the idle timeout.
+    pub fn timed_out(&self) -> bool {
+        self.visit.is_some() && self.last_input.elapsed() >= self.idle_timeout
+    }
+
+    /// Note a game that just ended during a visit.
+    pub fn game_over(&mut self, name: &'static str, score: u32) {
+        if let Some(visit) = &mut self.visit {
+            visit.games.push((name, score));
+        }
+    }
+
+    /// Close the visit, if any, with why it ended (`idle` or `exit`).
+    pub fn end_visit(&mut self, reason: &str) {
+        let Some(visit) = self.visit.take() else { return };
+        let ended = now_secs();
+        let length = ended.saturating_sub(visit.started);
+        let games = if visit.games.is_empty() {
+            "no games finished".to_string()
+        } else {
+            visit.games.iter().map(|(name, score)| format!("{} {}", name, score)).collect::<Vec<_>>().join(", ")
+        };
+        self.log(&format!(
+            "{}  end ({}) after {}m{:02}s: {}",
+            format_datetime(ended), reason, length / 60, length % 60, games
+        ));
+    }
+
+    /// Append a line; a log that can't be written never stops the arcade.
+    fn log(&self, line: &str) {
+        if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(&self.log_path) {
+            let _ = writeln!(file, "{}", line);
+        }
+    }
+}
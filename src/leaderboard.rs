@@ -0,0 +1,190 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::thread;
+
+use crate::config::Config;
+use crate::scores::{GAME_NAMES, data_path, now_secs};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum SyncStatus {
+    /// No leaderboard URL configured
+    Disabled,
+    Synced,
+    Syncing,
+    /// Last attempt failed; scores are waiting in the queue
+    Offline,
+}
+
+/// Online leaderboard client. Every submission is queued on disk first and
+/// only dropped from the queue once the server accepted it, so scores set
+/// while offline go up on the next launch.
+pub struct Leaderboard {
+    url: Option<String>,
+    secret: String,
+    /// Signed, form-encoded payloads waiting to be sent (oldest first)
+    queue: Vec<String>,
+    path: PathBuf,
+    status: SyncStatus,
+    /// Upload thread result channel and the number of payloads it was given
+    in_flight: Option<(mpsc::Receiver<usize>, usize)>,
+}
+
+impl Leaderboard {
+    pub fn new(config: &Config) -> Self {
+        let path = Self::queue_path();
+        let queue = fs::read_to_string(&path)
+            .map(|text| text.lines().filter(|l| !l.is_empty()).map(String::from).collect())
+            .unwrap_or_default();
+        let mut lb = Leaderboard {
+            // Nothing can be sent without the `leaderboard` feature
+            url: config.leaderboard_url.clone().filter(|_| cfg!(feature = "leaderboard")),
+            secret: config.leaderboard_secret.clone(),
+            queue,
+            path,
+            status: SyncStatus::Disabled,
+            in_flight: None,
+        };
+        if lb.url.is_some() {
+            lb.status = SyncStatus::Synced;
+            lb.sync();
+        }
+        lb
+    }
+
+    fn queue_path() -> PathBuf {
+        data_path("rustcade.pending")
+    }
+
+    pub fn status(&self) -> SyncStatus {
+        self.status
+    }
+
+    pub fn pending(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Queue a score for upload and try to send it right away. `speed` is
+    /// the handicap in percent, so the server can flag slowed-down runs,
+    /// and `mods` the run's modifier flags (see `scores::flag_labels`).
+    pub fn submit(&mut self, game_idx: usize, name: &str, score: u32, speed: u32, mods: u32) {
+        if self.url.is_none() || game_idx >= GAME_NAMES.len() { return; }
+        let body = format!(
+            "game={}&name={}&score={}&speed={}&mods={}&ts={}",
+            url_encode(GAME_NAMES[game_idx]),
+            url_encode(name),
+            score,
+            speed,
+            mods,
+            now_secs(),
+        );
+        let sig = sign(&self.secret, &body);
+        self.queue.push(format!("{}&sig={}", body, sig));
+        self.write_queue();
+        self.sync();
+    }
+
+    /// Start a background upload of the queue unless one is already running.
+    fn sync(&mut self) {
+        let Some(url) = self.url.clone() else { return };
+        if self.in_flight.is_some() || self.queue.is_empty() { return; }
+
+        let payloads = self.queue.clone();
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            // Stop at the first failure so the queue keeps its order
+            let sent = payloads.iter().take_while(|p| post_form(&url, p)).count();
+            let _ = tx.send(sent);
+        });
+        self.in_flight = Some((rx, self.queue.len()));
+        self.status = SyncStatus::Syncing;
+    }
+
+    /// Collect the result of a finished upload; call once per tick.
+    pub fn poll(&mut self) {
+        let Some((rx, batch)) = &self.in_flight else { return };
+        let batch = *batch;
+        let sent = match rx.try_recv() {
+            Ok(sent) => sent,
+            Err(mpsc::TryRecvError::Empty) => return,
+            Err(mpsc::TryRecvError::Disconnected) => 0,
+        };
+        self.in_flight = None;
+        self.queue.drain(..sent.min(self.queue.len()));
+        self.write_queue();
+        if !self.queue.is_empty() && sent == batch {
+            // New scores were queued while uploading
+            self.sync();
+        } else {
+            self.status = if self.queue.is_empty() { SyncStatus::Synced } else { SyncStatus::Offline };
+        }
+    }
+
+    /// Drop every queued score, including any upload still in flight.
+    pub fn clear_queue(&mut self) {
+        self.queue.clear();
+        self.in_flight = None;
+        self.write_queue();
+        self.status = if self.url.is_some() { SyncStatus::Synced } else { SyncStatus::Disabled };
+    }
+
+    fn write_queue(&self) {
+        if self.queue.is_empty() {
+            let _ = fs::remove_file(&self.path);
+        } else {
+            let _ = fs::write(&self.path, self.queue.join("\n"));
+        }
+    }
+}
+
+fn url_encode(s: &str) -> String {
+    let mut out = String::new();
+    for b in s.bytes() {
+        if b.is_ascii_alphanumeric() || b"-_.~".contains(&b) {
+            out.push(b as char);
+        } else {
+            out.push_str(&format!("%{:02X}", b));
+        }
+    }
+    out
+}
+
+#[cfg(feature = "leaderboard")]
+use net::{post_form, sign};
+
+/// Without the `leaderboard` feature nothing is signed or sent.
+#[cfg(not(feature = "leaderboard"))]
+fn sign(_secret: &str, _body: &str) -> String {
+    String::new()
+}
+
+#[cfg(not(feature = "leaderboard"))]
+fn post_form(_url: &str, _body: &str) -> bool {
+    false
+}
+
+#[cfg(feature = "leaderboard")]
+mod net {
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    use std::time::Duration;
+
+    const NET_TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// POST a form body to an `http://` or `https://` URL; true on 2xx.
+    pub fn post_form(url: &str, body: &str) -> bool {
+        ureq::post(url)
+            .timeout(NET_TIMEOUT)
+            .set("Content-Type", "application/x-www-form-urlencoded")
+            .send_string(body)
+            .is_ok()
+    }
+
+    /// HMAC-SHA256 of `body` under `secret`, as lowercase hex.
+    pub fn sign(secret: &str, body: &str) -> String {
+        let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret.as_bytes()) else { return String::new() };
+        mac.update(body.as_bytes());
+        mac.finalize().into_bytes().iter().map(|b| format!("{:02x}", b)).collect()
+    }
+}
@@ -1,57 +1,77 @@
+mod achievements;
 mod app;
+mod audio;
+mod config;
+mod control;
 mod event;
+mod frontend;
+mod keymap;
+mod kiosk;
 mod games;
+mod leaderboard;
+mod metrics;
+mod plugins;
+mod profile;
+mod rng;
 mod scores;
+mod scripting;
+mod seasons;
+mod session;
+mod stats;
 mod ui;
 
 use std::io;
 
-use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
-    execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
-};
-use ratatui::backend::CrosstermBackend;
-use ratatui::Terminal;
-
 use app::App;
-use event::{Event, EventHandler};
-
-fn main() -> io::Result<()> {
-    // Setup terminal
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
-    terminal.clear()?;
+use frontend::TerminalFrontend;
 
-    // Create app and event handler
-    let mut app = App::new();
-    let event_handler = EventHandler::new(16); // ~60 FPS
-
-    // Main loop
-    loop {
-        terminal.draw(|frame| ui::render(frame, &mut app))?;
+/// Command line options.
+struct Args {
+    seed: Option<u64>,
+    kiosk: bool,
+}
 
-        match event_handler.next()? {
-            Event::Tick => app.on_tick(),
-            Event::Key(key) => app.on_key(key),
+/// Read the command line; checked before the terminal is taken over, so a
+/// mistake is printed where it can be seen.
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args { seed: None, kiosk: false };
+    let mut words = std::env::args().skip(1);
+    while let Some(word) = words.next() {
+        match word.as_str() {
+            "--seed" => {
+                let value = words.next().ok_or("--seed wants a number")?;
+                let seed = value.parse().map_err(|_| format!("--seed wants a number, got {:?}", value))?;
+                args.seed = Some(seed);
+            }
+            "--kiosk" => args.kiosk = true,
+            _ => {}
         }
+    }
+    Ok(args)
+}
 
-        if app.should_quit {
-            break;
+fn main() -> io::Result<()> {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("rustcade: {}", message);
+            std::process::exit(2);
         }
+    };
+    let mut frontend = TerminalFrontend::start()?;
+    let mut app = App::new();
+    if let Some(seed) = args.seed {
+        app.set_seed(seed);
     }
-
-    // Restore terminal
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
-
-    Ok(())
+    if args.kiosk {
+        app.enable_kiosk();
+    }
+    let result = frontend::run(&mut frontend, &mut app);
+    let summary = app.on_exit();
+    // Restores the terminal
+    drop(frontend);
+    for line in summary.iter().flat_map(|s| s.lines()) {
+        println!("{}", line);
+    }
+    result
 }
@@ -0,0 +1,213 @@
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::scores::GAME_NAMES;
+
+/// Upper bounds of the tick and input latency histogram buckets, in microseconds.
+const TICK_BUCKETS_US: [u64; 8] = [250, 500, 1_000, 2_000, 5_000, 10_000, 25_000, 50_000];
+const READ_TIMEOUT: Duration = Duration::from_secs(2);
+/// Keypresses the overlay's average and maximum latency cover.
+const LATENCY_WINDOW: usize = 64;
+
+/// Counters shared with the HTTP thread.
+struct Shared {
+    started: Instant,
+    frames: AtomicU64,
+    games_played: [AtomicU64; GAME_NAMES.len()],
+    scores_submitted: AtomicU64,
+    leaderboard_pending: AtomicU64,
+    /// Cumulative counts per bucket; the last slot is +Inf
+    tick_buckets: [AtomicU64; TICK_BUCKETS_US.len() + 1],
+    tick_sum_us: AtomicU64,
+    /// Keypress to the end of the next frame, same buckets as the ticks
+    input_buckets: [AtomicU64; TICK_BUCKETS_US.len() + 1],
+    input_sum_us: AtomicU64,
+}
+
+/// Recent keypress latencies, in microseconds, for the F3 overlay.
+#[derive(Default)]
+pub struct InputLatency {
+    /// Read by the input thread to handled by `App::on_key`
+    pub handled_us: u64,
+    /// Read by the input thread to the next frame drawn
+    pub frame_us: u64,
+    recent: VecDeque<u64>,
+}
+
+impl InputLatency {
+    /// Average and worst key-to-frame latency over the last few keys.
+    pub fn avg_max_us(&self) -> (u64, u64) {
+        let n = self.recent.len().max(1) as u64;
+        (self.recent.iter().sum::<u64>() / n, self.recent.iter().copied().max().unwrap_or(0))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.recent.is_empty()
+    }
+}
+
+/// Runtime counters for monitoring a long-running cabinet, served in the
+/// Prometheus text format by `serve` when `[metrics] listen` is set. The
+/// counters are always kept; they're a few relaxed atomic adds per frame.
+pub struct Metrics {
+    shared: Arc<Shared>,
+    /// Which tables were over at the last check, to count each game once
+    was_over: [bool; GAME_NAMES.len()],
+    pub input: InputLatency,
+    /// Receipt time of the oldest key handled since the last frame
+    key_pending: Option<Instant>,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        let shared = Arc::new(Shared {
+            started: Instant::now(),
+            frames: AtomicU64::new(0),
+            games_played: std::array::from_fn(|_| AtomicU64::new(0)),
+            scores_submitted: AtomicU64::new(0),
+            leaderboard_pending: AtomicU64::new(0),
+            tick_buckets: std::array::from_fn(|_| AtomicU64::new(0)),
+            tick_sum_us: AtomicU64::new(0),
+            input_buckets: std::array::from_fn(|_| AtomicU64::new(0)),
+            input_sum_us: AtomicU64::new(0),
+        });
+        Metrics { shared, was_over: [false; GAME_NAMES.len()], input: InputLatency::default(), key_pending: None }
+    }
+
+    /// Serve the counters over HTTP on `addr`; Err says why not, for the
+    /// player to see. Each scrape gets its own thread, so a client that
+    /// connects and says nothing doesn't hold up the others.
+    pub fn serve(&self, addr: &str) -> Result<(), String> {
+        let listener = TcpListener::bind(addr).map_err(|e| format!("{}: {}", addr, e))?;
+        let shared = Arc::clone(&self.shared);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { continue };
+                let shared = Arc::clone(&shared);
+                thread::spawn(move || {
+                    let _ = stream.set_read_timeout(Some(READ_TIMEOUT));
+                    let mut head = [0u8; 1024];
+                    let n = stream.read(&mut head).unwrap_or(0);
+                    let request = String::from_utf8_lossy(&head[..n]);
+                    let path = request.split_whitespace().nth(1).unwrap_or("");
+                    let response = if request.starts_with("GET ") && (path == "/metrics" || path == "/") {
+                        let body = shared.render();
+                        format!(
+                            "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                            body.len(),
+                            body,
+                        )
+                    } else {
+                        "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string()
+                    };
+                    let _ = stream.write_all(response.as_bytes());
+                });
+            }
+        });
+        Ok(())
+    }
+
+    /// Count a frame, and close out the latency of any key it shows.
+    pub fn frame_rendered(&mut self) {
+        self.shared.frames.fetch_add(1, Ordering::Relaxed);
+        let Some(received) = self.key_pending.take() else { return };
+        let us = received.elapsed().as_micros() as u64;
+        self.input.frame_us = us;
+        if self.input.recent.len() == LATENCY_WINDOW {
+            self.input.recent.pop_front();
+        }
+        self.input.recent.push_back(us);
+        observe(&self.shared.input_buckets, &self.shared.input_sum_us, us);
+    }
+
+    /// A key from the input thread has been handled; its frame is next.
+    pub fn key_handled(&mut self, received: Instant) {
+        self.input.handled_us = received.elapsed().as_micros() as u64;
+        self.key_pending.get_or_insert(received);
+    }
+
+    /// Frames drawn since launch.
+    pub fn frames(&self) -> u64 {
+        self.shared.frames.load(Ordering::Relaxed)
+    }
+
+    pub fn score_submitted(&self) {
+        self.shared.scores_submitted.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn set_leaderboard_pending(&self, pending: usize) {
+        self.shared.leaderboard_pending.store(pending as u64, Ordering::Relaxed);
+    }
+
+    /// Count a game as played when its table first reports game over.
+    pub fn game_over(&mut self, table: usize, over: bool) {
+        if over && !self.was_over[table] {
+            self.shared.games_played[table].fetch_add(1, Ordering::Relaxed);
+        }
+        self.was_over[table] = over;
+    }
+
+    pub fn tick_took(&self, elapsed: Duration) {
+        observe(&self.shared.tick_buckets, &self.shared.tick_sum_us, elapsed.as_micros() as u64);
+    }
+}
+
+fn observe(buckets: &[AtomicU64; TICK_BUCKETS_US.len() + 1], sum_us: &AtomicU64, us: u64) {
+    let bucket = TICK_BUCKETS_US.iter().position(|&b| us <= b).unwrap_or(TICK_BUCKETS_US.len());
+    buckets[bucket].fetch_add(1, Ordering::Relaxed);
+    sum_us.fetch_add(us, Ordering::Relaxed);
+}
+
+impl Shared {
+    fn render(&self) -> String {
+        let get = |a: &AtomicU64| a.load(Ordering::Relaxed);
+        let mut out = String::new();
+
+        out.push_str("# HELP rustcade_uptime_seconds Seconds since the arcade started.\n");
+        out.push_str("# TYPE rustcade_uptime_seconds gauge\n");
+        out.push_str(&format!("rustcade_uptime_seconds {}\n", self.started.elapsed().as_secs()));
+
+        out.push_str("# HELP rustcade_frames_rendered_total Frames drawn to the terminal.\n");
+        out.push_str("# TYPE rustcade_frames_rendered_total counter\n");
+        out.push_str(&format!("rustcade_frames_rendered_total {}\n", get(&self.frames)));
+
+        out.push_str("# HELP rustcade_games_played_total Games that reached game over.\n");
+        out.push_str("# TYPE rustcade_games_played_total counter\n");
+        for (name, count) in GAME_NAMES.iter().zip(&self.games_played) {
+            out.push_str(&format!("rustcade_games_played_total{{game=\"{}\"}} {}\n", name, get(count)));
+        }
+
+        out.push_str("# HELP rustcade_scores_submitted_total Scores entered on the high score tables.\n");
+        out.push_str("# TYPE rustcade_scores_submitted_total counter\n");
+        out.push_str(&format!("rustcade_scores_submitted_total {}\n", get(&self.scores_submitted)));
+
+        out.push_str("# HELP rustcade_leaderboard_pending Scores waiting to be uploaded.\n");
+        out.push_str("# TYPE rustcade_leaderboard_pending gauge\n");
+        out.push_str(&format!("rustcade_leaderboard_pending {}\n", get(&self.leaderboard_pending)));
+
+        out.push_str("# HELP rustcade_tick_seconds Time spent in each main loop tick.\n");
+        out.push_str("# TYPE rustcade_tick_seconds histogram\n");
+        histogram(&mut out, "rustcade_tick_seconds", &self.tick_buckets, &self.tick_sum_us);
+
+        out.push_str("# HELP rustcade_input_latency_seconds Keypress read to the next frame drawn.\n");
+        out.push_str("# TYPE rustcade_input_latency_seconds histogram\n");
+        histogram(&mut out, "rustcade_input_latency_seconds", &self.input_buckets, &self.input_sum_us);
+        out
+    }
+}
+
+fn histogram(out: &mut String, name: &str, buckets: &[AtomicU64], sum_us: &AtomicU64) {
+    let mut cumulative = 0;
+    for (i, bucket) in buckets.iter().enumerate() {
+        cumulative += bucket.load(Ordering::Relaxed);
+        let le = TICK_BUCKETS_US.get(i).map_or("+Inf".to_string(), |us| format!("{}", *us as f64 / 1e6));
+        out.push_str(&format!("{}_bucket{{le=\"{}\"}} {}\n", name, le, cumulative));
+    }
+    out.push_str(&format!("{}_sum {}\n", name, sum_us.load(Ordering::Relaxed) as f64 / 1e6));
+    out.push_str(&format!("{}_count {}\n", name, cumulative));
+}
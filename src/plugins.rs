@@ -0,0 +1,422 @@
+//! Extra games from shared libraries, built with `--features plugins`.
+//! Every library in the `plugins/` folder next to the binary that exports
+//! `rustcade_plugin_v1` is listed on the Plugins tab. The export returns a
+//! static table of C functions, so a game can be written in any language
+//! with a C ABI:
+//!
+//! ```c
+//! typedef struct { uint32_t ch, fg, bg; } RcCell;  /* fg/bg 0xRRGGBB, above 0xFFFFFF = default */
+//! typedef struct {
+//!     uint32_t abi_version;                         /* 1 */
+//!     const char *name, *icon, *author, *description;
+//!     const char *tags;                             /* "puzzle,physics" */
+//!     const char *controls;                         /* "Space\tJump\nP\tPause" */
+//!     void *(*create)(void);
+//!     void (*destroy)(void *game);
+//!     void (*update)(void *game);                   /* one 16 ms simulation step */
+//!     void (*key)(void *game, uint32_t code, uint32_t modifiers);
+//!     void (*reset)(void *game);
+//!     uint32_t (*score)(const void *game);
+//!     uint32_t (*flags)(const void *game);          /* 1 = game over, 2 = paused */
+//!     void (*render)(void *game, RcCell *cells, uint16_t width, uint16_t height);
+//! } RcPluginV1;
+//! const RcPluginV1 *rustcade_plugin_v1(void);
+//! ```
+//!
+//! `code` is a character key's Unicode scalar, or `0x110000` plus Left 1,
+//! Right 2, Up 3, Down 4, Enter 5, Esc 6, Backspace 7, Tab 8, Delete 9, or
+//! `0x110100` plus n for Fn. `modifiers` has Shift 1, Ctrl 2 and Alt 4.
+//! `render` gets a blank grid, row by row, to draw into. Plugin games run
+//! inside the arcade's process and aren't put on the score tables.
+
+use std::path::PathBuf;
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::games::{Game, GameInfo};
+use crate::scores::data_path;
+use crate::ui::hud;
+use crate::ui::theme::{Ink, Role, Theme};
+
+/// What the Plugins tab itself shows in help while no game is picked.
+const HOST_INFO: GameInfo = GameInfo {
+    name: "Plugins",
+    icon: "🧩",
+    author: "RustCade",
+    blurb: "Games loaded\nfrom plugins/",
+    description: "Community games loaded from shared libraries.",
+    controls: &[("↑ / ↓", "Select game"), ("Enter", "Play"), ("L", "Back to the list (paused or over)")],
+    tags: &[],
+    color: Ink(Color::Rgb(200, 160, 255), Role::Violet),
+    border_color: Ink(Color::Rgb(100, 80, 140), Role::Violet),
+};
+
+/// The Plugins tab: a list of loaded games, and the one being played.
+pub struct PluginHost {
+    games: Vec<Box<dyn Game>>,
+    selected: usize,
+    /// Game being played; None shows the list
+    active: Option<usize>,
+    /// Libraries that didn't load, and why
+    errors: Vec<String>,
+}
+
+impl PluginHost {
+    pub fn load() -> Self {
+        let mut errors = Vec::new();
+        let games = load_dir(&Self::plugins_dir(), &mut errors);
+        PluginHost { games, selected: 0, active: None, errors }
+    }
+
+    /// The tab only shows once there's something to show.
+    pub fn is_empty(&self) -> bool {
+        self.games.is_empty() && self.errors.is_empty()
+    }
+
+    fn plugins_dir() -> PathBuf {
+        data_path("plugins")
+    }
+
+    fn render_list(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let mut lines = vec![Line::from("")];
+        for (i, game) in self.games.iter().enumerate() {
+            let info = game.info();
+            let selected = i == self.selected;
+            let marker = if selected { "▶ " } else { "  " };
+            let name_style = if selected {
+                Style::default().fg(theme.paint(Color::White, Role::Bright)).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.ink(info.color))
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {}", marker), Style::default().fg(theme.paint(Color::Rgb(255, 220, 80), Role::Yellow))),
+                Span::styled(format!("{} {}", info.icon, info.name), name_style),
+                Span::styled(format!("  by {}", info.author), Style::default().fg(theme.paint(Color::Rgb(80, 80, 100), Role::Faint))),
+            ]));
+            lines.push(Line::from(Span::styled(
+                format!("      {}", info.description),
+                Style::default().fg(theme.paint(Color::Rgb(120, 120, 140), Role::Dim)),
+            )));
+        }
+        if self.games.is_empty() {
+            lines.push(Line::from(Span::styled("  No games loaded.", Style::default().fg(theme.paint(Color::Gray, Role::Dim)))));
+        }
+        for error in &self.errors {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(format!("  ⚠ {}", error), Style::default().fg(theme.ink(crate::ui::fx::alert_color())))));
+        }
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.ink(HOST_INFO.border_color)))
+            .title(" 🧩 Plugins ")
+            .title_style(Style::default().fg(theme.ink(HOST_INFO.color)).add_modifier(Modifier::BOLD))
+            .title_bottom(hud::menu_legend(None, &[("↑↓", "Select"), ("Enter", "Play")], theme));
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
+
+impl Game for PluginHost {
+    fn info(&self) -> &'static GameInfo {
+        match self.active {
+            Some(g) => self.games[g].info(),
+            None => &HOST_INFO,
+        }
+    }
+
+    fn update(&mut self) {
+        if let Some(g) = self.active {
+            self.games[g].update();
+        }
+    }
+
+    fn handle_input(&mut self, key: KeyEvent) {
+        let Some(g) = self.active else {
+            match key.code {
+                KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+                KeyCode::Down => self.selected = (self.selected + 1).min(self.games.len().saturating_sub(1)),
+                KeyCode::Enter if !self.games.is_empty() => self.active = Some(self.selected),
+                _ => {}
+            }
+            return;
+        };
+        let game = &mut self.games[g];
+        if matches!(key.code, KeyCode::Char('l') | KeyCode::Char('L')) && (game.is_game_over() || game.is_paused()) {
+            self.active = None;
+            return;
+        }
+        game.handle_input(key);
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        match self.active {
+            Some(g) => {
+                let block = self.games[g].title_bar(theme);
+                let inner = block.inner(area);
+                frame.render_widget(block, area);
+                self.games[g].render(frame, inner, theme);
+            }
+            None => self.render_list(frame, area, theme),
+        }
+    }
+
+    fn reset(&mut self) {
+        if let Some(g) = self.active {
+            self.games[g].reset();
+        }
+    }
+
+    fn get_score(&self) -> u32 {
+        self.active.map_or(0, |g| self.games[g].get_score())
+    }
+
+    fn is_game_over(&self) -> bool {
+        self.active.is_some_and(|g| self.games[g].is_game_over())
+    }
+
+    /// The list counts as paused, so the event loop can idle on it.
+    fn is_paused(&self) -> bool {
+        self.active.is_none_or(|g| self.games[g].is_paused())
+    }
+}
+
+#[cfg(feature = "plugins")]
+use native::load_dir;
+
+/// Without the `plugins` feature there's nothing to load.
+#[cfg(not(feature = "plugins"))]
+fn load_dir(_dir: &std::path::Path, _errors: &mut Vec<String>) -> Vec<Box<dyn Game>> {
+    Vec::new()
+}
+
+#[cfg(feature = "plugins")]
+mod native {
+    use std::ffi::{c_char, c_void, CStr};
+    use std::fs;
+    use std::path::Path;
+
+    use crossterm::event::{KeyCode, KeyEvent};
+    use libloading::Library;
+    use ratatui::prelude::*;
+
+    use crate::games::{Game, GameInfo};
+    use crate::ui::theme::{Ink, Role, Theme};
+
+    const ABI_VERSION: u32 = 1;
+    const ENTRY: &[u8] = b"rustcade_plugin_v1";
+    const FLAG_GAME_OVER: u32 = 1;
+    const FLAG_PAUSED: u32 = 2;
+    /// Cell colours above 0xFFFFFF keep the terminal's default
+    const DEFAULT_COLOR: u32 = 0x0100_0000;
+    const SPECIAL_KEY: u32 = 0x11_0000;
+    const FN_KEY: u32 = 0x11_0100;
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct Cell {
+        ch: u32,
+        fg: u32,
+        bg: u32,
+    }
+
+    /// `RcPluginV1`. Function pointers are nullable on the C side, so
+    /// they're checked once at load.
+    #[repr(C)]
+    struct Table {
+        abi_version: u32,
+        name: *const c_char,
+        icon: *const c_char,
+        author: *const c_char,
+        description: *const c_char,
+        tags: *const c_char,
+        controls: *const c_char,
+        create: Option<extern "C" fn() -> *mut c_void>,
+        destroy: Option<extern "C" fn(*mut c_void)>,
+        update: Option<extern "C" fn(*mut c_void)>,
+        key: Option<extern "C" fn(*mut c_void, u32, u32)>,
+        reset: Option<extern "C" fn(*mut c_void)>,
+        score: Option<extern "C" fn(*const c_void) -> u32>,
+        flags: Option<extern "C" fn(*const c_void) -> u32>,
+        render: Option<extern "C" fn(*mut c_void, *mut Cell, u16, u16)>,
+    }
+
+    /// The table's functions, all present.
+    struct Funcs {
+        destroy: extern "C" fn(*mut c_void),
+        update: extern "C" fn(*mut c_void),
+        key: extern "C" fn(*mut c_void, u32, u32),
+        reset: extern "C" fn(*mut c_void),
+        score: extern "C" fn(*const c_void) -> u32,
+        flags: extern "C" fn(*const c_void) -> u32,
+        render: extern "C" fn(*mut c_void, *mut Cell, u16, u16),
+    }
+
+    /// One game from a library. Dropping it destroys the game, then
+    /// unloads the library its functions live in.
+    struct NativeGame {
+        funcs: Funcs,
+        game: *mut c_void,
+        info: &'static GameInfo,
+        cells: Vec<Cell>,
+        _library: Library,
+    }
+
+    /// Every plugin library in `dir`; ones that fail are noted in `errors`.
+    pub fn load_dir(dir: &Path, errors: &mut Vec<String>) -> Vec<Box<dyn Game>> {
+        let Ok(entries) = fs::read_dir(dir) else { return Vec::new() };
+        let mut paths: Vec<_> = entries
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|ext| ext == std::env::consts::DLL_EXTENSION))
+            .collect();
+        paths.sort();
+        let mut games: Vec<Box<dyn Game>> = Vec::new();
+        for path in paths {
+            let file = path.file_name().map_or(String::new(), |f| f.to_string_lossy().into_owned());
+            match load(&path) {
+                Ok(game) => games.push(Box::new(game)),
+                Err(e) => errors.push(format!("{}: {}", file, e)),
+            }
+        }
+        games
+    }
+
+    fn load(path: &Path) -> Result<NativeGame, String> {
+        // SAFETY: loading runs the library's initialisers; plugins are
+        // trusted code the player installed.
+        let library = unsafe { Library::new(path) }.map_err(|e| e.to_string())?;
+        // SAFETY: the symbol is declared as `const RcPluginV1 *(void)`.
+        let entry = unsafe { library.get::<extern "C" fn() -> *const Table>(ENTRY) }.map_err(|e| e.to_string())?;
+        // SAFETY: the table is static data inside the library, which
+        // outlives every use through `NativeGame::_library`.
+        let table = unsafe { entry().as_ref() }.ok_or("rustcade_plugin_v1 returned NULL")?;
+        if table.abi_version != ABI_VERSION {
+            return Err(format!("ABI version {}, expected {}", table.abi_version, ABI_VERSION));
+        }
+        let missing = |name: &str| format!("missing {}", name);
+        let create = table.create.ok_or_else(|| missing("create"))?;
+        let funcs = Funcs {
+            destroy: table.destroy.ok_or_else(|| missing("destroy"))?,
+            update: table.update.ok_or_else(|| missing("update"))?,
+            key: table.key.ok_or_else(|| missing("key"))?,
+            reset: table.reset.ok_or_else(|| missing("reset"))?,
+            score: table.score.ok_or_else(|| missing("score"))?,
+            flags: table.flags.ok_or_else(|| missing("flags"))?,
+            render: table.render.ok_or_else(|| missing("render"))?,
+        };
+        let info = leak_info(table, path);
+        let game = create();
+        if game.is_null() {
+            return Err("create returned NULL".to_string());
+        }
+        Ok(NativeGame { funcs, game, info, cells: Vec::new(), _library: library })
+    }
+
+    /// Copy the table's strings out, for a `GameInfo` that lives as long
+    /// as the arcade. Plugins load once, so this leaks a few bytes each.
+    fn leak_info(table: &Table, path: &Path) -> &'static GameInfo {
+        let text = |p: *const c_char| -> &'static str {
+            if p.is_null() { return ""; }
+            // SAFETY: non-null strings in the table are NUL-terminated.
+            let s = unsafe { CStr::from_ptr(p) }.to_string_lossy().into_owned();
+            Box::leak(s.into_boxed_str())
+        };
+        let mut name = text(table.name);
+        if name.is_empty() {
+            name = Box::leak(path.file_stem().unwrap_or_default().to_string_lossy().into_owned().into_boxed_str());
+        }
+        let tags: Vec<&'static str> = text(table.tags).split(',').map(str::trim).filter(|t| !t.is_empty()).collect();
+        let controls: Vec<(&'static str, &'static str)> =
+            text(table.controls).lines().filter_map(|line| line.split_once('\t')).collect();
+        Box::leak(Box::new(GameInfo {
+            name,
+            icon: text(table.icon),
+            author: text(table.author),
+            blurb: "",
+            description: text(table.description),
+            controls: Box::leak(controls.into_boxed_slice()),
+            tags: Box::leak(tags.into_boxed_slice()),
+            color: Ink(Color::Rgb(200, 160, 255), Role::Violet),
+            border_color: Ink(Color::Rgb(100, 80, 140), Role::Violet),
+        }))
+    }
+
+    /// A key as the ABI's (code, modifiers); None for keys it has no code for.
+    fn encode_key(key: KeyEvent) -> Option<(u32, u32)> {
+        let code = match key.code {
+            KeyCode::Char(c) => c as u32,
+            KeyCode::Left => SPECIAL_KEY + 1,
+            KeyCode::Right => SPECIAL_KEY + 2,
+            KeyCode::Up => SPECIAL_KEY + 3,
+            KeyCode::Down => SPECIAL_KEY + 4,
+            KeyCode::Enter => SPECIAL_KEY + 5,
+            KeyCode::Esc => SPECIAL_KEY + 6,
+            KeyCode::Backspace => SPECIAL_KEY + 7,
+            KeyCode::Tab => SPECIAL_KEY + 8,
+            KeyCode::Delete => SPECIAL_KEY + 9,
+            KeyCode::F(n) => FN_KEY + n as u32,
+            _ => return None,
+        };
+        // crossterm's Shift, Ctrl and Alt bits are the ABI's
+        Some((code, key.modifiers.bits() as u32 & 0b111))
+    }
+
+    fn color(v: u32) -> Color {
+        if v >= DEFAULT_COLOR { Color::Reset } else { Color::Rgb((v >> 16) as u8, (v >> 8) as u8, v as u8) }
+    }
+
+    impl Game for NativeGame {
+        fn info(&self) -> &'static GameInfo {
+            self.info
+        }
+
+        fn update(&mut self) {
+            (self.funcs.update)(self.game);
+        }
+
+        fn handle_input(&mut self, key: KeyEvent) {
+            if let Some((code, modifiers)) = encode_key(key) {
+                (self.funcs.key)(self.game, code, modifiers);
+            }
+        }
+
+        fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+            let blank = Cell { ch: ' ' as u32, fg: DEFAULT_COLOR, bg: DEFAULT_COLOR };
+            self.cells.clear();
+            self.cells.resize(area.width as usize * area.height as usize, blank);
+            (self.funcs.render)(self.game, self.cells.as_mut_ptr(), area.width, area.height);
+            let buf = frame.buffer_mut();
+            for (i, c) in self.cells.iter().enumerate() {
+                let x = area.x + (i % area.width as usize) as u16;
+                let y = area.y + (i / area.width as usize) as u16;
+                buf[(x, y)].set_char(char::from_u32(c.ch).unwrap_or(' ')).set_fg(color(c.fg)).set_bg(color(c.bg));
+            }
+            // Plugins pick plain RGB, so the theme can only guess at them
+            theme.recolour(buf, area);
+        }
+
+        fn reset(&mut self) {
+            (self.funcs.reset)(self.game);
+        }
+
+        fn get_score(&self) -> u32 {
+            (self.funcs.score)(self.game)
+        }
+
+        fn is_game_over(&self) -> bool {
+            (self.funcs.flags)(self.game) & FLAG_GAME_OVER != 0
+        }
+
+        fn is_paused(&self) -> bool {
+            (self.funcs.flags)(self.game) & FLAG_PAUSED != 0
+        }
+    }
+
+    impl Drop for NativeGame {
+        fn drop(&mut self) {
+            (self.funcs.destroy)(self.game);
+        }
+    }
+}
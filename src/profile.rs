@@ -0,0 +1,57 @@
+use crate::config::Config;
+use crate::scores::now_secs;
+use crate::session::{load_progress, save_progress, StateReader, StateWriter};
+use crate::ui::compat::RenderProfile;
+use crate::ui::theme::Theme;
+
+const PROFILE_SAVE: &str = "profile";
+
+/// The player, as set up by the first-run wizard. Missing at startup
+/// means this is the first run (or progress was reset), and the wizard
+/// is shown.
+#[derive(Clone)]
+pub struct Profile {
+    /// Filled in when a score makes a table; empty to type one each time
+    pub name: String,
+    pub theme: Theme,
+    /// Chosen glyph set; None follows `[display] profile` in config.toml
+    pub charset: Option<RenderProfile>,
+    /// Unix seconds the profile was made
+    pub created: u64,
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Profile { name: String::new(), theme: Theme::Arcade, charset: None, created: 0 }
+    }
+}
+
+impl Profile {
+    pub fn load() -> Option<Profile> {
+        let data = load_progress(PROFILE_SAVE)?;
+        let mut r = StateReader::new(&data);
+        let name = r.string()?;
+        let theme = Theme::from_index(r.u8()?);
+        // 0 is auto, then `RenderProfile::ALL` from 1
+        let charset = r.u8()?.checked_sub(1).and_then(|i| RenderProfile::ALL.get(i as usize).copied());
+        let created = r.u64()?;
+        Some(Profile { name, theme, charset, created })
+    }
+
+    pub fn save(&mut self) {
+        if self.created == 0 {
+            self.created = now_secs();
+        }
+        let mut w = StateWriter::new();
+        w.string(&self.name);
+        w.u8(self.theme as u8);
+        w.u8(self.charset.map_or(0, |p| p as u8 + 1));
+        w.u64(self.created);
+        save_progress(PROFILE_SAVE, &w.finish());
+    }
+
+    /// Glyph set to draw with: the profile's own pick, else config.toml's.
+    pub fn render_profile(&self, config: &Config) -> RenderProfile {
+        self.charset.unwrap_or(config.render_profile)
+    }
+}
@@ -0,0 +1,59 @@
+use rand::{Error, RngCore};
+
+/// Mixes the arcade seed with a game's index so each game gets its own stream
+const GAME_SALT: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// The random stream a game draws from: SplitMix64, so its whole state is
+/// one u64 that saves with the game and resumes where it left off. It's an
+/// `RngCore`, so `gen_range`, `gen_bool` and `shuffle` work as usual.
+#[derive(Clone)]
+pub struct GameRng {
+    state: u64,
+}
+
+impl GameRng {
+    pub fn new(seed: u64) -> Self {
+        GameRng { state: seed }
+    }
+
+    /// A fresh stream for ordinary play.
+    pub fn from_entropy() -> Self {
+        GameRng::new(rand::random())
+    }
+
+    /// Game `game`'s stream under an arcade-wide `--seed`.
+    pub fn for_game(seed: u64, game: usize) -> Self {
+        GameRng::new(seed ^ (game as u64 + 1).wrapping_mul(GAME_SALT))
+    }
+
+    /// Everything needed to pick the stream up again with `new`.
+    pub fn state(&self) -> u64 {
+        self.state
+    }
+}
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(GAME_SALT);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
@@ -1,20 +1,155 @@
 use std::fs;
 use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::seasons::{civil_from_days, SeasonPeriod};
 
 const MAGIC: &[u8; 4] = b"RCS2";
-const NUM_GAMES: usize = 8;
+const HISTORY_MAGIC: &[u8; 4] = b"RCH5";
+/// History written before records carried a detail stat
+const HISTORY_MAGIC_V1: &[u8; 4] = b"RCH1";
+/// History written before records carried the game speed
+const HISTORY_MAGIC_V2: &[u8; 4] = b"RCH2";
+/// History written before records carried modifier flags
+const HISTORY_MAGIC_V3: &[u8; 4] = b"RCH3";
+/// History written before records carried extra stats
+const HISTORY_MAGIC_V4: &[u8; 4] = b"RCH4";
+const NUM_GAMES: usize = 19;
 const SCORES_PER_GAME: usize = 3;
 const TOTAL_SCORES: usize = NUM_GAMES * SCORES_PER_GAME;
 const NAME_LEN: usize = 9;
 // Each entry: 9 bytes name + 4 bytes score = 13 bytes
 const ENTRY_SIZE: usize = NAME_LEN + 4;
-// File size: 4 magic + 18 * 13 = 238 bytes
+// File size: 4 magic + TOTAL_SCORES entries (older files hold fewer games)
 const FILE_SIZE: usize = 4 + TOTAL_SCORES * ENTRY_SIZE;
 
 pub const GAME_NAMES: [&str; NUM_GAMES] = [
-    "Frogger", "Breakout", "Dino Run", "Invaders", "JezzBall", "Asteroids", "Booster", "Beam",
+    "Frogger",
+    "Breakout",
+    "Dino Run",
+    "Invaders",
+    "JezzBall",
+    "Asteroids",
+    "Booster",
+    "Beam",
+    "Beam TA",
+    "Beam Dump",
+    "Scope",
+    "Router",
+    "Racer",
+    "Delve",
+    "Columns",
+    "Whack",
+    "Life",
+    "Pong",
+    "Minesweeper",
 ];
 
+/// Beam time attack's table, right after Beam's. Games added since come
+/// after it, so their tables are one on from their Home tile.
+pub const BEAM_TA: usize = 8;
+
+/// Score table of a game, by Home tile index.
+pub fn table_of(game: usize) -> usize {
+    if game < BEAM_TA { game } else { game + 1 }
+}
+
+/// Home tile index of the game a score table belongs to.
+pub fn game_of(table: usize) -> usize {
+    if table <= BEAM_TA { table.min(BEAM_TA - 1) } else { table - 1 }
+}
+
+/// Tables whose score is a time in centiseconds, where lower is better.
+const TIMED_GAMES: [usize; 1] = [8];
+
+pub fn is_timed(game_idx: usize) -> bool {
+    TIMED_GAMES.contains(&game_idx)
+}
+
+/// Score as shown to the player: points, or seconds for timed tables.
+pub fn format_score(game_idx: usize, score: u32) -> String {
+    if is_timed(game_idx) {
+        format!("{}.{:02}s", score / 100, score % 100)
+    } else {
+        score.to_string()
+    }
+}
+
+/// Extra stat a table records next to the score, e.g. waves cleared.
+pub fn detail_label(game_idx: usize) -> Option<&'static str> {
+    match game_idx {
+        2 => Some("m"),
+        3 => Some("waves"),
+        4 => Some("levels"),
+        9 => Some("targets"),
+        10 => Some("% acc"),
+        11 => Some("packets"),
+        12 => Some("checkpoints"),
+        13 => Some("floors"),
+        14 => Some("gems"),
+        15 => Some("ms median"),
+        16 => Some("challenges"),
+        17 => Some("conceded"),
+        18 => Some("secs"),
+        _ => None,
+    }
+}
+
+/// Detail as shown in score lists ("4 waves"); empty for tables without one.
+pub fn format_detail(game_idx: usize, detail: u32) -> String {
+    detail_label(game_idx).map_or(String::new(), |label| format!("{} {}", detail, label))
+}
+
+/// Further stats a table records after the detail, as shown in score
+/// lists ("57 obstacles, top 2.4×"); empty for tables without any.
+pub fn format_extra(game_idx: usize, extra: &[u32]) -> String {
+    match (game_idx, extra) {
+        // Obstacles cleared, then top speed as a percentage of the start
+        (2, [obstacles, top_speed, ..]) => format!("{} obstacles, top {:.1}×", obstacles, *top_speed as f32 / 100.0),
+        // Ships lost, then shot accuracy in percent
+        (5, [lost, accuracy, ..]) => format!("{} ships lost, {}% accuracy", lost, accuracy),
+        _ => String::new(),
+    }
+}
+
+/// Modifier toggles a table records as bit flags, lowest bit first, as
+/// (short tag, description).
+pub fn flag_labels(game_idx: usize) -> &'static [(&'static str, &'static str)] {
+    match game_idx {
+        2 => &[("DAY", "daily seeded run")],
+        3 => &[("NS", "no shields"), ("INV", "invisible invaders"), ("2xB", "double-speed bombs"), ("WAV", "custom wave files")],
+        13 => &[("DAY", "daily dungeon")],
+        17 => &[("EZ", "easy CPU"), ("IMP", "impossible CPU")],
+        18 => &[("INT", "intermediate 16×16 board"), ("EXP", "expert 30×16 board")],
+        _ => &[],
+    }
+}
+
+/// Modifiers as shown in score lists ("NS+INV"); empty when none were on.
+pub fn format_flags(game_idx: usize, flags: u32) -> String {
+    flag_labels(game_idx)
+        .iter()
+        .enumerate()
+        .filter(|(bit, _)| flags & (1 << bit) != 0)
+        .map(|(_, (tag, _))| *tag)
+        .collect::<Vec<_>>()
+        .join("+")
+}
+
+/// Game speed multiplier as shown next to a score ("0.75×"); empty at
+/// normal speed. Speeds are stored as percentages.
+pub fn format_speed(speed: u32) -> String {
+    if speed == 100 { return String::new(); }
+    let text = format!("{:.2}", speed as f32 / 100.0);
+    format!("{}×", text.trim_end_matches('0').trim_end_matches('.'))
+}
+
+/// True if `score` ranks ahead of `other` in this game's table (0 = empty slot).
+fn beats(game_idx: usize, score: u32, other: u32) -> bool {
+    if other == 0 { return score > 0; }
+    if is_timed(game_idx) { score < other } else { score > other }
+}
+
 #[derive(Clone)]
 pub struct ScoreEntry {
     pub name: String,
@@ -30,43 +165,90 @@ impl ScoreEntry {
     }
 }
 
+/// One submitted score, kept even after it falls out of the top 3.
+#[derive(Clone)]
+pub struct HistoryEntry {
+    pub game: usize,
+    pub name: String,
+    pub score: u32,
+    /// Unix seconds; 0 for scores carried over from before history existed
+    pub timestamp: u64,
+    /// Table-specific stat (see `detail_label`); 0 when the table has none
+    pub detail: u32,
+    /// Game speed handicap in percent (100 = normal)
+    pub speed: u32,
+    /// Modifiers the run was played with (see `flag_labels`)
+    pub flags: u32,
+    /// Stats past the detail (see `format_extra`); empty when the table has none
+    pub extra: Vec<u32>,
+}
+
 #[derive(Clone)]
 pub struct HighScores {
     scores: Vec<Vec<ScoreEntry>>,
     path: PathBuf,
+    history: Vec<HistoryEntry>,
+    history_path: PathBuf,
+    seasons: SeasonPeriod,
+    /// Label of the season the top 3 tables currently hold
+    season: Option<String>,
     /// Track which games have had their score submitted this session
     /// to avoid duplicate submissions
     submitted: [bool; NUM_GAMES],
 }
 
+/// `file_name` next to the executable, where the arcade keeps everything it
+/// saves and reads; relative to the working directory if that's unknown.
+pub fn data_path(file_name: &str) -> PathBuf {
+    if let Ok(exe) = std::env::current_exe() {
+        if let Some(dir) = exe.parent() {
+            return dir.join(file_name);
+        }
+    }
+    PathBuf::from(file_name)
+}
+
 impl HighScores {
     pub fn load() -> Self {
-        let path = Self::scores_path();
+        let path = data_path("rustcade.scores");
         let mut hs = HighScores {
             scores: (0..NUM_GAMES)
                 .map(|_| (0..SCORES_PER_GAME).map(|_| ScoreEntry::empty()).collect())
                 .collect(),
             path,
+            history: Vec::new(),
+            history_path: data_path("rustcade.history"),
+            seasons: SeasonPeriod::None,
+            season: None,
             submitted: [false; NUM_GAMES],
         };
         hs.read_file();
-        hs
-    }
-
-    fn scores_path() -> PathBuf {
-        // Store next to the executable
-        if let Ok(exe) = std::env::current_exe() {
-            if let Some(dir) = exe.parent() {
-                return dir.join("rustcade.scores");
+        if !hs.read_history() {
+            // First run with history: seed it from the existing top 3
+            for game in 0..NUM_GAMES {
+                for entry in &hs.scores[game] {
+                    if entry.score > 0 {
+                        hs.history.push(HistoryEntry {
+                            game,
+                            name: entry.name.clone(),
+                            score: entry.score,
+                            timestamp: 0,
+                            detail: 0,
+                            speed: 100,
+                            flags: 0,
+                            extra: Vec::new(),
+                        });
+                    }
+                }
             }
         }
-        PathBuf::from("rustcade.scores")
+        hs
     }
 
     fn read_file(&mut self) {
         let Ok(data) = fs::read(&self.path) else { return };
-        if data.len() < FILE_SIZE { return; }
-        if &data[0..4] != MAGIC { return; }
+        // Shorter files predate the newer tables; read the games they have
+        if data.len() < 4 || &data[0..4] != MAGIC { return; }
 
         let mut offset = 4;
         for game in 0..NUM_GAMES {
@@ -114,34 +296,121 @@ impl HighScores {
         let _ = fs::write(&self.path, &buf);
     }
 
-    /// Check if a score would qualify for the top 3 (without inserting it)
-    pub fn qualifies(&self, game_idx: usize, score: u32) -> bool {
-        if game_idx >= NUM_GAMES || score == 0 { return false; }
-        for i in 0..SCORES_PER_GAME {
-            if score > self.scores[game_idx][i].score {
-                return true;
+    /// Returns false if there is no readable history file yet.
+    fn read_history(&mut self) -> bool {
+        let Ok(data) = fs::read(&self.history_path) else { return false };
+        if data.len() < 4 { return false; }
+        let (has_detail, has_speed, has_flags, has_extra) = match &data[0..4] {
+            m if m == HISTORY_MAGIC => (true, true, true, true),
+            m if m == HISTORY_MAGIC_V4 => (true, true, true, false),
+            m if m == HISTORY_MAGIC_V3 => (true, true, false, false),
+            m if m == HISTORY_MAGIC_V2 => (true, false, false, false),
+            m if m == HISTORY_MAGIC_V1 => (false, false, false, false),
+            _ => return false,
+        };
+
+        // Each record: game u8, score u32, timestamp u64, [detail u32,] [speed u8,]
+        // [flags u8,] name length u8, name, [extra count u8, extra u32 each]
+        let header = 14 + if has_detail { 4 } else { 0 } + if has_speed { 1 } else { 0 } + if has_flags { 1 } else { 0 };
+        let mut offset = 4;
+        while offset + header <= data.len() {
+            let game = data[offset] as usize;
+            let score = u32::from_le_bytes([
+                data[offset + 1], data[offset + 2], data[offset + 3], data[offset + 4],
+            ]);
+            let mut ts = [0u8; 8];
+            ts.copy_from_slice(&data[offset + 5..offset + 13]);
+            let timestamp = u64::from_le_bytes(ts);
+            let detail = if has_detail {
+                u32::from_le_bytes([data[offset + 13], data[offset + 14], data[offset + 15], data[offset + 16]])
+            } else {
+                0
+            };
+            let speed = if has_speed { data[offset + 17] as u32 } else { 100 };
+            let flags = if has_flags { data[offset + 18] as u32 } else { 0 };
+            let name_len = data[offset + header - 1] as usize;
+            offset += header;
+            if offset + name_len > data.len() { break; }
+            let name = String::from_utf8_lossy(&data[offset..offset + name_len]).to_string();
+            offset += name_len;
+            let mut extra = Vec::new();
+            if has_extra {
+                let Some(&count) = data.get(offset) else { break };
+                offset += 1;
+                for _ in 0..count {
+                    let Some(bytes) = data.get(offset..offset + 4) else { break };
+                    extra.push(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]));
+                    offset += 4;
+                }
+            }
+            if game < NUM_GAMES {
+                self.history.push(HistoryEntry { game, name, score, timestamp, detail, speed, flags, extra });
             }
         }
-        false
+        true
     }
 
-    /// Submit a score for a game with a name. Returns true if it's a new high score (top 3).
-    pub fn submit(&mut self, game_idx: usize, name: &str, score: u32) -> bool {
-        if game_idx >= NUM_GAMES || score == 0 { return false; }
+    fn write_history(&self) {
+        let mut buf = HISTORY_MAGIC.to_vec();
+        for entry in &self.history {
+            let name_bytes = entry.name.as_bytes();
+            let len = name_bytes.len().min(255);
+            buf.push(entry.game as u8);
+            buf.extend_from_slice(&entry.score.to_le_bytes());
+            buf.extend_from_slice(&entry.timestamp.to_le_bytes());
+            buf.extend_from_slice(&entry.detail.to_le_bytes());
+            buf.push(entry.speed.min(255) as u8);
+            buf.push(entry.flags as u8);
+            buf.push(len as u8);
+            buf.extend_from_slice(&name_bytes[..len]);
+            let count = entry.extra.len().min(255);
+            buf.push(count as u8);
+            for value in &entry.extra[..count] {
+                buf.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        let _ = fs::write(&self.history_path, &buf);
+    }
 
-        // Truncate name to 9 chars
-        let name: String = name.chars().take(NAME_LEN).collect();
-
-        // Find insertion point (sorted descending)
-        let mut insert_at = None;
-        for i in 0..SCORES_PER_GAME {
-            if score > self.scores[game_idx][i].score {
-                insert_at = Some(i);
-                break;
+    /// Wipe the top 3 tables and the whole history, on disk too.
+    pub fn reset(&mut self) {
+        for table in &mut self.scores {
+            for entry in table.iter_mut() {
+                *entry = ScoreEntry::empty();
             }
         }
+        self.history.clear();
+        self.write_file();
+        self.write_history();
+    }
+
+    /// Check if a score would qualify for the top 3 (without inserting it)
+    pub fn qualifies(&self, game_idx: usize, score: u32) -> bool {
+        self.rank_for(game_idx, score).is_some()
+    }
 
-        if let Some(pos) = insert_at {
+    /// Top-3 place (0-based) a score would take, if any.
+    pub fn rank_for(&self, game_idx: usize, score: u32) -> Option<usize> {
+        if game_idx >= NUM_GAMES || score == 0 { return None; }
+        (0..SCORES_PER_GAME).find(|&i| beats(game_idx, score, self.scores[game_idx][i].score))
+    }
+
+    /// Submit a finished run, with its detail stat, speed, modifier flags
+    /// and extra stats; it's stamped with the current time. Returns true if
+    /// it's a new high score (top 3).
+    pub fn submit(&mut self, mut entry: HistoryEntry) -> bool {
+        let (game_idx, score) = (entry.game, entry.score);
+        if game_idx >= NUM_GAMES || score == 0 { return false; }
+
+        // Truncate name to 9 chars
+        let name: String = entry.name.chars().take(NAME_LEN).collect();
+        entry.name = name.clone();
+        entry.timestamp = now_secs();
+        self.history.push(entry);
+        self.write_history();
+
+        // Find insertion point (best first)
+        if let Some(pos) = self.rank_for(game_idx, score) {
             // Shift lower scores down
             for i in (pos + 1..SCORES_PER_GAME).rev() {
                 self.scores[game_idx][i] = self.scores[game_idx][i - 1].clone();
@@ -162,6 +431,83 @@ impl HighScores {
         self.scores[game_idx].clone()
     }
 
+    /// Every recorded score, oldest first
+    pub fn history(&self) -> &[HistoryEntry] {
+        &self.history
+    }
+
+    /// Delete one history entry and rebuild that game's top 3 from what's left.
+    pub fn delete_history(&mut self, index: usize) {
+        if index >= self.history.len() { return; }
+        let game = self.history.remove(index).game;
+        self.rebuild_top(game);
+        self.write_file();
+        self.write_history();
+    }
+
+    /// Refill a game's top 3 from the history, limited to the current season.
+    fn rebuild_top(&mut self, game: usize) {
+        let mut remaining: Vec<&HistoryEntry> = self
+            .history
+            .iter()
+            .filter(|e| e.game == game && self.seasons.label(e.timestamp) == self.season)
+            .collect();
+        if is_timed(game) {
+            remaining.sort_by_key(|e| e.score);
+        } else {
+            remaining.sort_by_key(|e| std::cmp::Reverse(e.score));
+        }
+        for slot in 0..SCORES_PER_GAME {
+            self.scores[game][slot] = match remaining.get(slot) {
+                Some(e) => ScoreEntry { name: e.name.clone(), score: e.score },
+                None => ScoreEntry::empty(),
+            };
+        }
+    }
+
+    // ── Seasons ──────────────────────────────────────────────────────────────
+
+    pub fn set_seasons(&mut self, seasons: SeasonPeriod) {
+        self.seasons = seasons;
+        self.season = None;
+        self.roll_season();
+    }
+
+    /// Start a new season if the calendar has moved past the current one.
+    /// The old season's scores stay in the history for the score browser.
+    pub fn roll_season(&mut self) {
+        let label = self.seasons.label(now_secs());
+        if label.is_none() || label == self.season { return; }
+        self.season = label;
+        for game in 0..NUM_GAMES {
+            self.rebuild_top(game);
+        }
+        self.write_file();
+    }
+
+    pub fn current_season(&self) -> Option<&str> {
+        self.season.as_deref()
+    }
+
+    pub fn season_of(&self, entry: &HistoryEntry) -> Option<String> {
+        self.seasons.label(entry.timestamp)
+    }
+
+    /// Every season that has scores, newest first.
+    pub fn season_labels(&self) -> Vec<String> {
+        let mut entries: Vec<&HistoryEntry> = self.history.iter().collect();
+        entries.sort_by_key(|e| std::cmp::Reverse(e.timestamp));
+        let mut labels: Vec<String> = Vec::new();
+        for entry in entries {
+            if let Some(label) = self.seasons.label(entry.timestamp) {
+                if !labels.contains(&label) {
+                    labels.push(label);
+                }
+            }
+        }
+        labels
+    }
+
     /// Check if a game score has been submitted this run (to avoid duplicates)
     pub fn was_submitted(&self, game_idx: usize) -> bool {
         if game_idx >= NUM_GAMES { return false; }
@@ -182,3 +528,22 @@ impl HighScores {
         }
     }
 }
+
+pub fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+/// Format a unix timestamp as a UTC `YYYY-MM-DD` date.
+pub fn format_date(timestamp: u64) -> String {
+    if timestamp == 0 {
+        return "—".to_string();
+    }
+    let (year, month, day) = civil_from_days((timestamp / 86_400) as i64);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Format a unix timestamp as `YYYY-MM-DD HH:MM:SS`, in UTC.
+pub fn format_datetime(timestamp: u64) -> String {
+    let secs = timestamp % 86_400;
+    format!("{} {:02}:{:02}:{:02}", format_date(timestamp), secs / 3600, secs / 60 % 60, secs % 60)
+}
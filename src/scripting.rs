@@ -0,0 +1,199 @@
+//! Script hooks for bots and automated feedback loops, built with
+//! `--features scripting`. Each game can have a Rhai script in the
+//! `scripts/` folder next to `config.toml`, named after its `[handicap]`
+//! key (`booster.rhai`, `breakout.rhai`, ...). The script's top level runs
+//! once when it loads; after that `fn tick(state)` is called every
+//! simulation step while its game is on screen:
+//!
+//! ```rhai
+//! fn tick(state) {
+//!     // `this` is a map that survives between ticks
+//!     this.kicks = this.kicks ?? 0;
+//!     if state.running == 0.0 && state.tick > 60 { press("Space"); }
+//!     if state.orbit_x > 1.0 && this.kicks < 10 {
+//!         command("set all htrim -0.0005");
+//!         this.kicks += 1;
+//!     }
+//! }
+//! ```
+//!
+//! `state` holds `score`, `game_over`, `paused` and `tick`, plus whatever
+//! the game exposes through `Game::script_state`. `press(key)` queues a
+//! key (`Left`, `Space`, `f`, `Ctrl+Right`, ...), `command(line)` runs a
+//! Booster console command and `print` shows a status line. Scripts have
+//! no file, module or `eval` access and a per-tick operation budget; one
+//! that errors is stopped until the arcade restarts.
+
+#[cfg(feature = "scripting")]
+pub use rhai_host::Scripts;
+#[cfg(not(feature = "scripting"))]
+pub use disabled::Scripts;
+
+#[cfg(feature = "scripting")]
+mod rhai_host {
+    use std::cell::RefCell;
+    use std::fs;
+    use std::rc::Rc;
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use rhai::module_resolvers::DummyModuleResolver;
+    use rhai::{CallFnOptions, Dynamic, Engine, Map, Scope, AST};
+
+    use crate::config::{Config, GAME_KEYS};
+    use crate::games::GAME_COUNT;
+    use crate::keymap::KeyBind;
+
+    /// Operations one `tick` may run before it's treated as a runaway loop.
+    const MAX_OPERATIONS: u64 = 50_000;
+    /// Keys one `tick` may queue.
+    const MAX_KEYS_PER_TICK: usize = 64;
+
+    struct GameScript {
+        name: String,
+        ast: AST,
+        /// The script's `this`, kept between ticks
+        memory: Dynamic,
+        ticks: u64,
+        error: Option<String>,
+    }
+
+    pub struct Scripts {
+        engine: Engine,
+        games: [Option<GameScript>; GAME_COUNT],
+        keys: Rc<RefCell<Vec<KeyEvent>>>,
+        output: Rc<RefCell<String>>,
+    }
+
+    impl Scripts {
+        pub fn load() -> Self {
+            let keys: Rc<RefCell<Vec<KeyEvent>>> = Rc::default();
+            let output: Rc<RefCell<String>> = Rc::default();
+
+            let mut engine = Engine::new();
+            engine
+                .set_module_resolver(DummyModuleResolver::new())
+                .disable_symbol("eval")
+                .set_max_operations(MAX_OPERATIONS)
+                .set_max_call_levels(32)
+                .set_max_expr_depths(64, 32)
+                .set_max_string_size(4096)
+                .set_max_array_size(1024)
+                .set_max_map_size(256);
+
+            let out = output.clone();
+            engine.on_print(move |s| *out.borrow_mut() = s.to_string());
+            engine.on_debug(|_, _, _| {});
+
+            let queue = keys.clone();
+            engine.register_fn("press", move |key: &str| -> Result<(), Box<rhai::EvalAltResult>> {
+                let bind = KeyBind::parse(key).ok_or_else(|| format!("press: unknown key '{}'", key))?;
+                push(&queue, KeyEvent::new(bind.code, bind.modifiers))
+            });
+            let queue = keys.clone();
+            engine.register_fn("command", move |line: &str| -> Result<(), Box<rhai::EvalAltResult>> {
+                // Typed into the Booster console, then closed again
+                let typed = std::iter::once(':')
+                    .chain(line.chars())
+                    .map(|c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+                    .chain([KeyCode::Enter, KeyCode::Esc].map(|k| KeyEvent::new(k, KeyModifiers::NONE)));
+                for key in typed {
+                    push(&queue, key)?;
+                }
+                Ok(())
+            });
+
+            let dir = Config::config_path().and_then(|p| p.parent().map(|d| d.join("scripts")));
+            let games = std::array::from_fn(|g| {
+                let path = dir.as_ref()?.join(format!("{}.rhai", GAME_KEYS[g]));
+                let source = fs::read_to_string(&path).ok()?;
+                let name = format!("{}.rhai", GAME_KEYS[g]);
+                let mut script = GameScript {
+                    name,
+                    ast: AST::empty(),
+                    memory: Dynamic::from_map(Map::new()),
+                    ticks: 0,
+                    error: None,
+                };
+                match engine.compile(&source) {
+                    Ok(ast) => match engine.run_ast(&ast) {
+                        Ok(()) => script.ast = ast,
+                        Err(e) => script.error = Some(e.to_string()),
+                    },
+                    Err(e) => script.error = Some(e.to_string()),
+                }
+                Some(script)
+            });
+
+            Scripts { engine, games, keys, output }
+        }
+
+        /// Run a game's `tick` and return the keys it pressed.
+        pub fn tick(&mut self, game: usize, score: u32, game_over: bool, paused: bool, state: Vec<(&'static str, f64)>) -> Vec<KeyEvent> {
+            let Some(script) = self.games.get_mut(game).and_then(|s| s.as_mut()) else { return Vec::new() };
+            if script.error.is_some() { return Vec::new(); }
+
+            let mut map = Map::new();
+            map.insert("score".into(), Dynamic::from_int(score as i64));
+            map.insert("game_over".into(), Dynamic::from_bool(game_over));
+            map.insert("paused".into(), Dynamic::from_bool(paused));
+            map.insert("tick".into(), Dynamic::from_int(script.ticks as i64));
+            for (key, value) in state {
+                map.insert(key.into(), Dynamic::from_float(value));
+            }
+            script.ticks += 1;
+
+            let options = CallFnOptions::new().eval_ast(false).bind_this_ptr(&mut script.memory);
+            let result = self.engine.call_fn_with_options::<Dynamic>(options, &mut Scope::new(), &script.ast, "tick", (map,));
+            let keys = std::mem::take(&mut *self.keys.borrow_mut());
+            if let Err(e) = result {
+                script.error = Some(e.to_string());
+                return Vec::new();
+            }
+            keys
+        }
+
+        /// One-line status for the game's script, if it has one.
+        pub fn status(&self, game: usize) -> Option<String> {
+            let script = self.games.get(game)?.as_ref()?;
+            Some(match &script.error {
+                Some(e) => format!("{} stopped: {}", script.name, e),
+                None => {
+                    let output = self.output.borrow();
+                    if output.is_empty() { format!("{} running", script.name) } else { format!("{}: {}", script.name, output) }
+                }
+            })
+        }
+    }
+
+    fn push(queue: &Rc<RefCell<Vec<KeyEvent>>>, key: KeyEvent) -> Result<(), Box<rhai::EvalAltResult>> {
+        let mut queue = queue.borrow_mut();
+        if queue.len() >= MAX_KEYS_PER_TICK {
+            return Err(format!("more than {} keys in one tick", MAX_KEYS_PER_TICK).into());
+        }
+        queue.push(key);
+        Ok(())
+    }
+}
+
+/// Stand-in when the arcade is built without scripting: no game has a script.
+#[cfg(not(feature = "scripting"))]
+mod disabled {
+    use crossterm::event::KeyEvent;
+
+    pub struct Scripts;
+
+    impl Scripts {
+        pub fn load() -> Self {
+            Scripts
+        }
+
+        pub fn tick(&mut self, _game: usize, _score: u32, _game_over: bool, _paused: bool, _state: Vec<(&'static str, f64)>) -> Vec<KeyEvent> {
+            Vec::new()
+        }
+
+        pub fn status(&self, _game: usize) -> Option<String> {
+            None
+        }
+    }
+}
+
@@ -0,0 +1,90 @@
+/// How the leaderboard is split into seasons. When a new season starts the
+/// top 3 tables start empty again; older scores stay in the history.
+#[derive(Clone, PartialEq)]
+pub enum SeasonPeriod {
+    None,
+    Weekly,
+    Monthly,
+    Quarterly,
+    Yearly,
+    /// Explicit season start dates (unix seconds, ascending)
+    Custom(Vec<u64>),
+}
+
+impl SeasonPeriod {
+    /// Parse `[seasons] period` and the optional comma-separated `starts` dates.
+    pub fn parse(period: &str, starts: Option<&str>) -> Option<SeasonPeriod> {
+        if let Some(starts) = starts.filter(|s| !s.trim().is_empty()) {
+            let mut dates: Vec<u64> = starts.split(',').filter_map(|d| parse_date(d.trim())).collect();
+            if dates.is_empty() { return None; }
+            dates.sort_unstable();
+            return Some(SeasonPeriod::Custom(dates));
+        }
+        match period {
+            "none" | "" => Some(SeasonPeriod::None),
+            "weekly" => Some(SeasonPeriod::Weekly),
+            "monthly" => Some(SeasonPeriod::Monthly),
+            "quarterly" => Some(SeasonPeriod::Quarterly),
+            "yearly" => Some(SeasonPeriod::Yearly),
+            _ => None,
+        }
+    }
+
+    /// Label of the season containing `timestamp`, or None when seasons are off.
+    /// Scores without a timestamp (from before history existed) land in "Archive".
+    pub fn label(&self, timestamp: u64) -> Option<String> {
+        let days = (timestamp / 86_400) as i64;
+        let (year, month, _) = civil_from_days(days);
+        let label = match self {
+            SeasonPeriod::None => return None,
+            _ if timestamp == 0 => "Archive".to_string(),
+            SeasonPeriod::Weekly => {
+                // Weeks start on Monday; 1970-01-01 was a Thursday
+                let monday = days - (days + 3).rem_euclid(7);
+                let (y, m, d) = civil_from_days(monday);
+                format!("Week of {:04}-{:02}-{:02}", y, m, d)
+            }
+            SeasonPeriod::Monthly => format!("{:04}-{:02}", year, month),
+            SeasonPeriod::Quarterly => format!("{:04}-Q{}", year, (month - 1) / 3 + 1),
+            SeasonPeriod::Yearly => format!("{:04}", year),
+            SeasonPeriod::Custom(starts) => match starts.iter().filter(|&&s| s <= timestamp).count() {
+                0 => "Preseason".to_string(),
+                n => format!("Season {}", n),
+            },
+        };
+        Some(label)
+    }
+}
+
+/// Days since 1970-01-01 to a (year, month, day) civil date.
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Parse `YYYY-MM-DD` to unix seconds at UTC midnight.
+fn parse_date(s: &str) -> Option<u64> {
+    let mut parts = s.split('-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: i64 = parts.next()?.parse().ok()?;
+    let day: i64 = parts.next()?.parse().ok()?;
+    if parts.next().is_some() || !(1..=12).contains(&month) || !(1..=31).contains(&day) { return None; }
+
+    // Inverse of civil_from_days
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+    u64::try_from(days * 86_400).ok()
+}
@@ -0,0 +1,224 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::scores::data_path;
+
+const MAGIC: &[u8; 4] = b"RCSS";
+
+/// Autosaved snapshot of the running session. The file only exists while
+/// the arcade is running (or after it died), so finding one at startup
+/// means the previous session did not exit cleanly.
+pub struct Session {
+    pub tab_index: usize,
+    /// Score waiting in the name-entry prompt: (game index, score, name so far)
+    pub pending_score: Option<(usize, u32, String)>,
+    /// Detail stat recorded with the pending score (see `scores::detail_label`)
+    pub pending_detail: u32,
+    /// Speed handicap the pending score was played at, in percent
+    pub pending_speed: u32,
+    /// Modifier flags of the pending score (see `scores::flag_labels`)
+    pub pending_flags: u32,
+    /// Extra stats of the pending score (see `scores::format_extra`)
+    pub pending_extra: Vec<u32>,
+    /// Opaque blob from `Game::serialize_state` for the active game
+    pub game_state: Vec<u8>,
+}
+
+impl Session {
+    fn session_path() -> PathBuf {
+        data_path("rustcade.session")
+    }
+
+    pub fn load() -> Option<Session> {
+        let data = fs::read(Self::session_path()).ok()?;
+        if data.len() < 4 || &data[0..4] != MAGIC { return None; }
+        let mut r = StateReader::new(&data[4..]);
+        let tab_index = r.u8()? as usize;
+        let pending_score = if r.bool()? {
+            Some((r.u8()? as usize, r.u32()?, r.string()?))
+        } else {
+            None
+        };
+        let game_state = r.bytes()?;
+        // Older session files end here
+        let pending_detail = r.u32().unwrap_or(0);
+        let pending_speed = r.u32().unwrap_or(100);
+        let pending_flags = r.u32().unwrap_or(0);
+        let count = r.u8().unwrap_or(0);
+        let pending_extra = (0..count).map_while(|_| r.u32()).collect();
+        Some(Session { tab_index, pending_score, pending_detail, pending_speed, pending_flags, pending_extra, game_state })
+    }
+
+    pub fn save(&self) {
+        let mut w = StateWriter::new();
+        w.u8(self.tab_index as u8);
+        match &self.pending_score {
+            Some((game, score, name)) => {
+                w.bool(true);
+                w.u8(*game as u8);
+                w.u32(*score);
+                w.string(name);
+            }
+            None => w.bool(false),
+        }
+        w.bytes(&self.game_state);
+        w.u32(self.pending_detail);
+        w.u32(self.pending_speed);
+        w.u32(self.pending_flags);
+        w.u8(self.pending_extra.len().min(255) as u8);
+        for &value in self.pending_extra.iter().take(255) {
+            w.u32(value);
+        }
+        let mut buf = MAGIC.to_vec();
+        buf.extend_from_slice(&w.finish());
+        let _ = fs::write(Self::session_path(), &buf);
+    }
+
+    /// Remove the autosave (clean exit, or the player declined recovery).
+    pub fn clear() {
+        let _ = fs::remove_file(Self::session_path());
+    }
+}
+
+// ── Continue snapshot ────────────────────────────────────────────────────────
+
+const SNAPSHOT_SAVE: &str = "snapshot";
+
+/// The game left running at a clean exit, offered as "Continue" on Home
+/// at the next launch. Kept as a progress file, so it survives the exit
+/// that clears the session autosave.
+pub struct Snapshot {
+    /// Game index, as in `Tab::game_index`
+    pub game: usize,
+    /// Opaque blob from `Game::serialize_state`
+    pub game_state: Vec<u8>,
+}
+
+impl Snapshot {
+    pub fn load() -> Option<Snapshot> {
+        let data = load_progress(SNAPSHOT_SAVE)?;
+        let mut r = StateReader::new(&data);
+        let game = r.u8()? as usize;
+        let game_state = r.bytes()?;
+        Some(Snapshot { game, game_state })
+    }
+
+    pub fn save(&self) {
+        let mut w = StateWriter::new();
+        w.u8(self.game as u8);
+        w.bytes(&self.game_state);
+        save_progress(SNAPSHOT_SAVE, &w.finish());
+    }
+
+    /// Remove the snapshot once it's been continued.
+    pub fn clear() {
+        clear_progress(SNAPSHOT_SAVE);
+    }
+}
+
+// ── Binary state encoding ────────────────────────────────────────────────────
+
+/// Little-endian writer used by games to serialize their state.
+#[derive(Default)]
+pub struct StateWriter {
+    buf: Vec<u8>,
+}
+
+impl StateWriter {
+    pub fn new() -> Self {
+        StateWriter { buf: Vec::new() }
+    }
+
+    pub fn u8(&mut self, v: u8) { self.buf.push(v); }
+    pub fn bool(&mut self, v: bool) { self.buf.push(v as u8); }
+    pub fn u32(&mut self, v: u32) { self.buf.extend_from_slice(&v.to_le_bytes()); }
+    pub fn u64(&mut self, v: u64) { self.buf.extend_from_slice(&v.to_le_bytes()); }
+    pub fn f32(&mut self, v: f32) { self.buf.extend_from_slice(&v.to_le_bytes()); }
+
+    pub fn bytes(&mut self, v: &[u8]) {
+        self.u32(v.len() as u32);
+        self.buf.extend_from_slice(v);
+    }
+
+    pub fn string(&mut self, v: &str) { self.bytes(v.as_bytes()); }
+
+    pub fn finish(self) -> Vec<u8> { self.buf }
+}
+
+/// Reader matching `StateWriter`; every getter returns `None` on truncated data.
+pub struct StateReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> StateReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        StateReader { data, pos: 0 }
+    }
+
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        if self.pos + n > self.data.len() { return None; }
+        let s = &self.data[self.pos..self.pos + n];
+        self.pos += n;
+        Some(s)
+    }
+
+    pub fn u8(&mut self) -> Option<u8> { self.take(1).map(|b| b[0]) }
+    pub fn bool(&mut self) -> Option<bool> { self.u8().map(|b| b != 0) }
+    pub fn u32(&mut self) -> Option<u32> { self.take(4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]])) }
+    pub fn u64(&mut self) -> Option<u64> {
+        self.take(8).map(|b| u64::from_le_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]))
+    }
+    pub fn f32(&mut self) -> Option<f32> { self.take(4).map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]])) }
+
+    pub fn bytes(&mut self) -> Option<Vec<u8>> {
+        let len = self.u32()? as usize;
+        self.take(len).map(|b| b.to_vec())
+    }
+
+    pub fn string(&mut self) -> Option<String> {
+        String::from_utf8(self.bytes()?).ok()
+    }
+}
+
+// ── Saved progress ───────────────────────────────────────────────────────────
+
+const PROGRESS_MAGIC: &[u8; 4] = b"RCPG";
+
+/// Progress that outlives a session (e.g. a campaign level), stored as
+/// `rustcade.<name>.save` next to the executable and encoded with
+/// `StateWriter`. Unlike the session autosave it survives a clean exit.
+fn progress_path(name: &str) -> PathBuf {
+    data_path(&format!("rustcade.{}.save", name))
+}
+
+pub fn load_progress(name: &str) -> Option<Vec<u8>> {
+    let data = fs::read(progress_path(name)).ok()?;
+    if data.len() < 4 || &data[0..4] != PROGRESS_MAGIC { return None; }
+    Some(data[4..].to_vec())
+}
+
+pub fn save_progress(name: &str, data: &[u8]) {
+    let mut buf = PROGRESS_MAGIC.to_vec();
+    buf.extend_from_slice(data);
+    let _ = fs::write(progress_path(name), &buf);
+}
+
+pub fn clear_progress(name: &str) {
+    let _ = fs::remove_file(progress_path(name));
+}
+
+/// Remove every saved progress file and the session autosave.
+pub fn clear_all_progress() {
+    let path = progress_path("");
+    let dir = path.parent().filter(|d| !d.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.starts_with("rustcade.") && name.ends_with(".save") {
+                let _ = fs::remove_file(entry.path());
+            }
+        }
+    }
+    Session::clear();
+}
@@ -0,0 +1,281 @@
+use std::time::Duration;
+
+use crate::games::GAME_COUNT;
+use crate::scores::{format_datetime, format_score, game_of, is_timed, now_secs, table_of, HighScores, BEAM_TA, GAME_NAMES};
+use crate::session::{load_progress, save_progress, StateReader, StateWriter};
+
+const STATS_SAVE: &str = "play_stats";
+/// Play time kept in memory before it's written out
+const SAVE_EVERY: Duration = Duration::from_secs(30);
+/// Scores the Home card's sparkline covers
+const RECENT_SCORES: usize = 16;
+const SPARK: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+/// Past sessions kept in the stats file, newest last
+const MAX_SESSIONS: usize = 100;
+/// Reaction times kept per game, newest last
+const MAX_REACTIONS: usize = 500;
+/// Score tables, Beam time attack included
+const TABLES: usize = GAME_NAMES.len();
+/// Starts files that say how many games and tables they hold. Older ones
+/// open with Frogger's play time and always had 8 games and 9 tables.
+const COUNTED: u64 = u64::MAX;
+const OLD_GAMES: usize = 8;
+const OLD_TABLES: usize = 9;
+
+/// Time spent in each game and when it was last played, kept with the
+/// other progress files. Indexed like the Home tiles.
+pub struct PlayStats {
+    play_time: [Duration; GAME_COUNT],
+    /// Unix seconds; 0 if never played
+    last_played: [u64; GAME_COUNT],
+    unsaved: Duration,
+    /// Since the arcade was started
+    session: SessionSummary,
+    /// Earlier sessions, oldest first
+    sessions: Vec<SessionSummary>,
+    /// Reaction times in ms from finished runs of games that time them
+    reactions: [Vec<u32>; GAME_COUNT],
+}
+
+/// One run of the arcade: time in each game, games finished per score
+/// table with the best score, and new places on the high score tables.
+/// Stays on this machine, in the stats file.
+#[derive(Clone, Default)]
+pub struct SessionSummary {
+    /// Unix seconds
+    pub started: u64,
+    pub ended: u64,
+    pub play_time: [Duration; GAME_COUNT],
+    pub runs: [u32; TABLES],
+    /// Best score per table; 0 if none finished
+    pub best: [u32; TABLES],
+    /// Scores that made a high score table
+    pub table_entries: u32,
+}
+
+/// What the Home screen shows about the highlighted game.
+pub struct GamePreview {
+    /// Latest scores, oldest first
+    pub recent: Vec<u32>,
+    pub play_time: Duration,
+    pub last_played: u64,
+    /// Median and 90th percentile reaction times in ms, for games that time them
+    pub reaction: Option<(u32, u32)>,
+}
+
+impl PlayStats {
+    pub fn load() -> Self {
+        let mut stats = PlayStats {
+            play_time: [Duration::ZERO; GAME_COUNT],
+            last_played: [0; GAME_COUNT],
+            unsaved: Duration::ZERO,
+            session: SessionSummary { started: now_secs(), ..Default::default() },
+            sessions: Vec::new(),
+            reactions: std::array::from_fn(|_| Vec::new()),
+        };
+        let Some(data) = load_progress(STATS_SAVE) else { return stats };
+        let mut r = StateReader::new(&data);
+        let (games, tables) = if StateReader::new(&data).u64() == Some(COUNTED) {
+            r.u64();
+            let games = r.u32().unwrap_or(0) as usize;
+            (games, r.u32().unwrap_or(0) as usize)
+        } else {
+            (OLD_GAMES, OLD_TABLES)
+        };
+        for g in 0..games {
+            let (Some(secs), Some(last)) = (r.u64(), r.u64()) else { break };
+            if g < GAME_COUNT {
+                stats.play_time[g] = Duration::from_secs(secs);
+                stats.last_played[g] = last;
+            }
+        }
+        // Files from before session summaries end here
+        let count = r.u32().unwrap_or(0);
+        for _ in 0..count {
+            let Some(session) = SessionSummary::read(&mut r, games, tables) else { break };
+            stats.sessions.push(session);
+        }
+        // And files from before reaction times here
+        let games = r.u32().unwrap_or(0) as usize;
+        for g in 0..games {
+            let Some(count) = r.u32() else { break };
+            let times: Option<Vec<u32>> = (0..count).map(|_| r.u32()).collect();
+            let Some(times) = times else { break };
+            if g < GAME_COUNT {
+                stats.reactions[g] = times;
+            }
+        }
+        stats
+    }
+
+    pub fn save(&mut self) {
+        self.unsaved = Duration::ZERO;
+        let mut w = StateWriter::new();
+        w.u64(COUNTED);
+        w.u32(GAME_COUNT as u32);
+        w.u32(TABLES as u32);
+        for g in 0..GAME_COUNT {
+            w.u64(self.play_time[g].as_secs());
+            w.u64(self.last_played[g]);
+        }
+        w.u32(self.sessions.len() as u32);
+        for session in &self.sessions {
+            session.write(&mut w);
+        }
+        w.u32(GAME_COUNT as u32);
+        for times in &self.reactions {
+            w.u32(times.len() as u32);
+            times.iter().for_each(|&t| w.u32(t));
+        }
+        save_progress(STATS_SAVE, &w.finish());
+    }
+
+    /// Count `elapsed` of play in game `g`.
+    pub fn played(&mut self, g: usize, elapsed: Duration) {
+        self.play_time[g] += elapsed;
+        self.session.play_time[g] += elapsed;
+        self.last_played[g] = now_secs();
+        self.unsaved += elapsed;
+        if self.unsaved >= SAVE_EVERY {
+            self.save();
+        }
+    }
+
+    /// A game on score table `table` ended with `score`.
+    pub fn finished(&mut self, table: usize, score: u32) {
+        let s = &mut self.session;
+        s.runs[table] += 1;
+        s.best[table] = match s.best[table] {
+            0 => score,
+            best if is_timed(table) => best.min(score),
+            best => best.max(score),
+        };
+    }
+
+    /// Reaction times in ms from a finished run of game `g`.
+    pub fn reacted(&mut self, g: usize, times: &[u32]) {
+        if times.is_empty() { return; }
+        let kept = &mut self.reactions[g];
+        kept.extend_from_slice(times);
+        let excess = kept.len().saturating_sub(MAX_REACTIONS);
+        kept.drain(..excess);
+        self.save();
+    }
+
+    /// A score just earned a place on its high score table.
+    pub fn table_entry(&mut self) {
+        self.session.table_entries += 1;
+    }
+
+    /// Close this session and keep it with the others; the summary, unless
+    /// nothing was played.
+    pub fn end_session(&mut self) -> Option<SessionSummary> {
+        let mut session = self.session.clone();
+        session.ended = now_secs();
+        let played = session.play_time.iter().any(|t| !t.is_zero()) || session.runs.iter().any(|&n| n > 0);
+        if played {
+            self.sessions.push(session.clone());
+            let excess = self.sessions.len().saturating_sub(MAX_SESSIONS);
+            self.sessions.drain(..excess);
+        }
+        self.save();
+        played.then_some(session)
+    }
+
+    pub fn preview(&self, g: usize, high_scores: &HighScores) -> GamePreview {
+        let table = table_of(g);
+        let mut recent: Vec<u32> = high_scores.history().iter().rev().filter(|e| e.game == table).take(RECENT_SCORES).map(|e| e.score).collect();
+        recent.reverse();
+        let times = &self.reactions[g];
+        let reaction = percentile(times, 50).zip(percentile(times, 90));
+        GamePreview { recent, play_time: self.play_time[g], last_played: self.last_played[g], reaction }
+    }
+}
+
+impl SessionSummary {
+    fn write(&self, w: &mut StateWriter) {
+        w.u64(self.started);
+        w.u64(self.ended);
+        for time in &self.play_time {
+            w.u64(time.as_secs());
+        }
+        for (runs, best) in self.runs.iter().zip(&self.best) {
+            w.u32(*runs);
+            w.u32(*best);
+        }
+        w.u32(self.table_entries);
+    }
+
+    /// A session from a file holding `games` games and `tables` tables.
+    fn read(r: &mut StateReader, games: usize, tables: usize) -> Option<SessionSummary> {
+        let mut session = SessionSummary { started: r.u64()?, ended: r.u64()?, ..Default::default() };
+        for g in 0..games {
+            let time = Duration::from_secs(r.u64()?);
+            if let Some(slot) = session.play_time.get_mut(g) {
+                *slot = time;
+            }
+        }
+        for table in 0..tables {
+            let (runs, best) = (r.u32()?, r.u32()?);
+            if table < TABLES {
+                session.runs[table] = runs;
+                session.best[table] = best;
+            }
+        }
+        session.table_entries = r.u32()?;
+        Some(session)
+    }
+
+    /// Plain text for the terminal after the arcade closes.
+    pub fn lines(&self) -> Vec<String> {
+        let total: Duration = self.play_time.iter().sum();
+        let mut lines = vec![format!(
+            "RustCade session {}: {} played",
+            format_datetime(self.started),
+            format_play_time(total),
+        )];
+        for (table, name) in GAME_NAMES.iter().enumerate() {
+            // Beam time attack's time is counted under Beam
+            let time = if table == BEAM_TA { Duration::ZERO } else { self.play_time[game_of(table)] };
+            if time.as_secs() == 0 && self.runs[table] == 0 { continue; }
+            let time = if table == BEAM_TA { String::new() } else { format_play_time(time) };
+            let mut line = format!("  {:<10} {:>7}", name, time);
+            if self.runs[table] > 0 {
+                line += &format!(
+                    "  {} finished, best {}",
+                    self.runs[table],
+                    format_score(table, self.best[table]),
+                );
+            }
+            lines.push(line);
+        }
+        if self.table_entries > 0 {
+            lines.push(format!("  {} new high score table entr{}", self.table_entries, if self.table_entries == 1 { "y" } else { "ies" }));
+        }
+        lines
+    }
+}
+
+/// One block character per score, scaled to the highest.
+pub fn sparkline(values: &[u32]) -> String {
+    let max = values.iter().copied().max().unwrap_or(0).max(1) as u64;
+    values.iter().map(|&v| SPARK[(v as u64 * (SPARK.len() as u64 - 1) / max) as usize]).collect()
+}
+
+/// The `p`th percentile (nearest rank) of `values`; None if there are none.
+pub fn percentile(values: &[u32], p: u32) -> Option<u32> {
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    let rank = (sorted.len() * p.min(100) as usize).div_ceil(100).max(1);
+    sorted.get(rank - 1).copied()
+}
+
+/// Play time as "2h 05m", "12m" or "45s".
+pub fn format_play_time(time: Duration) -> String {
+    let secs = time.as_secs();
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m", secs / 60),
+        _ => format!("{}h {:02}m", secs / 3600, secs / 60 % 60),
+    }
+}
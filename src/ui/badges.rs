@@ -0,0 +1,146 @@
+use std::time::{Duration, Instant};
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::achievements::{Achievements, Badge};
+use crate::scores::format_date;
+use crate::ui::theme::{Ink, Role, Theme};
+
+/// Long enough to read the lesson through
+const CARD_TIME: Duration = Duration::from_secs(12);
+const CARD_WIDTH: u16 = 64;
+/// Room for the longest lesson at that width
+const CARD_HEIGHT: u16 = 8;
+const GOLD: Ink = Ink(Color::Rgb(255, 200, 60), Role::Yellow);
+
+/// Badge list, opened with A on the Home tab: every badge, locked or not,
+/// with the lesson (or how to earn it) for the selected one.
+#[derive(Default)]
+pub struct BadgeBrowser {
+    pub selected: usize,
+}
+
+impl BadgeBrowser {
+    pub fn new() -> Self {
+        BadgeBrowser::default()
+    }
+
+    /// Returns false when the list should close.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('a') | KeyCode::Char('A') => return false,
+            KeyCode::Down => self.selected = (self.selected + 1).min(Badge::ALL.len() - 1),
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            _ => {}
+        }
+        true
+    }
+}
+
+pub fn render_badges(frame: &mut Frame, area: Rect, browser: &BadgeBrowser, achievements: &Achievements, theme: &Theme) {
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Double)
+        .border_style(Style::default().fg(theme.ink(GOLD)))
+        .title(format!(" 🏅 Badges {}/{} ", achievements.count(), Badge::ALL.len()))
+        .title_style(Style::default().fg(theme.ink(GOLD)).add_modifier(Modifier::BOLD))
+        .style(Style::default().bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),                              // Track name
+            Constraint::Length(Badge::ALL.len() as u16 + 1),    // Badges
+            Constraint::Min(1),                                 // Lesson or hint
+            Constraint::Length(1),                              // Help bar
+        ])
+        .split(inner);
+
+    let label = Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint));
+    let track = Paragraph::new(Line::from(vec![
+        Span::styled(" Accelerator physics", Style::default().fg(theme.paint(Color::Rgb(120, 200, 255), Role::Blue)).add_modifier(Modifier::BOLD)),
+        Span::styled("  earned in the Booster", label),
+    ]));
+    frame.render_widget(track, chunks[0]);
+
+    let mut lines: Vec<Line> = Vec::new();
+    for (i, &badge) in Badge::ALL.iter().enumerate() {
+        let earned = achievements.unlocked_at(badge);
+        let mark = if earned.is_some() { "★" } else { "☆" };
+        let style = if i == browser.selected {
+            Style::default().fg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)).bg(theme.ink(GOLD)).add_modifier(Modifier::BOLD)
+        } else if earned.is_some() {
+            Style::default().fg(theme.ink(GOLD)).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.paint(Color::Rgb(140, 140, 160), Role::Dim))
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!(" {} {:<36}", mark, badge.title()), style),
+            Span::styled(format!("  {}", earned.map_or("locked".to_string(), format_date)), label),
+        ]));
+    }
+    frame.render_widget(Paragraph::new(lines), chunks[1]);
+
+    let badge = Badge::ALL[browser.selected];
+    let text = if achievements.unlocked_at(badge).is_some() {
+        Span::styled(badge.lesson(), Style::default().fg(theme.paint(Color::Rgb(210, 210, 230), Role::Text)))
+    } else {
+        Span::styled(badge.hint(), label)
+    };
+    let detail = Paragraph::new(Line::from(text))
+        .wrap(Wrap { trim: true })
+        .block(Block::default().padding(Padding::horizontal(1)));
+    frame.render_widget(detail, chunks[2]);
+
+    let sep = Span::styled("│ ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint)));
+    let key = |k: &'static str| Span::styled(k, Style::default().fg(theme.paint(Color::DarkGray, Role::Faint)));
+    let help = Paragraph::new(Line::from(vec![
+        key(" ↑↓"), Span::raw(" Select "), sep,
+        key("A/Esc"), Span::raw(" Close"),
+    ]))
+    .style(Style::default().fg(theme.paint(Color::Rgb(120, 120, 140), Role::Dim)));
+    frame.render_widget(help, chunks[3]);
+}
+
+/// A newly earned badge and its lesson, across the bottom of the screen
+/// for a while. It clears itself; the Home badge list keeps the lesson.
+pub struct BadgeCard {
+    badge: Badge,
+    shown: Instant,
+}
+
+impl BadgeCard {
+    pub fn new(badge: Badge) -> Self {
+        BadgeCard { badge, shown: Instant::now() }
+    }
+
+    pub fn expired(&self) -> bool {
+        self.shown.elapsed() >= CARD_TIME
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let (w, h) = (CARD_WIDTH.min(area.width), CARD_HEIGHT.min(area.height));
+        if w < 20 || h < 3 { return; }
+        let rect = Rect::new(area.x + (area.width - w) / 2, area.bottom() - h, w, h);
+        frame.render_widget(Clear, rect);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.ink(GOLD)))
+            .title(format!(" 🏅 Badge: {} ", self.badge.title()))
+            .title_style(Style::default().fg(theme.ink(GOLD)).add_modifier(Modifier::BOLD))
+            .padding(Padding::horizontal(1))
+            .style(Style::default().bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
+        let text = Paragraph::new(self.badge.lesson())
+            .style(Style::default().fg(theme.paint(Color::Rgb(210, 210, 230), Role::Text)))
+            .wrap(Wrap { trim: true })
+            .block(block);
+        frame.render_widget(text, rect);
+    }
+}
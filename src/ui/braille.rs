@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+
+use ratatui::prelude::*;
+
+/// Sparse layer of braille dots over a character grid. Every cell is a 2×4
+/// dot matrix, so shapes drawn here move in half-cell steps horizontally and
+/// quarter-cell steps vertically. Coordinates are in dots; anything outside
+/// the canvas is clipped.
+pub struct BrailleCanvas {
+    cols: usize,
+    rows: usize,
+    cells: HashMap<(usize, usize), u8>,
+}
+
+impl BrailleCanvas {
+    pub fn new(cols: usize, rows: usize) -> Self {
+        BrailleCanvas { cols, rows, cells: HashMap::new() }
+    }
+
+    pub fn dot_width(&self) -> i32 { (self.cols * 2) as i32 }
+    pub fn dot_height(&self) -> i32 { (self.rows * 4) as i32 }
+
+    fn bit(sub_x: usize, sub_y: usize) -> u8 {
+        match (sub_x, sub_y) {
+            (0, 0) => 0x01,
+            (0, 1) => 0x02,
+            (0, 2) => 0x04,
+            (0, 3) => 0x40,
+            (1, 0) => 0x08,
+            (1, 1) => 0x10,
+            (1, 2) => 0x20,
+            (1, 3) => 0x80,
+            _ => 0,
+        }
+    }
+
+    pub fn set(&mut self, x: i32, y: i32) {
+        if x < 0 || y < 0 || x >= self.dot_width() || y >= self.dot_height() { return; }
+        let (x, y) = (x as usize, y as usize);
+        *self.cells.entry((x / 2, y / 4)).or_insert(0) |= Self::bit(x % 2, y % 4);
+    }
+
+    /// Bresenham line between two dots, inclusive.
+    pub fn line(&mut self, x0: i32, y0: i32, x1: i32, y1: i32) {
+        let dx = (x1 - x0).abs();
+        let dy = -(y1 - y0).abs();
+        let sx = if x0 < x1 { 1 } else { -1 };
+        let sy = if y0 < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+        let (mut cx, mut cy) = (x0, y0);
+        loop {
+            self.set(cx, cy);
+            if cx == x1 && cy == y1 { break; }
+            let e2 = 2 * err;
+            if e2 >= dy { err += dy; cx += sx; }
+            if e2 <= dx { err += dx; cy += sy; }
+        }
+    }
+
+    /// Fill the dots in `x0..x1` × `y0..y1`.
+    pub fn fill_rect(&mut self, x0: i32, y0: i32, x1: i32, y1: i32) {
+        let x0 = x0.max(0);
+        let y0 = y0.max(0);
+        let x1 = x1.min(self.dot_width());
+        let y1 = y1.min(self.dot_height());
+        for y in y0..y1 {
+            for x in x0..x1 {
+                self.set(x, y);
+            }
+        }
+    }
+
+    /// Outline of the dots in `x0..x1` × `y0..y1`.
+    pub fn rect(&mut self, x0: i32, y0: i32, x1: i32, y1: i32) {
+        if x1 <= x0 || y1 <= y0 { return; }
+        self.line(x0, y0, x1 - 1, y0);
+        self.line(x0, y1 - 1, x1 - 1, y1 - 1);
+        self.line(x0, y0, x0, y1 - 1);
+        self.line(x1 - 1, y0, x1 - 1, y1 - 1);
+    }
+
+    /// Outline of an axis-aligned ellipse, traced in short chords.
+    pub fn ellipse(&mut self, cx: f32, cy: f32, rx: f32, ry: f32) {
+        let steps = ((rx + ry) * 2.0).clamp(8.0, 96.0) as i32;
+        let point = |i: i32| {
+            let a = i as f32 / steps as f32 * std::f32::consts::TAU;
+            ((cx + rx * a.cos()).round() as i32, (cy + ry * a.sin()).round() as i32)
+        };
+        for i in 0..steps {
+            let (x0, y0) = point(i);
+            let (x1, y1) = point(i + 1);
+            self.line(x0, y0, x1, y1);
+        }
+    }
+
+    /// Drop every cell that `other` touches, so a layer drawn later doesn't
+    /// bleed into it.
+    pub fn mask(&mut self, other: &BrailleCanvas) {
+        self.cells.retain(|cell, _| !other.cells.contains_key(cell));
+    }
+
+    /// Write the dots onto a character grid in `color`. With `bg` set the
+    /// touched cells are replaced outright; otherwise they keep the grid's
+    /// background and merge with braille already drawn there.
+    pub fn draw(&self, grid: &mut [Vec<(char, Style)>], color: Color, bg: Option<Color>, bold: bool) {
+        for (&(cx, cy), &bits) in &self.cells {
+            if bits == 0 || cy >= grid.len() || cx >= grid[cy].len() { continue; }
+            let existing = grid[cy][cx];
+            let mut style = Style::default().fg(color);
+            style.bg = bg.or(existing.1.bg);
+            if bold { style = style.add_modifier(Modifier::BOLD); }
+            let old = existing.0 as u32;
+            let merged = if bg.is_none() && (0x2800..0x2900).contains(&old) {
+                (old - 0x2800) as u8 | bits
+            } else {
+                bits
+            };
+            let ch = char::from_u32(0x2800 + merged as u32).unwrap_or(' ');
+            grid[cy][cx] = (ch, style);
+        }
+    }
+}
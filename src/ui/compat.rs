@@ -0,0 +1,236 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::time::Duration;
+
+use ratatui::buffer::Buffer;
+use ratatui::prelude::*;
+
+/// What the terminal can be trusted to draw. Picked from the OS and
+/// environment unless `[display] profile` names one.
+#[derive(Clone, Copy, PartialEq)]
+pub enum RenderProfile {
+    /// Modern Unix terminals: every glyph and true colour
+    Full,
+    /// Windows Terminal (ConPTY): true colour and braille, but emoji
+    /// widths are unreliable and keys can arrive twice
+    WindowsTerminal,
+    /// The classic console window: 16 colours and the Consolas/CP437
+    /// glyph set, plus the ConPTY key quirks
+    Conhost,
+}
+
+/// Two identical presses closer together than this are one ConPTY key
+/// reported twice; real key repeat is several times slower.
+pub const KEY_DEDUPE: Duration = Duration::from_millis(10);
+
+static PROFILE: AtomicU8 = AtomicU8::new(0);
+
+impl RenderProfile {
+    pub const ALL: [RenderProfile; 3] = [RenderProfile::Full, RenderProfile::WindowsTerminal, RenderProfile::Conhost];
+
+    /// Windows Terminal sets `WT_SESSION`; other terminals that run on
+    /// Windows (WezTerm, Alacritty) set `TERM_PROGRAM` and draw fine.
+    pub fn detect() -> Self {
+        if !cfg!(windows) || std::env::var_os("TERM_PROGRAM").is_some() {
+            RenderProfile::Full
+        } else if std::env::var_os("WT_SESSION").is_some() {
+            RenderProfile::WindowsTerminal
+        } else {
+            RenderProfile::Conhost
+        }
+    }
+
+    /// `auto`, `full`, `windows_terminal` or `conhost`.
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "auto" => Some(Self::detect()),
+            "full" => Some(RenderProfile::Full),
+            "windows_terminal" => Some(RenderProfile::WindowsTerminal),
+            "conhost" => Some(RenderProfile::Conhost),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            RenderProfile::Full => "Full Unicode, true colour",
+            RenderProfile::WindowsTerminal => "No emoji (Windows Terminal)",
+            RenderProfile::Conhost => "Console: box lines, 16 colours",
+        }
+    }
+
+    /// Whether the input thread should drop doubled key presses.
+    pub fn dedupe_keys(self) -> bool {
+        self != RenderProfile::Full
+    }
+}
+
+pub fn set_profile(profile: RenderProfile) {
+    PROFILE.store(profile as u8, Ordering::Relaxed);
+}
+
+pub fn profile() -> RenderProfile {
+    match PROFILE.load(Ordering::Relaxed) {
+        1 => RenderProfile::WindowsTerminal,
+        2 => RenderProfile::Conhost,
+        _ => RenderProfile::Full,
+    }
+}
+
+/// What the environment says about the terminal, shown by the first-run
+/// setup to suggest a profile.
+pub struct Capabilities {
+    /// 16, 256 or 16777216
+    pub colors: u32,
+    /// The variable the colour count came from, e.g. `COLORTERM=truecolor`
+    pub colors_from: String,
+    /// `LC_ALL`, `LC_CTYPE` or `LANG`, whichever is set first
+    pub locale: Option<String>,
+    pub detected: RenderProfile,
+}
+
+impl Capabilities {
+    pub fn detect() -> Self {
+        let var = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty());
+        let detected = RenderProfile::detect();
+        let (colors, colors_from) = match (var("COLORTERM"), var("TERM")) {
+            (Some(c), _) if c == "truecolor" || c == "24bit" => (1 << 24, format!("COLORTERM={}", c)),
+            (_, Some(t)) if t.contains("256color") => (256, format!("TERM={}", t)),
+            // ConPTY sets neither but draws true colour
+            _ if detected == RenderProfile::WindowsTerminal => (1 << 24, "WT_SESSION".to_string()),
+            (_, Some(t)) => (16, format!("TERM={}", t)),
+            (_, None) => (16, "TERM not set".to_string()),
+        };
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"].into_iter().find_map(var);
+        Capabilities { colors, colors_from, locale, detected }
+    }
+
+    /// Whether the locale promises UTF-8; Windows terminals don't say.
+    pub fn utf8(&self) -> Option<bool> {
+        let locale = self.locale.as_ref()?.to_ascii_lowercase();
+        Some(locale.contains("utf-8") || locale.contains("utf8"))
+    }
+
+    /// The detected profile, or the console one if only 16 colours are
+    /// promised; the Linux console and plain `xterm` entries land here.
+    pub fn suggested(&self) -> RenderProfile {
+        if self.colors <= 16 {
+            RenderProfile::Conhost
+        } else {
+            self.detected
+        }
+    }
+}
+
+/// Rewrite a finished frame for the current profile. The games draw as
+/// usual; this swaps what the terminal can't show for what it can.
+pub fn apply(buf: &mut Buffer) {
+    let profile = profile();
+    if profile == RenderProfile::Full { return; }
+    let conhost = profile == RenderProfile::Conhost;
+    for cell in buf.content.iter_mut() {
+        let mut chars = cell.symbol().chars();
+        if let (Some(c), None | Some('\u{fe0f}')) = (chars.next(), chars.next()) {
+            let swap = emoji_substitute(c).or_else(|| if conhost { console_substitute(c) } else { None });
+            if let Some(s) = swap {
+                cell.set_char(s);
+            }
+        }
+        if conhost {
+            cell.fg = to_console_color(cell.fg);
+            cell.bg = to_console_color(cell.bg);
+        }
+    }
+}
+
+/// Emoji drawn as one plain character; the cell after it is already blank.
+fn emoji_substitute(c: char) -> Option<char> {
+    let s = match c {
+        '🏆' | '🥇' | '🥈' | '🥉' | '★' => '*',
+        '🎮' | '🕹' | '⌨' => '>',
+        '🐸' => 'F',
+        '🧱' | '🟦' => '#',
+        '🦖' => 'D',
+        '👾' => 'W',
+        '☄' => 'o',
+        '🦀' => 'C',
+        '💀' => 'X',
+        '🎉' | '💫' => '!',
+        '⚡' => '~',
+        '⚛' | '⚙' | '🔧' | '🧹' => '%',
+        '☁' => '=',
+        '⏸' => '=',
+        '⏱' => '@',
+        '📌' => '^',
+        '💾' => '+',
+        '⚠' => '!',
+        '⚑' => 'P',
+        c if ('\u{1f000}'..='\u{1faff}').contains(&c) => '*',
+        _ => return None,
+    };
+    Some(s)
+}
+
+/// Glyphs Consolas and the raster fonts lack, as their nearest CP437 kin.
+fn console_substitute(c: char) -> Option<char> {
+    let s = match c {
+        '╭' => '┌',
+        '╮' => '┐',
+        '╰' => '└',
+        '╯' => '┘',
+        '▁' | '▂' | '▃' => '_',
+        '▅' | '▆' | '▇' => '▄',
+        '∝' | '∽' => '~',
+        '⊕' => '+',
+        '⊖' | '−' => '-',
+        '✓' => 'v',
+        '✗' => 'x',
+        // Braille: shade by how many of the eight dots are lit
+        '\u{2800}'..='\u{28ff}' => match (c as u32 - 0x2800).count_ones() {
+            0 => ' ',
+            1..=2 => '░',
+            3..=5 => '▒',
+            _ => '▓',
+        },
+        _ => return None,
+    };
+    Some(s)
+}
+
+/// The console's 16 colours, as the default "Campbell" scheme draws them.
+const CONSOLE: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (12, 12, 12)),
+    (Color::Red, (197, 15, 31)),
+    (Color::Green, (19, 161, 14)),
+    (Color::Yellow, (193, 156, 0)),
+    (Color::Blue, (0, 55, 218)),
+    (Color::Magenta, (136, 23, 152)),
+    (Color::Cyan, (58, 150, 221)),
+    (Color::Gray, (204, 204, 204)),
+    (Color::DarkGray, (118, 118, 118)),
+    (Color::LightRed, (231, 72, 86)),
+    (Color::LightGreen, (22, 198, 12)),
+    (Color::LightYellow, (249, 241, 165)),
+    (Color::LightBlue, (59, 120, 255)),
+    (Color::LightMagenta, (180, 0, 158)),
+    (Color::LightCyan, (97, 214, 214)),
+    (Color::White, (242, 242, 242)),
+];
+
+/// A colour as RGB, named ones as the console draws them; None for the
+/// terminal's own default and palette indices.
+pub fn to_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    match color {
+        Color::Rgb(r, g, b) => Some((r, g, b)),
+        _ => CONSOLE.iter().find(|(c, _)| *c == color).map(|(_, rgb)| *rgb),
+    }
+}
+
+/// Nearest console colour to a true-colour one; named colours pass through.
+fn to_console_color(color: Color) -> Color {
+    let Color::Rgb(r, g, b) = color else { return color };
+    let dist = |(cr, cg, cb): (u8, u8, u8)| {
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+        d(r, cr) + d(g, cg) + d(b, cb)
+    };
+    CONSOLE.iter().min_by_key(|(_, rgb)| dist(*rgb)).map_or(color, |(c, _)| *c)
+}
@@ -0,0 +1,253 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use ratatui::buffer::Buffer;
+use ratatui::prelude::*;
+
+use crate::ui::braille::BrailleCanvas;
+use crate::ui::compat;
+use crate::ui::theme::{Ink, Role, Theme};
+
+pub type Rgb = (u8, u8, u8);
+
+// ── Palettes ─────────────────────────────────────────────────────────────────
+
+/// Hot white-orange sparks (rock and metal impacts)
+pub const SPARKS: &[Ink] = &[
+    Ink(Color::Rgb(255, 230, 150), Role::Yellow),
+    Ink(Color::Rgb(255, 170, 60), Role::Orange),
+    Ink(Color::Rgb(200, 180, 150), Role::Text),
+];
+/// Flame colours for bigger explosions
+pub const FIRE: &[Ink] = &[
+    Ink(Color::Rgb(255, 220, 90), Role::Yellow),
+    Ink(Color::Rgb(255, 140, 30), Role::Orange),
+    Ink(Color::Rgb(220, 60, 30), Role::Red),
+    Ink(Color::White, Role::Bright),
+];
+/// Collision outlines drawn over the playfield in practice mode
+pub const HITBOX: Ink = Ink(Color::Rgb(255, 0, 255), Role::Magenta);
+
+// ── Emitters ─────────────────────────────────────────────────────────────────
+
+/// How a burst of particles leaves its origin.
+#[derive(Clone, Copy)]
+pub struct Emitter {
+    pub count: usize,
+    /// Top speed in field units per tick; each particle gets 30-100% of it
+    pub speed: f32,
+    /// Lifetime in ticks; each particle lives 50-100% of it
+    pub life: u32,
+    /// Centre direction in radians (0 = right, π/2 = down)
+    pub direction: f32,
+    /// Total angle the particles fan over; TAU sprays every way
+    pub spread: f32,
+    /// Added to vy each tick, positive pulls down
+    pub gravity: f32,
+}
+
+impl Emitter {
+    /// Explosion: particles in every direction.
+    pub const fn burst(count: usize, speed: f32, life: u32) -> Self {
+        Emitter { count, speed, life, direction: 0.0, spread: std::f32::consts::TAU, gravity: 0.0 }
+    }
+
+    /// Narrow spray, e.g. a trail behind something moving.
+    pub const fn spray(count: usize, speed: f32, life: u32, direction: f32, spread: f32) -> Self {
+        Emitter { count, speed, life, direction, spread, gravity: 0.0 }
+    }
+
+    pub const fn with_gravity(self, gravity: f32) -> Self {
+        Emitter { gravity, ..self }
+    }
+}
+
+// ── Particle system ──────────────────────────────────────────────────────────
+
+#[derive(Clone)]
+struct Particle {
+    x: f32,
+    y: f32,
+    prev_x: f32,
+    prev_y: f32,
+    vx: f32,
+    vy: f32,
+    gravity: f32,
+    life: u32,
+    max_life: u32,
+    color: Ink,
+}
+
+/// Cosmetic particles in a game's field coordinates. Uses its own random
+/// stream so effects never change a game's simulation.
+#[derive(Clone)]
+pub struct Particles {
+    items: Vec<Particle>,
+    rng: u32,
+    /// Velocity kept per tick
+    pub drag: f32,
+}
+
+impl Particles {
+    pub fn new(seed: u32) -> Self {
+        Particles { items: Vec::new(), rng: seed.max(1), drag: 0.94 }
+    }
+
+    fn rand(&mut self) -> f32 {
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 17;
+        self.rng ^= self.rng << 5;
+        (self.rng % 10_000) as f32 / 10_000.0
+    }
+
+    /// Spawn one emitter's worth of particles at (x, y), cycling through `palette`.
+    pub fn emit(&mut self, emitter: &Emitter, x: f32, y: f32, palette: &[Ink]) {
+        for i in 0..emitter.count {
+            let angle = emitter.direction + (self.rand() - 0.5) * emitter.spread;
+            let v = emitter.speed * (0.3 + 0.7 * self.rand());
+            let life = (emitter.life / 2 + (self.rand() * emitter.life as f32 / 2.0) as u32).max(1);
+            self.items.push(Particle {
+                x, y,
+                prev_x: x, prev_y: y,
+                vx: angle.cos() * v,
+                vy: angle.sin() * v,
+                gravity: emitter.gravity,
+                life,
+                max_life: life,
+                color: palette.get(i % palette.len().max(1)).copied().unwrap_or(Ink(Color::White, Role::Bright)),
+            });
+        }
+    }
+
+    pub fn update(&mut self) {
+        for p in &mut self.items {
+            p.prev_x = p.x;
+            p.prev_y = p.y;
+            p.x += p.vx;
+            p.y += p.vy;
+            p.vx *= self.drag;
+            p.vy = p.vy * self.drag + p.gravity;
+            p.life -= 1;
+        }
+        self.items.retain(|p| p.life > 0);
+    }
+
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    /// Draw as braille dots that dim as they age. `scale` converts field
+    /// units to dots and `alpha` blends from the previous update's positions.
+    pub fn draw(&self, grid: &mut [Vec<(char, Style)>], scale: (f32, f32), alpha: f32, theme: &Theme) {
+        let rows = grid.len();
+        let cols = grid.first().map_or(0, Vec::len);
+        for p in &self.items {
+            let x = p.prev_x + (p.x - p.prev_x) * alpha;
+            let y = p.prev_y + (p.y - p.prev_y) * alpha;
+            let fade = p.life as f32 / p.max_life as f32;
+            let Ink(color, role) = p.color;
+            let (r, g, b) = compat::to_rgb(color).unwrap_or((255, 255, 255));
+            let color = theme.shade(Color::Rgb((r as f32 * fade) as u8, (g as f32 * fade) as u8, (b as f32 * fade) as u8), role, fade);
+            let mut dot = BrailleCanvas::new(cols, rows);
+            dot.set((x * scale.0) as i32, (y * scale.1) as i32);
+            dot.draw(grid, color, None, fade > 0.5);
+        }
+    }
+}
+
+// ── Flash safety ─────────────────────────────────────────────────────────────
+
+/// Simulation ticks per second; turns the flash cap into tick periods.
+const TICKS_PER_SEC: u32 = 60;
+/// Photosensitivity guidance: no more than three flashes a second.
+pub const DEFAULT_MAX_FLASH_HZ: u32 = 3;
+pub const DEFAULT_ALERT_COLOR: Rgb = (255, 80, 80);
+/// Strongest background tint a screen flash may apply, so even a red
+/// flash never saturates the whole screen
+const MAX_FLASH_STRENGTH: f32 = 0.35;
+const FLASH_TICKS: u32 = 8;
+
+static MAX_FLASH_HZ: AtomicU32 = AtomicU32::new(DEFAULT_MAX_FLASH_HZ);
+static ALERT_COLOR: AtomicU32 = AtomicU32::new(pack(DEFAULT_ALERT_COLOR));
+
+const fn pack((r, g, b): Rgb) -> u32 {
+    (r as u32) << 16 | (g as u32) << 8 | b as u32
+}
+
+/// Cap every blink and screen flash at `hz` per second; 0 turns them off.
+pub fn set_flash_limit(hz: u32) {
+    MAX_FLASH_HZ.store(hz, Ordering::Relaxed);
+}
+
+pub fn set_alert_color(rgb: Rgb) {
+    ALERT_COLOR.store(pack(rgb), Ordering::Relaxed);
+}
+
+/// Colour for warnings and game-over banners.
+pub fn alert_color() -> Ink {
+    let v = ALERT_COLOR.load(Ordering::Relaxed);
+    Ink(Color::Rgb((v >> 16) as u8, (v >> 8) as u8, v as u8), Role::Red)
+}
+
+/// Shortest on/off cycle allowed, in ticks; None when flashing is off.
+fn min_flash_period() -> Option<u64> {
+    match MAX_FLASH_HZ.load(Ordering::Relaxed) {
+        0 => None,
+        hz => Some(TICKS_PER_SEC.div_ceil(hz) as u64),
+    }
+}
+
+/// Blink phase for something cycling every `period` ticks: true for the
+/// first half. Slowed down to the flash cap, and always on when flashing
+/// is disabled.
+pub fn blink(tick: u64, period: u64) -> bool {
+    let Some(min) = min_flash_period() else { return true };
+    let period = period.max(min).max(2);
+    tick % period < period / 2
+}
+
+/// Brief tint over a whole game area. Triggers closer together than the
+/// flash cap allows are dropped, and the tint strength is capped.
+#[derive(Clone, Default)]
+pub struct ScreenFlash {
+    ticks: u32,
+    cooldown: u64,
+    color: Option<Ink>,
+}
+
+impl ScreenFlash {
+    pub fn new() -> Self {
+        ScreenFlash::default()
+    }
+
+    pub fn trigger(&mut self, color: Ink) {
+        let Some(min) = min_flash_period() else { return };
+        if self.cooldown > 0 { return; }
+        self.color = Some(color);
+        self.ticks = FLASH_TICKS;
+        self.cooldown = min.max(FLASH_TICKS as u64);
+    }
+
+    pub fn update(&mut self) {
+        self.ticks = self.ticks.saturating_sub(1);
+        self.cooldown = self.cooldown.saturating_sub(1);
+    }
+
+    /// Blend the flash colour into the background of every cell in `area`.
+    pub fn draw(&self, buf: &mut Buffer, area: Rect, theme: &Theme) {
+        let Some(color) = self.color.filter(|_| self.ticks > 0) else { return };
+        let strength = MAX_FLASH_STRENGTH * self.ticks as f32 / FLASH_TICKS as f32;
+        let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * strength) as u8;
+        let (r, g, b) = theme.rgb(color);
+        let area = area.intersection(buf.area);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let cell = &mut buf[(x, y)];
+                let (br, bg, bb) = match cell.bg {
+                    Color::Rgb(br, bg, bb) => (br, bg, bb),
+                    _ => (0, 0, 0),
+                };
+                cell.set_bg(Color::Rgb(mix(br, r), mix(bg, g), mix(bb, b)));
+            }
+        }
+    }
+}
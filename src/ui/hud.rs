@@ -0,0 +1,221 @@
+use std::time::{Duration, Instant};
+
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::games::GameInfo;
+use crate::ui::fx;
+use crate::ui::theme::{Role, Theme};
+
+/// Keys every simple game's legend ends with.
+const PLAY_KEYS: [(&str, &str); 4] = [("P", "Pause"), ("R", "Restart"), ("Esc", "Menu"), ("?", "Help")];
+/// The same before a run has started, when there's nothing to pause.
+const MENU_KEYS: [(&str, &str); 2] = [("Esc", "Menu"), ("?", "Help")];
+
+fn key_style(theme: &Theme) -> Style {
+    Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))
+}
+
+fn separator(theme: &Theme) -> Span<'static> {
+    Span::styled("│ ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint)))
+}
+
+/// Footer key legend: an optional highlighted call to action, the game's
+/// own keys, then P / R / Esc / ?.
+pub fn legend(prompt: Option<&str>, keys: &[(&str, &str)], theme: &Theme) -> Line<'static> {
+    key_line(prompt, keys, &PLAY_KEYS, theme)
+}
+
+/// Legend for a start or title screen: the game's keys, then Esc / ?.
+pub fn menu_legend(prompt: Option<&str>, keys: &[(&str, &str)], theme: &Theme) -> Line<'static> {
+    key_line(prompt, keys, &MENU_KEYS, theme)
+}
+
+/// Legend for an overlay that takes every key: only its own keys.
+pub fn overlay_legend(keys: &[(&str, &str)], theme: &Theme) -> Line<'static> {
+    key_line(None, keys, &[], theme)
+}
+
+fn key_line(prompt: Option<&str>, keys: &[(&str, &str)], tail: &[(&str, &str)], theme: &Theme) -> Line<'static> {
+    let mut spans = vec![Span::raw(" ")];
+    if let Some(prompt) = prompt {
+        spans.push(Span::styled(format!("{} ", prompt), Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD)));
+        spans.push(separator(theme));
+    }
+    for (i, (key, action)) in keys.iter().chain(tail).enumerate() {
+        if i > 0 {
+            spans.push(separator(theme));
+        }
+        spans.push(Span::styled(format!("{} {} ", key, action), key_style(theme)));
+    }
+    Line::from(spans)
+}
+
+/// Footer for a finished run: a headline in `color`, then what to press.
+pub fn outcome(headline: &str, color: Color, detail: &str, theme: &Theme) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(format!(" {} ", headline), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+        Span::styled(detail.to_string(), Style::default().fg(theme.paint(Color::Gray, Role::Dim))),
+    ])
+}
+
+/// Footer for a lost run.
+pub fn game_over(detail: &str, theme: &Theme) -> Line<'static> {
+    outcome("💀 GAME OVER!", theme.ink(fx::alert_color()), detail, theme)
+}
+
+/// Footer while paused.
+pub fn paused(theme: &Theme) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(" ⏸ PAUSED ", Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD)),
+        separator(theme),
+        Span::styled("P Resume ", key_style(theme)),
+        separator(theme),
+        Span::styled("R Restart ", key_style(theme)),
+        separator(theme),
+        Span::styled("Esc Menu", key_style(theme)),
+    ])
+}
+
+/// A game's frame: rounded border in its accent colour, its icon and name
+/// on the left, the score and any state badge (PAUSED, GAME OVER) on the right.
+pub fn title_bar(info: &GameInfo, score: u32, badge: Option<&str>, theme: &Theme) -> Block<'static> {
+    let accent = Style::default().fg(theme.ink(info.color)).add_modifier(Modifier::BOLD);
+    let mut right = vec![Span::styled(format!(" {} ", score), accent)];
+    if let Some(badge) = badge {
+        right.push(Span::styled(
+            format!(" {} ", badge),
+            Style::default().fg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)).bg(theme.ink(info.color)).add_modifier(Modifier::BOLD),
+        ));
+        right.push(Span::raw(" "));
+    }
+    Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.ink(info.color)))
+        .title(Line::from(Span::styled(format!(" {} {} ", info.icon, info.name), accent)))
+        .title(Line::from(right).right_aligned())
+}
+
+/// Boxed PAUSED banner centred over a paused game's playfield.
+pub fn pause_overlay(frame: &mut Frame, field: Rect, theme: &Theme) {
+    let w = 26u16.min(field.width);
+    let h = 4u16.min(field.height);
+    if w < 12 || h < 3 { return; }
+    let area = Rect::new(field.x + (field.width - w) / 2, field.y + (field.height - h) / 2, w, h);
+    frame.render_widget(Clear, area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)))
+        .style(Style::default().bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
+    let text = vec![
+        Line::from(Span::styled("⏸ PAUSED", Style::default().fg(theme.paint(Color::Yellow, Role::Yellow)).add_modifier(Modifier::BOLD))),
+        Line::from(Span::styled("P to resume", Style::default().fg(theme.paint(Color::Gray, Role::Dim)))),
+    ];
+    frame.render_widget(Paragraph::new(text).alignment(Alignment::Center).block(block), area);
+}
+
+/// Simulation steps each number of a countdown stays up (~0.8 s).
+const COUNT_STEPS: u32 = 50;
+
+/// A 3-2-1 "get ready" hold before play resumes, after unpausing or a
+/// respawn, so the player isn't hit before they've found their bearings.
+/// Games freeze their simulation while `tick` returns true.
+#[derive(Clone, Copy, Default)]
+pub struct Countdown {
+    steps: u32,
+}
+
+impl Countdown {
+    pub fn start(&mut self) {
+        self.steps = 3 * COUNT_STEPS;
+    }
+
+    pub fn cancel(&mut self) {
+        self.steps = 0;
+    }
+
+    /// P in a game with a countdown: pausing drops any hold in progress,
+    /// resuming starts a fresh one.
+    pub fn toggle_pause(&mut self, paused: &mut bool) {
+        *paused = !*paused;
+        if *paused { self.cancel() } else { self.start() }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.steps > 0
+    }
+
+    /// Count one simulation step; true while the hold is still on.
+    pub fn tick(&mut self) -> bool {
+        if self.steps == 0 { return false; }
+        self.steps -= 1;
+        true
+    }
+
+    /// Boxed number centred over the playfield while the hold is on.
+    pub fn render(&self, frame: &mut Frame, field: Rect, theme: &Theme) {
+        if self.steps == 0 { return; }
+        let w = 17u16.min(field.width);
+        let h = 4u16.min(field.height);
+        if w < 12 || h < 3 { return; }
+        let area = Rect::new(field.x + (field.width - w) / 2, field.y + (field.height - h) / 2, w, h);
+        frame.render_widget(Clear, area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.paint(Color::Rgb(80, 255, 120), Role::Green)))
+            .style(Style::default().bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
+        let text = vec![
+            Line::from(Span::styled("GET READY", Style::default().fg(theme.paint(Color::Gray, Role::Dim)))),
+            Line::from(Span::styled(
+                format!("{}", self.steps.div_ceil(COUNT_STEPS)),
+                Style::default().fg(theme.paint(Color::Rgb(80, 255, 120), Role::Green)).add_modifier(Modifier::BOLD),
+            )),
+        ];
+        frame.render_widget(Paragraph::new(text).alignment(Alignment::Center).block(block), area);
+    }
+}
+
+/// How long a toast stays up; errors get longer to be read.
+const TOAST_TIME: Duration = Duration::from_secs(3);
+const TOAST_ERROR_TIME: Duration = Duration::from_secs(8);
+
+/// A one-line notice in the top-right corner that clears itself, e.g.
+/// after the config file was reloaded.
+pub struct Toast {
+    text: String,
+    error: bool,
+    shown: Instant,
+}
+
+impl Toast {
+    pub fn info(text: impl Into<String>) -> Self {
+        Toast { text: text.into(), error: false, shown: Instant::now() }
+    }
+
+    pub fn error(text: impl Into<String>) -> Self {
+        Toast { text: text.into(), error: true, shown: Instant::now() }
+    }
+
+    pub fn expired(&self) -> bool {
+        self.shown.elapsed() >= if self.error { TOAST_ERROR_TIME } else { TOAST_TIME }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let color = if self.error { theme.ink(fx::alert_color()) } else { theme.paint(Color::Rgb(80, 255, 120), Role::Green) };
+        let icon = if self.error { "⚠" } else { "✓" };
+        let text = format!("{} {}", icon, self.text);
+        let w = (text.chars().count() as u16 + 4).min(area.width);
+        if w < 8 || area.height < 3 { return; }
+        let rect = Rect::new(area.right() - w, area.y, w, 3);
+        frame.render_widget(Clear, rect);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(color))
+            .style(Style::default().bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
+        frame.render_widget(Paragraph::new(Span::styled(text, Style::default().fg(color))).block(block), rect);
+    }
+}
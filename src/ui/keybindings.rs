@@ -0,0 +1,151 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::keymap::{is_reserved, KeyBind, Keymap, ACTIONS};
+use crate::ui::theme::{Role, Theme};
+
+/// Key binding editor, opened with K on the Home tab. Enter waits for the
+/// next key press, with any modifiers, and binds it to the selected action.
+#[derive(Default)]
+pub struct KeyBindings {
+    pub selected: usize,
+    pub capturing: bool,
+    /// Feedback from the last capture, shown until the next key
+    pub message: Option<String>,
+}
+
+impl KeyBindings {
+    pub fn new() -> Self {
+        KeyBindings::default()
+    }
+
+    /// Returns false when the editor should close.
+    pub fn handle_key(&mut self, key: KeyEvent, keymap: &mut Keymap) -> bool {
+        self.message = None;
+        let action = &ACTIONS[self.selected];
+
+        if self.capturing {
+            self.capturing = false;
+            let bind = KeyBind::from_event(key);
+            if key.code == KeyCode::Esc {
+                return true;
+            }
+            if is_reserved(bind) {
+                self.message = Some(format!("{} is reserved by the arcade", bind.label()));
+                return true;
+            }
+            if let Some(other) = ACTIONS.iter().find(|a| a.tab == action.tab && a.id != action.id && keymap.binding(a) == bind) {
+                self.message = Some(format!("{} is already bound to {}", bind.label(), other.name));
+                return true;
+            }
+            keymap.bind(action, bind);
+            self.message = Some(format!("{} → {}", action.name, bind.label()));
+            return true;
+        }
+
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('k') | KeyCode::Char('K') => return false,
+            KeyCode::Down => self.selected = (self.selected + 1).min(ACTIONS.len() - 1),
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Enter => self.capturing = true,
+            KeyCode::Backspace | KeyCode::Delete => keymap.unbind(action),
+            KeyCode::Char('l') | KeyCode::Char('L') => {
+                keymap.set_layout(keymap.layout.next());
+                self.message = Some(format!("Layout preset: {}", keymap.layout.name()));
+            }
+            KeyCode::Char('h') | KeyCode::Char('H') => {
+                keymap.toggle_left_handed();
+                self.message = Some(if keymap.left_handed {
+                    "Left-handed: WASD ↔ arrows, Q/E ↔ [ ]".to_string()
+                } else {
+                    "Right-handed: default keys".to_string()
+                });
+            }
+            _ => {}
+        }
+        true
+    }
+}
+
+pub fn render_keybindings(frame: &mut Frame, area: Rect, editor: &KeyBindings, keymap: &Keymap, theme: &Theme) {
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Double)
+        .border_style(Style::default().fg(theme.paint(Color::Rgb(120, 200, 255), Role::Blue)))
+        .title(" ⌨ Key Bindings ")
+        .title_style(Style::default().fg(theme.paint(Color::Rgb(150, 220, 255), Role::Blue)).add_modifier(Modifier::BOLD))
+        .style(Style::default().bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Layout preset
+            Constraint::Min(1),    // Actions
+            Constraint::Length(2), // Capture prompt / result
+            Constraint::Length(1), // Help bar
+        ])
+        .split(inner);
+
+    let label = Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint));
+    let value = Style::default().fg(theme.paint(Color::Rgb(80, 200, 255), Role::Blue)).add_modifier(Modifier::BOLD);
+
+    let preset = Paragraph::new(Line::from(vec![
+        Span::styled(" Layout preset: ", label),
+        Span::styled(keymap.layout.name(), value),
+        Span::styled("   Hand: ", label),
+        Span::styled(if keymap.left_handed { "left (mirrored)" } else { "right" }, value),
+    ]));
+    frame.render_widget(preset, chunks[0]);
+
+    let mut lines: Vec<Line> = Vec::new();
+    for (i, action) in ACTIONS.iter().enumerate() {
+        let bind = keymap.binding(action).label();
+        let source = if keymap.is_custom(action) {
+            "custom"
+        } else if keymap.is_from_config(action) {
+            "config.toml"
+        } else {
+            "preset"
+        };
+        let style = if i == editor.selected {
+            Style::default().fg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)).bg(theme.paint(Color::Rgb(150, 220, 255), Role::Blue)).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.paint(Color::Rgb(200, 200, 220), Role::Text))
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!(" {:<26} {:<14}", action.name, bind), style),
+            Span::styled(format!("  {}", source), label),
+        ]));
+    }
+    frame.render_widget(Paragraph::new(lines), chunks[1]);
+
+    let status = if editor.capturing {
+        Line::from(Span::styled(
+            format!(" Press the key you want for {}  (Esc cancels)", ACTIONS[editor.selected].name),
+            Style::default().fg(theme.paint(Color::Rgb(255, 220, 80), Role::Yellow)).add_modifier(Modifier::BOLD),
+        ))
+    } else if let Some(message) = &editor.message {
+        Line::from(Span::styled(format!(" {}", message), Style::default().fg(theme.paint(Color::Rgb(255, 220, 80), Role::Yellow))))
+    } else {
+        Line::from("")
+    };
+    frame.render_widget(Paragraph::new(status), chunks[2]);
+
+    let sep = Span::styled("│ ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint)));
+    let key = |k: &'static str| Span::styled(k, Style::default().fg(theme.paint(Color::DarkGray, Role::Faint)));
+    let help = Paragraph::new(Line::from(vec![
+        key(" ↑↓"), Span::raw(" Select "), sep.clone(),
+        key("Enter"), Span::raw(" Rebind "), sep.clone(),
+        key("Bksp"), Span::raw(" Use default "), sep.clone(),
+        key("L"), Span::raw(" Layout "), sep.clone(),
+        key("H"), Span::raw(" Left-handed "), sep,
+        key("K/Esc"), Span::raw(" Close"),
+    ]))
+    .style(Style::default().fg(theme.paint(Color::Rgb(120, 120, 140), Role::Dim)));
+    frame.render_widget(help, chunks[3]);
+}
@@ -0,0 +1,208 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::ui::theme::{Role, Theme};
+
+/// Word the player has to type before anything is wiped.
+const CONFIRM_WORD: &str = "RESET";
+
+/// Something the maintenance screen can put back to its defaults.
+#[derive(Clone, Copy, PartialEq)]
+pub enum ResetTarget {
+    Scores,
+    Progress,
+    Leaderboard,
+    Config,
+}
+
+impl ResetTarget {
+    pub const ALL: [ResetTarget; 4] = [
+        ResetTarget::Scores,
+        ResetTarget::Progress,
+        ResetTarget::Leaderboard,
+        ResetTarget::Config,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            ResetTarget::Scores => "High scores",
+            ResetTarget::Progress => "Saved progress",
+            ResetTarget::Leaderboard => "Leaderboard queue",
+            ResetTarget::Config => "Settings",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            ResetTarget::Scores => "Top 3 tables and the full score history",
+            ResetTarget::Progress => "Campaign saves, your profile and the session autosave",
+            ResetTarget::Leaderboard => "Scores still waiting to be uploaded",
+            ResetTarget::Config => "Delete config.toml and use the defaults",
+        }
+    }
+}
+
+pub enum MaintenanceAction {
+    None,
+    Close,
+    Apply(Vec<ResetTarget>),
+}
+
+/// Reset screen, opened with M on the Home tab. Targets are ticked first and
+/// only wiped after the confirmation word is typed out.
+#[derive(Default)]
+pub struct MaintenanceMenu {
+    pub selected: usize,
+    pub checked: [bool; ResetTarget::ALL.len()],
+    /// Some while waiting for the confirmation word
+    pub typed: Option<String>,
+    /// Result of the last reset, shown until the next key
+    pub message: Option<String>,
+}
+
+impl MaintenanceMenu {
+    pub fn new() -> Self {
+        MaintenanceMenu::default()
+    }
+
+    /// Open with `targets` already ticked.
+    pub fn ticked(targets: &[ResetTarget]) -> Self {
+        let mut menu = MaintenanceMenu::new();
+        for (checked, target) in menu.checked.iter_mut().zip(ResetTarget::ALL) {
+            *checked = targets.contains(&target);
+        }
+        menu
+    }
+
+    fn targets(&self) -> Vec<ResetTarget> {
+        ResetTarget::ALL.iter().zip(self.checked).filter(|(_, on)| *on).map(|(t, _)| *t).collect()
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> MaintenanceAction {
+        self.message = None;
+
+        if let Some(typed) = &mut self.typed {
+            match key.code {
+                KeyCode::Esc => self.typed = None,
+                KeyCode::Backspace => { typed.pop(); }
+                KeyCode::Enter => {
+                    let confirmed = typed.eq_ignore_ascii_case(CONFIRM_WORD);
+                    self.typed = None;
+                    if !confirmed {
+                        self.message = Some("Confirmation did not match; nothing was reset".to_string());
+                        return MaintenanceAction::None;
+                    }
+                    let targets = self.targets();
+                    self.checked = [false; ResetTarget::ALL.len()];
+                    self.message = Some(format!(
+                        "Reset: {}",
+                        targets.iter().map(|t| t.label()).collect::<Vec<_>>().join(", "),
+                    ));
+                    return MaintenanceAction::Apply(targets);
+                }
+                KeyCode::Char(c) if typed.len() < CONFIRM_WORD.len() => typed.push(c.to_ascii_uppercase()),
+                _ => {}
+            }
+            return MaintenanceAction::None;
+        }
+
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('m') | KeyCode::Char('M') => return MaintenanceAction::Close,
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1).min(ResetTarget::ALL.len() - 1);
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Char(' ') => self.checked[self.selected] = !self.checked[self.selected],
+            KeyCode::Char('a') | KeyCode::Char('A') => {
+                let all = self.checked.iter().all(|&c| c);
+                self.checked = [!all; ResetTarget::ALL.len()];
+            }
+            KeyCode::Enter => {
+                if self.checked.iter().any(|&c| c) {
+                    self.typed = Some(String::new());
+                } else {
+                    self.message = Some("Tick something to reset with Space first".to_string());
+                }
+            }
+            _ => {}
+        }
+        MaintenanceAction::None
+    }
+}
+
+pub fn render_maintenance(frame: &mut Frame, area: Rect, menu: &MaintenanceMenu, theme: &Theme) {
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Double)
+        .border_style(Style::default().fg(theme.paint(Color::Rgb(255, 100, 80), Role::Red)))
+        .title(" 🧹 Maintenance ")
+        .title_style(Style::default().fg(theme.paint(Color::Rgb(255, 140, 100), Role::Orange)).add_modifier(Modifier::BOLD))
+        .style(Style::default().bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Intro
+            Constraint::Min(1),    // Targets
+            Constraint::Length(2), // Confirmation / result
+            Constraint::Length(1), // Help bar
+        ])
+        .split(inner);
+
+    let label = Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint));
+    let value = Style::default().fg(theme.paint(Color::Rgb(80, 200, 255), Role::Blue)).add_modifier(Modifier::BOLD);
+
+    let intro = Paragraph::new(Line::from(Span::styled(
+        " Put data back to its defaults. Ticked items are deleted for good.",
+        label,
+    )));
+    frame.render_widget(intro, chunks[0]);
+
+    let mut lines: Vec<Line> = Vec::new();
+    for (i, target) in ResetTarget::ALL.iter().enumerate() {
+        let check = if menu.checked[i] { "[x]" } else { "[ ]" };
+        let style = if i == menu.selected {
+            Style::default().fg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)).bg(theme.paint(Color::Rgb(255, 140, 100), Role::Orange)).add_modifier(Modifier::BOLD)
+        } else if menu.checked[i] {
+            Style::default().fg(theme.paint(Color::Rgb(255, 120, 100), Role::Red)).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.paint(Color::Rgb(200, 200, 220), Role::Text))
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!(" {} {:<18}", check, target.label()), style),
+            Span::styled(format!("  {}", target.description()), label),
+        ]));
+    }
+    frame.render_widget(Paragraph::new(lines), chunks[1]);
+
+    let status = if let Some(typed) = &menu.typed {
+        Line::from(vec![
+            Span::styled(" Type ", label),
+            Span::styled(CONFIRM_WORD, Style::default().fg(theme.paint(Color::Rgb(255, 80, 80), Role::Red)).add_modifier(Modifier::BOLD)),
+            Span::styled(" and press Enter to confirm: ", label),
+            Span::styled(format!("{}▌", typed), value),
+        ])
+    } else if let Some(message) = &menu.message {
+        Line::from(Span::styled(format!(" {}", message), Style::default().fg(theme.paint(Color::Rgb(255, 220, 80), Role::Yellow))))
+    } else {
+        Line::from("")
+    };
+    frame.render_widget(Paragraph::new(status), chunks[2]);
+
+    let sep = Span::styled("│ ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint)));
+    let key = |k: &'static str| Span::styled(k, Style::default().fg(theme.paint(Color::DarkGray, Role::Faint)));
+    let help = Paragraph::new(Line::from(vec![
+        key(" ↑↓"), Span::raw(" Select "), sep.clone(),
+        key("Space"), Span::raw(" Tick "), sep.clone(),
+        key("A"), Span::raw(" All "), sep.clone(),
+        key("Enter"), Span::raw(" Reset "), sep,
+        key("M/Esc"), Span::raw(" Close"),
+    ]))
+    .style(Style::default().fg(theme.paint(Color::Rgb(120, 120, 140), Role::Dim)));
+    frame.render_widget(help, chunks[3]);
+}
@@ -1,50 +1,258 @@
+pub mod badges;
+pub mod braille;
+pub mod compat;
+pub mod fx;
 pub mod home;
+pub mod hud;
+pub mod keybindings;
+pub mod maintenance;
+pub mod onboarding;
+pub mod score_browser;
+pub mod score_table;
+pub mod settings;
+pub mod starfield;
 pub mod tabs;
+pub mod theme;
+
+pub use score_table::ScoreTable;
 
 use ratatui::prelude::*;
 use ratatui::widgets::*;
 
 use crate::app::{App, Tab};
-use crate::games::Game;
-use crate::scores::GAME_NAMES;
+use crate::games::{Game, GameInfo};
+use crate::scores::{detail_label, format_detail, format_extra, format_flags, format_score, format_speed, table_of, ScoreEntry, GAME_NAMES};
+use crate::session::Session;
+use crate::ui::theme::{Role, Theme};
+
+/// Smallest corner viewport for a pinned game; most HUDs need about this much.
+const PIP_MIN_W: u16 = 44;
+const PIP_MIN_H: u16 = 16;
 
-pub fn render(frame: &mut Frame, app: &mut App) {
-    let chunks = Layout::default()
+/// The tab bar and the content area below it.
+fn screen_layout(area: Rect) -> std::rc::Rc<[Rect]> {
+    Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3), // Tab bar
             Constraint::Min(0),   // Content
         ])
-        .split(frame.area());
+        .split(area)
+}
+
+/// Where a game on its own tab is drawn, on a `width` × `height` terminal.
+pub fn game_area(width: u16, height: u16) -> Rect {
+    screen_layout(Rect::new(0, 0, width, height))[1]
+}
+
+pub fn render(frame: &mut Frame, app: &mut App, theme: &Theme) {
+    let chunks = screen_layout(frame.area());
+    // Cells nobody colours take the theme's text and background
+    let area = frame.area();
+    frame.buffer_mut().set_style(area, Style::default().fg(theme.paint(Color::Reset, Role::Text)).bg(theme.background()));
 
-    tabs::render_tabs(frame, app, chunks[0]);
+    tabs::render_tabs(frame, app, chunks[0], theme);
 
+    let alpha = app.render_alpha;
     match app.current_tab {
-        Tab::Home => home::render_home(frame, chunks[1], app.selected_game, app.show_high_scores, &app.high_scores),
-        Tab::Frogger => app.frogger.render(frame, chunks[1]),
-        Tab::Breakout => app.breakout.render(frame, chunks[1]),
-        Tab::DinoRun => app.dino_run.render(frame, chunks[1]),
-        Tab::SpaceInvaders => app.space_invaders.render(frame, chunks[1]),
-        Tab::JezzBall => app.jezzball.render(frame, chunks[1]),
-        Tab::Asteroids => app.asteroids.render(frame, chunks[1]),
-        Tab::Booster => app.booster.render(frame, chunks[1]),
-        Tab::Beam => app.beam.render(frame, chunks[1]),
+        Tab::Home => app.home_tiles = home::render_home(frame, chunks[1], app, theme),
+        Tab::Frogger => app.frogger.render_interpolated(frame, chunks[1], theme, alpha),
+        Tab::Breakout => app.breakout.render_interpolated(frame, chunks[1], theme, alpha),
+        Tab::DinoRun => app.dino_run.render_interpolated(frame, chunks[1], theme, alpha),
+        Tab::SpaceInvaders => app.space_invaders.render_interpolated(frame, chunks[1], theme, alpha),
+        Tab::JezzBall => app.jezzball.render_interpolated(frame, chunks[1], theme, alpha),
+        Tab::Asteroids => app.asteroids.render_interpolated(frame, chunks[1], theme, alpha),
+        Tab::Booster => app.booster.render_interpolated(frame, chunks[1], theme, alpha),
+        Tab::Beam => app.beam.render_interpolated(frame, chunks[1], theme, alpha),
+        Tab::BeamDump => app.beam_dump.render_interpolated(frame, chunks[1], theme, alpha),
+        Tab::Scope => app.scope.render_interpolated(frame, chunks[1], theme, alpha),
+        Tab::Router => app.router.render_interpolated(frame, chunks[1], theme, alpha),
+        Tab::Racer => app.racer.render_interpolated(frame, chunks[1], theme, alpha),
+        Tab::Delve => app.delve.render_interpolated(frame, chunks[1], theme, alpha),
+        Tab::Columns => app.columns.render_interpolated(frame, chunks[1], theme, alpha),
+        Tab::Whack => app.whack.render_interpolated(frame, chunks[1], theme, alpha),
+        Tab::Life => app.life.render_interpolated(frame, chunks[1], theme, alpha),
+        Tab::Pong => app.pong.render_interpolated(frame, chunks[1], theme, alpha),
+        Tab::Minesweeper => app.minesweeper.render_interpolated(frame, chunks[1], theme, alpha),
+        Tab::Plugins => app.plugins.render(frame, chunks[1], theme),
+        Tab::Settings => {
+            let values = app.setting_values();
+            settings::render_settings_tab(frame, chunks[1], &app.settings_tab, &values, theme);
+        }
+    }
+
+    // Pinned game, in a corner of another game's screen
+    if !matches!(app.current_tab, Tab::Home | Tab::Settings) {
+        if let Some(g) = app.pinned_behind() {
+            render_pinned(frame, chunks[1], g, app, theme);
+        }
+    }
+
+    // Bot script status, on the tab bar's bottom border
+    if let Some(status) = app.current_tab.game_index().and_then(|g| app.scripts.status(g)) {
+        let text = format!(" ⚙ {} ", status);
+        let width = (text.chars().count() as u16).min(chunks[0].width.saturating_sub(4));
+        let area = Rect::new(chunks[0].right().saturating_sub(width + 2), chunks[0].bottom().saturating_sub(1), width, 1);
+        let color = if status.contains(" stopped: ") { theme.ink(fx::alert_color()) } else { theme.paint(Color::Rgb(120, 200, 255), Role::Blue) };
+        if width > 0 {
+            frame.render_widget(Paragraph::new(text).style(Style::default().fg(color)), area);
+        }
+    }
+
+    // F3 debug overlay: input latency, on the tab bar's bottom border
+    if app.show_hitboxes {
+        let input = &app.metrics.input;
+        let text = if input.is_empty() {
+            " input latency: press a key ".to_string()
+        } else {
+            let (avg, max) = input.avg_max_us();
+            let ms = |us: u64| us as f64 / 1000.0;
+            format!(
+                " key→handled {:.1}ms · key→frame {:.1}ms (avg {:.1} max {:.1}) ",
+                ms(input.handled_us), ms(input.frame_us), ms(avg), ms(max),
+            )
+        };
+        let width = (text.chars().count() as u16).min(chunks[0].width.saturating_sub(4));
+        let area = Rect::new(chunks[0].x + 2, chunks[0].bottom().saturating_sub(1), width, 1);
+        frame.render_widget(Paragraph::new(text).style(Style::default().fg(theme.ink(fx::HITBOX))), area);
+    }
+
+    // Practice watermark, on the game's bottom border
+    if app.current_tab.game_index().is_some_and(|g| app.practice[g]) {
+        let text = " PRACTICE · scores not saved ";
+        let width = (text.chars().count() as u16).min(chunks[1].width);
+        let area = Rect::new(chunks[1].x + (chunks[1].width - width) / 2, chunks[1].bottom().saturating_sub(1), width, 1);
+        let style = Style::default().fg(theme.paint(Color::Black, Role::Background)).bg(theme.paint(Color::Rgb(255, 200, 60), Role::Yellow)).add_modifier(Modifier::BOLD);
+        frame.render_widget(Paragraph::new(text).style(style), area);
+    }
+
+    // Full-screen score browser over the Home content
+    if app.show_high_scores && matches!(app.current_tab, Tab::Home) {
+        score_browser::render_score_browser(frame, chunks[1], &mut app.score_browser, &app.high_scores, theme);
+    }
+    if app.show_maintenance && matches!(app.current_tab, Tab::Home | Tab::Settings) {
+        maintenance::render_maintenance(frame, chunks[1], &app.maintenance, theme);
+    }
+    if app.show_keybindings && matches!(app.current_tab, Tab::Home) {
+        keybindings::render_keybindings(frame, chunks[1], &app.key_bindings, &app.keymap, theme);
+    }
+    if app.show_badges && matches!(app.current_tab, Tab::Home) {
+        badges::render_badges(frame, chunks[1], &app.badge_browser, &app.achievements, theme);
+    }
+    if app.show_settings && matches!(app.current_tab, Tab::Home) {
+        settings::render_settings(frame, chunks[1], &app.display_settings, theme);
     }
 
     // Help overlay (renders on top of everything)
     if app.show_help {
-        render_help_overlay(frame, frame.area(), &app.current_tab, app.help_scroll);
+        let info = app.active_game().map(|game| game.info());
+        render_help_overlay(frame, frame.area(), &app.current_tab, info, app.help_scroll, theme);
     }
 
     // Name entry overlay (renders on top of everything)
     if app.entering_name {
-        render_name_entry(frame, frame.area(), &app.name_buffer, app.name_game_idx, app.name_score);
+        render_name_entry(frame, frame.area(), app, theme);
+    }
+
+    // Crash recovery prompt (shown once at startup)
+    if let Some(session) = &app.recovered_session {
+        render_recover_prompt(frame, frame.area(), session, theme);
+    }
+
+    // Config reload notice, over the content's top-right corner
+    if let Some(toast) = &app.toast {
+        toast.render(frame, chunks[1], theme);
+    }
+    if let Some(card) = &app.badge_card {
+        card.render(frame, chunks[1], theme);
+    }
+
+    // First-run setup, and the tour after it
+    if let Some(onboarding) = &app.onboarding {
+        onboarding.render(frame, frame.area(), theme);
+    }
+    if let Some(tour) = &app.tour {
+        tour.render(frame, chunks[1], theme);
+    }
+
+    // Last, so it sees everything drawn above
+    compat::apply(frame.buffer_mut());
+}
+
+/// Corner viewport for the pinned game, with its name and the swap key.
+fn render_pinned(frame: &mut Frame, area: Rect, g: usize, app: &mut App, theme: &Theme) {
+    let w = (area.width * 2 / 5).max(PIP_MIN_W).min(area.width);
+    let h = (area.height * 2 / 5).max(PIP_MIN_H).min(area.height);
+    let pip = Rect::new(area.right() - w, area.bottom() - h, w, h);
+    frame.render_widget(Clear, pip);
+    let alpha = app.render_alpha;
+    if let Some(game) = app.pinned_game_mut() {
+        game.render_interpolated(frame, pip, theme, alpha);
     }
+    let text = format!(" 📌 {} · F5 swap ", GAME_NAMES[table_of(g)]);
+    let width = (text.chars().count() as u16 + 1).min(pip.width.saturating_sub(2));
+    let label = Rect::new(pip.x + 1, pip.bottom().saturating_sub(1), width, 1);
+    let style = Style::default().fg(theme.paint(Color::Black, Role::Background)).bg(theme.paint(Color::Rgb(120, 200, 255), Role::Blue)).add_modifier(Modifier::BOLD);
+    frame.render_widget(Paragraph::new(text).style(style), label);
 }
 
-fn render_name_entry(frame: &mut Frame, area: Rect, name_buffer: &str, game_idx: usize, score: u32) {
+fn render_recover_prompt(frame: &mut Frame, area: Rect, session: &Session, theme: &Theme) {
     let overlay_w = 44u16.min(area.width.saturating_sub(4));
-    let overlay_h = 13u16.min(area.height.saturating_sub(4));
+    let overlay_h = 11u16.min(area.height.saturating_sub(4));
+    let x = area.x + (area.width.saturating_sub(overlay_w)) / 2;
+    let y = area.y + (area.height.saturating_sub(overlay_h)) / 2;
+    let overlay_area = Rect::new(x, y, overlay_w, overlay_h);
+
+    frame.render_widget(Clear, overlay_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Double)
+        .border_style(Style::default().fg(theme.paint(Color::Rgb(80, 200, 255), Role::Blue)))
+        .title(" 💾 RECOVER SESSION ")
+        .title_style(Style::default().fg(theme.paint(Color::Rgb(80, 200, 255), Role::Blue)).add_modifier(Modifier::BOLD))
+        .style(Style::default().bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
+    let inner = block.inner(overlay_area);
+    frame.render_widget(block, overlay_area);
+
+    let tab_name = Tab::from_index(session.tab_index).map(|t| t.title().trim().to_string()).unwrap_or_default();
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("  Recover previous session?", Style::default().fg(theme.paint(Color::Rgb(180, 180, 200), Role::Text))),
+        ]),
+        Line::from(""),
+    ];
+    if !session.game_state.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled("  🎮 Resume ", Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint))),
+            Span::styled(tab_name, Style::default().fg(theme.paint(Color::Rgb(80, 200, 255), Role::Blue)).add_modifier(Modifier::BOLD)),
+        ]));
+    }
+    if let Some((game_idx, score, _)) = &session.pending_score {
+        let game_name = GAME_NAMES.get(*game_idx).copied().unwrap_or("Unknown");
+        lines.push(Line::from(vec![
+            Span::styled("  🏆 Unsaved score: ", Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint))),
+            Span::styled(format!("{} ({})", format_score(*game_idx, *score), game_name), Style::default().fg(theme.paint(Color::Rgb(255, 215, 0), Role::Yellow)).add_modifier(Modifier::BOLD)),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("  Y", Style::default().fg(theme.paint(Color::Rgb(80, 200, 255), Role::Blue)).add_modifier(Modifier::BOLD)),
+        Span::styled(" restore  ", Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint))),
+        Span::styled("N", Style::default().fg(theme.paint(Color::Rgb(80, 200, 255), Role::Blue)).add_modifier(Modifier::BOLD)),
+        Span::styled(" discard", Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint))),
+    ]));
+
+    let p = Paragraph::new(lines).style(Style::default().bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
+    frame.render_widget(p, inner);
+}
+
+fn render_name_entry(frame: &mut Frame, area: Rect, app: &App, theme: &Theme) {
+    let (name_buffer, game_idx, score) = (app.name_buffer.as_str(), app.name_game_idx, app.name_score);
+    let (detail, speed, flags) = (app.name_detail, app.name_speed, app.name_flags);
+    let overlay_w = 52u16.min(area.width.saturating_sub(4));
+    let overlay_h = 16u16.min(area.height.saturating_sub(4));
     let x = area.x + (area.width.saturating_sub(overlay_w)) / 2;
     let y = area.y + (area.height.saturating_sub(overlay_h)) / 2;
     let overlay_area = Rect::new(x, y, overlay_w, overlay_h);
@@ -61,10 +269,10 @@ fn render_name_entry(frame: &mut Frame, area: Rect, name_buffer: &str, game_idx:
     let block = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Double)
-        .border_style(Style::default().fg(Color::Rgb(255, 220, 80)))
+        .border_style(Style::default().fg(theme.paint(Color::Rgb(255, 220, 80), Role::Yellow)))
         .title(" 🏆 NEW HIGH SCORE! ")
-        .title_style(Style::default().fg(Color::Rgb(255, 220, 80)).add_modifier(Modifier::BOLD))
-        .style(Style::default().bg(Color::Rgb(15, 15, 25)));
+        .title_style(Style::default().fg(theme.paint(Color::Rgb(255, 220, 80), Role::Yellow)).add_modifier(Modifier::BOLD))
+        .style(Style::default().bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
     let inner = block.inner(overlay_area);
     frame.render_widget(block, overlay_area);
 
@@ -74,382 +282,866 @@ fn render_name_entry(frame: &mut Frame, area: Rect, name_buffer: &str, game_idx:
     let remaining = max_len - typed_len;
     let display_name = format!("{}{}", name_buffer, "_".repeat(remaining));
 
-    let lines = vec![
+    // The top 3 with the new score in its place
+    let rank = app.high_scores.rank_for(game_idx, score);
+    let mut podium = app.high_scores.top_scores(game_idx);
+    if let Some(rank) = rank {
+        let name = if name_buffer.is_empty() { "YOU" } else { name_buffer };
+        podium.insert(rank, ScoreEntry { name: name.to_string(), score });
+        podium.truncate(podium.len() - 1);
+    }
+
+    let mut lines = vec![
         Line::from(""),
         Line::from(vec![
             Span::styled("  🎮 ", Style::default()),
-            Span::styled(game_name, Style::default().fg(Color::Rgb(80, 200, 255)).add_modifier(Modifier::BOLD)),
+            Span::styled(game_name, Style::default().fg(theme.paint(Color::Rgb(80, 200, 255), Role::Blue)).add_modifier(Modifier::BOLD)),
+            Span::styled(
+                if flags != 0 { format!("  with {}", format_flags(game_idx, flags)) } else { String::new() },
+                Style::default().fg(theme.paint(Color::Rgb(255, 160, 60), Role::Orange)),
+            ),
         ]),
         Line::from(""),
         Line::from(vec![
-            Span::styled(format!("  Score: {}", score), Style::default().fg(Color::Rgb(255, 215, 0)).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("  Score: {}", format_score(game_idx, score)), Style::default().fg(theme.paint(Color::Rgb(255, 215, 0), Role::Yellow)).add_modifier(Modifier::BOLD)),
+            Span::styled(
+                match (detail_label(game_idx).is_some(), format_extra(game_idx, &app.name_extra)) {
+                    (false, extra) if extra.is_empty() => String::new(),
+                    (false, extra) => format!("  ({})", extra),
+                    (true, extra) if extra.is_empty() => format!("  ({})", format_detail(game_idx, detail)),
+                    (true, extra) => format!("  ({}, {})", format_detail(game_idx, detail), extra),
+                },
+                Style::default().fg(theme.paint(Color::Rgb(180, 180, 200), Role::Text)),
+            ),
+            Span::styled(
+                if speed != 100 { format!("  at {} speed", format_speed(speed)) } else { String::new() },
+                Style::default().fg(theme.paint(Color::Rgb(255, 160, 60), Role::Orange)),
+            ),
         ]),
         Line::from(""),
+    ];
+    // Where it lands on the podium
+    lines.extend(ScoreTable::new(game_idx, &podium, theme).highlight(rank).lines());
+    lines.extend([
+        Line::from(""),
         Line::from(vec![
-            Span::styled("  Enter your name:", Style::default().fg(Color::Rgb(180, 180, 200))),
+            Span::styled("  Enter your name:", Style::default().fg(theme.paint(Color::Rgb(180, 180, 200), Role::Text))),
         ]),
         Line::from(""),
         Line::from(vec![
-            Span::styled("    [ ", Style::default().fg(Color::Rgb(100, 100, 130))),
-            Span::styled(&display_name, Style::default().fg(Color::Rgb(255, 255, 255)).add_modifier(Modifier::BOLD)),
-            Span::styled(" ]", Style::default().fg(Color::Rgb(100, 100, 130))),
+            Span::styled("    [ ", Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint))),
+            Span::styled(&display_name, Style::default().fg(theme.paint(Color::Rgb(255, 255, 255), Role::Bright)).add_modifier(Modifier::BOLD)),
+            Span::styled(" ]", Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint))),
         ]),
         Line::from(""),
         Line::from(vec![
-            Span::styled("  Enter", Style::default().fg(Color::Rgb(80, 200, 255)).add_modifier(Modifier::BOLD)),
-            Span::styled(" confirm  ", Style::default().fg(Color::Rgb(100, 100, 130))),
-            Span::styled("Esc", Style::default().fg(Color::Rgb(80, 200, 255)).add_modifier(Modifier::BOLD)),
-            Span::styled(" skip", Style::default().fg(Color::Rgb(100, 100, 130))),
+            Span::styled("  Enter", Style::default().fg(theme.paint(Color::Rgb(80, 200, 255), Role::Blue)).add_modifier(Modifier::BOLD)),
+            Span::styled(" confirm  ", Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint))),
+            Span::styled("Esc", Style::default().fg(theme.paint(Color::Rgb(80, 200, 255), Role::Blue)).add_modifier(Modifier::BOLD)),
+            Span::styled(" skip", Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint))),
         ]),
-    ];
+    ]);
 
-    let p = Paragraph::new(lines).style(Style::default().bg(Color::Rgb(15, 15, 25)));
+    let p = Paragraph::new(lines).style(Style::default().bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
     frame.render_widget(p, inner);
 }
 
-fn help_key(key: &str, desc: &str) -> Line<'static> {
+fn help_key(key: &str, desc: &str, theme: &Theme) -> Line<'static> {
     Line::from(vec![
-        Span::styled(format!("  {:<18}", key), Style::default().fg(Color::Rgb(80, 200, 255))),
-        Span::styled(desc.to_string(), Style::default().fg(Color::Rgb(170, 170, 190))),
+        Span::styled(format!("  {:<18}", key), Style::default().fg(theme.paint(Color::Rgb(80, 200, 255), Role::Blue))),
+        Span::styled(desc.to_string(), Style::default().fg(theme.paint(Color::Rgb(170, 170, 190), Role::Text))),
     ])
 }
 
-fn help_section(title: &str) -> Line<'static> {
+fn help_section(title: &str, theme: &Theme) -> Line<'static> {
     Line::from(vec![
-        Span::styled(format!("  {}", title), Style::default().fg(Color::Rgb(255, 220, 80)).add_modifier(Modifier::BOLD)),
+        Span::styled(format!("  {}", title), Style::default().fg(theme.paint(Color::Rgb(255, 220, 80), Role::Yellow)).add_modifier(Modifier::BOLD)),
     ])
 }
 
-fn help_text(text: &str) -> Line<'static> {
+fn help_text(text: &str, theme: &Theme) -> Line<'static> {
     Line::from(vec![
-        Span::styled(format!("  {}", text), Style::default().fg(Color::Rgb(140, 140, 160))),
+        Span::styled(format!("  {}", text), Style::default().fg(theme.paint(Color::Rgb(140, 140, 160), Role::Dim))),
     ])
 }
 
+/// Description, author and tags from a game's `GameInfo`.
+fn help_about(info: &GameInfo, theme: &Theme) -> Vec<Line<'static>> {
+    vec![
+        help_text(info.description, theme),
+        Line::from(vec![
+            Span::styled(format!("  by {}  ", info.author), Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint))),
+            Span::styled(format!("#{}", info.tags.join(" #")), Style::default().fg(theme.paint(Color::Rgb(80, 120, 160), Role::Blue))),
+        ]),
+        help_blank(),
+    ]
+}
+
 fn help_blank() -> Line<'static> {
     Line::from("")
 }
 
-fn help_lines_for_tab(tab: &Tab) -> Vec<Line<'static>> {
+/// Pause and restart keys, the same in every simple game's controls list.
+fn help_play_keys(theme: &Theme) -> Vec<Line<'static>> {
+    vec![
+        help_key("P", "Pause / resume", theme),
+        help_key("R", "Restart", theme),
+        help_key("Enter / Space", "Play again after game over", theme),
+    ]
+}
+
+fn help_lines_for_tab(tab: &Tab, theme: &Theme) -> Vec<Line<'static>> {
     match tab {
         Tab::Home => vec![
-            help_section("Rust-Cade Arcade"),
-            help_blank(),
-            help_text("Select a game from the home screen and jump in!"),
-            help_blank(),
-            help_section("Navigation"),
-            help_key("1-8", "Quick-launch game by number"),
-            help_key("Arrow keys", "Select game tile"),
-            help_key("Enter", "Play selected game"),
-            help_key("Tab / Shift+Tab", "Switch between game tabs"),
-            help_key("Esc", "Return to Home from any game"),
-            help_key("H", "Toggle high scores display"),
-            help_key("?", "Show this help screen"),
-            help_key("Q / Ctrl+C", "Quit"),
-            help_blank(),
-            help_section("Common In-Game Controls"),
-            help_key("P", "Pause / Unpause"),
-            help_key("R", "Reset / Restart"),
-            help_key("Enter / Space", "Restart after game over"),
+            help_section("Rust-Cade Arcade", theme),
+            help_blank(),
+            help_text("Select a game from the home screen and jump in!", theme),
+            help_blank(),
+            help_section("Navigation", theme),
+            help_key("1-9", "Quick-launch game by number", theme),
+            help_key("Arrow keys", "Select game tile", theme),
+            help_key("Enter", "Play selected game", theme),
+            help_key("Click", "Play the game clicked", theme),
+            help_key("C", "Continue the game left running at the last exit", theme),
+            help_key("/", "Search games by name or tag (physics, puzzle...)", theme),
+            help_key("Tab / Shift+Tab", "Switch between game tabs", theme),
+            help_key("Esc", "Return to Home from any game", theme),
+            help_key("H", "Browse score history", theme),
+            help_key("M", "Maintenance: reset scores, progress or settings", theme),
+            help_key("K", "Key bindings and keyboard layout presets", theme),
+            help_key("A", "Badges, with the physics behind each one", theme),
+            help_key("T", "Display: colour theme and character set", theme),
+            help_key("S", "Cycle the selected game's speed (0.5x-2x)", theme),
+            help_key("P", "Practice mode: slower, with aids, not scored", theme),
+            help_key("V", "Cycle the selected game's volume", theme),
+            help_key("U", "Mute or unmute all sound", theme),
+            help_key("F3", "Collision outlines and input latency (any tab)", theme),
+            help_key("F4", "Pin this game in a corner while playing another", theme),
+            help_key("F5", "Swap the pinned game with the one on screen", theme),
+            help_key("?", "Show this help screen", theme),
+            help_key("Q / Ctrl+C", "Quit", theme),
+            help_blank(),
+            help_section("Score History", theme),
+            help_key("Up / Down", "Select entry", theme),
+            help_key("Left / Right", "Previous / next page", theme),
+            help_key("G / 0-9", "Filter by game (0 = all)", theme),
+            help_key("D", "Cycle date range", theme),
+            help_key("S", "Sort by score / newest", theme),
+            help_key("E", "Cycle seasons (when configured)", theme),
+            help_key("X / Del", "Delete entry (asks to confirm)", theme),
+            help_blank(),
+            help_section("Common In-Game Controls", theme),
+            help_key("P", "Pause / Unpause (3-2-1 countdown to resume)", theme),
+            help_key("R", "Reset / Restart", theme),
+            help_key("Enter / Space", "Restart after game over", theme),
         ],
         Tab::Frogger => vec![
-            help_section("Frogger"),
-            help_blank(),
-            help_text("Guide the frog from the bottom to the goal pads at the top."),
-            help_text("Cross busy roads without getting hit by cars."),
-            help_text("Hop onto floating logs to cross the river -- don't fall in!"),
-            help_text("Reach all 5 goal pads to win. You have 3 lives."),
-            help_blank(),
-            help_section("Scoring"),
-            help_text("+10 pts per upward hop"),
-            help_text("+100 pts per goal pad reached"),
-            help_text("+500 pts for reaching all 5 goals"),
-            help_blank(),
-            help_section("Controls"),
-            help_key("Up", "Move frog up (toward goals)"),
-            help_key("Down", "Move frog down"),
-            help_key("Left / Right", "Move frog sideways"),
-            help_key("P", "Pause"),
-            help_key("R", "Restart"),
-        ],
+            help_section("Frogger", theme),
+            help_blank(),
+            help_text("Guide the frog from the bottom to the goal pads at the top.", theme),
+            help_text("Cross busy roads without getting hit by cars.", theme),
+            help_text("Hop onto floating logs to cross the river -- don't fall in!", theme),
+            help_text("Reach all 5 goal pads to win. You have 3 lives.", theme),
+            help_blank(),
+            help_section("Scoring", theme),
+            help_text("+10 pts per upward hop", theme),
+            help_text("+100 pts per goal pad reached", theme),
+            help_text("+500 pts for reaching all 5 goals", theme),
+            help_blank(),
+            help_section("Controls", theme),
+            help_key("Up", "Move frog up (toward goals)", theme),
+            help_key("Down", "Move frog down", theme),
+            help_key("Left / Right", "Move frog sideways", theme),
+            help_key("T", "Speedrun timer with per-pad splits", theme),
+        ]
+        .into_iter()
+        .chain(help_play_keys(theme))
+        .collect(),
         Tab::Breakout => vec![
-            help_section("Breakout"),
-            help_blank(),
-            help_text("Bounce the ball off your paddle to destroy all bricks."),
-            help_text("6 rows of colored bricks with increasing point values."),
-            help_text("Top rows are worth more. Ball speed increases as you go."),
-            help_text("You have 3 lives. Lose one each time the ball falls."),
-            help_blank(),
-            help_section("Scoring"),
-            help_text("Top row (red): 60 pts   |  Row 2: 50 pts"),
-            help_text("Row 3: 40 pts           |  Row 4: 30 pts"),
-            help_text("Row 5: 20 pts           |  Bottom row: 10 pts"),
-            help_blank(),
-            help_section("Tips"),
-            help_text("Hit the ball near paddle edges for sharper angles."),
-            help_text("Clear all bricks to win!"),
-            help_blank(),
-            help_section("Controls"),
-            help_key("Left / Right", "Move paddle"),
-            help_key("Space / Up", "Launch ball"),
-            help_key("P", "Pause"),
-            help_key("R", "Restart"),
-        ],
+            help_section("Breakout", theme),
+            help_blank(),
+            help_text("Bounce the ball off your paddle to destroy all bricks.", theme),
+            help_text("6 rows of colored bricks with increasing point values.", theme),
+            help_text("Top rows are worth more. Ball speed increases as you go.", theme),
+            help_text("You have 3 lives. Lose one each time the ball falls.", theme),
+            help_blank(),
+            help_section("Scoring", theme),
+            help_text("Top row (red): 60 pts   |  Row 2: 50 pts", theme),
+            help_text("Row 3: 40 pts           |  Row 4: 30 pts", theme),
+            help_text("Row 5: 20 pts           |  Bottom row: 10 pts", theme),
+            help_blank(),
+            help_section("Tips", theme),
+            help_text("Hit the ball near paddle edges for sharper angles.", theme),
+            help_blank(),
+            help_section("Campaign", theme),
+            help_text("Clear all bricks to advance; each level starts faster.", theme),
+            help_text("Your level and score are saved after every cleared level.", theme),
+            help_text("The start screen offers to continue where you left off,", theme),
+            help_text("even after a game over.", theme),
+            help_blank(),
+            help_section("Controls", theme),
+            help_key("Left / Right", "Move paddle", theme),
+            help_key("Mouse", "Paddle follows the pointer, click to launch", theme),
+            help_key("Space / Up", "Launch ball", theme),
+            help_key("A", "Assist paddle (lowers the score multiplier)", theme),
+            help_key("D", "AI demo; any key stops it", theme),
+            help_key("Enter / C", "Continue campaign (start screen)", theme),
+            help_key("N", "New campaign (start screen)", theme),
+        ]
+        .into_iter()
+        .chain(help_play_keys(theme))
+        .collect(),
         Tab::DinoRun => vec![
-            help_section("Dino Run"),
-            help_blank(),
-            help_text("Classic endless runner -- the dino runs automatically."),
-            help_text("Jump over cacti and duck under birds to survive."),
-            help_text("Speed increases gradually up to 1.5x as you progress."),
-            help_text("Birds start appearing after 200 points."),
-            help_blank(),
-            help_section("Scoring"),
-            help_text("Score increases over time as long as you survive."),
-            help_text("Higher score = faster speed = more challenge!"),
-            help_blank(),
-            help_section("Controls"),
-            help_key("Space / Up", "Jump (also starts game)"),
-            help_key("Down", "Duck (on ground) / Fast fall (in air)"),
-            help_key("P", "Pause"),
-            help_key("R", "Restart"),
-        ],
+            help_section("Dino Run", theme),
+            help_blank(),
+            help_text("Classic endless runner -- the dino runs automatically.", theme),
+            help_text("Jump over cacti and duck under birds to survive.", theme),
+            help_text("Speed increases gradually up to 1.5x as you progress.", theme),
+            help_text("Birds start appearing after 200 points.", theme),
+            help_blank(),
+            help_section("Scoring", theme),
+            help_text("Score increases over time as long as you survive.", theme),
+            help_text("Higher score = faster speed = more challenge!", theme),
+            help_blank(),
+            help_section("Two Lanes", theme),
+            help_text("A back and a front lane; obstacles come down either one,", theme),
+            help_text("and past 100 points sometimes both at once.", theme),
+            help_text("Up / Down switch lanes on the ground. Up in the back lane", theme),
+            help_text("jumps and Down in the front lane ducks, as in one lane.", theme),
+            help_blank(),
+            help_section("Controls", theme),
+            help_key("Space / Up", "Jump (also starts game)", theme),
+            help_key("Down", "Duck (on ground) / Fast fall (in air)", theme),
+            help_key("L", "Two-lane mode (start screen)", theme),
+        ]
+        .into_iter()
+        .chain(help_play_keys(theme))
+        .collect(),
         Tab::SpaceInvaders => vec![
-            help_section("Space Invaders"),
-            help_blank(),
-            help_text("Defend Earth! Destroy all 55 aliens in the formation."),
-            help_text("Aliens march sideways and descend. They shoot back!"),
-            help_text("4 shields protect you but degrade from both sides."),
-            help_text("Clear all aliens to advance to the next level."),
-            help_blank(),
-            help_section("Scoring"),
-            help_text("Top row aliens:    30 pts each"),
-            help_text("Middle row aliens: 20 pts each"),
-            help_text("Bottom row aliens: 10 pts each"),
-            help_blank(),
-            help_section("Game Over"),
-            help_text("Lose a life when hit by an alien bullet."),
-            help_text("Instant loss if aliens reach the bottom."),
-            help_text("3 lives total."),
-            help_blank(),
-            help_section("Controls"),
-            help_key("Left / Right", "Move ship"),
-            help_key("Space / Up", "Fire (max 3 bullets)"),
-            help_key("P", "Pause"),
-            help_key("R", "Restart"),
+            help_section("Space Invaders", theme),
+            help_blank(),
+            help_text("Defend Earth! Destroy all 55 aliens in the formation.", theme),
+            help_text("Aliens march sideways and descend. They shoot back!", theme),
+            help_text("4 shields protect you but degrade from both sides.", theme),
+            help_text("Clear all aliens to advance to the next level.", theme),
+            help_blank(),
+            help_section("Scoring", theme),
+            help_text("Top row aliens:    30 pts each", theme),
+            help_text("Middle row aliens: 20 pts each", theme),
+            help_text("Bottom row aliens: 10 pts each", theme),
+            help_text("Waves cleared are saved with your score.", theme),
+            help_blank(),
+            help_section("Modifiers", theme),
+            help_text("Before your first point, or at game over, toggle:", theme),
+            help_text("1  No shields (NS)              +25% final score", theme),
+            help_text("2  Invisible invaders (INV)     +50% final score", theme),
+            help_text("   -- they only flash as the formation steps", theme),
+            help_text("3  Double-speed bombs (2xB)     +25% final score", theme),
+            help_text("Toggling restarts the game. The modifiers used", theme),
+            help_text("are saved with your score.", theme),
+            help_blank(),
+            help_section("Game Over", theme),
+            help_text("Lose a life when hit by an alien bullet.", theme),
+            help_text("Instant loss if aliens reach the bottom.", theme),
+            help_text("3 lives total.", theme),
+            help_blank(),
+            help_section("Controls", theme),
+            help_key("Left / Right", "Move ship", theme),
+            help_key("Space / Up", "Fire (max 3 bullets)", theme),
+            help_key("1 / 2 / 3", "Toggle modifiers", theme),
+            help_key("P", "Pause", theme),
+            help_key("R", "Restart", theme),
         ],
         Tab::JezzBall => vec![
-            help_section("JezzBall"),
-            help_blank(),
-            help_text("Trap bouncing balls by building walls across the grid."),
-            help_text("Walls grow from your cursor in both directions."),
-            help_text("If a ball hits a growing wall, you lose a life!"),
-            help_text("Fill 75% of the grid to advance to the next level."),
-            help_text("Each level adds more balls (up to 8)."),
-            help_blank(),
-            help_section("Scoring"),
-            help_text("+10 pts per wall completed"),
-            help_text("+1 pt per cell filled"),
-            help_text("+100 x level for clearing a level"),
-            help_blank(),
-            help_section("Strategy"),
-            help_text("Watch ball trajectories before placing walls."),
-            help_text("Toggle wall direction to best divide the space."),
-            help_text("Isolate balls into small regions."),
-            help_blank(),
-            help_section("Controls"),
-            help_key("Arrow keys", "Move cursor"),
-            help_key("Space / Enter", "Place wall"),
-            help_key("D", "Toggle direction (H/V)"),
-            help_key("P", "Pause"),
-            help_key("R", "Restart"),
+            help_section("JezzBall", theme),
+            help_blank(),
+            help_text("Trap bouncing balls by building walls across the grid.", theme),
+            help_text("Walls grow from your cursor in both directions.", theme),
+            help_text("If a ball hits a growing wall, you lose a life!", theme),
+            help_text("Fill 75% of the grid to advance to the next level.", theme),
+            help_text("Each level adds more balls (up to 8).", theme),
+            help_blank(),
+            help_section("Scoring", theme),
+            help_text("+10 pts per wall completed", theme),
+            help_text("+1 pt per cell filled", theme),
+            help_text("+100 x level for clearing a level", theme),
+            help_blank(),
+            help_section("Strategy", theme),
+            help_text("Watch ball trajectories before placing walls.", theme),
+            help_text("Toggle wall direction to best divide the space.", theme),
+            help_text("Isolate balls into small regions.", theme),
+            help_blank(),
+            help_section("Level Clear", theme),
+            help_text("The level's walls replay beside its stats:", theme),
+            help_text("walls built and lost, time, and capture per wall.", theme),
+            help_text("The graph shows capture % over the level.", theme),
+            help_text("All-time totals are kept between sessions.", theme),
+            help_blank(),
+            help_section("Controls", theme),
+            help_key("Arrow keys", "Move cursor", theme),
+            help_key("Space / Enter", "Place wall", theme),
+            help_key("D", "Toggle direction (H/V)", theme),
+            help_key("Left click", "Horizontal wall at the pointer", theme),
+            help_key("Right click", "Vertical wall at the pointer", theme),
+            help_key("V (level clear)", "Watch the replay again", theme),
+            help_key("P", "Pause", theme),
+            help_key("R", "Restart", theme),
         ],
         Tab::Asteroids => vec![
-            help_section("Asteroids"),
-            help_blank(),
-            help_text("Pilot your ship through an asteroid field."),
-            help_text("Destroy all asteroids to clear the level."),
-            help_text("Large asteroids split into 2 medium, medium into 2 small."),
-            help_text("Your ship wraps around screen edges. So do asteroids."),
-            help_text("3 lives. Brief invulnerability after each hit."),
-            help_blank(),
-            help_section("Scoring"),
-            help_text("Large asteroids:  20 pts"),
-            help_text("Medium asteroids: 50 pts"),
-            help_text("Small asteroids:  100 pts"),
-            help_blank(),
-            help_section("Tips"),
-            help_text("Use thrust sparingly -- momentum carries you."),
-            help_text("Friction slowly slows you down (0.99x per tick)."),
-            help_text("Max 8 bullets on screen, 5-tick fire cooldown."),
-            help_blank(),
-            help_section("Controls"),
-            help_key("Left / Right", "Rotate ship"),
-            help_key("Up", "Thrust forward"),
-            help_key("Space", "Fire"),
-            help_key("P", "Pause"),
-            help_key("R", "Restart"),
+            help_section("Asteroids", theme),
+            help_blank(),
+            help_text("Pilot your ship through an asteroid field.", theme),
+            help_text("Destroy all asteroids to clear the level.", theme),
+            help_text("Large asteroids split into 2 medium, medium into 2 small.", theme),
+            help_text("Shots push rocks along; off-centre hits set them spinning.", theme),
+            help_text("Pieces carry on with the rock's motion and spin.", theme),
+            help_text("Your ship wraps around screen edges. So do asteroids.", theme),
+            help_text("3 lives. Brief invulnerability after each hit.", theme),
+            help_blank(),
+            help_section("Scoring", theme),
+            help_text("Large asteroids:  20 pts", theme),
+            help_text("Medium asteroids: 50 pts", theme),
+            help_text("Small asteroids:  100 pts", theme),
+            help_blank(),
+            help_section("Tips", theme),
+            help_text("Use thrust sparingly -- momentum carries you.", theme),
+            help_text("Friction slowly slows you down (0.99x per tick).", theme),
+            help_text("Max 8 bullets on screen, 5-tick fire cooldown.", theme),
+            help_text("Screen shake can be turned off with reduced_motion", theme),
+            help_text("under [accessibility] in config.toml.", theme),
+            help_blank(),
+            help_section("Controls", theme),
+            help_key("Left / Right", "Rotate ship", theme),
+            help_key("Up", "Thrust forward", theme),
+            help_key("Space", "Fire", theme),
+            help_key("P", "Pause", theme),
+            help_key("R", "Restart", theme),
+        ],
+        Tab::Plugins => vec![
+            help_section("Plugins", theme),
+            help_blank(),
+            help_text("Each shared library in plugins/ next to the binary", theme),
+            help_text("adds a game. They aren't put on the score tables.", theme),
+            help_blank(),
+            help_section("Controls", theme),
+            help_key("Up / Down", "Select a game in the list", theme),
+            help_key("Enter", "Play it", theme),
+            help_key("L", "Back to the list, when paused or over", theme),
+        ],
+        Tab::Settings => vec![
+            help_section("Settings", theme),
+            help_blank(),
+            help_text("Each change applies straight away and is written", theme),
+            help_text("back to config.toml, keeping the file's comments.", theme),
+            help_text("Keyboard presets go to the key bindings file, as K", theme),
+            help_text("on Home saves them. Hidden in kiosk mode.", theme),
+            help_blank(),
+            help_section("Controls", theme),
+            help_key("Up / Down", "Select a setting", theme),
+            help_key("Left / Right", "Change it", theme),
+            help_key("Enter", "Change it, or open the score reset", theme),
+            help_key("Esc", "Back to Home", theme),
         ],
         Tab::Beam => vec![
-            help_section("Beam -- Particle Beam Simulation"),
-            help_blank(),
-            help_text("Keep a particle beam stable for 5 orbits around a"),
-            help_text("24-section accelerator ring by tuning magnets."),
-            help_blank(),
-            help_section("How It Works"),
-            help_text("The beam circulates through 24 sections. Each section"),
-            help_text("has 6 magnets you must configure:"),
-            help_blank(),
-            help_text("  QF (Focus Quad)    Focuses beam in X, defocuses in Y"),
-            help_text("  D1 (Dipole 1)      Bends beam around the ring"),
-            help_text("  QD (Defocus Quad)  Defocuses in X, focuses in Y"),
-            help_text("  D2 (Dipole 2)      Bends beam around the ring"),
-            help_text("  VT (Vertical Trim) Fine vertical position correction"),
-            help_text("  HT (Horiz. Trim)   Fine horizontal position correction"),
-            help_blank(),
-            help_section("Getting Started"),
-            help_text("1. Set BOTH dipoles (D1, D2) to ~0.131 in every section."),
-            help_text("   Without this, the beam flies straight into the wall!"),
-            help_text("   Tip: set one section, then press C to copy to all."),
-            help_text("2. Adjust QF/QD to control beam size (focusing)."),
-            help_text("3. Press SPACE to start the beam."),
-            help_text("4. Use trims (VT/HT) to correct orbit if needed."),
-            help_blank(),
-            help_section("Loss Conditions"),
-            help_text("Position beyond +/-50: instant beam loss (hard wall)."),
-            help_text("Beam edges past +/-25: accumulating losses."),
-            help_text("Game over when accumulated losses reach 100."),
-            help_text("Red diamond markers show dynamic aperture restrictions."),
-            help_blank(),
-            help_section("Power Supply Ramps"),
-            help_text("Each magnet has 10 ramp points (keys 0-9), one per turn."),
-            help_text("This lets you program different magnet strengths per orbit."),
-            help_text("Ramp values are constrained within +/-0.5 of neighbors."),
-            help_blank(),
-            help_section("Bump Mode (B key)"),
-            help_text("Creates a controlled orbit perturbation using trim dipoles"),
-            help_text("across 3, 4, or 5 consecutive sections."),
-            help_text("Coefficients sum to zero so the net angle cancels out."),
-            help_text("  3-bump: [+1, -2, +1]"),
-            help_text("  4-bump: [+1, -1, -1, +1]"),
-            help_text("  5-bump: [+1, -2, +2, -2, +1]"),
-            help_blank(),
-            help_section("Difficulty"),
-            help_text("Easy: beam size stays constant."),
-            help_text("Hard: beam grows 0.05 units per element (phase instability)."),
-            help_text("Press D to toggle before starting."),
-            help_blank(),
-            help_section("Scoring"),
-            help_text("Score = sum of |magnet powers| x 100."),
-            help_text("Lower power usage = more efficient = better score!"),
-            help_blank(),
-            help_section("Controls"),
-            help_key("Space", "Start beam / Restart after game over"),
-            help_key("Up / Down", "Select magnet (or adjust bump trims)"),
-            help_key("Left / Right", "Decrease / Increase magnet power"),
-            help_key("[ / ]", "Jump to prev / next section"),
-            help_key("+ / -", "Double / Halve power step size"),
-            help_key("0-9", "Select ramp point for current turn"),
-            help_key("C", "Copy current section to all sections"),
-            help_key("Z", "Zero selected magnet ramp value"),
-            help_key("X", "Zero all ramp values in current section"),
-            help_key("B", "Toggle bump mode (off/3/4/5)"),
-            help_key("W / S", "Bump: adjust H-trim only"),
-            help_key("E / Q", "Bump: adjust V-trim only"),
-            help_key("D", "Toggle difficulty (Easy/Hard)"),
-            help_key("P", "Pause"),
-            help_key("R", "Restart"),
+            help_section("Beam -- Particle Beam Simulation", theme),
+            help_blank(),
+            help_text("Keep a particle beam stable for 5 orbits around a", theme),
+            help_text("24-section accelerator ring by tuning magnets.", theme),
+            help_blank(),
+            help_section("How It Works", theme),
+            help_text("The beam circulates through 24 sections. Each section", theme),
+            help_text("has 6 magnets you must configure:", theme),
+            help_blank(),
+            help_text("  QF (Focus Quad)    Focuses beam in X, defocuses in Y", theme),
+            help_text("  D1 (Dipole 1)      Bends beam around the ring", theme),
+            help_text("  QD (Defocus Quad)  Defocuses in X, focuses in Y", theme),
+            help_text("  D2 (Dipole 2)      Bends beam around the ring", theme),
+            help_text("  VT (Vertical Trim) Fine vertical position correction", theme),
+            help_text("  HT (Horiz. Trim)   Fine horizontal position correction", theme),
+            help_blank(),
+            help_section("Getting Started", theme),
+            help_text("1. Set BOTH dipoles (D1, D2) to ~0.131 in every section.", theme),
+            help_text("   Without this, the beam flies straight into the wall!", theme),
+            help_text("   Tip: set one section, then press C to copy to all.", theme),
+            help_text("2. Adjust QF/QD to control beam size (focusing).", theme),
+            help_text("3. Press SPACE to start the beam.", theme),
+            help_text("4. Use trims (VT/HT) to correct orbit if needed.", theme),
+            help_blank(),
+            help_section("Loss Conditions", theme),
+            help_text("Position beyond +/-50: instant beam loss (hard wall).", theme),
+            help_text("Beam edges past +/-25: accumulating losses.", theme),
+            help_text("Game over when accumulated losses reach 100.", theme),
+            help_text("Red diamond markers show dynamic aperture restrictions.", theme),
+            help_blank(),
+            help_section("Power Supply Ramps", theme),
+            help_text("Each magnet has 10 ramp points (keys 0-9), one per turn.", theme),
+            help_text("This lets you program different magnet strengths per orbit.", theme),
+            help_text("Ramp values are constrained within +/-0.5 of neighbors.", theme),
+            help_blank(),
+            help_section("Economy", theme),
+            help_text("The score rewards the lightest touch: the power the design", theme),
+            help_text("bend needs over all the |power| spent, magnet by magnet as", theme),
+            help_text("the beam passes and on through the rest of the 10-point ramp.", theme),
+            help_text("100% is worth 1000. The meter runs live during a run and", theme),
+            help_text("projects the whole ramp program before SPACE.", theme),
+            help_blank(),
+            help_section("Bump Mode (B key)", theme),
+            help_text("Creates a controlled orbit perturbation using trim dipoles", theme),
+            help_text("across 3, 4, or 5 consecutive sections.", theme),
+            help_text("Coefficients sum to zero so the net angle cancels out.", theme),
+            help_text("  3-bump: [+1, -2, +1]", theme),
+            help_text("  4-bump: [+1, -1, -1, +1]", theme),
+            help_text("  5-bump: [+1, -2, +2, -2, +1]", theme),
+            help_blank(),
+            help_section("Difficulty", theme),
+            help_text("Easy: beam size stays constant.", theme),
+            help_text("Hard: beam grows 0.05 units per element (phase instability).", theme),
+            help_text("Press D to toggle before starting.", theme),
+            help_blank(),
+            help_section("Fault Mode (M)", theme),
+            help_text("One random magnet is miswired: a quad or trim with its", theme),
+            help_text("polarity reversed, or a section's VT/HT cables swapped.", theme),
+            help_text("Find it from how the orbit responds, select it and press F.", theme),
+            help_text("A right flag is worth 250, less 75 per wrong flag (3 max).", theme),
+            help_text("The fault stays through retries until the orbit is done.", theme),
+            help_blank(),
+            help_section("Time Attack (T)", theme),
+            help_text("Arming time attack zeroes every magnet and starts a clock.", theme),
+            help_text("Establish 5 consecutive turns ending within 2 units of the", theme),
+            help_text("target orbit; the time goes to the Beam TA table (lower wins).", theme),
+            help_text("Retries after a loss keep the clock running; R restarts it.", theme),
+            help_blank(),
+            help_section("Challenges", theme),
+            help_text("A opens the restriction editor: pick a section, X/Y cycle its", theme),
+            help_text("restriction (none, <=0, >=0), arrows and J/L move the target.", theme),
+            help_text("S names and saves the layout to the challenges folder and", theme),
+            help_text("shows a share code; U pastes someone else's code to import it.", theme),
+            help_text("G cycles curated and saved challenges; N loads today's daily.", theme),
+            help_blank(),
+            help_section("Scoring", theme),
+            help_text("Score = sum of |magnet powers| x 100.", theme),
+            help_text("Lower power usage = more efficient = better score!", theme),
+            help_blank(),
+            help_section("Controls", theme),
+            help_key("Space", "Start beam / Restart after game over", theme),
+            help_key("Up / Down", "Select magnet (or adjust bump trims)", theme),
+            help_key("Left / Right", "Decrease / Increase magnet power", theme),
+            help_key("[ / ]", "Jump to prev / next section", theme),
+            help_key("+ / -", "Double / Halve power step size", theme),
+            help_key("0-9", "Select ramp point for current turn", theme),
+            help_key("C", "Copy current section to all sections", theme),
+            help_key("Z", "Zero selected magnet ramp value", theme),
+            help_key("X", "Zero all ramp values in current section", theme),
+            help_key("B", "Toggle bump mode (off/3/4/5)", theme),
+            help_key("W / S", "Bump: adjust H-trim only", theme),
+            help_key("E / Q", "Bump: adjust V-trim only", theme),
+            help_key("D", "Toggle difficulty (Easy/Hard)", theme),
+            help_key("T", "Toggle time attack (before starting)", theme),
+            help_key("A", "Restriction editor (before starting)", theme),
+            help_key("G", "Load next challenge from the pool", theme),
+            help_key("N", "Load the daily challenge", theme),
+            help_key("U", "Import a challenge share code", theme),
+            help_key("P", "Pause", theme),
+            help_key("R", "Restart", theme),
+            help_blank(),
+            help_section("Mouse", theme),
+            help_key("Click ring", "Select section (bump: move bump there)", theme),
+            help_key("Click magnet", "Select magnet in the panel", theme),
+            help_key("Wheel on ring", "Step through sections", theme),
+            help_key("Wheel on panel", "Adjust power of the magnet under cursor", theme),
+        ],
+        Tab::BeamDump => vec![
+            help_section("Beam Dump -- Ablate the Target", theme),
+            help_blank(),
+            help_text("Steer a proton bunch into a brick target using corrector", theme),
+            help_text("kicks; there is no paddle. Every hit deposits energy that", theme),
+            help_text("ablates the brick, and each kick costs a little charge.", theme),
+            help_blank(),
+            help_section("Controls", theme),
+            help_key("Left / Right", "Horizontal corrector kick", theme),
+            help_key("Up / Down", "Vertical corrector kick", theme),
+            help_key("Space", "Inject the next pulse", theme),
+            help_key("P", "Pause", theme),
+            help_key("R", "Restart", theme),
+            help_blank(),
+            help_section("Beam Pipe", theme),
+            help_text("The walls are the pipe: grazing them scrapes intensity", theme),
+            help_text("and a dimmer bunch deposits less. Falling out of the", theme),
+            help_text("bottom of the pipe loses the pulse. 3 pulses per run.", theme),
+            help_blank(),
+            help_section("Target", theme),
+            help_text("░ graphite  1 hit    ▒ copper  2 hits    ▓ tungsten  3 hits", theme),
+            help_text("Clear the target to move on to a denser one.", theme),
+        ],
+        Tab::Scope => vec![
+            help_section("Scope -- Oscilloscope Rhythm", theme),
+            help_blank(),
+            help_text("Pulses scroll along two traces toward the red trigger", theme),
+            help_text("line. Fire each channel's trigger as its pulse crosses.", theme),
+            help_blank(),
+            help_section("Controls", theme),
+            help_key("F / Left", "Channel 1 trigger (yellow, top)", theme),
+            help_key("J / Right", "Channel 2 trigger (cyan, bottom)", theme),
+            help_key("Left / Right", "Pick a beat map before starting", theme),
+            help_key("Space", "Start", theme),
+            help_key("P", "Pause", theme),
+            help_key("R", "Restart", theme),
+            help_blank(),
+            help_section("Scoring", theme),
+            help_text("PERFECT 300 · GREAT 200 · GOOD 100, times the combo", theme),
+            help_text("multiplier (up to 4×). Misses and triggers with no pulse", theme),
+            help_text("under the line drain the signal; the run fails at zero.", theme),
+            help_blank(),
+            help_section("Beat Maps", theme),
+            help_text("Add .txt files to patterns/ next to the binary: bpm,", theme),
+            help_text("steps and wave lines, then 1, 2 or 3 per step to pulse", theme),
+            help_text("channel 1, 2 or both, and . to rest.", theme),
+        ],
+        Tab::Router => vec![
+            help_section("Router -- Packet Router Puzzle", theme),
+            help_blank(),
+            help_text("Packets queue at the numbered sources and hop along the", theme),
+            help_text("wires to the lettered sink of their colour. Turn the", theme),
+            help_text("router tiles until every wire end meets its neighbour.", theme),
+            help_blank(),
+            help_section("Controls", theme),
+            help_key("Arrows", "Move the cursor", theme),
+            help_key("Space / Enter", "Rotate the tile clockwise", theme),
+            help_key("Z / X", "Rotate anticlockwise", theme),
+            help_key("Click", "Rotate a tile (right-click back)", theme),
+            help_key("P", "Pause", theme),
+            help_key("R", "Restart", theme),
+            help_blank(),
+            help_section("Routing", theme),
+            help_text("At a junction a packet takes the first way that leads to", theme),
+            help_text("its sink, straight on first; crossings only go straight.", theme),
+            help_text("A packet stuck at a dead end is dropped after a few hops.", theme),
+            help_text("Five dropped packets, or a full queue, and the network's down.", theme),
+        ],
+        Tab::Racer => vec![
+            help_section("Racer -- Pseudo-3D Circuit", theme),
+            help_blank(),
+            help_text("Drive the looping circuit against the clock. Each", theme),
+            help_text("checkpoint gantry adds time; the run ends when it's out.", theme),
+            help_blank(),
+            help_section("Controls", theme),
+            help_key("Up / W", "One notch more throttle", theme),
+            help_key("Down / S", "One notch less throttle", theme),
+            help_key("Left / Right, A / D", "Steer (hold)", theme),
+            help_key("Space", "Brake and close the throttle", theme),
+            help_key("P", "Pause", theme),
+            help_key("R", "Restart", theme),
+            help_blank(),
+            help_section("Driving", theme),
+            help_text("Bends push the car to the outside, harder the faster", theme),
+            help_text("you take them. The grass holds you to 80 km/h, and", theme),
+            help_text("running into traffic costs most of your speed.", theme),
+        ],
+        Tab::Delve => vec![
+            help_section("Delve -- Ten-Floor Roguelike", theme),
+            help_blank(),
+            help_text("Fight down ten floors of rooms and corridors to the", theme),
+            help_text("dragon at the bottom. Death is permanent: R or a lost", theme),
+            help_text("fight starts a brand new dungeon.", theme),
+            help_blank(),
+            help_section("Controls", theme),
+            help_key("Arrows / h j k l", "Move; walk into a monster to attack", theme),
+            help_key(". / Space", "Wait a turn", theme),
+            help_key("U", "Drink a potion (heals half your health)", theme),
+            help_key("> / Enter", "Take the stairs down", theme),
+            help_key("D", "Daily dungeon, before your first move", theme),
+            help_key("P", "Pause", theme),
+            help_key("R", "New dungeon", theme),
+            help_blank(),
+            help_section("The Dungeon", theme),
+            help_text("Each floor is laid out from the run's seed, so the daily", theme),
+            help_text("dungeon is the same for everyone that day. Monsters wake", theme),
+            help_text("when your torch finds them. ! potions, $ gold, / blades,", theme),
+            help_text("[ armour and ? scrolls that map the floor lie about.", theme),
+        ],
+        Tab::Columns => vec![
+            help_section("Columns -- Falling Gems", theme),
+            help_blank(),
+            help_text("Columns of three gems fall into the well. Line up three", theme),
+            help_text("or more alike across, down or diagonally to clear them.", theme),
+            help_text("The game ends when a column can't fit in the well.", theme),
+            help_blank(),
+            help_section("Controls", theme),
+            help_key("← →", "Move the column", theme),
+            help_key("↑ / X", "Cycle the gems down (Z cycles up)", theme),
+            help_key("↓", "Soft drop", theme),
+            help_key("Space", "Hard drop", theme),
+            help_key("P", "Pause", theme),
+            help_key("R", "Restart", theme),
+            help_blank(),
+            help_section("Chains", theme),
+            help_text("Gems drop into the gaps a clear leaves, and any lines", theme),
+            help_text("they make clear too, each step of the chain scoring", theme),
+            help_text("more. Several lines at once earn a combo bonus. The", theme),
+            help_text("columns fall faster every 30 gems.", theme),
+        ],
+        Tab::Whack => vec![
+            help_section("Whack -- Reaction Trainer", theme),
+            help_blank(),
+            help_text("Moles pop up in nine holes, each with its own key. Hit", theme),
+            help_text("them before they duck back down; three that get away", theme),
+            help_text("end the game. The window shrinks every 10 hits.", theme),
+            help_blank(),
+            help_section("Controls", theme),
+            help_key("Q W E / A S D / Z X C", "Whack a hole, row by row", theme),
+            help_key("7 8 9 / 4 5 6 / 1 2 3", "The same holes on the number pad", theme),
+            help_key("Click", "Whack a hole", theme),
+            help_key("P", "Pause", theme),
+            help_key("R", "Restart", theme),
+            help_blank(),
+            help_section("Moles", theme),
+            help_text("Golden moles are worth triple but duck sooner. Leave", theme),
+            help_text("bombs alone: hitting one costs a life. Every hit's", theme),
+            help_text("reaction time is kept, and Home shows the median and", theme),
+            help_text("90th percentile over your recent runs.", theme),
+        ],
+        Tab::Life => vec![
+            help_section("Life -- Conway's Game of Life", theme),
+            help_blank(),
+            help_text("A cell with three live neighbours is born; one with two", theme),
+            help_text("or three lives on; every other cell dies. Draw a start,", theme),
+            help_text("run it, and see what it becomes.", theme),
+            help_blank(),
+            help_section("Controls", theme),
+            help_key("Arrows", "Move the cursor (Shift: 8 cells)", theme),
+            help_key("Space", "Flip a cell, or stamp the pattern", theme),
+            help_key("[ / ]", "Pick a pattern to stamp", theme),
+            help_key("O", "Turn the pattern a quarter", theme),
+            help_key("Enter", "Run or stop", theme),
+            help_key("N / .", "Step one generation", theme),
+            help_key("+ / -", "Run speed", theme),
+            help_key("R / Backspace", "Rewind to before the run", theme),
+            help_key("C", "Clear what you placed", theme),
+            help_key("M", "Sandbox, then each challenge", theme),
+            help_key("P", "Pause", theme),
+            help_blank(),
+            help_section("Challenges", theme),
+            help_text("Place a few cells in the green build zone, then run them", theme),
+            help_text("to meet the goal before the generation limit. Spare cells", theme),
+            help_text("and generations add to the score.", theme),
+        ],
+        Tab::Pong => vec![
+            help_section("Pong -- First to 11", theme),
+            help_blank(),
+            help_text("Return the ball past the CPU's paddle. Where it meets", theme),
+            help_text("your paddle sets the angle: the centre sends it back", theme),
+            help_text("flat, the ends steep.", theme),
+            help_blank(),
+            help_section("Controls", theme),
+            help_key("Up / Down, W / S", "Move your paddle", theme),
+            help_key("Mouse", "Paddle follows the pointer", theme),
+            help_key("D", "CPU: Easy, Normal or Impossible (before the first point)", theme),
+            help_key("R", "Restart the match", theme),
+            help_key("P", "Pause", theme),
+            help_blank(),
+            help_section("Spin", theme),
+            help_text("Hit while your paddle is moving and the ball takes some of", theme),
+            help_text("that speed and spin with it, curving the way you moved.", theme),
+            help_text("Easy and Normal aim for the straight-line path, so a curve", theme),
+            help_text("can beat them; Impossible reads it, but can't always reach", theme),
+            help_text("a fast, steep ball.", theme),
+            help_blank(),
+            help_section("Scoring", theme),
+            help_text("100 a point and 1000 for the match, doubled on Normal and", theme),
+            help_text("four times on Impossible. The tables show the points", theme),
+            help_text("conceded and the CPU level.", theme),
+        ],
+        Tab::Minesweeper => vec![
+            help_section("Minesweeper -- Clear the Field", theme),
+            help_blank(),
+            help_text("Open every square that isn't a mine. A number tells how", theme),
+            help_text("many of the eight squares around it hide one; an empty", theme),
+            help_text("square opens its neighbours for you. Your first square is", theme),
+            help_text("never a mine.", theme),
+            help_blank(),
+            help_section("Controls", theme),
+            help_key("Arrows", "Move the cursor", theme),
+            help_key("Space / Enter", "Open a square", theme),
+            help_key("F", "Flag or unflag a mine", theme),
+            help_key("Click", "Open a square", theme),
+            help_key("Right-click", "Flag or unflag", theme),
+            help_key("Middle-click", "Open around a number", theme),
+            help_key("D", "Board size (before the first square)", theme),
+            help_key("R", "New board", theme),
+            help_key("P", "Pause", theme),
+            help_blank(),
+            help_section("Chording", theme),
+            help_text("Open a number that already has that many flags around it", theme),
+            help_text("and the rest of its neighbours open at once. A wrong flag", theme),
+            help_text("sets off the mine it was hiding.", theme),
+            help_blank(),
+            help_section("Scoring", theme),
+            help_text("Only a cleared field scores: 10000 / (seconds + 10), times", theme),
+            help_text("4 on Intermediate (16×16, 40 mines) and 10 on Expert", theme),
+            help_text("(30×16, 99 mines). The tables show the time and board.", theme),
         ],
         Tab::Booster => vec![
-            help_section("Booster -- Fermilab Booster Synchrotron"),
-            help_blank(),
-            help_text("Accelerate protons from 400 MeV to 8 GeV in a realistic"),
-            help_text("simulation of the Fermilab Booster synchrotron."),
-            help_text("Navigate the critical transition energy crossing and"),
-            help_text("extract the beam successfully."),
-            help_blank(),
-            help_section("Game Phases"),
-            help_text("SETUP        Configure correctors before injection"),
-            help_text("INJECT       Beam enters at 400 MeV (press SPACE)"),
-            help_text("RAMP         Energy ramping, tune correction needed"),
-            help_text("PRE-Xt       Approaching transition -- chromaticity critical"),
-            help_text("TRANSITION   At gamma ~= gamma_t, must flip RF phase (T)"),
-            help_text("POST-Xt      Damp oscillations after transition"),
-            help_text("EXTRACTED!   Success -- beam reached 8 GeV!"),
-            help_blank(),
-            help_section("Key Concept: Transition Crossing"),
-            help_text("At ~turn 7100, beam energy reaches transition gamma (5.446)."),
-            help_text("The slip factor eta crosses zero -- RF phase must flip!"),
-            help_text("Press T to toggle RF phase at the right moment."),
-            help_text("Good chromaticity correction (sextupoles) reduces losses."),
-            help_text("Target: chromaticity ~7 for clean transition."),
-            help_blank(),
-            help_section("Corrector Magnets (6 types per cell)"),
-            help_text("H-Trim      Horizontal orbit correction (rad)"),
-            help_text("V-Trim      Vertical orbit correction (rad)"),
-            help_text("Trim-Quad   Quadrupole strength fine-tune (m^-2)"),
-            help_text("Skew-Quad   X-Y coupling correction (m^-2)"),
-            help_text("Sext-A      Chromaticity family A (m^-3)"),
-            help_text("Sext-B      Chromaticity family B (m^-3)"),
-            help_blank(),
-            help_section("Bus Corrections"),
-            help_text("MQAT (J/K)   Quad bus trim -- adjusts all quad strengths"),
-            help_text("MDAT (M/N)   Bend bus trim -- adjusts all dipole angles"),
-            help_blank(),
-            help_section("Display Modes (V to cycle)"),
-            help_text("Orbit        Turn-by-turn X-Y position plot"),
-            help_text("X-X'         Horizontal phase space + Courant-Snyder ellipse"),
-            help_text("Y-Y'         Vertical phase space + ellipse"),
-            help_text("Longitudinal RF bucket diagram (phi vs dE)"),
-            help_text("Tune         Qx-Qy working point with resonance lines"),
-            help_blank(),
-            help_section("Bump Mode (B)"),
-            help_text("Apply coordinated trim corrections across 3/4/5 sections."),
-            help_text("Coefficients cancel so the bump is localized."),
-            help_text("Use W/S for H-trims, E/Q for V-trims in bump mode."),
-            help_blank(),
-            help_section("Loss Conditions"),
-            help_text("Hard wall at +/-50 display units = instant loss."),
-            help_text("Beam edges beyond +/-25 units accumulate losses."),
-            help_text("Game over when losses reach 100 or intensity drops to 0."),
-            help_blank(),
-            help_section("Scoring"),
-            help_text("Score = (intensity x 1000) + turns completed"),
-            help_text("       + 500 if transition crossed + 2000 if extracted"),
-            help_blank(),
-            help_section("Controls -- General"),
-            help_key("Space", "Inject beam"),
-            help_key("I", "Inject at custom X,Y coordinates"),
-            help_key("[ / ]", "Navigate cells 0-23"),
-            help_key("Up / Down", "Cycle corrector type"),
-            help_key("Left / Right", "Adjust selected corrector"),
-            help_key("+ / -", "Double / Halve adjustment step"),
-            help_key("C", "Copy cell correctors to all 24 cells"),
-            help_key("Z", "Zero current corrector"),
-            help_key("V", "Cycle display mode"),
-            help_key(". (period)", "Cycle sim speed (Slow/Normal/Fast)"),
-            help_blank(),
-            help_section("Controls -- RF & Bus"),
-            help_key("F / G", "Increase / Decrease RF voltage"),
-            help_key("T", "Toggle RF phase (for transition)"),
-            help_key("J / K", "Increase / Decrease quad bus (MQAT)"),
-            help_key("M / N", "Increase / Decrease bend bus (MDAT)"),
-            help_blank(),
-            help_section("Controls -- Bump Mode"),
-            help_key("B", "Toggle bump mode (off/3/4/5)"),
-            help_key("Up / Down", "Adjust all bump trims"),
-            help_key("Left / Right", "Shift bump position"),
-            help_key("W / S", "Adjust H-trim only"),
-            help_key("E / Q", "Adjust V-trim only"),
-            help_key("Z", "Zero all bump trims"),
-            help_blank(),
-            help_key("P", "Pause"),
-            help_key("R", "Reset (preserves corrector settings)"),
+            help_section("Booster -- Fermilab Booster Synchrotron", theme),
+            help_blank(),
+            help_text("Accelerate protons from 400 MeV to 8 GeV in a realistic", theme),
+            help_text("simulation of the Fermilab Booster synchrotron.", theme),
+            help_text("Navigate the critical transition energy crossing and", theme),
+            help_text("extract the beam successfully.", theme),
+            help_blank(),
+            help_section("Game Phases", theme),
+            help_text("SETUP        Configure correctors before injection", theme),
+            help_text("INJECT       Beam enters at 400 MeV (press SPACE)", theme),
+            help_text("RAMP         Energy ramping, tune correction needed", theme),
+            help_text("PRE-Xt       Approaching transition -- chromaticity critical", theme),
+            help_text("TRANSITION   At gamma ~= gamma_t, must flip RF phase (T)", theme),
+            help_text("POST-Xt      Damp oscillations after transition", theme),
+            help_text("EXTRACTED!   Success -- beam reached 8 GeV!", theme),
+            help_blank(),
+            help_section("Key Concept: Transition Crossing", theme),
+            help_text("At ~turn 7100, beam energy reaches transition gamma (5.446).", theme),
+            help_text("The slip factor eta crosses zero -- RF phase must flip!", theme),
+            help_text("Press T to toggle RF phase at the right moment.", theme),
+            help_text("Good chromaticity correction (sextupoles) reduces losses.", theme),
+            help_text("Target: chromaticity ~7 for clean transition.", theme),
+            help_blank(),
+            help_section("Corrector Magnets (6 types per cell)", theme),
+            help_text("H-Trim      Horizontal orbit correction (rad)", theme),
+            help_text("V-Trim      Vertical orbit correction (rad)", theme),
+            help_text("Trim-Quad   Quadrupole strength fine-tune (m^-2)", theme),
+            help_text("Skew-Quad   X-Y coupling correction (m^-2)", theme),
+            help_text("Sext-A      Chromaticity family A (m^-3)", theme),
+            help_text("Sext-B      Chromaticity family B (m^-3)", theme),
+            help_text("Coils heat with the square of their current. A value past", theme),
+            help_text("its rating shows orange; a cell that reaches 90C trips and", theme),
+            help_text("delivers no kicks until it cools to 50C (marked x on the", theme),
+            help_text("ring). Supplies stop at twice the rating.", theme),
+            help_blank(),
+            help_section("Orbit Drift & Slow Feedback", theme),
+            help_text("Ground motion and supply drift move the closed orbit through", theme),
+            help_text("the cycle, and any orbit blows up the emittance. In the console,", theme),
+            help_text("feedback <gain> <turns> corrects from the BPM averages every so", theme),
+            help_text("many turns (feedback off stops it). Too few turns and BPM noise", theme),
+            help_text("gets in; too many and the drift runs away; a gain near 2 rings.", theme),
+            help_text("The orbit plot shows CO (rms closed orbit) and the loop.", theme),
+            help_blank(),
+            help_section("Bus Corrections", theme),
+            help_text("MQAT (J/K)   Quad bus trim -- adjusts all quad strengths", theme),
+            help_text("MDAT (M/N)   Bend bus trim -- adjusts all dipole angles", theme),
+            help_blank(),
+            help_section("Display Modes (V to cycle)", theme),
+            help_text("Orbit        Turn-by-turn X-Y position plot", theme),
+            help_text("X-X'         Horizontal phase space + Courant-Snyder ellipse", theme),
+            help_text("Y-Y'         Vertical phase space + ellipse", theme),
+            help_text("Longitudinal RF bucket diagram (phi vs dE)", theme),
+            help_text("Tune         Qx-Qy working point with resonance lines", theme),
+            help_text("ORM-H/V      Orbit response heatmap: BPM cells (rows) vs", theme),
+            help_text("             trim correctors (columns); warm = +, cool = -", theme),
+            help_text("Emit         RMS emittance growth (h/v) vs turn through the cycle", theme),
+            help_text("Fill         84 RF buckets around the ring; the notch (·) must", theme),
+            help_text("             sit under the extraction kicker (▼) at extraction", theme),
+            help_text("Cycle        Intensity and losses vs turn, over a ghost of the", theme),
+            help_text("             best of the last 10 finished cycles", theme),
+            help_text("Match        Quad scan points and fit, fitted injected ellipses", theme),
+            help_text("             against the ring's matched circle", theme),
+            help_blank(),
+            help_section("Injection Matching", theme),
+            help_text("The transfer line delivers the beam off the ring's Twiss; the", theme),
+            help_text("mismatch factor B is how much the emittance grows once it", theme),
+            help_text("filaments. Before injecting, :scan sweeps MQAT and fits the", theme),
+            help_text("injected Twiss from the profile monitor; :match rematches the", theme),
+            help_text("line to the fit. Up to 300 setup points for B near 1.0.", theme),
+            help_blank(),
+            help_section("Harmonic Cavity", theme),
+            help_text("A second RF system at 2-4x the 84 harmonic reshapes the bucket.", theme),
+            help_text("At ~50% amplitude and 180 deg (2h) the bucket bottom goes flat,", theme),
+            help_text("stretching the bunch and cutting space-charge losses at injection.", theme),
+            help_text("Too much amplitude splits the bucket and the beam is lost.", theme),
+            help_blank(),
+            help_section("Notching & Cogging", theme),
+            help_text("At turn 300 the notcher clears 3 buckets for the kicker rise.", theme),
+            help_text("The notch drifts each cycle; trim MDAT (M/N) to cog it back.", theme),
+            help_text("Beam left in the kicker window is lost at extraction.", theme),
+            help_blank(),
+            help_section("Bump Mode (B)", theme),
+            help_text("Apply coordinated trim corrections across 3/4/5 sections.", theme),
+            help_text("Coefficients cancel so the bump is localized.", theme),
+            help_text("Use W/S for H-trims, E/Q for V-trims in bump mode.", theme),
+            help_blank(),
+            help_section("Loss Conditions", theme),
+            help_text("Hard wall at +/-50 display units = instant loss.", theme),
+            help_text("Beam edges beyond +/-25 units accumulate losses.", theme),
+            help_text("Game over when losses reach 100 or intensity drops to 0.", theme),
+            help_blank(),
+            help_section("Scoring", theme),
+            help_text("Score = (intensity x emittance kept x 1000) + turns completed", theme),
+            help_text("       + 500 if transition crossed + 2000 if extracted", theme),
+            help_text("Emittance kept = ε₀/ε, 1.0 with no blow-up (see Emit view)", theme),
+            help_blank(),
+            help_section("Controls -- General", theme),
+            help_key("Space", "Inject beam", theme),
+            help_key("I", "Inject at custom X,Y coordinates", theme),
+            help_key("[ / ]", "Navigate cells 0-23", theme),
+            help_key("Up / Down", "Cycle corrector type", theme),
+            help_key("Left / Right", "Adjust selected corrector", theme),
+            help_key("+ / -", "Double / Halve adjustment step", theme),
+            help_key("C", "Copy cell correctors to all 24 cells", theme),
+            help_key("Z", "Zero current corrector", theme),
+            help_key("V", "Cycle display mode", theme),
+            help_key(". (period)", "Cycle sim speed (Slow/Normal/Fast)", theme),
+            help_blank(),
+            help_section("Controls -- RF & Bus", theme),
+            help_key("F / G", "Increase / Decrease RF voltage", theme),
+            help_key("T", "Toggle RF phase (for transition)", theme),
+            help_key("H / L", "Increase / Decrease harmonic cavity voltage", theme),
+            help_key("Y / U", "Shift harmonic cavity phase -/+ 10 deg", theme),
+            help_key("O", "Cycle harmonic cavity multiple (2h/3h/4h)", theme),
+            help_key("J / K", "Increase / Decrease quad bus (MQAT)", theme),
+            help_key("M / N", "Increase / Decrease bend bus (MDAT)", theme),
+            help_blank(),
+            help_section("Controls -- Bump Mode", theme),
+            help_key("B", "Toggle bump mode (off/3/4/5)", theme),
+            help_key("Up / Down", "Adjust all bump trims", theme),
+            help_key("Left / Right", "Shift bump position", theme),
+            help_key("W / S", "Adjust H-trim only", theme),
+            help_key("E / Q", "Adjust V-trim only", theme),
+            help_key("Z", "Zero all bump trims", theme),
+            help_blank(),
+            help_section("Command Console (:)", theme),
+            help_text("Type settings the way the control room does. Up/Down recall,", theme),
+            help_text("Esc closes. Cells are c1-c24.", theme),
+            help_text("set c12 htrim -0.002       One corrector (or 'all' cells)", theme),
+            help_text("set rf 0.8                 rf rfphase rf2 rf2phase mqat mdat", theme),
+            help_text("get c12 / get rf           Read back a cell or parameter", theme),
+            help_text("ramp rf 0.5 0.9 @turn 3000 Ramp linearly from now to that turn", theme),
+            help_text("ramps [clear]              List or drop the ramps", theme),
+            help_text("measure [tune|orbit|...]   Also chrom, emit, intensity, energy", theme),
+            help_text("zero c12 / inject [x y]    Zero correctors / inject beam", theme),
+            help_blank(),
+            help_section("Controls -- Mouse", theme),
+            help_key("Click ring", "Select cell (bump: move bump there)", theme),
+            help_key("Click corrector", "Select corrector in the panel", theme),
+            help_key("Wheel on ring", "Step through cells", theme),
+            help_key("Wheel on panel", "Adjust corrector under cursor", theme),
+            help_blank(),
+            help_key("P", "Pause", theme),
+            help_key("R", "Reset (preserves corrector settings)", theme),
         ],
     }
 }
 
-fn render_help_overlay(frame: &mut Frame, area: Rect, tab: &Tab, scroll: u16) {
-    let lines = help_lines_for_tab(tab);
+fn render_help_overlay(frame: &mut Frame, area: Rect, tab: &Tab, info: Option<&GameInfo>, scroll: u16, theme: &Theme) {
+    let mut lines = help_lines_for_tab(tab, theme);
+    // The game's own description under its heading
+    if let Some(info) = info {
+        let at = 2.min(lines.len());
+        lines.splice(at..at, help_about(info, theme));
+        // Plugin games bring their own key list
+        if matches!(tab, Tab::Plugins) && !info.controls.is_empty() {
+            lines.push(help_blank());
+            lines.push(help_section(&format!("{} Controls", info.name), theme));
+            lines.extend(info.controls.iter().map(|(keys, action)| help_key(keys, action, theme)));
+        }
+    }
     let content_height = lines.len() as u16 + 4; // +4 for border + title/footer padding
 
     let overlay_w = 64u16.min(area.width.saturating_sub(4));
@@ -469,23 +1161,35 @@ fn render_help_overlay(frame: &mut Frame, area: Rect, tab: &Tab, scroll: u16) {
         Tab::JezzBall => " ? JezzBall Help ",
         Tab::Asteroids => " ? Asteroids Help ",
         Tab::Beam => " ? Beam Help ",
+        Tab::BeamDump => " ? Beam Dump Help ",
+        Tab::Scope => " ? Scope Help ",
+        Tab::Router => " ? Router Help ",
+        Tab::Racer => " ? Racer Help ",
+        Tab::Delve => " ? Delve Help ",
+        Tab::Columns => " ? Columns Help ",
+        Tab::Whack => " ? Whack Help ",
+        Tab::Life => " ? Life Help ",
+        Tab::Pong => " ? Pong Help ",
+        Tab::Minesweeper => " ? Minesweeper Help ",
+        Tab::Plugins => " ? Plugins Help ",
+        Tab::Settings => " ? Settings Help ",
         Tab::Booster => " ? Booster Help ",
     };
 
     let block = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Double)
-        .border_style(Style::default().fg(Color::Rgb(80, 200, 255)))
+        .border_style(Style::default().fg(theme.paint(Color::Rgb(80, 200, 255), Role::Blue)))
         .title(title)
-        .title_style(Style::default().fg(Color::Rgb(80, 200, 255)).add_modifier(Modifier::BOLD))
+        .title_style(Style::default().fg(theme.paint(Color::Rgb(80, 200, 255), Role::Blue)).add_modifier(Modifier::BOLD))
         .title_bottom(Line::from(vec![
-            Span::styled(" Press ", Style::default().fg(Color::Rgb(100, 100, 130))),
-            Span::styled("?", Style::default().fg(Color::Rgb(255, 220, 80)).add_modifier(Modifier::BOLD)),
-            Span::styled(" or ", Style::default().fg(Color::Rgb(100, 100, 130))),
-            Span::styled("Esc", Style::default().fg(Color::Rgb(255, 220, 80)).add_modifier(Modifier::BOLD)),
-            Span::styled(" to close ", Style::default().fg(Color::Rgb(100, 100, 130))),
+            Span::styled(" Press ", Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint))),
+            Span::styled("?", Style::default().fg(theme.paint(Color::Rgb(255, 220, 80), Role::Yellow)).add_modifier(Modifier::BOLD)),
+            Span::styled(" or ", Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint))),
+            Span::styled("Esc", Style::default().fg(theme.paint(Color::Rgb(255, 220, 80), Role::Yellow)).add_modifier(Modifier::BOLD)),
+            Span::styled(" to close ", Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint))),
         ]))
-        .style(Style::default().bg(Color::Rgb(15, 15, 25)));
+        .style(Style::default().bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
 
     let inner = block.inner(overlay_area);
     frame.render_widget(block, overlay_area);
@@ -494,7 +1198,7 @@ fn render_help_overlay(frame: &mut Frame, area: Rect, tab: &Tab, scroll: u16) {
     let scroll_pos = scroll.min(max_scroll);
 
     let p = Paragraph::new(lines)
-        .style(Style::default().bg(Color::Rgb(15, 15, 25)))
+        .style(Style::default().bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)))
         .scroll((scroll_pos, 0));
     frame.render_widget(p, inner);
 }
@@ -0,0 +1,456 @@
+use std::time::{Duration, Instant};
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::app::Tab;
+use crate::keymap::Layout as KeyLayout;
+use crate::profile::Profile;
+use crate::ui::compat::{Capabilities, RenderProfile};
+use crate::ui::theme::{Ink, Role, Theme};
+
+/// Longest name the score tables take
+const MAX_NAME_LEN: usize = 9;
+const WIZARD_WIDTH: u16 = 72;
+const WIZARD_HEIGHT: u16 = 20;
+/// Smallest terminal every game fits in
+const MIN_SIZE: (u16, u16) = (80, 24);
+const ACCENT: Ink = Ink(Color::Rgb(80, 200, 255), Role::Blue);
+const LABEL: Ink = Ink(Color::Rgb(100, 100, 130), Role::Faint);
+const TEXT: Ink = Ink(Color::Rgb(210, 210, 230), Role::Text);
+const BG: Ink = Ink(Color::Rgb(15, 15, 25), Role::Background);
+
+#[derive(Clone, Copy, PartialEq)]
+enum Step {
+    Terminal,
+    Theme,
+    Charset,
+    Keys,
+    Name,
+    Tour,
+}
+
+impl Step {
+    const ALL: [Step; 6] = [Step::Terminal, Step::Theme, Step::Charset, Step::Keys, Step::Name, Step::Tour];
+
+    fn title(self) -> &'static str {
+        match self {
+            Step::Terminal => "Terminal",
+            Step::Theme => "Theme",
+            Step::Charset => "Characters",
+            Step::Keys => "Keys",
+            Step::Name => "Profile",
+            Step::Tour => "Tour",
+        }
+    }
+}
+
+pub enum OnboardingAction {
+    None,
+    /// A theme or character set is being tried; show it straight away
+    Preview,
+    /// Setup is over (finished or skipped); start the tour if asked
+    Done { tour: bool },
+}
+
+/// First-run setup: what the terminal reports, then a theme, glyph set,
+/// keyboard layout and name, and the offer of a tour. Shown over Home
+/// so each choice can be seen on the real screen.
+pub struct Onboarding {
+    step: Step,
+    caps: Capabilities,
+    pub theme: Theme,
+    /// None follows `[display] profile`, as `Profile::charset`
+    pub charset: Option<RenderProfile>,
+    pub layout: KeyLayout,
+    pub left_handed: bool,
+    pub name: String,
+}
+
+impl Onboarding {
+    pub fn new() -> Self {
+        let caps = Capabilities::detect();
+        let layout = caps.locale.as_deref().map_or(KeyLayout::Qwerty, KeyLayout::for_locale);
+        let name = ["USER", "USERNAME"].into_iter()
+            .find_map(|v| std::env::var(v).ok())
+            .map(|n| n.chars().filter(char::is_ascii_graphic).take(MAX_NAME_LEN).collect::<String>().to_ascii_uppercase())
+            .unwrap_or_default();
+        Onboarding { step: Step::Terminal, caps, theme: Theme::Arcade, charset: None, layout, left_handed: false, name }
+    }
+
+    /// The profile these choices make.
+    pub fn profile(&self) -> Profile {
+        Profile { name: self.name.clone(), theme: self.theme, charset: self.charset, ..Profile::default() }
+    }
+
+    fn index(&self) -> usize {
+        Step::ALL.iter().position(|&s| s == self.step).unwrap_or(0)
+    }
+
+    fn go(&mut self, delta: isize) {
+        let i = (self.index() as isize + delta).clamp(0, Step::ALL.len() as isize - 1);
+        self.step = Step::ALL[i as usize];
+    }
+
+    /// Charset choices in list order: auto, then each profile.
+    fn charsets() -> impl Iterator<Item = Option<RenderProfile>> {
+        std::iter::once(None).chain(RenderProfile::ALL.into_iter().map(Some))
+    }
+
+    /// Move the current step's choice up or down its list.
+    fn cycle(&mut self, delta: isize) -> OnboardingAction {
+        fn step<T: Copy + PartialEq>(all: &[T], now: T, delta: isize) -> T {
+            let i = all.iter().position(|&t| t == now).unwrap_or(0) as isize;
+            all[(i + delta).rem_euclid(all.len() as isize) as usize]
+        }
+        match self.step {
+            Step::Theme => self.theme = step(&Theme::ALL, self.theme, delta),
+            Step::Charset => self.charset = step(&Self::charsets().collect::<Vec<_>>(), self.charset, delta),
+            Step::Keys => self.layout = step(&KeyLayout::ALL, self.layout, delta),
+            _ => return OnboardingAction::None,
+        }
+        OnboardingAction::Preview
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> OnboardingAction {
+        if self.step == Step::Tour {
+            return match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => OnboardingAction::Done { tour: true },
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => OnboardingAction::Done { tour: false },
+                KeyCode::Left => { self.go(-1); OnboardingAction::None }
+                _ => OnboardingAction::None,
+            };
+        }
+        match key.code {
+            // Skipping keeps what's been chosen so far and doesn't ask again
+            KeyCode::Esc => return OnboardingAction::Done { tour: false },
+            KeyCode::Enter | KeyCode::Right => self.go(1),
+            KeyCode::Left => self.go(-1),
+            KeyCode::Up => return self.cycle(-1),
+            KeyCode::Down => return self.cycle(1),
+            KeyCode::Backspace if self.step == Step::Name => { self.name.pop(); }
+            KeyCode::Char(c) if self.step == Step::Name && self.name.chars().count() < MAX_NAME_LEN && c.is_ascii_graphic() => {
+                self.name.push(c.to_ascii_uppercase());
+            }
+            KeyCode::Char('l') | KeyCode::Char('L') if self.step == Step::Keys => self.left_handed = !self.left_handed,
+            _ => {}
+        }
+        OnboardingAction::None
+    }
+
+    /// Centred on `area`, which should be the whole screen: its size is
+    /// reported as the terminal's.
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let w = WIZARD_WIDTH.min(area.width);
+        let h = WIZARD_HEIGHT.min(area.height);
+        let rect = Rect::new(area.x + (area.width - w) / 2, area.y + (area.height - h) / 2, w, h);
+        frame.render_widget(Clear, rect);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double)
+            .border_style(Style::default().fg(theme.ink(ACCENT)))
+            .title(" 🕹 Welcome to RustCade ")
+            .title_style(Style::default().fg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD))
+            .padding(Padding::horizontal(1))
+            .style(Style::default().bg(theme.ink(BG)));
+        let inner = block.inner(rect);
+        frame.render_widget(block, rect);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+
+        // Where we are: Terminal › Theme › ...
+        let mut steps = Vec::new();
+        for (i, step) in Step::ALL.iter().enumerate() {
+            if i > 0 {
+                steps.push(Span::styled(" › ", Style::default().fg(theme.ink(LABEL))));
+            }
+            let style = match i.cmp(&self.index()) {
+                std::cmp::Ordering::Less => Style::default().fg(theme.paint(Color::Rgb(80, 160, 100), Role::Green)),
+                std::cmp::Ordering::Equal => Style::default().fg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD),
+                std::cmp::Ordering::Greater => Style::default().fg(theme.ink(LABEL)),
+            };
+            steps.push(Span::styled(step.title(), style));
+        }
+        frame.render_widget(Paragraph::new(Line::from(steps)), chunks[0]);
+
+        let lines = match self.step {
+            Step::Terminal => self.terminal_lines(area, theme),
+            Step::Theme => {
+                let mut lines = intro("Pick a colour scheme. It's applied to the screen behind as you choose.", theme);
+                lines.extend(Theme::ALL.iter().map(|&t| option(t == self.theme, t.name(), t.description(), theme)));
+                lines
+            }
+            Step::Charset => {
+                let mut lines = intro("Which characters can this terminal draw? Check the sample below.", theme);
+                for charset in Self::charsets() {
+                    let name = charset.map_or("Auto", RenderProfile::label);
+                    let note = match charset {
+                        None => "config.toml's, else detected",
+                        Some(p) if p == self.caps.suggested() => "suggested",
+                        Some(_) => "",
+                    };
+                    lines.push(option(charset == self.charset, name, note, theme));
+                }
+                lines.push(Line::from(""));
+                lines.push(Line::from(vec![
+                    Span::styled("  Sample: ", Style::default().fg(theme.ink(LABEL))),
+                    Span::styled("╭──╮ ⣿⡷⠶⠦ ▁▃▅▇ ★ 🐸 🦖 ✓", Style::default().fg(theme.paint(Color::Rgb(255, 200, 60), Role::Yellow))),
+                ]));
+                lines
+            }
+            Step::Keys => {
+                let mut lines = intro("Keyboard layout, so punctuation controls sit on keys you can reach.", theme);
+                lines.extend(KeyLayout::ALL.iter().map(|&l| {
+                    // C and POSIX locales don't say where the keyboard is from
+                    let locale = self.caps.locale.as_deref().filter(|l| l.contains('_'));
+                    let note = if Some(l) == locale.map(KeyLayout::for_locale) { "matches your locale" } else { "" };
+                    option(l == self.layout, l.name(), note, theme)
+                }));
+                lines.push(Line::from(""));
+                lines.push(Line::from(vec![
+                    Span::styled("  L ", Style::default().fg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD)),
+                    Span::styled("Left-handed: ", Style::default().fg(theme.ink(TEXT))),
+                    Span::styled(if self.left_handed { "on" } else { "off" }, Style::default().fg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD)),
+                    Span::styled("  (the arrows and WASD swap places)", Style::default().fg(theme.ink(LABEL))),
+                ]));
+                lines
+            }
+            Step::Name => {
+                let mut lines = intro("Your name, filled in whenever a score makes the tables. Leave it empty to type one each time.", theme);
+                lines.push(Line::from(vec![
+                    Span::styled("  Name: ", Style::default().fg(theme.ink(LABEL))),
+                    Span::styled(format!("{:_<width$}", self.name, width = MAX_NAME_LEN), Style::default().fg(theme.paint(Color::Rgb(255, 215, 0), Role::Yellow)).add_modifier(Modifier::BOLD)),
+                ]));
+                lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled("  Kept as rustcade.profile.save next to the binary.", Style::default().fg(theme.ink(LABEL)))));
+                lines
+            }
+            Step::Tour => {
+                let who = if self.name.is_empty() { String::new() } else { format!(", {}", self.name) };
+                let mut lines = intro(&format!("All set{}. Take a one-minute tour of the tabs before you play?", who), theme);
+                lines.push(Line::from(vec![
+                    Span::styled("  Y", Style::default().fg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD)),
+                    Span::styled(" take the tour   ", Style::default().fg(theme.ink(TEXT))),
+                    Span::styled("N", Style::default().fg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD)),
+                    Span::styled(" straight to Home", Style::default().fg(theme.ink(TEXT))),
+                ]));
+                lines
+            }
+        };
+        frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), chunks[1]);
+
+        let key = |k: &'static str| Span::styled(k, Style::default().fg(theme.paint(Color::DarkGray, Role::Faint)));
+        let sep = || Span::styled(" │ ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint)));
+        let mut help = Vec::new();
+        if matches!(self.step, Step::Theme | Step::Charset | Step::Keys) {
+            help.extend([key("↑↓"), Span::raw(" Choose"), sep()]);
+        }
+        if self.step != Step::Tour {
+            help.extend([key("Enter/→"), Span::raw(" Next"), sep()]);
+        }
+        if self.step != Step::Terminal {
+            help.extend([key("←"), Span::raw(" Back"), sep()]);
+        }
+        help.extend([key("Esc"), Span::raw(if self.step == Step::Tour { " No tour" } else { " Skip setup" })]);
+        frame.render_widget(Paragraph::new(Line::from(help)).style(Style::default().fg(theme.paint(Color::Rgb(120, 120, 140), Role::Dim))), chunks[2]);
+    }
+
+    fn terminal_lines(&self, area: Rect, theme: &Theme) -> Vec<Line<'static>> {
+        let caps = &self.caps;
+        let mut lines = intro("Let's set the arcade up for this terminal. Here's what it reports:", theme);
+        let colors = match caps.colors {
+            16 => "16",
+            256 => "256",
+            _ => "true colour",
+        };
+        lines.push(fact("Colours", format!("{} ({})", colors, caps.colors_from), caps.colors > 16, theme));
+        let unicode = match (caps.utf8(), &caps.locale) {
+            (Some(utf8), Some(locale)) => (format!("{} ({})", if utf8 { "UTF-8" } else { "not UTF-8" }, locale), utf8),
+            _ => ("no locale set".to_string(), cfg!(windows)),
+        };
+        lines.push(fact("Unicode", unicode.0, unicode.1, theme));
+        let (w, h) = (area.width, area.height);
+        let big_enough = w >= MIN_SIZE.0 && h >= MIN_SIZE.1;
+        let size = if big_enough {
+            format!("{} × {}", w, h)
+        } else {
+            format!("{} × {}: some games need {} × {}", w, h, MIN_SIZE.0, MIN_SIZE.1)
+        };
+        lines.push(fact("Size", size, big_enough, theme));
+        lines.push(fact("Looks like", caps.detected.label().to_string(), true, theme));
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "  The next steps suggest settings to suit; Esc keeps the defaults.",
+            Style::default().fg(theme.ink(LABEL)),
+        )));
+        lines
+    }
+}
+
+fn intro(text: &str, theme: &Theme) -> Vec<Line<'static>> {
+    vec![Line::from(Span::styled(text.to_string(), Style::default().fg(theme.ink(TEXT)))), Line::from("")]
+}
+
+fn option(selected: bool, name: &str, note: &str, theme: &Theme) -> Line<'static> {
+    let (mark, style) = if selected {
+        ("▶ ", Style::default().fg(theme.ink(BG)).bg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD))
+    } else {
+        ("  ", Style::default().fg(theme.ink(TEXT)))
+    };
+    Line::from(vec![
+        Span::styled(format!("{}{:<34}", mark, name), style),
+        Span::styled(format!(" {}", note), Style::default().fg(theme.ink(LABEL))),
+    ])
+}
+
+fn fact(label: &str, value: String, ok: bool, theme: &Theme) -> Line<'static> {
+    let (mark, color) = if ok { ("✓", theme.paint(Color::Rgb(80, 255, 120), Role::Green)) } else { ("!", theme.paint(Color::Rgb(255, 200, 60), Role::Yellow)) };
+    Line::from(vec![
+        Span::styled(format!("  {} ", mark), Style::default().fg(color)),
+        Span::styled(format!("{:<12}", label), Style::default().fg(theme.ink(LABEL))),
+        Span::styled(value, Style::default().fg(theme.ink(TEXT))),
+    ])
+}
+
+// ── Guided tour ──────────────────────────────────────────────────────────────
+
+/// One stop on the tour: a tab to show and a caption over it.
+struct TourStop {
+    tab: Tab,
+    /// Open that tab's help as well
+    help: bool,
+    title: &'static str,
+    text: &'static str,
+    secs: u64,
+}
+
+/// A minute in all.
+const TOUR: [TourStop; 6] = [
+    TourStop {
+        tab: Tab::Home,
+        help: false,
+        title: "Home",
+        text: "Every game has a tile here. The arrow keys pick one and Enter starts it; the number keys jump straight to the first nine.",
+        secs: 10,
+    },
+    TourStop {
+        tab: Tab::Home,
+        help: false,
+        title: "Tabs",
+        text: "The bar along the top has a tab for Home and one for each game. Tab and Shift+Tab step along it from anywhere.",
+        secs: 8,
+    },
+    TourStop {
+        tab: Tab::Frogger,
+        help: false,
+        title: "A game tab",
+        text: "Each game waits on its own tab and keeps its place while you look at others. Esc always comes back to Home.",
+        secs: 10,
+    },
+    TourStop {
+        tab: Tab::Frogger,
+        help: true,
+        title: "Help",
+        text: "? on any tab lists that tab's keys and rules, like these for Frogger. ? or Esc closes it again.",
+        secs: 10,
+    },
+    TourStop {
+        tab: Tab::Booster,
+        help: false,
+        title: "Simulations",
+        text: "Some tabs aren't arcade games: the Booster and Beam tabs run particle accelerators, and A on Home lists the badges they teach.",
+        secs: 10,
+    },
+    TourStop {
+        tab: Tab::Home,
+        help: false,
+        title: "Home keys",
+        text: "H opens the score tables, K the key bindings and M the resets. S and P set the selected game's speed and practice mode. Have fun!",
+        secs: 12,
+    },
+];
+
+/// The one-minute tour after setup. It moves itself along, and the
+/// arrows skip ahead or back.
+pub struct Tour {
+    stop: usize,
+    /// When the current stop came up
+    since: Instant,
+}
+
+impl Tour {
+    pub fn new() -> Self {
+        Tour { stop: 0, since: Instant::now() }
+    }
+
+    /// The tab to show and whether its help is open.
+    pub fn view(&self) -> (Tab, bool) {
+        let stop = &TOUR[self.stop];
+        (stop.tab, stop.help)
+    }
+
+    /// Move on once the stop's time is up; false when the tour is over.
+    pub fn tick(&mut self) -> bool {
+        if self.since.elapsed() < Duration::from_secs(TOUR[self.stop].secs) { return true; }
+        self.go(1)
+    }
+
+    fn go(&mut self, delta: isize) -> bool {
+        let next = self.stop as isize + delta;
+        if next >= TOUR.len() as isize { return false; }
+        self.stop = next.max(0) as usize;
+        self.since = Instant::now();
+        true
+    }
+
+    /// False when the tour should end.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') => false,
+            KeyCode::Right | KeyCode::Enter | KeyCode::Char(' ') => self.go(1),
+            KeyCode::Left => self.go(-1),
+            _ => true,
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let stop = &TOUR[self.stop];
+        let (w, h) = (WIZARD_WIDTH.min(area.width), 6.min(area.height));
+        if w < 30 || h < 5 { return; }
+        let rect = Rect::new(area.x + (area.width - w) / 2, area.bottom() - h, w, h);
+        frame.render_widget(Clear, rect);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.ink(ACCENT)))
+            .title(format!(" Tour {}/{}: {} ", self.stop + 1, TOUR.len(), stop.title))
+            .title_style(Style::default().fg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD))
+            .padding(Padding::horizontal(1))
+            .style(Style::default().bg(theme.ink(BG)));
+        let inner = block.inner(rect);
+        frame.render_widget(block, rect);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+        frame.render_widget(Paragraph::new(stop.text).style(Style::default().fg(theme.ink(TEXT))).wrap(Wrap { trim: true }), chunks[0]);
+
+        // Time through the whole tour, then the keys
+        let total: u64 = TOUR.iter().map(|s| s.secs).sum();
+        let done = TOUR[..self.stop].iter().map(|s| s.secs).sum::<u64>() as f64
+            + self.since.elapsed().as_secs_f64().min(stop.secs as f64);
+        let keys = " ←→ stop · Esc end ";
+        let bar_w = chunks[1].width.saturating_sub(keys.len() as u16) as usize;
+        let filled = ((done / total as f64) * bar_w as f64) as usize;
+        frame.render_widget(Paragraph::new(Line::from(vec![
+            Span::styled("━".repeat(filled), Style::default().fg(theme.ink(ACCENT))),
+            Span::styled("━".repeat(bar_w.saturating_sub(filled)), Style::default().fg(theme.paint(Color::Rgb(50, 50, 70), Role::Panel))),
+            Span::styled(keys, Style::default().fg(theme.paint(Color::DarkGray, Role::Faint))),
+        ])), chunks[1]);
+    }
+}
@@ -0,0 +1,296 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::scores::{format_date, format_detail, format_extra, format_flags, format_score, format_speed, is_timed, now_secs, HighScores, GAME_NAMES};
+use crate::ui::score_table::medal;
+use crate::ui::theme::{Role, Theme};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum DateFilter {
+    All,
+    Today,
+    Week,
+    Month,
+    Year,
+}
+
+impl DateFilter {
+    fn next(self) -> Self {
+        match self {
+            DateFilter::All => DateFilter::Today,
+            DateFilter::Today => DateFilter::Week,
+            DateFilter::Week => DateFilter::Month,
+            DateFilter::Month => DateFilter::Year,
+            DateFilter::Year => DateFilter::All,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            DateFilter::All => "All time",
+            DateFilter::Today => "Today",
+            DateFilter::Week => "Last 7 days",
+            DateFilter::Month => "Last 30 days",
+            DateFilter::Year => "Last 365 days",
+        }
+    }
+
+    /// Earliest timestamp that passes the filter (None = no limit).
+    fn cutoff(self, now: u64) -> Option<u64> {
+        match self {
+            DateFilter::All => None,
+            DateFilter::Today => Some(now - now % 86_400),
+            DateFilter::Week => Some(now.saturating_sub(7 * 86_400)),
+            DateFilter::Month => Some(now.saturating_sub(30 * 86_400)),
+            DateFilter::Year => Some(now.saturating_sub(365 * 86_400)),
+        }
+    }
+}
+
+/// Full-screen browser over the score history, opened with H on the Home tab.
+pub struct ScoreBrowser {
+    /// None = all games
+    pub game_filter: Option<usize>,
+    pub date_filter: DateFilter,
+    /// None = every season (only used when seasons are configured)
+    pub season_filter: Option<String>,
+    pub sort_by_date: bool,
+    pub selected: usize,
+    /// Row count of the last rendered page
+    pub page_size: usize,
+    pub confirm_delete: bool,
+}
+
+impl ScoreBrowser {
+    /// Opens on the current season, if seasons are configured.
+    pub fn new(high_scores: &HighScores) -> Self {
+        ScoreBrowser {
+            game_filter: None,
+            date_filter: DateFilter::All,
+            season_filter: high_scores.current_season().map(String::from),
+            sort_by_date: false,
+            selected: 0,
+            page_size: 10,
+            confirm_delete: false,
+        }
+    }
+
+    /// History indices matching the filters, in display order.
+    pub fn visible(&self, high_scores: &HighScores) -> Vec<usize> {
+        let cutoff = self.date_filter.cutoff(now_secs());
+        let history = high_scores.history();
+        let mut rows: Vec<usize> = (0..history.len())
+            .filter(|&i| self.game_filter.is_none_or(|g| history[i].game == g))
+            .filter(|&i| cutoff.is_none_or(|c| history[i].timestamp >= c))
+            .filter(|&i| self.season_filter.is_none() || high_scores.season_of(&history[i]) == self.season_filter)
+            .collect();
+        if self.sort_by_date {
+            rows.sort_by(|&a, &b| history[b].timestamp.cmp(&history[a].timestamp));
+        } else if self.game_filter.is_some_and(is_timed) {
+            // Fastest first
+            rows.sort_by_key(|&i| history[i].score);
+        } else {
+            rows.sort_by(|&a, &b| history[b].score.cmp(&history[a].score));
+        }
+        rows
+    }
+
+    /// Returns false when the browser should close.
+    pub fn handle_key(&mut self, key: KeyEvent, high_scores: &mut HighScores) -> bool {
+        let rows = self.visible(high_scores);
+
+        if self.confirm_delete {
+            if matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+                if let Some(&idx) = rows.get(self.selected) {
+                    high_scores.delete_history(idx);
+                }
+            }
+            self.confirm_delete = false;
+            self.clamp_selection(high_scores);
+            return true;
+        }
+
+        let page = self.page_size.max(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('h') | KeyCode::Char('H') => return false,
+            KeyCode::Down | KeyCode::Char('j') => self.selected += 1,
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Right | KeyCode::PageDown => self.selected += page,
+            KeyCode::Left | KeyCode::PageUp => self.selected = self.selected.saturating_sub(page),
+            KeyCode::Home => self.selected = 0,
+            KeyCode::End => self.selected = rows.len().saturating_sub(1),
+            KeyCode::Char('0') => { self.game_filter = None; self.selected = 0; }
+            KeyCode::Char(c @ '1'..='9') => {
+                self.game_filter = Some(c as usize - '1' as usize);
+                self.selected = 0;
+            }
+            KeyCode::Char('g') | KeyCode::Char('G') => {
+                self.game_filter = match self.game_filter {
+                    None => Some(0),
+                    Some(g) if g + 1 < GAME_NAMES.len() => Some(g + 1),
+                    Some(_) => None,
+                };
+                self.selected = 0;
+            }
+            KeyCode::Char('d') | KeyCode::Char('D') => {
+                self.date_filter = self.date_filter.next();
+                self.selected = 0;
+            }
+            KeyCode::Char('s') | KeyCode::Char('S') => {
+                self.sort_by_date = !self.sort_by_date;
+                self.selected = 0;
+            }
+            KeyCode::Char('e') | KeyCode::Char('E') => {
+                // Step back through seasons, newest first, then "all"
+                let labels = high_scores.season_labels();
+                self.season_filter = match &self.season_filter {
+                    None => labels.first().cloned(),
+                    Some(cur) => match labels.iter().position(|l| l == cur) {
+                        Some(i) => labels.get(i + 1).cloned(),
+                        None => labels.first().cloned(),
+                    },
+                };
+                self.selected = 0;
+            }
+            KeyCode::Delete | KeyCode::Char('x') | KeyCode::Char('X') => {
+                self.confirm_delete = !rows.is_empty();
+            }
+            _ => {}
+        }
+        self.clamp_selection(high_scores);
+        true
+    }
+
+    fn clamp_selection(&mut self, high_scores: &HighScores) {
+        let len = self.visible(high_scores).len();
+        self.selected = self.selected.min(len.saturating_sub(1));
+    }
+}
+
+pub fn render_score_browser(frame: &mut Frame, area: Rect, browser: &mut ScoreBrowser, high_scores: &HighScores, theme: &Theme) {
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Double)
+        .border_style(Style::default().fg(theme.paint(Color::Rgb(255, 200, 80), Role::Orange)))
+        .title(" 🏆 Score History ")
+        .title_style(Style::default().fg(theme.paint(Color::Rgb(255, 220, 80), Role::Yellow)).add_modifier(Modifier::BOLD))
+        .style(Style::default().bg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Filters
+            Constraint::Length(1), // Column header
+            Constraint::Min(1),    // Rows
+            Constraint::Length(1), // Page indicator
+            Constraint::Length(1), // Help bar
+        ])
+        .split(inner);
+
+    let label = Style::default().fg(theme.paint(Color::Rgb(100, 100, 130), Role::Faint));
+    let value = Style::default().fg(theme.paint(Color::Rgb(80, 200, 255), Role::Blue)).add_modifier(Modifier::BOLD);
+    let game_label = browser.game_filter.map_or("All games", |g| GAME_NAMES[g]);
+    let mut filter_spans = vec![
+        Span::styled(" Game: ", label),
+        Span::styled(game_label, value),
+        Span::styled("   Date: ", label),
+        Span::styled(browser.date_filter.label(), value),
+        Span::styled("   Sort: ", label),
+        Span::styled(if browser.sort_by_date { "Newest" } else { "Score" }, value),
+    ];
+    if let Some(current) = high_scores.current_season() {
+        let season = match &browser.season_filter {
+            None => "All seasons".to_string(),
+            Some(s) if s == current => format!("{} (current)", s),
+            Some(s) => format!("{} (archived)", s),
+        };
+        filter_spans.push(Span::styled("   Season: ", label));
+        filter_spans.push(Span::styled(season, value));
+    }
+    let filters = Paragraph::new(Line::from(filter_spans));
+    frame.render_widget(filters, chunks[0]);
+
+    let header = Paragraph::new(Line::from(Span::styled(
+        format!(" {:>4}  {:<10} {:<10} {:>10}  {:<10} {:<10} {:<6} {:<10}    {}", "#", "Game", "Name", "Score", "Date", "Detail", "Speed", "Mods", "Stats"),
+        Style::default().fg(theme.paint(Color::Rgb(180, 180, 200), Role::Text)).add_modifier(Modifier::BOLD),
+    )));
+    frame.render_widget(header, chunks[1]);
+
+    let rows = browser.visible(high_scores);
+    let page_size = (chunks[2].height as usize).max(1);
+    browser.page_size = page_size;
+    let page = browser.selected / page_size;
+    let page_count = rows.len().div_ceil(page_size).max(1);
+
+    let history = high_scores.history();
+    let tops: Vec<_> = (0..GAME_NAMES.len()).map(|g| high_scores.top_scores(g)).collect();
+    let mut lines: Vec<Line> = Vec::new();
+    if rows.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "   No scores match these filters",
+            Style::default().fg(theme.paint(Color::Rgb(60, 60, 80), Role::Faint)),
+        )));
+    }
+    for (i, &idx) in rows.iter().enumerate().skip(page * page_size).take(page_size) {
+        let entry = &history[idx];
+        let name = if entry.name.is_empty() { "???" } else { entry.name.as_str() };
+        // Flag entries that still hold a top-3 slot
+        let medal = tops[entry.game].iter().position(|t| t.score == entry.score && t.name == entry.name).map_or("  ", medal);
+        let text = format!(
+            " {:>4}  {:<10} {:<10} {:>10}  {:<10} {:<10} {:<6} {:<10} {} {}",
+            i + 1,
+            GAME_NAMES[entry.game],
+            name,
+            format_score(entry.game, entry.score),
+            format_date(entry.timestamp),
+            format_detail(entry.game, entry.detail),
+            format_speed(entry.speed),
+            format_flags(entry.game, entry.flags),
+            medal,
+            format_extra(entry.game, &entry.extra),
+        );
+        let style = if i == browser.selected {
+            Style::default().fg(theme.paint(Color::Rgb(15, 15, 25), Role::Background)).bg(theme.paint(Color::Rgb(255, 220, 80), Role::Yellow)).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.paint(Color::Rgb(200, 200, 220), Role::Text))
+        };
+        lines.push(Line::from(Span::styled(text, style)));
+    }
+    frame.render_widget(Paragraph::new(lines), chunks[2]);
+
+    let indicator = if browser.confirm_delete {
+        Line::from(vec![
+            Span::styled(" Delete this entry? ", Style::default().fg(theme.paint(Color::Rgb(255, 80, 80), Role::Red)).add_modifier(Modifier::BOLD)),
+            Span::styled("Y", value),
+            Span::styled(" yes  ", label),
+            Span::styled("any key", value),
+            Span::styled(" cancel", label),
+        ])
+    } else {
+        Line::from(Span::styled(
+            format!(" Page {}/{}  ({} entries)", page + 1, page_count, rows.len()),
+            label,
+        ))
+    };
+    frame.render_widget(Paragraph::new(indicator), chunks[3]);
+
+    let sep = Span::styled("│ ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint)));
+    let key = |k: &'static str| Span::styled(k, Style::default().fg(theme.paint(Color::DarkGray, Role::Faint)));
+    let help = Paragraph::new(Line::from(vec![
+        key(" ↑↓"), Span::raw(" Select "), sep.clone(),
+        key("←→"), Span::raw(" Page "), sep.clone(),
+        key("G/0-9"), Span::raw(" Game "), sep.clone(),
+        key("D"), Span::raw(" Dates "), sep.clone(),
+        key("S"), Span::raw(" Sort "), sep.clone(),
+        key("E"), Span::raw(" Season "), sep.clone(),
+        key("X"), Span::raw(" Delete "), sep,
+        key("H/Esc"), Span::raw(" Close"),
+    ]))
+    .style(Style::default().fg(theme.paint(Color::Rgb(120, 120, 140), Role::Dim)));
+    frame.render_widget(help, chunks[4]);
+}
@@ -0,0 +1,87 @@
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+
+use crate::scores::{format_score, ScoreEntry};
+use crate::ui::theme::{Ink, Role, Theme};
+
+/// Medal for a 0-based rank, or two spaces off the podium so columns line up.
+pub fn medal(rank: usize) -> &'static str {
+    match rank {
+        0 => "🥇",
+        1 => "🥈",
+        2 => "🥉",
+        _ => "  ",
+    }
+}
+
+/// 1-based place as "1st", "2nd", "11th", "23rd".
+pub fn ordinal(place: usize) -> String {
+    let suffix = match (place % 10, place % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", place, suffix)
+}
+
+/// A game's top scores, one row each: medal, place, name and score. Empty
+/// slots show as dashes so the podium keeps its shape.
+pub struct ScoreTable<'a> {
+    game: usize,
+    entries: &'a [ScoreEntry],
+    highlight: Option<usize>,
+    accent: Ink,
+    theme: Theme,
+}
+
+impl<'a> ScoreTable<'a> {
+    pub fn new(game: usize, entries: &'a [ScoreEntry], theme: &Theme) -> Self {
+        ScoreTable { game, entries, highlight: None, accent: Ink(Color::Rgb(255, 215, 0), Role::Yellow), theme: *theme }
+    }
+
+    /// Pick out one row, e.g. the score just being entered.
+    pub fn highlight(self, rank: Option<usize>) -> Self {
+        ScoreTable { highlight: rank, ..self }
+    }
+
+    /// Colour of the scores.
+    pub fn accent(self, accent: Ink) -> Self {
+        ScoreTable { accent, ..self }
+    }
+
+    pub fn lines(&self) -> Vec<Line<'static>> {
+        let theme = self.theme;
+        let accent = theme.ink(self.accent);
+        let dim = Style::default().fg(theme.paint(Color::Rgb(70, 70, 90), Role::Faint));
+        self.entries
+            .iter()
+            .enumerate()
+            .map(|(rank, entry)| {
+                let mut spans = vec![Span::raw(format!("  {} ", medal(rank)))];
+                if entry.score == 0 {
+                    spans.push(Span::styled(format!("{:<5}{:<10} {:>8}", ordinal(rank + 1), "---", "—"), dim));
+                    return Line::from(spans);
+                }
+                let (name_style, score_style) = if self.highlight == Some(rank) {
+                    let on = Style::default().bg(theme.paint(Color::Rgb(60, 60, 90), Role::Faint)).add_modifier(Modifier::BOLD);
+                    (on.fg(theme.paint(Color::White, Role::Bright)), on.fg(accent))
+                } else {
+                    (Style::default().fg(theme.paint(Color::Rgb(200, 200, 220), Role::Text)), Style::default().fg(accent))
+                };
+                let name = if entry.name.is_empty() { "???" } else { entry.name.as_str() };
+                spans.push(Span::styled(format!("{:<5}", ordinal(rank + 1)), Style::default().fg(theme.paint(Color::Rgb(120, 120, 150), Role::Dim))));
+                spans.push(Span::styled(format!("{:<10}", name), name_style));
+                spans.push(Span::styled(format!(" {:>8}", format_score(self.game, entry.score)), score_style));
+                Line::from(spans)
+            })
+            .collect()
+    }
+}
+
+impl Widget for ScoreTable<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Paragraph::new(self.lines()).render(area, buf);
+    }
+}
@@ -0,0 +1,310 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::profile::Profile;
+use crate::ui::compat::RenderProfile;
+use crate::ui::hud;
+use crate::ui::theme::{Ink, Role, Theme};
+
+const ACCENT: Ink = Ink(Color::Rgb(80, 200, 255), Role::Blue);
+const LABEL: Ink = Ink(Color::Rgb(100, 100, 130), Role::Faint);
+const TEXT: Ink = Ink(Color::Rgb(210, 210, 230), Role::Text);
+const BG: Ink = Ink(Color::Rgb(15, 15, 25), Role::Background);
+/// Colours the games draw with, shown so a theme can be judged on them
+const SWATCHES: [(&str, Ink); 8] = [
+    ("score", Ink(Color::Rgb(255, 215, 0), Role::Yellow)),
+    ("alert", Ink(Color::Rgb(255, 60, 60), Role::Red)),
+    ("frog", Ink(Color::Rgb(80, 255, 120), Role::Green)),
+    ("water", Ink(Color::Rgb(60, 120, 220), Role::Blue)),
+    ("menu", Ink(Color::Rgb(80, 200, 255), Role::Blue)),
+    ("alien", Ink(Color::Rgb(200, 120, 255), Role::Violet)),
+    ("fire", Ink(Color::Rgb(255, 140, 30), Role::Orange)),
+    ("text", Ink(Color::Rgb(140, 140, 140), Role::Dim)),
+];
+
+/// One row of the Settings tab.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Setting {
+    TickRate,
+    /// Shown, not changed: the games are tuned to its fixed step
+    Simulation,
+    Theme,
+    Difficulty,
+    Volume,
+    Mute,
+    KeyLayout,
+    LeftHanded,
+    ResetScores,
+}
+
+impl Setting {
+    pub const ALL: [Setting; 9] = [
+        Setting::TickRate,
+        Setting::Simulation,
+        Setting::Theme,
+        Setting::Difficulty,
+        Setting::Volume,
+        Setting::Mute,
+        Setting::KeyLayout,
+        Setting::LeftHanded,
+        Setting::ResetScores,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            Setting::TickRate => "Tick rate",
+            Setting::Simulation => "Simulation",
+            Setting::Theme => "Theme",
+            Setting::Difficulty => "Beam difficulty",
+            Setting::Volume => "Master volume",
+            Setting::Mute => "Sound",
+            Setting::KeyLayout => "Keyboard layout",
+            Setting::LeftHanded => "Left-handed keys",
+            Setting::ResetScores => "Reset scores",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Setting::TickRate => "How often input is read and the screen redrawn",
+            Setting::Simulation => "Fixed game step; fps is frames drawn in the last game",
+            Setting::Theme => "Colours for every screen",
+            Setting::Difficulty => "Where new Beam games start",
+            Setting::Volume => "Scales each game's own volume (V on Home)",
+            Setting::Mute => "Every cue at once (U on Home)",
+            Setting::KeyLayout => "Key preset; drops custom bindings (K on Home)",
+            Setting::LeftHanded => "Arrows and WASD swap places",
+            Setting::ResetScores => "Opens the reset screen with the tables ticked",
+        }
+    }
+
+    /// Changed with ← →, rather than shown or opened.
+    fn adjustable(self) -> bool {
+        !matches!(self, Setting::Simulation | Setting::ResetScores)
+    }
+}
+
+pub enum SettingsTabAction {
+    None,
+    /// Step a setting's value up or down its choices
+    Change(Setting, isize),
+    /// Enter on Reset scores: open the reset screen
+    ResetScores,
+}
+
+/// The Settings tab: a row per setting, changed with ← →. Each change
+/// applies at once and is written back to config.toml.
+#[derive(Default)]
+pub struct SettingsTab {
+    pub selected: usize,
+    /// Where the last change went, or why it couldn't be saved
+    pub message: Option<String>,
+}
+
+impl SettingsTab {
+    pub fn handle_key(&mut self, key: KeyEvent) -> SettingsTabAction {
+        let setting = Setting::ALL[self.selected];
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => self.selected = (self.selected + 1).min(Setting::ALL.len() - 1),
+            KeyCode::Enter if setting == Setting::ResetScores => return SettingsTabAction::ResetScores,
+            KeyCode::Left | KeyCode::Char('h') => return SettingsTabAction::Change(setting, -1),
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Enter | KeyCode::Char(' ') => {
+                return SettingsTabAction::Change(setting, 1);
+            }
+            _ => {}
+        }
+        SettingsTabAction::None
+    }
+}
+
+/// The choice `delta` places from `now` in `all`, wrapping at the ends.
+pub fn step<T: Copy + PartialEq>(all: &[T], now: T, delta: isize) -> T {
+    let i = all.iter().position(|&t| t == now).unwrap_or(0) as isize;
+    all[(i + delta).rem_euclid(all.len() as isize) as usize]
+}
+
+/// `values` holds each setting's current value as shown, in
+/// `Setting::ALL` order.
+pub fn render_settings_tab(frame: &mut Frame, area: Rect, tab: &SettingsTab, values: &[String], theme: &Theme) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.paint(Color::Rgb(60, 150, 200), Role::Blue)))
+        .title(" ⚙ Settings ")
+        .title_style(Style::default().fg(theme.paint(Color::Rgb(200, 120, 255), Role::Violet)).add_modifier(Modifier::BOLD));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Intro
+            Constraint::Min(1),    // Settings
+            Constraint::Length(2), // Where it was saved
+            Constraint::Length(1), // Help bar
+        ])
+        .split(inner);
+
+    let intro = Paragraph::new(Line::from(Span::styled(
+        " Changes apply straight away and are saved to config.toml.",
+        Style::default().fg(theme.ink(LABEL)),
+    )));
+    frame.render_widget(intro, chunks[0]);
+
+    let mut lines = Vec::new();
+    for (i, (setting, value)) in Setting::ALL.iter().zip(values).enumerate() {
+        let (label, value_style) = if i == tab.selected {
+            (Style::default().fg(theme.ink(BG)).bg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD), Style::default().fg(theme.paint(Color::Rgb(255, 220, 80), Role::Yellow)).add_modifier(Modifier::BOLD))
+        } else {
+            (Style::default().fg(theme.ink(TEXT)), Style::default().fg(theme.ink(ACCENT)))
+        };
+        let arrows = if i == tab.selected && setting.adjustable() { ("◀ ", " ▶") } else { ("  ", "  ") };
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {:<18}", setting.label()), label),
+            Span::styled(format!("  {}{:<22}{}", arrows.0, value, arrows.1), value_style),
+            Span::styled(format!("  {}", setting.description()), Style::default().fg(theme.ink(LABEL))),
+        ]));
+        lines.push(Line::from(""));
+    }
+    frame.render_widget(Paragraph::new(lines), chunks[1]);
+
+    if let Some(message) = &tab.message {
+        let status = Line::from(Span::styled(format!(" {}", message), Style::default().fg(theme.paint(Color::Rgb(255, 220, 80), Role::Yellow))));
+        frame.render_widget(Paragraph::new(status), chunks[2]);
+    }
+
+    let help = hud::overlay_legend(&[("↑↓", "Select"), ("←→", "Change"), ("Enter", "Open / change"), ("Esc", "Home")], theme);
+    frame.render_widget(Paragraph::new(help), chunks[3]);
+}
+
+pub enum SettingsAction {
+    None,
+    /// A theme or character set is being tried; show it straight away
+    Preview,
+    /// Keep the choices in the profile
+    Keep,
+    /// Put back what the profile had
+    Cancel,
+}
+
+/// Display settings, opened with T on the Home tab: the colour theme and
+/// character set the first-run setup asked for, changed later. Each pick
+/// is shown on the screen behind until Enter keeps it or Esc puts the
+/// old one back.
+pub struct DisplaySettings {
+    /// Theme list focused, else the character sets
+    on_theme: bool,
+    pub theme: Theme,
+    /// None follows `[display] profile`, as `Profile::charset`
+    pub charset: Option<RenderProfile>,
+}
+
+impl DisplaySettings {
+    pub fn new(profile: &Profile) -> Self {
+        DisplaySettings { on_theme: true, theme: profile.theme, charset: profile.charset }
+    }
+
+    /// Charset choices in list order: auto, then each profile.
+    fn charsets() -> Vec<Option<RenderProfile>> {
+        std::iter::once(None).chain(RenderProfile::ALL.into_iter().map(Some)).collect()
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> SettingsAction {
+        let delta = match key.code {
+            KeyCode::Esc | KeyCode::Char('t') | KeyCode::Char('T') => return SettingsAction::Cancel,
+            KeyCode::Enter => return SettingsAction::Keep,
+            KeyCode::Tab | KeyCode::BackTab | KeyCode::Left | KeyCode::Right => {
+                self.on_theme = !self.on_theme;
+                return SettingsAction::None;
+            }
+            KeyCode::Up | KeyCode::Char('k') => -1,
+            KeyCode::Down | KeyCode::Char('j') => 1,
+            _ => return SettingsAction::None,
+        };
+        if self.on_theme {
+            self.theme = step(&Theme::ALL, self.theme, delta);
+        } else {
+            self.charset = step(&Self::charsets(), self.charset, delta);
+        }
+        SettingsAction::Preview
+    }
+}
+
+pub fn render_settings(frame: &mut Frame, area: Rect, settings: &DisplaySettings, theme: &Theme) {
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Double)
+        .border_style(Style::default().fg(theme.ink(ACCENT)))
+        .title(" 🎨 Display ")
+        .title_style(Style::default().fg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD))
+        .style(Style::default().bg(theme.ink(BG)));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Intro
+            Constraint::Min(1),    // Themes and character sets
+            Constraint::Length(2), // Swatches
+            Constraint::Length(1), // Help bar
+        ])
+        .split(inner);
+
+    let intro = Paragraph::new(Line::from(Span::styled(
+        " Colours and characters for every screen. Each choice shows behind this one as you pick it.",
+        Style::default().fg(theme.ink(LABEL)),
+    )));
+    frame.render_widget(intro, chunks[0]);
+
+    let mut lines = vec![heading("Theme", settings.on_theme, theme)];
+    lines.extend(Theme::ALL.iter().map(|&t| option(t == settings.theme, settings.on_theme, t.name(), t.description(), theme)));
+    lines.push(Line::from(""));
+    lines.push(heading("Characters", !settings.on_theme, theme));
+    for charset in DisplaySettings::charsets() {
+        let name = charset.map_or("Auto", RenderProfile::label);
+        let note = if charset.is_none() { "config.toml's, else detected" } else { "" };
+        lines.push(option(charset == settings.charset, !settings.on_theme, name, note, theme));
+    }
+    frame.render_widget(Paragraph::new(lines), chunks[1]);
+
+    // In the theme being tried, like everything else on screen
+    let mut swatches = vec![Span::styled(" ", Style::default())];
+    for (name, color) in SWATCHES {
+        swatches.push(Span::styled("██", Style::default().fg(theme.ink(color))));
+        swatches.push(Span::styled(format!(" {}  ", name), Style::default().fg(theme.ink(color))));
+    }
+    frame.render_widget(Paragraph::new(Line::from(swatches)), chunks[2]);
+
+    let sep = Span::styled("│ ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 60), Role::Faint)));
+    let key = |k: &'static str| Span::styled(k, Style::default().fg(theme.paint(Color::DarkGray, Role::Faint)));
+    let help = Paragraph::new(Line::from(vec![
+        key(" ↑↓"), Span::raw(" Choose "), sep.clone(),
+        key("Tab"), Span::raw(" Theme / Characters "), sep.clone(),
+        key("Enter"), Span::raw(" Keep "), sep,
+        key("T/Esc"), Span::raw(" Cancel"),
+    ]))
+    .style(Style::default().fg(theme.paint(Color::Rgb(120, 120, 140), Role::Dim)));
+    frame.render_widget(help, chunks[3]);
+}
+
+fn heading(title: &str, focused: bool, theme: &Theme) -> Line<'static> {
+    let color = if focused { theme.paint(Color::Rgb(255, 220, 80), Role::Yellow) } else { theme.ink(LABEL) };
+    Line::from(Span::styled(format!(" {}", title), Style::default().fg(color).add_modifier(Modifier::BOLD)))
+}
+
+fn option(selected: bool, focused: bool, name: &str, note: &str, theme: &Theme) -> Line<'static> {
+    let (mark, style) = match (selected, focused) {
+        (true, true) => ("▶ ", Style::default().fg(theme.ink(BG)).bg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD)),
+        (true, false) => ("▶ ", Style::default().fg(theme.ink(ACCENT)).add_modifier(Modifier::BOLD)),
+        _ => ("  ", Style::default().fg(theme.ink(TEXT))),
+    };
+    Line::from(vec![
+        Span::styled(format!("   {}{:<32}", mark, name), style),
+        Span::styled(format!(" {}", note), Style::default().fg(theme.ink(LABEL))),
+    ])
+}
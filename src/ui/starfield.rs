@@ -0,0 +1,64 @@
+use ratatui::prelude::*;
+
+use crate::ui::theme::{Role, Theme};
+
+/// Parallax star background for the space games, drawn with plain
+/// characters under the braille layers. Stars come from a hash of their
+/// cell, so nothing is stored and every frame of a tick looks the same.
+#[derive(Clone, Copy)]
+pub struct Starfield {
+    /// Stars per thousand cells, all layers together
+    pub density: f32,
+    /// Cells per tick the nearest layer moves (x, y); farther layers
+    /// move a half and a quarter as fast
+    pub drift: (f32, f32),
+    pub seed: u32,
+}
+
+/// Far to near: speed factor, share of the stars, glyph, brightness
+const LAYERS: [(f32, f32, char, u8); 3] = [
+    (0.25, 0.55, '.', 45),
+    (0.5, 0.3, '·', 80),
+    (1.0, 0.15, '•', 125),
+];
+
+impl Starfield {
+    pub const fn new(density: f32, drift: (f32, f32)) -> Self {
+        Starfield { density, drift, seed: 0x5747 }
+    }
+
+    pub const fn with_seed(self, seed: u32) -> Self {
+        Starfield { seed, ..self }
+    }
+
+    /// Fill the empty cells of `grid` with stars as they sit at `tick`.
+    /// Pass a fixed tick to hold the field still (e.g. reduced motion).
+    pub fn draw(&self, grid: &mut [Vec<(char, Style)>], bg: Color, tick: u64, theme: &Theme) {
+        for (layer, &(speed, share, glyph, brightness)) in LAYERS.iter().enumerate() {
+            let threshold = (self.density * share / 1000.0 * u32::MAX as f32) as u32;
+            let dx = (self.drift.0 * speed * tick as f32).floor() as i64;
+            let dy = (self.drift.1 * speed * tick as f32).floor() as i64;
+            for (y, row) in grid.iter_mut().enumerate() {
+                for (x, cell) in row.iter_mut().enumerate() {
+                    if cell.0 != ' ' { continue; }
+                    // Sample the field at the cell the drift has brought here
+                    let h = hash(x as i64 - dx, y as i64 - dy, self.seed ^ layer as u32);
+                    if h < threshold {
+                        // Low hash bits vary the shade a little
+                        let b = brightness.saturating_add((h % 24) as u8);
+                        *cell = (glyph, Style::default().fg(theme.shade(Color::Rgb(b, b, b.saturating_add(12)), Role::Bright, b as f32 / 255.0)).bg(bg));
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn hash(x: i64, y: i64, seed: u32) -> u32 {
+    let mut h = (x as u32).wrapping_mul(0x9E37_79B1) ^ (y as u32).wrapping_mul(0x85EB_CA77) ^ seed.wrapping_mul(0xC2B2_AE3D);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x2C1B_3C6D);
+    h ^= h >> 12;
+    h = h.wrapping_mul(0x297A_2D39);
+    h ^ (h >> 15)
+}
@@ -1,18 +1,36 @@
 use ratatui::prelude::*;
 use ratatui::widgets::*;
 
-use crate::app::{App, Tab};
+use crate::app::App;
+use crate::ui::theme::{Role, Theme};
 
-pub fn render_tabs(frame: &mut Frame, app: &App, area: Rect) {
-    let titles: Vec<Line> = Tab::all()
+/// Space the divider and the padding either side of a title take
+const TAB_GAP: usize = 5;
+
+pub fn render_tabs(frame: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+    let tabs = app.tabs();
+    let selected = tabs.iter().position(|t| *t == app.current_tab).unwrap_or(0);
+    // When they don't all fit, show the run of tabs around the current one
+    let fits = |range: &std::ops::Range<usize>| {
+        tabs[range.clone()].iter().map(|t| t.title().chars().count() + TAB_GAP).sum::<usize>() <= area.width.saturating_sub(2) as usize + TAB_GAP
+    };
+    let mut shown = selected..selected + 1;
+    loop {
+        let wider = [shown.start.checked_sub(1).map(|s| s..shown.end), (shown.end < tabs.len()).then(|| shown.start..shown.end + 1)];
+        match wider.into_iter().flatten().find(|r| fits(r)) {
+            Some(range) => shown = range,
+            None => break,
+        }
+    }
+    let titles: Vec<Line> = tabs[shown.clone()]
         .iter()
         .map(|t| {
             let style = if *t == app.current_tab {
                 Style::default()
-                    .fg(Color::Rgb(255, 220, 80))
+                    .fg(theme.paint(Color::Rgb(255, 220, 80), Role::Yellow))
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::Rgb(120, 120, 140))
+                Style::default().fg(theme.paint(Color::Rgb(120, 120, 140), Role::Dim))
             };
             Line::from(Span::styled(t.title(), style))
         })
@@ -22,23 +40,23 @@ pub fn render_tabs(frame: &mut Frame, app: &App, area: Rect) {
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Rgb(60, 150, 200)))
+                .border_style(Style::default().fg(theme.paint(Color::Rgb(60, 150, 200), Role::Blue)))
                 .border_type(BorderType::Rounded)
                 .title(" 🕹 RustCade ")
                 .title_style(
                     Style::default()
-                        .fg(Color::Rgb(200, 120, 255))
+                        .fg(theme.paint(Color::Rgb(200, 120, 255), Role::Violet))
                         .add_modifier(Modifier::BOLD),
                 ),
         )
-        .select(app.current_tab.index())
-        .style(Style::default().fg(Color::White))
+        .select(selected - shown.start)
+        .style(Style::default().fg(theme.paint(Color::White, Role::Bright)))
         .highlight_style(
             Style::default()
-                .fg(Color::Rgb(255, 220, 80))
+                .fg(theme.paint(Color::Rgb(255, 220, 80), Role::Yellow))
                 .add_modifier(Modifier::BOLD),
         )
-        .divider(Span::styled(" │ ", Style::default().fg(Color::Rgb(60, 60, 80))));
+        .divider(Span::styled(" │ ", Style::default().fg(theme.paint(Color::Rgb(60, 60, 80), Role::Faint))));
 
     frame.render_widget(tabs, area);
 }
@@ -0,0 +1,220 @@
+use ratatui::prelude::*;
+
+use crate::ui::compat;
+use crate::ui::fx::Rgb;
+
+/// Colour scheme every screen and game draws with. Each colour is drawn
+/// as an `Ink`: the arcade's own colour plus the part it plays, and the
+/// theme picks the colour for that part from its palette.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Theme {
+    /// The colours as drawn
+    Arcade,
+    /// Greys spread apart and saturated accents, on black
+    HighContrast,
+    /// Black, white and greys, for terminals that only do shades
+    Monochrome,
+    /// Ethan Schoonover's Solarized (dark): eight accents on blue-grey tones
+    Solarized,
+}
+
+/// The part a colour plays, which every theme but Arcade colours by.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Role {
+    /// The screen behind everything
+    Background,
+    /// Raised surfaces: overlays, panels, dark fills
+    Panel,
+    /// Lines and text meant to stay in the background
+    Faint,
+    Dim,
+    Text,
+    /// Titles and anything that should stand out without a hue
+    Bright,
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Cyan,
+    Blue,
+    Violet,
+    Magenta,
+}
+
+impl Role {
+    /// Nearest role to a colour nobody gave one, by brightness for greys
+    /// and by hue for the rest. Only plugin games, drawn through a C ABI
+    /// that has no roles, are coloured this way.
+    pub fn nearest((r, g, b): Rgb) -> Role {
+        let spread = r.max(g).max(b) - r.min(g).min(b);
+        if spread < GREY_SPREAD {
+            return match (r as u32 * 299 + g as u32 * 587 + b as u32 * 114) / 1000 {
+                0..=23 => Role::Background,
+                24..=59 => Role::Panel,
+                60..=109 => Role::Faint,
+                110..=169 => Role::Dim,
+                170..=224 => Role::Text,
+                _ => Role::Bright,
+            };
+        }
+        let (rf, gf, bf) = (r as f32, g as f32, b as f32);
+        let max = rf.max(gf).max(bf);
+        let d = max - rf.min(gf).min(bf);
+        let sector = if max == rf {
+            ((gf - bf) / d).rem_euclid(6.0)
+        } else if max == gf {
+            (bf - rf) / d + 2.0
+        } else {
+            (rf - gf) / d + 4.0
+        };
+        match (sector * 60.0) as u32 {
+            0..=14 | 336..=360 => Role::Red,
+            15..=41 => Role::Orange,
+            42..=80 => Role::Yellow,
+            81..=150 => Role::Green,
+            151..=196 => Role::Cyan,
+            197..=240 => Role::Blue,
+            241..=285 => Role::Violet,
+            _ => Role::Magenta,
+        }
+    }
+}
+
+/// A colour as the arcade draws it, and the part it plays.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Ink(pub Color, pub Role);
+
+/// Colours closer to grey than this (channel spread) count as greys
+const GREY_SPREAD: u8 = 48;
+
+/// Each palette's colour for every role, in `Role` order.
+const HIGH_CONTRAST: [Rgb; 14] = [
+    (0, 0, 0), (0, 0, 0), (140, 140, 140), (210, 210, 210), (255, 255, 255), (255, 255, 255),
+    (255, 70, 70), (255, 160, 40), (255, 255, 0), (0, 255, 90), (0, 255, 255), (100, 170, 255), (200, 140, 255), (255, 80, 255),
+];
+/// Warm accents and alerts white, cool ones a step down, so a shape and
+/// what it stands on stay apart.
+const MONOCHROME: [Rgb; 14] = [
+    (0, 0, 0), (28, 28, 28), (90, 90, 90), (160, 160, 160), (215, 215, 215), (255, 255, 255),
+    (255, 255, 255), (190, 190, 190), (255, 255, 255), (215, 215, 215), (235, 235, 235), (160, 160, 160), (160, 160, 160), (190, 190, 190),
+];
+/// Solarized dark: base03, base02, base01, base00, base0 and base2, then
+/// the eight accents.
+const SOLARIZED: [Rgb; 14] = [
+    (0, 43, 54), (7, 54, 66), (88, 110, 117), (101, 123, 131), (131, 148, 150), (238, 232, 213),
+    (220, 50, 47), (203, 75, 22), (181, 137, 0), (133, 153, 0), (42, 161, 152), (38, 139, 210), (108, 113, 196), (211, 54, 130),
+];
+
+impl Theme {
+    /// In save-file order; new themes go on the end.
+    pub const ALL: [Theme; 4] = [Theme::Arcade, Theme::HighContrast, Theme::Monochrome, Theme::Solarized];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Theme::Arcade => "Arcade",
+            Theme::HighContrast => "High contrast",
+            Theme::Monochrome => "Monochrome",
+            Theme::Solarized => "Solarized",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Theme::Arcade => "Each game's own colours (default)",
+            Theme::HighContrast => "Bright colours on black",
+            Theme::Monochrome => "Black, white and greys",
+            Theme::Solarized => "Solarized dark's sixteen colours",
+        }
+    }
+
+    /// Name in `[display] theme`.
+    pub fn key(self) -> &'static str {
+        match self {
+            Theme::Arcade => "arcade",
+            Theme::HighContrast => "high_contrast",
+            Theme::Monochrome => "monochrome",
+            Theme::Solarized => "solarized",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "default" => Some(Theme::Arcade),
+            name => Self::ALL.iter().copied().find(|t| t.key() == name),
+        }
+    }
+
+    pub fn from_index(i: u8) -> Self {
+        Self::ALL.get(i as usize).copied().unwrap_or(Theme::Arcade)
+    }
+
+    fn palette(self) -> Option<&'static [Rgb; 14]> {
+        match self {
+            Theme::Arcade => None,
+            Theme::HighContrast => Some(&HIGH_CONTRAST),
+            Theme::Monochrome => Some(&MONOCHROME),
+            Theme::Solarized => Some(&SOLARIZED),
+        }
+    }
+
+    /// The colour to draw `ink` in.
+    pub fn ink(self, Ink(color, role): Ink) -> Color {
+        match self.palette() {
+            Some(palette) => {
+                let (r, g, b) = palette[role as usize];
+                Color::Rgb(r, g, b)
+            }
+            None => color,
+        }
+    }
+
+    /// `ink` for a colour written out where it's drawn.
+    pub fn paint(self, color: Color, role: Role) -> Color {
+        self.ink(Ink(color, role))
+    }
+
+    /// `ink` as RGB, for colours that are blended or faded.
+    pub fn rgb(self, ink: Ink) -> Rgb {
+        compat::to_rgb(self.ink(ink)).unwrap_or((255, 255, 255))
+    }
+
+    /// `color` is already shaded to `level` (0-1) for a gradient or a fade;
+    /// palettes shade their colour for `role` up from the background.
+    pub fn shade(self, color: Color, role: Role, level: f32) -> Color {
+        let Some(palette) = self.palette() else { return color };
+        let (base, top) = (palette[Role::Background as usize], palette[role as usize]);
+        let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * level.clamp(0.0, 1.0)) as u8;
+        Color::Rgb(mix(base.0, top.0), mix(base.1, top.1), mix(base.2, top.2))
+    }
+
+    /// What the screen is cleared to; the terminal's own under Arcade.
+    pub fn background(self) -> Color {
+        match self.palette() {
+            Some(_) => self.paint(Color::Reset, Role::Background),
+            None => Color::Reset,
+        }
+    }
+
+    /// Recolour what a plugin game drew in `area`, which can't say what
+    /// its colours are for.
+    #[cfg(feature = "plugins")]
+    pub fn recolour(self, buf: &mut Buffer, area: Rect) {
+        if self.palette().is_none() {
+            return;
+        }
+        let area = area.intersection(buf.area);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let Some(cell) = buf.cell_mut((x, y)) else { continue };
+                cell.fg = match compat::to_rgb(cell.fg) {
+                    Some(rgb) => self.paint(cell.fg, Role::nearest(rgb)),
+                    None => self.paint(cell.fg, Role::Text),
+                };
+                cell.bg = match compat::to_rgb(cell.bg) {
+                    Some(rgb) => self.paint(cell.bg, Role::nearest(rgb)),
+                    None => self.background(),
+                };
+            }
+        }
+    }
+}